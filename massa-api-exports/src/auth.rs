@@ -0,0 +1,95 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use serde::Deserialize;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// scope granting access to every method of an API
+pub const ALL_METHODS: &str = "*";
+
+/// an access token and the methods it is allowed to call
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuthToken {
+    /// secret sent by the clients in the `Authorization: Bearer <token>` header
+    pub token: String,
+    /// names of the methods that can be called with this token, `*` allows all of them
+    pub methods: Vec<String>,
+}
+
+impl AuthToken {
+    /// whether this token can call `method`
+    pub fn allows(&self, method: &str) -> bool {
+        self.methods
+            .iter()
+            .any(|allowed| allowed == ALL_METHODS || allowed == method)
+    }
+
+    /// whether this token can call every method of the API
+    pub fn allows_all(&self) -> bool {
+        self.methods.iter().any(|allowed| allowed == ALL_METHODS)
+    }
+}
+
+/// tokens accepted by an authenticated API
+///
+/// They are read from a JSON file of the form
+/// `[{"token": "<secret>", "methods": ["stop_node", "node_sign_message"]}]`
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct AuthTokens(Vec<AuthToken>);
+
+impl AuthTokens {
+    /// load the tokens from a JSON file
+    pub fn from_file(path: &Path) -> std::io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// find the token matching the value of an `Authorization` header
+    pub fn authenticate(&self, authorization: &str) -> Option<&AuthToken> {
+        let secret = authorization.strip_prefix("Bearer ")?.trim();
+        // every token is compared so that the lookup time does not depend on which one matches
+        self.0
+            .iter()
+            .filter(|token| constant_time_eq(token.token.as_bytes(), secret.as_bytes()))
+            .last()
+    }
+}
+
+/// compare two secrets in a time that only depends on their length
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authenticate() {
+        let tokens: AuthTokens = serde_json::from_str(
+            r#"[
+                {"token": "admin", "methods": ["*"]},
+                {"token": "monitoring", "methods": ["get_staking_addresses"]}
+            ]"#,
+        )
+        .unwrap();
+
+        let admin = tokens.authenticate("Bearer admin").unwrap();
+        assert!(admin.allows_all());
+        assert!(admin.allows("stop_node"));
+
+        let monitoring = tokens.authenticate("Bearer monitoring").unwrap();
+        assert!(!monitoring.allows_all());
+        assert!(monitoring.allows("get_staking_addresses"));
+        assert!(!monitoring.allows("stop_node"));
+
+        assert!(tokens.authenticate("Bearer unknown").is_none());
+        assert!(tokens.authenticate("admin").is_none());
+        assert!(tokens.authenticate("Basic admin").is_none());
+    }
+}
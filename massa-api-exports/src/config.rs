@@ -1,5 +1,6 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use crate::rate_limit::RateLimitConfig;
 use massa_time::MassaTime;
 use std::net::SocketAddr;
 use std::path::PathBuf;
@@ -46,6 +47,16 @@ pub struct APIConfig {
     pub enable_http: bool,
     /// whether to enable WS.
     pub enable_ws: bool,
+    /// path to the PEM certificate chain served over TLS. TLS is disabled when not set
+    pub tls_cert_path: Option<PathBuf>,
+    /// path to the PEM private key of the TLS certificate
+    pub tls_key_path: Option<PathBuf>,
+    /// path to the PEM CA certificates used to verify client certificates. Client certificates are not required when not set
+    pub tls_client_ca_path: Option<PathBuf>,
+    /// path to the JSON file listing the tokens allowed to call the private API. Authentication is disabled when not set
+    pub auth_tokens_path: Option<PathBuf>,
+    /// rate limiting of the public API clients
+    pub rate_limit: RateLimitConfig,
     /// max datastore value length
     pub max_datastore_value_length: u64,
     /// max op datastore entry
@@ -2,7 +2,7 @@
 
 use displaydoc::Display;
 use jsonrpsee::core::Error as JsonRpseeError;
-use jsonrpsee::types::{error::CallError, ErrorObject};
+use jsonrpsee::types::{error::CallError, ErrorObject, ErrorObjectOwned};
 
 use massa_consensus_exports::error::ConsensusError;
 use massa_execution_exports::ExecutionError;
@@ -51,9 +51,11 @@ pub enum ApiError {
     BadRequest(String),
     /// Internal server error: {0}
     InternalServerError(String),
+    /// Rate limit exceeded: {0}
+    RateLimited(String),
 }
 
-impl From<ApiError> for JsonRpseeError {
+impl From<ApiError> for ErrorObjectOwned {
     fn from(err: ApiError) -> Self {
         // JSON-RPC Server errors codes must be between -32099 to -32000
         let code = match err {
@@ -74,8 +76,15 @@ impl From<ApiError> for JsonRpseeError {
             ApiError::MissingCommandSender(_) => -32017,
             ApiError::MissingConfig(_) => -32018,
             ApiError::WrongAPI => -32019,
+            ApiError::RateLimited(_) => -32020,
         };
 
-        CallError::Custom(ErrorObject::owned(code, err.to_string(), None::<()>)).into()
+        ErrorObject::owned(code, err.to_string(), None::<()>)
+    }
+}
+
+impl From<ApiError> for JsonRpseeError {
+    fn from(err: ApiError) -> Self {
+        CallError::Custom(err.into()).into()
     }
 }
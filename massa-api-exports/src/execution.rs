@@ -1,7 +1,11 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_execution_exports::{CallTrace, ExecutionTraceStep, OperationEstimation};
 use massa_final_state::StateChanges;
-use massa_models::{address::Address, output_event::SCOutputEvent, slot::Slot};
+use massa_models::{
+    address::Address, amount::Amount, operation::OperationType, output_event::SCOutputEvent,
+    slot::Slot,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::VecDeque, fmt::Display};
 
@@ -27,6 +31,9 @@ pub struct ExecuteReadOnlyResponse {
     pub gas_cost: u64,
     /// state changes caused by the execution step
     pub state_changes: StateChanges,
+    /// call trace of the execution, if requested and the execution succeeded
+    #[serde(default)]
+    pub trace: Option<CallTraceOutput>,
 }
 
 impl Display for ExecuteReadOnlyResponse {
@@ -48,6 +55,10 @@ impl Display for ExecuteReadOnlyResponse {
                 writeln!(f, "{}", event)?; // id already displayed in event
             }
         }
+        if let Some(trace) = &self.trace {
+            writeln!(f, "Trace:")?;
+            write!(f, "{}", trace)?;
+        }
         Ok(())
     }
 }
@@ -66,6 +77,9 @@ pub struct ReadOnlyBytecodeExecution {
     /// whether to start execution from final or active state. Default false
     #[serde(default)]
     pub is_final: bool,
+    /// whether to record the call trace of the execution. Default false
+    #[serde(default)]
+    pub with_trace: bool,
 }
 
 /// read SC call request
@@ -84,4 +98,268 @@ pub struct ReadOnlyCall {
     /// whether to start execution from final or active state. Default false
     #[serde(default)]
     pub is_final: bool,
+    /// state overrides applied for this call only. Default empty
+    #[serde(default)]
+    pub state_overrides: Vec<StateOverrideInput>,
+    /// whether to record the call trace of the execution. Default false
+    #[serde(default)]
+    pub with_trace: bool,
+}
+
+/// state override of an address for a read-only call
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct StateOverrideInput {
+    /// overridden address
+    pub address: Address,
+    /// balance to use, optional
+    pub balance: Option<Amount>,
+    /// bytecode to use, optional
+    pub bytecode: Option<Vec<u8>>,
+    /// datastore entries to set. Default empty
+    #[serde(default)]
+    pub datastore: Vec<DatastoreOverrideEntry>,
+    /// roll count to use, optional
+    pub roll_count: Option<u64>,
+}
+
+/// datastore entry set by a state override
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct DatastoreOverrideEntry {
+    /// datastore key
+    pub key: Vec<u8>,
+    /// datastore value
+    pub value: Vec<u8>,
+}
+
+/// trace of a smart contract call
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct CallTraceOutput {
+    /// called address
+    pub address: Address,
+    /// coins transferred to the called address
+    pub coins: Amount,
+    /// steps of the call, in execution order
+    pub steps: Vec<ExecutionTraceStepOutput>,
+    /// whether the call returned without error
+    pub finished: bool,
+}
+
+impl From<CallTrace> for CallTraceOutput {
+    fn from(trace: CallTrace) -> Self {
+        CallTraceOutput {
+            address: trace.address,
+            coins: trace.coins,
+            steps: trace.steps.into_iter().map(Into::into).collect(),
+            finished: trace.finished,
+        }
+    }
+}
+
+impl CallTraceOutput {
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let indent = "    ".repeat(depth);
+        writeln!(
+            f,
+            "{}Call to {} with {} coins{}",
+            indent,
+            self.address,
+            self.coins,
+            if self.finished { "" } else { " (not finished)" }
+        )?;
+        for step in self.steps.iter() {
+            match step {
+                ExecutionTraceStepOutput::Call(call) => call.fmt_indented(f, depth + 1)?,
+                ExecutionTraceStepOutput::TransferCoins { from, to, amount } => writeln!(
+                    f,
+                    "{}    Transfer of {} coins from {} to {}",
+                    indent, amount, from, to
+                )?,
+                ExecutionTraceStepOutput::SetData { address, key, .. } => writeln!(
+                    f,
+                    "{}    Datastore write of key {:?} for {}",
+                    indent, key, address
+                )?,
+                ExecutionTraceStepOutput::SendMessage {
+                    destination,
+                    handler,
+                    ..
+                } => writeln!(
+                    f,
+                    "{}    Message sent to {} for handler {}",
+                    indent, destination, handler
+                )?,
+                ExecutionTraceStepOutput::GasCheckpoint { remaining_gas } => {
+                    writeln!(f, "{}    Remaining gas: {}", indent, remaining_gas)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for CallTraceOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// step of a smart contract call trace
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum ExecutionTraceStepOutput {
+    /// nested call
+    Call(CallTraceOutput),
+    /// coin transfer
+    TransferCoins {
+        /// sending address
+        from: Address,
+        /// receiving address
+        to: Address,
+        /// transferred amount
+        amount: Amount,
+    },
+    /// datastore write
+    SetData {
+        /// address owning the datastore
+        address: Address,
+        /// datastore key
+        key: Vec<u8>,
+        /// written value, truncated to its first 256 bytes
+        value: Vec<u8>,
+        /// length of the full written value
+        value_length: u64,
+    },
+    /// asynchronous message emission
+    SendMessage {
+        /// destination address
+        destination: Address,
+        /// destination handler
+        handler: String,
+        /// maximum gas of the message execution
+        max_gas: u64,
+        /// fee paid for the message
+        fee: Amount,
+        /// coins sent with the message
+        coins: Amount,
+    },
+    /// remaining gas when the control came back from the VM
+    GasCheckpoint {
+        /// remaining gas
+        remaining_gas: u64,
+    },
+}
+
+impl From<ExecutionTraceStep> for ExecutionTraceStepOutput {
+    fn from(step: ExecutionTraceStep) -> Self {
+        match step {
+            ExecutionTraceStep::Call(call) => ExecutionTraceStepOutput::Call(call.into()),
+            ExecutionTraceStep::TransferCoins { from, to, amount } => {
+                ExecutionTraceStepOutput::TransferCoins { from, to, amount }
+            }
+            ExecutionTraceStep::SetData {
+                address,
+                key,
+                value,
+                value_length,
+            } => ExecutionTraceStepOutput::SetData {
+                address,
+                key,
+                value,
+                value_length,
+            },
+            ExecutionTraceStep::SendMessage {
+                destination,
+                handler,
+                max_gas,
+                fee,
+                coins,
+            } => ExecutionTraceStepOutput::SendMessage {
+                destination,
+                handler,
+                max_gas,
+                fee,
+                coins,
+            },
+            ExecutionTraceStep::GasCheckpoint { remaining_gas } => {
+                ExecutionTraceStepOutput::GasCheckpoint { remaining_gas }
+            }
+        }
+    }
+}
+
+/// operation estimation request
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct OperationEstimationInput {
+    /// address of the operation sender
+    pub sender_address: Address,
+    /// unsigned operation to simulate
+    pub op: OperationType,
+    /// whether to start execution from final or active state. Default false
+    #[serde(default)]
+    pub is_final: bool,
+}
+
+/// balance change of an address predicted by an operation estimation
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct BalanceChangeOutput {
+    /// address
+    pub address: Address,
+    /// balance before the operation
+    pub previous: Amount,
+    /// balance after the operation
+    pub predicted: Amount,
+}
+
+/// estimated costs and effects of an operation
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct OperationEstimationOutput {
+    /// the slot at which the operation was simulated
+    pub executed_at: Slot,
+    /// gas used by the operation
+    pub gas_used: u64,
+    /// net amount of coins spent on storage costs by the operation
+    pub storage_cost: Amount,
+    /// suggested fee for the operation
+    pub suggested_fee: Amount,
+    /// predicted balance changes
+    pub balance_changes: Vec<BalanceChangeOutput>,
+}
+
+impl From<OperationEstimation> for OperationEstimationOutput {
+    fn from(estimation: OperationEstimation) -> Self {
+        OperationEstimationOutput {
+            executed_at: estimation.executed_at,
+            gas_used: estimation.gas_used,
+            storage_cost: estimation.storage_cost,
+            suggested_fee: estimation.suggested_fee,
+            balance_changes: estimation
+                .balance_changes
+                .into_iter()
+                .map(|(address, change)| BalanceChangeOutput {
+                    address,
+                    previous: change.previous,
+                    predicted: change.predicted,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Display for OperationEstimationOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Simulated at slot: {}", self.executed_at)?;
+        writeln!(f, "Gas used: {}", self.gas_used)?;
+        writeln!(f, "Storage cost: {}", self.storage_cost)?;
+        writeln!(f, "Suggested fee: {}", self.suggested_fee)?;
+        if !self.balance_changes.is_empty() {
+            writeln!(f, "Balance changes:")?;
+            for change in self.balance_changes.iter() {
+                writeln!(
+                    f,
+                    "\t{}: {} -> {}",
+                    change.address, change.previous, change.predicted
+                )?;
+            }
+        }
+        Ok(())
+    }
 }
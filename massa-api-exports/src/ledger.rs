@@ -1,5 +1,8 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_final_state::FinalLedgerProof;
+use massa_ledger_exports::{Key as LedgerKey, KeyType};
+use massa_models::address::Address;
 use massa_models::amount::Amount;
 use massa_models::ledger::LedgerData;
 
@@ -28,3 +31,56 @@ impl std::fmt::Display for LedgerInfo {
         Ok(())
     }
 }
+
+/// Ledger sub-entry of an address
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub enum LedgerProofKey {
+    /// balance of the address
+    Balance,
+    /// bytecode of the address
+    Bytecode,
+    /// datastore entry of the address
+    Datastore(Vec<u8>),
+}
+
+/// Ledger proof query input structure
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LedgerProofInput {
+    /// address owning the ledger sub-entry
+    pub address: Address,
+    /// ledger sub-entry to prove
+    pub key: LedgerProofKey,
+}
+
+impl From<&LedgerProofInput> for LedgerKey {
+    fn from(input: &LedgerProofInput) -> Self {
+        let key_type = match &input.key {
+            LedgerProofKey::Balance => KeyType::BALANCE,
+            LedgerProofKey::Bytecode => KeyType::BYTECODE,
+            LedgerProofKey::Datastore(key) => KeyType::DATASTORE(key.clone()),
+        };
+        LedgerKey::new(&input.address, key_type)
+    }
+}
+
+/// Ledger proof query output structure
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LedgerProofOutput {
+    /// address owning the ledger sub-entry
+    pub address: Address,
+    /// proven ledger sub-entry
+    pub key: LedgerProofKey,
+    /// final value of the sub-entry and its proof against the final state hash
+    pub proof: FinalLedgerProof,
+}
+
+impl std::fmt::Display for LedgerProofOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Address: {}", self.address)?;
+        writeln!(f, "Key: {:?}", self.key)?;
+        writeln!(f, "Final slot: {}", self.proof.slot)?;
+        writeln!(f, "Final value: {:?}", self.proof.value)?;
+        writeln!(f, "Final state hash: {}", self.proof.final_state_hash)?;
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 
 /// address related structures
 pub mod address;
+/// access tokens of the authenticated APIs
+pub mod auth;
 /// block-related structures
 pub mod block;
 /// node configuration
@@ -33,6 +35,8 @@ pub mod node;
 pub mod operation;
 /// page
 pub mod page;
+/// rate limiting of the API clients
+pub mod rate_limit;
 /// rolls
 pub mod rolls;
 /// slots
@@ -1,8 +1,10 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_models::{
+    address::Address,
     block_id::BlockId,
     operation::{OperationId, SecureShareOperation},
+    slot::Slot,
 };
 
 use massa_signature::{PublicKey, Signature};
@@ -21,6 +23,17 @@ pub struct OperationInput {
     pub serialized_content: Vec<u8>,
 }
 
+/// Filter on the operations sent or received by an address
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AddressOperationsFilter {
+    /// sender, recipient or call target of the operations
+    pub address: Address,
+    /// optional start slot (included)
+    pub start: Option<Slot>,
+    /// optional end slot (excluded)
+    pub end: Option<Slot>,
+}
+
 /// Operation and contextual info about it
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OperationInfo {
@@ -0,0 +1,278 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use crate::error::ApiError;
+use parking_lot::Mutex;
+use schnellru::{ByLength, LruMap};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+/// rate limiting settings of an API
+#[derive(Debug, Deserialize, Clone)]
+pub struct RateLimitConfig {
+    /// whether to rate limit the clients
+    pub enabled: bool,
+    /// cost units given back to each client every second
+    pub refill_per_second: u64,
+    /// maximum cost units a client can accumulate, i.e. spend in a burst
+    pub burst: u64,
+    /// amount of read-only execution gas charged as one cost unit
+    pub gas_per_cost_unit: u64,
+    /// maximum number of clients tracked at once
+    pub max_clients: usize,
+    /// header to which a trusted reverse proxy appends the IP of the client, used to identify the clients.
+    /// Only its last entry is used, the previous ones are set by the client or other proxies
+    #[serde(default)]
+    pub client_ip_header: Option<String>,
+    /// cost of the methods by name, the other methods cost one unit per call
+    #[serde(default)]
+    pub method_costs: HashMap<String, u64>,
+}
+
+/// identify a client by its IP
+///
+/// IPv6 clients are identified by their /64 network, as each of them is usually given a whole one,
+/// IPv4-mapped IPv6 addresses being identified as the IPv4 address they map.
+pub fn ip_client_id(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => format!("ip:{}", ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => format!("ip:{}", ip),
+            None => {
+                let [a, b, c, d, ..] = ip.segments();
+                format!("ip:{}/64", Ipv6Addr::new(a, b, c, d, 0, 0, 0, 0))
+            }
+        },
+    }
+}
+
+/// identify a client by the IP forwarded by a trusted reverse proxy, kept as is if it is not a valid IP
+pub fn forwarded_ip_client_id(ip: &str) -> String {
+    let ip = ip.trim();
+    ip.parse()
+        .map(ip_client_id)
+        .unwrap_or_else(|_| format!("ip:{}", ip))
+}
+
+/// remaining cost units of a client
+struct Bucket {
+    /// negative when the client owes gas of a past read-only execution
+    budget: i64,
+    last_refill: Instant,
+}
+
+/// token bucket rate limiter charging each client for the cost of its calls
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    /// the client charged the longest time ago is forgotten first when `max_clients` are tracked
+    buckets: Mutex<LruMap<String, Bucket, ByLength>>,
+}
+
+impl RateLimiter {
+    /// create a rate limiter
+    pub fn new(config: RateLimitConfig) -> Self {
+        let max_clients = u32::try_from(config.max_clients).unwrap_or(u32::MAX).max(1);
+        RateLimiter {
+            config,
+            buckets: Mutex::new(LruMap::new(ByLength::new(max_clients))),
+        }
+    }
+
+    /// rate limiting settings
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// cost of a call to `method`
+    pub fn method_cost(&self, method: &str) -> u64 {
+        self.config.method_costs.get(method).copied().unwrap_or(1)
+    }
+
+    /// charge `cost` units to `client`, the call is rejected without being charged if the client can't afford it
+    pub fn charge(&self, client: &str, cost: u64) -> Result<(), ApiError> {
+        self.charge_at(client, cost, Instant::now())
+    }
+
+    fn charge_at(&self, client: &str, cost: u64, now: Instant) -> Result<(), ApiError> {
+        let mut buckets = self.buckets.lock();
+        // looking the client up makes it the most recently charged one
+        if buckets.get(client).is_none() {
+            buckets.insert(
+                client.to_string(),
+                Bucket {
+                    budget: self.config.burst as i64,
+                    last_refill: now,
+                },
+            );
+        }
+        let bucket = buckets
+            .peek_mut(client)
+            .expect("the client was just looked up or inserted");
+        self.refill(bucket, now);
+        if bucket.budget < cost as i64 {
+            return Err(ApiError::RateLimited(format!(
+                "call cost {} units but only {} are available, {} units are given back every second",
+                cost,
+                bucket.budget.max(0),
+                self.config.refill_per_second
+            )));
+        }
+        bucket.budget -= cost as i64;
+        Ok(())
+    }
+
+    /// charge the gas used by the read-only executions of `client`
+    ///
+    /// The budget can become negative, in which case the next calls are rejected until the debt is paid back.
+    pub fn charge_gas(&self, client: &str, gas: u64) {
+        self.charge_gas_at(client, gas, Instant::now())
+    }
+
+    fn charge_gas_at(&self, client: &str, gas: u64, now: Instant) {
+        let cost = gas / self.config.gas_per_cost_unit.max(1);
+        let mut buckets = self.buckets.lock();
+        if let Some(bucket) = buckets.peek_mut(client) {
+            self.refill(bucket, now);
+            bucket.budget = bucket.budget.saturating_sub(cost as i64);
+        }
+    }
+
+    /// give back the units earned by a client since its last refill
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let burst = self.config.burst as i64;
+        if self.config.refill_per_second == 0 || bucket.budget >= burst {
+            bucket.last_refill = now;
+            return;
+        }
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        let units = elapsed.as_millis() as u64 * self.config.refill_per_second / 1000;
+        if units == 0 {
+            return;
+        }
+        bucket.budget = bucket.budget.saturating_add(units as i64).min(burst);
+        // only consume the time actually converted into units
+        bucket.last_refill += Duration::from_millis(units * 1000 / self.config.refill_per_second);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RateLimitConfig {
+        RateLimitConfig {
+            enabled: true,
+            refill_per_second: 0,
+            burst: 10,
+            gas_per_cost_unit: 100,
+            max_clients: 2,
+            client_ip_header: None,
+            method_costs: HashMap::from([("execute_read_only_call".to_string(), 4)]),
+        }
+    }
+
+    #[test]
+    fn test_charge() {
+        let limiter = RateLimiter::new(config());
+        let cost = limiter.method_cost("execute_read_only_call");
+        assert_eq!(cost, 4);
+        assert_eq!(limiter.method_cost("get_status"), 1);
+
+        limiter.charge("a", cost).unwrap();
+        limiter.charge("a", cost).unwrap();
+        assert!(matches!(
+            limiter.charge("a", cost),
+            Err(ApiError::RateLimited(_))
+        ));
+        // the rejected call was not charged
+        limiter.charge("a", 2).unwrap();
+
+        // clients have their own budget
+        limiter.charge("b", cost).unwrap();
+    }
+
+    #[test]
+    fn test_eviction() {
+        let limiter = RateLimiter::new(config());
+        let start = Instant::now();
+        limiter.charge_at("a", 10, start).unwrap();
+        limiter
+            .charge_at("b", 10, start + Duration::from_secs(1))
+            .unwrap();
+
+        // only `max_clients` of them are tracked, the client charged the longest time ago is forgotten
+        limiter
+            .charge_at("c", 1, start + Duration::from_secs(2))
+            .unwrap();
+        limiter
+            .charge_at("a", 10, start + Duration::from_secs(3))
+            .unwrap();
+        // which forgot "b" in turn, while "c" is still tracked
+        assert!(limiter
+            .charge_at("c", 10, start + Duration::from_secs(4))
+            .is_err());
+    }
+
+    #[test]
+    fn test_ip_client_id() {
+        assert_eq!(ip_client_id("10.0.0.1".parse().unwrap()), "ip:10.0.0.1");
+        // the addresses of a /64 network are the same client
+        assert_eq!(
+            ip_client_id("2001:db8:1:2:3:4:5:6".parse().unwrap()),
+            "ip:2001:db8:1:2::/64"
+        );
+        assert_eq!(
+            ip_client_id("2001:db8:1:2::7".parse().unwrap()),
+            "ip:2001:db8:1:2::/64"
+        );
+        assert_eq!(
+            ip_client_id("::ffff:10.0.0.1".parse().unwrap()),
+            "ip:10.0.0.1"
+        );
+        assert_eq!(
+            forwarded_ip_client_id(" 2001:db8:1:2:3:4:5:6"),
+            "ip:2001:db8:1:2::/64"
+        );
+        assert_eq!(forwarded_ip_client_id(" unknown"), "ip:unknown");
+    }
+
+    #[test]
+    fn test_refill() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            refill_per_second: 4,
+            ..config()
+        });
+        let start = Instant::now();
+        limiter.charge_at("a", 10, start).unwrap();
+        assert!(limiter.charge_at("a", 1, start).is_err());
+
+        // one unit is given back every 250ms, the time of an incomplete unit is not lost
+        let after = |millis| start + Duration::from_millis(millis);
+        assert!(limiter.charge_at("a", 1, after(200)).is_err());
+        limiter.charge_at("a", 1, after(300)).unwrap();
+        assert!(limiter.charge_at("a", 1, after(400)).is_err());
+        limiter.charge_at("a", 1, after(500)).unwrap();
+
+        // the budget does not grow beyond `burst`
+        assert!(limiter.charge_at("a", 11, after(60_000)).is_err());
+        limiter.charge_at("a", 10, after(60_000)).unwrap();
+
+        // a debt of gas is paid back by the refill
+        limiter.charge_gas_at("a", 400, after(60_000));
+        assert!(limiter.charge_at("a", 1, after(61_000)).is_err());
+        limiter.charge_at("a", 1, after(61_250)).unwrap();
+    }
+
+    #[test]
+    fn test_charge_gas() {
+        let limiter = RateLimiter::new(config());
+        limiter.charge("a", 1).unwrap();
+        // the client now owes 1 unit
+        limiter.charge_gas("a", 1_000);
+        assert!(matches!(
+            limiter.charge("a", 1),
+            Err(ApiError::RateLimited(_))
+        ));
+    }
+}
@@ -14,9 +14,10 @@ use massa_api_exports::error::ApiError;
 use massa_api_exports::page::{PageRequest, PagedVec, PagedVecV2};
 use massa_api_exports::ApiRequest;
 use massa_consensus_exports::{ConsensusChannels, ConsensusController};
-use massa_execution_exports::ExecutionController;
+use massa_execution_exports::{ExecutionChannels, ExecutionController};
 use massa_models::address::Address;
 use massa_models::block_id::BlockId;
+use massa_models::execution::EventFilter;
 use massa_models::slot::Slot;
 use massa_models::timeslots::get_latest_block_slot_at_timestamp;
 use massa_models::version::Version;
@@ -31,6 +32,7 @@ impl API<ApiV2> {
         consensus_controller: Box<dyn ConsensusController>,
         consensus_channels: ConsensusChannels,
         execution_controller: Box<dyn ExecutionController>,
+        execution_channels: ExecutionChannels,
         pool_channels: PoolChannels,
         api_settings: APIConfig,
         version: Version,
@@ -39,6 +41,7 @@ impl API<ApiV2> {
             consensus_controller,
             consensus_channels,
             execution_controller,
+            execution_channels,
             pool_channels,
             api_settings,
             version,
@@ -53,7 +56,7 @@ impl ApiServer for API<ApiV2> {
         url: &SocketAddr,
         api_config: &APIConfig,
     ) -> Result<StopHandle, JsonRpseeError> {
-        crate::serve(self.into_rpc(), url, api_config).await
+        crate::serve(self.into_rpc(), url, api_config, None, true).await
     }
 }
 
@@ -149,6 +152,19 @@ impl MassaApiServer for API<ApiV2> {
     ) -> SubscriptionResult {
         broadcast_via_ws(self.0.pool_channels.operation_sender.clone(), pending).await
     }
+
+    async fn subscribe_new_sc_events(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: EventFilter,
+    ) -> SubscriptionResult {
+        broadcast_via_ws_filtered(
+            self.0.execution_channels.sc_event_sender.clone(),
+            pending,
+            move |event| filter.matches(event),
+        )
+        .await
+    }
 }
 
 // Brodcast the stream(sender) content via a WebSocket
@@ -156,6 +172,19 @@ async fn broadcast_via_ws<T: Serialize + Send + Clone + 'static>(
     sender: tokio::sync::broadcast::Sender<T>,
     pending: PendingSubscriptionSink,
 ) -> SubscriptionResult {
+    broadcast_via_ws_filtered(sender, pending, |_| true).await
+}
+
+// Brodcast the stream(sender) content matching the filter via a WebSocket
+async fn broadcast_via_ws_filtered<T, F>(
+    sender: tokio::sync::broadcast::Sender<T>,
+    pending: PendingSubscriptionSink,
+    filter: F,
+) -> SubscriptionResult
+where
+    T: Serialize + Send + Clone + 'static,
+    F: Fn(&T) -> bool + Send,
+{
     let sink = pending.accept().await?;
     let closed = sink.closed();
     let stream = BroadcastStream::new(sender.subscribe());
@@ -168,6 +197,12 @@ async fn broadcast_via_ws<T: Serialize + Send + Clone + 'static>(
 
             // received new item from the stream.
             Either::Right((Some(Ok(item)), c)) => {
+                // skip the items not matching the filter
+                if !filter(&item) {
+                    closed = c;
+                    continue;
+                }
+
                 let notif = SubscriptionMessage::from_json(&item)?;
 
                 if sink.send(notif).await.is_err() {
@@ -6,6 +6,7 @@ use massa_api_exports::page::PagedVecV2;
 use massa_api_exports::ApiRequest;
 use massa_models::address::Address;
 use massa_models::block_id::BlockId;
+use massa_models::execution::EventFilter;
 use massa_models::version::Version;
 
 /// Exposed API methods
@@ -57,4 +58,12 @@ pub trait MassaApi {
 		item = Operation
 	)]
     async fn subscribe_new_operations(&self) -> SubscriptionResult;
+
+    /// New smart contract events matching the filter.
+    #[subscription(
+		name = "subscribe_new_sc_events" => "new_sc_events",
+		unsubscribe = "unsubscribe_new_sc_events",
+		item = SCOutputEvent
+	)]
+    async fn subscribe_new_sc_events(&self, filter: EventFilter) -> SubscriptionResult;
 }
@@ -0,0 +1,277 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Token authentication of the JSON-RPC requests
+//!
+//! Clients send their token in the `Authorization: Bearer <token>` header.
+//! The methods called by HTTP requests (single or batched) are checked against the scopes of the token.
+//! WebSocket messages can't be inspected here, so upgrading to WebSocket requires a token allowing every method.
+
+use futures::future::BoxFuture;
+use hyper::body::HttpBody;
+use hyper::{header, Body, Method, Request, Response, StatusCode};
+use jsonrpsee::core::Error as JsonRpseeError;
+use massa_api_exports::auth::AuthTokens;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// load the tokens allowed to call an API, if authentication is enabled
+pub(crate) fn load_auth_tokens(
+    path: &Option<PathBuf>,
+) -> Result<Option<AuthTokens>, JsonRpseeError> {
+    path.as_ref()
+        .map(|path| {
+            AuthTokens::from_file(path).map_err(|e| {
+                JsonRpseeError::Custom(format!(
+                    "could not load the API tokens from {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// layer rejecting the requests that are not allowed by a token
+#[derive(Clone)]
+pub(crate) struct AuthLayer {
+    tokens: Arc<AuthTokens>,
+    max_request_body_size: u32,
+}
+
+impl AuthLayer {
+    pub(crate) fn new(tokens: AuthTokens, max_request_body_size: u32) -> Self {
+        AuthLayer {
+            tokens: Arc::new(tokens),
+            max_request_body_size,
+        }
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthService {
+            inner,
+            tokens: self.tokens.clone(),
+            max_request_body_size: self.max_request_body_size,
+        }
+    }
+}
+
+/// service checking the token of the requests before forwarding them to the inner service
+#[derive(Clone)]
+pub(crate) struct AuthService<S> {
+    inner: S,
+    tokens: Arc<AuthTokens>,
+    max_request_body_size: u32,
+}
+
+impl<S> Service<Request<Body>> for AuthService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // the service that was polled ready is the one handling the request
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let tokens = self.tokens.clone();
+        let max_request_body_size = self.max_request_body_size as usize;
+
+        Box::pin(async move {
+            let Some(token) = request
+                .headers()
+                .get(header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| tokens.authenticate(value))
+            else {
+                return Ok(reject(StatusCode::UNAUTHORIZED, "missing or invalid API token"));
+            };
+
+            if token.allows_all() {
+                return inner.call(request).await;
+            }
+            if request.method() != Method::POST {
+                return Ok(reject(
+                    StatusCode::FORBIDDEN,
+                    "this API token is restricted to HTTP POST requests",
+                ));
+            }
+
+            let (parts, body) = request.into_parts();
+            let Some(bytes) = read_body(body, max_request_body_size).await else {
+                return Ok(reject(StatusCode::PAYLOAD_TOO_LARGE, "request body too large"));
+            };
+            if let Some(method) = called_methods(&bytes)
+                .into_iter()
+                .find(|method| !token.allows(method))
+            {
+                return Ok(reject(
+                    StatusCode::FORBIDDEN,
+                    &format!("this API token is not allowed to call {}", method),
+                ));
+            }
+
+            inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        })
+    }
+}
+
+/// read a request body, or `None` if it is larger than `limit` or can't be read
+pub(crate) async fn read_body(mut body: Body, limit: usize) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.ok()?;
+        if bytes.len() + chunk.len() > limit {
+            return None;
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Some(bytes)
+}
+
+/// names of the methods called by a JSON-RPC request or batch of requests
+///
+/// A body that is not valid JSON-RPC calls no method and is left to the server to reject.
+pub(crate) fn called_methods(body: &[u8]) -> Vec<String> {
+    let method = |call: &Value| call.get("method").and_then(Value::as_str).map(String::from);
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Array(calls)) => calls.iter().filter_map(method).collect(),
+        Ok(call) => method(&call).into_iter().collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+pub(crate) fn reject(status: StatusCode, message: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(message.to_string()));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    /// calls an authenticated service answering with the body of the requests it receives
+    async fn call(request: Request<Body>) -> (StatusCode, String) {
+        let tokens: AuthTokens = serde_json::from_str(
+            r#"[
+                {"token": "admin", "methods": ["*"]},
+                {"token": "monitoring", "methods": ["get_staking_addresses", "node_peers_whitelist"]}
+            ]"#,
+        )
+        .unwrap();
+        let inner = tower::service_fn(|request: Request<Body>| async move {
+            let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+            Ok::<_, Infallible>(Response::new(Body::from(body)))
+        });
+        let response = AuthLayer::new(tokens, 1024)
+            .layer(inner)
+            .oneshot(request)
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn request(method: Method, token: Option<&str>, body: &str) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri("/");
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    fn rpc_call(method: &str) -> String {
+        format!(
+            r#"{{"jsonrpc": "2.0", "id": 1, "method": "{}", "params": []}}"#,
+            method
+        )
+    }
+
+    #[tokio::test]
+    async fn test_auth_tokens_are_required() {
+        let body = rpc_call("get_staking_addresses");
+        for token in [None, Some("unknown")] {
+            let (status, _) = call(request(Method::POST, token, &body)).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_method_scopes() {
+        // the body is forwarded untouched once checked
+        let body = rpc_call("get_staking_addresses");
+        let (status, forwarded) = call(request(Method::POST, Some("monitoring"), &body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(forwarded, body);
+
+        let (status, _) = call(request(
+            Method::POST,
+            Some("monitoring"),
+            &rpc_call("stop_node"),
+        ))
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(request(Method::POST, Some("admin"), &rpc_call("stop_node"))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_auth_batch_scopes() {
+        // a batch is only allowed if every call of it is
+        let allowed = format!(
+            "[{}, {}]",
+            rpc_call("get_staking_addresses"),
+            rpc_call("node_peers_whitelist")
+        );
+        let (status, _) = call(request(Method::POST, Some("monitoring"), &allowed)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let denied = format!(
+            "[{}, {}]",
+            rpc_call("get_staking_addresses"),
+            rpc_call("stop_node")
+        );
+        let (status, _) = call(request(Method::POST, Some("monitoring"), &denied)).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_auth_non_post_requests() {
+        // WebSocket upgrades and other non-POST requests can't be scoped
+        let (status, _) = call(request(Method::GET, Some("monitoring"), "")).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = call(request(Method::GET, Some("admin"), "")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_auth_body_size_limit() {
+        let body = format!(
+            r#"{{"jsonrpc": "2.0", "id": 1, "method": "get_staking_addresses", "params": ["{}"]}}"#,
+            "a".repeat(1024)
+        );
+        let (status, _) = call(request(Method::POST, Some("monitoring"), &body)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -4,6 +4,7 @@
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
 use api_trait::MassaApiServer;
+use auth::{load_auth_tokens, AuthLayer};
 use hyper::Method;
 use jsonrpsee::core::{Error as JsonRpseeError, RpcResult};
 use jsonrpsee::proc_macros::rpc;
@@ -11,19 +12,26 @@ use jsonrpsee::server::{AllowHosts, ServerBuilder, ServerHandle};
 use jsonrpsee::RpcModule;
 use massa_api_exports::{
     address::AddressInfo,
+    auth::AuthTokens,
     block::{BlockInfo, BlockSummary},
     config::APIConfig,
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
     endorsement::EndorsementInfo,
     error::ApiError::WrongAPI,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
+    execution::{
+        CallTraceOutput, ExecuteReadOnlyResponse, OperationEstimationInput,
+        OperationEstimationOutput, ReadOnlyBytecodeExecution, ReadOnlyCall,
+    },
+    ledger::{LedgerProofInput, LedgerProofOutput},
     node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    operation::{AddressOperationsFilter, OperationInfo, OperationInput},
     page::{PageRequest, PagedVec},
+    rate_limit::RateLimiter,
     TimeInterval,
 };
+use massa_archive::ArchiveDB;
 use massa_consensus_exports::{ConsensusChannels, ConsensusController};
-use massa_execution_exports::ExecutionController;
+use massa_execution_exports::{ExecutionChannels, ExecutionController};
 use massa_models::clique::Clique;
 use massa_models::composite::PubkeySig;
 use massa_models::node::NodeId;
@@ -41,17 +49,23 @@ use massa_protocol_exports::ProtocolCommandSender;
 use massa_storage::Storage;
 use massa_wallet::Wallet;
 use parking_lot::RwLock;
+use rate_limit::RateLimitLayer;
 use serde_json::Value;
-use std::net::{IpAddr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, warn};
 
 mod api;
 mod api_trait;
+mod auth;
 mod private;
+mod proxy;
 mod public;
+mod rate_limit;
+mod tls;
 
 /// Public API component
 pub struct Public {
@@ -77,6 +91,8 @@ pub struct Public {
     pub network_command_sender: NetworkCommandSender,
     /// our node id
     pub node_id: NodeId,
+    /// persistent archive of final blocks, if archive mode is enabled
+    pub archive: Option<Arc<ArchiveDB>>,
 }
 
 /// Private API content
@@ -89,8 +105,8 @@ pub struct Private {
     pub api_settings: APIConfig,
     /// stop channel
     pub stop_node_channel: mpsc::Sender<()>,
-    /// User wallet
-    pub node_wallet: Arc<RwLock<Wallet>>,
+    /// User wallet, `None` when the staking keys are held by an external signer
+    pub node_wallet: Option<Arc<RwLock<Wallet>>>,
 }
 
 /// API v2 content
@@ -101,6 +117,8 @@ pub struct ApiV2 {
     pub consensus_channels: ConsensusChannels,
     /// link to the execution component
     pub execution_controller: Box<dyn ExecutionController>,
+    /// link(channels) to the execution component
+    pub execution_channels: ExecutionChannels,
     /// link(channels) to the pool component
     pub pool_channels: PoolChannels,
     /// API settings
@@ -138,6 +156,8 @@ async fn serve<T>(
     api: RpcModule<T>,
     url: &SocketAddr,
     api_config: &APIConfig,
+    auth_tokens: Option<AuthTokens>,
+    rate_limited: bool,
 ) -> Result<StopHandle, JsonRpseeError> {
     let allowed_hosts = if api_config.allow_hosts.is_empty() {
         AllowHosts::Any
@@ -172,18 +192,65 @@ async fn serve<T>(
         .allow_methods([Method::POST, Method::OPTIONS])
         // Allow requests from any origin
         .allow_origin(Any)
-        .allow_headers([hyper::header::CONTENT_TYPE]);
-
-    let middleware = tower::ServiceBuilder::new().layer(cors);
+        .allow_headers([hyper::header::CONTENT_TYPE, hyper::header::AUTHORIZATION]);
+
+    let auth = auth_tokens.map(|tokens| AuthLayer::new(tokens, api_config.max_request_body_size));
+    let rate_limit = if rate_limited && api_config.rate_limit.enabled {
+        // known tokens identify their clients, whether or not the API requires them
+        Some(RateLimitLayer::new(
+            RateLimiter::new(api_config.rate_limit.clone()),
+            load_auth_tokens(&api_config.auth_tokens_path)?,
+            api_config.max_request_body_size,
+        ))
+    } else {
+        None
+    };
+    let proxy_rate_limit = rate_limit.clone();
+    let middleware = tower::ServiceBuilder::new()
+        .layer(cors)
+        .option_layer(auth)
+        .option_layer(rate_limit);
+
+    // with TLS or rate limiting, the server is only reached through the proxy bound to `url`
+    let tls_acceptor = tls::tls_acceptor(
+        api_config.tls_cert_path.as_deref(),
+        api_config.tls_key_path.as_deref(),
+        api_config.tls_client_ca_path.as_deref(),
+    )?;
+    let proxied = tls_acceptor.is_some() || proxy_rate_limit.is_some();
+    let server_url = if proxied {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)
+    } else {
+        *url
+    };
 
     let server = server_builder
         .set_middleware(middleware)
-        .build(url)
+        .build(server_url)
         .await
         .expect("failed to build server");
 
+    let proxy = if proxied {
+        let upstream = server.local_addr()?;
+        Some(
+            proxy::spawn_proxy(
+                tls_acceptor,
+                url,
+                upstream,
+                proxy_rate_limit,
+                api_config.max_connections,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
     let server_handler = server.start(api).expect("server start failed");
-    let stop_handler = StopHandle { server_handler };
+    let stop_handler = StopHandle {
+        server_handler,
+        proxy,
+    };
 
     Ok(stop_handler)
 }
@@ -191,11 +258,15 @@ async fn serve<T>(
 /// Used to be able to stop the API
 pub struct StopHandle {
     server_handler: ServerHandle,
+    proxy: Option<JoinHandle<()>>,
 }
 
 impl StopHandle {
     /// stop the API gracefully
     pub async fn stop(self) {
+        if let Some(proxy) = self.proxy {
+            proxy.abort();
+        }
         match self.server_handler.stop() {
             Ok(_) => {
                 info!("API stop signal sent successfully");
@@ -237,6 +308,14 @@ pub trait MassaRpc {
         arg: Vec<ReadOnlyCall>,
     ) -> RpcResult<Vec<ExecuteReadOnlyResponse>>;
 
+    /// Estimate the gas, storage costs, fee and balance changes of an unsigned operation
+    /// by simulating it in read-only mode.
+    #[method(name = "estimate_operation")]
+    async fn estimate_operation(
+        &self,
+        arg: OperationEstimationInput,
+    ) -> RpcResult<OperationEstimationOutput>;
+
     /// Remove a vector of addresses used to stake.
     /// No confirmation to expect.
     #[method(name = "remove_staking_addresses")]
@@ -330,6 +409,16 @@ pub trait MassaRpc {
     #[method(name = "get_operations")]
     async fn get_operations(&self, arg: Vec<OperationId>) -> RpcResult<Vec<OperationInfo>>;
 
+    /// Returns the operations sent or received by an address, sorted by slot of the including block.
+    /// Optional parameters: from `<start>` (included) and to `<end>` (excluded) slot.
+    /// Operations that are no longer in memory are only listed if archive mode is enabled.
+    #[method(name = "get_operations_by_address")]
+    async fn get_operations_by_address(
+        &self,
+        filter: AddressOperationsFilter,
+        page_request: Option<PageRequest>,
+    ) -> RpcResult<PagedVec<OperationInfo>>;
+
     /// Returns endorsement(s) information associated to a given list of endorsement(s) ID(s)
     #[method(name = "get_endorsements")]
     async fn get_endorsements(&self, arg: Vec<EndorsementId>) -> RpcResult<Vec<EndorsementInfo>>;
@@ -355,6 +444,13 @@ pub trait MassaRpc {
         arg: Vec<DatastoreEntryInput>,
     ) -> RpcResult<Vec<DatastoreEntryOutput>>;
 
+    /// Get the final values of multiple ledger sub-entries with their proofs against the final state hash.
+    #[method(name = "get_ledger_proofs")]
+    async fn get_ledger_proofs(
+        &self,
+        arg: Vec<LedgerProofInput>,
+    ) -> RpcResult<Vec<LedgerProofOutput>>;
+
     /// Get addresses.
     #[method(name = "get_addresses")]
     async fn get_addresses(&self, arg: Vec<Address>) -> RpcResult<Vec<AddressInfo>>;
@@ -373,6 +469,11 @@ pub trait MassaRpc {
     async fn get_filtered_sc_output_event(&self, arg: EventFilter)
         -> RpcResult<Vec<SCOutputEvent>>;
 
+    /// Get the call trace of a final smart contract operation.
+    /// Returns null if operation traces are disabled or the trace is no longer kept.
+    #[method(name = "get_operation_trace")]
+    async fn get_operation_trace(&self, arg: OperationId) -> RpcResult<Option<CallTraceOutput>>;
+
     /// Get OpenRPC specification.
     #[method(name = "rpc.discover")]
     async fn get_openrpc_spec(&self) -> RpcResult<Value>;
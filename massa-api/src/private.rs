@@ -1,5 +1,6 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use crate::auth::load_auth_tokens;
 use crate::{MassaRpcServer, Private, RpcServer, StopHandle, Value, API};
 
 use async_trait::async_trait;
@@ -12,9 +13,13 @@ use massa_api_exports::{
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
     endorsement::EndorsementInfo,
     error::ApiError,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
+    execution::{
+        CallTraceOutput, ExecuteReadOnlyResponse, OperationEstimationInput,
+        OperationEstimationOutput, ReadOnlyBytecodeExecution, ReadOnlyCall,
+    },
+    ledger::{LedgerProofInput, LedgerProofOutput},
     node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    operation::{AddressOperationsFilter, OperationInfo, OperationInput},
     page::{PageRequest, PagedVec},
     ListType, ScrudOperation, TimeInterval,
 };
@@ -30,7 +35,7 @@ use massa_models::{
 };
 use massa_network_exports::NetworkCommandSender;
 use massa_signature::KeyPair;
-use massa_wallet::Wallet;
+use massa_wallet::{Wallet, WalletError};
 
 use parking_lot::RwLock;
 use std::collections::BTreeSet;
@@ -47,7 +52,7 @@ impl API<Private> {
         network_command_sender: NetworkCommandSender,
         execution_controller: Box<dyn ExecutionController>,
         api_settings: APIConfig,
-        node_wallet: Arc<RwLock<Wallet>>,
+        node_wallet: Option<Arc<RwLock<Wallet>>>,
     ) -> (Self, mpsc::Receiver<()>) {
         let (stop_node_channel, rx) = mpsc::channel(1);
         (
@@ -70,7 +75,8 @@ impl RpcServer for API<Private> {
         url: &SocketAddr,
         settings: &APIConfig,
     ) -> Result<StopHandle, JsonRpseeError> {
-        crate::serve(self.into_rpc(), url, settings).await
+        let auth_tokens = load_auth_tokens(&settings.auth_tokens_path)?;
+        crate::serve(self.into_rpc(), url, settings, auth_tokens, false).await
     }
 }
 
@@ -99,7 +105,7 @@ impl MassaRpcServer for API<Private> {
             Err(e) => return Err(ApiError::BadRequest(e.to_string()).into()),
         };
 
-        let node_wallet = self.0.node_wallet.clone();
+        let node_wallet = get_node_wallet(&self.0)?;
         let mut w_wallet = node_wallet.write();
         w_wallet
             .add_keypairs(keypairs)
@@ -121,8 +127,15 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api::<_>()
     }
 
+    async fn estimate_operation(
+        &self,
+        _: OperationEstimationInput,
+    ) -> RpcResult<OperationEstimationOutput> {
+        crate::wrong_api::<OperationEstimationOutput>()
+    }
+
     async fn remove_staking_addresses(&self, addresses: Vec<Address>) -> RpcResult<()> {
-        let node_wallet = self.0.node_wallet.clone();
+        let node_wallet = get_node_wallet(&self.0)?;
         let mut w_wallet = node_wallet.write();
         w_wallet
             .remove_addresses(&addresses)
@@ -130,7 +143,7 @@ impl MassaRpcServer for API<Private> {
     }
 
     async fn get_staking_addresses(&self) -> RpcResult<PreHashSet<Address>> {
-        let node_wallet = self.0.node_wallet.clone();
+        let node_wallet = get_node_wallet(&self.0)?;
         let w_wallet = node_wallet.read();
         Ok(w_wallet.get_wallet_address_list())
     }
@@ -183,6 +196,14 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api::<Vec<OperationInfo>>()
     }
 
+    async fn get_operations_by_address(
+        &self,
+        _: AddressOperationsFilter,
+        _: Option<PageRequest>,
+    ) -> RpcResult<PagedVec<OperationInfo>> {
+        crate::wrong_api::<PagedVec<OperationInfo>>()
+    }
+
     async fn get_endorsements(&self, _: Vec<EndorsementId>) -> RpcResult<Vec<EndorsementInfo>> {
         crate::wrong_api::<Vec<EndorsementInfo>>()
     }
@@ -206,6 +227,13 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api()
     }
 
+    async fn get_ledger_proofs(
+        &self,
+        _: Vec<LedgerProofInput>,
+    ) -> RpcResult<Vec<LedgerProofOutput>> {
+        crate::wrong_api::<Vec<LedgerProofOutput>>()
+    }
+
     async fn get_addresses(&self, _: Vec<Address>) -> RpcResult<Vec<AddressInfo>> {
         crate::wrong_api::<Vec<AddressInfo>>()
     }
@@ -218,6 +246,10 @@ impl MassaRpcServer for API<Private> {
         crate::wrong_api::<Vec<SCOutputEvent>>()
     }
 
+    async fn get_operation_trace(&self, _: OperationId) -> RpcResult<Option<CallTraceOutput>> {
+        crate::wrong_api::<Option<CallTraceOutput>>()
+    }
+
     async fn node_peers_whitelist(&self) -> RpcResult<Vec<IpAddr>> {
         let network_command_sender = self.0.network_command_sender.clone();
         match network_command_sender.get_peers().await {
@@ -442,3 +474,10 @@ fn write_ips_to_jsonfile(
             })
         })
 }
+
+/// Gets the staking wallet of the node, that is not loaded when the staking keys are held by an external signer
+fn get_node_wallet(api: &Private) -> Result<Arc<RwLock<Wallet>>, ApiError> {
+    api.node_wallet
+        .clone()
+        .ok_or(ApiError::WalletError(WalletError::KeysHeldByRemoteSigner))
+}
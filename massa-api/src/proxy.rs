@@ -0,0 +1,406 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Proxy in front of the JSON-RPC servers
+//!
+//! The jsonrpsee server handles neither TLS nor the address of its clients. When TLS or rate limiting
+//! is enabled, the server listens on an ephemeral loopback port and a proxy bound to the configured
+//! address terminates TLS with hyper (if enabled), then forwards the requests to it. Every request
+//! carries the address of the peer that sent it in the `PEER_ADDR_HEADER` header, as the server only
+//! sees the proxy as its client. WebSocket upgrades are forwarded, then the messages of the upgraded
+//! connections are charged to their client while they are relayed if the server is rate limited.
+//!
+//! At most `max_connections` connections are served at once, each of them having to complete its TLS
+//! handshake and send its first request within `CONNECTION_SETUP_TIMEOUT`. The connection tasks are
+//! aborted along with the proxy.
+
+use crate::rate_limit::RateLimitLayer;
+use hyper::client::HttpConnector;
+use hyper::header::HeaderValue;
+use hyper::server::conn::Http;
+use hyper::service::service_fn;
+use hyper::{Body, Client, Request, Response, StatusCode, Uri};
+use jsonrpsee::core::Error as JsonRpseeError;
+use parking_lot::Mutex;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio::sync::{Notify, Semaphore};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::timeout;
+use tokio_rustls::TlsAcceptor;
+use tracing::debug;
+
+/// header carrying the address of the peer that sent a request forwarded by the proxy
+pub(crate) const PEER_ADDR_HEADER: &str = "x-massa-peer-addr";
+
+/// time given to a new connection to complete its TLS handshake, then to send its first request
+const CONNECTION_SETUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// accept the connections on `url`, over TLS if an acceptor is given, and forward their requests to the server listening on `upstream`.
+/// Aborting the returned task aborts the connections it serves.
+pub(crate) async fn spawn_proxy(
+    acceptor: Option<TlsAcceptor>,
+    url: &SocketAddr,
+    upstream: SocketAddr,
+    rate_limit: Option<RateLimitLayer>,
+    max_connections: u32,
+) -> Result<JoinHandle<()>, JsonRpseeError> {
+    let listener = TcpListener::bind(url)
+        .await
+        .map_err(|e| JsonRpseeError::Custom(format!("could not bind {}: {}", url, e)))?;
+    let proxy = Proxy {
+        client: Client::new(),
+        upstream,
+        rate_limit,
+    };
+
+    let connection_slots = Arc::new(Semaphore::new(max_connections as usize));
+
+    Ok(tokio::spawn(async move {
+        // dropping the set, when this task is aborted, aborts the connection tasks
+        let mut connections = JoinSet::new();
+        loop {
+            // reap the finished connections, then wait for a free slot before accepting a new one
+            let permit = tokio::select! {
+                biased;
+                Some(_) = connections.join_next() => continue,
+                permit = connection_slots.clone().acquire_owned() => {
+                    permit.expect("the connection semaphore is never closed")
+                }
+            };
+            let (stream, peer_addr) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    debug!("failed to accept API connection: {}", e);
+                    continue;
+                }
+            };
+            let acceptor = acceptor.clone();
+            let proxy = proxy.clone();
+            connections.spawn(async move {
+                // the slot is released when the connection ends
+                let _permit = permit;
+                match acceptor {
+                    Some(acceptor) => {
+                        match timeout(CONNECTION_SETUP_TIMEOUT, acceptor.accept(stream)).await {
+                            Ok(Ok(stream)) => proxy.serve_connection(stream, peer_addr).await,
+                            Ok(Err(e)) => debug!("TLS handshake with {} failed: {}", peer_addr, e),
+                            Err(_) => debug!("TLS handshake with {} timed out", peer_addr),
+                        }
+                    }
+                    None => proxy.serve_connection(stream, peer_addr).await,
+                }
+            });
+        }
+    }))
+}
+
+#[derive(Clone)]
+struct Proxy {
+    client: Client<HttpConnector>,
+    upstream: SocketAddr,
+    rate_limit: Option<RateLimitLayer>,
+}
+
+impl Proxy {
+    async fn serve_connection<S>(self, stream: S, peer_addr: SocketAddr)
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        // the relays of the upgraded connections are owned by the connection task, to be aborted with it
+        let relays = Arc::new(Mutex::new(JoinSet::new()));
+        let first_request = Arc::new(Notify::new());
+        let service = {
+            let relays = relays.clone();
+            let first_request = first_request.clone();
+            service_fn(move |request| {
+                first_request.notify_one();
+                self.clone().forward(request, peer_addr, relays.clone())
+            })
+        };
+        let connection = Http::new()
+            .http1_only(true)
+            .serve_connection(stream, service)
+            .with_upgrades();
+        tokio::pin!(connection);
+
+        let result = tokio::select! {
+            result = &mut connection => result,
+            received = timeout(CONNECTION_SETUP_TIMEOUT, first_request.notified()) => {
+                if received.is_err() {
+                    debug!("API connection with {} sent no request in time", peer_addr);
+                    return;
+                }
+                connection.await
+            }
+        };
+        if let Err(e) = result {
+            debug!("API connection with {} closed: {}", peer_addr, e);
+        }
+
+        let mut relays = std::mem::take(&mut *relays.lock());
+        while relays.join_next().await.is_some() {}
+    }
+
+    /// forward a request to the server, relaying the connection once it is upgraded to WebSocket
+    async fn forward(
+        self,
+        mut request: Request<Body>,
+        peer_addr: SocketAddr,
+        relays: Arc<Mutex<JoinSet<()>>>,
+    ) -> Result<Response<Body>, Infallible> {
+        let path = request
+            .uri()
+            .path_and_query()
+            .map_or("/", |path| path.as_str());
+        let Ok(uri) = Uri::try_from(format!("http://{}{}", self.upstream, path)) else {
+            return Ok(error_response(StatusCode::BAD_REQUEST));
+        };
+        let peer_upgrade = hyper::upgrade::on(&mut request);
+
+        let (parts, body) = request.into_parts();
+        let mut upstream_request = Request::new(body);
+        *upstream_request.method_mut() = parts.method;
+        *upstream_request.uri_mut() = uri;
+        *upstream_request.headers_mut() = parts.headers;
+        // overrides any value sent by the peer
+        upstream_request.headers_mut().insert(
+            PEER_ADDR_HEADER,
+            HeaderValue::from_str(&peer_addr.to_string())
+                .expect("socket addresses are valid header values"),
+        );
+        let client_id = self
+            .rate_limit
+            .as_ref()
+            .map(|rate_limit| rate_limit.client_id(upstream_request.headers()));
+
+        let mut response = match self.client.request(upstream_request).await {
+            Ok(response) => response,
+            Err(e) => {
+                debug!("could not forward API request of {}: {}", peer_addr, e);
+                return Ok(error_response(StatusCode::BAD_GATEWAY));
+            }
+        };
+        if response.status() == StatusCode::SWITCHING_PROTOCOLS {
+            let upstream_upgrade = hyper::upgrade::on(&mut response);
+            relays.lock().spawn(async move {
+                let (mut peer, mut upstream) =
+                    match tokio::try_join!(peer_upgrade, upstream_upgrade) {
+                        Ok(upgraded) => upgraded,
+                        Err(e) => {
+                            debug!("could not upgrade API connection of {}: {}", peer_addr, e);
+                            return;
+                        }
+                    };
+                let relayed = match (self.rate_limit, client_id) {
+                    (Some(rate_limit), Some(client_id)) => {
+                        rate_limit.relay_websocket(&client_id, peer, upstream).await
+                    }
+                    _ => tokio::io::copy_bidirectional(&mut peer, &mut upstream)
+                        .await
+                        .map(|_| ()),
+                };
+                if let Err(e) = relayed {
+                    debug!("API connection with {} closed: {}", peer_addr, e);
+                }
+            });
+        }
+        Ok(response)
+    }
+}
+
+fn error_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tls::tls_acceptor;
+    use hyper::header;
+    use hyper::Server;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+    use tokio_rustls::client::TlsStream;
+    use tokio_rustls::rustls::{Certificate, ClientConfig, PrivateKey, RootCertStore, ServerName};
+    use tokio_rustls::TlsConnector;
+
+    fn test_file(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/tests/tls")
+            .join(name)
+    }
+
+    fn read_certificates(name: &str) -> Vec<Certificate> {
+        let file = std::fs::File::open(test_file(name)).unwrap();
+        let certs = rustls_pemfile::certs(&mut std::io::BufReader::new(file)).unwrap();
+        certs.into_iter().map(Certificate).collect()
+    }
+
+    fn read_private_key(name: &str) -> PrivateKey {
+        let file = std::fs::File::open(test_file(name)).unwrap();
+        let mut keys =
+            rustls_pemfile::pkcs8_private_keys(&mut std::io::BufReader::new(file)).unwrap();
+        PrivateKey(keys.remove(0))
+    }
+
+    /// server answering with the peer address forwarded by the proxy, and echoing the upgraded connections
+    fn start_upstream() -> SocketAddr {
+        let make_service = hyper::service::make_service_fn(|_| async {
+            Ok::<_, Infallible>(service_fn(|mut request: Request<Body>| async move {
+                if !request.headers().contains_key(header::UPGRADE) {
+                    let peer_addr = request
+                        .headers()
+                        .get(PEER_ADDR_HEADER)
+                        .map(|value| value.to_str().unwrap().to_string())
+                        .unwrap_or_default();
+                    return Ok::<_, Infallible>(Response::new(Body::from(peer_addr)));
+                }
+                tokio::spawn(async move {
+                    let mut upgraded = hyper::upgrade::on(&mut request).await.unwrap();
+                    let mut buffer = [0u8; 4];
+                    upgraded.read_exact(&mut buffer).await.unwrap();
+                    upgraded.write_all(&buffer).await.unwrap();
+                });
+                let mut response = Response::new(Body::empty());
+                *response.status_mut() = StatusCode::SWITCHING_PROTOCOLS;
+                response
+                    .headers_mut()
+                    .insert(header::CONNECTION, HeaderValue::from_static("upgrade"));
+                response
+                    .headers_mut()
+                    .insert(header::UPGRADE, HeaderValue::from_static("websocket"));
+                Ok(response)
+            }))
+        });
+        let server = Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        addr
+    }
+
+    async fn start_proxy(client_ca: Option<PathBuf>) -> SocketAddr {
+        let acceptor = tls_acceptor(
+            Some(&test_file("server.crt")),
+            Some(&test_file("server.key")),
+            client_ca.as_deref(),
+        )
+        .unwrap()
+        .unwrap();
+        // the proxy binds the address itself, find a free one first
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        spawn_proxy(Some(acceptor), &addr, start_upstream(), None, 100)
+            .await
+            .unwrap();
+        addr
+    }
+
+    async fn connect(addr: SocketAddr, client_cert: bool) -> std::io::Result<TlsStream<TcpStream>> {
+        let mut roots = RootCertStore::empty();
+        for cert in read_certificates("ca.crt") {
+            roots.add(&cert).unwrap();
+        }
+        let builder = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        let config = if client_cert {
+            builder
+                .with_client_auth_cert(
+                    read_certificates("client.crt"),
+                    read_private_key("client.key"),
+                )
+                .unwrap()
+        } else {
+            builder.with_no_client_auth()
+        };
+        let stream = TcpStream::connect(addr).await?;
+        TlsConnector::from(Arc::new(config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+    }
+
+    async fn read_to_string(stream: &mut TlsStream<TcpStream>) -> std::io::Result<String> {
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+        Ok(response)
+    }
+
+    #[tokio::test]
+    async fn test_tls_proxy_forwards_peer_addr() {
+        let addr = start_proxy(None).await;
+
+        let mut stream = connect(addr, false).await.unwrap();
+        let local_addr = stream.get_ref().0.local_addr().unwrap();
+        // the header sent by the peer is replaced by its actual address
+        stream
+            .write_all(
+                format!(
+                    "GET / HTTP/1.1\r\nHost: localhost\r\n{}: 1.2.3.4:5\r\nConnection: close\r\n\r\n",
+                    PEER_ADDR_HEADER
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+        let response = read_to_string(&mut stream).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with(&format!("\r\n\r\n{}", local_addr)));
+    }
+
+    #[tokio::test]
+    async fn test_tls_proxy_upgrade() {
+        let addr = start_proxy(None).await;
+
+        let mut stream = connect(addr, false).await.unwrap();
+        stream
+            .write_all(
+                b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: upgrade\r\nUpgrade: websocket\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        let mut response = Vec::new();
+        while !response.ends_with(b"\r\n\r\n") {
+            response.push(stream.read_u8().await.unwrap());
+        }
+        assert!(response.starts_with(b"HTTP/1.1 101"));
+
+        // the upgraded connection is relayed both ways
+        stream.write_all(b"ping").await.unwrap();
+        let mut echo = [0u8; 4];
+        stream.read_exact(&mut echo).await.unwrap();
+        assert_eq!(&echo, b"ping");
+    }
+
+    #[tokio::test]
+    async fn test_tls_proxy_client_certificates() {
+        let addr = start_proxy(Some(test_file("ca.crt"))).await;
+        let request = b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n";
+
+        // the server rejects the handshake of a client without certificate
+        let refused = match connect(addr, false).await {
+            Ok(mut stream) => {
+                let _ = stream.write_all(request).await;
+                read_to_string(&mut stream)
+                    .await
+                    .map_or(true, |r| r.is_empty())
+            }
+            Err(_) => true,
+        };
+        assert!(refused);
+
+        let mut stream = connect(addr, true).await.unwrap();
+        stream.write_all(request).await.unwrap();
+        let response = read_to_string(&mut stream).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+    }
+}
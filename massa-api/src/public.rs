@@ -11,18 +11,26 @@ use massa_api_exports::{
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
     endorsement::EndorsementInfo,
     error::ApiError,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall, ReadOnlyResult},
+    execution::{
+        CallTraceOutput, ExecuteReadOnlyResponse, OperationEstimationInput,
+        OperationEstimationOutput, ReadOnlyBytecodeExecution, ReadOnlyCall, ReadOnlyResult,
+        StateOverrideInput,
+    },
+    ledger::{LedgerProofInput, LedgerProofOutput},
     node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    operation::{AddressOperationsFilter, OperationInfo, OperationInput},
     page::{PageRequest, PagedVec},
     slot::SlotAmount,
     TimeInterval,
 };
+use massa_archive::ArchiveDB;
 use massa_consensus_exports::block_status::DiscardReason;
 use massa_consensus_exports::ConsensusController;
 use massa_execution_exports::{
-    ExecutionController, ExecutionStackElement, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+    ExecutionController, ExecutionStackElement, OperationEstimationRequest,
+    ReadOnlyExecutionRequest, ReadOnlyExecutionTarget, StateOverride,
 };
+use massa_models::bytecode::Bytecode;
 use massa_models::operation::OperationDeserializer;
 use massa_models::secure_share::SecureShareDeserializer;
 use massa_models::{
@@ -61,6 +69,7 @@ use massa_storage::Storage;
 use massa_time::MassaTime;
 use std::collections::BTreeMap;
 use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
 
 impl API<Public> {
     /// generate a new public API
@@ -76,6 +85,7 @@ impl API<Public> {
         network_command_sender: NetworkCommandSender,
         node_id: NodeId,
         storage: Storage,
+        archive: Option<Arc<ArchiveDB>>,
     ) -> Self {
         API(Public {
             consensus_controller,
@@ -89,6 +99,7 @@ impl API<Public> {
             execution_controller,
             selector_controller,
             storage,
+            archive,
         })
     }
 }
@@ -100,7 +111,7 @@ impl RpcServer for API<Public> {
         url: &SocketAddr,
         api_config: &APIConfig,
     ) -> Result<StopHandle, JsonRpseeError> {
-        crate::serve(self.into_rpc(), url, api_config).await
+        crate::serve(self.into_rpc(), url, api_config, None, true).await
     }
 }
 
@@ -134,6 +145,7 @@ impl MassaRpcServer for API<Public> {
             bytecode,
             operation_datastore,
             is_final,
+            with_trace,
         } in reqs
         {
             let address = address.unwrap_or_else(|| {
@@ -178,6 +190,8 @@ impl MassaRpcServer for API<Public> {
                     operation_datastore: op_datastore,
                 }],
                 is_final,
+                state_overrides: Default::default(),
+                with_trace,
             };
 
             // run
@@ -196,6 +210,9 @@ impl MassaRpcServer for API<Public> {
                 output_events: result
                     .as_ref()
                     .map_or_else(|_| Default::default(), |v| v.out.events.clone().0),
+                trace: result
+                    .as_ref()
+                    .map_or_else(|_| None, |v| v.trace.clone().map(Into::into)),
                 state_changes: result.map_or_else(|_| Default::default(), |v| v.out.state_changes),
             };
 
@@ -214,6 +231,10 @@ impl MassaRpcServer for API<Public> {
             return Err(ApiError::BadRequest("too many arguments".into()).into());
         }
 
+        for req in reqs.iter() {
+            check_state_overrides(&self.0.api_settings, &req.state_overrides)?;
+        }
+
         let mut res: Vec<ExecuteReadOnlyResponse> = Vec::with_capacity(reqs.len());
         for ReadOnlyCall {
             max_gas,
@@ -222,6 +243,8 @@ impl MassaRpcServer for API<Public> {
             parameter,
             caller_address,
             is_final,
+            state_overrides,
+            with_trace,
         } in reqs
         {
             let caller_address = caller_address.unwrap_or_else(|| {
@@ -257,6 +280,25 @@ impl MassaRpcServer for API<Public> {
                     },
                 ],
                 is_final,
+                state_overrides: state_overrides
+                    .into_iter()
+                    .map(|state_override| {
+                        (
+                            state_override.address,
+                            StateOverride {
+                                balance: state_override.balance,
+                                bytecode: state_override.bytecode.map(Bytecode),
+                                datastore: state_override
+                                    .datastore
+                                    .into_iter()
+                                    .map(|entry| (entry.key, entry.value))
+                                    .collect(),
+                                roll_count: state_override.roll_count,
+                            },
+                        )
+                    })
+                    .collect(),
+                with_trace,
             };
 
             // run
@@ -275,6 +317,9 @@ impl MassaRpcServer for API<Public> {
                 output_events: result
                     .as_ref()
                     .map_or_else(|_| Default::default(), |v| v.out.events.clone().0),
+                trace: result
+                    .as_ref()
+                    .map_or_else(|_| None, |v| v.trace.clone().map(Into::into)),
                 state_changes: result.map_or_else(|_| Default::default(), |v| v.out.state_changes),
             };
 
@@ -285,6 +330,22 @@ impl MassaRpcServer for API<Public> {
         Ok(res)
     }
 
+    async fn estimate_operation(
+        &self,
+        input: OperationEstimationInput,
+    ) -> RpcResult<OperationEstimationOutput> {
+        let estimation = self
+            .0
+            .execution_controller
+            .estimate_operation(OperationEstimationRequest {
+                sender_address: input.sender_address,
+                op: input.op,
+                is_final: input.is_final,
+            })
+            .map_err(ApiError::from)?;
+        Ok(estimation.into())
+    }
+
     async fn remove_staking_addresses(&self, _: Vec<Address>) -> RpcResult<()> {
         crate::wrong_api::<()>()
     }
@@ -476,24 +537,42 @@ impl MassaRpcServer for API<Public> {
     }
 
     async fn get_operations(&self, ops: Vec<OperationId>) -> RpcResult<Vec<OperationInfo>> {
-        // get the operations and the list of blocks that contain them from storage
-        let storage_info: Vec<(SecureShareOperation, PreHashSet<BlockId>)> = {
+        // get the operations and the list of blocks that contain them from storage,
+        // falling back to the archive (if enabled) for final operations no longer in memory
+        let mut archived_ops: PreHashSet<OperationId> = PreHashSet::default();
+        let in_memory: Vec<(
+            OperationId,
+            Option<(SecureShareOperation, PreHashSet<BlockId>)>,
+        )> = {
             let read_blocks = self.0.storage.read_blocks();
             let read_ops = self.0.storage.read_operations();
             ops.iter()
-                .filter_map(|id| {
-                    read_ops.get(id).cloned().map(|op| {
+                .map(|id| {
+                    let info = read_ops.get(id).map(|op| {
                         (
-                            op,
+                            op.clone(),
                             read_blocks
                                 .get_blocks_by_operation(id)
                                 .cloned()
                                 .unwrap_or_default(),
                         )
-                    })
+                    });
+                    (*id, info)
                 })
                 .collect()
         };
+        // the storage locks are released before reading the archive
+        let storage_info: Vec<(SecureShareOperation, PreHashSet<BlockId>)> = in_memory
+            .into_iter()
+            .filter_map(|(id, info)| {
+                if info.is_some() {
+                    return info;
+                }
+                let (op, block_id) = self.0.archive.as_ref()?.get_operation(&id)?;
+                archived_ops.insert(op.id);
+                Some((op, std::iter::once(block_id).collect()))
+            })
+            .collect();
 
         // keep only the ops id (found in storage)
         let ops: Vec<OperationId> = storage_info.iter().map(|(op, _)| op.id).collect();
@@ -520,6 +599,8 @@ impl MassaRpcServer for API<Public> {
                     // op status found in the final hashmap, so the op is "final"(first value of the tuple: Some(true))
                     // and we keep its status (copied as the second value of the tuple)
                     (Some(val), _) => (Some(true), Some(*val)),
+                    // op found in the archive, so the op is "final" but its status is no longer known
+                    (None, _) if archived_ops.contains(op) => (Some(true), None),
                     // op status NOT found in the final hashmap but in the speculative one, so the op is "not final"(first value of the tuple: Some(false))
                     // and we keep its status (copied as the second value of the tuple)
                     (None, Some(val)) => (Some(false), Some(*val)),
@@ -560,25 +641,79 @@ impl MassaRpcServer for API<Public> {
         Ok(res)
     }
 
+    async fn get_operations_by_address(
+        &self,
+        filter: AddressOperationsFilter,
+        page_request: Option<PageRequest>,
+    ) -> RpcResult<PagedVec<OperationInfo>> {
+        // a single call returns at most as many operations as a `get_operations` call allows, which is also the default
+        let max_limit = self.0.api_settings.max_arguments as usize;
+        let (limit, offset) = match page_request {
+            Some(PageRequest { limit, offset }) => (limit.min(max_limit), offset),
+            None => (max_limit, 0),
+        };
+        // the offset is a page index
+        let skip = offset.saturating_mul(limit);
+
+        // the archive is read lazily up to the requested page, off the async workers
+        let storage = self.0.storage.clone_without_refs();
+        let archive = self.0.archive.clone();
+        let op_ids = tokio::task::spawn_blocking(move || {
+            massa_archive::get_operations_by_address(
+                &storage,
+                archive.as_deref(),
+                &filter.address,
+                filter.start,
+                filter.end,
+            )
+            .skip(skip)
+            .take(limit)
+            .map(|(_, op_id)| op_id)
+            .collect()
+        })
+        .await
+        .map_err(|err| ApiError::InternalServerError(err.to_string()))?;
+        let operations = self.get_operations(op_ids).await?;
+        Ok(PagedVec::new(operations, None))
+    }
+
     async fn get_endorsements(&self, eds: Vec<EndorsementId>) -> RpcResult<Vec<EndorsementInfo>> {
-        // get the endorsements and the list of blocks that contain them from storage
-        let storage_info: Vec<(SecureShareEndorsement, PreHashSet<BlockId>)> = {
+        // get the endorsements and the list of blocks that contain them from storage,
+        // falling back to the archive (if enabled) for final endorsements no longer in memory
+        let mut archived_eds: PreHashSet<EndorsementId> = PreHashSet::default();
+        let in_memory: Vec<(
+            EndorsementId,
+            Option<(SecureShareEndorsement, PreHashSet<BlockId>)>,
+        )> = {
             let read_blocks = self.0.storage.read_blocks();
             let read_endos = self.0.storage.read_endorsements();
             eds.iter()
-                .filter_map(|id| {
-                    read_endos.get(id).cloned().map(|ed| {
+                .map(|id| {
+                    let info = read_endos.get(id).map(|ed| {
                         (
-                            ed,
+                            ed.clone(),
                             read_blocks
                                 .get_blocks_by_endorsement(id)
                                 .cloned()
                                 .unwrap_or_default(),
                         )
-                    })
+                    });
+                    (*id, info)
                 })
                 .collect()
         };
+        // the storage locks are released before reading the archive
+        let storage_info: Vec<(SecureShareEndorsement, PreHashSet<BlockId>)> = in_memory
+            .into_iter()
+            .filter_map(|(id, info)| {
+                if info.is_some() {
+                    return info;
+                }
+                let (ed, block_id) = self.0.archive.as_ref()?.get_endorsement(&id)?;
+                archived_eds.insert(ed.id);
+                Some((ed, std::iter::once(block_id).collect()))
+            })
+            .collect();
 
         // keep only the ops found in storage
         let eds: Vec<EndorsementId> = storage_info.iter().map(|(ed, _)| ed.id).collect();
@@ -610,9 +745,11 @@ impl MassaRpcServer for API<Public> {
                 .collect();
             storage_info
                 .iter()
-                .map(|(_ed, bs)| {
-                    bs.iter()
-                        .any(|b| block_statuses.get(b) == Some(&BlockGraphStatus::Final))
+                .map(|(ed, bs)| {
+                    archived_eds.contains(&ed.id)
+                        || bs
+                            .iter()
+                            .any(|b| block_statuses.get(b) == Some(&BlockGraphStatus::Final))
                 })
                 .collect()
         };
@@ -640,17 +777,31 @@ impl MassaRpcServer for API<Public> {
     }
 
     /// gets a block(s). Returns nothing if not found
-    /// only active blocks are returned
+    /// only active blocks are returned, unless archive mode is enabled
+    /// in which case final blocks are also looked up in the archive
     async fn get_blocks(&self, ids: Vec<BlockId>) -> RpcResult<Vec<BlockInfo>> {
         let consensus_controller = self.0.consensus_controller.clone();
         let storage = self.0.storage.clone_without_refs();
+        let archive = self.0.archive.clone();
         let blocks = ids
             .into_iter()
             .filter_map(|id| {
-                let content = if let Some(wrapped_block) = storage.read_blocks().get(&id) {
-                    wrapped_block.content.clone()
-                } else {
-                    return None;
+                // the storage lock is released before reading the archive
+                let in_memory = storage.read_blocks().get(&id).map(|b| b.content.clone());
+                let Some(content) = in_memory else {
+                    // the block may have been pruned from memory after becoming final,
+                    // final blocks are part of the blockclique
+                    let archived_block = archive.as_ref()?.get_block(&id)?;
+                    return Some(BlockInfo {
+                        id,
+                        content: Some(BlockInfoContent {
+                            is_final: true,
+                            is_in_blockclique: true,
+                            is_candidate: false,
+                            is_discarded: false,
+                            block: archived_block.content,
+                        }),
+                    });
                 };
 
                 if let Some(graph_status) = consensus_controller
@@ -659,7 +810,9 @@ impl MassaRpcServer for API<Public> {
                     .next()
                 {
                     let is_final = graph_status == BlockGraphStatus::Final;
-                    let is_in_blockclique = graph_status == BlockGraphStatus::ActiveInBlockclique;
+                    // final blocks are part of the blockclique
+                    let is_in_blockclique =
+                        graph_status == BlockGraphStatus::ActiveInBlockclique || is_final;
                     let is_candidate = graph_status == BlockGraphStatus::ActiveInBlockclique
                         || graph_status == BlockGraphStatus::ActiveInAlternativeCliques;
                     let is_discarded = graph_status == BlockGraphStatus::Discarded;
@@ -689,15 +842,19 @@ impl MassaRpcServer for API<Public> {
 
         let block_id_option = consensus_controller.get_blockclique_block_at_slot(slot);
 
-        let block_id = match block_id_option {
-            Some(id) => id,
-            None => return Ok(None),
-        };
-
-        let res = storage
-            .read_blocks()
-            .get(&block_id)
-            .map(|b| b.content.clone());
+        let res = block_id_option.and_then(|block_id| {
+            storage
+                .read_blocks()
+                .get(&block_id)
+                .map(|b| b.content.clone())
+        });
+
+        // fall back to the archive (if enabled) for final blocks no longer in memory
+        if res.is_none() {
+            if let Some(archive) = &self.0.archive {
+                return Ok(archive.get_block_by_slot(&slot).map(|b| b.content));
+            }
+        }
         Ok(res)
     }
 
@@ -779,6 +936,28 @@ impl MassaRpcServer for API<Public> {
             .collect())
     }
 
+    async fn get_ledger_proofs(
+        &self,
+        inputs: Vec<LedgerProofInput>,
+    ) -> RpcResult<Vec<LedgerProofOutput>> {
+        if inputs.len() as u64 > self.0.api_settings.max_arguments {
+            return Err(ApiError::BadRequest("too many arguments".into()).into());
+        }
+        let proofs = self
+            .0
+            .execution_controller
+            .get_final_ledger_proofs(inputs.iter().map(Into::into).collect());
+        Ok(inputs
+            .into_iter()
+            .zip(proofs)
+            .map(|(input, proof)| LedgerProofOutput {
+                address: input.address,
+                key: input.key,
+                proof,
+            })
+            .collect())
+    }
+
     async fn get_addresses(&self, addresses: Vec<Address>) -> RpcResult<Vec<AddressInfo>> {
         // get info from storage about which blocks the addresses have created
         let created_blocks: Vec<PreHashSet<BlockId>> = {
@@ -952,7 +1131,10 @@ impl MassaRpcServer for API<Public> {
             })
             .map(|op| match op {
                 Ok(operation) => {
-                    let _verify_signature = match operation.verify_signature() {
+                    let _verify_signature = match operation
+                        .verify_signature()
+                        .and_then(|_| operation.verify_multisig())
+                    {
                         Ok(()) => (),
                         Err(e) => return Err(ApiError::ModelsError(e).into()),
                     };
@@ -993,6 +1175,17 @@ impl MassaRpcServer for API<Public> {
         Ok(events)
     }
 
+    async fn get_operation_trace(
+        &self,
+        operation_id: OperationId,
+    ) -> RpcResult<Option<CallTraceOutput>> {
+        Ok(self
+            .0
+            .execution_controller
+            .get_operation_trace(&operation_id)
+            .map(Into::into))
+    }
+
     async fn node_peers_whitelist(&self) -> RpcResult<Vec<IpAddr>> {
         crate::wrong_api::<Vec<IpAddr>>()
     }
@@ -1056,3 +1249,45 @@ impl MassaRpcServer for API<Public> {
         openrpc
     }
 }
+
+/// Checks that the state overrides of a read-only call fit in the limits of the API,
+/// so that a single request cannot make the node hold or hash arbitrarily large states
+fn check_state_overrides(
+    settings: &APIConfig,
+    state_overrides: &[StateOverrideInput],
+) -> Result<(), ApiError> {
+    if state_overrides.len() as u64 > settings.max_arguments {
+        return Err(ApiError::BadRequest("too many state overrides".into()));
+    }
+    for state_override in state_overrides {
+        if let Some(bytecode) = &state_override.bytecode {
+            if bytecode.len() as u64 > settings.max_datastore_value_length {
+                return Err(ApiError::BadRequest(format!(
+                    "overridden bytecode of {} is too large",
+                    state_override.address
+                )));
+            }
+        }
+        if state_override.datastore.len() as u64 > settings.max_op_datastore_entry_count {
+            return Err(ApiError::BadRequest(format!(
+                "too many overridden datastore entries for {}",
+                state_override.address
+            )));
+        }
+        for entry in &state_override.datastore {
+            if entry.key.len() > settings.max_op_datastore_key_length as usize {
+                return Err(ApiError::BadRequest(format!(
+                    "overridden datastore key of {} is too long",
+                    state_override.address
+                )));
+            }
+            if entry.value.len() as u64 > settings.max_datastore_value_length {
+                return Err(ApiError::BadRequest(format!(
+                    "overridden datastore value of {} is too large",
+                    state_override.address
+                )));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,614 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Rate limiting of the JSON-RPC clients
+//!
+//! Clients are identified by their API token if it is a known one, then by the last IP appended by a trusted reverse proxy,
+//! then by the IP of their connection, forwarded by the proxy in front of rate limited servers (see the `proxy` module).
+//! Each HTTP request is charged the cost of the methods it calls, then the gas used by its read-only executions.
+//! WebSocket connections are charged when they are opened, then each of their messages is charged in the same way
+//! by the proxy relaying them.
+
+use crate::auth::{called_methods, read_body, reject};
+use crate::proxy::PEER_ADDR_HEADER;
+use futures::future::BoxFuture;
+use hyper::{header, Body, HeaderMap, Method, Request, Response, StatusCode};
+use jsonrpsee::types::ErrorObjectOwned;
+use massa_api_exports::auth::AuthTokens;
+use massa_api_exports::error::ApiError;
+use massa_api_exports::rate_limit::{forwarded_ip_client_id, ip_client_id, RateLimiter};
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::io::{Error as IoError, ErrorKind};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tower::{Layer, Service};
+
+/// methods whose responses report the gas used by read-only executions
+const GAS_METERED_METHODS: [&str; 3] = [
+    "execute_read_only_bytecode",
+    "execute_read_only_call",
+    "estimate_operation",
+];
+
+/// layer charging the requests to the budget of their client
+#[derive(Clone)]
+pub(crate) struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+    tokens: Arc<AuthTokens>,
+    max_request_body_size: u32,
+}
+
+impl RateLimitLayer {
+    pub(crate) fn new(
+        limiter: RateLimiter,
+        tokens: Option<AuthTokens>,
+        max_request_body_size: u32,
+    ) -> Self {
+        RateLimitLayer {
+            limiter: Arc::new(limiter),
+            tokens: Arc::new(tokens.unwrap_or_default()),
+            max_request_body_size,
+        }
+    }
+}
+
+impl RateLimitLayer {
+    /// identify the client sending a request
+    pub(crate) fn client_id(&self, headers: &HeaderMap) -> String {
+        client_id(
+            headers,
+            &self.tokens,
+            self.limiter.config().client_ip_header.as_deref(),
+        )
+    }
+
+    /// relay an upgraded WebSocket connection between a client and the server,
+    /// charging each message of the client as an HTTP request with the same body would be
+    ///
+    /// The connection is closed once the client can't afford a message.
+    pub(crate) async fn relay_websocket<P, U>(
+        &self,
+        client: &str,
+        peer: P,
+        upstream: U,
+    ) -> std::io::Result<()>
+    where
+        P: AsyncRead + AsyncWrite + Unpin,
+        U: AsyncRead + AsyncWrite + Unpin,
+    {
+        let (mut peer_reader, mut peer_writer) = tokio::io::split(peer);
+        let (mut upstream_reader, mut upstream_writer) = tokio::io::split(upstream);
+        let max_message_size = self.max_request_body_size as usize;
+        // ids of the read-only executions waiting for their response to charge their gas
+        let gas_metered_calls = Mutex::new(HashSet::new());
+
+        let requests = async {
+            // raw frames and payload of the message being received
+            let mut frames = Vec::new();
+            let mut message = Vec::new();
+            while let Some(frame) = read_frame(&mut peer_reader, max_message_size).await? {
+                // control frames can be sent between the frames of a message
+                if frame.is_control() {
+                    upstream_writer.write_all(&frame.raw).await?;
+                    continue;
+                }
+                message.extend(frame.payload());
+                if message.len() > max_message_size {
+                    return Err(IoError::new(ErrorKind::InvalidData, "message too large"));
+                }
+                frames.extend_from_slice(&frame.raw);
+                if !frame.fin {
+                    continue;
+                }
+
+                let calls = json_calls(&message);
+                let cost = calls
+                    .iter()
+                    .filter_map(|call| call.get("method").and_then(Value::as_str))
+                    .map(|method| self.limiter.method_cost(method))
+                    .sum::<u64>()
+                    .max(1);
+                self.limiter
+                    .charge(client, cost)
+                    .map_err(|e| IoError::new(ErrorKind::Other, e.to_string()))?;
+                gas_metered_calls.lock().extend(
+                    calls
+                        .iter()
+                        .filter(|call| {
+                            call.get("method")
+                                .and_then(Value::as_str)
+                                .map_or(false, |method| GAS_METERED_METHODS.contains(&method))
+                        })
+                        .filter_map(|call| call.get("id").map(Value::to_string)),
+                );
+                upstream_writer.write_all(&frames).await?;
+                frames.clear();
+                message.clear();
+            }
+            upstream_writer.shutdown().await
+        };
+
+        let responses = async {
+            let mut message = Vec::new();
+            // the server is trusted, the size of its messages is bounded by its own configuration
+            while let Some(frame) = read_frame(&mut upstream_reader, usize::MAX).await? {
+                peer_writer.write_all(&frame.raw).await?;
+                if frame.is_control() {
+                    continue;
+                }
+                // the messages are only decoded when they may report the gas of a read-only execution
+                if !gas_metered_calls.lock().is_empty() {
+                    message.extend(frame.payload());
+                }
+                if !frame.fin || message.is_empty() {
+                    continue;
+                }
+
+                let gas = {
+                    let mut pending_calls = gas_metered_calls.lock();
+                    json_calls(&message)
+                        .iter()
+                        .filter(|response| {
+                            response
+                                .get("id")
+                                .map_or(false, |id| pending_calls.remove(&id.to_string()))
+                        })
+                        .map(response_gas)
+                        .sum()
+                };
+                self.limiter.charge_gas(client, gas);
+                message.clear();
+            }
+            peer_writer.shutdown().await
+        };
+
+        tokio::try_join!(requests, responses).map(|_| ())
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+            tokens: self.tokens.clone(),
+            max_request_body_size: self.max_request_body_size,
+        }
+    }
+}
+
+/// service rejecting the requests of the clients that exceeded their budget
+#[derive(Clone)]
+pub(crate) struct RateLimitService<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+    tokens: Arc<AuthTokens>,
+    max_request_body_size: u32,
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // the service that was polled ready is the one handling the request
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let limiter = self.limiter.clone();
+        let client = client_id(
+            request.headers(),
+            &self.tokens,
+            limiter.config().client_ip_header.as_deref(),
+        );
+        let max_request_body_size = self.max_request_body_size as usize;
+
+        Box::pin(async move {
+            // only the connection is charged here, its WebSocket messages are charged by the proxy relaying them
+            if request.method() != Method::POST {
+                if let Err(e) = limiter.charge(&client, 1) {
+                    return Ok(rate_limited(e));
+                }
+                return inner.call(request).await;
+            }
+
+            let (parts, body) = request.into_parts();
+            let Some(bytes) = read_body(body, max_request_body_size).await else {
+                return Ok(reject(StatusCode::PAYLOAD_TOO_LARGE, "request body too large"));
+            };
+            let methods = called_methods(&bytes);
+            let cost = methods
+                .iter()
+                .map(|method| limiter.method_cost(method))
+                .sum::<u64>()
+                .max(1);
+            if let Err(e) = limiter.charge(&client, cost) {
+                return Ok(rate_limited(e));
+            }
+
+            let response = inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await?;
+            if !methods
+                .iter()
+                .any(|method| GAS_METERED_METHODS.contains(&method.as_str()))
+            {
+                return Ok(response);
+            }
+
+            let (parts, body) = response.into_parts();
+            let bytes = hyper::body::to_bytes(body).await.unwrap_or_default();
+            limiter.charge_gas(&client, used_gas(&bytes));
+            Ok(Response::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
+
+/// identify the client sending a request
+fn client_id(headers: &HeaderMap, tokens: &AuthTokens, client_ip_header: Option<&str>) -> String {
+    if let Some(token) = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| tokens.authenticate(value))
+    {
+        return format!("token:{}", token.token);
+    }
+    // proxies append the IP they forward for to the list, so only the last entry was set by the trusted proxy
+    if let Some(ip) = client_ip_header
+        .and_then(|name| headers.get_all(name).iter().last())
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+    {
+        return forwarded_ip_client_id(ip);
+    }
+    headers
+        .get(PEER_ADDR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<SocketAddr>().ok())
+        .map(|addr| ip_client_id(addr.ip()))
+        .unwrap_or_else(|| "anonymous".to_string())
+}
+
+/// calls of a JSON-RPC request, or responses of a JSON-RPC response, single or batched
+fn json_calls(body: &[u8]) -> Vec<Value> {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Array(calls)) => calls,
+        Ok(call) => vec![call],
+        Err(_) => Vec::new(),
+    }
+}
+
+/// gas used by the read-only executions reported in a JSON-RPC response or batch of responses
+fn used_gas(body: &[u8]) -> u64 {
+    json_calls(body).iter().map(response_gas).sum()
+}
+
+/// gas used by the read-only executions reported in a single JSON-RPC response
+fn response_gas(response: &Value) -> u64 {
+    match response.get("result") {
+        // read-only executions
+        Some(Value::Array(outputs)) => outputs
+            .iter()
+            .filter_map(|output| output.get("gas_cost").and_then(Value::as_u64))
+            .sum(),
+        // operation estimation
+        Some(output) => output.get("gas_used").and_then(Value::as_u64).unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// WebSocket frame, see RFC 6455
+struct Frame {
+    /// bytes of the frame as received, relayed as is
+    raw: Vec<u8>,
+    fin: bool,
+    opcode: u8,
+    mask: Option<[u8; 4]>,
+    /// offset of the payload in `raw`
+    payload_start: usize,
+}
+
+impl Frame {
+    /// close, ping and pong frames
+    fn is_control(&self) -> bool {
+        self.opcode & 0x8 != 0
+    }
+
+    /// unmasked payload of the frame
+    fn payload(&self) -> Vec<u8> {
+        let payload = &self.raw[self.payload_start..];
+        match self.mask {
+            Some(mask) => payload
+                .iter()
+                .enumerate()
+                .map(|(i, byte)| byte ^ mask[i % 4])
+                .collect(),
+            None => payload.to_vec(),
+        }
+    }
+}
+
+/// read a WebSocket frame whose payload is at most `max_payload` bytes, `None` at the end of the stream
+async fn read_frame<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_payload: usize,
+) -> std::io::Result<Option<Frame>> {
+    let mut raw = vec![0u8; 2];
+    match reader.read_exact(&mut raw).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let fin = raw[0] & 0x80 != 0;
+    let opcode = raw[0] & 0x0f;
+    let masked = raw[1] & 0x80 != 0;
+    let payload_len = match raw[1] & 0x7f {
+        126 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len).await?;
+            raw.extend_from_slice(&len);
+            u16::from_be_bytes(len) as u64
+        }
+        127 => {
+            let mut len = [0u8; 8];
+            reader.read_exact(&mut len).await?;
+            raw.extend_from_slice(&len);
+            u64::from_be_bytes(len)
+        }
+        len => len as u64,
+    };
+    if payload_len > max_payload as u64 {
+        return Err(IoError::new(ErrorKind::InvalidData, "frame too large"));
+    }
+    let mask = if masked {
+        let mut mask = [0u8; 4];
+        reader.read_exact(&mut mask).await?;
+        raw.extend_from_slice(&mask);
+        Some(mask)
+    } else {
+        None
+    };
+    let payload_start = raw.len();
+    raw.resize(payload_start + payload_len as usize, 0);
+    reader.read_exact(&mut raw[payload_start..]).await?;
+    Ok(Some(Frame {
+        raw,
+        fin,
+        opcode,
+        mask,
+        payload_start,
+    }))
+}
+
+/// JSON-RPC error response of a rejected request
+fn rate_limited(error: ApiError) -> Response<Body> {
+    let error: ErrorObjectOwned = error.into();
+    let body = serde_json::json!({ "jsonrpc": "2.0", "error": error, "id": Value::Null });
+    let mut response = Response::new(Body::from(body.to_string()));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_api_exports::rate_limit::RateLimitConfig;
+    use std::collections::HashMap;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    fn rate_limit_layer() -> RateLimitLayer {
+        let tokens: AuthTokens =
+            serde_json::from_str(r#"[{"token": "indexer", "methods": ["*"]}]"#).unwrap();
+        let limiter = RateLimiter::new(RateLimitConfig {
+            enabled: true,
+            refill_per_second: 0,
+            burst: 10,
+            gas_per_cost_unit: 100,
+            max_clients: 100,
+            client_ip_header: Some("x-forwarded-for".to_string()),
+            method_costs: HashMap::from([("execute_read_only_call".to_string(), 4)]),
+        });
+        RateLimitLayer::new(limiter, Some(tokens), 1024)
+    }
+
+    fn rpc_call(id: u64, method: &str) -> String {
+        format!(
+            r#"{{"jsonrpc": "2.0", "id": {}, "method": "{}", "params": []}}"#,
+            id, method
+        )
+    }
+
+    fn read_only_response(id: u64, gas_cost: u64) -> String {
+        format!(
+            r#"{{"jsonrpc": "2.0", "id": {}, "result": [{{"gas_cost": {}}}]}}"#,
+            id, gas_cost
+        )
+    }
+
+    #[test]
+    fn test_client_id() {
+        let layer = rate_limit_layer();
+        let headers = |entries: &[(&'static str, &str)]| {
+            let mut headers = HeaderMap::new();
+            for (name, value) in entries {
+                headers.append(*name, value.parse().unwrap());
+            }
+            headers
+        };
+        let peer = (PEER_ADDR_HEADER, "10.0.0.1:4321");
+
+        assert_eq!(
+            layer.client_id(&headers(&[("authorization", "Bearer indexer"), peer])),
+            "token:indexer"
+        );
+        // the entries before the last one are set by the client itself
+        assert_eq!(
+            layer.client_id(&headers(&[
+                ("x-forwarded-for", "1.1.1.1, 2.2.2.2"),
+                ("x-forwarded-for", "3.3.3.3, 4.4.4.4"),
+                peer
+            ])),
+            "ip:4.4.4.4"
+        );
+        // unknown tokens are ignored, clients are then identified by the IP of their connection
+        assert_eq!(
+            layer.client_id(&headers(&[("authorization", "Bearer unknown"), peer])),
+            "ip:10.0.0.1"
+        );
+        // IPv6 clients are identified by their /64 network
+        assert_eq!(
+            layer.client_id(&headers(&[(
+                PEER_ADDR_HEADER,
+                "[2001:db8:1:2:3:4:5:6]:4321"
+            )])),
+            "ip:2001:db8:1:2::/64"
+        );
+        assert_eq!(layer.client_id(&headers(&[])), "anonymous");
+    }
+
+    /// service answering the read-only calls with a gas cost of 300, and the other calls with a null result
+    async fn call(layer: &RateLimitLayer, peer_addr: &str, body: String) -> String {
+        let inner = tower::service_fn(|request: Request<Body>| async move {
+            let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+            let response = match called_methods(&body).first().map(String::as_str) {
+                Some("execute_read_only_call") => read_only_response(1, 300),
+                _ => r#"{"jsonrpc": "2.0", "id": 1, "result": null}"#.to_string(),
+            };
+            Ok::<_, Infallible>(Response::new(Body::from(response)))
+        });
+        let request = Request::builder()
+            .method(Method::POST)
+            .header(PEER_ADDR_HEADER, peer_addr)
+            .body(Body::from(body))
+            .unwrap();
+        let response = layer.layer(inner).oneshot(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    fn is_rate_limited(response: &str) -> bool {
+        response.contains(r#""error""#)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_http() {
+        let layer = rate_limit_layer();
+
+        // batches are charged every call they make
+        let batch = format!(
+            "[{}]",
+            (0..10)
+                .map(|id| rpc_call(id, "get_status"))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        assert!(!is_rate_limited(
+            &call(&layer, "10.0.0.1:1000", batch).await
+        ));
+        let response = call(&layer, "10.0.0.1:1001", rpc_call(1, "get_status")).await;
+        assert!(is_rate_limited(&response));
+
+        // the peers have their own budget
+        let response = call(&layer, "10.0.0.2:1000", rpc_call(1, "get_status")).await;
+        assert!(!is_rate_limited(&response));
+
+        // the read-only call costs 4 units, then 3 more for its gas
+        let read_only_call = rpc_call(1, "execute_read_only_call");
+        let response = call(&layer, "10.0.0.3:1000", read_only_call.clone()).await;
+        assert!(!is_rate_limited(&response));
+        let response = call(&layer, "10.0.0.3:1000", read_only_call).await;
+        assert!(is_rate_limited(&response));
+        let response = call(&layer, "10.0.0.3:1000", rpc_call(1, "get_status")).await;
+        assert!(!is_rate_limited(&response));
+    }
+
+    /// WebSocket frame, masked as sent by clients if a mask is given
+    fn frame(opcode: u8, fin: bool, payload: &[u8], mask: Option<[u8; 4]>) -> Vec<u8> {
+        let mut frame = vec![opcode | if fin { 0x80 } else { 0 }];
+        let mask_bit = if mask.is_some() { 0x80 } else { 0 };
+        match payload.len() {
+            len if len < 126 => frame.push(mask_bit | len as u8),
+            len => {
+                frame.push(mask_bit | 126);
+                frame.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+        }
+        match mask {
+            Some(mask) => {
+                frame.extend_from_slice(&mask);
+                frame.extend(payload.iter().enumerate().map(|(i, b)| b ^ mask[i % 4]));
+            }
+            None => frame.extend_from_slice(payload),
+        }
+        frame
+    }
+
+    fn text(payload: &str) -> Vec<u8> {
+        frame(0x1, true, payload.as_bytes(), Some([1, 2, 3, 4]))
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_websocket() {
+        let layer = rate_limit_layer();
+        let (mut peer, relay_peer) = tokio::io::duplex(4096);
+        let (relay_upstream, mut upstream) = tokio::io::duplex(4096);
+        let relay = tokio::spawn(async move {
+            layer
+                .relay_websocket("ip:10.0.0.1", relay_peer, relay_upstream)
+                .await
+        });
+
+        // the read-only call costs 4 units, then 3 more for the gas reported by its response
+        let read_only_call = rpc_call(1, "execute_read_only_call");
+        peer.write_all(&text(&read_only_call)).await.unwrap();
+        let received = read_frame(&mut upstream, 1024).await.unwrap().unwrap();
+        assert_eq!(received.payload(), read_only_call.as_bytes());
+        let response = frame(0x1, true, read_only_response(1, 300).as_bytes(), None);
+        upstream.write_all(&response).await.unwrap();
+        let received = read_frame(&mut peer, 1024).await.unwrap().unwrap();
+        assert_eq!(received.raw, response);
+
+        // control frames are relayed without being charged
+        let ping = frame(0x9, true, b"ping", Some([5, 6, 7, 8]));
+        peer.write_all(&ping).await.unwrap();
+        let received = read_frame(&mut upstream, 1024).await.unwrap().unwrap();
+        assert_eq!(received.raw, ping);
+
+        // fragmented messages are relayed once complete, and charged once
+        let call = rpc_call(2, "get_status");
+        let (start, end) = call.as_bytes().split_at(10);
+        peer.write_all(&frame(0x1, false, start, Some([1, 1, 1, 1])))
+            .await
+            .unwrap();
+        peer.write_all(&frame(0x0, true, end, Some([2, 2, 2, 2])))
+            .await
+            .unwrap();
+        let first = read_frame(&mut upstream, 1024).await.unwrap().unwrap();
+        let last = read_frame(&mut upstream, 1024).await.unwrap().unwrap();
+        assert_eq!([first.payload(), last.payload()].concat(), call.as_bytes());
+
+        // 2 units are left, the connection is closed instead of relaying a call costing 4
+        peer.write_all(&text(&rpc_call(3, "execute_read_only_call")))
+            .await
+            .unwrap();
+        assert!(read_frame(&mut upstream, 1024).await.unwrap().is_none());
+        assert!(relay.await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! TLS configuration of the JSON-RPC servers, see the `proxy` module for its termination
+
+use jsonrpsee::core::Error as JsonRpseeError;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+use tokio_rustls::rustls::server::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+
+/// build the TLS acceptor of the API, if TLS is enabled by setting a certificate
+pub(crate) fn tls_acceptor(
+    cert_path: Option<&Path>,
+    key_path: Option<&Path>,
+    client_ca_path: Option<&Path>,
+) -> Result<Option<TlsAcceptor>, JsonRpseeError> {
+    let Some(cert_path) = cert_path else {
+        return Ok(None);
+    };
+    let key_path = key_path.ok_or_else(|| {
+        JsonRpseeError::Custom("`tls_key_path` is required when `tls_cert_path` is set".into())
+    })?;
+
+    let certs = read_certificates(cert_path)?;
+    let key = read_private_key(key_path)?;
+
+    let builder = ServerConfig::builder().with_safe_defaults();
+    let builder = match client_ca_path {
+        Some(ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certificates(ca_path)? {
+                roots.add(&cert).map_err(|e| tls_error(ca_path, e))?;
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let mut config = builder
+        .with_single_cert(certs, key)
+        .map_err(|e| tls_error(cert_path, e))?;
+    // WebSocket upgrades are only defined over HTTP/1.1
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    Ok(Some(TlsAcceptor::from(Arc::new(config))))
+}
+
+fn read_certificates(path: &Path) -> Result<Vec<Certificate>, JsonRpseeError> {
+    let mut reader = BufReader::new(File::open(path).map_err(|e| tls_error(path, e))?);
+    let certs = rustls_pemfile::certs(&mut reader).map_err(|e| tls_error(path, e))?;
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn read_private_key(path: &Path) -> Result<PrivateKey, JsonRpseeError> {
+    let mut reader = BufReader::new(File::open(path).map_err(|e| tls_error(path, e))?);
+    loop {
+        match rustls_pemfile::read_one(&mut reader).map_err(|e| tls_error(path, e))? {
+            Some(
+                rustls_pemfile::Item::RSAKey(key)
+                | rustls_pemfile::Item::PKCS8Key(key)
+                | rustls_pemfile::Item::ECKey(key),
+            ) => return Ok(PrivateKey(key)),
+            Some(_) => continue,
+            None => return Err(tls_error(path, "no private key found")),
+        }
+    }
+}
+
+fn tls_error(path: &Path, error: impl Display) -> JsonRpseeError {
+    JsonRpseeError::Custom(format!(
+        "invalid TLS configuration in {}: {}",
+        path.display(),
+        error
+    ))
+}
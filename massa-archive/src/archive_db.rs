@@ -0,0 +1,367 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Persistent store of final blocks, operations and endorsements.
+//!
+//! Column families:
+//! * blocks: block id => serialized block
+//! * block_slots: slot key => block id
+//! * operations: operation id => block id followed by the serialized operation
+//! * endorsements: endorsement id => block id
+//! * operations_by_address: involved address, slot key and operation id => empty value
+//!
+//! Archives created before operations were indexed by every involved address have an
+//! `operations_by_sender` column family instead, which is migrated when the archive is opened.
+
+use crate::ArchiveConfig;
+use massa_hash::HASH_SIZE_BYTES;
+use massa_models::{
+    address::Address,
+    block::{Block, BlockDeserializer, BlockDeserializerArgs, SecureShareBlock},
+    block_id::BlockId,
+    endorsement::{EndorsementId, SecureShareEndorsement},
+    operation::{Operation, OperationDeserializer, OperationId, SecureShareOperation},
+    secure_share::{SecureShareDeserializer, SecureShareSerializer},
+    slot::{Slot, SLOT_KEY_SIZE},
+};
+use massa_serialization::{DeserializeError, Deserializer, Serializer};
+use rocksdb::{ColumnFamilyDescriptor, Direction, IteratorMode, Options, WriteBatch, DB};
+use tracing::{debug, info};
+
+const BLOCKS_CF: &str = "blocks";
+const BLOCK_SLOTS_CF: &str = "block_slots";
+const OPERATIONS_CF: &str = "operations";
+const ENDORSEMENTS_CF: &str = "endorsements";
+const ADDRESS_INDEX_CF: &str = "operations_by_address";
+const LEGACY_SENDER_INDEX_CF: &str = "operations_by_sender";
+/// number of legacy index entries migrated per write batch
+const MIGRATION_BATCH_SIZE: usize = 10_000;
+const OPEN_ERROR: &str = "critical: rocksdb open operation failed";
+const CRUD_ERROR: &str = "critical: rocksdb crud operation failed";
+const CF_ERROR: &str = "critical: rocksdb column family operation failed";
+const BLOCK_SER_ERROR: &str = "critical: block serialization failed";
+const BLOCK_DESER_ERROR: &str = "critical: block deserialization failed";
+const OPERATION_SER_ERROR: &str = "critical: operation serialization failed";
+const OPERATION_DESER_ERROR: &str = "critical: operation deserialization failed";
+
+/// Reads a block id from the first bytes of a buffer
+fn block_id_from_prefix(bytes: &[u8]) -> BlockId {
+    BlockId::from_bytes(
+        bytes[..HASH_SIZE_BYTES]
+            .try_into()
+            .expect("critical: archived block id is too short"),
+    )
+}
+
+/// Persistent archive of final blocks, operations and endorsements
+pub struct ArchiveDB {
+    /// RocksDB database
+    db: DB,
+    secure_share_serializer: SecureShareSerializer,
+    block_deserializer: SecureShareDeserializer<Block, BlockDeserializer>,
+    operation_deserializer: SecureShareDeserializer<Operation, OperationDeserializer>,
+}
+
+impl ArchiveDB {
+    /// Opens (or creates) an `ArchiveDB`
+    pub fn new(config: ArchiveConfig) -> Self {
+        let mut db_opts = Options::default();
+        db_opts.create_if_missing(true);
+        db_opts.create_missing_column_families(true);
+
+        let mut column_families = vec![
+            ColumnFamilyDescriptor::new(BLOCKS_CF, Options::default()),
+            ColumnFamilyDescriptor::new(BLOCK_SLOTS_CF, Options::default()),
+            ColumnFamilyDescriptor::new(OPERATIONS_CF, Options::default()),
+            ColumnFamilyDescriptor::new(ENDORSEMENTS_CF, Options::default()),
+            ColumnFamilyDescriptor::new(ADDRESS_INDEX_CF, Options::default()),
+        ];
+        let has_legacy_index = DB::list_cf(&db_opts, &config.path)
+            .map(|names| names.iter().any(|name| name == LEGACY_SENDER_INDEX_CF))
+            .unwrap_or(false);
+        if has_legacy_index {
+            column_families.push(ColumnFamilyDescriptor::new(
+                LEGACY_SENDER_INDEX_CF,
+                Options::default(),
+            ));
+        }
+        let db =
+            DB::open_cf_descriptors(&db_opts, &config.path, column_families).expect(OPEN_ERROR);
+
+        let mut archive = ArchiveDB {
+            db,
+            secure_share_serializer: SecureShareSerializer::new(),
+            // archived blocks were already checked when they were received:
+            // no genesis period check is needed when reading them back
+            block_deserializer: SecureShareDeserializer::new(BlockDeserializer::new(
+                BlockDeserializerArgs {
+                    thread_count: config.thread_count,
+                    max_operations_per_block: config.max_operations_per_block,
+                    endorsement_count: config.endorsement_count,
+                    max_denunciations_per_block_header: config.max_denunciations_per_block_header,
+                    last_start_period: None,
+                },
+            )),
+            operation_deserializer: SecureShareDeserializer::new(OperationDeserializer::new(
+                config.max_datastore_value_length,
+                config.max_function_name_length,
+                config.max_parameters_size,
+                config.max_op_datastore_entry_count,
+                config.max_op_datastore_key_length,
+                config.max_op_datastore_value_length,
+            )),
+        };
+        if has_legacy_index {
+            archive.migrate_legacy_sender_index();
+        }
+        archive
+    }
+
+    /// Rebuilds the address index from the legacy sender index, then drops the latter.
+    ///
+    /// Legacy keys are made of the sender address, the slot key and the operation id:
+    /// each archived operation is read back to index it under all its involved addresses.
+    /// Interrupting the migration is harmless as it is run again on the next opening.
+    fn migrate_legacy_sender_index(&mut self) {
+        let legacy_handle = self.db.cf_handle(LEGACY_SENDER_INDEX_CF).expect(CF_ERROR);
+        let address_handle = self.db.cf_handle(ADDRESS_INDEX_CF).expect(CF_ERROR);
+        let mut batch = WriteBatch::default();
+        let mut migrated_count = 0;
+        for (key, _) in self
+            .db
+            .iterator_cf(legacy_handle, IteratorMode::Start)
+            .flatten()
+        {
+            let Some(suffix_start) = key.len().checked_sub(SLOT_KEY_SIZE + HASH_SIZE_BYTES) else {
+                continue;
+            };
+            let slot_and_id = &key[suffix_start..];
+            let id_bytes: [u8; HASH_SIZE_BYTES] = slot_and_id[SLOT_KEY_SIZE..]
+                .try_into()
+                .expect("critical: invalid operation id in the legacy archive sender index");
+            let Some((operation, _)) = self.get_operation(&OperationId::from_bytes(&id_bytes)) else {
+                continue;
+            };
+            for address in operation.get_ledger_involved_addresses() {
+                batch.put_cf(
+                    address_handle,
+                    [&address.prefixed_bytes()[..], slot_and_id].concat(),
+                    b"",
+                );
+            }
+            migrated_count += 1;
+            if migrated_count % MIGRATION_BATCH_SIZE == 0 {
+                self.db.write(std::mem::take(&mut batch)).expect(CRUD_ERROR);
+            }
+        }
+        self.db.write(batch).expect(CRUD_ERROR);
+        self.db.drop_cf(LEGACY_SENDER_INDEX_CF).expect(CF_ERROR);
+        info!(
+            "migrated {} archived operations from the sender index to the address index",
+            migrated_count
+        );
+    }
+
+    /// Writes a final block, its operations and its endorsements to the archive
+    ///
+    /// # Arguments
+    /// * `block`: the newly finalized block
+    /// * `operations`: the operations included in the block
+    pub fn append_final_block(
+        &self,
+        block: &SecureShareBlock,
+        operations: &[SecureShareOperation],
+    ) {
+        let blocks_handle = self.db.cf_handle(BLOCKS_CF).expect(CF_ERROR);
+        let slots_handle = self.db.cf_handle(BLOCK_SLOTS_CF).expect(CF_ERROR);
+        let operations_handle = self.db.cf_handle(OPERATIONS_CF).expect(CF_ERROR);
+        let endorsements_handle = self.db.cf_handle(ENDORSEMENTS_CF).expect(CF_ERROR);
+        let address_handle = self.db.cf_handle(ADDRESS_INDEX_CF).expect(CF_ERROR);
+
+        let slot = block.content.header.content.slot;
+        let block_id_bytes = block.id.to_bytes();
+        let mut batch = WriteBatch::default();
+
+        // block and slot index
+        let mut serialized_block = Vec::new();
+        self.secure_share_serializer
+            .serialize(block, &mut serialized_block)
+            .expect(BLOCK_SER_ERROR);
+        batch.put_cf(blocks_handle, block_id_bytes, serialized_block);
+        batch.put_cf(slots_handle, slot.to_bytes_key(), block_id_bytes);
+
+        // endorsements, read back from the block header
+        for endorsement in &block.content.header.content.endorsements {
+            batch.put_cf(
+                endorsements_handle,
+                endorsement.id.to_bytes(),
+                block_id_bytes,
+            );
+        }
+
+        // operations and address index
+        for operation in operations {
+            let mut value = block_id_bytes.to_vec();
+            self.secure_share_serializer
+                .serialize(operation, &mut value)
+                .expect(OPERATION_SER_ERROR);
+            batch.put_cf(operations_handle, operation.id.to_bytes(), value);
+            // sender, and recipient or target if any
+            for address in operation.get_ledger_involved_addresses() {
+                batch.put_cf(
+                    address_handle,
+                    [
+                        &address.prefixed_bytes()[..],
+                        &slot.to_bytes_key()[..],
+                        &operation.id.to_bytes()[..],
+                    ]
+                    .concat(),
+                    b"",
+                );
+            }
+        }
+
+        self.db.write(batch).expect(CRUD_ERROR);
+        debug!(
+            "archived final block {} at slot {} with {} operations",
+            block.id,
+            slot,
+            operations.len()
+        );
+    }
+
+    /// Get an archived block
+    pub fn get_block(&self, block_id: &BlockId) -> Option<SecureShareBlock> {
+        let handle = self.db.cf_handle(BLOCKS_CF).expect(CF_ERROR);
+        self.db
+            .get_cf(handle, block_id.to_bytes())
+            .expect(CRUD_ERROR)
+            .map(|serialized_block| {
+                let (_, block) = self
+                    .block_deserializer
+                    .deserialize::<DeserializeError>(&serialized_block)
+                    .expect(BLOCK_DESER_ERROR);
+                block
+            })
+    }
+
+    /// Get the id of the final block archived at a given slot, if any
+    pub fn get_block_id_by_slot(&self, slot: &Slot) -> Option<BlockId> {
+        let handle = self.db.cf_handle(BLOCK_SLOTS_CF).expect(CF_ERROR);
+        self.db
+            .get_cf(handle, slot.to_bytes_key())
+            .expect(CRUD_ERROR)
+            .map(|bytes| block_id_from_prefix(&bytes))
+    }
+
+    /// Get the final block archived at a given slot, if any
+    pub fn get_block_by_slot(&self, slot: &Slot) -> Option<SecureShareBlock> {
+        self.get_block_id_by_slot(slot)
+            .and_then(|block_id| self.get_block(&block_id))
+    }
+
+    /// Get an archived operation along with the id of the final block that includes it
+    pub fn get_operation(
+        &self,
+        operation_id: &OperationId,
+    ) -> Option<(SecureShareOperation, BlockId)> {
+        let handle = self.db.cf_handle(OPERATIONS_CF).expect(CF_ERROR);
+        self.db
+            .get_cf(handle, operation_id.to_bytes())
+            .expect(CRUD_ERROR)
+            .map(|value| {
+                let (_, operation) = self
+                    .operation_deserializer
+                    .deserialize::<DeserializeError>(&value[HASH_SIZE_BYTES..])
+                    .expect(OPERATION_DESER_ERROR);
+                (operation, block_id_from_prefix(&value))
+            })
+    }
+
+    /// Get an archived endorsement along with the id of the final block that includes it
+    pub fn get_endorsement(
+        &self,
+        endorsement_id: &EndorsementId,
+    ) -> Option<(SecureShareEndorsement, BlockId)> {
+        let handle = self.db.cf_handle(ENDORSEMENTS_CF).expect(CF_ERROR);
+        let block_id = self
+            .db
+            .get_cf(handle, endorsement_id.to_bytes())
+            .expect(CRUD_ERROR)
+            .map(|bytes| block_id_from_prefix(&bytes))?;
+        let block = self.get_block(&block_id)?;
+        block
+            .content
+            .header
+            .content
+            .endorsements
+            .into_iter()
+            .find(|endorsement| &endorsement.id == endorsement_id)
+            .map(|endorsement| (endorsement, block_id))
+    }
+
+    /// Check whether an operation is archived
+    pub fn contains_operation(&self, operation_id: &OperationId) -> bool {
+        let handle = self.db.cf_handle(OPERATIONS_CF).expect(CF_ERROR);
+        self.db
+            .get_pinned_cf(handle, operation_id.to_bytes())
+            .expect(CRUD_ERROR)
+            .is_some()
+    }
+
+    /// Iterate over the archived operations sent or received by an address, sorted by slot
+    ///
+    /// The index is read lazily, so that callers only pay for the entries they consume.
+    ///
+    /// # Arguments
+    /// * `address`: the sender, recipient or call target of the operations
+    /// * `start`: optional first slot (included)
+    /// * `end`: optional last slot (excluded)
+    ///
+    /// # Returns
+    /// The ids of the operations along with the slot of the final block that includes them
+    pub fn get_operations_by_address(
+        &self,
+        address: &Address,
+        start: Option<Slot>,
+        end: Option<Slot>,
+    ) -> impl Iterator<Item = (Slot, OperationId)> + '_ {
+        let handle = self.db.cf_handle(ADDRESS_INDEX_CF).expect(CF_ERROR);
+        let prefix = address.prefixed_bytes();
+        let start_key = [
+            &prefix[..],
+            &start
+                .map(|slot| slot.to_bytes_key().to_vec())
+                .unwrap_or_default()[..],
+        ]
+        .concat();
+        let end_key = end.map(|slot| slot.to_bytes_key());
+
+        self.db
+            .iterator_cf(handle, IteratorMode::From(&start_key, Direction::Forward))
+            .flatten()
+            .map_while(move |(key, _)| {
+                if !key.starts_with(&prefix)
+                    || key.len() != prefix.len() + SLOT_KEY_SIZE + HASH_SIZE_BYTES
+                {
+                    return None;
+                }
+                let slot_bytes: [u8; SLOT_KEY_SIZE] = key
+                    [prefix.len()..prefix.len() + SLOT_KEY_SIZE]
+                    .try_into()
+                    .expect("critical: invalid slot key in the archive address index");
+                if end_key
+                    .map(|end_key| slot_bytes >= end_key)
+                    .unwrap_or(false)
+                {
+                    return None;
+                }
+                let id_bytes: [u8; HASH_SIZE_BYTES] = key[prefix.len() + SLOT_KEY_SIZE..]
+                    .try_into()
+                    .expect("critical: invalid operation id in the archive address index");
+                Some((
+                    Slot::from_bytes_key(&slot_bytes),
+                    OperationId::from_bytes(&id_bytes),
+                ))
+            })
+    }
+}
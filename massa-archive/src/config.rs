@@ -0,0 +1,30 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use std::path::PathBuf;
+
+/// Archive configuration
+#[derive(Debug, Clone)]
+pub struct ArchiveConfig {
+    /// path to the archive database
+    pub path: PathBuf,
+    /// thread count
+    pub thread_count: u8,
+    /// max number of operations per block
+    pub max_operations_per_block: u32,
+    /// endorsement count
+    pub endorsement_count: u32,
+    /// max denunciations in block header
+    pub max_denunciations_per_block_header: u32,
+    /// max datastore value length
+    pub max_datastore_value_length: u64,
+    /// max function name length
+    pub max_function_name_length: u16,
+    /// max parameter size
+    pub max_parameters_size: u32,
+    /// max operation datastore entry count
+    pub max_op_datastore_entry_count: u64,
+    /// max operation datastore key length
+    pub max_op_datastore_key_length: u8,
+    /// max operation datastore value length
+    pub max_op_datastore_value_length: u64,
+}
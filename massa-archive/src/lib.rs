@@ -0,0 +1,27 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! # General description
+//!
+//! Consensus prunes final blocks from memory after `force_keep_final_periods`,
+//! which makes older history unavailable to the API.
+//! This crate provides an opt-in persistent archive of final blocks,
+//! along with their operations and endorsements.
+//!
+//! The archive is written by a dedicated writer thread, fed by consensus each time a block becomes final,
+//! and read by the API as a fallback when an item is no longer in memory.
+//! Operations are additionally indexed by involved address (sender, recipient or call target) and slot.
+
+#![warn(missing_docs)]
+
+mod archive_db;
+mod config;
+mod lookup;
+mod writer;
+
+pub use archive_db::*;
+pub use config::*;
+pub use lookup::*;
+pub use writer::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Lookups combining the operations still in memory with the archived ones.
+
+use crate::ArchiveDB;
+use massa_models::{address::Address, operation::OperationId, slot::Slot};
+use massa_storage::Storage;
+
+/// Iterate over the operations sent or received by an address, sorted by slot
+///
+/// The operations still in memory are collected first, so that no storage lock is held while reading the archive,
+/// then merged with the archived ones as the iterator is consumed:
+/// callers skipping to a page and taking a few operations never load the whole history of the address.
+///
+/// # Arguments
+/// * `storage`: storage holding the operations still in memory
+/// * `archive`: the archive of final operations, if enabled
+/// * `address`: the sender, recipient or call target of the operations
+/// * `start`: optional first slot (included)
+/// * `end`: optional last slot (excluded)
+///
+/// # Returns
+/// The ids of the operations along with the slot of the earliest block that includes them
+pub fn get_operations_by_address<'a>(
+    storage: &Storage,
+    archive: Option<&'a ArchiveDB>,
+    address: &Address,
+    start: Option<Slot>,
+    end: Option<Slot>,
+) -> impl Iterator<Item = (Slot, OperationId)> + 'a {
+    let in_range = |slot: &Slot| {
+        start.map(|start| *slot >= start).unwrap_or(true)
+            && end.map(|end| *slot < end).unwrap_or(true)
+    };
+
+    // operations still in memory, along with the slot of the earliest block that includes them
+    let mut in_memory: Vec<(Slot, OperationId)> = {
+        let read_blocks = storage.read_blocks();
+        let read_ops = storage.read_operations();
+        read_ops
+            .get_operations_involving(address)
+            .into_iter()
+            .flatten()
+            .filter_map(|op_id| {
+                read_blocks
+                    .get_blocks_by_operation(op_id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|block_id| read_blocks.get(block_id))
+                    .map(|block| block.content.header.content.slot)
+                    .min()
+                    .map(|slot| (slot, *op_id))
+            })
+            .filter(|(slot, _)| in_range(slot))
+            .collect()
+    };
+    // final operations are listed by the archive, whose slot takes precedence
+    if let Some(archive) = archive {
+        in_memory.retain(|(_, op_id)| !archive.contains_operation(op_id));
+    }
+    in_memory.sort_unstable();
+
+    let address = *address;
+    let mut in_memory = in_memory.into_iter().peekable();
+    let mut archived = archive
+        .into_iter()
+        .flat_map(move |archive| archive.get_operations_by_address(&address, start, end))
+        .peekable();
+    std::iter::from_fn(move || match (in_memory.peek(), archived.peek()) {
+        (Some(memory_entry), Some(archived_entry)) if memory_entry > archived_entry => {
+            archived.next()
+        }
+        (Some(_), _) => in_memory.next(),
+        (None, _) => archived.next(),
+    })
+}
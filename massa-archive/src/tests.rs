@@ -0,0 +1,346 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use crate::{get_operations_by_address, ArchiveConfig, ArchiveDB, ArchiveWriter};
+use massa_hash::Hash;
+use massa_models::{
+    address::Address,
+    amount::Amount,
+    block::{Block, BlockSerializer, SecureShareBlock},
+    block_header::{BlockHeader, BlockHeaderSerializer},
+    block_id::BlockId,
+    config::{
+        ENDORSEMENT_COUNT, MAX_DATASTORE_VALUE_LENGTH, MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+        MAX_FUNCTION_NAME_LENGTH, MAX_OPERATIONS_PER_BLOCK, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+        MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        MAX_PARAMETERS_SIZE, THREAD_COUNT,
+    },
+    endorsement::{Endorsement, EndorsementSerializer, SecureShareEndorsement},
+    operation::{Operation, OperationSerializer, OperationType, SecureShareOperation},
+    secure_share::SecureShareContent,
+    slot::Slot,
+};
+use massa_signature::KeyPair;
+use massa_storage::Storage;
+use rocksdb::{Options, DB};
+use std::sync::Arc;
+use tempfile::TempDir;
+
+fn get_archive_config(path: &TempDir) -> ArchiveConfig {
+    ArchiveConfig {
+        path: path.path().to_path_buf(),
+        thread_count: THREAD_COUNT,
+        max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
+        endorsement_count: ENDORSEMENT_COUNT,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+        max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
+        max_function_name_length: MAX_FUNCTION_NAME_LENGTH,
+        max_parameters_size: MAX_PARAMETERS_SIZE,
+        max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+        max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
+        max_op_datastore_value_length: MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+    }
+}
+
+/// Creates a final block at `slot`, with a transaction and an endorsement
+fn create_final_block(
+    slot: Slot,
+) -> (
+    SecureShareBlock,
+    SecureShareOperation,
+    SecureShareEndorsement,
+    Address,
+    Address,
+) {
+    let recipient_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+    create_final_block_with_transaction(slot, &KeyPair::generate(), recipient_address)
+}
+
+/// Creates a final block at `slot`, with a transaction between the given accounts and an endorsement
+fn create_final_block_with_transaction(
+    slot: Slot,
+    sender_keypair: &KeyPair,
+    recipient_address: Address,
+) -> (
+    SecureShareBlock,
+    SecureShareOperation,
+    SecureShareEndorsement,
+    Address,
+    Address,
+) {
+    let sender_address = Address::from_public_key(&sender_keypair.get_public_key());
+
+    let operation = Operation::new_verifiable(
+        Operation {
+            fee: Amount::from_raw(10),
+            expire_period: 10,
+            op: OperationType::Transaction {
+                recipient_address,
+                amount: Amount::from_raw(1000),
+            },
+        },
+        OperationSerializer::new(),
+        sender_keypair,
+    )
+    .unwrap();
+
+    let endorsement = Endorsement::new_verifiable(
+        Endorsement {
+            slot,
+            index: 0,
+            endorsed_block: BlockId(Hash::compute_from("parent".as_bytes())),
+        },
+        EndorsementSerializer::new(),
+        &KeyPair::generate(),
+    )
+    .unwrap();
+
+    let creator_keypair = KeyPair::generate();
+    let header = BlockHeader::new_verifiable(
+        BlockHeader {
+            slot,
+            parents: (0..THREAD_COUNT)
+                .map(|i| BlockId(Hash::compute_from(&[i])))
+                .collect(),
+            operation_merkle_root: Hash::compute_from(&operation.serialized_data),
+            endorsements: vec![endorsement.clone()],
+            denunciations: Vec::new(),
+        },
+        BlockHeaderSerializer::new(),
+        &creator_keypair,
+    )
+    .unwrap();
+    let block = Block::new_verifiable(
+        Block {
+            header,
+            operations: vec![operation.id],
+        },
+        BlockSerializer::new(),
+        &creator_keypair,
+    )
+    .unwrap();
+
+    (
+        block,
+        operation,
+        endorsement,
+        sender_address,
+        recipient_address,
+    )
+}
+
+#[test]
+fn test_archive_final_block() {
+    let temp_dir = TempDir::new().unwrap();
+    let archive = ArchiveDB::new(get_archive_config(&temp_dir));
+
+    let slot = Slot::new(5, 0);
+    let (block, operation, endorsement, sender_address, recipient_address) =
+        create_final_block(slot);
+    archive.append_final_block(&block, &[operation.clone()]);
+
+    // block by id and by slot
+    let archived_block = archive.get_block(&block.id).expect("block not archived");
+    assert_eq!(archived_block.id, block.id);
+    assert_eq!(archived_block.serialized_data, block.serialized_data);
+    assert_eq!(archive.get_block_id_by_slot(&slot), Some(block.id));
+    assert!(archive.get_block_by_slot(&Slot::new(6, 0)).is_none());
+
+    // operation with its including block
+    let (archived_op, op_block_id) = archive
+        .get_operation(&operation.id)
+        .expect("operation not archived");
+    assert_eq!(archived_op.id, operation.id);
+    assert_eq!(op_block_id, block.id);
+
+    // endorsement with its including block
+    let (archived_endo, endo_block_id) = archive
+        .get_endorsement(&endorsement.id)
+        .expect("endorsement not archived");
+    assert_eq!(archived_endo.id, endorsement.id);
+    assert_eq!(endo_block_id, block.id);
+
+    // address index, for both the sender and the recipient, with slot bounds
+    assert_eq!(
+        archive
+            .get_operations_by_address(&sender_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(slot, operation.id)]
+    );
+    assert_eq!(
+        archive
+            .get_operations_by_address(&recipient_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(slot, operation.id)]
+    );
+    assert!(archive
+        .get_operations_by_address(&sender_address, Some(Slot::new(6, 0)), None)
+        .next()
+        .is_none());
+    assert!(archive
+        .get_operations_by_address(&sender_address, None, Some(slot))
+        .next()
+        .is_none());
+}
+
+#[test]
+fn test_archive_writer() {
+    let temp_dir = TempDir::new().unwrap();
+    let archive = Arc::new(ArchiveDB::new(get_archive_config(&temp_dir)));
+
+    let slot = Slot::new(5, 0);
+    let (block, operation, _, sender_address, _) = create_final_block(slot);
+
+    // queued blocks are written before the writer stops
+    let mut writer = ArchiveWriter::start(archive.clone(), 1);
+    let sender = writer.sender();
+    sender.append_final_block(block.clone(), vec![operation.clone()]);
+    writer.stop();
+    assert_eq!(archive.get_block_id_by_slot(&slot), Some(block.id));
+    assert_eq!(
+        archive
+            .get_operations_by_address(&sender_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(slot, operation.id)]
+    );
+
+    // blocks sent after the writer stopped are dropped
+    let (late_block, _, _, _, _) = create_final_block(Slot::new(6, 0));
+    sender.append_final_block(late_block, Vec::new());
+    assert!(archive.get_block_by_slot(&Slot::new(6, 0)).is_none());
+}
+
+#[test]
+fn test_archive_legacy_sender_index_migration() {
+    let temp_dir = TempDir::new().unwrap();
+    let slot = Slot::new(5, 0);
+    let (block, operation, _, sender_address, recipient_address) = create_final_block(slot);
+    ArchiveDB::new(get_archive_config(&temp_dir)).append_final_block(&block, &[operation.clone()]);
+
+    // turn the database into one written when operations were only indexed by sender
+    {
+        let mut db = DB::open_cf(
+            &Options::default(),
+            temp_dir.path(),
+            [
+                "blocks",
+                "block_slots",
+                "operations",
+                "endorsements",
+                "operations_by_address",
+            ],
+        )
+        .unwrap();
+        db.drop_cf("operations_by_address").unwrap();
+        db.create_cf("operations_by_sender", &Options::default())
+            .unwrap();
+        let legacy_handle = db.cf_handle("operations_by_sender").unwrap();
+        db.put_cf(
+            legacy_handle,
+            [
+                &sender_address.prefixed_bytes()[..],
+                &slot.to_bytes_key()[..],
+                &operation.id.to_bytes()[..],
+            ]
+            .concat(),
+            b"",
+        )
+        .unwrap();
+    }
+
+    // the recipient is indexed again and the legacy index is gone
+    let archive = ArchiveDB::new(get_archive_config(&temp_dir));
+    assert_eq!(
+        archive
+            .get_operations_by_address(&sender_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(slot, operation.id)]
+    );
+    assert_eq!(
+        archive
+            .get_operations_by_address(&recipient_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(slot, operation.id)]
+    );
+    drop(archive);
+    assert!(!DB::list_cf(&Options::default(), temp_dir.path())
+        .unwrap()
+        .contains(&"operations_by_sender".to_string()));
+}
+
+#[test]
+fn test_get_operations_by_address_lookup() {
+    let temp_dir = TempDir::new().unwrap();
+    let archive = ArchiveDB::new(get_archive_config(&temp_dir));
+
+    // an archived final block, and a block that is still in memory
+    let archived_slot = Slot::new(5, 0);
+    let (archived_block, archived_operation, _, _, recipient_address) =
+        create_final_block(archived_slot);
+    archive.append_final_block(&archived_block, &[archived_operation.clone()]);
+    let memory_slot = Slot::new(7, 0);
+    let (memory_block, memory_operation, _, memory_sender_address, _) =
+        create_final_block(memory_slot);
+    let mut storage = Storage::create_root();
+    storage.store_operations(vec![memory_operation.clone()]);
+    storage.store_block(memory_block);
+
+    assert_eq!(
+        get_operations_by_address(&storage, Some(&archive), &recipient_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(archived_slot, archived_operation.id)]
+    );
+    assert_eq!(
+        get_operations_by_address(&storage, None, &memory_sender_address, None, None)
+            .collect::<Vec<_>>(),
+        vec![(memory_slot, memory_operation.id)]
+    );
+    assert!(get_operations_by_address(
+        &storage,
+        Some(&archive),
+        &memory_sender_address,
+        Some(Slot::new(8, 0)),
+        None
+    )
+    .next()
+    .is_none());
+    // the archive is not looked up when it is disabled
+    assert!(
+        get_operations_by_address(&storage, None, &recipient_address, None, None)
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_get_operations_by_address_pages() {
+    let temp_dir = TempDir::new().unwrap();
+    let archive = ArchiveDB::new(get_archive_config(&temp_dir));
+    let mut storage = Storage::create_root();
+    let sender_keypair = KeyPair::generate();
+    let sender_address = Address::from_public_key(&sender_keypair.get_public_key());
+
+    // operations of the same sender, archived at even periods and still in memory at odd ones,
+    // the block at period 4 being both archived and in memory
+    let mut expected = Vec::new();
+    for period in 2..7 {
+        let slot = Slot::new(period, 0);
+        let recipient_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let (block, operation, _, _, _) =
+            create_final_block_with_transaction(slot, &sender_keypair, recipient_address);
+        if period % 2 == 0 {
+            archive.append_final_block(&block, &[operation.clone()]);
+        }
+        if period % 2 == 1 || period == 4 {
+            storage.store_operations(vec![operation.clone()]);
+            storage.store_block(block);
+        }
+        expected.push((slot, operation.id));
+    }
+
+    let lookup =
+        || get_operations_by_address(&storage, Some(&archive), &sender_address, None, None);
+    assert_eq!(lookup().collect::<Vec<_>>(), expected);
+    assert_eq!(lookup().skip(1).take(2).collect::<Vec<_>>(), expected[1..3]);
+    assert_eq!(lookup().skip(4).take(2).collect::<Vec<_>>(), expected[4..]);
+    assert!(lookup().nth(5).is_none());
+}
@@ -0,0 +1,104 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Thread writing final blocks to the archive, so that consensus does not write to RocksDB itself.
+
+use crate::ArchiveDB;
+use crossbeam_channel::{bounded, Receiver, Sender};
+use massa_models::{block::SecureShareBlock, operation::SecureShareOperation};
+use std::{
+    sync::Arc,
+    thread::{self, JoinHandle},
+};
+use tracing::{info, warn};
+
+/// Commands processed by the archive writer thread
+enum ArchiveWriterCommand {
+    /// write a final block and its operations
+    AppendFinalBlock(Box<SecureShareBlock>, Vec<SecureShareOperation>),
+    /// write the pending blocks, then stop
+    Stop,
+}
+
+/// Cloneable handle used to send final blocks to the archive writer thread
+#[derive(Clone)]
+pub struct ArchiveWriterSender(Sender<ArchiveWriterCommand>);
+
+impl ArchiveWriterSender {
+    /// Queues a final block and its operations for writing.
+    /// Blocks are written in the order in which they are queued.
+    pub fn append_final_block(
+        &self,
+        block: SecureShareBlock,
+        operations: Vec<SecureShareOperation>,
+    ) {
+        let block_id = block.id;
+        if self
+            .0
+            .send(ArchiveWriterCommand::AppendFinalBlock(
+                Box::new(block),
+                operations,
+            ))
+            .is_err()
+        {
+            warn!(
+                "could not archive final block {}: archive writer is stopped",
+                block_id
+            );
+        }
+    }
+}
+
+/// Owns the archive writer thread
+pub struct ArchiveWriter {
+    sender: Sender<ArchiveWriterCommand>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ArchiveWriter {
+    /// Starts the archive writer thread
+    ///
+    /// # Arguments
+    /// * `archive`: the archive to write to
+    /// * `channel_size`: number of final blocks that can be queued before the senders block
+    pub fn start(archive: Arc<ArchiveDB>, channel_size: usize) -> Self {
+        let (sender, receiver) = bounded(channel_size);
+        let handle = thread::Builder::new()
+            .name("archive writer".into())
+            .spawn(move || run(archive, receiver))
+            .expect("failed to spawn thread : archive writer");
+        ArchiveWriter {
+            sender,
+            handle: Some(handle),
+        }
+    }
+
+    /// Gets a handle to send final blocks to the writer thread
+    pub fn sender(&self) -> ArchiveWriterSender {
+        ArchiveWriterSender(self.sender.clone())
+    }
+
+    /// Stops the writer thread once the queued blocks are written
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            info!("stopping archive writer...");
+            let _ = self.sender.send(ArchiveWriterCommand::Stop);
+            handle
+                .join()
+                .expect("archive writer thread panicked on try to join");
+            info!("archive writer stopped");
+        }
+    }
+}
+
+impl Drop for ArchiveWriter {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Main loop of the archive writer thread
+fn run(archive: Arc<ArchiveDB>, receiver: Receiver<ArchiveWriterCommand>) {
+    while let Ok(ArchiveWriterCommand::AppendFinalBlock(block, operations)) = receiver.recv() {
+        archive.append_final_block(&block, &operations);
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Checkpoints of an ongoing bootstrap, allowing the client to resume it after a restart
+//! instead of downloading the whole final state again.
+//!
+//! A checkpoint contains the final state received so far, except for the ledger which is already on disk,
+//! along with the cursors to send to the server and the consensus blocks received so far.
+//! Resuming from a checkpoint against any server is then the same as resuming after a lost connection:
+//! the parts received after the checkpoint are sent again, and `final_state_changes`
+//! brings the state up to date with the server.
+
+use std::path::PathBuf;
+
+use massa_consensus_exports::bootstrapable_graph::{
+    BootstrapableGraph, BootstrapableGraphDeserializer, BootstrapableGraphSerializer,
+};
+use massa_serialization::{
+    DeserializeError, Deserializer, OptionDeserializer, OptionSerializer, Serializer,
+};
+use massa_time::MassaTime;
+use tracing::{info, warn};
+
+use crate::{
+    error::BootstrapError,
+    messages::{
+        BootstrapClientMessage, BootstrapClientMessageDeserializer,
+        BootstrapClientMessageSerializer,
+    },
+    settings::{BootstrapClientConfig, BootstrapServerMessageDeserializerArgs},
+    BootstrapConfig, GlobalBootstrapState,
+};
+
+/// Saves and restores the progress of the bootstrap client
+pub(crate) struct BootstrapCheckpoint {
+    /// path of the checkpoint file
+    path: PathBuf,
+    /// minimal time between two checkpoints
+    interval: MassaTime,
+    /// time of the last checkpoint
+    last_save: MassaTime,
+    message_serializer: BootstrapClientMessageSerializer,
+    message_deserializer: BootstrapClientMessageDeserializer,
+    graph_serializer: OptionSerializer<BootstrapableGraph, BootstrapableGraphSerializer>,
+    graph_deserializer: OptionDeserializer<BootstrapableGraph, BootstrapableGraphDeserializer>,
+}
+
+impl BootstrapCheckpoint {
+    /// Creates a new `BootstrapCheckpoint`
+    pub(crate) fn new(cfg: &BootstrapConfig) -> Result<Self, BootstrapError> {
+        let client_cfg: BootstrapClientConfig = cfg.into();
+        let args: BootstrapServerMessageDeserializerArgs = (&client_cfg).into();
+        Ok(Self {
+            path: cfg.bootstrap_checkpoint_path.clone(),
+            interval: cfg.bootstrap_checkpoint_interval,
+            last_save: MassaTime::now()?,
+            message_serializer: BootstrapClientMessageSerializer::new(),
+            message_deserializer: BootstrapClientMessageDeserializer::new(
+                cfg.thread_count,
+                cfg.max_datastore_key_length,
+                cfg.max_consensus_block_ids,
+            ),
+            graph_serializer: OptionSerializer::new(BootstrapableGraphSerializer::new()),
+            graph_deserializer: OptionDeserializer::new(BootstrapableGraphDeserializer::new(
+                (&args).into(),
+                args.max_bootstrap_blocks_length,
+            )),
+        })
+    }
+
+    /// Restores the final state and the consensus blocks of the checkpoint, if any.
+    /// Returns the message to send to the server to resume the bootstrap.
+    ///
+    /// An unreadable checkpoint is discarded, and the bootstrap starts from scratch.
+    pub(crate) fn load(
+        &self,
+        global_bootstrap_state: &mut GlobalBootstrapState,
+    ) -> Option<BootstrapClientMessage> {
+        if !self.path.is_file() {
+            return None;
+        }
+        match self.try_load(global_bootstrap_state) {
+            Ok(message) => Some(message),
+            Err(err) => {
+                warn!(
+                    "could not resume bootstrap from checkpoint {}: {}",
+                    self.path.display(),
+                    err
+                );
+                global_bootstrap_state.final_state.write().reset();
+                global_bootstrap_state.graph = None;
+                self.remove();
+                None
+            }
+        }
+    }
+
+    fn try_load(
+        &self,
+        global_bootstrap_state: &mut GlobalBootstrapState,
+    ) -> Result<BootstrapClientMessage, BootstrapError> {
+        let mut final_state = global_bootstrap_state.final_state.write();
+        let extra = final_state.load_bootstrap_checkpoint(&self.path)?;
+        let (rest, message) = self
+            .message_deserializer
+            .deserialize::<DeserializeError>(&extra)
+            .map_err(|err| BootstrapError::GeneralError(format!("{}", err)))?;
+        let (_, graph) = self
+            .graph_deserializer
+            .deserialize::<DeserializeError>(rest)
+            .map_err(|err| BootstrapError::GeneralError(format!("{}", err)))?;
+        let BootstrapClientMessage::AskBootstrapPart { last_slot: Some(slot), .. } = &message else {
+            return Err(BootstrapError::GeneralError(
+                "the checkpoint does not resume the final state streaming".to_string(),
+            ));
+        };
+        info!("Resuming bootstrap from slot {}", slot);
+        global_bootstrap_state.graph = graph;
+        Ok(message)
+    }
+
+    /// Saves the progress of the bootstrap if the last checkpoint is older than the checkpoint interval
+    pub(crate) fn save_if_due(
+        &mut self,
+        next_bootstrap_message: &BootstrapClientMessage,
+        global_bootstrap_state: &GlobalBootstrapState,
+    ) -> Result<(), BootstrapError> {
+        if MassaTime::now()?.saturating_sub(self.last_save) < self.interval {
+            return Ok(());
+        }
+        self.save(next_bootstrap_message, global_bootstrap_state)
+    }
+
+    /// Saves the progress of the bootstrap, if the final state is being streamed
+    pub(crate) fn save(
+        &mut self,
+        next_bootstrap_message: &BootstrapClientMessage,
+        global_bootstrap_state: &GlobalBootstrapState,
+    ) -> Result<(), BootstrapError> {
+        // nothing to resume before the first part or after the final state streaming
+        if !matches!(
+            next_bootstrap_message,
+            BootstrapClientMessage::AskBootstrapPart {
+                last_slot: Some(_),
+                ..
+            }
+        ) {
+            return Ok(());
+        }
+        let mut extra = Vec::new();
+        self.message_serializer
+            .serialize(next_bootstrap_message, &mut extra)?;
+        self.graph_serializer
+            .serialize(&global_bootstrap_state.graph, &mut extra)?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        global_bootstrap_state
+            .final_state
+            .read()
+            .save_bootstrap_checkpoint(&self.path, &extra)?;
+        self.last_save = MassaTime::now()?;
+        Ok(())
+    }
+
+    /// Removes the checkpoint, once the bootstrap is over or has to start from scratch
+    pub(crate) fn remove(&self) {
+        if self.path.is_file() {
+            if let Err(err) = std::fs::remove_file(&self.path) {
+                warn!(
+                    "could not remove bootstrap checkpoint {}: {}",
+                    self.path.display(),
+                    err
+                );
+            }
+        }
+    }
+}
@@ -3,7 +3,12 @@ use std::{collections::HashSet, net::SocketAddr, sync::Arc, time::Duration};
 
 use massa_final_state::FinalState;
 use massa_logging::massa_trace;
-use massa_models::{node::NodeId, streaming_step::StreamingStep, version::Version};
+use massa_metrics::{
+    BOOTSTRAP_ATTEMPTS, BOOTSTRAP_FINISHED, BOOTSTRAP_PARTS, BOOTSTRAP_SLOT_PERIOD,
+};
+use massa_models::{
+    compression::Compression, node::NodeId, streaming_step::StreamingStep, version::Version,
+};
 use massa_signature::PublicKey;
 use massa_time::MassaTime;
 use massa_versioning_worker::versioning::{MipStore, MipStoreRaw};
@@ -13,13 +18,15 @@ use rand::{
     SeedableRng,
 };
 use tokio::time::sleep;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, info_span, warn, Instrument};
 
 use crate::{
+    checkpoint::BootstrapCheckpoint,
     client_binder::BootstrapClientBinder,
     error::BootstrapError,
     establisher::{BSConnector, Duplex},
     messages::{BootstrapClientMessage, BootstrapServerMessage},
+    parallel::bootstrap_from_servers,
     settings::IpType,
     BootstrapConfig, GlobalBootstrapState,
 };
@@ -27,11 +34,13 @@ use crate::{
 /// This function will send the starting point to receive a stream of the ledger and will receive and process each part until receive a `BootstrapServerMessage::FinalStateFinished` message from the server.
 /// `next_bootstrap_message` passed as parameter must be `BootstrapClientMessage::AskFinalStatePart` enum variant.
 /// `next_bootstrap_message` will be updated after receiving each part so that in case of connection lost we can restart from the last message we processed.
+/// It is also saved to `checkpoint` from time to time, along with the state received so far, so that the bootstrap can resume after a restart.
 async fn stream_final_state_and_consensus<D: Duplex>(
     cfg: &BootstrapConfig,
     client: &mut BootstrapClientBinder<D>,
     next_bootstrap_message: &mut BootstrapClientMessage,
     global_bootstrap_state: &mut GlobalBootstrapState,
+    checkpoint: &mut BootstrapCheckpoint,
 ) -> Result<(), BootstrapError> {
     if let BootstrapClientMessage::AskBootstrapPart { .. } = &next_bootstrap_message {
         match tokio::time::timeout(
@@ -68,6 +77,7 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                     pos_cycle_part,
                     pos_credits_part,
                     exec_ops_part,
+                    exec_de_part,
                     final_state_changes,
                     consensus_part,
                     consensus_outdated_ids,
@@ -93,6 +103,9 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                     let last_ops_step = write_final_state
                         .executed_ops
                         .set_executed_ops_part(exec_ops_part);
+                    let last_de_step = write_final_state
+                        .executed_denunciations
+                        .set_executed_denunciations_part(exec_de_part);
                     for (changes_slot, changes) in final_state_changes.iter() {
                         write_final_state.ledger.apply_changes(
                             changes.ledger_changes.clone(),
@@ -114,8 +127,17 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                                 .executed_ops
                                 .apply_changes(changes.executed_ops_changes.clone(), *changes_slot);
                         }
+                        if !changes.executed_denunciations_changes.is_empty() {
+                            write_final_state.executed_denunciations.apply_changes(
+                                changes.executed_denunciations_changes.clone(),
+                                *changes_slot,
+                            );
+                        }
                     }
                     write_final_state.slot = slot;
+                    drop(write_final_state);
+                    BOOTSTRAP_PARTS.inc();
+                    BOOTSTRAP_SLOT_PERIOD.set(slot.period as i64);
 
                     // Set consensus blocks
                     if let Some(graph) = global_bootstrap_state.graph.as_mut() {
@@ -148,6 +170,7 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                         last_cycle_step,
                         last_credits_step,
                         last_ops_step,
+                        last_de_step,
                         last_consensus_step,
                         send_last_start_period: false,
                     };
@@ -161,6 +184,12 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                         "client final state slot changes length: {}",
                         final_state_changes.len()
                     );
+
+                    if let Err(err) =
+                        checkpoint.save_if_due(next_bootstrap_message, global_bootstrap_state)
+                    {
+                        warn!("could not save bootstrap checkpoint: {}", err);
+                    }
                 }
                 BootstrapServerMessage::BootstrapFinished => {
                     info!("State bootstrap complete");
@@ -177,11 +206,13 @@ async fn stream_final_state_and_consensus<D: Duplex>(
                         last_cycle_step: StreamingStep::Started,
                         last_credits_step: StreamingStep::Started,
                         last_ops_step: StreamingStep::Started,
+                        last_de_step: StreamingStep::Started,
                         last_consensus_step: StreamingStep::Started,
                         send_last_start_period: true,
                     };
                     let mut write_final_state = global_bootstrap_state.final_state.write();
                     write_final_state.reset();
+                    checkpoint.remove();
                     return Err(BootstrapError::GeneralError(String::from("Slot too old")));
                 }
                 BootstrapServerMessage::BootstrapError { error } => {
@@ -211,10 +242,29 @@ async fn bootstrap_from_server<D: Duplex>(
     client: &mut BootstrapClientBinder<D>,
     next_bootstrap_message: &mut BootstrapClientMessage,
     global_bootstrap_state: &mut GlobalBootstrapState,
+    checkpoint: &mut BootstrapCheckpoint,
     our_version: Version,
 ) -> Result<(), BootstrapError> {
     massa_trace!("bootstrap.lib.bootstrap_from_server", {});
+    bootstrap_handshake(cfg, client, our_version).await?;
+    bootstrap_messages(
+        cfg,
+        client,
+        next_bootstrap_message,
+        global_bootstrap_state,
+        checkpoint,
+    )
+    .await
+}
 
+/// Opens a bootstrap session: checks that the server did not refuse it,
+/// then performs the handshake and checks the ping, version and clock of the server.
+/// needs to be CANCELLABLE
+pub(crate) async fn bootstrap_handshake<D: Duplex>(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<D>,
+    our_version: Version,
+) -> Result<(), BootstrapError> {
     // read error (if sent by the server)
     // client.next() is not cancel-safe but we drop the whole client object if cancelled => it's OK
     match tokio::time::timeout(cfg.read_error_timeout.into(), client.next()).await {
@@ -256,32 +306,34 @@ async fn bootstrap_from_server<D: Duplex>(
 
     // First, clock and version.
     // client.next() is not cancel-safe but we drop the whole client object if cancelled => it's OK
-    let server_time = match tokio::time::timeout(cfg.read_timeout.into(), client.next()).await {
-        Err(_) => {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::TimedOut,
-                "bootstrap clock sync read timed out",
-            )
-            .into())
-        }
-        Ok(Err(e)) => return Err(e),
-        Ok(Ok(BootstrapServerMessage::BootstrapTime {
-            server_time,
-            version,
-        })) => {
-            if !our_version.is_compatible(&version) {
-                return Err(BootstrapError::IncompatibleVersionError(format!(
-                    "remote is running incompatible version: {} (local node version: {})",
-                    version, our_version
-                )));
+    let (server_time, server_compression) =
+        match tokio::time::timeout(cfg.read_timeout.into(), client.next()).await {
+            Err(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "bootstrap clock sync read timed out",
+                )
+                .into())
             }
-            server_time
-        }
-        Ok(Ok(BootstrapServerMessage::BootstrapError { error })) => {
-            return Err(BootstrapError::ReceivedError(error))
-        }
-        Ok(Ok(msg)) => return Err(BootstrapError::UnexpectedServerMessage(msg)),
-    };
+            Ok(Err(e)) => return Err(e),
+            Ok(Ok(BootstrapServerMessage::BootstrapTime {
+                server_time,
+                version,
+                compression,
+            })) => {
+                if !our_version.is_compatible(&version) {
+                    return Err(BootstrapError::IncompatibleVersionError(format!(
+                        "remote is running incompatible version: {} (local node version: {})",
+                        version, our_version
+                    )));
+                }
+                (server_time, compression)
+            }
+            Ok(Ok(BootstrapServerMessage::BootstrapError { error })) => {
+                return Err(BootstrapError::ReceivedError(error))
+            }
+            Ok(Ok(msg)) => return Err(BootstrapError::UnexpectedServerMessage(msg)),
+        };
 
     // get the time of reception
     let recv_time = MassaTime::now()?;
@@ -310,6 +362,37 @@ async fn bootstrap_from_server<D: Duplex>(
         return Err(BootstrapError::ClockError(message));
     }
 
+    // ask for compressed messages if the server supports it
+    if server_compression == Some(Compression::Zstd) {
+        let compression = Compression::Zstd;
+        match tokio::time::timeout(
+            cfg.write_timeout.into(),
+            client.send(&BootstrapClientMessage::EnableCompression { compression }),
+        )
+        .await
+        {
+            Err(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "bootstrap enable compression send timed out",
+            )
+            .into()),
+            Ok(Err(e)) => Err(e),
+            Ok(Ok(_)) => Ok(()),
+        }?;
+        client.set_compression(Some(compression));
+    }
+    Ok(())
+}
+
+/// Asks data to the server depending on the last message sent, until the bootstrap succeeds
+/// needs to be CANCELLABLE
+pub(crate) async fn bootstrap_messages<D: Duplex>(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<D>,
+    next_bootstrap_message: &mut BootstrapClientMessage,
+    global_bootstrap_state: &mut GlobalBootstrapState,
+    checkpoint: &mut BootstrapCheckpoint,
+) -> Result<(), BootstrapError> {
     let write_timeout: std::time::Duration = cfg.write_timeout.into();
     // Loop to ask data to the server depending on the last message we sent
     loop {
@@ -320,6 +403,7 @@ async fn bootstrap_from_server<D: Duplex>(
                     client,
                     next_bootstrap_message,
                     global_bootstrap_state,
+                    checkpoint,
                 )
                 .await?;
             }
@@ -379,13 +463,21 @@ async fn bootstrap_from_server<D: Duplex>(
             BootstrapClientMessage::BootstrapError { error: _ } => {
                 panic!("The next message to send shouldn't be BootstrapError");
             }
+            BootstrapClientMessage::AskBootstrapLedgerRange { .. }
+            | BootstrapClientMessage::AskFinalStateHash { .. }
+            | BootstrapClientMessage::EnableCompression { .. } => {
+                return Err(BootstrapError::GeneralError(format!(
+                    "Try to bootstrap from a single server but the message to send to the server was {:#?}",
+                    next_bootstrap_message
+                )));
+            }
         };
     }
     info!("Successful bootstrap");
     Ok(())
 }
 
-async fn send_client_message<D: Duplex>(
+pub(crate) async fn send_client_message<D: Duplex>(
     message_to_send: &BootstrapClientMessage,
     client: &mut BootstrapClientBinder<D>,
     write_timeout: Duration,
@@ -404,7 +496,7 @@ async fn send_client_message<D: Duplex>(
     }
 }
 
-fn connect_to_server(
+pub(crate) fn connect_to_server(
     connector: &mut impl BSConnector,
     bootstrap_config: &BootstrapConfig,
     addr: &SocketAddr,
@@ -526,11 +618,58 @@ pub async fn get_state(
             last_cycle_step: StreamingStep::Started,
             last_credits_step: StreamingStep::Started,
             last_ops_step: StreamingStep::Started,
+            last_de_step: StreamingStep::Started,
             last_consensus_step: StreamingStep::Started,
             send_last_start_period: true,
         };
     let mut global_bootstrap_state = GlobalBootstrapState::new(final_state.clone());
 
+    // resume from the checkpoint of a previous bootstrap attempt, if any
+    let mut checkpoint = BootstrapCheckpoint::new(bootstrap_config)?;
+    let resumed = if let Some(message) = checkpoint.load(&mut global_bootstrap_state) {
+        next_bootstrap_message = message;
+        true
+    } else {
+        false
+    };
+
+    // bootstrap the ledger from several servers at once, unless resuming from a checkpoint
+    if !resumed && bootstrap_config.max_parallel_bootstrap_servers > 1 {
+        match bootstrap_from_servers(
+            bootstrap_config,
+            &mut connector,
+            &filtered_bootstrap_list,
+            &mut global_bootstrap_state,
+            &mut checkpoint,
+            version,
+        )
+        .instrument(info_span!("parallel_bootstrap_session"))
+        .await
+        {
+            Ok(()) => {
+                checkpoint.remove();
+                BOOTSTRAP_FINISHED.set(1);
+                return Ok(global_bootstrap_state);
+            }
+            Err(e) => {
+                warn!(
+                    "Could not bootstrap from several servers, falling back to a single server: {}",
+                    e
+                );
+                global_bootstrap_state.final_state.write().reset();
+                global_bootstrap_state.graph = None;
+                global_bootstrap_state.peers = None;
+                global_bootstrap_state.mip_store = None;
+                checkpoint.remove();
+                // the servers that were just used refuse new sessions from us until `per_ip_min_interval` is elapsed
+                let used_servers = bootstrap_config
+                    .max_parallel_bootstrap_servers
+                    .min(filtered_bootstrap_list.len());
+                filtered_bootstrap_list.rotate_left(used_servers);
+            }
+        }
+    }
+
     loop {
         for (addr, node_id) in filtered_bootstrap_list.iter() {
             if let Some(end) = end_timestamp {
@@ -539,6 +678,7 @@ pub async fn get_state(
                 }
             }
             info!("Start bootstrapping from {}", addr);
+            BOOTSTRAP_ATTEMPTS.inc();
             match connect_to_server(
                 &mut connector,
                 bootstrap_config,
@@ -546,17 +686,42 @@ pub async fn get_state(
                 &node_id.get_public_key(),
             ) {
                 Ok(mut client) => {
-                    match bootstrap_from_server(bootstrap_config, &mut client, &mut next_bootstrap_message, &mut global_bootstrap_state,version)
-                    .await  // cancellable
-                    {
-                        Err(BootstrapError::ReceivedError(error)) => warn!("Error received from bootstrap server: {}", error),
+                    let result = bootstrap_from_server(
+                        bootstrap_config,
+                        &mut client,
+                        &mut next_bootstrap_message,
+                        &mut global_bootstrap_state,
+                        &mut checkpoint,
+                        version,
+                    )
+                    .instrument(info_span!("bootstrap_session", server = %addr))
+                    .await; // cancellable
+                    if result.is_err() {
+                        if let Err(err) =
+                            checkpoint.save(&next_bootstrap_message, &global_bootstrap_state)
+                        {
+                            warn!("could not save bootstrap checkpoint: {}", err);
+                        }
+                    }
+                    match result {
+                        Err(BootstrapError::ReceivedError(error)) => {
+                            warn!("Error received from bootstrap server: {}", error)
+                        }
                         Err(e) => {
                             warn!("Error while bootstrapping: {}", e);
                             // We allow unused result because we don't care if an error is thrown when sending the error message to the server we will close the socket anyway.
-                            let _ = tokio::time::timeout(bootstrap_config.write_error_timeout.into(), client.send(&BootstrapClientMessage::BootstrapError { error: e.to_string() })).await;
+                            let _ = tokio::time::timeout(
+                                bootstrap_config.write_error_timeout.into(),
+                                client.send(&BootstrapClientMessage::BootstrapError {
+                                    error: e.to_string(),
+                                }),
+                            )
+                            .await;
                         }
                         Ok(()) => {
-                            return Ok(global_bootstrap_state)
+                            checkpoint.remove();
+                            BOOTSTRAP_FINISHED.set(1);
+                            return Ok(global_bootstrap_state);
                         }
                     }
                 }
@@ -10,11 +10,13 @@ use crate::settings::BootstrapClientConfig;
 use async_speed_limit::clock::StandardClock;
 use async_speed_limit::{Limiter, Resource};
 use massa_hash::{Hash, HASH_SIZE_BYTES};
+use massa_models::compression::{decode_frame, Compression};
 use massa_models::serialization::{DeserializeMinBEInt, SerializeMinBEInt};
 use massa_models::version::{Version, VersionSerializer};
 use massa_serialization::{DeserializeError, Deserializer, Serializer};
 use massa_signature::{PublicKey, Signature, SIGNATURE_SIZE_BYTES};
 use rand::{rngs::StdRng, RngCore, SeedableRng};
+use std::borrow::Cow;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
 
@@ -27,6 +29,7 @@ pub struct BootstrapClientBinder<D: Duplex> {
     prev_message: Option<Hash>,
     version_serializer: VersionSerializer,
     cfg: BootstrapClientConfig,
+    compression: Option<Compression>,
 }
 
 impl<D: Duplex> BootstrapClientBinder<D> {
@@ -45,9 +48,15 @@ impl<D: Duplex> BootstrapClientBinder<D> {
             prev_message: None,
             version_serializer: VersionSerializer::new(),
             cfg,
+            compression: None,
         }
     }
 
+    /// Expects compressed messages from the server from now on
+    pub fn set_compression(&mut self, compression: Option<Compression>) {
+        self.compression = compression;
+    }
+
     /// Performs a handshake. Should be called after connection
     /// NOT cancel-safe
     pub async fn handshake(&mut self, version: Version) -> Result<(), BootstrapError> {
@@ -97,8 +106,9 @@ impl<D: Duplex> BootstrapClientBinder<D> {
                     .await?;
                 let msg_hash = Hash::compute_from(&sig_msg_bytes);
                 self.remote_pubkey.verify_signature(&msg_hash, &sig)?;
+                let msg_bytes = self.decompress(&sig_msg_bytes[HASH_SIZE_BYTES..])?;
                 let (_, msg) = message_deserializer
-                    .deserialize::<DeserializeError>(&sig_msg_bytes[HASH_SIZE_BYTES..])
+                    .deserialize::<DeserializeError>(&msg_bytes)
                     .map_err(|err| BootstrapError::GeneralError(format!("{}", err)))?;
                 msg
             } else {
@@ -107,8 +117,9 @@ impl<D: Duplex> BootstrapClientBinder<D> {
                 self.duplex.read_exact(&mut sig_msg_bytes[..]).await?;
                 let msg_hash = Hash::compute_from(&sig_msg_bytes);
                 self.remote_pubkey.verify_signature(&msg_hash, &sig)?;
+                let msg_bytes = self.decompress(&sig_msg_bytes[..])?;
                 let (_, msg) = message_deserializer
-                    .deserialize::<DeserializeError>(&sig_msg_bytes[..])
+                    .deserialize::<DeserializeError>(&msg_bytes)
                     .map_err(|err| BootstrapError::GeneralError(format!("{}", err)))?;
                 msg
             }
@@ -116,6 +127,18 @@ impl<D: Duplex> BootstrapClientBinder<D> {
         Ok(message)
     }
 
+    /// Extracts the message from a frame whose signature was checked.
+    /// Decompressed messages are bounded by the same size as raw ones.
+    fn decompress<'a>(&self, frame: &'a [u8]) -> Result<Cow<'a, [u8]>, BootstrapError> {
+        match self.compression {
+            Some(_) => Ok(decode_frame(
+                frame,
+                self.cfg.max_bootstrap_message_size as usize,
+            )?),
+            None => Ok(Cow::Borrowed(frame)),
+        }
+    }
+
     #[allow(dead_code)]
     /// Send a message to the bootstrap server
     pub async fn send(&mut self, msg: &BootstrapClientMessage) -> Result<(), BootstrapError> {
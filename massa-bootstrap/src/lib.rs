@@ -19,11 +19,13 @@ use massa_network_exports::BootstrapPeers;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
+mod checkpoint;
 mod client;
 mod client_binder;
 mod error;
 mod establisher;
 mod messages;
+mod parallel;
 mod server;
 mod server_binder;
 mod settings;
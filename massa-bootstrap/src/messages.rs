@@ -8,10 +8,17 @@ use massa_async_pool::{
 use massa_consensus_exports::bootstrapable_graph::{
     BootstrapableGraph, BootstrapableGraphDeserializer, BootstrapableGraphSerializer,
 };
-use massa_executed_ops::{ExecutedOpsDeserializer, ExecutedOpsSerializer};
+use massa_executed_ops::{
+    ExecutedDenunciationsDeserializer, ExecutedDenunciationsSerializer, ExecutedOpsDeserializer,
+    ExecutedOpsSerializer,
+};
 use massa_final_state::{StateChanges, StateChangesDeserializer, StateChangesSerializer};
+use massa_hash::{Hash, HashDeserializer, HashSerializer};
 use massa_ledger_exports::{Key as LedgerKey, KeyDeserializer, KeySerializer};
+use massa_models::address::{Address, AddressDeserializer, AddressSerializer};
 use massa_models::block_id::{BlockId, BlockIdDeserializer, BlockIdSerializer};
+use massa_models::compression::{Compression, CompressionDeserializer, CompressionSerializer};
+use massa_models::denunciation::DenunciationId;
 use massa_models::operation::OperationId;
 use massa_models::prehash::PreHashSet;
 use massa_models::serialization::{
@@ -58,6 +65,9 @@ pub enum BootstrapServerMessage {
         server_time: MassaTime,
         /// The version of the bootstrap server.
         version: Version,
+        /// Frame compression supported by the server, if any.
+        /// Sent last so that clients unaware of it ignore it.
+        compression: Option<Compression>,
     },
     /// Bootstrap peers
     BootstrapPeers {
@@ -78,6 +88,8 @@ pub enum BootstrapServerMessage {
         pos_credits_part: DeferredCredits,
         /// Part of the executed operations
         exec_ops_part: BTreeMap<Slot, PreHashSet<OperationId>>,
+        /// Part of the executed denunciations
+        exec_de_part: BTreeMap<Slot, PreHashSet<DenunciationId>>,
         /// Ledger change for addresses inferior to `address` of the client message until the actual slot.
         final_state_changes: Vec<(Slot, StateChanges)>,
         /// Part of the consensus graph
@@ -101,6 +113,12 @@ pub enum BootstrapServerMessage {
         /// Error message
         error: String,
     },
+    /// Hash of the final state at the asked slot
+    BootstrapFinalStateHash {
+        /// Hash of the final state, `None` if the server is not at the asked slot yet
+        /// or no longer remembers it
+        final_state_hash: Option<Hash>,
+    },
 }
 
 #[derive(IntoPrimitive, Debug, Eq, PartialEq, TryFromPrimitive)]
@@ -113,6 +131,7 @@ enum MessageServerTypeId {
     SlotTooOld = 4u32,
     BootstrapError = 5u32,
     MipStore = 6u32,
+    FinalStateHash = 7u32,
 }
 
 /// Serializer for `BootstrapServerMessage`
@@ -121,6 +140,7 @@ pub struct BootstrapServerMessageSerializer {
     u64_serializer: U64VarIntSerializer,
     time_serializer: MassaTimeSerializer,
     version_serializer: VersionSerializer,
+    compression_serializer: CompressionSerializer,
     peers_serializer: BootstrapPeersSerializer,
     state_changes_serializer: StateChangesSerializer,
     bootstrapable_graph_serializer: BootstrapableGraphSerializer,
@@ -131,8 +151,10 @@ pub struct BootstrapServerMessageSerializer {
     opt_pos_cycle_serializer: OptionSerializer<CycleInfo, CycleInfoSerializer>,
     pos_credits_serializer: DeferredCreditsSerializer,
     exec_ops_serializer: ExecutedOpsSerializer,
+    exec_de_serializer: ExecutedDenunciationsSerializer,
     opt_last_start_period_serializer: OptionSerializer<u64, U64VarIntSerializer>,
     store_serializer: MipStoreRawSerializer,
+    opt_hash_serializer: OptionSerializer<Hash, HashSerializer>,
 }
 
 impl Default for BootstrapServerMessageSerializer {
@@ -149,6 +171,7 @@ impl BootstrapServerMessageSerializer {
             u64_serializer: U64VarIntSerializer::new(),
             time_serializer: MassaTimeSerializer::new(),
             version_serializer: VersionSerializer::new(),
+            compression_serializer: CompressionSerializer::new(),
             peers_serializer: BootstrapPeersSerializer::new(),
             state_changes_serializer: StateChangesSerializer::new(),
             bootstrapable_graph_serializer: BootstrapableGraphSerializer::new(),
@@ -159,8 +182,10 @@ impl BootstrapServerMessageSerializer {
             opt_pos_cycle_serializer: OptionSerializer::new(CycleInfoSerializer::new()),
             pos_credits_serializer: DeferredCreditsSerializer::new(),
             exec_ops_serializer: ExecutedOpsSerializer::new(),
+            exec_de_serializer: ExecutedDenunciationsSerializer::new(),
             opt_last_start_period_serializer: OptionSerializer::new(U64VarIntSerializer::new()),
             store_serializer: MipStoreRawSerializer::new(),
+            opt_hash_serializer: OptionSerializer::new(HashSerializer::new()),
         }
     }
 }
@@ -178,6 +203,7 @@ impl Serializer<BootstrapServerMessage> for BootstrapServerMessageSerializer {
     /// let bootstrap_server_message = BootstrapServerMessage::BootstrapTime {
     ///    server_time: MassaTime::from(0),
     ///    version: Version::from_str("TEST.1.10").unwrap(),
+    ///    compression: None,
     /// };
     /// let mut message_serialized = Vec::new();
     /// message_serializer.serialize(&bootstrap_server_message, &mut message_serialized).unwrap();
@@ -191,11 +217,13 @@ impl Serializer<BootstrapServerMessage> for BootstrapServerMessageSerializer {
             BootstrapServerMessage::BootstrapTime {
                 server_time,
                 version,
+                compression,
             } => {
                 self.u32_serializer
                     .serialize(&u32::from(MessageServerTypeId::BootstrapTime), buffer)?;
                 self.time_serializer.serialize(server_time, buffer)?;
                 self.version_serializer.serialize(version, buffer)?;
+                self.compression_serializer.serialize(compression, buffer)?;
             }
             BootstrapServerMessage::BootstrapPeers { peers } => {
                 self.u32_serializer
@@ -209,6 +237,7 @@ impl Serializer<BootstrapServerMessage> for BootstrapServerMessageSerializer {
                 pos_cycle_part,
                 pos_credits_part,
                 exec_ops_part,
+                exec_de_part,
                 final_state_changes,
                 consensus_part,
                 consensus_outdated_ids,
@@ -232,6 +261,8 @@ impl Serializer<BootstrapServerMessage> for BootstrapServerMessageSerializer {
                     .serialize(pos_credits_part, buffer)?;
                 // executed operations
                 self.exec_ops_serializer.serialize(exec_ops_part, buffer)?;
+                // executed denunciations
+                self.exec_de_serializer.serialize(exec_de_part, buffer)?;
                 // changes length
                 self.u64_serializer
                     .serialize(&(final_state_changes.len() as u64), buffer)?;
@@ -275,6 +306,12 @@ impl Serializer<BootstrapServerMessage> for BootstrapServerMessageSerializer {
                 )?;
                 buffer.extend(error.as_bytes())
             }
+            BootstrapServerMessage::BootstrapFinalStateHash { final_state_hash } => {
+                self.u32_serializer
+                    .serialize(&u32::from(MessageServerTypeId::FinalStateHash), buffer)?;
+                self.opt_hash_serializer
+                    .serialize(final_state_hash, buffer)?;
+            }
         }
         Ok(())
     }
@@ -285,6 +322,7 @@ pub struct BootstrapServerMessageDeserializer {
     message_id_deserializer: U32VarIntDeserializer,
     time_deserializer: MassaTimeDeserializer,
     version_deserializer: VersionDeserializer,
+    compression_deserializer: CompressionDeserializer,
     peers_deserializer: BootstrapPeersDeserializer,
     length_state_changes: U64VarIntDeserializer,
     state_changes_deserializer: StateChangesDeserializer,
@@ -297,8 +335,10 @@ pub struct BootstrapServerMessageDeserializer {
     opt_pos_cycle_deserializer: OptionDeserializer<CycleInfo, CycleInfoDeserializer>,
     pos_credits_deserializer: DeferredCreditsDeserializer,
     exec_ops_deserializer: ExecutedOpsDeserializer,
+    exec_de_deserializer: ExecutedDenunciationsDeserializer,
     opt_last_start_period_deserializer: OptionDeserializer<u64, U64VarIntDeserializer>,
     store_deserializer: MipStoreRawDeserializer,
+    opt_hash_deserializer: OptionDeserializer<Hash, HashDeserializer>,
 }
 
 impl BootstrapServerMessageDeserializer {
@@ -312,6 +352,7 @@ impl BootstrapServerMessageDeserializer {
                 Included(MassaTime::from_millis(u64::MAX)),
             )),
             version_deserializer: VersionDeserializer::new(),
+            compression_deserializer: CompressionDeserializer::new(),
             peers_deserializer: BootstrapPeersDeserializer::new(args.max_advertise_length),
             state_changes_deserializer: StateChangesDeserializer::new(
                 args.thread_count,
@@ -325,6 +366,7 @@ impl BootstrapServerMessageDeserializer {
                 args.max_production_stats_length,
                 args.max_credits_length,
                 args.max_ops_changes_length,
+                args.max_denunciation_changes_length,
             ),
             length_state_changes: U64VarIntDeserializer::new(
                 Included(0),
@@ -371,6 +413,11 @@ impl BootstrapServerMessageDeserializer {
                 args.max_executed_ops_length,
                 args.max_operations_per_block as u64,
             ),
+            exec_de_deserializer: ExecutedDenunciationsDeserializer::new(
+                args.thread_count,
+                args.max_executed_denunciations_length,
+                args.max_denunciations_per_block_header as u64,
+            ),
             opt_last_start_period_deserializer: OptionDeserializer::new(
                 U64VarIntDeserializer::new(Included(u64::MIN), Included(u64::MAX)),
             ),
@@ -378,6 +425,7 @@ impl BootstrapServerMessageDeserializer {
                 args.mip_store_stats_block_considered,
                 args.mip_store_stats_counters_max,
             ),
+            opt_hash_deserializer: OptionDeserializer::new(HashDeserializer::new()),
         }
     }
 }
@@ -403,12 +451,15 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
     ///     max_datastore_entry_count: 1000, max_bootstrap_error_length: 1000, max_changes_slot_count: 1000,
     ///     max_rolls_length: 1000, max_production_stats_length: 1000, max_credits_length: 1000,
     ///     max_executed_ops_length: 1000, max_ops_changes_length: 1000,
+    ///     max_denunciations_per_block_header: 128, max_executed_denunciations_length: 1000,
+    ///     max_denunciation_changes_length: 1000,
     ///     mip_store_stats_block_considered: 100, mip_store_stats_counters_max: 10
     /// };
     /// let message_deserializer = BootstrapServerMessageDeserializer::new(args);
     /// let bootstrap_server_message = BootstrapServerMessage::BootstrapTime {
     ///    server_time: MassaTime::from(0),
     ///    version: Version::from_str("TEST.1.10").unwrap(),
+    ///    compression: None,
     /// };
     /// let mut message_serialized = Vec::new();
     /// message_serializer.serialize(&bootstrap_server_message, &mut message_serialized).unwrap();
@@ -417,6 +468,7 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
     ///     BootstrapServerMessage::BootstrapTime {
     ///        server_time,
     ///        version,
+    ///        ..
     ///    } => {
     ///     assert_eq!(server_time, MassaTime::from(0));
     ///     assert_eq!(version, Version::from_str("TEST.1.10").unwrap());
@@ -450,11 +502,15 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
                     context("Failed version deserialization", |input| {
                         self.version_deserializer.deserialize(input)
                     }),
+                    context("Failed compression deserialization", |input| {
+                        self.compression_deserializer.deserialize(input)
+                    }),
                 ))
                 .map(
-                    |(server_time, version)| BootstrapServerMessage::BootstrapTime {
+                    |(server_time, version, compression)| BootstrapServerMessage::BootstrapTime {
                         server_time,
                         version,
+                        compression,
                     },
                 )
                 .parse(input),
@@ -489,6 +545,9 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
                     context("Failed exec_ops_part deserialization", |input| {
                         self.exec_ops_deserializer.deserialize(input)
                     }),
+                    context("Failed exec_de_part deserialization", |input| {
+                        self.exec_de_deserializer.deserialize(input)
+                    }),
                     context(
                         "Failed final_state_changes deserialization",
                         length_count(
@@ -519,6 +578,7 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
                         pos_cycle_part,
                         pos_credits_part,
                         exec_ops_part,
+                        exec_de_part,
                         final_state_changes,
                         consensus_part,
                         consensus_outdated_ids,
@@ -531,6 +591,7 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
                             pos_cycle_part,
                             pos_credits_part,
                             exec_ops_part,
+                            exec_de_part,
                             final_state_changes,
                             consensus_part,
                             consensus_outdated_ids,
@@ -553,6 +614,17 @@ impl Deserializer<BootstrapServerMessage> for BootstrapServerMessageDeserializer
                     error: String::from_utf8_lossy(error).into_owned(),
                 })
                 .parse(input),
+                MessageServerTypeId::FinalStateHash => {
+                    context("Failed final_state_hash deserialization", |input| {
+                        self.opt_hash_deserializer.deserialize(input)
+                    })
+                    .map(
+                        |final_state_hash| BootstrapServerMessage::BootstrapFinalStateHash {
+                            final_state_hash,
+                        },
+                    )
+                    .parse(input)
+                }
             }
         })
         .parse(buffer)
@@ -579,6 +651,8 @@ pub enum BootstrapClientMessage {
         last_credits_step: StreamingStep<Slot>,
         /// Last received executed operation associated slot
         last_ops_step: StreamingStep<Slot>,
+        /// Last received executed denunciation associated slot
+        last_de_step: StreamingStep<Slot>,
         /// Last received consensus block slot
         last_consensus_step: StreamingStep<PreHashSet<BlockId>>,
         /// Should be true only for the first part, false later
@@ -586,6 +660,28 @@ pub enum BootstrapClientMessage {
     },
     /// Ask for mip store
     AskBootstrapMipStore,
+    /// Ask for a range of the ledger, along with the ledger changes
+    AskBootstrapLedgerRange {
+        /// Slot we are attached to for changes
+        last_slot: Option<Slot>,
+        /// Last received ledger key
+        last_ledger_step: StreamingStep<LedgerKey>,
+        /// First address of the range, `None` to start at the beginning of the ledger
+        ledger_start: Option<Address>,
+        /// Address at which the range ends, `None` to go up to the end of the ledger
+        ledger_end: Option<Address>,
+    },
+    /// Ask for the hash of the final state at a given slot
+    AskFinalStateHash {
+        /// Slot of the final state
+        slot: Slot,
+    },
+    /// Ask the server to compress the messages it sends from now on.
+    /// Only sent if the server advertised that compression. Not answered.
+    EnableCompression {
+        /// Compression to use
+        compression: Compression,
+    },
     /// Bootstrap error
     BootstrapError {
         /// Error message
@@ -603,6 +699,9 @@ enum MessageClientTypeId {
     BootstrapError = 2u32,
     BootstrapSuccess = 3u32,
     AskBootstrapMipStore = 4u32,
+    AskLedgerRange = 5u32,
+    AskFinalStateHash = 6u32,
+    EnableCompression = 7u32,
 }
 
 /// Serializer for `BootstrapClientMessage`
@@ -618,6 +717,9 @@ pub struct BootstrapClientMessageSerializer {
         PreHashSetSerializer<BlockId, BlockIdSerializer>,
     >,
     bool_serializer: BoolSerializer,
+    opt_slot_serializer: OptionSerializer<Slot, SlotSerializer>,
+    opt_address_serializer: OptionSerializer<Address, AddressSerializer>,
+    compression_serializer: CompressionSerializer,
 }
 
 impl BootstrapClientMessageSerializer {
@@ -634,6 +736,9 @@ impl BootstrapClientMessageSerializer {
                 BlockIdSerializer::new(),
             )),
             bool_serializer: BoolSerializer::new(),
+            opt_slot_serializer: OptionSerializer::new(SlotSerializer::new()),
+            opt_address_serializer: OptionSerializer::new(AddressSerializer::new()),
+            compression_serializer: CompressionSerializer::new(),
         }
     }
 }
@@ -675,6 +780,7 @@ impl Serializer<BootstrapClientMessage> for BootstrapClientMessageSerializer {
                 last_cycle_step,
                 last_credits_step,
                 last_ops_step,
+                last_de_step,
                 last_consensus_step,
                 send_last_start_period,
             } => {
@@ -691,6 +797,7 @@ impl Serializer<BootstrapClientMessage> for BootstrapClientMessageSerializer {
                     self.slot_step_serializer
                         .serialize(last_credits_step, buffer)?;
                     self.slot_step_serializer.serialize(last_ops_step, buffer)?;
+                    self.slot_step_serializer.serialize(last_de_step, buffer)?;
                     self.block_ids_step_serializer
                         .serialize(last_consensus_step, buffer)?;
                     self.bool_serializer
@@ -718,6 +825,32 @@ impl Serializer<BootstrapClientMessage> for BootstrapClientMessageSerializer {
                     buffer,
                 )?;
             }
+            BootstrapClientMessage::AskBootstrapLedgerRange {
+                last_slot,
+                last_ledger_step,
+                ledger_start,
+                ledger_end,
+            } => {
+                self.u32_serializer
+                    .serialize(&u32::from(MessageClientTypeId::AskLedgerRange), buffer)?;
+                self.opt_slot_serializer.serialize(last_slot, buffer)?;
+                self.ledger_step_serializer
+                    .serialize(last_ledger_step, buffer)?;
+                self.opt_address_serializer
+                    .serialize(ledger_start, buffer)?;
+                self.opt_address_serializer.serialize(ledger_end, buffer)?;
+            }
+            BootstrapClientMessage::AskFinalStateHash { slot } => {
+                self.u32_serializer
+                    .serialize(&u32::from(MessageClientTypeId::AskFinalStateHash), buffer)?;
+                self.slot_serializer.serialize(slot, buffer)?;
+            }
+            BootstrapClientMessage::EnableCompression { compression } => {
+                self.u32_serializer
+                    .serialize(&u32::from(MessageClientTypeId::EnableCompression), buffer)?;
+                self.compression_serializer
+                    .serialize(&Some(*compression), buffer)?;
+            }
         }
         Ok(())
     }
@@ -737,6 +870,9 @@ pub struct BootstrapClientMessageDeserializer {
         PreHashSetDeserializer<BlockId, BlockIdDeserializer>,
     >,
     bool_deserializer: BoolDeserializer,
+    opt_slot_deserializer: OptionDeserializer<Slot, SlotDeserializer>,
+    opt_address_deserializer: OptionDeserializer<Address, AddressDeserializer>,
+    compression_deserializer: CompressionDeserializer,
 }
 
 impl BootstrapClientMessageDeserializer {
@@ -776,6 +912,12 @@ impl BootstrapClientMessageDeserializer {
                 ),
             ),
             bool_deserializer: BoolDeserializer::new(),
+            opt_slot_deserializer: OptionDeserializer::new(SlotDeserializer::new(
+                (Included(0), Included(u64::MAX)),
+                (Included(0), Excluded(thread_count)),
+            )),
+            opt_address_deserializer: OptionDeserializer::new(AddressDeserializer::new()),
+            compression_deserializer: CompressionDeserializer::new(),
         }
     }
 }
@@ -836,6 +978,7 @@ impl Deserializer<BootstrapClientMessage> for BootstrapClientMessageDeserializer
                                 last_cycle_step: StreamingStep::Started,
                                 last_credits_step: StreamingStep::Started,
                                 last_ops_step: StreamingStep::Started,
+                                last_de_step: StreamingStep::Started,
                                 last_consensus_step: StreamingStep::Started,
                                 send_last_start_period: true,
                             },
@@ -860,6 +1003,9 @@ impl Deserializer<BootstrapClientMessage> for BootstrapClientMessageDeserializer
                             context("Failed last_ops_step deserialization", |input| {
                                 self.slot_step_deserializer.deserialize(input)
                             }),
+                            context("Failed last_de_step deserialization", |input| {
+                                self.slot_step_deserializer.deserialize(input)
+                            }),
                             context("Failed last_consensus_step deserialization", |input| {
                                 self.block_ids_step_deserializer.deserialize(input)
                             }),
@@ -875,6 +1021,7 @@ impl Deserializer<BootstrapClientMessage> for BootstrapClientMessageDeserializer
                                 last_cycle_step,
                                 last_credits_step,
                                 last_ops_step,
+                                last_de_step,
                                 last_consensus_step,
                                 send_last_start_period,
                             )| {
@@ -885,6 +1032,7 @@ impl Deserializer<BootstrapClientMessage> for BootstrapClientMessageDeserializer
                                     last_cycle_step,
                                     last_credits_step,
                                     last_ops_step,
+                                    last_de_step,
                                     last_consensus_step,
                                     send_last_start_period,
                                 }
@@ -906,6 +1054,52 @@ impl Deserializer<BootstrapClientMessage> for BootstrapClientMessageDeserializer
                 MessageClientTypeId::BootstrapSuccess => {
                     Ok((input, BootstrapClientMessage::BootstrapSuccess))
                 }
+                MessageClientTypeId::AskLedgerRange => tuple((
+                    context("Failed last_slot deserialization", |input| {
+                        self.opt_slot_deserializer.deserialize(input)
+                    }),
+                    context("Failed last_ledger_step deserialization", |input| {
+                        self.ledger_step_deserializer.deserialize(input)
+                    }),
+                    context("Failed ledger_start deserialization", |input| {
+                        self.opt_address_deserializer.deserialize(input)
+                    }),
+                    context("Failed ledger_end deserialization", |input| {
+                        self.opt_address_deserializer.deserialize(input)
+                    }),
+                ))
+                .map(|(last_slot, last_ledger_step, ledger_start, ledger_end)| {
+                    BootstrapClientMessage::AskBootstrapLedgerRange {
+                        last_slot,
+                        last_ledger_step,
+                        ledger_start,
+                        ledger_end,
+                    }
+                })
+                .parse(input),
+                MessageClientTypeId::AskFinalStateHash => {
+                    context("Failed slot deserialization", |input| {
+                        self.slot_deserializer.deserialize(input)
+                    })
+                    .map(|slot| BootstrapClientMessage::AskFinalStateHash { slot })
+                    .parse(input)
+                }
+                MessageClientTypeId::EnableCompression => {
+                    context("Failed compression deserialization", |input| {
+                        match self.compression_deserializer.deserialize(input)? {
+                            (rest, Some(compression)) => Ok((
+                                rest,
+                                BootstrapClientMessage::EnableCompression { compression },
+                            )),
+                            // unknown or missing compression
+                            (_, None) => Err(nom::Err::Error(ParseError::from_error_kind(
+                                input,
+                                nom::error::ErrorKind::Verify,
+                            ))),
+                        }
+                    })
+                    .parse(input)
+                }
             }
         })
         .parse(buffer)
@@ -0,0 +1,474 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Bootstrap from several servers at once.
+//!
+//! The ledger is split into disjoint ranges of addresses, each streamed from a different server
+//! along with the changes of its addresses. The ranges that are received first keep following
+//! the changes of their server until every range is received.
+//!
+//! The rest of the final state and the consensus blocks are then streamed from the server
+//! whose range is attached to the latest slot, starting from the oldest slot of the ranges:
+//! its ledger changes bring every range to the same slot.
+//! Finally, the hash of the assembled final state is checked against the hash computed by the other servers.
+//!
+//! Servers that do not know about ledger ranges close the session,
+//! and the client falls back to bootstrapping from a single server.
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use massa_final_state::FinalState;
+use massa_hash::{Hash, HASH_SIZE_BYTES};
+use massa_ledger_exports::{Key as LedgerKey, LedgerChanges};
+use massa_metrics::{BOOTSTRAP_ATTEMPTS, BOOTSTRAP_PARTS};
+use massa_models::{
+    address::{Address, SCAddress, UserAddress},
+    node::NodeId,
+    slot::Slot,
+    streaming_step::StreamingStep,
+    version::Version,
+};
+use parking_lot::RwLock;
+use tokio::{net::TcpStream, sync::watch, task::JoinSet, time::sleep};
+use tracing::{info, info_span, warn, Instrument};
+
+use crate::{
+    checkpoint::BootstrapCheckpoint,
+    client::{bootstrap_handshake, bootstrap_messages, connect_to_server, send_client_message},
+    client_binder::BootstrapClientBinder,
+    error::BootstrapError,
+    establisher::BSConnector,
+    messages::{BootstrapClientMessage, BootstrapServerMessage},
+    BootstrapConfig, GlobalBootstrapState,
+};
+
+/// Delay between two requests of the changes of a received range, or of the final state hash.
+/// It has to stay well below the duration of the changes history kept by the servers,
+/// and below their read timeout so that the sessions stay open.
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Number of times a server is asked for the final state hash at the slot of the assembled state
+const HASH_CHECK_ATTEMPTS: u32 = 10;
+
+/// Number of buckets the ledger is split in: the first byte of the hash of user addresses,
+/// then the first byte of the hash of smart contract addresses
+const ADDRESS_BUCKETS: usize = 512;
+
+/// Range of the ledger streamed from a server
+#[derive(Clone, Debug)]
+pub(crate) struct LedgerRange {
+    /// first address of the range, `None` for the beginning of the ledger
+    pub(crate) start: Option<Address>,
+    /// address at which the range ends, `None` for the end of the ledger
+    pub(crate) end: Option<Address>,
+}
+
+impl LedgerRange {
+    /// Whether the address belongs to the range
+    pub(crate) fn contains(&self, address: &Address) -> bool {
+        self.start.map_or(true, |start| *address >= start)
+            && self.end.map_or(true, |end| *address < end)
+    }
+}
+
+/// Splits the ledger in `count` contiguous ranges, of similar sizes if the addresses are uniformly distributed
+pub(crate) fn get_ledger_ranges(count: usize) -> Vec<LedgerRange> {
+    let boundaries: Vec<Option<Address>> = std::iter::once(None)
+        .chain((1..count).map(|index| Some(get_bucket_address(index * ADDRESS_BUCKETS / count))))
+        .chain(std::iter::once(None))
+        .collect();
+    boundaries
+        .windows(2)
+        .map(|bounds| LedgerRange {
+            start: bounds[0],
+            end: bounds[1],
+        })
+        .collect()
+}
+
+/// Gets the first address of a bucket
+fn get_bucket_address(bucket: usize) -> Address {
+    let mut bytes = [0u8; HASH_SIZE_BYTES];
+    bytes[0] = (bucket % 256) as u8;
+    let hash = Hash::from_bytes(&bytes);
+    if bucket < 256 {
+        Address::User(UserAddress(hash))
+    } else {
+        Address::SC(SCAddress(hash))
+    }
+}
+
+/// Gets the state from several bootstrap servers at once.
+///
+/// At least two servers of `bootstrap_list` have to accept the session.
+/// On error, the state received so far is left as is and has to be reset before bootstrapping again.
+/// needs to be CANCELLABLE
+pub(crate) async fn bootstrap_from_servers(
+    cfg: &BootstrapConfig,
+    connector: &mut impl BSConnector,
+    bootstrap_list: &[(SocketAddr, NodeId)],
+    global_bootstrap_state: &mut GlobalBootstrapState,
+    checkpoint: &mut BootstrapCheckpoint,
+    our_version: Version,
+) -> Result<(), BootstrapError> {
+    // open the sessions
+    let mut clients = Vec::new();
+    for (addr, node_id) in bootstrap_list {
+        if clients.len() >= cfg.max_parallel_bootstrap_servers {
+            break;
+        }
+        let mut client = match connect_to_server(connector, cfg, addr, &node_id.get_public_key()) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("Error while connecting to bootstrap server {}: {}", addr, e);
+                continue;
+            }
+        };
+        match bootstrap_handshake(cfg, &mut client, our_version).await {
+            Ok(()) => clients.push((*addr, client)),
+            Err(e) => warn!(
+                "Error while opening a session with bootstrap server {}: {}",
+                addr, e
+            ),
+        }
+    }
+    if clients.len() < 2 {
+        return Err(BootstrapError::GeneralError(
+            "not enough bootstrap servers available to bootstrap from several servers".into(),
+        ));
+    }
+
+    // stream the ledger ranges
+    let range_count = clients.len();
+    info!(
+        "Start bootstrapping the ledger from {} servers",
+        range_count
+    );
+    BOOTSTRAP_ATTEMPTS.inc();
+    let received_ranges = Arc::new(AtomicUsize::new(0));
+    let mut range_tasks = JoinSet::new();
+    for ((addr, mut client), range) in clients.into_iter().zip(get_ledger_ranges(range_count)) {
+        let cfg = cfg.clone();
+        let final_state = global_bootstrap_state.final_state.clone();
+        let received_ranges = received_ranges.clone();
+        // spawned tasks don't inherit the current span
+        let span = info_span!("bootstrap_ledger_range", server = %addr);
+        range_tasks.spawn(
+            async move {
+                let result = bootstrap_ledger_range(
+                    &cfg,
+                    &mut client,
+                    &final_state,
+                    &range,
+                    &received_ranges,
+                    range_count,
+                )
+                .await;
+                (addr, client, result)
+            }
+            .instrument(span),
+        );
+    }
+    let mut sessions = Vec::with_capacity(range_count);
+    while let Some(joined) = range_tasks.join_next().await {
+        let (addr, client, result) = joined.map_err(|err| {
+            BootstrapError::GeneralError(format!("ledger range task failed: {}", err))
+        })?;
+        let slot = result.map_err(|err| {
+            BootstrapError::GeneralError(format!(
+                "could not bootstrap a ledger range from {}: {}",
+                addr, err
+            ))
+        })?;
+        sessions.push((addr, client, slot));
+    }
+
+    // the ledger changes of the server that is the most advanced bring every range to its slot
+    sessions.sort_by_key(|(_, _, slot)| std::cmp::Reverse(*slot));
+    let first_slot = sessions
+        .iter()
+        .map(|(_, _, slot)| *slot)
+        .min()
+        .expect("there are at least two sessions");
+    let (addr, mut client, _) = sessions.remove(0);
+
+    // keep the other sessions open, to check the final state hash once it is assembled
+    let (assembled_state_tx, assembled_state_rx) = watch::channel(None);
+    let mut hash_tasks = JoinSet::new();
+    for (addr, mut client, _) in sessions {
+        let cfg = cfg.clone();
+        let assembled_state_rx = assembled_state_rx.clone();
+        hash_tasks.spawn(async move {
+            let result =
+                check_final_state_hash(&cfg, &mut client, assembled_state_rx, first_slot).await;
+            (addr, result)
+        });
+    }
+
+    info!(
+        "Ledger received from {} servers, bootstrapping the rest of the state from {}",
+        range_count, addr
+    );
+    let mut next_bootstrap_message = BootstrapClientMessage::AskBootstrapPart {
+        last_slot: Some(first_slot),
+        last_ledger_step: StreamingStep::Finished(None),
+        last_pool_step: StreamingStep::Started,
+        last_cycle_step: StreamingStep::Started,
+        last_credits_step: StreamingStep::Started,
+        last_ops_step: StreamingStep::Started,
+        last_de_step: StreamingStep::Started,
+        last_consensus_step: StreamingStep::Started,
+        send_last_start_period: true,
+    };
+    bootstrap_messages(
+        cfg,
+        &mut client,
+        &mut next_bootstrap_message,
+        global_bootstrap_state,
+        checkpoint,
+    )
+    .await?;
+
+    // cross-check the assembled final state with the other servers
+    let assembled_state = {
+        let mut final_state = global_bootstrap_state.final_state.write();
+        let slot = final_state.slot;
+        final_state.ledger.set_initial_slot(slot);
+        final_state.compute_state_hash_at_slot(slot);
+        (slot, final_state.final_state_hash)
+    };
+    // a receiver is kept above, so the sending cannot fail
+    let _ = assembled_state_tx.send(Some(assembled_state));
+    while let Some(joined) = hash_tasks.join_next().await {
+        let (addr, result) = joined.map_err(|err| {
+            BootstrapError::GeneralError(format!("final state hash task failed: {}", err))
+        })?;
+        result.map_err(|err| {
+            BootstrapError::GeneralError(format!(
+                "could not check the final state hash with {}: {}",
+                addr, err
+            ))
+        })?;
+    }
+    info!(
+        "Final state hash {} at slot {} confirmed by {} servers",
+        assembled_state.1, assembled_state.0, range_count
+    );
+    Ok(())
+}
+
+/// Streams a range of the ledger from a server, then keeps following the changes of the range
+/// until every range is received.
+/// Returns the slot the range is attached to.
+async fn bootstrap_ledger_range(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<TcpStream>,
+    final_state: &Arc<RwLock<FinalState>>,
+    range: &LedgerRange,
+    received_ranges: &AtomicUsize,
+    range_count: usize,
+) -> Result<Slot, BootstrapError> {
+    let mut last_slot = stream_ledger_range(
+        cfg,
+        client,
+        final_state,
+        range,
+        None,
+        StreamingStep::Started,
+    )
+    .await?;
+    received_ranges.fetch_add(1, Ordering::SeqCst);
+    while received_ranges.load(Ordering::SeqCst) < range_count {
+        sleep(FOLLOW_INTERVAL).await;
+        last_slot = stream_ledger_range(
+            cfg,
+            client,
+            final_state,
+            range,
+            Some(last_slot),
+            StreamingStep::Finished(None),
+        )
+        .await?;
+    }
+    Ok(last_slot)
+}
+
+/// Asks a range of the ledger to a server, and applies the received entries and the changes of the range
+/// until the server has nothing more to send.
+/// Returns the slot the range is attached to.
+async fn stream_ledger_range(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<TcpStream>,
+    final_state: &Arc<RwLock<FinalState>>,
+    range: &LedgerRange,
+    mut last_slot: Option<Slot>,
+    last_ledger_step: StreamingStep<LedgerKey>,
+) -> Result<Slot, BootstrapError> {
+    let message = BootstrapClientMessage::AskBootstrapLedgerRange {
+        last_slot,
+        last_ledger_step,
+        ledger_start: range.start,
+        ledger_end: range.end,
+    };
+    match tokio::time::timeout(cfg.write_timeout.into(), client.send(&message)).await {
+        Err(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "bootstrap ask ledger range send timed out",
+        )
+        .into()),
+        Ok(Err(e)) => Err(e),
+        Ok(Ok(_)) => Ok(()),
+    }?;
+    loop {
+        let msg = match tokio::time::timeout(cfg.read_timeout.into(), client.next()).await {
+            Err(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "ledger range bootstrap read timed out",
+                )
+                .into());
+            }
+            Ok(Err(e)) => return Err(e),
+            Ok(Ok(msg)) => msg,
+        };
+        match msg {
+            BootstrapServerMessage::BootstrapPart {
+                slot,
+                ledger_part,
+                final_state_changes,
+                ..
+            } => {
+                let mut write_final_state = final_state.write();
+                // a server cannot overwrite the entries of the other ranges
+                write_final_state.ledger.set_ledger_range_part(
+                    ledger_part,
+                    range.start.as_ref(),
+                    range.end.as_ref(),
+                )?;
+                // the changes of the other ranges are applied by their own stream
+                for (changes_slot, changes) in final_state_changes {
+                    let ledger_changes = LedgerChanges(
+                        changes
+                            .ledger_changes
+                            .0
+                            .into_iter()
+                            .filter(|(address, _)| range.contains(address))
+                            .collect(),
+                    );
+                    write_final_state
+                        .ledger
+                        .apply_changes(ledger_changes, changes_slot, None);
+                }
+                last_slot = Some(slot);
+                BOOTSTRAP_PARTS.inc();
+            }
+            BootstrapServerMessage::BootstrapFinished => {
+                return last_slot.ok_or_else(|| {
+                    BootstrapError::GeneralError(
+                        "the ledger range is not attached to a slot".to_string(),
+                    )
+                });
+            }
+            BootstrapServerMessage::SlotTooOld => {
+                return Err(BootstrapError::GeneralError(String::from("Slot too old")));
+            }
+            BootstrapServerMessage::BootstrapError { error } => {
+                return Err(BootstrapError::ReceivedError(error));
+            }
+            other => return Err(BootstrapError::UnexpectedServerMessage(other)),
+        }
+    }
+}
+
+/// Keeps the session with a server open until the final state is assembled,
+/// then checks that the server computed the same final state hash at the slot of the assembled state
+async fn check_final_state_hash(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<TcpStream>,
+    mut assembled_state: watch::Receiver<Option<(Slot, Hash)>>,
+    keepalive_slot: Slot,
+) -> Result<(), BootstrapError> {
+    let (slot, hash) = loop {
+        let assembled = *assembled_state.borrow();
+        if let Some(assembled) = assembled {
+            break assembled;
+        }
+        match tokio::time::timeout(FOLLOW_INTERVAL, assembled_state.changed()).await {
+            // keep the session alive
+            Err(_) => {
+                ask_final_state_hash(cfg, client, keepalive_slot).await?;
+            }
+            Ok(Err(_)) => {
+                return Err(BootstrapError::GeneralError(
+                    "the final state was not assembled".to_string(),
+                ))
+            }
+            Ok(Ok(())) => {}
+        }
+    };
+
+    for _ in 0..HASH_CHECK_ATTEMPTS {
+        match ask_final_state_hash(cfg, client, slot).await? {
+            Some(server_hash) if server_hash == hash => {
+                match tokio::time::timeout(
+                    cfg.write_timeout.into(),
+                    client.send(&BootstrapClientMessage::BootstrapSuccess),
+                )
+                .await
+                {
+                    Err(_) => Err(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "send bootstrap success timed out",
+                    )
+                    .into()),
+                    Ok(Err(e)) => Err(e),
+                    Ok(Ok(_)) => Ok(()),
+                }?;
+                return Ok(());
+            }
+            Some(server_hash) => {
+                return Err(BootstrapError::GeneralError(format!(
+                    "final state hash mismatch at slot {}: {} was assembled but the server has {}",
+                    slot, hash, server_hash
+                )));
+            }
+            // the server is not at the slot yet
+            None => sleep(FOLLOW_INTERVAL).await,
+        }
+    }
+    Err(BootstrapError::GeneralError(format!(
+        "the server did not provide its final state hash at slot {}",
+        slot
+    )))
+}
+
+/// Asks the final state hash at a slot to a server
+async fn ask_final_state_hash(
+    cfg: &BootstrapConfig,
+    client: &mut BootstrapClientBinder<TcpStream>,
+    slot: Slot,
+) -> Result<Option<Hash>, BootstrapError> {
+    match send_client_message(
+        &BootstrapClientMessage::AskFinalStateHash { slot },
+        client,
+        cfg.write_timeout.into(),
+        cfg.read_timeout.into(),
+        "ask final state hash timed out",
+    )
+    .await?
+    {
+        BootstrapServerMessage::BootstrapFinalStateHash { final_state_hash } => {
+            Ok(final_state_hash)
+        }
+        BootstrapServerMessage::BootstrapError { error } => {
+            Err(BootstrapError::ReceivedError(error))
+        }
+        other => Err(BootstrapError::UnexpectedServerMessage(other)),
+    }
+}
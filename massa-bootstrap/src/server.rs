@@ -34,10 +34,11 @@ use massa_final_state::{FinalState, FinalStateError};
 use massa_ledger_exports::Key as LedgerKey;
 use massa_logging::massa_trace;
 use massa_models::{
-    block_id::BlockId, prehash::PreHashSet, slot::Slot, streaming_step::StreamingStep,
-    version::Version,
+    address::Address, block_id::BlockId, compression::Compression, prehash::PreHashSet, slot::Slot,
+    streaming_step::StreamingStep, version::Version,
 };
 use massa_network_exports::NetworkCommandSender;
+use massa_pos_exports::DeferredCredits;
 use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use massa_versioning_worker::versioning::MipStore;
@@ -54,7 +55,7 @@ use tokio::{
     net::TcpStream,
     runtime::{self, Handle, Runtime},
 };
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn};
 use white_black_list::*;
 
 use crate::{
@@ -502,6 +503,8 @@ fn run_bootstrap_session<D: Duplex + 'static>(
     mip_store: MipStore,
 ) {
     debug!("running bootstrap for peer {}", remote_addr);
+    // the session is run on this thread, which stays in its span until the end
+    let _span = info_span!("bootstrap_session", client = %remote_addr).entered();
     bs_loop_rt_handle.block_on(async move {
         let res = tokio::time::timeout(
             config.bootstrap_timeout.into(),
@@ -564,6 +567,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
     mut last_cycle_step: StreamingStep<u64>,
     mut last_credits_step: StreamingStep<Slot>,
     mut last_ops_step: StreamingStep<Slot>,
+    mut last_de_step: StreamingStep<Slot>,
     mut last_consensus_step: StreamingStep<PreHashSet<BlockId>>,
     mut send_last_start_period: bool,
     write_timeout: Duration,
@@ -581,6 +585,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
         let pos_cycle_part;
         let pos_credits_part;
         let exec_ops_part;
+        let exec_de_part;
         let final_state_changes;
         let last_start_period;
 
@@ -620,6 +625,11 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
                 .get_executed_ops_part(last_ops_step);
             exec_ops_part = ops_data;
 
+            let (de_data, new_de_step) = final_state_read
+                .executed_denunciations
+                .get_executed_denunciations_part(last_de_step);
+            exec_de_part = de_data;
+
             if let Some(slot) = last_slot && slot != final_state_read.slot {
                 if slot > final_state_read.slot {
                     return Err(BootstrapError::GeneralError(
@@ -633,6 +643,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
                     new_cycle_step,
                     new_credits_step,
                     new_ops_step,
+                    new_de_step,
                 ) {
                     Ok(data) => data,
                     Err(err) if matches!(err, FinalStateError::InvalidSlot(_)) => {
@@ -651,6 +662,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
             last_cycle_step = new_cycle_step;
             last_credits_step = new_credits_step;
             last_ops_step = new_ops_step;
+            last_de_step = new_de_step;
             last_slot = Some(final_state_read.slot);
             current_slot = final_state_read.slot;
             send_last_start_period = false;
@@ -678,6 +690,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
             && last_cycle_step.finished()
             && last_credits_step.finished()
             && last_ops_step.finished()
+            && last_de_step.finished()
         {
             StreamingStep::Finished(Some(current_slot))
         } else {
@@ -748,6 +761,7 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
                     pos_cycle_part,
                     pos_credits_part,
                     exec_ops_part,
+                    exec_de_part,
                     final_state_changes,
                     consensus_part,
                     consensus_outdated_ids,
@@ -768,6 +782,145 @@ pub async fn stream_bootstrap_information<D: Duplex + 'static>(
     Ok(())
 }
 
+/// Streams the ledger entries of the addresses in `[ledger_start, ledger_end)`, along with the ledger changes,
+/// to a client bootstrapping disjoint ranges of the ledger from several servers.
+///
+/// Once the range is streamed, the client asks for it again with a finished cursor
+/// to receive the changes of the following slots.
+pub async fn stream_ledger_range<D: Duplex + 'static>(
+    server: &mut BootstrapServerBinder<D>,
+    final_state: Arc<RwLock<FinalState>>,
+    mut last_slot: Option<Slot>,
+    mut last_ledger_step: StreamingStep<LedgerKey>,
+    ledger_start: Option<Address>,
+    ledger_end: Option<Address>,
+    write_timeout: Duration,
+) -> Result<(), BootstrapError> {
+    loop {
+        let current_slot;
+        let ledger_part;
+        let final_state_changes;
+        // the client needs at least one part to know the slot the range is attached to
+        let attached = last_slot.is_some();
+
+        let mut slot_too_old = false;
+
+        // Scope of the final state read
+        {
+            let final_state_read = final_state.read();
+
+            let (data, mut new_ledger_step) = final_state_read.ledger.get_ledger_range_part(
+                last_ledger_step.clone(),
+                ledger_start.as_ref(),
+                ledger_end.as_ref(),
+            )?;
+            // an empty range is finished right away
+            if data.is_empty() && matches!(new_ledger_step, StreamingStep::Started) {
+                new_ledger_step = StreamingStep::Finished(None);
+            }
+            ledger_part = data;
+
+            if let Some(slot) = last_slot && slot != final_state_read.slot {
+                if slot > final_state_read.slot {
+                    return Err(BootstrapError::GeneralError(
+                        "Bootstrap cursor set to future slot".to_string(),
+                    ));
+                }
+                // the other cursors are not started so only the ledger changes are retrieved
+                final_state_changes = match final_state_read.get_state_changes_part(
+                    slot,
+                    new_ledger_step.clone(),
+                    StreamingStep::Started,
+                    StreamingStep::Started,
+                    StreamingStep::Started,
+                    StreamingStep::Started,
+                    StreamingStep::Started,
+                ) {
+                    Ok(data) => data,
+                    Err(err) if matches!(err, FinalStateError::InvalidSlot(_)) => {
+                        slot_too_old = true;
+                        Vec::default()
+                    }
+                    Err(err) => return Err(BootstrapError::FinalStateError(err)),
+                };
+            } else {
+                final_state_changes = Vec::new();
+            }
+
+            // Update cursors for next turn
+            last_ledger_step = new_ledger_step;
+            last_slot = Some(final_state_read.slot);
+            current_slot = final_state_read.slot;
+        }
+
+        if slot_too_old {
+            match server
+                .send_msg(write_timeout, BootstrapServerMessage::SlotTooOld)
+                .await
+            {
+                Err(_) => Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "SlotTooOld message send timed out",
+                )
+                .into()),
+                Ok(Err(e)) => Err(e),
+                Ok(Ok(_)) => Ok(()),
+            }?;
+            return Ok(());
+        }
+
+        debug!("Ledger range bootstrap cursor: {:?}", last_ledger_step);
+
+        // If the range is streamed and its changes are up to date, exit
+        if attached && last_ledger_step.finished() && final_state_changes.is_empty() {
+            match server
+                .send_msg(write_timeout, BootstrapServerMessage::BootstrapFinished)
+                .await
+            {
+                Err(_) => Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "bootstrap ledger range finished send timed out",
+                )
+                .into()),
+                Ok(Err(e)) => Err(e),
+                Ok(Ok(_)) => Ok(()),
+            }?;
+            break;
+        }
+
+        match server
+            .send_msg(
+                write_timeout,
+                BootstrapServerMessage::BootstrapPart {
+                    slot: current_slot,
+                    ledger_part,
+                    async_pool_part: Default::default(),
+                    pos_cycle_part: None,
+                    pos_credits_part: DeferredCredits::new_without_hash(),
+                    exec_ops_part: Default::default(),
+                    exec_de_part: Default::default(),
+                    final_state_changes,
+                    consensus_part: BootstrapableGraph {
+                        final_blocks: Default::default(),
+                    },
+                    consensus_outdated_ids: PreHashSet::default(),
+                    last_start_period: None,
+                },
+            )
+            .await
+        {
+            Err(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "bootstrap ledger range part send timed out",
+            )
+            .into()),
+            Ok(Err(e)) => Err(e),
+            Ok(Ok(_)) => Ok(()),
+        }?;
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn manage_bootstrap<D: Duplex + 'static>(
     bootstrap_config: &BootstrapConfig,
@@ -818,6 +971,7 @@ async fn manage_bootstrap<D: Duplex + 'static>(
             BootstrapServerMessage::BootstrapTime {
                 server_time,
                 version,
+                compression: Some(Compression::Zstd),
             },
         )
         .await
@@ -864,6 +1018,7 @@ async fn manage_bootstrap<D: Duplex + 'static>(
                     last_cycle_step,
                     last_credits_step,
                     last_ops_step,
+                    last_de_step,
                     last_consensus_step,
                     send_last_start_period,
                 } => {
@@ -877,12 +1032,51 @@ async fn manage_bootstrap<D: Duplex + 'static>(
                         last_cycle_step,
                         last_credits_step,
                         last_ops_step,
+                        last_de_step,
                         last_consensus_step,
                         send_last_start_period,
                         write_timeout,
                     )
                     .await?;
                 }
+                BootstrapClientMessage::AskBootstrapLedgerRange {
+                    last_slot,
+                    last_ledger_step,
+                    ledger_start,
+                    ledger_end,
+                } => {
+                    stream_ledger_range(
+                        server,
+                        final_state.clone(),
+                        last_slot,
+                        last_ledger_step,
+                        ledger_start,
+                        ledger_end,
+                        write_timeout,
+                    )
+                    .await?;
+                }
+                BootstrapClientMessage::AskFinalStateHash { slot } => {
+                    let final_state_hash = final_state.read().get_final_state_hash_at_slot(slot);
+                    match server
+                        .send_msg(
+                            write_timeout,
+                            BootstrapServerMessage::BootstrapFinalStateHash { final_state_hash },
+                        )
+                        .await
+                    {
+                        Err(_) => Err(std::io::Error::new(
+                            std::io::ErrorKind::TimedOut,
+                            "bootstrap final state hash send timed out",
+                        )
+                        .into()),
+                        Ok(Err(e)) => Err(e),
+                        Ok(Ok(_)) => Ok(()),
+                    }?;
+                }
+                BootstrapClientMessage::EnableCompression { compression } => {
+                    server.set_compression(Some(compression));
+                }
                 BootstrapClientMessage::AskBootstrapMipStore => {
                     let vs = mip_store.0.read().to_owned();
                     match tokio::time::timeout(
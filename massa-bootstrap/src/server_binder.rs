@@ -11,6 +11,7 @@ use async_speed_limit::clock::StandardClock;
 use async_speed_limit::{Limiter, Resource};
 use massa_hash::Hash;
 use massa_hash::HASH_SIZE_BYTES;
+use massa_models::compression::{encode_frame, Compression};
 use massa_models::serialization::{DeserializeMinBEInt, SerializeMinBEInt};
 use massa_models::version::{Version, VersionDeserializer, VersionSerializer};
 use massa_serialization::{DeserializeError, Deserializer, Serializer};
@@ -39,6 +40,7 @@ pub struct BootstrapServerBinder<D: Duplex> {
     version_serializer: VersionSerializer,
     version_deserializer: VersionDeserializer,
     write_error_timeout: MassaTime,
+    compression: Option<Compression>,
 }
 
 impl<D: Duplex + 'static> BootstrapServerBinder<D> {
@@ -73,8 +75,14 @@ impl<D: Duplex + 'static> BootstrapServerBinder<D> {
             version_serializer: VersionSerializer::new(),
             version_deserializer: VersionDeserializer::new(),
             write_error_timeout,
+            compression: None,
         }
     }
+
+    /// Compresses the messages sent from now on, once the client asked for it
+    pub fn set_compression(&mut self, compression: Option<Compression>) {
+        self.compression = compression;
+    }
     /// Performs a handshake. Should be called after connection
     /// NOT cancel-safe
     /// MUST always be followed by a send of the `BootstrapMessage::BootstrapTime`
@@ -162,6 +170,10 @@ impl<D: Duplex + 'static> BootstrapServerBinder<D> {
         // serialize message
         let mut msg_bytes = Vec::new();
         BootstrapServerMessageSerializer::new().serialize(&msg, &mut msg_bytes)?;
+        // the signature covers the frame as sent, so that the client checks it before decompressing
+        if let Some(compression) = self.compression {
+            msg_bytes = encode_frame(compression, &msg_bytes)?;
+        }
         let msg_len: u32 = msg_bytes.len().try_into().map_err(|e| {
             BootstrapError::GeneralError(format!("bootstrap message too large to encode: {}", e))
         })?;
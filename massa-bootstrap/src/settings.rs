@@ -57,6 +57,12 @@ pub struct BootstrapConfig {
     pub keep_ledger: bool,
     /// Max simultaneous bootstraps
     pub max_simultaneous_bootstraps: u32,
+    /// Checkpoint allowing an interrupted bootstrap to resume after a restart
+    pub bootstrap_checkpoint_path: PathBuf,
+    /// Minimal time between two bootstrap checkpoints
+    pub bootstrap_checkpoint_interval: MassaTime,
+    /// Max number of servers the ledger is bootstrapped from at once, 1 to bootstrap from a single server
+    pub max_parallel_bootstrap_servers: usize,
     /// Minimum interval between two bootstrap attempts from a given IP
     pub per_ip_min_interval: MassaTime,
     /// Max size of the IP list
@@ -119,6 +125,12 @@ pub struct BootstrapConfig {
     pub max_executed_ops_length: u64,
     /// max executed ops changes
     pub max_ops_changes_length: u64,
+    /// max denunciations in a block header
+    pub max_denunciations_per_block_header: u32,
+    /// max executed denunciations
+    pub max_executed_denunciations_length: u64,
+    /// max executed denunciations changes
+    pub max_denunciation_changes_length: u64,
     /// consensus bootstrap part size
     pub consensus_bootstrap_part_size: u64,
     /// max number of consensus block ids when sending a bootstrap cursor from the client
@@ -171,6 +183,9 @@ pub struct BootstrapClientConfig {
     pub max_credits_length: u64,
     pub max_executed_ops_length: u64,
     pub max_ops_changes_length: u64,
+    pub max_denunciations_per_block_header: u32,
+    pub max_executed_denunciations_length: u64,
+    pub max_denunciation_changes_length: u64,
     pub mip_store_stats_block_considered: usize,
     pub mip_store_stats_counters_max: usize,
 }
@@ -200,6 +215,9 @@ pub struct BootstrapServerMessageDeserializerArgs {
     pub max_credits_length: u64,
     pub max_executed_ops_length: u64,
     pub max_ops_changes_length: u64,
+    pub max_denunciations_per_block_header: u32,
+    pub max_executed_denunciations_length: u64,
+    pub max_denunciation_changes_length: u64,
     pub mip_store_stats_block_considered: usize,
     pub mip_store_stats_counters_max: usize,
 }
@@ -212,6 +230,7 @@ impl From<&BootstrapServerMessageDeserializerArgs> for BlockDeserializerArgs {
             thread_count: value.thread_count,
             max_operations_per_block: value.max_operations_per_block,
             endorsement_count: value.endorsement_count,
+            max_denunciations_per_block_header: value.max_denunciations_per_block_header,
             last_start_period: None,
         }
     }
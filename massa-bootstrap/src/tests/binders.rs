@@ -6,15 +6,18 @@ use crate::{
     client_binder::BootstrapClientBinder, server_binder::BootstrapServerBinder,
     tests::tools::get_bootstrap_config, BootstrapPeers,
 };
+use massa_models::compression::Compression;
 use massa_models::config::{
     BOOTSTRAP_RANDOMNESS_SIZE_BYTES, CONSENSUS_BOOTSTRAP_PART_SIZE, ENDORSEMENT_COUNT,
     MAX_ADVERTISE_LENGTH, MAX_ASYNC_MESSAGE_DATA, MAX_ASYNC_POOL_LENGTH,
     MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_BOOTSTRAP_BLOCKS, MAX_BOOTSTRAP_ERROR_LENGTH,
     MAX_BOOTSTRAP_FINAL_STATE_PARTS_SIZE, MAX_BOOTSTRAP_MESSAGE_SIZE, MAX_DATASTORE_ENTRY_COUNT,
     MAX_DATASTORE_KEY_LENGTH, MAX_DATASTORE_VALUE_LENGTH, MAX_DEFERRED_CREDITS_LENGTH,
-    MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH, MAX_LEDGER_CHANGES_COUNT,
-    MAX_OPERATIONS_PER_BLOCK, MAX_PRODUCTION_STATS_LENGTH, MAX_ROLLS_COUNT_LENGTH,
-    MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX, THREAD_COUNT,
+    MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_DENUNCIATION_CHANGES_LENGTH,
+    MAX_EXECUTED_DENUNCIATIONS_LENGTH, MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH,
+    MAX_LEDGER_CHANGES_COUNT, MAX_OPERATIONS_PER_BLOCK, MAX_PRODUCTION_STATS_LENGTH,
+    MAX_ROLLS_COUNT_LENGTH, MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX,
+    THREAD_COUNT,
 };
 use massa_models::node::NodeId;
 use massa_models::version::Version;
@@ -56,6 +59,9 @@ impl<D: Duplex> BootstrapClientBinder<D> {
             max_credits_length: MAX_DEFERRED_CREDITS_LENGTH,
             max_executed_ops_length: MAX_EXECUTED_OPS_LENGTH,
             max_ops_changes_length: MAX_EXECUTED_OPS_CHANGES_LENGTH,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+            max_executed_denunciations_length: MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+            max_denunciation_changes_length: MAX_DENUNCIATION_CHANGES_LENGTH,
             mip_store_stats_block_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
             mip_store_stats_counters_max: MIP_STORE_STATS_COUNTERS_MAX,
         };
@@ -354,3 +360,94 @@ async fn test_binders_try_double_send_client_works() {
     server_thread.await.unwrap();
     client_thread.await.unwrap();
 }
+
+/// The server advertises compression, the client enables it and then receives a compressed message
+#[tokio::test]
+#[serial]
+async fn test_binders_compression() {
+    let (bootstrap_config, server_keypair): &(BootstrapConfig, KeyPair) = &BOOTSTRAP_CONFIG_KEYPAIR;
+
+    let server = tokio::net::TcpListener::bind("localhost:0").await.unwrap();
+    let client = tokio::net::TcpStream::connect(server.local_addr().unwrap())
+        .await
+        .unwrap();
+    let server = server.accept().await.unwrap();
+
+    let mut server = BootstrapServerBinder::new(
+        server.0,
+        server_keypair.clone(),
+        BootstrapSrvBindCfg {
+            max_bytes_read_write: f64::INFINITY,
+            max_bootstrap_message_size: MAX_BOOTSTRAP_MESSAGE_SIZE,
+            thread_count: THREAD_COUNT,
+            max_datastore_key_length: MAX_DATASTORE_KEY_LENGTH,
+            randomness_size_bytes: BOOTSTRAP_RANDOMNESS_SIZE_BYTES,
+            consensus_bootstrap_part_size: CONSENSUS_BOOTSTRAP_PART_SIZE,
+            write_error_timeout: MassaTime::from_millis(1000),
+        },
+    );
+    let mut client = BootstrapClientBinder::test_default(
+        client,
+        bootstrap_config.bootstrap_list[0].1.get_public_key(),
+    );
+    let error = "compressible error ".repeat(500);
+
+    let server_error = error.clone();
+    let server_thread = tokio::spawn(async move {
+        let version: Version = Version::from_str("TEST.1.10").unwrap();
+
+        server.handshake(version).await.unwrap();
+        server
+            .send(BootstrapServerMessage::BootstrapTime {
+                server_time: MassaTime::now().unwrap(),
+                version,
+                compression: Some(Compression::Zstd),
+            })
+            .await
+            .unwrap();
+
+        match server.next().await.unwrap() {
+            BootstrapClientMessage::EnableCompression { compression } => {
+                server.set_compression(Some(compression));
+            }
+            _ => panic!("Bad message receive: Expected an enable compression message"),
+        }
+
+        server
+            .send(BootstrapServerMessage::BootstrapError {
+                error: server_error,
+            })
+            .await
+            .unwrap();
+    });
+
+    let client_thread = tokio::spawn(async move {
+        let version: Version = Version::from_str("TEST.1.10").unwrap();
+
+        client.handshake(version).await.unwrap();
+        match client.next().await.unwrap() {
+            BootstrapServerMessage::BootstrapTime { compression, .. } => {
+                assert_eq!(compression, Some(Compression::Zstd));
+            }
+            _ => panic!("Bad message receive: Expected a bootstrap time message"),
+        }
+
+        client
+            .send(&BootstrapClientMessage::EnableCompression {
+                compression: Compression::Zstd,
+            })
+            .await
+            .unwrap();
+        client.set_compression(Some(Compression::Zstd));
+
+        match client.next().await.unwrap() {
+            BootstrapServerMessage::BootstrapError { error: received } => {
+                assert_eq!(received, error);
+            }
+            _ => panic!("Bad message receive: Expected an error message"),
+        }
+    });
+
+    server_thread.await.unwrap();
+    client_thread.await.unwrap();
+}
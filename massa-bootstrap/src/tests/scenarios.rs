@@ -3,44 +3,46 @@
 use super::{
     mock_establisher,
     tools::{
-        bridge_mock_streams, get_boot_state, get_peers, get_random_final_state_bootstrap,
-        get_random_ledger_changes, wait_network_command,
+        bridge_mock_streams, get_boot_state, get_final_state_config, get_peers,
+        get_random_final_state_bootstrap, get_random_ledger_changes, wait_network_command,
+        BASE_BOOTSTRAP_IP,
     },
 };
+use crate::checkpoint::BootstrapCheckpoint;
+use crate::parallel::get_ledger_ranges;
 use crate::tests::tools::{
-    get_random_async_pool_changes, get_random_executed_ops_changes, get_random_pos_changes,
+    get_random_async_pool_changes, get_random_executed_de_changes, get_random_executed_ops_changes,
+    get_random_pos_changes,
 };
-use crate::BootstrapConfig;
 use crate::{
     get_state, start_bootstrap_server,
     tests::tools::{assert_eq_bootstrap_graph, get_bootstrap_config},
 };
-use massa_async_pool::AsyncPoolConfig;
+use crate::{BootstrapClientMessage, BootstrapConfig, GlobalBootstrapState};
+use massa_async_pool::test_exports::assert_eq_async_pool_bootstrap_state;
 use massa_consensus_exports::{
     bootstrapable_graph::BootstrapableGraph,
     test_exports::{MockConsensusController, MockConsensusControllerMessage},
 };
-use massa_executed_ops::ExecutedOpsConfig;
 use massa_final_state::{
     test_exports::{assert_eq_final_state, assert_eq_final_state_hash},
-    FinalState, FinalStateConfig, StateChanges,
+    FinalState, StateChanges,
 };
 use massa_hash::{Hash, HASH_SIZE_BYTES};
-use massa_ledger_exports::LedgerConfig;
+use massa_ledger_exports::{Key as LedgerKey, KeyType};
 use massa_models::config::{MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX};
 use massa_models::{
-    address::Address, config::MAX_DATASTORE_VALUE_LENGTH, node::NodeId, slot::Slot,
-    streaming_step::StreamingStep, version::Version,
-};
-use massa_models::{
-    config::{
-        MAX_ASYNC_MESSAGE_DATA, MAX_ASYNC_POOL_LENGTH, MAX_DATASTORE_KEY_LENGTH, POS_SAVED_CYCLES,
-    },
+    address::{Address, SCAddress},
+    node::NodeId,
     prehash::PreHashSet,
+    slot::Slot,
+    streaming_step::StreamingStep,
+    version::Version,
 };
 use massa_network_exports::{NetworkCommand, NetworkCommandSender};
 use massa_pos_exports::{
-    test_exports::assert_eq_pos_selection, PoSConfig, PoSFinalState, SelectorConfig,
+    test_exports::{assert_eq_pos_selection, assert_eq_pos_state},
+    PoSFinalState, SelectorConfig,
 };
 use massa_pos_worker::start_selector_worker;
 use massa_signature::KeyPair;
@@ -52,6 +54,7 @@ use parking_lot::RwLock;
 use serial_test::serial;
 use std::{
     collections::HashMap,
+    net::SocketAddr,
     path::PathBuf,
     str::FromStr,
     sync::{atomic::Ordering, Arc},
@@ -98,37 +101,8 @@ async fn test_bootstrap_server() {
 
     // setup final state local config
     let temp_dir = TempDir::new().unwrap();
-    let final_state_local_config = FinalStateConfig {
-        ledger_config: LedgerConfig {
-            thread_count,
-            initial_ledger_path: "".into(),
-            disk_ledger_path: temp_dir.path().to_path_buf(),
-            max_key_length: MAX_DATASTORE_KEY_LENGTH,
-            max_ledger_part_size: 100_000,
-            max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
-        },
-        async_pool_config: AsyncPoolConfig {
-            thread_count,
-            max_length: MAX_ASYNC_POOL_LENGTH,
-            max_async_message_data: MAX_ASYNC_MESSAGE_DATA,
-            bootstrap_part_size: 100,
-        },
-        pos_config: PoSConfig {
-            periods_per_cycle,
-            thread_count,
-            cycle_history_length: POS_SAVED_CYCLES,
-            credits_bootstrap_part_size: 100,
-        },
-        executed_ops_config: ExecutedOpsConfig {
-            thread_count,
-            bootstrap_part_size: 10,
-        },
-        final_history_length: 100,
-        initial_seed_string: "".into(),
-        initial_rolls_path: "".into(),
-        thread_count,
-        periods_per_cycle,
-    };
+    let final_state_local_config =
+        get_final_state_config(thread_count, periods_per_cycle, temp_dir.path());
 
     // setup selector local config
     let selector_local_config = SelectorConfig {
@@ -315,6 +289,7 @@ async fn test_bootstrap_server() {
                 ledger_changes: get_random_ledger_changes(10),
                 async_pool_changes: get_random_async_pool_changes(10),
                 executed_ops_changes: get_random_executed_ops_changes(10),
+                executed_denunciations_changes: get_random_executed_de_changes(10),
             };
             final_write
                 .changes_history
@@ -397,3 +372,509 @@ async fn test_bootstrap_server() {
     server_selector_manager.stop();
     client_selector_manager.stop();
 }
+
+#[tokio::test]
+#[serial]
+async fn test_bootstrap_from_several_servers() {
+    let thread_count = 2;
+    let periods_per_cycle = 2;
+    let rolls_path = PathBuf::from_str("../massa-node/base_config/initial_rolls.json").unwrap();
+    let genesis_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let version = Version::from_str("TEST.1.10").unwrap();
+
+    // two servers, the client bootstraps the ledger from both of them
+    let server_keypairs = [KeyPair::generate(), KeyPair::generate()];
+    let mut bootstrap_config =
+        get_bootstrap_config(NodeId::new(server_keypairs[0].get_public_key()));
+    bootstrap_config.bootstrap_list.push((
+        SocketAddr::new(BASE_BOOTSTRAP_IP, 8070),
+        NodeId::new(server_keypairs[1].get_public_key()),
+    ));
+    bootstrap_config.max_parallel_bootstrap_servers = 2;
+
+    let mip_stats_cfg = MipStatsConfig {
+        block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+        counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+    };
+    let mip_store = MipStore::try_from(([], mip_stats_cfg)).unwrap();
+
+    // setup final state local config
+    let temp_dir = TempDir::new().unwrap();
+    let final_state_local_config =
+        get_final_state_config(thread_count, periods_per_cycle, temp_dir.path());
+
+    // start proof-of-stake selectors
+    let selector_local_config = SelectorConfig {
+        thread_count,
+        periods_per_cycle,
+        genesis_address,
+        ..Default::default()
+    };
+    let (mut server_selector_manager, server_selector_controller) =
+        start_selector_worker(selector_local_config.clone())
+            .expect("could not start server selector controller");
+    let (mut client_selector_manager, client_selector_controller) =
+        start_selector_worker(selector_local_config)
+            .expect("could not start client selector controller");
+
+    // both servers share the same final state, so that they compute the same final state hash
+    let final_state_server = Arc::new(RwLock::new(get_random_final_state_bootstrap(
+        PoSFinalState::new(
+            final_state_local_config.pos_config.clone(),
+            "",
+            &rolls_path,
+            server_selector_controller,
+            Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        )
+        .unwrap(),
+        final_state_local_config.clone(),
+    )));
+    {
+        let mut final_state_server_write = final_state_server.write();
+        let slot = final_state_server_write.slot;
+        final_state_server_write.compute_state_hash_at_slot(slot);
+    }
+    let final_state_client = Arc::new(RwLock::new(FinalState::create_final_state(
+        PoSFinalState::new(
+            final_state_local_config.pos_config.clone(),
+            "",
+            &rolls_path,
+            client_selector_controller,
+            Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        )
+        .unwrap(),
+        final_state_local_config,
+    )));
+
+    // start the bootstrap servers, any of them can be asked the rest of the state
+    let sent_graph = get_boot_state();
+    let mut bootstrap_managers = Vec::new();
+    let mut bootstrap_interfaces = HashMap::new();
+    for ((addr, _), keypair) in bootstrap_config.bootstrap_list.iter().zip(server_keypairs) {
+        let (consensus_controller, mut consensus_event_receiver) =
+            MockConsensusController::new_with_receiver();
+        let (network_cmd_tx, mut network_cmd_rx) = mpsc::channel::<NetworkCommand>(5);
+        let (mut mock_bs_listener, bootstrap_interface) = mock_establisher::new();
+        bootstrap_managers.push(
+            start_bootstrap_server::<TcpStream>(
+                consensus_controller,
+                NetworkCommandSender(network_cmd_tx),
+                final_state_server.clone(),
+                bootstrap_config.clone(),
+                mock_bs_listener.get_listener(addr).unwrap(),
+                keypair,
+                version,
+                mip_store.clone(),
+            )
+            .unwrap()
+            .unwrap(),
+        );
+        bootstrap_interfaces.insert(*addr, bootstrap_interface);
+
+        // send the peers when asked
+        tokio::spawn(async move {
+            while let Some(cmd) = network_cmd_rx.recv().await {
+                if let NetworkCommand::GetBootstrapPeers(response) = cmd {
+                    response.send(get_peers()).unwrap();
+                }
+            }
+        });
+
+        // send the consensus blocks in a first part, then finish the consensus streaming
+        let sent_graph = sent_graph.clone();
+        std::thread::spawn(move || loop {
+            consensus_event_receiver.wait_command(
+                MassaTime::from_millis(20_000),
+                |cmd| match &cmd {
+                    MockConsensusControllerMessage::GetBootstrapableGraph {
+                        cursor,
+                        response_tx,
+                        ..
+                    } => {
+                        if cursor == &StreamingStep::Started {
+                            response_tx
+                                .send(Ok((
+                                    sent_graph.clone(),
+                                    PreHashSet::default(),
+                                    StreamingStep::Ongoing(PreHashSet::default()),
+                                )))
+                                .unwrap();
+                        } else {
+                            response_tx
+                                .send(Ok((
+                                    BootstrapableGraph {
+                                        final_blocks: Vec::new(),
+                                    },
+                                    PreHashSet::default(),
+                                    StreamingStep::Finished(None),
+                                )))
+                                .unwrap();
+                        }
+                        Some(())
+                    }
+                    _ => None,
+                },
+            );
+        });
+    }
+
+    // launch the get_state process
+    let (mut mock_remote_connector, mut remote_interface) = mock_establisher::new();
+    let client_config = bootstrap_config.clone();
+    let final_state_client_clone = final_state_client.clone();
+    let get_state_h = tokio::spawn(async move {
+        get_state(
+            &client_config,
+            final_state_client_clone,
+            mock_remote_connector.get_connector(),
+            version,
+            MassaTime::now().unwrap().saturating_sub(1000.into()),
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+    });
+
+    // accept the connection attempts of the client, and bridge each of them to the server it targets
+    let (connection_tx, mut connection_rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        while let Ok((remote_rw, conn_addr, waker)) =
+            remote_interface.wait_connection_attempt_from_controller()
+        {
+            waker.store(true, Ordering::Relaxed);
+            if connection_tx.send((remote_rw, conn_addr)).is_err() {
+                break;
+            }
+        }
+    });
+    let mut connected_servers = Vec::new();
+    while connected_servers.len() < bootstrap_interfaces.len() {
+        let (remote_rw, conn_addr) = connection_rx
+            .recv()
+            .await
+            .expect("the client stopped connecting to the bootstrap servers");
+        assert!(
+            !connected_servers.contains(&conn_addr),
+            "client connected twice to the same bootstrap server"
+        );
+        connected_servers.push(conn_addr);
+        let remote_addr = std::net::SocketAddr::from_str("82.245.72.98:10000").unwrap(); // not checked
+        let bootstrap_bridge = tokio::time::timeout(
+            std::time::Duration::from_millis(1000),
+            bootstrap_interfaces[&conn_addr].connect_to_controller(&remote_addr),
+        )
+        .await
+        .expect("timeout while connecting to bootstrap")
+        .expect("could not connect to bootstrap");
+        bootstrap_bridge.set_nonblocking(true).unwrap();
+        let bootstrap_bridge = TcpStream::from_std(bootstrap_bridge).unwrap();
+        remote_rw.set_nonblocking(true).unwrap();
+        let remote_bridge = TcpStream::from_std(remote_rw).unwrap();
+        tokio::spawn(bridge_mock_streams(remote_bridge, bootstrap_bridge));
+    }
+
+    // wait for get_state
+    let bootstrap_res = get_state_h
+        .await
+        .expect("error while waiting for get_state to finish");
+
+    // the ledger ranges and the rest of the state were assembled into the same final state
+    assert_eq_final_state(&final_state_server.read(), &final_state_client.read());
+    assert_eq_final_state_hash(&final_state_server.read(), &final_state_client.read());
+    assert_eq!(
+        final_state_server.read().final_state_hash,
+        final_state_client.read().final_state_hash
+    );
+    assert_eq!(
+        get_peers().0,
+        bootstrap_res.peers.unwrap().0,
+        "mismatch between sent and received peers"
+    );
+    assert_eq_bootstrap_graph(&sent_graph, &bootstrap_res.graph.unwrap());
+
+    // stop bootstrap servers
+    for bootstrap_manager in bootstrap_managers {
+        bootstrap_manager
+            .stop()
+            .await
+            .expect("could not stop bootstrap server");
+    }
+
+    // stop selector controllers
+    server_selector_manager.stop();
+    client_selector_manager.stop();
+}
+
+#[test]
+#[serial]
+fn test_final_state_snapshot() {
+    let thread_count = 2;
+    let periods_per_cycle = 2;
+    let rolls_path = PathBuf::from_str("../massa-node/base_config/initial_rolls.json").unwrap();
+    let genesis_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+
+    // create a MIP store
+    let mip_stats_cfg = MipStatsConfig {
+        block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+        counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+    };
+    let mi_1 = MipInfo {
+        name: "MIP-0002".to_string(),
+        version: 2,
+        components: HashMap::from([(MipComponent::Address, 1)]),
+        start: MassaTime::from(5),
+        timeout: MassaTime::from(10),
+        activation_delay: MassaTime::from(4),
+    };
+    let state_1 = MipState::new(MassaTime::from(3));
+    let mip_store = MipStore::try_from(([(mi_1, state_1)], mip_stats_cfg)).unwrap();
+
+    // setup final state configs, with one disk ledger per final state
+    let exported_ledger_dir = TempDir::new().unwrap();
+    let imported_ledger_dir = TempDir::new().unwrap();
+    let exported_config =
+        get_final_state_config(thread_count, periods_per_cycle, exported_ledger_dir.path());
+    let imported_config =
+        get_final_state_config(thread_count, periods_per_cycle, imported_ledger_dir.path());
+
+    // start proof-of-stake selector
+    let (mut selector_manager, selector_controller) = start_selector_worker(SelectorConfig {
+        thread_count,
+        periods_per_cycle,
+        genesis_address,
+        ..Default::default()
+    })
+    .expect("could not start selector controller");
+
+    // export a random final state
+    let mut exported_final_state = get_random_final_state_bootstrap(
+        PoSFinalState::new(
+            exported_config.pos_config.clone(),
+            "",
+            &rolls_path,
+            selector_controller.clone(),
+            Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        )
+        .unwrap(),
+        exported_config,
+    );
+    let slot = exported_final_state.slot;
+    exported_final_state.compute_state_hash_at_slot(slot);
+    let snapshot_dir = TempDir::new().unwrap();
+    let snapshot_path = snapshot_dir.path().join("final_state.snapshot");
+    exported_final_state
+        .export_snapshot(&mip_store.0.read(), &snapshot_path)
+        .unwrap();
+
+    // import it in an empty final state
+    let mut imported_final_state = FinalState::create_final_state(
+        PoSFinalState::new(
+            imported_config.pos_config.clone(),
+            "",
+            &rolls_path,
+            selector_controller,
+            Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        )
+        .unwrap(),
+        imported_config,
+    );
+    let imported_mip_store = imported_final_state
+        .import_snapshot(&snapshot_path)
+        .unwrap();
+
+    // check the restored final state and MIP store
+    assert_eq_final_state(&exported_final_state, &imported_final_state);
+    assert_eq_final_state_hash(&exported_final_state, &imported_final_state);
+    assert_eq!(
+        exported_final_state.final_state_hash,
+        imported_final_state.final_state_hash
+    );
+    assert_eq!(*mip_store.0.read(), imported_mip_store);
+
+    // ledger proofs of the restored state must be checkable against its final state hash
+    let (address, _) = imported_final_state
+        .ledger
+        .get_every_address()
+        .into_iter()
+        .next()
+        .unwrap();
+    let balance_key = LedgerKey::new(&address, KeyType::BALANCE);
+    let absent_key = LedgerKey::new(&address, KeyType::DATASTORE(b"absent key".to_vec()));
+    let mut proofs =
+        imported_final_state.get_ledger_proofs(&[balance_key.clone(), absent_key.clone()]);
+    let absent_proof = proofs.pop().unwrap();
+    let balance_proof = proofs.pop().unwrap();
+    assert!(balance_proof.value.is_some());
+    balance_proof.verify(&balance_key).unwrap();
+    let mut forged_proof = balance_proof.clone();
+    forged_proof.value = Some(vec![0]);
+    assert!(forged_proof.verify(&balance_key).is_err());
+    assert!(absent_proof.value.is_none());
+    absent_proof.verify(&absent_key).unwrap();
+
+    // a corrupted snapshot must be rejected
+    let mut snapshot_bytes = std::fs::read(&snapshot_path).unwrap();
+    let last_index = snapshot_bytes.len() - 1;
+    snapshot_bytes[last_index] ^= 1;
+    std::fs::write(&snapshot_path, snapshot_bytes).unwrap();
+    assert!(imported_final_state
+        .import_snapshot(&snapshot_path)
+        .is_err());
+    // and leave the final state untouched
+    assert_eq_final_state(&exported_final_state, &imported_final_state);
+
+    // stop selector controller
+    selector_manager.stop();
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_checkpoint() {
+    let thread_count = 2;
+    let periods_per_cycle = 2;
+    let rolls_path = PathBuf::from_str("../massa-node/base_config/initial_rolls.json").unwrap();
+    let genesis_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let bootstrap_config = get_bootstrap_config(NodeId::new(KeyPair::generate().get_public_key()));
+
+    // setup final state configs, with one disk ledger per final state
+    let saved_ledger_dir = TempDir::new().unwrap();
+    let resumed_ledger_dir = TempDir::new().unwrap();
+    let saved_config =
+        get_final_state_config(thread_count, periods_per_cycle, saved_ledger_dir.path());
+    let resumed_config =
+        get_final_state_config(thread_count, periods_per_cycle, resumed_ledger_dir.path());
+
+    // start proof-of-stake selector
+    let (mut selector_manager, selector_controller) = start_selector_worker(SelectorConfig {
+        thread_count,
+        periods_per_cycle,
+        genesis_address,
+        ..Default::default()
+    })
+    .expect("could not start selector controller");
+
+    // save the checkpoint of a partially bootstrapped random final state
+    let saved_final_state = get_random_final_state_bootstrap(
+        PoSFinalState::new(
+            saved_config.pos_config.clone(),
+            "",
+            &rolls_path,
+            selector_controller.clone(),
+            Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        )
+        .unwrap(),
+        saved_config,
+    );
+    let slot = saved_final_state.slot;
+    let mut saved_state = GlobalBootstrapState::new(Arc::new(RwLock::new(saved_final_state)));
+    saved_state.graph = Some(get_boot_state());
+    let next_bootstrap_message = BootstrapClientMessage::AskBootstrapPart {
+        last_slot: Some(slot),
+        last_ledger_step: StreamingStep::Finished(None),
+        last_pool_step: StreamingStep::Started,
+        last_cycle_step: StreamingStep::Ongoing(1),
+        last_credits_step: StreamingStep::Started,
+        last_ops_step: StreamingStep::Started,
+        last_de_step: StreamingStep::Started,
+        last_consensus_step: StreamingStep::Started,
+        send_last_start_period: false,
+    };
+    let mut checkpoint = BootstrapCheckpoint::new(&bootstrap_config).unwrap();
+    checkpoint
+        .save(&next_bootstrap_message, &saved_state)
+        .unwrap();
+
+    // resume from it in an empty final state
+    let mut resumed_state =
+        GlobalBootstrapState::new(Arc::new(RwLock::new(FinalState::create_final_state(
+            PoSFinalState::new(
+                resumed_config.pos_config.clone(),
+                "",
+                &rolls_path,
+                selector_controller,
+                Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+            )
+            .unwrap(),
+            resumed_config,
+        ))));
+    let Some(BootstrapClientMessage::AskBootstrapPart {
+        last_slot,
+        last_ledger_step,
+        last_cycle_step,
+        send_last_start_period,
+        ..
+    }) = checkpoint.load(&mut resumed_state) else {
+        panic!("the checkpoint should resume the final state streaming");
+    };
+    assert_eq!(last_slot, Some(slot));
+    assert_eq!(last_ledger_step, StreamingStep::Finished(None));
+    assert_eq!(last_cycle_step, StreamingStep::Ongoing(1));
+    assert!(!send_last_start_period);
+    assert_eq_bootstrap_graph(
+        saved_state.graph.as_ref().unwrap(),
+        resumed_state.graph.as_ref().unwrap(),
+    );
+
+    // everything but the ledger, which stays on disk, is restored
+    {
+        let saved_final_state = saved_state.final_state.read();
+        let resumed_final_state = resumed_state.final_state.read();
+        assert_eq!(saved_final_state.slot, resumed_final_state.slot);
+        assert_eq_async_pool_bootstrap_state(
+            &saved_final_state.async_pool,
+            &resumed_final_state.async_pool,
+        );
+        assert_eq_pos_state(&saved_final_state.pos_state, &resumed_final_state.pos_state);
+        assert_eq!(
+            saved_final_state.executed_ops.ops,
+            resumed_final_state.executed_ops.ops
+        );
+        assert_eq!(
+            saved_final_state.executed_denunciations.denunciations,
+            resumed_final_state.executed_denunciations.denunciations
+        );
+        assert!(resumed_final_state.ledger.get_every_address().is_empty());
+    }
+
+    // a corrupted checkpoint is discarded, and the bootstrap starts from scratch
+    let mut checkpoint_bytes = std::fs::read(&bootstrap_config.bootstrap_checkpoint_path).unwrap();
+    let last_index = checkpoint_bytes.len() - 1;
+    checkpoint_bytes[last_index] ^= 1;
+    std::fs::write(
+        &bootstrap_config.bootstrap_checkpoint_path,
+        checkpoint_bytes,
+    )
+    .unwrap();
+    assert!(checkpoint.load(&mut resumed_state).is_none());
+    assert!(!bootstrap_config.bootstrap_checkpoint_path.exists());
+
+    // stop selector controller
+    selector_manager.stop();
+}
+
+#[test]
+fn test_ledger_ranges() {
+    let user_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let sc_address = Address::SC(SCAddress(Hash::compute_from(b"sc")));
+    for count in 1..=8 {
+        let ranges = get_ledger_ranges(count);
+        assert_eq!(ranges.len(), count);
+        assert!(ranges[0].start.is_none());
+        assert!(ranges[count - 1].end.is_none());
+        // the ranges are contiguous
+        for bounds in ranges.windows(2) {
+            assert_eq!(bounds[0].end, bounds[1].start);
+        }
+        // every address belongs to exactly one range
+        for address in [user_address, sc_address] {
+            assert_eq!(
+                ranges
+                    .iter()
+                    .filter(|range| range.contains(&address))
+                    .count(),
+                1
+            );
+        }
+    }
+}
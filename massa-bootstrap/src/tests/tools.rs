@@ -4,18 +4,20 @@ use crate::establisher::Duplex;
 use crate::settings::{BootstrapConfig, IpType};
 use bitvec::vec::BitVec;
 use massa_async_pool::test_exports::{create_async_pool, get_random_message};
-use massa_async_pool::{AsyncPoolChanges, Change};
+use massa_async_pool::{AsyncPoolChanges, AsyncPoolConfig, Change};
 use massa_consensus_exports::{
     bootstrapable_graph::{
         BootstrapableGraph, BootstrapableGraphDeserializer, BootstrapableGraphSerializer,
     },
     export_active_block::{ExportActiveBlock, ExportActiveBlockSerializer},
 };
-use massa_executed_ops::{ExecutedOps, ExecutedOpsConfig};
+use massa_executed_ops::{
+    ExecutedDenunciations, ExecutedDenunciationsConfig, ExecutedOps, ExecutedOpsConfig,
+};
 use massa_final_state::test_exports::create_final_state;
 use massa_final_state::{FinalState, FinalStateConfig};
 use massa_hash::Hash;
-use massa_ledger_exports::{LedgerChanges, LedgerEntry, SetUpdateOrDelete};
+use massa_ledger_exports::{LedgerChanges, LedgerConfig, LedgerEntry, SetUpdateOrDelete};
 use massa_ledger_worker::test_exports::create_final_ledger;
 use massa_models::block::BlockDeserializerArgs;
 use massa_models::bytecode::Bytecode;
@@ -25,12 +27,13 @@ use massa_models::config::{
     MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_BOOTSTRAP_BLOCKS, MAX_BOOTSTRAP_ERROR_LENGTH,
     MAX_BOOTSTRAP_FINAL_STATE_PARTS_SIZE, MAX_BOOTSTRAP_MESSAGE_SIZE, MAX_CONSENSUS_BLOCKS_IDS,
     MAX_DATASTORE_ENTRY_COUNT, MAX_DATASTORE_KEY_LENGTH, MAX_DATASTORE_VALUE_LENGTH,
-    MAX_DEFERRED_CREDITS_LENGTH, MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH,
-    MAX_FUNCTION_NAME_LENGTH, MAX_LEDGER_CHANGES_COUNT, MAX_OPERATIONS_PER_BLOCK,
-    MAX_OPERATION_DATASTORE_ENTRY_COUNT, MAX_OPERATION_DATASTORE_KEY_LENGTH,
-    MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE, MAX_PRODUCTION_STATS_LENGTH,
-    MAX_ROLLS_COUNT_LENGTH, MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX,
-    PERIODS_PER_CYCLE, THREAD_COUNT,
+    MAX_DEFERRED_CREDITS_LENGTH, MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+    MAX_DENUNCIATION_CHANGES_LENGTH, MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+    MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH, MAX_FUNCTION_NAME_LENGTH,
+    MAX_LEDGER_CHANGES_COUNT, MAX_OPERATIONS_PER_BLOCK, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+    MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE,
+    MAX_PRODUCTION_STATS_LENGTH, MAX_ROLLS_COUNT_LENGTH, MIP_STORE_STATS_BLOCK_CONSIDERED,
+    MIP_STORE_STATS_COUNTERS_MAX, PERIODS_PER_CYCLE, POS_SAVED_CYCLES, THREAD_COUNT,
 };
 use massa_models::node::NodeId;
 use massa_models::{
@@ -40,6 +43,7 @@ use massa_models::{
     block::BlockSerializer,
     block_header::{BlockHeader, BlockHeaderSerializer},
     block_id::BlockId,
+    denunciation::DenunciationId,
     endorsement::Endorsement,
     endorsement::EndorsementSerializer,
     operation::OperationId,
@@ -49,7 +53,9 @@ use massa_models::{
     slot::Slot,
 };
 use massa_network_exports::{BootstrapPeers, NetworkCommand};
-use massa_pos_exports::{CycleInfo, DeferredCredits, PoSChanges, PoSFinalState, ProductionStats};
+use massa_pos_exports::{
+    CycleInfo, DeferredCredits, PoSChanges, PoSConfig, PoSFinalState, ProductionStats,
+};
 use massa_serialization::{DeserializeError, Deserializer, Serializer};
 use massa_signature::KeyPair;
 use massa_time::MassaTime;
@@ -59,8 +65,9 @@ use std::str::FromStr;
 use std::{
     collections::BTreeMap,
     net::{IpAddr, Ipv4Addr, SocketAddr},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
+use tempfile::TempDir;
 use tokio::{sync::mpsc::Receiver, time::sleep};
 
 // Use loop-back address. use port 0 to auto-assign a port
@@ -228,6 +235,73 @@ pub fn get_random_executed_ops_changes(r_limit: u64) -> PreHashMap<OperationId,
     ops_changes
 }
 
+pub fn get_random_executed_de(
+    _r_limit: u64,
+    slot: Slot,
+    config: ExecutedDenunciationsConfig,
+) -> ExecutedDenunciations {
+    let mut executed_de = ExecutedDenunciations::new(config);
+    executed_de.apply_changes(get_random_executed_de_changes(10), slot);
+    executed_de
+}
+
+pub fn get_random_executed_de_changes(r_limit: u64) -> PreHashMap<DenunciationId, Slot> {
+    let mut de_changes = PreHashMap::default();
+    for i in 0..r_limit {
+        de_changes.insert(
+            DenunciationId::new(Hash::compute_from(&get_some_random_bytes())),
+            Slot {
+                period: i + 10,
+                thread: 0,
+            },
+        );
+    }
+    de_changes
+}
+
+/// final state config of the tests, with the disk ledger at the given path
+pub fn get_final_state_config(
+    thread_count: u8,
+    periods_per_cycle: u64,
+    disk_ledger_path: &Path,
+) -> FinalStateConfig {
+    FinalStateConfig {
+        ledger_config: LedgerConfig {
+            thread_count,
+            initial_ledger_path: "".into(),
+            disk_ledger_path: disk_ledger_path.to_path_buf(),
+            max_key_length: MAX_DATASTORE_KEY_LENGTH,
+            max_ledger_part_size: 100_000,
+            max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
+        },
+        async_pool_config: AsyncPoolConfig {
+            thread_count,
+            max_length: MAX_ASYNC_POOL_LENGTH,
+            max_async_message_data: MAX_ASYNC_MESSAGE_DATA,
+            bootstrap_part_size: 100,
+        },
+        pos_config: PoSConfig {
+            periods_per_cycle,
+            thread_count,
+            cycle_history_length: POS_SAVED_CYCLES,
+            credits_bootstrap_part_size: 100,
+        },
+        executed_ops_config: ExecutedOpsConfig {
+            thread_count,
+            bootstrap_part_size: 10,
+        },
+        executed_denunciations_config: ExecutedDenunciationsConfig {
+            denunciation_expire_periods: 10,
+            bootstrap_part_size: 10,
+        },
+        final_history_length: 100,
+        initial_seed_string: "".into(),
+        initial_rolls_path: "".into(),
+        thread_count,
+        periods_per_cycle,
+    }
+}
+
 /// generates a random bootstrap state for the final state
 pub fn get_random_final_state_bootstrap(
     pos: PoSFinalState,
@@ -267,6 +341,7 @@ pub fn get_random_final_state_bootstrap(
         VecDeque::new(),
         get_random_pos_state(r_limit, pos),
         get_random_executed_ops(r_limit, slot, config.executed_ops_config),
+        get_random_executed_de(r_limit, slot, config.executed_denunciations_config),
     )
 }
 
@@ -305,6 +380,9 @@ pub fn get_bootstrap_config(bootstrap_public_key: NodeId) -> BootstrapConfig {
         max_clock_delta: MassaTime::from_millis(1000),
         cache_duration: 10000.into(),
         max_simultaneous_bootstraps: 2,
+        bootstrap_checkpoint_path: TempDir::new().unwrap().into_path().join("checkpoint"),
+        bootstrap_checkpoint_interval: 10000.into(),
+        max_parallel_bootstrap_servers: 1,
         ip_list_max_size: 10,
         per_ip_min_interval: 10000.into(),
         max_bytes_read_write: std::f64::INFINITY,
@@ -336,6 +414,9 @@ pub fn get_bootstrap_config(bootstrap_public_key: NodeId) -> BootstrapConfig {
         max_credits_length: MAX_DEFERRED_CREDITS_LENGTH,
         max_executed_ops_length: MAX_EXECUTED_OPS_LENGTH,
         max_ops_changes_length: MAX_EXECUTED_OPS_CHANGES_LENGTH,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+        max_executed_denunciations_length: MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+        max_denunciation_changes_length: MAX_DENUNCIATION_CHANGES_LENGTH,
         consensus_bootstrap_part_size: CONSENSUS_BOOTSTRAP_PART_SIZE,
         max_consensus_block_ids: MAX_CONSENSUS_BLOCKS_IDS,
         mip_store_stats_block_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
@@ -415,6 +496,7 @@ pub fn get_boot_state() -> BootstrapableGraph {
                         )
                         .unwrap(),
                     ],
+                    denunciations: Vec::new(),
                 },
                 BlockHeaderSerializer::new(),
                 &keypair,
@@ -443,6 +525,7 @@ pub fn get_boot_state() -> BootstrapableGraph {
         thread_count: THREAD_COUNT,
         max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
         endorsement_count: ENDORSEMENT_COUNT,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
         last_start_period: Some(0),
     };
     let bootstrapable_graph_deserializer =
@@ -4,10 +4,17 @@
 //!
 //! Read `lib.rs` module documentation for more information.
 
+use crate::kdf::Argon2Params;
 use pbkdf2::Params;
 
-/// Cipher version
-pub const VERSION: u32 = 0;
+/// Cipher version used for encryption
+pub const VERSION: u32 = ARGON2ID_VERSION;
+
+/// Cipher version deriving the key with `PBKDF2`, only supported for decryption
+pub const PBKDF2_VERSION: u32 = 0;
+
+/// Cipher version deriving the key with `Argon2id`
+pub const ARGON2ID_VERSION: u32 = 1;
 
 /// AES-GCM-SIV nonce size.
 ///
@@ -18,8 +25,31 @@ pub const NONCE_SIZE: usize = 12;
 /// `PBKDF2` salt size.
 pub const SALT_SIZE: usize = 12;
 
+/// `Argon2id` salt size.
+pub const ARGON2_SALT_SIZE: usize = 16;
+
+/// AES-256 key size.
+pub const KEY_SIZE: usize = 32;
+
 /// `PBKDF2` hash parameters.
 pub const HASH_PARAMS: Params = Params {
     rounds: 10_000,
-    output_length: 32,
+    output_length: KEY_SIZE,
+};
+
+/// Maximum `Argon2id` memory size accepted in an encrypted content header, in KiB.
+/// The header is read before the content is authenticated, it must not make the key derivation arbitrarily expensive.
+pub const MAX_ARGON2_MEMORY_COST_KIB: u32 = 256 * 1024;
+
+/// Maximum `Argon2id` number of iterations accepted in an encrypted content header.
+pub const MAX_ARGON2_ITERATIONS: u32 = 16;
+
+/// Maximum `Argon2id` degree of parallelism accepted in an encrypted content header.
+pub const MAX_ARGON2_PARALLELISM: u32 = 16;
+
+/// Default `Argon2id` parameters, following the OWASP recommendations.
+pub const DEFAULT_ARGON2_PARAMS: Argon2Params = Argon2Params {
+    memory_cost_kib: 19 * 1024,
+    iterations: 2,
+    parallelism: 1,
 };
@@ -4,32 +4,148 @@
 //!
 //! Read `lib.rs` module documentation for more information.
 
-use aes_gcm::aead::Aead;
+use aes_gcm::aead::{Aead, Payload};
 use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
 use pbkdf2::{
     password_hash::{PasswordHasher, SaltString},
     Pbkdf2,
 };
 
-use crate::constants::{HASH_PARAMS, NONCE_SIZE, SALT_SIZE};
+use crate::constants::{
+    ARGON2ID_VERSION, ARGON2_SALT_SIZE, HASH_PARAMS, NONCE_SIZE, PBKDF2_VERSION, SALT_SIZE,
+};
 use crate::error::CipherError;
+use crate::kdf::{derive_argon2id_key, Argon2Params};
 use massa_serialization::{DeserializeError, Deserializer, U32VarIntDeserializer};
 
 use std::ops::Bound::Included;
 
 /// Decryption function using AES-GCM cipher.
+/// Supports the content produced by all the cipher versions, and returns the version along with the data.
 ///
 /// Read `lib.rs` module documentation for more information.
 pub fn decrypt(password: &str, data: &[u8]) -> Result<(u32, Vec<u8>), CipherError> {
-    // parse cipher version
-    let (rest, version) = U32VarIntDeserializer::new(Included(0), Included(u32::MAX))
+    let (rest, version) = parse_u32(data, "version")?;
+    let decrypted_bytes = match version {
+        PBKDF2_VERSION => decrypt_pbkdf2(password, rest)?,
+        ARGON2ID_VERSION => decrypt_argon2id(password, data, rest)?,
+        _ => {
+            return Err(CipherError::DecryptionError(format!(
+                "unsupported cipher version {}",
+                version
+            )))
+        }
+    };
+    Ok((version, decrypted_bytes))
+}
+
+/// Gets the cipher version of encrypted content, without decrypting it
+pub fn get_version(data: &[u8]) -> Result<u32, CipherError> {
+    Ok(parse_u32(data, "version")?.1)
+}
+
+/// Parses a header value
+fn parse_u32<'a>(data: &'a [u8], name: &str) -> Result<(&'a [u8], u32), CipherError> {
+    U32VarIntDeserializer::new(Included(0), Included(u32::MAX))
         .deserialize::<DeserializeError>(data)
         .map_err(|_| {
+            CipherError::DecryptionError(format!(
+                "wallet file truncated: {} missing or incomplete",
+                name
+            ))
+        })
+}
+
+/// Header of the content encrypted with the key derived by `Argon2id`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Argon2Header {
+    /// `Argon2id` parameters
+    pub params: Argon2Params,
+    /// `Argon2id` salt
+    pub salt: [u8; ARGON2_SALT_SIZE],
+    /// AES-GCM nonce
+    pub nonce: [u8; NONCE_SIZE],
+}
+
+/// Gets the header of content encrypted with the key derived by `Argon2id`, without decrypting it
+pub fn get_argon2_header(data: &[u8]) -> Result<Argon2Header, CipherError> {
+    let (rest, version) = parse_u32(data, "version")?;
+    if version != ARGON2ID_VERSION {
+        return Err(CipherError::DecryptionError(format!(
+            "cipher version {} does not derive the key with Argon2id",
+            version
+        )));
+    }
+    Ok(parse_argon2_header(rest)?.1)
+}
+
+/// Parses the header following the version of content encrypted with the key derived by `Argon2id`
+fn parse_argon2_header(rest: &[u8]) -> Result<(&[u8], Argon2Header), CipherError> {
+    // parse Argon2id parameters
+    let (rest, memory_cost_kib) = parse_u32(rest, "memory cost")?;
+    let (rest, iterations) = parse_u32(rest, "iterations")?;
+    let (rest, parallelism) = parse_u32(rest, "parallelism")?;
+    let params = Argon2Params {
+        memory_cost_kib,
+        iterations,
+        parallelism,
+    };
+
+    // parse Argon2id salt
+    let salt = rest
+        .get(..ARGON2_SALT_SIZE)
+        .and_then(|salt| salt.try_into().ok())
+        .ok_or_else(|| {
+            CipherError::DecryptionError(
+                "wallet file truncated: salt missing or incomplete".to_string(),
+            )
+        })?;
+
+    // parse AES-GCM nonce
+    let nonce_end_index = ARGON2_SALT_SIZE + NONCE_SIZE;
+    let nonce = rest
+        .get(ARGON2_SALT_SIZE..nonce_end_index)
+        .and_then(|nonce| nonce.try_into().ok())
+        .ok_or_else(|| {
             CipherError::DecryptionError(
-                "wallet file truncated: version missing or incomplete".to_string(),
+                "wallet file truncated: nonce missing or incomplete".to_string(),
             )
         })?;
 
+    Ok((
+        &rest[nonce_end_index..],
+        Argon2Header {
+            params,
+            salt,
+            nonce,
+        },
+    ))
+}
+
+/// Decrypts the content following the version, with the key derived by `Argon2id`.
+/// The header, from the beginning of `data` to the encrypted bytes, is authenticated.
+fn decrypt_argon2id(password: &str, data: &[u8], rest: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let (encrypted_bytes, header) = parse_argon2_header(rest)?;
+
+    // compute Argon2id password hash
+    let password_hash = derive_argon2id_key(password, &header.salt, &header.params)?;
+
+    // decrypt the data
+    let header_size = data.len() - encrypted_bytes.len();
+    let cipher = Aes256Gcm::new_from_slice(&password_hash).expect("invalid size key");
+    cipher
+        .decrypt(
+            Nonce::from_slice(&header.nonce),
+            Payload {
+                msg: encrypted_bytes,
+                aad: &data[..header_size],
+            },
+        )
+        .map_err(|_| CipherError::DecryptionError("wrong password or corrupted data".to_string()))
+}
+
+/// Decrypts the content following the version, with the key derived by `PBKDF2`
+fn decrypt_pbkdf2(password: &str, rest: &[u8]) -> Result<Vec<u8>, CipherError> {
     // parse PBKDF2 salt
     let salt_data = rest.get(..SALT_SIZE).ok_or_else(|| {
         CipherError::DecryptionError(
@@ -56,7 +172,7 @@ pub fn decrypt(password: &str, data: &[u8]) -> Result<(u32, Vec<u8>), CipherErro
 
     // decrypt the data
     let cipher = Aes256Gcm::new_from_slice(password_hash.as_bytes()).expect("invalid size key");
-    let decrypted_bytes = cipher
+    cipher
         .decrypt(
             nonce,
             rest.get(nonce_end_index..).ok_or_else(|| {
@@ -65,8 +181,31 @@ pub fn decrypt(password: &str, data: &[u8]) -> Result<(u32, Vec<u8>), CipherErro
                 )
             })?,
         )
-        .map_err(|_| {
-            CipherError::DecryptionError("wrong password or corrupted data".to_string())
-        })?;
-    Ok((version, decrypted_bytes))
+        .map_err(|_| CipherError::DecryptionError("wrong password or corrupted data".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_decrypt_pbkdf2_fixture() {
+        // "massa wallet content" encrypted with "password" by the version 0 cipher:
+        // salt "bWFzc2FzYWx0", nonce 000102030405060708090a0b
+        let encrypted = from_hex("006257467a6332467a59577830000102030405060708090a0be772d36ef5295fe9ef50333304019802195312351ef983b13440d918298638a3f64ed412");
+        assert_eq!(get_version(&encrypted).unwrap(), PBKDF2_VERSION);
+        assert_eq!(
+            decrypt("password", &encrypted).unwrap(),
+            (PBKDF2_VERSION, b"massa wallet content".to_vec())
+        );
+        assert!(decrypt("wrong password", &encrypted).is_err());
+        assert!(decrypt("password", &encrypted[..20]).is_err());
+    }
 }
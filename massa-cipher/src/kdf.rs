@@ -0,0 +1,73 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! massa-cipher key derivation module.
+//!
+//! Read `lib.rs` module documentation for more information.
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    DEFAULT_ARGON2_PARAMS, KEY_SIZE, MAX_ARGON2_ITERATIONS, MAX_ARGON2_MEMORY_COST_KIB,
+    MAX_ARGON2_PARALLELISM,
+};
+use crate::error::CipherError;
+
+/// `Argon2id` parameters, stored in the header of the encrypted content
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Argon2Params {
+    /// memory size, in KiB
+    pub memory_cost_kib: u32,
+    /// number of iterations
+    pub iterations: u32,
+    /// degree of parallelism
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        DEFAULT_ARGON2_PARAMS
+    }
+}
+
+impl Argon2Params {
+    /// Checks that the parameters do not exceed the limits accepted when decrypting
+    fn check_limits(&self) -> Result<(), CipherError> {
+        if self.memory_cost_kib > MAX_ARGON2_MEMORY_COST_KIB
+            || self.iterations > MAX_ARGON2_ITERATIONS
+            || self.parallelism > MAX_ARGON2_PARALLELISM
+        {
+            return Err(CipherError::KdfError(format!(
+                "Argon2id parameters {:?} exceed the limits (memory cost {} KiB, {} iterations, parallelism {})",
+                self,
+                MAX_ARGON2_MEMORY_COST_KIB,
+                MAX_ARGON2_ITERATIONS,
+                MAX_ARGON2_PARALLELISM
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Derives the cipher key from the password with `Argon2id`.
+/// Fails if the parameters exceed the limits, so that no content is encrypted with parameters
+/// that would be rejected when decrypting it.
+pub(crate) fn derive_argon2id_key(
+    password: &str,
+    salt: &[u8],
+    params: &Argon2Params,
+) -> Result<[u8; KEY_SIZE], CipherError> {
+    params.check_limits()?;
+    let params = Params::new(
+        params.memory_cost_kib,
+        params.iterations,
+        params.parallelism,
+        Some(KEY_SIZE),
+    )
+    .map_err(|e| CipherError::KdfError(e.to_string()))?;
+    let mut key = [0u8; KEY_SIZE];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| CipherError::KdfError(e.to_string()))?;
+    Ok(key)
+}
@@ -7,8 +7,14 @@
 //! AES-GCM is a state-of-the-art high-performance Authenticated Encryption with Associated Data (AEAD)
 //! that provides confidentiality and authenticity.
 //!
-//! To hash the password before using it as a cipher key, we use the `PBKDF2` key derivation function
-//! as specified in [RFC 2898](https://datatracker.ietf.org/doc/html/rfc2898).
+//! To hash the password before using it as a cipher key, we use the memory-hard `Argon2id` key derivation
+//! function as specified in [RFC 9106](https://datatracker.ietf.org/doc/html/rfc9106).
+//! Its parameters are stored in the header of the encrypted content, so that they can be tuned.
+//!
+//! The encrypted content starts with a cipher version, so that content produced by previous versions
+//! can still be decrypted and then re-encrypted with the current one:
+//! * version 0 derives the key with `PBKDF2` as specified in [RFC 2898](https://datatracker.ietf.org/doc/html/rfc2898)
+//! * version 1 derives the key with `Argon2id`, and authenticates the header along with the data
 //!
 //! The AES-GCM crate we use has received one security audit by NCC Group, with no significant findings.
 
@@ -16,7 +22,10 @@ mod constants;
 mod decrypt;
 mod encrypt;
 mod error;
+mod kdf;
 
-pub use decrypt::decrypt;
-pub use encrypt::encrypt;
+pub use constants::VERSION;
+pub use decrypt::{decrypt, get_argon2_header, get_version, Argon2Header};
+pub use encrypt::{encrypt, encrypt_with_params};
 pub use error::CipherError;
+pub use kdf::Argon2Params;
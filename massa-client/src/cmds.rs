@@ -1,9 +1,13 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use crate::display::Output;
+use crate::offline::{
+    read_operation_file, write_operation_file, SignedOperationFile, UnsignedOperationFile,
+};
 use crate::{client_warning, rpc_error};
 use anyhow::{anyhow, bail, Result};
 use console::style;
+use dialoguer::{Confirm, Password};
 use massa_api_exports::{
     address::{AddressInfo, CompactAddressInfo},
     datastore::DatastoreEntryInput,
@@ -23,9 +27,9 @@ use massa_models::{
     slot::Slot,
 };
 use massa_sdk::Client;
-use massa_signature::KeyPair;
+use massa_signature::{KeyPair, MultiSigPublicKey, PublicKey};
 use massa_time::MassaTime;
-use massa_wallet::Wallet;
+use massa_wallet::{Keystore, Wallet};
 use serde::Serialize;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Write as _;
@@ -195,8 +199,8 @@ pub enum Command {
 
     #[strum(
         ascii_case_insensitive,
-        props(args = "show-all-keys"),
-        message = "show wallet info (addresses, balances ...)"
+        props(args = "[show-all-keys | show-seed-phrase]"),
+        message = "show wallet info (addresses, balances ...), or the seed phrase from which accounts are derived"
     )]
     wallet_info,
 
@@ -216,7 +220,8 @@ pub enum Command {
 
     #[strum(
         ascii_case_insensitive,
-        message = "generate a secret key and add it into the wallet"
+        props(args = "[derive | restore AccountCount]"),
+        message = "generate a secret key and add it into the wallet, derive it as the next account of the wallet seed phrase (created if needed), or restore the first accounts of a seed phrase (prompted for)"
     )]
     wallet_generate_secret_key,
 
@@ -234,6 +239,20 @@ pub enum Command {
     )]
     wallet_remove_addresses,
 
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "Address OutputPath"),
+        message = "export an account of the wallet into a new keystore file encrypted with its own password"
+    )]
+    wallet_export_keystore,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "PathToKeystore"),
+        message = "import the account of a keystore file into the wallet"
+    )]
+    wallet_import_keystore,
+
     #[strum(
         ascii_case_insensitive,
         props(args = "Address string"),
@@ -276,6 +295,104 @@ pub enum Command {
     )]
     call_smart_contract,
 
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "Address RollCount Fee ExpirePeriod OutputPath",
+            pwd_not_needed = "true"
+        ),
+        message = "build an unsigned roll buy operation into a file, to be signed offline"
+    )]
+    build_buy_rolls,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "Address RollCount Fee ExpirePeriod OutputPath",
+            pwd_not_needed = "true"
+        ),
+        message = "build an unsigned roll sell operation into a file, to be signed offline"
+    )]
+    build_sell_rolls,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "SenderAddress ReceiverAddress Amount Fee ExpirePeriod OutputPath",
+            pwd_not_needed = "true"
+        ),
+        message = "build an unsigned transaction into a file, to be signed offline"
+    )]
+    build_transaction,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "SenderAddress PathToBytecode MaxGas Fee ExpirePeriod OutputPath",
+            pwd_not_needed = "true"
+        ),
+        message = "build an unsigned operation containing byte code into a file, to be signed offline"
+    )]
+    build_execute_smart_contract,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "SenderAddress TargetAddress FunctionName Parameter MaxGas Coins Fee ExpirePeriod OutputPath",
+            pwd_not_needed = "true"
+        ),
+        message = "build an unsigned smart contract call into a file, to be signed offline"
+    )]
+    build_call_smart_contract,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "Threshold PublicKey1 PublicKey2 ...", pwd_not_needed = "true"),
+        message = "compute the address of a multi-signature account requiring Threshold signatures among the given public keys"
+    )]
+    multisig_address,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "PathToUnsignedOperation OutputPath Threshold PublicKey1 PublicKey2 ...",
+            pwd_not_needed = "true"
+        ),
+        message = "turn an operation file built with a multi-signature address as sender into a multi-signature operation, to be signed by the members"
+    )]
+    build_multisig_operation,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "Address PathToMultiSigOperation OutputPath"),
+        message = "add the signature of a member of a multi-signature address to a multi-signature operation file"
+    )]
+    sign_multisig_operation_file,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(
+            args = "OutputPath PathToMultiSigOperation1 PathToMultiSigOperation2 ...",
+            pwd_not_needed = "true"
+        ),
+        message = "merge the signatures collected separately by the members of a multi-signature address"
+    )]
+    merge_multisig_operation_files,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "PathToUnsignedOperation OutputPath"),
+        message = "sign an operation file built offline with the wallet key of its sender, without contacting the node"
+    )]
+    sign_operation_file,
+
+    #[strum(
+        ascii_case_insensitive,
+        props(args = "PathToSignedOperation", pwd_not_needed = "true"),
+        message = "check a signed operation file and send the operation to the node"
+    )]
+    send_operation_file,
+
     #[strum(
         ascii_case_insensitive,
         props(
@@ -659,9 +776,20 @@ impl Command {
 
             Command::wallet_info => {
                 let show_keys = parameters.len() == 1 && parameters[0] == "show-all-keys";
+                let show_seed_phrase = parameters.len() == 1 && parameters[0] == "show-seed-phrase";
 
                 let wallet = wallet_opt.as_mut().unwrap();
 
+                if show_seed_phrase {
+                    let Some(mnemonic) = wallet.get_mnemonic() else {
+                        bail!("the wallet has no seed phrase, use 'wallet_generate_secret_key derive' to create one");
+                    };
+                    if !json {
+                        client_warning!("do not share your seed phrase");
+                    }
+                    return Ok(Box::new(mnemonic.to_string()));
+                }
+
                 if !json && show_keys {
                     client_warning!("do not share your secret key");
                 }
@@ -769,8 +897,51 @@ impl Command {
             Command::wallet_generate_secret_key => {
                 let wallet = wallet_opt.as_mut().unwrap();
 
-                let key = KeyPair::generate();
-                let ad = wallet.add_keypairs(vec![key])?[0];
+                if parameters.first().map(String::as_str) == Some("restore") {
+                    if parameters.len() != 2 {
+                        bail!("wrong number of parameters");
+                    }
+                    let account_count = parameters[1].parse::<u32>()?;
+                    // the seed phrase is prompted for so that it does not end up in the shell history
+                    let phrase = Password::new()
+                        .with_prompt("Enter the seed phrase")
+                        .interact()?;
+                    let passphrase = Password::new()
+                        .with_prompt("Enter the seed phrase passphrase (empty if none)")
+                        .allow_empty_password(true)
+                        .interact()?;
+                    let addresses =
+                        wallet.restore_from_mnemonic(&phrase, &passphrase, account_count)?;
+                    if json {
+                        return Ok(Box::new(addresses));
+                    }
+                    for address in addresses {
+                        println!("Restored address {} in the wallet.", address);
+                    }
+                    return Ok(Box::new(()));
+                }
+
+                let ad = match parameters.first().map(String::as_str) {
+                    None => wallet.add_keypairs(vec![KeyPair::generate()])?[0],
+                    Some("derive") => {
+                        if wallet.get_mnemonic().is_none() {
+                            let passphrase = Password::new()
+                                .with_prompt(
+                                    "Enter a passphrase for the new seed phrase (empty for none)",
+                                )
+                                .with_confirmation("Confirm passphrase", "Passphrases mismatching")
+                                .allow_empty_password(true)
+                                .interact()?;
+                            let mnemonic = wallet.generate_hd_seed(&passphrase)?.to_string();
+                            if !json {
+                                println!("Generated the seed phrase of the wallet:\n{}", mnemonic);
+                                client_warning!("write down your seed phrase and passphrase and do not share them: they allow to restore all the derived accounts");
+                            }
+                        }
+                        wallet.derive_next_account()?
+                    }
+                    Some(_) => bail!("unknown parameter, expected 'derive' or 'restore'"),
+                };
                 if json {
                     Ok(Box::new(ad.to_string()))
                 } else {
@@ -817,6 +988,60 @@ impl Command {
                 Ok(Box::new(()))
             }
 
+            Command::wallet_export_keystore => {
+                let wallet = wallet_opt.as_mut().unwrap();
+
+                if parameters.len() != 2 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let path = parameters[1].parse::<PathBuf>()?;
+                let password = Password::new()
+                    .with_prompt("Enter new password for keystore")
+                    .with_confirmation("Confirm password", "Passwords mismatching")
+                    .interact()?;
+
+                let keystore = wallet.export_keystore(&addr, &password)?;
+                // the keystore is only readable by its owner, and never replaces an existing file
+                let mut options = std::fs::OpenOptions::new();
+                options.write(true).create_new(true);
+                #[cfg(unix)]
+                std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+                let mut file = options
+                    .open(&path)
+                    .map_err(|err| anyhow!("could not create {}: {}", path.display(), err))?;
+                std::io::Write::write_all(&mut file, &serde_json::to_vec_pretty(&keystore)?)?;
+                file.sync_all()?;
+                if json {
+                    Ok(Box::new(addr.to_string()))
+                } else {
+                    println!("Exported address {} to {}", addr, path.display());
+                    Ok(Box::new(()))
+                }
+            }
+
+            Command::wallet_import_keystore => {
+                let wallet = wallet_opt.as_mut().unwrap();
+
+                if parameters.len() != 1 {
+                    bail!("wrong number of parameters");
+                }
+                let path = parameters[0].parse::<PathBuf>()?;
+                let keystore: Keystore = serde_json::from_slice(&tokio::fs::read(&path).await?)?;
+                let password = Password::new()
+                    .with_prompt(format!("Enter password of keystore {}", keystore.address))
+                    .interact()?;
+
+                let addr = wallet.import_keystore(&keystore, &password)?;
+                if json {
+                    Ok(Box::new(addr.to_string()))
+                } else {
+                    println!("Imported address {} to the wallet.", addr);
+                    println!("Type `node_start_staking <address>` to start staking with the corresponding key.\n");
+                    Ok(Box::new(()))
+                }
+            }
+
             Command::buy_rolls => {
                 let wallet = wallet_opt.as_mut().unwrap();
 
@@ -1079,6 +1304,249 @@ impl Command {
                 )
                 .await
             }
+            Command::build_buy_rolls => {
+                if parameters.len() != 5 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let roll_count = parameters[1].parse::<u64>()?;
+                let fee = parameters[2].parse::<Amount>()?;
+                let expire_period = parameters[3].parse::<u64>()?;
+                let path = parameters[4].parse::<PathBuf>()?;
+
+                build_operation(
+                    OperationType::RollBuy { roll_count },
+                    fee,
+                    expire_period,
+                    addr,
+                    &path,
+                    json,
+                )
+                .await
+            }
+            Command::build_sell_rolls => {
+                if parameters.len() != 5 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let roll_count = parameters[1].parse::<u64>()?;
+                let fee = parameters[2].parse::<Amount>()?;
+                let expire_period = parameters[3].parse::<u64>()?;
+                let path = parameters[4].parse::<PathBuf>()?;
+
+                build_operation(
+                    OperationType::RollSell { roll_count },
+                    fee,
+                    expire_period,
+                    addr,
+                    &path,
+                    json,
+                )
+                .await
+            }
+            Command::build_transaction => {
+                if parameters.len() != 6 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let recipient_address = parameters[1].parse::<Address>()?;
+                let amount = parameters[2].parse::<Amount>()?;
+                let fee = parameters[3].parse::<Amount>()?;
+                let expire_period = parameters[4].parse::<u64>()?;
+                let path = parameters[5].parse::<PathBuf>()?;
+
+                build_operation(
+                    OperationType::Transaction {
+                        recipient_address,
+                        amount,
+                    },
+                    fee,
+                    expire_period,
+                    addr,
+                    &path,
+                    json,
+                )
+                .await
+            }
+            Command::build_execute_smart_contract => {
+                if parameters.len() != 6 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let bytecode_path = parameters[1].parse::<PathBuf>()?;
+                let max_gas = parameters[2].parse::<u64>()?;
+                let fee = parameters[3].parse::<Amount>()?;
+                let expire_period = parameters[4].parse::<u64>()?;
+                let path = parameters[5].parse::<PathBuf>()?;
+                let data = get_file_as_byte_vec(&bytecode_path).await?;
+
+                build_operation(
+                    OperationType::ExecuteSC {
+                        data,
+                        max_gas,
+                        datastore: BTreeMap::new(),
+                    },
+                    fee,
+                    expire_period,
+                    addr,
+                    &path,
+                    json,
+                )
+                .await
+            }
+            Command::build_call_smart_contract => {
+                if parameters.len() != 9 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let target_addr = parameters[1].parse::<Address>()?;
+                let target_func = parameters[2].clone();
+                let param = parameters[3].clone().into_bytes();
+                let max_gas = parameters[4].parse::<u64>()?;
+                let coins = parameters[5].parse::<Amount>()?;
+                let fee = parameters[6].parse::<Amount>()?;
+                let expire_period = parameters[7].parse::<u64>()?;
+                let path = parameters[8].parse::<PathBuf>()?;
+
+                build_operation(
+                    OperationType::CallSC {
+                        target_addr,
+                        target_func,
+                        param,
+                        max_gas,
+                        coins,
+                    },
+                    fee,
+                    expire_period,
+                    addr,
+                    &path,
+                    json,
+                )
+                .await
+            }
+            Command::multisig_address => {
+                if parameters.len() < 2 {
+                    bail!("wrong number of parameters");
+                }
+                let threshold = parameters[0].parse::<u8>()?;
+                let public_keys = parse_vec::<PublicKey>(&parameters[1..])?;
+                let public_key = MultiSigPublicKey::new(threshold, public_keys)?;
+                Ok(Box::new(
+                    Address::from_multisig_public_key(&public_key).to_string(),
+                ))
+            }
+            Command::build_multisig_operation => {
+                if parameters.len() < 4 {
+                    bail!("wrong number of parameters");
+                }
+                let input_path = parameters[0].parse::<PathBuf>()?;
+                let output_path = parameters[1].parse::<PathBuf>()?;
+                let threshold = parameters[2].parse::<u8>()?;
+                let public_keys = parse_vec::<PublicKey>(&parameters[3..])?;
+                let public_key = MultiSigPublicKey::new(threshold, public_keys)?;
+
+                let unsigned: UnsignedOperationFile = read_operation_file(&input_path).await?;
+                let multisig = unsigned.into_multisig(public_key)?;
+                write_operation_file(&output_path, &multisig).await?;
+                if !json {
+                    println!(
+                        "Multi-signature operation written to {}",
+                        output_path.display()
+                    );
+                }
+                Ok(Box::new(multisig))
+            }
+            Command::sign_multisig_operation_file => {
+                let wallet = wallet_opt.as_mut().unwrap();
+
+                if parameters.len() != 3 {
+                    bail!("wrong number of parameters");
+                }
+                let addr = parameters[0].parse::<Address>()?;
+                let input_path = parameters[1].parse::<PathBuf>()?;
+                let output_path = parameters[2].parse::<PathBuf>()?;
+
+                let mut multisig: UnsignedOperationFile = read_operation_file(&input_path).await?;
+                multisig.add_multisig_signature(wallet, addr)?;
+                write_operation_file(&output_path, &multisig).await?;
+                if !json {
+                    println!(
+                        "Partially signed operation written to {}",
+                        output_path.display()
+                    );
+                }
+                Ok(Box::new(multisig))
+            }
+            Command::merge_multisig_operation_files => {
+                if parameters.len() < 3 {
+                    bail!("wrong number of parameters");
+                }
+                let output_path = parameters[0].parse::<PathBuf>()?;
+                let input_paths = parse_vec::<PathBuf>(&parameters[1..])?;
+
+                let mut multisig: UnsignedOperationFile =
+                    read_operation_file(&input_paths[0]).await?;
+                for input_path in &input_paths[1..] {
+                    let other: UnsignedOperationFile = read_operation_file(input_path).await?;
+                    multisig.merge_multisig_signatures(&other)?;
+                }
+                write_operation_file(&output_path, &multisig).await?;
+                if !json {
+                    println!("Merged operation written to {}", output_path.display());
+                }
+                Ok(Box::new(multisig))
+            }
+            Command::sign_operation_file => {
+                let wallet = wallet_opt.as_mut().unwrap();
+
+                if parameters.len() != 2 {
+                    bail!("wrong number of parameters");
+                }
+                let input_path = parameters[0].parse::<PathBuf>()?;
+                let output_path = parameters[1].parse::<PathBuf>()?;
+
+                let unsigned: UnsignedOperationFile = read_operation_file(&input_path).await?;
+                // the file may come from an untrusted machine: show what is about to be signed
+                if json {
+                    eprintln!("{}", unsigned);
+                } else {
+                    println!("{}", unsigned);
+                }
+                if !Confirm::new()
+                    .with_prompt("Sign this operation?")
+                    .default(false)
+                    .interact()?
+                {
+                    bail!("signature cancelled");
+                }
+                let signed = unsigned.sign(wallet)?;
+                write_operation_file(&output_path, &signed).await?;
+                if !json {
+                    println!("Signed operation written to {}", output_path.display());
+                }
+                Ok(Box::new(signed))
+            }
+            Command::send_operation_file => {
+                if parameters.len() != 1 {
+                    bail!("wrong number of parameters");
+                }
+                let path = parameters[0].parse::<PathBuf>()?;
+
+                let signed: SignedOperationFile = read_operation_file(&path).await?;
+                signed.verify()?;
+                if !json {
+                    println!("{}", signed);
+                }
+                match client.public.send_operations(vec![signed.to_input()]).await {
+                    Ok(operation_ids) => {
+                        if !json {
+                            println!("Sent operation IDs:");
+                        }
+                        Ok(Box::new(operation_ids))
+                    }
+                    Err(e) => rpc_error!(e),
+                }
+            }
             Command::wallet_sign => {
                 let wallet = wallet_opt.as_mut().unwrap();
 
@@ -1119,6 +1587,7 @@ impl Command {
                         address,
                         operation_datastore: None, // TODO - #3072
                         is_final,
+                        with_trace: false,
                     })
                     .await
                 {
@@ -1154,6 +1623,8 @@ impl Command {
                         parameter,
                         max_gas,
                         is_final,
+                        state_overrides: Vec::new(),
+                        with_trace: false,
                     })
                     .await
                 {
@@ -1393,6 +1864,30 @@ async fn send_operation(
     }
 }
 
+/// helper to write an unsigned operation into a file, for it to be signed offline
+async fn build_operation(
+    op: OperationType,
+    fee: Amount,
+    expire_period: u64,
+    addr: Address,
+    path: &std::path::Path,
+    json: bool,
+) -> Result<Box<dyn Output>> {
+    let unsigned = UnsignedOperationFile {
+        sender_address: addr,
+        operation: Operation {
+            fee,
+            expire_period,
+            op,
+        },
+    };
+    write_operation_file(path, &unsigned).await?;
+    if !json {
+        println!("Unsigned operation written to {}", path.display());
+    }
+    Ok(Box::new(unsigned))
+}
+
 /// TODO: ugly utilities functions
 /// takes a slice of string and makes it into a `Vec<T>`
 pub fn parse_vec<T: std::str::FromStr>(args: &[String]) -> anyhow::Result<Vec<T>, anyhow::Error>
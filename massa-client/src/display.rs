@@ -1,6 +1,7 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
 use crate::cmds::ExtendedWallet;
+use crate::offline::{SignedOperationFile, UnsignedOperationFile};
 use console::style;
 use erased_serde::{Serialize, Serializer};
 use massa_api_exports::{
@@ -587,3 +588,15 @@ impl Output for ExecuteReadOnlyResponse {
         println!("{}", self);
     }
 }
+
+impl Output for UnsignedOperationFile {
+    fn pretty_print(&self) {
+        println!("{}", self);
+    }
+}
+
+impl Output for SignedOperationFile {
+    fn pretty_print(&self) {
+        println!("{}", self);
+    }
+}
@@ -19,6 +19,7 @@ use structopt::StructOpt;
 
 mod cmds;
 mod display;
+mod offline;
 mod repl;
 mod settings;
 
@@ -56,6 +57,9 @@ struct Args {
     #[structopt(short = "p", long = "pwd")]
     /// Wallet password
     password: Option<String>,
+    /// API token of the node private API, overrides the one of the configuration file
+    #[structopt(long)]
+    auth_token: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -104,6 +108,11 @@ async fn run(args: Args) -> Result<()> {
         id_kind: SETTINGS.client.id_kind.clone(),
         max_log_length: SETTINGS.client.max_log_length,
         headers: SETTINGS.client.headers.clone(),
+        tls: SETTINGS.client.tls,
+        auth_token: args
+            .auth_token
+            .clone()
+            .or_else(|| SETTINGS.client.auth_token.clone()),
     };
 
     let http_config = HttpConfig {
@@ -153,7 +162,7 @@ async fn run(args: Args) -> Result<()> {
                     _ => ask_password(&args.wallet),
                 };
 
-                let wallet = Wallet::new(args.wallet, password)?;
+                let wallet = Wallet::new(args.wallet, password, SETTINGS.wallet.argon2)?;
                 Some(wallet)
             }
             false => None,
@@ -0,0 +1,280 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Portable operation files, allowing to build an operation without a node,
+//! to sign it on a machine that holds the keys but has no network access,
+//! and to broadcast it later from an online machine.
+//! Operations of a multi-signature address go through the same files,
+//! each member adding its signature before the operation is signed and sent.
+
+use anyhow::{bail, Result};
+use massa_api_exports::operation::OperationInput;
+use massa_models::{
+    address::Address,
+    operation::{Operation, OperationId, OperationSerializer, OperationType},
+    secure_share::{Id, SecureShareContent},
+};
+use massa_serialization::Serializer;
+use massa_signature::{MultiSigPublicKey, PublicKey, Signature};
+use massa_wallet::Wallet;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::Path;
+
+/// Unsigned operation, to be signed by its sender
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsignedOperationFile {
+    /// address that must sign the operation
+    pub sender_address: Address,
+    /// content of the operation
+    pub operation: Operation,
+}
+
+impl UnsignedOperationFile {
+    /// Wraps the operation so that it is sent on behalf of a multi-signature address.
+    /// The operation must have been built with the multi-signature address as sender.
+    pub fn into_multisig(self, public_key: MultiSigPublicKey) -> Result<UnsignedOperationFile> {
+        let multisig_address = Address::from_multisig_public_key(&public_key);
+        if self.sender_address != multisig_address {
+            bail!(
+                "the operation is sent by {} instead of the multi-signature address {}",
+                self.sender_address,
+                multisig_address
+            );
+        }
+        if let OperationType::MultiSig { .. } = self.operation.op {
+            bail!("the operation is already a multi-signature operation");
+        }
+        Ok(UnsignedOperationFile {
+            sender_address: multisig_address,
+            operation: Operation {
+                op: OperationType::MultiSig {
+                    public_key,
+                    signatures: Default::default(),
+                    op: Box::new(self.operation.op),
+                },
+                ..self.operation
+            },
+        })
+    }
+
+    /// Adds the signature of a member of the multi-signature address, whose key must be in the wallet
+    pub fn add_multisig_signature(&mut self, wallet: &Wallet, address: Address) -> Result<()> {
+        wallet.sign_multisig_operation(&mut self.operation, address)?;
+        Ok(())
+    }
+
+    /// Merges the signatures collected separately by the members of the multi-signature address
+    pub fn merge_multisig_signatures(&mut self, other: &UnsignedOperationFile) -> Result<()> {
+        let hash = self.operation.compute_multisig_hash();
+        if hash.is_none() || hash != other.operation.compute_multisig_hash() {
+            bail!("the files do not contain the same multi-signature operation");
+        }
+        if let (
+            OperationType::MultiSig { signatures, .. },
+            OperationType::MultiSig {
+                signatures: other_signatures,
+                ..
+            },
+        ) = (&mut self.operation.op, &other.operation.op)
+        {
+            signatures.merge(other_signatures);
+        }
+        Ok(())
+    }
+
+    /// Signs the operation with the key of the sender, that must be in the wallet.
+    /// Operations of a multi-signature address are signed by any of its members in the wallet.
+    pub fn sign(self, wallet: &Wallet) -> Result<SignedOperationFile> {
+        let op = wallet.create_operation(self.operation, self.sender_address)?;
+        Ok(SignedOperationFile {
+            operation_id: op.id,
+            sender_address: op.content_creator_address,
+            creator_public_key: op.content_creator_pub_key,
+            signature: op.signature,
+            serialized_content: op.serialized_data,
+            operation: op.content,
+        })
+    }
+}
+
+impl Display for UnsignedOperationFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Unsigned operation")?;
+        writeln!(f, "Sender address: {}", self.sender_address)?;
+        write!(f, "{}", self.operation)
+    }
+}
+
+/// Signed operation, ready to be broadcast
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedOperationFile {
+    /// id of the operation
+    pub operation_id: OperationId,
+    /// address that signed the operation
+    pub sender_address: Address,
+    /// public key that signed the operation
+    pub creator_public_key: PublicKey,
+    /// signature of the operation
+    pub signature: Signature,
+    /// signed serialized content of the operation
+    pub serialized_content: Vec<u8>,
+    /// readable content of the operation
+    pub operation: Operation,
+}
+
+impl SignedOperationFile {
+    /// Checks that the signed content is the displayed operation and that the signature is valid
+    pub fn verify(&self) -> Result<()> {
+        let mut serialized_content = Vec::new();
+        OperationSerializer::new().serialize(&self.operation, &mut serialized_content)?;
+        if serialized_content != self.serialized_content {
+            bail!("the signed content does not match the operation");
+        }
+        if Operation::compute_creator_address(&self.operation, &self.creator_public_key)
+            != self.sender_address
+        {
+            bail!("the public key does not match the sender address");
+        }
+        let hash = Operation::compute_hash(
+            &self.operation,
+            &self.serialized_content,
+            &self.creator_public_key,
+        );
+        if OperationId::new(hash) != self.operation_id {
+            bail!("the operation id does not match the signed content");
+        }
+        self.creator_public_key
+            .verify_signature(&hash, &self.signature)?;
+        if let OperationType::MultiSig {
+            public_key,
+            signatures,
+            ..
+        } = &self.operation.op
+        {
+            if public_key.get_index(&self.creator_public_key).is_none() {
+                bail!("the operation is not signed by a member of the multi-signature address");
+            }
+            let multisig_hash = self
+                .operation
+                .compute_multisig_hash()
+                .expect("multi-signature operation");
+            public_key.verify_signature(&multisig_hash, signatures)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the input expected by `send_operations`
+    pub fn to_input(&self) -> OperationInput {
+        OperationInput {
+            creator_public_key: self.creator_public_key,
+            signature: self.signature,
+            serialized_content: self.serialized_content.clone(),
+        }
+    }
+}
+
+impl Display for SignedOperationFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Signed operation {}", self.operation_id)?;
+        writeln!(f, "Sender address: {}", self.sender_address)?;
+        writeln!(f, "Sender public key: {}", self.creator_public_key)?;
+        write!(f, "{}", self.operation)
+    }
+}
+
+/// Writes an operation file as JSON
+pub async fn write_operation_file<T: Serialize>(path: &Path, content: &T) -> Result<()> {
+    tokio::fs::write(path, serde_json::to_vec_pretty(content)?).await?;
+    Ok(())
+}
+
+/// Reads an operation file written by `write_operation_file`
+pub async fn read_operation_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    Ok(serde_json::from_slice(&tokio::fs::read(path).await?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_models::amount::Amount;
+    use massa_signature::KeyPair;
+
+    /// Signs a transaction from a new address of a temporary wallet
+    fn signed_operation_file() -> SignedOperationFile {
+        let wallet_dir = tempfile::tempdir().unwrap();
+        let mut wallet = Wallet::new(
+            wallet_dir.path().join("wallet.dat"),
+            "test".to_string(),
+            Default::default(),
+        )
+        .unwrap();
+        let sender_address = wallet.add_keypairs(vec![KeyPair::generate()]).unwrap()[0];
+        let recipient_address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        UnsignedOperationFile {
+            sender_address,
+            operation: Operation {
+                fee: Amount::from_raw(1000),
+                expire_period: 10,
+                op: OperationType::Transaction {
+                    recipient_address,
+                    amount: Amount::from_raw(42),
+                },
+            },
+        }
+        .sign(&wallet)
+        .unwrap()
+    }
+
+    /// Writes and reads back a file, as when it goes from the offline to the online machine
+    async fn round_trip(signed: &SignedOperationFile) -> SignedOperationFile {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signed.json");
+        write_operation_file(&path, signed).await.unwrap();
+        read_operation_file(&path).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_signed_operation_file_round_trip() {
+        let signed = signed_operation_file();
+        signed.verify().unwrap();
+        let read = round_trip(&signed).await;
+        read.verify().unwrap();
+        assert_eq!(read.operation_id, signed.operation_id);
+        assert_eq!(read.serialized_content, signed.serialized_content);
+        assert_eq!(read.to_input().signature, signed.signature);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_signed_operation_file() {
+        let signed = signed_operation_file();
+
+        // displayed operation differing from the signed content
+        let mut tampered = round_trip(&signed).await;
+        tampered.operation.fee = Amount::from_raw(1);
+        assert!(tampered.verify().is_err());
+
+        // signed content modified along with the displayed operation
+        let mut tampered = round_trip(&signed).await;
+        tampered.operation.fee = Amount::from_raw(1);
+        tampered.serialized_content.clear();
+        OperationSerializer::new()
+            .serialize(&tampered.operation, &mut tampered.serialized_content)
+            .unwrap();
+        assert!(tampered.verify().is_err());
+
+        // operation id of another operation
+        let mut tampered = round_trip(&signed).await;
+        tampered.operation_id = signed_operation_file().operation_id;
+        assert!(tampered.verify().is_err());
+
+        // signature of another operation
+        let mut tampered = round_trip(&signed).await;
+        tampered.signature = signed_operation_file().signature;
+        assert!(tampered.verify().is_err());
+
+        // public key not matching the sender address
+        let mut tampered = round_trip(&signed).await;
+        tampered.creator_public_key = KeyPair::generate().get_public_key();
+        assert!(tampered.verify().is_err());
+    }
+}
@@ -129,7 +129,11 @@ pub(crate) async fn run(
                                     _ => ask_password(wallet_path),
                                 };
 
-                            let wallet = Wallet::new(wallet_path.to_path_buf(), password)?;
+                            let wallet = Wallet::new(
+                                wallet_path.to_path_buf(),
+                                password,
+                                SETTINGS.wallet.argon2,
+                            )?;
                             wallet_opt = Some(wallet);
                         }
 
@@ -3,6 +3,7 @@
 //! Build here the default client settings from the configuration file toml
 use massa_models::config::build_massa_settings;
 use massa_time::MassaTime;
+use massa_wallet::Argon2Params;
 use serde::Deserialize;
 use std::{net::IpAddr, path::PathBuf};
 
@@ -17,6 +18,7 @@ pub struct Settings {
     pub history_file_path: PathBuf,
     pub timeout: MassaTime,
     pub client: ClientSettings,
+    pub wallet: WalletSettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -37,9 +39,18 @@ pub struct ClientSettings {
     pub id_kind: String,
     pub max_log_length: u32,
     pub headers: Vec<(String, String)>,
+    pub tls: bool,
+    pub auth_token: Option<String>,
     pub http: HttpSettings,
 }
 
+/// Wallet settings
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalletSettings {
+    /// `Argon2id` parameters the wallet file is encrypted with
+    pub argon2: Argon2Params,
+}
+
 ///TODO add WebSocket to CLI
 /// Http client settings.
 /// the Http client settings
@@ -1,3 +1,4 @@
+use massa_archive::ArchiveDB;
 use massa_execution_exports::ExecutionController;
 use massa_models::block::{FilledBlock, SecureShareBlock};
 use massa_models::block_header::BlockHeader;
@@ -7,6 +8,7 @@ use massa_models::secure_share::SecureShare;
 use massa_pool_exports::PoolController;
 use massa_pos_exports::SelectorController;
 use massa_protocol_exports::ProtocolCommandSender;
+use std::sync::Arc;
 
 use crate::events::ConsensusEvent;
 
@@ -31,4 +33,6 @@ pub struct ConsensusChannels {
     pub filled_block_sender: tokio::sync::broadcast::Sender<FilledBlock>,
     /// Channel use for Denunciation factory to create denunciations
     pub denunciation_factory_sender: crossbeam_channel::Sender<DenunciationPrecursor>,
+    /// Persistent archive of final blocks, if archive mode is enabled
+    pub archive: Option<Arc<ArchiveDB>>,
 }
@@ -199,6 +199,7 @@ impl Deserializer<ExportActiveBlock> for ExportActiveBlockDeserializer {
     ///             )
     ///             .unwrap(),
     ///         ],
+    ///         denunciations: Vec::new(),
     ///     },
     ///     BlockHeaderSerializer::new(),
     ///     &keypair,
@@ -221,7 +222,7 @@ impl Deserializer<ExportActiveBlock> for ExportActiveBlockDeserializer {
     /// let mut serialized = Vec::new();
     /// ExportActiveBlockSerializer::new().serialize(&export_active_block, &mut serialized).unwrap();
     /// let args = BlockDeserializerArgs {
-    ///   thread_count: 32, max_operations_per_block: 16, endorsement_count: 1000,last_start_period: Some(0),};
+    ///   thread_count: 32, max_operations_per_block: 16, endorsement_count: 1000, max_denunciations_per_block_header: 128, last_start_period: Some(0),};
     /// let (rest, export_deserialized) = ExportActiveBlockDeserializer::new(args).deserialize::<DeserializeError>(&serialized).unwrap();
     /// assert_eq!(export_deserialized.block.id, export_active_block.block.id);
     /// assert_eq!(export_deserialized.block.serialized_data, export_active_block.block.serialized_data);
@@ -1,3 +1,4 @@
+use massa_archive::ArchiveWriter;
 use massa_consensus_exports::ConsensusManager;
 use std::{sync::mpsc::SyncSender, thread::JoinHandle};
 use tracing::log::info;
@@ -6,6 +7,7 @@ use crate::commands::ConsensusCommand;
 
 pub struct ConsensusManagerImpl {
     pub consensus_thread: Option<(SyncSender<ConsensusCommand>, JoinHandle<()>)>,
+    pub archive_writer: Option<ArchiveWriter>,
 }
 
 impl ConsensusManager for ConsensusManagerImpl {
@@ -19,5 +21,9 @@ impl ConsensusManager for ConsensusManagerImpl {
                 .expect("consensus thread panicked on try to join");
         }
         info!("consensus worker stopped");
+        // the blocks finalized before the consensus thread stopped are written before returning
+        if let Some(mut archive_writer) = self.archive_writer.take() {
+            archive_writer.stop();
+        }
     }
 }
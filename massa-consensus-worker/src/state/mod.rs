@@ -3,6 +3,7 @@ use std::{
     vec,
 };
 
+use massa_archive::ArchiveWriterSender;
 use massa_consensus_exports::{
     block_graph_export::BlockGraphExport,
     block_status::{BlockStatus, ExportCompiledBlock, HeaderOrBlock},
@@ -93,6 +94,8 @@ pub struct ConsensusState {
     /// Blocks indexed by slot (used for multi-stake limiting). Blocks
     /// should be saved in this map when we receive the header or the full block directly.
     pub nonfinal_active_blocks_per_slot: HashMap<Slot, PreHashSet<BlockId>>,
+    /// Sends the newly final blocks to the archive writer thread, if archive mode is enabled
+    pub archive_writer: Option<ArchiveWriterSender>,
 }
 
 impl ConsensusState {
@@ -8,6 +8,7 @@ use massa_consensus_exports::{
     error::ConsensusError,
 };
 use massa_logging::massa_trace;
+use massa_metrics::{CONSENSUS_FINAL_BLOCKS, CONSENSUS_STALE_BLOCKS};
 use massa_models::{
     active_block::ActiveBlock,
     address::Address,
@@ -20,6 +21,7 @@ use massa_models::{
 use massa_signature::PublicKey;
 use massa_storage::Storage;
 use massa_time::MassaTime;
+use tracing::info_span;
 use tracing::log::{debug, info};
 
 use super::ConsensusState;
@@ -79,6 +81,7 @@ impl ConsensusState {
         block_id: BlockId,
         current_slot: Option<Slot>,
     ) -> Result<BTreeSet<(Slot, BlockId)>, ConsensusError> {
+        let _span = info_span!("process_block", %block_id).entered();
         // list items to reprocess
         let mut reprocess = BTreeSet::new();
 
@@ -621,14 +624,31 @@ impl ConsensusState {
             let mut final_block_slots = HashMap::with_capacity(finalized_blocks.len());
             let mut final_block_stats = VecDeque::with_capacity(finalized_blocks.len());
             for b_id in finalized_blocks {
-                if let Some(BlockStatus::Active {
-                    a_block,
-                    storage: _,
-                }) = self.block_statuses.get(&b_id)
+                if let Some(BlockStatus::Active { a_block, storage }) =
+                    self.block_statuses.get(&b_id)
                 {
                     // add to final blocks to notify execution
                     final_block_slots.insert(a_block.slot, b_id);
 
+                    // write to the archive (if enabled)
+                    if let Some(archive_writer) = &self.archive_writer {
+                        let block = storage
+                            .read_blocks()
+                            .get(&b_id)
+                            .cloned()
+                            .expect("final block absent from its storage");
+                        let operations = {
+                            let ops = storage.read_operations();
+                            block
+                                .content
+                                .operations
+                                .iter()
+                                .filter_map(|op_id| ops.get(op_id).cloned())
+                                .collect::<Vec<_>>()
+                        };
+                        archive_writer.append_final_block(block, operations);
+                    }
+
                     // add to stats
                     let block_is_from_protocol = self
                         .protocol_blocks
@@ -641,6 +661,7 @@ impl ConsensusState {
                     ));
                 }
             }
+            CONSENSUS_FINAL_BLOCKS.inc_by(final_block_stats.len() as u64);
             self.final_block_stats.extend(final_block_stats);
 
             // add stale blocks to stats
@@ -648,6 +669,7 @@ impl ConsensusState {
             let timestamp = MassaTime::now()?;
             for (_b_id, (_b_creator, _b_slot)) in new_stale_block_ids_creators_slots.into_iter() {
                 self.stale_block_stats.push_back(timestamp);
+                CONSENSUS_STALE_BLOCKS.inc();
             }
             final_block_slots
         };
@@ -1,5 +1,6 @@
 use super::ConsensusState;
 use massa_consensus_exports::error::ConsensusError;
+use massa_metrics::{CONSENSUS_ACTIVE_BLOCKS, CONSENSUS_BLOCKCLIQUE_SIZE, CONSENSUS_CLIQUES};
 use massa_models::stats::ConsensusStats;
 use massa_time::MassaTime;
 use std::cmp::max;
@@ -46,6 +47,15 @@ impl ConsensusState {
         }
         // prune stats
         self.prune_stats()?;
+        // update metrics
+        let blockclique_size = self
+            .max_cliques
+            .iter()
+            .find(|clique| clique.is_blockclique)
+            .map_or(0, |clique| clique.block_ids.len());
+        CONSENSUS_BLOCKCLIQUE_SIZE.set(blockclique_size as i64);
+        CONSENSUS_CLIQUES.set(self.get_clique_count() as i64);
+        CONSENSUS_ACTIVE_BLOCKS.set(self.active_index.len() as i64);
         Ok(())
     }
 
@@ -1,3 +1,4 @@
+use massa_archive::ArchiveWriter;
 use massa_consensus_exports::{
     bootstrapable_graph::BootstrapableGraph, ConsensusChannels, ConsensusConfig,
     ConsensusController, ConsensusManager,
@@ -60,6 +61,11 @@ pub fn start_consensus_worker(
     let bootstrap_part_size = config.bootstrap_part_size;
     let stats_desync_detection_timespan =
         config.t0.checked_mul(config.periods_per_cycle * 2).unwrap();
+    // archive writes are done by a dedicated thread, off the block processing path
+    let archive_writer = channels
+        .archive
+        .clone()
+        .map(|archive| ArchiveWriter::start(archive, CHANNEL_SIZE));
     let shared_state = Arc::new(RwLock::new(ConsensusState {
         storage: storage.clone(),
         config: config.clone(),
@@ -97,6 +103,7 @@ pub fn start_consensus_worker(
         ),
         prev_blockclique: Default::default(),
         nonfinal_active_blocks_per_slot: Default::default(),
+        archive_writer: archive_writer.as_ref().map(ArchiveWriter::sender),
     }));
 
     let shared_state_cloned = shared_state.clone();
@@ -110,6 +117,7 @@ pub fn start_consensus_worker(
 
     let manager = ConsensusManagerImpl {
         consensus_thread: Some((tx.clone(), consensus_thread)),
+        archive_writer,
     };
 
     let controller = ConsensusControllerImpl::new(
@@ -7,3 +7,11 @@ pub struct ExecutedOpsConfig {
     /// Maximum size of a bootstrap part
     pub bootstrap_part_size: u64,
 }
+
+#[derive(Debug, Clone)]
+pub struct ExecutedDenunciationsConfig {
+    /// Period delta after which a denunciation expires
+    pub denunciation_expire_periods: u64,
+    /// Maximum size of a bootstrap part
+    pub bootstrap_part_size: u64,
+}
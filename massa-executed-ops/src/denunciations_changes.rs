@@ -0,0 +1,113 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use massa_models::{
+    denunciation::{DenunciationId, DenunciationIdDeserializer, DenunciationIdSerializer},
+    prehash::PreHashMap,
+    slot::{Slot, SlotDeserializer, SlotSerializer},
+};
+use massa_serialization::{
+    Deserializer, SerializeError, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
+};
+use nom::{
+    error::{context, ContextError, ParseError},
+    multi::length_count,
+    sequence::tuple,
+    IResult, Parser,
+};
+use std::ops::Bound::{Excluded, Included};
+
+/// Speculative changes for ExecutedDenunciations: denunciation id and denounced slot
+pub type ExecutedDenunciationsChanges = PreHashMap<DenunciationId, Slot>;
+
+/// `ExecutedDenunciationsChanges` Serializer
+pub struct ExecutedDenunciationsChangesSerializer {
+    u64_serializer: U64VarIntSerializer,
+    denunciation_id_serializer: DenunciationIdSerializer,
+    slot_serializer: SlotSerializer,
+}
+
+impl Default for ExecutedDenunciationsChangesSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutedDenunciationsChangesSerializer {
+    /// Create a new `ExecutedDenunciationsChanges` Serializer
+    pub fn new() -> ExecutedDenunciationsChangesSerializer {
+        ExecutedDenunciationsChangesSerializer {
+            u64_serializer: U64VarIntSerializer::new(),
+            denunciation_id_serializer: DenunciationIdSerializer::new(),
+            slot_serializer: SlotSerializer::new(),
+        }
+    }
+}
+
+impl Serializer<ExecutedDenunciationsChanges> for ExecutedDenunciationsChangesSerializer {
+    fn serialize(
+        &self,
+        value: &ExecutedDenunciationsChanges,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), SerializeError> {
+        self.u64_serializer
+            .serialize(&(value.len() as u64), buffer)?;
+        for (de_id, slot) in value {
+            self.denunciation_id_serializer.serialize(de_id, buffer)?;
+            self.slot_serializer.serialize(slot, buffer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deserializer for `ExecutedDenunciationsChanges`
+pub struct ExecutedDenunciationsChangesDeserializer {
+    u64_deserializer: U64VarIntDeserializer,
+    denunciation_id_deserializer: DenunciationIdDeserializer,
+    slot_deserializer: SlotDeserializer,
+}
+
+impl ExecutedDenunciationsChangesDeserializer {
+    /// Create a new deserializer for `ExecutedDenunciationsChanges`
+    pub fn new(
+        thread_count: u8,
+        max_denunciations_changes_length: u64,
+    ) -> ExecutedDenunciationsChangesDeserializer {
+        ExecutedDenunciationsChangesDeserializer {
+            u64_deserializer: U64VarIntDeserializer::new(
+                Included(u64::MIN),
+                Included(max_denunciations_changes_length),
+            ),
+            denunciation_id_deserializer: DenunciationIdDeserializer::new(),
+            slot_deserializer: SlotDeserializer::new(
+                (Included(u64::MIN), Included(u64::MAX)),
+                (Included(0), Excluded(thread_count)),
+            ),
+        }
+    }
+}
+
+impl Deserializer<ExecutedDenunciationsChanges> for ExecutedDenunciationsChangesDeserializer {
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], ExecutedDenunciationsChanges, E> {
+        context(
+            "ExecutedDenunciationsChanges",
+            length_count(
+                context("ExecutedDenunciationsChanges length", |input| {
+                    self.u64_deserializer.deserialize(input)
+                }),
+                tuple((
+                    context("denunciation id", |input| {
+                        self.denunciation_id_deserializer.deserialize(input)
+                    }),
+                    context("denounced slot", |input| {
+                        self.slot_deserializer.deserialize(input)
+                    }),
+                )),
+            ),
+        )
+        .map(|ids| ids.into_iter().collect())
+        .parse(buffer)
+    }
+}
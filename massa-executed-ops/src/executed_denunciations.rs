@@ -0,0 +1,333 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file defines a structure to list and prune previously executed denunciations.
+//! Used to detect denunciation reuse.
+
+use crate::{denunciations_changes::ExecutedDenunciationsChanges, ExecutedDenunciationsConfig};
+use massa_hash::{Hash, HASH_SIZE_BYTES};
+use massa_models::{
+    denunciation::{DenunciationId, DenunciationIdDeserializer, DenunciationIdSerializer},
+    prehash::PreHashSet,
+    secure_share::Id,
+    slot::{Slot, SlotDeserializer, SlotSerializer},
+    streaming_step::StreamingStep,
+};
+use massa_serialization::{
+    Deserializer, SerializeError, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
+};
+use nom::{
+    error::{context, ContextError, ParseError},
+    multi::length_count,
+    sequence::tuple,
+    IResult, Parser,
+};
+use std::{
+    collections::BTreeMap,
+    ops::Bound::{Excluded, Included, Unbounded},
+};
+
+const EXECUTED_DENUNCIATIONS_HASH_INITIAL_BYTES: &[u8; 32] = &[0; HASH_SIZE_BYTES];
+
+/// A structure to list and prune previously executed denunciations
+#[derive(Debug, Clone)]
+pub struct ExecutedDenunciations {
+    /// Executed denunciations configuration
+    config: ExecutedDenunciationsConfig,
+    /// Executed denunciations btreemap with the denounced slot as index for better pruning complexity
+    pub sorted_denunciations: BTreeMap<Slot, PreHashSet<DenunciationId>>,
+    /// Executed denunciations only for better insertion complexity
+    pub denunciations: PreHashSet<DenunciationId>,
+    /// Accumulated hash of the executed denunciations
+    pub hash: Hash,
+}
+
+impl ExecutedDenunciations {
+    /// Creates a new `ExecutedDenunciations`
+    pub fn new(config: ExecutedDenunciationsConfig) -> Self {
+        Self {
+            config,
+            sorted_denunciations: BTreeMap::new(),
+            denunciations: PreHashSet::default(),
+            hash: Hash::from_bytes(EXECUTED_DENUNCIATIONS_HASH_INITIAL_BYTES),
+        }
+    }
+
+    /// Reset the executed denunciations
+    ///
+    /// USED FOR BOOTSTRAP ONLY
+    pub fn reset(&mut self) {
+        self.sorted_denunciations.clear();
+        self.denunciations.clear();
+        self.hash = Hash::from_bytes(EXECUTED_DENUNCIATIONS_HASH_INITIAL_BYTES);
+    }
+
+    /// Returns the number of executed denunciations
+    pub fn len(&self) -> usize {
+        self.denunciations.len()
+    }
+
+    /// Check executed denunciations emptiness
+    pub fn is_empty(&self) -> bool {
+        self.denunciations.is_empty()
+    }
+
+    /// Internal function used to insert the values of a denunciation id iter and update the object hash
+    fn extend_and_compute_hash<'a, I>(&mut self, values: I)
+    where
+        I: Iterator<Item = &'a DenunciationId>,
+    {
+        for de_id in values {
+            if self.denunciations.insert(*de_id) {
+                // XOR the hash to allow reversibility when the denunciation is pruned
+                self.hash ^= *de_id.get_hash();
+            }
+        }
+    }
+
+    /// Apply speculative denunciations changes to the final executed denunciations state
+    pub fn apply_changes(&mut self, changes: ExecutedDenunciationsChanges, slot: Slot) {
+        self.extend_and_compute_hash(changes.keys());
+        for (de_id, de_slot) in changes {
+            self.sorted_denunciations
+                .entry(de_slot)
+                .or_default()
+                .insert(de_id);
+        }
+
+        self.prune(slot);
+    }
+
+    /// Check if a denunciation was executed
+    pub fn contains(&self, de_id: &DenunciationId) -> bool {
+        self.denunciations.contains(de_id)
+    }
+
+    /// Prune all denunciations that can no longer be included in a block at `slot`
+    /// because their denounced slot is expired
+    fn prune(&mut self, slot: Slot) {
+        let min_period = slot
+            .period
+            .saturating_sub(self.config.denunciation_expire_periods);
+        let kept = self.sorted_denunciations.split_off(&Slot::new(min_period, 0));
+        let removed = std::mem::take(&mut self.sorted_denunciations);
+        for (_, ids) in removed {
+            for de_id in ids {
+                self.denunciations.remove(&de_id);
+                self.hash ^= *de_id.get_hash();
+            }
+        }
+        self.sorted_denunciations = kept;
+    }
+
+    /// Get a part of the executed denunciations.
+    /// Used exclusively by the bootstrap server.
+    ///
+    /// # Returns
+    /// A tuple containing the data and the next executed denunciations streaming step
+    pub fn get_executed_denunciations_part(
+        &self,
+        cursor: StreamingStep<Slot>,
+    ) -> (
+        BTreeMap<Slot, PreHashSet<DenunciationId>>,
+        StreamingStep<Slot>,
+    ) {
+        let mut de_part = BTreeMap::new();
+        let left_bound = match cursor {
+            StreamingStep::Started => Unbounded,
+            StreamingStep::Ongoing(slot) => Excluded(slot),
+            StreamingStep::Finished(_) => return (de_part, cursor),
+        };
+        let mut de_part_last_slot: Option<Slot> = None;
+        for (slot, ids) in self.sorted_denunciations.range((left_bound, Unbounded)) {
+            if de_part.len() < self.config.bootstrap_part_size as usize {
+                de_part.insert(*slot, ids.clone());
+                de_part_last_slot = Some(*slot);
+            } else {
+                break;
+            }
+        }
+        if let Some(last_slot) = de_part_last_slot {
+            (de_part, StreamingStep::Ongoing(last_slot))
+        } else {
+            (de_part, StreamingStep::Finished(None))
+        }
+    }
+
+    /// Set a part of the executed denunciations.
+    /// Used exclusively by the bootstrap client.
+    /// Takes the data returned from `get_executed_denunciations_part` as input.
+    ///
+    /// # Returns
+    /// The next executed denunciations streaming step
+    pub fn set_executed_denunciations_part(
+        &mut self,
+        part: BTreeMap<Slot, PreHashSet<DenunciationId>>,
+    ) -> StreamingStep<Slot> {
+        self.sorted_denunciations.extend(part.clone());
+        self.extend_and_compute_hash(part.iter().flat_map(|(_, ids)| ids));
+        if let Some(slot) = self
+            .sorted_denunciations
+            .last_key_value()
+            .map(|(slot, _)| slot)
+        {
+            StreamingStep::Ongoing(*slot)
+        } else {
+            StreamingStep::Finished(None)
+        }
+    }
+}
+
+#[test]
+fn test_executed_denunciations_prune() {
+    use massa_models::prehash::PreHashMap;
+
+    let config = ExecutedDenunciationsConfig {
+        denunciation_expire_periods: 5,
+        bootstrap_part_size: 10,
+    };
+
+    let mut executed_de = ExecutedDenunciations::new(config);
+    let mut changes = PreHashMap::default();
+    for i in 0u8..10 {
+        changes.insert(
+            DenunciationId::new(Hash::compute_from(&[i])),
+            Slot::new(i as u64, 0),
+        );
+    }
+    executed_de.apply_changes(changes, Slot::new(9, 0));
+
+    // denunciations of periods strictly lower than 9 - 5 are expired
+    assert_eq!(executed_de.len(), 6);
+    assert!(!executed_de.contains(&DenunciationId::new(Hash::compute_from(&[3u8]))));
+    assert!(executed_de.contains(&DenunciationId::new(Hash::compute_from(&[4u8]))));
+
+    // prune every element, the hash should be XORed back to its initial value
+    executed_de.apply_changes(PreHashMap::default(), Slot::new(20, 0));
+    assert!(executed_de.is_empty());
+    assert_eq!(
+        executed_de.hash,
+        Hash::from_bytes(EXECUTED_DENUNCIATIONS_HASH_INITIAL_BYTES),
+        "executed denunciations hash was not reset to its initial value"
+    );
+}
+
+/// `ExecutedDenunciations` Serializer
+pub struct ExecutedDenunciationsSerializer {
+    slot_serializer: SlotSerializer,
+    u64_serializer: U64VarIntSerializer,
+    denunciation_id_serializer: DenunciationIdSerializer,
+}
+
+impl Default for ExecutedDenunciationsSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutedDenunciationsSerializer {
+    /// Create a new `ExecutedDenunciations` Serializer
+    pub fn new() -> ExecutedDenunciationsSerializer {
+        ExecutedDenunciationsSerializer {
+            slot_serializer: SlotSerializer::new(),
+            u64_serializer: U64VarIntSerializer::new(),
+            denunciation_id_serializer: DenunciationIdSerializer::new(),
+        }
+    }
+}
+
+impl Serializer<BTreeMap<Slot, PreHashSet<DenunciationId>>> for ExecutedDenunciationsSerializer {
+    fn serialize(
+        &self,
+        value: &BTreeMap<Slot, PreHashSet<DenunciationId>>,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), SerializeError> {
+        // executed denunciations length
+        self.u64_serializer
+            .serialize(&(value.len() as u64), buffer)?;
+        // executed denunciations
+        for (slot, ids) in value {
+            // slot
+            self.slot_serializer.serialize(slot, buffer)?;
+            // slot ids length
+            self.u64_serializer.serialize(&(ids.len() as u64), buffer)?;
+            // slot ids
+            for de_id in ids {
+                self.denunciation_id_serializer.serialize(de_id, buffer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializer for `ExecutedDenunciations`
+pub struct ExecutedDenunciationsDeserializer {
+    denunciation_id_deserializer: DenunciationIdDeserializer,
+    slot_deserializer: SlotDeserializer,
+    denunciations_length_deserializer: U64VarIntDeserializer,
+    slot_denunciations_length_deserializer: U64VarIntDeserializer,
+}
+
+impl ExecutedDenunciationsDeserializer {
+    /// Create a new deserializer for `ExecutedDenunciations`
+    pub fn new(
+        thread_count: u8,
+        max_executed_denunciations_length: u64,
+        max_denunciations_per_slot: u64,
+    ) -> ExecutedDenunciationsDeserializer {
+        ExecutedDenunciationsDeserializer {
+            denunciation_id_deserializer: DenunciationIdDeserializer::new(),
+            slot_deserializer: SlotDeserializer::new(
+                (Included(u64::MIN), Included(u64::MAX)),
+                (Included(0), Excluded(thread_count)),
+            ),
+            denunciations_length_deserializer: U64VarIntDeserializer::new(
+                Included(u64::MIN),
+                Included(max_executed_denunciations_length),
+            ),
+            slot_denunciations_length_deserializer: U64VarIntDeserializer::new(
+                Included(u64::MIN),
+                Included(max_denunciations_per_slot),
+            ),
+        }
+    }
+}
+
+impl Deserializer<BTreeMap<Slot, PreHashSet<DenunciationId>>>
+    for ExecutedDenunciationsDeserializer
+{
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], BTreeMap<Slot, PreHashSet<DenunciationId>>, E> {
+        context(
+            "ExecutedDenunciations",
+            length_count(
+                context("ExecutedDenunciations length", |input| {
+                    self.denunciations_length_deserializer.deserialize(input)
+                }),
+                context(
+                    "slot denunciations",
+                    tuple((
+                        context("slot", |input| self.slot_deserializer.deserialize(input)),
+                        length_count(
+                            context("slot denunciations length", |input| {
+                                self.slot_denunciations_length_deserializer
+                                    .deserialize(input)
+                            }),
+                            context("denunciation id", |input| {
+                                self.denunciation_id_deserializer.deserialize(input)
+                            }),
+                        ),
+                    )),
+                ),
+            ),
+        )
+        .map(|denunciations| {
+            denunciations
+                .into_iter()
+                .map(|(slot, ids)| (slot, ids.into_iter().collect()))
+                .collect()
+        })
+        .parse(buffer)
+    }
+}
@@ -1,9 +1,13 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 mod config;
+mod denunciations_changes;
+mod executed_denunciations;
 mod executed_ops;
 mod ops_changes;
 
 pub use config::*;
+pub use denunciations_changes::*;
+pub use executed_denunciations::*;
 pub use executed_ops::*;
 pub use ops_changes::*;
@@ -0,0 +1,10 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use massa_models::output_event::SCOutputEvent;
+
+/// channels used by the execution worker
+#[derive(Clone)]
+pub struct ExecutionChannels {
+    /// Broadcast sender(channel) for new smart contract events
+    pub sc_event_sender: tokio::sync::broadcast::Sender<SCOutputEvent>,
+}
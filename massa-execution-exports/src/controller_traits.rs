@@ -4,7 +4,12 @@
 
 use crate::types::ReadOnlyExecutionRequest;
 use crate::ExecutionError;
-use crate::{ExecutionAddressInfo, ReadOnlyExecutionOutput};
+use crate::{
+    CallTrace, ExecutionAddressInfo, OperationEstimation, OperationEstimationRequest,
+    ReadOnlyExecutionOutput,
+};
+use massa_final_state::FinalLedgerProof;
+use massa_ledger_exports::Key as LedgerKey;
 use massa_models::address::Address;
 use massa_models::amount::Amount;
 use massa_models::block_id::BlockId;
@@ -61,6 +66,10 @@ pub trait ExecutionController: Send + Sync {
     ///             false: operation failed
     fn get_op_exec_status(&self) -> (HashMap<OperationId, bool>, HashMap<OperationId, bool>);
 
+    /// Get the call trace of a final smart contract operation,
+    /// if operation traces are enabled and it is still kept in memory
+    fn get_operation_trace(&self, operation_id: &OperationId) -> Option<CallTrace>;
+
     /// Get a copy of a single datastore entry with its final and active values
     ///
     /// # Return value
@@ -77,6 +86,10 @@ pub trait ExecutionController: Send + Sync {
     /// By default it returns an empty map.
     fn get_cycle_active_rolls(&self, cycle: u64) -> BTreeMap<Address, u64>;
 
+    /// Get the final values of a batch of ledger keys along with proofs
+    /// that can be checked against the final state hash
+    fn get_final_ledger_proofs(&self, keys: Vec<LedgerKey>) -> Vec<FinalLedgerProof>;
+
     /// Execute read-only SC function call without causing modifications to the consensus state
     ///
     /// # arguments
@@ -90,6 +103,20 @@ pub trait ExecutionController: Send + Sync {
         req: ReadOnlyExecutionRequest,
     ) -> Result<ReadOnlyExecutionOutput, ExecutionError>;
 
+    /// Estimate the costs and effects of an operation by simulating it
+    /// without causing modifications to the consensus state
+    ///
+    /// # arguments
+    /// * `req`: an instance of `OperationEstimationRequest` describing the operation and its sender
+    ///
+    /// # returns
+    /// An instance of `OperationEstimation` containing the gas used, the storage costs,
+    /// a suggested fee and the balance changes of the operation, or an error if the execution failed.
+    fn estimate_operation(
+        &self,
+        req: OperationEstimationRequest,
+    ) -> Result<OperationEstimation, ExecutionError>;
+
     /// List which operations inside the provided list were not executed
     fn unexecuted_ops_among(
         &self,
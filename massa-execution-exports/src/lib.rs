@@ -22,6 +22,9 @@
 //!
 //! # Architecture
 //!
+//! ## `channels.rs`
+//! Defines the channels used by the execution worker to broadcast execution outputs.
+//!
 //! ## `config.rs`
 //! Contains configuration parameters for the execution system.
 //!
@@ -34,6 +37,9 @@
 //! ## `event_store.rs`
 //! Defines an indexed, finite-size storage system for execution events.
 //!
+//! ## `trace.rs`
+//! Defines the structured traces recorded during smart contract executions.
+//!
 //! ## `types.rs`
 //! Defines useful shared structures.
 //!
@@ -44,20 +50,25 @@
 
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
+mod channels;
 mod controller_traits;
 mod error;
 mod event_store;
 mod settings;
+mod trace;
 mod types;
 
+pub use channels::ExecutionChannels;
 pub use controller_traits::{ExecutionController, ExecutionManager};
 pub use error::ExecutionError;
 pub use event_store::EventStore;
 pub use massa_sc_runtime::GasCosts;
 pub use settings::{ExecutionConfig, StorageCostsConstants};
+pub use trace::{CallTrace, ExecutionTraceStep, MAX_TRACED_VALUE_LENGTH};
 pub use types::{
-    ExecutionAddressInfo, ExecutionOutput, ExecutionStackElement, ReadOnlyCallRequest,
-    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+    BalanceChange, ExecutionAddressInfo, ExecutionOutput, ExecutionStackElement,
+    OperationEstimation, OperationEstimationRequest, ReadOnlyCallRequest, ReadOnlyExecutionOutput,
+    ReadOnlyExecutionRequest, ReadOnlyExecutionTarget, StateOverride,
 };
 
 #[cfg(any(feature = "testing", feature = "gas_calibration"))]
@@ -3,6 +3,7 @@
 //! This module provides the structures used to provide configuration parameters to the Execution system
 
 use massa_models::amount::Amount;
+use massa_models::slot::Slot;
 use massa_sc_runtime::GasCosts;
 use massa_time::MassaTime;
 use num::rational::Ratio;
@@ -44,6 +45,10 @@ pub struct ExecutionConfig {
     pub block_reward: Amount,
     /// operation validity period
     pub operation_validity_period: u64,
+    /// number of rolls slashed per executed denunciation
+    pub roll_count_to_slash_on_denunciation: u64,
+    /// number of periods after which a denunciation can no longer be included in a block
+    pub denunciation_expire_periods: u64,
     /// endorsement count
     pub endorsement_count: u64,
     /// periods per cycle
@@ -62,6 +67,10 @@ pub struct ExecutionConfig {
     pub storage_costs_constants: StorageCostsConstants,
     /// Max gas for read only executions
     pub max_read_only_gas: u64,
+    /// Fee per unit of gas used to suggest operation fees in estimations
+    pub estimated_fee_per_gas: Amount,
+    /// Fee per byte of the serialized operation type used to suggest operation fees in estimations
+    pub estimated_fee_per_byte: Amount,
     /// Gas costs
     pub gas_costs: GasCosts,
     /// Path to the initial vesting file
@@ -76,4 +85,26 @@ pub struct ExecutionConfig {
     pub hd_cache_size: usize,
     /// Amount of entries removed when `hd_cache_size` is reached
     pub snip_amount: usize,
+    /// whether to persist final events in an indexed on-disk store instead of the in-memory one
+    pub final_events_db_enabled: bool,
+    /// Path to the on-disk final events store
+    pub final_events_db_path: PathBuf,
+    /// number of most recent slots for which final events are kept on disk, unlimited if None
+    pub final_events_db_max_slots: Option<u64>,
+    /// approximate maximum size in bytes of the on-disk final events, unlimited if None
+    pub final_events_db_max_bytes: Option<u64>,
+    /// final slot at the output of which a snapshot of the final state is exported, if any
+    pub snapshot_export_slot: Option<Slot>,
+    /// path of the exported final state snapshot file
+    pub snapshot_export_path: PathBuf,
+    /// whether smart contract events broadcast is enabled
+    pub broadcast_enabled: bool,
+    /// smart contract events sender(channel) capacity
+    pub broadcast_sc_events_capacity: usize,
+    /// whether to record the traces of executed smart contract operations
+    pub operation_traces_enabled: bool,
+    /// maximum number of final operation traces kept in memory
+    pub max_final_operation_traces: usize,
+    /// maximum number of bytes taken in memory by the final operation traces
+    pub max_final_operation_traces_size: usize,
 }
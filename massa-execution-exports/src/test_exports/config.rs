@@ -3,7 +3,7 @@
 //! This file defines testing tools related to the configuration
 
 use crate::{ExecutionConfig, StorageCostsConstants};
-use massa_models::config::*;
+use massa_models::{amount::Amount, config::*};
 use massa_sc_runtime::GasCosts;
 use massa_time::MassaTime;
 use std::path::PathBuf;
@@ -33,6 +33,8 @@ impl Default for ExecutionConfig {
             endorsement_count: ENDORSEMENT_COUNT as u64,
             max_gas_per_block: MAX_GAS_PER_BLOCK,
             operation_validity_period: OPERATION_VALIDITY_PERIODS,
+            roll_count_to_slash_on_denunciation: ROLL_COUNT_TO_SLASH_ON_DENUNCIATION,
+            denunciation_expire_periods: DENUNCIATION_EXPIRE_PERIODS,
             periods_per_cycle: PERIODS_PER_CYCLE,
             // reset genesis timestamp because we are in test mode that can take a while to process
             genesis_timestamp: MassaTime::now().expect("Impossible to reset the timestamp in test"),
@@ -44,6 +46,8 @@ impl Default for ExecutionConfig {
             max_datastore_value_size: MAX_DATASTORE_VALUE_LENGTH,
             storage_costs_constants,
             max_read_only_gas: 100_000_000,
+            estimated_fee_per_gas: Amount::from_raw(1),
+            estimated_fee_per_byte: Amount::from_raw(1),
             gas_costs: GasCosts::new(
                 concat!(
                     env!("CARGO_MANIFEST_DIR"),
@@ -63,6 +67,17 @@ impl Default for ExecutionConfig {
             lru_cache_size: 1000,
             hd_cache_size: 10_000,
             snip_amount: 10,
+            final_events_db_enabled: false,
+            final_events_db_path: TempDir::new().unwrap().path().to_path_buf(),
+            final_events_db_max_slots: None,
+            final_events_db_max_bytes: None,
+            snapshot_export_slot: None,
+            snapshot_export_path: PathBuf::default(),
+            broadcast_enabled: true,
+            broadcast_sc_events_capacity: 5000,
+            operation_traces_enabled: true,
+            max_final_operation_traces: 1000,
+            max_final_operation_traces_size: 10_000_000,
         }
     }
 }
@@ -3,10 +3,11 @@
 //! This file defines utilities to mock the crate for testing purposes
 
 use crate::{
-    ExecutionAddressInfo, ExecutionController, ExecutionError, ReadOnlyExecutionOutput,
-    ReadOnlyExecutionRequest,
+    CallTrace, ExecutionAddressInfo, ExecutionController, ExecutionError, OperationEstimation,
+    OperationEstimationRequest, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
 };
-use massa_ledger_exports::LedgerEntry;
+use massa_final_state::FinalLedgerProof;
+use massa_ledger_exports::{Key as LedgerKey, LedgerEntry};
 use massa_models::{
     address::Address,
     amount::Amount,
@@ -67,6 +68,13 @@ pub enum MockExecutionControllerMessage {
         /// response channel
         response_tx: mpsc::Sender<Result<ReadOnlyExecutionOutput, ExecutionError>>,
     },
+    /// operation estimation request
+    EstimateOperation {
+        /// operation estimation request
+        req: OperationEstimationRequest,
+        /// response channel
+        response_tx: mpsc::Sender<Result<OperationEstimation, ExecutionError>>,
+    },
     /// Not executed operation among call
     UnexecutedOpsAmong {
         /// operation ids
@@ -185,6 +193,10 @@ impl ExecutionController for MockExecutionController {
         BTreeMap::default()
     }
 
+    fn get_final_ledger_proofs(&self, _keys: Vec<LedgerKey>) -> Vec<FinalLedgerProof> {
+        Vec::default()
+    }
+
     fn execute_readonly_request(
         &self,
         req: ReadOnlyExecutionRequest,
@@ -197,6 +209,18 @@ impl ExecutionController for MockExecutionController {
         response_rx.recv().unwrap()
     }
 
+    fn estimate_operation(
+        &self,
+        req: OperationEstimationRequest,
+    ) -> Result<OperationEstimation, ExecutionError> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.0
+            .lock()
+            .send(MockExecutionControllerMessage::EstimateOperation { req, response_tx })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
+
     fn unexecuted_ops_among(
         &self,
         ops: &PreHashSet<OperationId>,
@@ -226,4 +250,8 @@ impl ExecutionController for MockExecutionController {
     fn get_op_exec_status(&self) -> (HashMap<OperationId, bool>, HashMap<OperationId, bool>) {
         (HashMap::new(), HashMap::new())
     }
+
+    fn get_operation_trace(&self, _operation_id: &OperationId) -> Option<CallTrace> {
+        None
+    }
 }
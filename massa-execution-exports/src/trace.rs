@@ -0,0 +1,112 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file defines the structured traces recorded during smart contract executions
+
+use massa_models::{address::Address, amount::Amount};
+
+/// Number of bytes of a written datastore value kept in a trace
+pub const MAX_TRACED_VALUE_LENGTH: usize = 256;
+
+/// Trace of a smart contract call, with the steps it went through in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallTrace {
+    /// called address
+    pub address: Address,
+    /// coins transferred to the called address at the beginning of the call
+    pub coins: Amount,
+    /// steps recorded during the call, including nested calls
+    pub steps: Vec<ExecutionTraceStep>,
+    /// whether the call returned without error
+    pub finished: bool,
+}
+
+impl CallTrace {
+    /// Creates the trace of a call that has not returned yet
+    pub fn new(address: Address, coins: Amount) -> Self {
+        CallTrace {
+            address,
+            coins,
+            steps: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Approximate number of bytes taken in memory by the trace, including its nested calls
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<CallTrace>()
+            + self
+                .steps
+                .iter()
+                .map(ExecutionTraceStep::size)
+                .sum::<usize>()
+    }
+}
+
+/// Step recorded in the trace of a call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionTraceStep {
+    /// nested call to another smart contract
+    Call(CallTrace),
+    /// coins transferred by the smart contract
+    TransferCoins {
+        /// sending address
+        from: Address,
+        /// receiving address
+        to: Address,
+        /// transferred amount
+        amount: Amount,
+    },
+    /// datastore entry written by the smart contract
+    SetData {
+        /// address owning the datastore
+        address: Address,
+        /// datastore key
+        key: Vec<u8>,
+        /// written value, truncated to its first `MAX_TRACED_VALUE_LENGTH` bytes
+        value: Vec<u8>,
+        /// length of the full written value
+        value_length: u64,
+    },
+    /// asynchronous message sent by the smart contract
+    SendMessage {
+        /// destination address of the message
+        destination: Address,
+        /// handler function of the message
+        handler: String,
+        /// maximum gas of the message execution
+        max_gas: u64,
+        /// fee paid for the message
+        fee: Amount,
+        /// coins sent with the message
+        coins: Amount,
+    },
+    /// gas remaining when the control came back from the VM
+    GasCheckpoint {
+        /// remaining gas
+        remaining_gas: u64,
+    },
+}
+
+impl ExecutionTraceStep {
+    /// Creates the step of a datastore write, keeping only the beginning of large values
+    pub fn set_data(address: Address, key: &[u8], value: &[u8]) -> Self {
+        ExecutionTraceStep::SetData {
+            address,
+            key: key.to_vec(),
+            value: value[..value.len().min(MAX_TRACED_VALUE_LENGTH)].to_vec(),
+            value_length: value.len() as u64,
+        }
+    }
+
+    /// Approximate number of bytes taken in memory by the step
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<ExecutionTraceStep>()
+            + match self {
+                ExecutionTraceStep::Call(call) => call.size(),
+                ExecutionTraceStep::SetData { key, value, .. } => key.len() + value.len(),
+                ExecutionTraceStep::SendMessage { handler, .. } => handler.len(),
+                ExecutionTraceStep::TransferCoins { .. }
+                | ExecutionTraceStep::GasCheckpoint { .. } => 0,
+            }
+    }
+}
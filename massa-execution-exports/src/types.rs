@@ -3,10 +3,12 @@
 //! This file exports useful types used to interact with the execution worker
 
 use crate::event_store::EventStore;
+use crate::trace::CallTrace;
 use massa_final_state::StateChanges;
 use massa_models::datastore::Datastore;
 use massa_models::{
     address::Address, address::ExecutionAddressCycleInfo, amount::Amount, block_id::BlockId,
+    bytecode::Bytecode, operation::OperationId, operation::OperationType, prehash::PreHashMap,
     slot::Slot,
 };
 use std::collections::{BTreeMap, BTreeSet};
@@ -47,6 +49,8 @@ pub struct ExecutionOutput {
     pub state_changes: StateChanges,
     /// events emitted by the execution step
     pub events: EventStore,
+    /// traces of the smart contract operations executed in the step, if operation traces are enabled
+    pub operation_traces: PreHashMap<OperationId, CallTrace>,
 }
 
 /// structure describing the output of a read only execution
@@ -58,6 +62,21 @@ pub struct ReadOnlyExecutionOutput {
     pub gas_cost: u64,
     /// Returned value from the module call
     pub call_result: Vec<u8>,
+    /// Net amount of coins spent on storage costs during the execution
+    pub storage_cost: Amount,
+    /// Balance changes caused by the execution, indexed by address
+    pub balance_changes: BTreeMap<Address, BalanceChange>,
+    /// Trace of the execution, if it was requested
+    pub trace: Option<CallTrace>,
+}
+
+/// structure describing the change of the balance of an address caused by an execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// balance before the execution
+    pub previous: Amount,
+    /// balance after the execution
+    pub predicted: Amount,
 }
 
 /// structure describing different types of read-only execution request
@@ -73,6 +92,23 @@ pub struct ReadOnlyExecutionRequest {
     ///
     /// Whether to start execution from final or active state
     pub is_final: bool,
+    /// State overrides applied on top of the start state for this execution only
+    pub state_overrides: BTreeMap<Address, StateOverride>,
+    /// Whether to record a trace of the execution, returned only if the execution succeeds
+    pub with_trace: bool,
+}
+
+/// Overrides of the state of an address for a single read-only execution
+#[derive(Debug, Clone, Default)]
+pub struct StateOverride {
+    /// balance to use instead of the actual one
+    pub balance: Option<Amount>,
+    /// bytecode to use instead of the actual one
+    pub bytecode: Option<Bytecode>,
+    /// datastore entries to set, other entries are kept
+    pub datastore: Datastore,
+    /// roll count to use instead of the actual one
+    pub roll_count: Option<u64>,
 }
 
 /// structure describing different possible targets of a read-only execution request
@@ -90,6 +126,38 @@ pub enum ReadOnlyExecutionTarget {
         /// Parameter to pass to the target function
         parameter: Vec<u8>,
     },
+
+    /// Execute the effects of an operation sent by the first address of the call stack,
+    /// without debiting its fee
+    OperationExecution(OperationType),
+}
+
+/// structure describing a request to estimate the costs and effects of an operation
+#[derive(Debug, Clone)]
+pub struct OperationEstimationRequest {
+    /// Address of the operation sender
+    pub sender_address: Address,
+    /// Operation to simulate
+    pub op: OperationType,
+    /// execution start state
+    ///
+    /// Whether to start execution from final or active state
+    pub is_final: bool,
+}
+
+/// structure describing the estimated costs and effects of an operation
+#[derive(Debug, Clone)]
+pub struct OperationEstimation {
+    /// Slot at which the operation was simulated
+    pub executed_at: Slot,
+    /// Gas used by the operation
+    pub gas_used: u64,
+    /// Net amount of coins spent on storage costs by the operation
+    pub storage_cost: Amount,
+    /// Suggested fee for the operation, covering the gas used and the size of the operation
+    pub suggested_fee: Amount,
+    /// Balance changes caused by the operation, indexed by address
+    pub balance_changes: BTreeMap<Address, BalanceChange>,
 }
 
 /// structure describing a read-only call
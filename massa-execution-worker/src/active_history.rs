@@ -3,7 +3,8 @@ use massa_ledger_exports::{
     LedgerEntry, LedgerEntryUpdate, SetOrDelete, SetOrKeep, SetUpdateOrDelete,
 };
 use massa_models::{
-    address::Address, amount::Amount, bytecode::Bytecode, operation::OperationId, slot::Slot,
+    address::Address, amount::Amount, bytecode::Bytecode, denunciation::DenunciationId,
+    operation::OperationId, slot::Slot,
 };
 use massa_pos_exports::DeferredCredits;
 use std::collections::{HashMap, VecDeque};
@@ -59,6 +60,22 @@ impl ActiveHistory {
         HistorySearchResult::NoInfo
     }
 
+    /// Lazily query (from end to beginning) the active list of executed denunciations to check if a denunciation was executed.
+    ///
+    /// Returns a `HistorySearchResult`.
+    pub fn fetch_executed_denunciation(&self, de_id: &DenunciationId) -> HistorySearchResult<()> {
+        for history_element in self.0.iter().rev() {
+            if history_element
+                .state_changes
+                .executed_denunciations_changes
+                .contains_key(de_id)
+            {
+                return HistorySearchResult::Present(());
+            }
+        }
+        HistorySearchResult::NoInfo
+    }
+
     /// Lazily query (from end to beginning) the active balance of an address after a given index.
     ///
     /// Returns a `HistorySearchResult`.
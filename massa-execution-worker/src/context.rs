@@ -7,15 +7,18 @@
 //! More generally, the context acts only on its own state
 //! and does not write anything persistent to the consensus state.
 
+use crate::execution_trace::ExecutionTracer;
 use crate::speculative_async_pool::SpeculativeAsyncPool;
+use crate::speculative_executed_denunciations::SpeculativeExecutedDenunciations;
 use crate::speculative_executed_ops::SpeculativeExecutedOps;
 use crate::speculative_ledger::SpeculativeLedger;
 use crate::vesting_manager::VestingManager;
 use crate::{active_history::ActiveHistory, speculative_roll_state::SpeculativeRollState};
 use massa_async_pool::{AsyncMessage, AsyncMessageId};
-use massa_executed_ops::ExecutedOpsChanges;
+use massa_executed_ops::{ExecutedDenunciationsChanges, ExecutedOpsChanges};
 use massa_execution_exports::{
-    EventStore, ExecutionConfig, ExecutionError, ExecutionOutput, ExecutionStackElement,
+    CallTrace, EventStore, ExecutionConfig, ExecutionError, ExecutionOutput, ExecutionStackElement,
+    ExecutionTraceStep, StateOverride,
 };
 use massa_final_state::{FinalState, StateChanges};
 use massa_ledger_exports::LedgerChanges;
@@ -25,8 +28,10 @@ use massa_models::{
     address::Address,
     amount::Amount,
     block_id::BlockId,
+    denunciation::DenunciationId,
     operation::OperationId,
     output_event::{EventExecutionContext, SCOutputEvent},
+    prehash::PreHashMap,
     slot::Slot,
 };
 use massa_module_cache::controller::ModuleCache;
@@ -41,8 +46,9 @@ use tracing::debug;
 /// A snapshot taken from an `ExecutionContext` and that represents its current state.
 /// The `ExecutionContext` state can then be restored later from this snapshot.
 pub struct ExecutionContextSnapshot {
-    /// speculative ledger changes caused so far in the context
-    pub ledger_changes: LedgerChanges,
+    /// speculative ledger changes caused so far in the context,
+    /// with the storage costs paid and refunded for them
+    pub ledger_changes: (LedgerChanges, Amount, Amount),
 
     /// speculative asynchronous pool messages emitted so far in the context
     pub async_pool_changes: Vec<(AsyncMessageId, AsyncMessage)>,
@@ -50,6 +56,9 @@ pub struct ExecutionContextSnapshot {
     /// speculative list of operations executed
     pub executed_ops: ExecutedOpsChanges,
 
+    /// speculative list of denunciations executed
+    pub executed_denunciations: ExecutedDenunciationsChanges,
+
     /// speculative roll state changes caused so far in the context
     pub pos_changes: PoSChanges,
 
@@ -102,6 +111,9 @@ pub struct ExecutionContext {
     /// speculative list of executed operations
     speculative_executed_ops: SpeculativeExecutedOps,
 
+    /// speculative list of executed denunciations
+    speculative_executed_denunciations: SpeculativeExecutedDenunciations,
+
     /// max gas for this execution
     pub max_gas: u64,
 
@@ -143,6 +155,12 @@ pub struct ExecutionContext {
 
     // Vesting Manager
     pub vesting_manager: Arc<VestingManager>,
+
+    /// recorder of the trace of the current execution, if it is traced
+    pub tracer: Option<ExecutionTracer>,
+
+    /// traces of the smart contract operations executed so far in the context
+    pub operation_traces: PreHashMap<OperationId, CallTrace>,
 }
 
 impl ExecutionContext {
@@ -180,7 +198,14 @@ impl ExecutionContext {
                 final_state.clone(),
                 active_history.clone(),
             ),
-            speculative_executed_ops: SpeculativeExecutedOps::new(final_state, active_history),
+            speculative_executed_ops: SpeculativeExecutedOps::new(
+                final_state.clone(),
+                active_history.clone(),
+            ),
+            speculative_executed_denunciations: SpeculativeExecutedDenunciations::new(
+                final_state,
+                active_history,
+            ),
             max_gas: Default::default(),
             slot: Slot::new(0, 0),
             created_addr_index: Default::default(),
@@ -196,6 +221,8 @@ impl ExecutionContext {
             module_cache,
             config,
             vesting_manager,
+            tracer: Default::default(),
+            operation_traces: Default::default(),
         }
     }
 
@@ -207,6 +234,7 @@ impl ExecutionContext {
             async_pool_changes: self.speculative_async_pool.get_snapshot(),
             pos_changes: self.speculative_roll_state.get_snapshot(),
             executed_ops: self.speculative_executed_ops.get_snapshot(),
+            executed_denunciations: self.speculative_executed_denunciations.get_snapshot(),
             created_addr_index: self.created_addr_index,
             created_event_index: self.created_event_index,
             stack: self.stack.clone(),
@@ -232,6 +260,8 @@ impl ExecutionContext {
             .reset_to_snapshot(snapshot.pos_changes);
         self.speculative_executed_ops
             .reset_to_snapshot(snapshot.executed_ops);
+        self.speculative_executed_denunciations
+            .reset_to_snapshot(snapshot.executed_denunciations);
         self.created_addr_index = snapshot.created_addr_index;
         self.created_event_index = snapshot.created_event_index;
         self.stack = snapshot.stack;
@@ -258,6 +288,7 @@ impl ExecutionContext {
     /// * `slot`: slot at which the execution will happen
     /// * `req`: parameters of the read only execution
     /// * `final_state`: thread-safe access to the final state. Note that this will be used only for reading, never for writing
+    /// * `state_overrides`: per-address overrides of the state, applied for this execution only
     ///
     /// # returns
     /// A `ExecutionContext` instance ready for a read-only execution
@@ -271,6 +302,7 @@ impl ExecutionContext {
         active_history: Arc<RwLock<ActiveHistory>>,
         module_cache: Arc<RwLock<ModuleCache>>,
         vesting_manager: Arc<VestingManager>,
+        state_overrides: &BTreeMap<Address, StateOverride>,
     ) -> Self {
         // Deterministically seed the unsafe RNG to allow the bytecode to use it.
         // Note that consecutive read-only calls for the same slot will get the same random seed.
@@ -287,8 +319,8 @@ impl ExecutionContext {
         // but not cryptographically secure (and that's ok because the internal state is exposed anyways)
         let unsafe_rng = Xoshiro256PlusPlus::from_seed(seed);
 
-        // return readonly context
-        ExecutionContext {
+        // create readonly context
+        let mut context = ExecutionContext {
             max_gas,
             slot,
             stack: call_stack,
@@ -301,7 +333,22 @@ impl ExecutionContext {
                 module_cache,
                 vesting_manager,
             )
-        }
+        };
+
+        // apply the state overrides
+        context.speculative_ledger.set_overrides(state_overrides);
+        context.speculative_roll_state.set_roll_overrides(
+            state_overrides
+                .iter()
+                .filter_map(|(addr, state_override)| {
+                    state_override
+                        .roll_count
+                        .map(|roll_count| (*addr, roll_count))
+                })
+                .collect(),
+        );
+
+        context
     }
 
     /// This function takes a batch of asynchronous operations to execute, removing them from the speculative pool.
@@ -495,6 +542,11 @@ impl ExecutionContext {
         self.speculative_ledger.get_balance(address)
     }
 
+    /// gets the net amount of coins spent on storage costs so far in the context
+    pub fn get_storage_cost(&self) -> Amount {
+        self.speculative_ledger.get_storage_cost()
+    }
+
     /// Get deferred credits of an address starting from a given slot
     pub fn get_address_deferred_credits(
         &self,
@@ -685,6 +737,32 @@ impl ExecutionContext {
         )
     }
 
+    /// Try to slash `roll_count` rolls from the denounced address. If not enough rolls,
+    /// slash the available amount of the future deferred credits of the address.
+    ///
+    /// # Arguments
+    /// * `denounced_addr`: address to slash the rolls from
+    /// * `roll_count`: number of rolls to slash
+    ///
+    /// # Returns
+    /// The slashed amount
+    pub fn try_slash_rolls(
+        &mut self,
+        denounced_addr: &Address,
+        roll_count: u64,
+    ) -> Result<Amount, ExecutionError> {
+        let min_credit_slot = self
+            .slot
+            .get_next_slot(self.config.thread_count)
+            .expect("unexpected slot overflow in context.try_slash_rolls");
+        self.speculative_roll_state.try_slash_rolls(
+            denounced_addr,
+            roll_count,
+            self.config.roll_price,
+            min_credit_slot,
+        )
+    }
+
     /// Update production statistics of an address.
     ///
     /// # Arguments
@@ -775,15 +853,46 @@ impl ExecutionContext {
             async_pool_changes: self.speculative_async_pool.take(),
             pos_changes: self.speculative_roll_state.take(),
             executed_ops_changes: self.speculative_executed_ops.take(),
+            executed_denunciations_changes: self.speculative_executed_denunciations.take(),
         };
         ExecutionOutput {
             slot,
             block_id: std::mem::take(&mut self.opt_block_id),
             state_changes,
             events: std::mem::take(&mut self.events),
+            operation_traces: std::mem::take(&mut self.operation_traces),
+        }
+    }
+
+    /// Records the beginning of a call in the execution trace, if the execution is traced
+    pub fn trace_enter_call(&mut self, address: Address, coins: Amount) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.enter_call(address, coins);
+        }
+    }
+
+    /// Records the return of the current call in the execution trace, if the execution is traced
+    pub fn trace_exit_call(&mut self) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.exit_call();
         }
     }
 
+    /// Records a step of the current call in the execution trace, if the execution is traced
+    pub fn trace_step(&mut self, step: ExecutionTraceStep) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.record(step);
+        }
+    }
+
+    /// Stops tracing the execution and returns the recorded call tree, if any
+    ///
+    /// # Arguments
+    /// * `success`: whether the traced execution succeeded
+    pub fn take_trace(&mut self, success: bool) -> Option<CallTrace> {
+        self.tracer.take().and_then(|tracer| tracer.finish(success))
+    }
+
     /// Sets a bytecode for an address in the speculative ledger.
     /// Fail if the address is absent from the ledger.
     ///
@@ -873,6 +982,23 @@ impl ExecutionContext {
             .insert_executed_op(op_id, op_exec_status, op_valid_until_slot)
     }
 
+    /// Check if a denunciation was previously executed (to prevent reuse)
+    pub fn is_denunciation_executed(&self, de_id: &DenunciationId) -> bool {
+        self.speculative_executed_denunciations
+            .is_denunciation_executed(de_id)
+    }
+
+    /// Insert an executed denunciation.
+    /// Does not check for reuse, please use `is_denunciation_executed` before.
+    ///
+    /// # Arguments
+    /// * `de_id`: denunciation ID
+    /// * `de_slot`: slot of the denounced items
+    pub fn insert_executed_denunciation(&mut self, de_id: DenunciationId, de_slot: Slot) {
+        self.speculative_executed_denunciations
+            .insert_executed_denunciation(de_id, de_slot)
+    }
+
     /// gets the cycle information for an address
     pub fn get_address_cycle_infos(
         &self,
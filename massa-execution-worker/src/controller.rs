@@ -6,15 +6,23 @@
 use crate::execution::ExecutionState;
 use crate::request_queue::{RequestQueue, RequestWithResponseSender};
 use massa_execution_exports::{
-    ExecutionAddressInfo, ExecutionConfig, ExecutionController, ExecutionError, ExecutionManager,
-    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
+    CallTrace, ExecutionAddressInfo, ExecutionConfig, ExecutionController, ExecutionError,
+    ExecutionManager, ExecutionStackElement, OperationEstimation, OperationEstimationRequest,
+    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
 };
+use massa_final_state::FinalLedgerProof;
+use massa_ledger_exports::Key as LedgerKey;
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
 use massa_models::prehash::{PreHashMap, PreHashSet};
 use massa_models::stats::ExecutionStats;
-use massa_models::{address::Address, amount::Amount, operation::OperationId};
+use massa_models::{
+    address::Address,
+    amount::Amount,
+    operation::{OperationId, OperationType, OperationTypeSerializer},
+};
 use massa_models::{block_id::BlockId, slot::Slot};
+use massa_serialization::Serializer;
 use massa_storage::Storage;
 use parking_lot::{Condvar, Mutex, RwLock};
 use std::collections::{BTreeMap, HashMap};
@@ -87,6 +95,8 @@ impl ExecutionInputData {
 #[derive(Clone)]
 /// implementation of the execution controller
 pub struct ExecutionControllerImpl {
+    /// execution configuration
+    pub(crate) config: ExecutionConfig,
     /// input data to process in the VM loop
     /// with a wake-up condition variable that needs to be triggered when the data changes
     pub(crate) input_data: Arc<(Condvar, Mutex<ExecutionInputData>)>,
@@ -137,6 +147,13 @@ impl ExecutionController for ExecutionControllerImpl {
             .get_filtered_sc_output_event(filter)
     }
 
+    /// Get the call trace of a final smart contract operation
+    fn get_operation_trace(&self, operation_id: &OperationId) -> Option<CallTrace> {
+        self.execution_state
+            .read()
+            .get_operation_trace(operation_id)
+    }
+
     /// Get the final and candidate values of balance.
     ///
     /// # Return value
@@ -174,6 +191,11 @@ impl ExecutionController for ExecutionControllerImpl {
         self.execution_state.read().get_cycle_active_rolls(cycle)
     }
 
+    /// Get the final values of a batch of ledger keys along with their proofs
+    fn get_final_ledger_proofs(&self, keys: Vec<LedgerKey>) -> Vec<FinalLedgerProof> {
+        self.execution_state.read().get_final_ledger_proofs(&keys)
+    }
+
     /// Executes a read-only request
     /// Read-only requests do not modify consensus state
     fn execute_readonly_request(
@@ -215,6 +237,63 @@ impl ExecutionController for ExecutionControllerImpl {
         }
     }
 
+    /// Estimates the costs and effects of an operation through a read-only execution
+    fn estimate_operation(
+        &self,
+        req: OperationEstimationRequest,
+    ) -> Result<OperationEstimation, ExecutionError> {
+        // simulate the operation with the gas it allows itself to use
+        let max_gas = match req.op.executed_op() {
+            OperationType::ExecuteSC { max_gas, .. } | OperationType::CallSC { max_gas, .. } => {
+                *max_gas
+            }
+            _ => 0,
+        };
+        // operations without gas, like transactions and roll operations, still take block space
+        let mut op_bytes = Vec::new();
+        OperationTypeSerializer::new()
+            .serialize(&req.op, &mut op_bytes)
+            .map_err(|err| {
+                ExecutionError::RuntimeError(format!("could not serialize the operation: {}", err))
+            })?;
+        let output = self.execute_readonly_request(ReadOnlyExecutionRequest {
+            max_gas,
+            call_stack: vec![ExecutionStackElement {
+                address: req.sender_address,
+                coins: Default::default(),
+                owned_addresses: vec![req.sender_address],
+                operation_datastore: None,
+            }],
+            target: ReadOnlyExecutionTarget::OperationExecution(req.op),
+            is_final: req.is_final,
+            state_overrides: Default::default(),
+            with_trace: false,
+        })?;
+
+        // suggest a fee covering the gas used and the size of the operation
+        let suggested_fee = self
+            .config
+            .estimated_fee_per_gas
+            .checked_mul_u64(output.gas_cost)
+            .and_then(|gas_fee| {
+                self.config
+                    .estimated_fee_per_byte
+                    .checked_mul_u64(op_bytes.len() as u64)
+                    .and_then(|size_fee| gas_fee.checked_add(size_fee))
+            })
+            .ok_or_else(|| {
+                ExecutionError::RuntimeError("overflow when computing the suggested fee".into())
+            })?;
+
+        Ok(OperationEstimation {
+            executed_at: output.out.slot,
+            gas_used: output.gas_cost,
+            storage_cost: output.storage_cost,
+            suggested_fee,
+            balance_changes: output.balance_changes,
+        })
+    }
+
     /// List which operations inside the provided list were not executed
     fn unexecuted_ops_among(
         &self,
@@ -10,26 +10,36 @@
 
 use crate::active_history::{ActiveHistory, HistorySearchResult};
 use crate::context::{ExecutionContext, ExecutionContextSnapshot};
+use crate::execution_trace::OperationTraceStore;
+use crate::final_events_db::FinalEventsDB;
 use crate::interface_impl::InterfaceImpl;
 use crate::stats::ExecutionStatsCounter;
 use crate::vesting_manager::VestingManager;
 use massa_async_pool::AsyncMessage;
 use massa_execution_exports::{
-    EventStore, ExecutionConfig, ExecutionError, ExecutionOutput, ExecutionStackElement,
-    ReadOnlyExecutionOutput, ReadOnlyExecutionRequest, ReadOnlyExecutionTarget,
+    BalanceChange, CallTrace, EventStore, ExecutionChannels, ExecutionConfig, ExecutionError,
+    ExecutionOutput, ExecutionStackElement, ExecutionTraceStep, ReadOnlyExecutionOutput,
+    ReadOnlyExecutionRequest, ReadOnlyExecutionTarget, StateOverride,
 };
-use massa_final_state::FinalState;
-use massa_ledger_exports::{SetOrDelete, SetUpdateOrDelete};
+use massa_final_state::{FinalLedgerProof, FinalState};
+use massa_ledger_exports::{
+    Key as LedgerKey, LedgerChanges, SetOrDelete, SetOrKeep, SetUpdateOrDelete,
+};
+use massa_metrics::{EXECUTION_CURSOR_PERIOD, EXECUTION_CURSOR_THREAD, EXECUTION_SLOT_LAG};
 use massa_models::address::ExecutionAddressCycleInfo;
 use massa_models::bytecode::Bytecode;
+use massa_models::config::{
+    DENUNCIATIONS_BLOCK_COMPONENT_VERSION, MULTISIG_ADDRESS_COMPONENT_VERSION,
+};
 use massa_models::execution::EventFilter;
 use massa_models::output_event::SCOutputEvent;
 use massa_models::prehash::PreHashSet;
 use massa_models::stats::ExecutionStats;
-use massa_models::timeslots::get_block_slot_timestamp;
+use massa_models::timeslots::{get_block_slot_timestamp, get_latest_block_slot_at_timestamp};
 use massa_models::{
     address::Address,
     block_id::BlockId,
+    denunciation::{Denunciation, DenunciationId},
     operation::{OperationId, OperationType, SecureShareOperation},
 };
 use massa_models::{amount::Amount, slot::Slot};
@@ -38,7 +48,8 @@ use massa_module_cache::controller::ModuleCache;
 use massa_pos_exports::SelectorController;
 use massa_sc_runtime::{Interface, Response, VMError};
 use massa_storage::Storage;
-use massa_versioning_worker::versioning::MipStore;
+use massa_time::MassaTime;
+use massa_versioning_worker::versioning::{MipComponent, MipStore};
 use parking_lot::{Mutex, RwLock};
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
@@ -68,6 +79,10 @@ pub(crate) struct ExecutionState {
     pub final_cursor: Slot,
     // store containing execution events that became final
     final_events: EventStore,
+    // optional persistent store of final execution events, replacing `final_events` when enabled
+    final_events_db: Option<FinalEventsDB>,
+    // store containing the call traces of final operations, when enabled
+    final_operation_traces: OperationTraceStore,
     // final state with atomic R/W access
     final_state: Arc<RwLock<FinalState>>,
     // execution context (see documentation in context.rs)
@@ -82,6 +97,8 @@ pub(crate) struct ExecutionState {
     vesting_manager: Arc<VestingManager>,
     // MipStore (Versioning)
     mip_store: MipStore,
+    // channels used to broadcast execution outputs
+    channels: ExecutionChannels,
 }
 
 impl ExecutionState {
@@ -90,6 +107,8 @@ impl ExecutionState {
     /// # Arguments
     /// * `config`: execution configuration
     /// * `final_state`: atomic access to the final state
+    /// * `mip_store`: versioning store
+    /// * `channels`: channels used to broadcast execution outputs
     ///
     /// # returns
     /// A new `ExecutionState`
@@ -97,6 +116,7 @@ impl ExecutionState {
         config: ExecutionConfig,
         final_state: Arc<RwLock<FinalState>>,
         mip_store: MipStore,
+        channels: ExecutionChannels,
     ) -> ExecutionState {
         // Get the slot at the output of which the final state is attached.
         // This should be among the latest final slots.
@@ -138,6 +158,21 @@ impl ExecutionState {
             vesting_manager.clone(),
         )));
 
+        // Open the persistent final events store if enabled
+        let final_events_db = config.final_events_db_enabled.then(|| {
+            let db = FinalEventsDB::new(
+                config.final_events_db_path.clone(),
+                config.thread_count,
+                config.final_events_db_max_slots,
+                config.final_events_db_max_bytes,
+            );
+            info!(
+                "final events store opened, last stored slot: {:?}",
+                db.get_slot()
+            );
+            db
+        });
+
         // Instantiate the interface providing ABI access to the VM, share the execution context with it
         let execution_interface = Box::new(InterfaceImpl::new(
             config.clone(),
@@ -153,6 +188,9 @@ impl ExecutionState {
             active_history,
             // empty final event store: it is not recovered through bootstrap
             final_events: Default::default(),
+            final_events_db,
+            // empty final trace store: it is not recovered through bootstrap
+            final_operation_traces: Default::default(),
             // no active slots executed yet: set active_cursor to the last final block
             active_cursor: last_final_slot,
             final_cursor: last_final_slot,
@@ -161,6 +199,7 @@ impl ExecutionState {
             config,
             vesting_manager,
             mip_store,
+            channels,
         }
     }
 
@@ -169,6 +208,39 @@ impl ExecutionState {
         self.stats_counter.get_stats(self.active_cursor)
     }
 
+    /// Update the metrics of the execution cursors and of how far behind the current slot they are
+    pub fn update_metrics(&self) {
+        let latest_slot = MassaTime::now().ok().and_then(|now| {
+            get_latest_block_slot_at_timestamp(
+                self.config.thread_count,
+                self.config.t0,
+                self.config.genesis_timestamp,
+                now,
+            )
+            .ok()
+            .flatten()
+        });
+        for (cursor, slot) in [("final", self.final_cursor), ("active", self.active_cursor)] {
+            EXECUTION_CURSOR_PERIOD
+                .with_label_values(&[cursor])
+                .set(slot.period as i64);
+            EXECUTION_CURSOR_THREAD
+                .with_label_values(&[cursor])
+                .set(slot.thread as i64);
+            // a cursor ahead of the current slot is not lagging
+            let lag = latest_slot
+                .and_then(|latest_slot| {
+                    latest_slot
+                        .slots_since(&slot, self.config.thread_count)
+                        .ok()
+                })
+                .unwrap_or(0);
+            EXECUTION_SLOT_LAG
+                .with_label_values(&[cursor])
+                .set(lag as i64);
+        }
+    }
+
     /// Applies the output of an execution to the final execution state.
     /// The newly applied final output should be from the slot just after the last executed final slot
     ///
@@ -192,6 +264,20 @@ impl ExecutionState {
             .write()
             .finalize(exec_out.slot, exec_out.state_changes);
 
+        // export a snapshot of the final state if it was requested at this slot
+        if self.config.snapshot_export_slot == Some(exec_out.slot) {
+            if let Err(err) = self
+                .final_state
+                .read()
+                .export_snapshot(&self.mip_store.0.read(), &self.config.snapshot_export_path)
+            {
+                warn!(
+                    "could not export the final state snapshot at slot {}: {}",
+                    exec_out.slot, err
+                );
+            }
+        }
+
         // update the final ledger's slot
         self.final_cursor = exec_out.slot;
 
@@ -203,8 +289,22 @@ impl ExecutionState {
 
         // append generated events to the final event store
         exec_out.events.finalize();
-        self.final_events.extend(exec_out.events);
-        self.final_events.prune(self.config.max_final_events);
+        self.broadcast_events(&exec_out.events);
+        if let Some(final_events_db) = self.final_events_db.as_mut() {
+            final_events_db.apply_events(&exec_out.events.0, exec_out.slot);
+        } else {
+            self.final_events.extend(exec_out.events);
+            self.final_events.prune(self.config.max_final_events);
+        }
+
+        // keep the call traces of the final operations
+        if self.config.operation_traces_enabled {
+            self.final_operation_traces.extend(
+                std::mem::take(&mut exec_out.operation_traces),
+                self.config.max_final_operation_traces,
+                self.config.max_final_operation_traces_size,
+            );
+        }
     }
 
     /// Applies an execution output to the active (non-final) state
@@ -223,10 +323,27 @@ impl ExecutionState {
         // update active cursor to reflect the new latest active slot
         self.active_cursor = exec_out.slot;
 
+        // broadcast the candidate events
+        self.broadcast_events(&exec_out.events);
+
         // add the execution output at the end of the output history
         self.active_history.write().0.push_back(exec_out);
     }
 
+    /// Broadcasts the given events to the subscribers, if broadcast is enabled
+    fn broadcast_events(&self, events: &EventStore) {
+        if !self.config.broadcast_enabled {
+            return;
+        }
+        for event in events.0.iter() {
+            if let Err(err) = self.channels.sc_event_sender.send(event.clone()) {
+                // no subscriber is listening
+                debug!("cannot broadcast smart contract event: {}", err);
+                break;
+            }
+        }
+    }
+
     /// Helper function.
     /// Within a locked execution context (lock is taken at the beginning of the function then released at the end):
     /// - if not yet executed then transfer fee and add the operation to the context then return a context snapshot
@@ -252,6 +369,16 @@ impl ExecutionState {
             ));
         }
 
+        // the id of a multi-signature operation depends on its sender and collected signatures,
+        // so it is also checked against the hash signed by the members
+        if let Some(multisig_hash) = operation.content.compute_multisig_hash() {
+            if context.is_op_executed(&OperationId::new(multisig_hash)) {
+                return Err(ExecutionError::IncludeOperationError(
+                    "multi-signature operation was executed previously".to_string(),
+                ));
+            }
+        }
+
         // debit the fee from the operation sender
         // fail execution if there are not enough coins
         if let Err(err) =
@@ -280,6 +407,114 @@ impl ExecutionState {
         Ok(context_snapshot)
     }
 
+    /// Check whether the block version allowing denunciations in block headers is active at the given slot
+    fn are_block_denunciations_active(&self, slot: &Slot) -> bool {
+        match get_block_slot_timestamp(
+            self.config.thread_count,
+            self.config.t0,
+            self.config.genesis_timestamp,
+            *slot,
+        ) {
+            Ok(slot_ts) => {
+                self.mip_store
+                    .get_component_version_at(&MipComponent::Block, slot_ts)
+                    >= DENUNCIATIONS_BLOCK_COMPONENT_VERSION
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Check whether the address version allowing multi-signature addresses and operations is active at the given slot
+    fn are_multisig_addresses_active(&self, slot: &Slot) -> bool {
+        match get_block_slot_timestamp(
+            self.config.thread_count,
+            self.config.t0,
+            self.config.genesis_timestamp,
+            *slot,
+        ) {
+            Ok(slot_ts) => {
+                self.mip_store
+                    .get_component_version_at(&MipComponent::Address, slot_ts)
+                    >= MULTISIG_ADDRESS_COMPONENT_VERSION
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Execute a denunciation in the context of a block:
+    /// slash the rolls (or deferred credits) of the denounced staker
+    /// and credit the block creator with half of the slashed amount.
+    ///
+    /// # Arguments
+    /// * `denunciation`: denunciation to execute
+    /// * `block_slot`: slot of the block in which the denunciation is included
+    /// * `block_credits`: mutable reference towards the total block reward/fee credits
+    pub fn execute_denunciation(
+        &self,
+        denunciation: &Denunciation,
+        block_slot: Slot,
+        block_credits: &mut Amount,
+    ) -> Result<(), ExecutionError> {
+        let de_id = DenunciationId::from(denunciation);
+        let de_slot = *denunciation.get_slot();
+
+        // check that the denounced slot is not in the future nor expired
+        if de_slot > block_slot
+            || block_slot.period.saturating_sub(de_slot.period)
+                > self.config.denunciation_expire_periods
+        {
+            return Err(ExecutionError::IncludeDenunciationError(format!(
+                "denunciation for slot {} cannot be included at slot {}",
+                de_slot, block_slot
+            )));
+        }
+
+        // check the denunciation signatures
+        if !denunciation.is_valid().unwrap_or(false) {
+            return Err(ExecutionError::IncludeDenunciationError(
+                "invalid denunciation".to_string(),
+            ));
+        }
+
+        // acquire write access to the context
+        let mut context = context_guard!(self);
+
+        // check that the denunciation was not executed previously (replay protection)
+        if context.is_denunciation_executed(&de_id) {
+            return Err(ExecutionError::IncludeDenunciationError(
+                "denunciation was already executed".to_string(),
+            ));
+        }
+
+        // mark the denunciation as executed, even if the slash fails, to prevent reuse
+        context.insert_executed_denunciation(de_id, de_slot);
+
+        // slash the denounced staker
+        let denounced_addr = Address::from_public_key(denunciation.get_public_key());
+        let slashed_amount = context.try_slash_rolls(
+            &denounced_addr,
+            self.config.roll_count_to_slash_on_denunciation,
+        )?;
+
+        // credit half of the slashed amount to the block creator
+        let reward = slashed_amount
+            .checked_div_u64(2)
+            .expect("critical: slashed amount checked_div factor is 0");
+        *block_credits = block_credits.saturating_add(reward);
+
+        // emit an event
+        let event = context.event_create(
+            format!(
+                "denunciation {} executed: {} slashed from {}",
+                de_id, slashed_amount, denounced_addr
+            ),
+            false,
+        );
+        context.event_emit(event);
+
+        Ok(())
+    }
+
     /// Execute an operation in the context of a block.
     /// Assumes the execution context was initialized at the beginning of the slot.
     ///
@@ -324,6 +559,28 @@ impl ExecutionState {
             ));
         }
 
+        // multi-signature addresses and operations are only accepted once their MIP is active
+        let multisig_hash = operation.content.compute_multisig_hash();
+        if (multisig_hash.is_some()
+            || operation
+                .get_ledger_involved_addresses()
+                .iter()
+                .any(|addr| matches!(addr, Address::MultiSig(_))))
+            && !self.are_multisig_addresses_active(&block_slot)
+        {
+            return Err(ExecutionError::IncludeOperationError(
+                "multi-signature addresses are not active yet".to_string(),
+            ));
+        }
+
+        // check that multi-signature operations are signed by enough members
+        if let Err(err) = operation.verify_multisig() {
+            return Err(ExecutionError::IncludeOperationError(format!(
+                "invalid multi-signature operation: {}",
+                err
+            )));
+        }
+
         // get operation ID
         let operation_id = operation.id;
 
@@ -338,29 +595,48 @@ impl ExecutionState {
         // update block credits
         *block_credits = new_block_credits;
 
+        // multi-signature operations execute the operation they wrap
+        let executed_op = operation.content.op.executed_op();
+
+        // trace smart contract operations if requested
+        if self.config.operation_traces_enabled
+            && matches!(
+                executed_op,
+                OperationType::ExecuteSC { .. } | OperationType::CallSC { .. }
+            )
+        {
+            context_guard!(self).tracer = Some(Default::default());
+        }
+
         // Call the execution process specific to the operation type.
-        let execution_result = match &operation.content.op {
-            OperationType::ExecuteSC { .. } => {
-                self.execute_executesc_op(&operation.content.op, sender_addr)
-            }
-            OperationType::CallSC { .. } => {
-                self.execute_callsc_op(&operation.content.op, sender_addr)
-            }
+        let execution_result = match executed_op {
+            OperationType::ExecuteSC { .. } => self
+                .execute_executesc_op(executed_op, sender_addr)
+                .map(|_gas_used| ()),
+            OperationType::CallSC { .. } => self
+                .execute_callsc_op(executed_op, sender_addr)
+                .map(|_gas_used| ()),
             OperationType::RollBuy { .. } => {
-                self.execute_roll_buy_op(&operation.content.op, sender_addr, block_slot)
-            }
-            OperationType::RollSell { .. } => {
-                self.execute_roll_sell_op(&operation.content.op, sender_addr)
+                self.execute_roll_buy_op(executed_op, sender_addr, block_slot)
             }
+            OperationType::RollSell { .. } => self.execute_roll_sell_op(executed_op, sender_addr),
             OperationType::Transaction { .. } => {
-                self.execute_transaction_op(&operation.content.op, sender_addr)
+                self.execute_transaction_op(executed_op, sender_addr)
             }
+            OperationType::MultiSig { .. } => Err(ExecutionError::IncludeOperationError(
+                "nested multi-signature operation".to_string(),
+            )),
         };
 
         {
             // lock execution context
             let mut context = context_guard!(self);
 
+            // keep the trace of the operation, including the steps of a failed execution
+            if let Some(trace) = context.take_trace(execution_result.is_ok()) {
+                context.operation_traces.insert(operation_id, trace);
+            }
+
             // check execution results
             match execution_result {
                 Ok(_) => context.insert_executed_op(
@@ -385,6 +661,15 @@ impl ExecutionState {
                     )
                 }
             }
+
+            // a multi-signature operation cannot be replayed with another sender or subset of signatures
+            if let Some(multisig_hash) = multisig_hash {
+                context.insert_executed_op(
+                    OperationId::new(multisig_hash),
+                    true,
+                    Slot::new(operation.content.expire_period, op_thread),
+                );
+            }
         }
 
         Ok(())
@@ -545,11 +830,14 @@ impl ExecutionState {
     /// # Arguments
     /// * `operation`: the `WrappedOperation` to process, must be an `ExecuteSC`
     /// * `sender_addr`: address of the sender
+    ///
+    /// # Returns
+    /// The amount of gas used by the execution
     pub fn execute_executesc_op(
         &self,
         operation: &OperationType,
         sender_addr: Address,
-    ) -> Result<(), ExecutionError> {
+    ) -> Result<u64, ExecutionError> {
         // process ExecuteSC operations only
         let (bytecode, max_gas, datastore) = match &operation {
             OperationType::ExecuteSC {
@@ -575,6 +863,7 @@ impl ExecutionState {
                 owned_addresses: vec![sender_addr],
                 operation_datastore: Some(datastore.clone()),
             }];
+            context.trace_enter_call(sender_addr, Amount::zero());
         };
 
         // load the tmp module
@@ -589,7 +878,7 @@ impl ExecutionState {
                 "not enough gas to pay for singlepass compilation".to_string(),
             ))?;
         // run the VM
-        massa_sc_runtime::run_main(
+        let response = massa_sc_runtime::run_main(
             &*self.execution_interface,
             module,
             remaining_gas,
@@ -599,8 +888,11 @@ impl ExecutionState {
             context: "ExecuteSC".to_string(),
             error,
         })?;
+        context_guard!(self).trace_step(ExecutionTraceStep::GasCheckpoint {
+            remaining_gas: response.remaining_gas,
+        });
 
-        Ok(())
+        Ok(max_gas.saturating_sub(response.remaining_gas))
     }
 
     /// Execute an operation of type `CallSC`
@@ -611,11 +903,14 @@ impl ExecutionState {
     /// * `block_creator_addr`: address of the block creator
     /// * `operation_id`: ID of the operation
     /// * `sender_addr`: address of the sender
+    ///
+    /// # Returns
+    /// The amount of gas used by the execution
     pub fn execute_callsc_op(
         &self,
         operation: &OperationType,
         sender_addr: Address,
-    ) -> Result<(), ExecutionError> {
+    ) -> Result<u64, ExecutionError> {
         // process CallSC operations only
         let (max_gas, target_addr, target_func, param, coins) = match &operation {
             OperationType::CallSC {
@@ -651,6 +946,7 @@ impl ExecutionState {
                     operation_datastore: None,
                 },
             ];
+            context.trace_enter_call(target_addr, coins);
 
             // Debit the sender's balance with the coins to transfer
             if let Err(err) = context.transfer_coins(Some(sender_addr), None, coins, false) {
@@ -670,7 +966,7 @@ impl ExecutionState {
 
             // quit if there is no function to be called
             if target_func.is_empty() {
-                return Ok(());
+                return Ok(0);
             }
 
             // Load bytecode. Assume empty bytecode if not found.
@@ -696,11 +992,14 @@ impl ExecutionState {
             }
             _ => (),
         }
-        response.map_err(|error| ExecutionError::VMError {
+        let response = response.map_err(|error| ExecutionError::VMError {
             context: "CallSC".to_string(),
             error,
         })?;
-        Ok(())
+        context_guard!(self).trace_step(ExecutionTraceStep::GasCheckpoint {
+            remaining_gas: response.remaining_gas,
+        });
+        Ok(max_gas.saturating_sub(response.remaining_gas))
     }
 
     /// Tries to execute an asynchronous message
@@ -926,6 +1225,31 @@ impl ExecutionState {
                 }
             }
 
+            // Try executing the denunciations of this block.
+            // They are ignored until the block version that supports them is active.
+            // Errors are logged but do not interrupt the execution of the slot.
+            if self.are_block_denunciations_active(&stored_block.content.header.content.slot) {
+                for denunciation in stored_block.content.header.content.denunciations.iter() {
+                    if let Err(err) = self.execute_denunciation(
+                        denunciation,
+                        stored_block.content.header.content.slot,
+                        &mut block_credits,
+                    ) {
+                        debug!(
+                            "failed executing denunciation {} in block {}: {}",
+                            DenunciationId::from(denunciation),
+                            block_id,
+                            err
+                        );
+                    }
+                }
+            } else if !stored_block.content.header.content.denunciations.is_empty() {
+                debug!(
+                    "ignoring the denunciations of block {}: not active yet",
+                    block_id
+                );
+            }
+
             // Get block creator address
             let block_creator_addr = stored_block.content_creator_address;
 
@@ -1144,7 +1468,7 @@ impl ExecutionState {
         };
 
         // create a readonly execution context
-        let execution_context = ExecutionContext::readonly(
+        let mut execution_context = ExecutionContext::readonly(
             self.config.clone(),
             slot,
             req.max_gas,
@@ -1153,11 +1477,21 @@ impl ExecutionState {
             self.active_history.clone(),
             self.module_cache.clone(),
             self.vesting_manager.clone(),
+            &req.state_overrides,
         );
+        if req.with_trace {
+            execution_context.tracer = Some(Default::default());
+        }
 
         // run the interpreter according to the target type
-        let exec_response = match req.target {
+        let (gas_cost, call_result) = match req.target {
             ReadOnlyExecutionTarget::BytecodeExecution(bytecode) => {
+                // the bytecode runs in the context of the top of the call stack
+                if let Some(caller) = execution_context.stack.last() {
+                    let caller_addr = caller.address;
+                    execution_context.trace_enter_call(caller_addr, Amount::zero());
+                }
+
                 // set the execution context
                 *context_guard!(self) = execution_context;
 
@@ -1167,7 +1501,7 @@ impl ExecutionState {
                     .read()
                     .load_tmp_module(&bytecode, req.max_gas)?;
                 // run the VM
-                massa_sc_runtime::run_main(
+                let response = massa_sc_runtime::run_main(
                     &*self.execution_interface,
                     module,
                     req.max_gas,
@@ -1176,7 +1510,14 @@ impl ExecutionState {
                 .map_err(|error| ExecutionError::VMError {
                     context: "ReadOnlyExecutionTarget::BytecodeExecution".to_string(),
                     error,
-                })?
+                })?;
+                context_guard!(self).trace_step(ExecutionTraceStep::GasCheckpoint {
+                    remaining_gas: response.remaining_gas,
+                });
+                (
+                    req.max_gas.saturating_sub(response.remaining_gas),
+                    response.ret,
+                )
             }
             ReadOnlyExecutionTarget::FunctionCall {
                 target_addr,
@@ -1188,6 +1529,7 @@ impl ExecutionState {
                     .get_bytecode(&target_addr)
                     .unwrap_or_default()
                     .0;
+                execution_context.trace_enter_call(target_addr, Amount::zero());
 
                 // set the execution context
                 *context_guard!(self) = execution_context;
@@ -1215,22 +1557,138 @@ impl ExecutionState {
                     }
                     _ => (),
                 }
-                response.map_err(|error| ExecutionError::VMError {
+                let response = response.map_err(|error| ExecutionError::VMError {
                     context: "ReadOnlyExecutionTarget::FunctionCall".to_string(),
                     error,
-                })?
+                })?;
+                context_guard!(self).trace_step(ExecutionTraceStep::GasCheckpoint {
+                    remaining_gas: response.remaining_gas,
+                });
+                (
+                    req.max_gas.saturating_sub(response.remaining_gas),
+                    response.ret,
+                )
+            }
+            ReadOnlyExecutionTarget::OperationExecution(op) => {
+                // the operation is sent by the first address of the call stack
+                let sender_addr = execution_context
+                    .stack
+                    .first()
+                    .map(|element| element.address)
+                    .ok_or_else(|| {
+                        ExecutionError::RuntimeError(
+                            "no operation sender in the read-only call stack".to_string(),
+                        )
+                    })?;
+
+                // set the execution context
+                {
+                    let mut context = context_guard!(self);
+                    *context = execution_context;
+                    context.creator_address = Some(sender_addr);
+                }
+
+                // run the execution process specific to the operation type,
+                // multi-signature operations being simulated without checking their signatures
+                let op = op.executed_op();
+                let gas_used = match op {
+                    OperationType::ExecuteSC { .. } => {
+                        self.execute_executesc_op(op, sender_addr)?
+                    }
+                    OperationType::CallSC { .. } => self.execute_callsc_op(op, sender_addr)?,
+                    OperationType::RollBuy { .. } => {
+                        self.execute_roll_buy_op(op, sender_addr, slot)?;
+                        0
+                    }
+                    OperationType::RollSell { .. } => {
+                        self.execute_roll_sell_op(op, sender_addr)?;
+                        0
+                    }
+                    OperationType::Transaction { .. } => {
+                        self.execute_transaction_op(op, sender_addr)?;
+                        0
+                    }
+                    OperationType::MultiSig { .. } => {
+                        return Err(ExecutionError::RuntimeError(
+                            "nested multi-signature operation".to_string(),
+                        ))
+                    }
+                };
+                (gas_used, Vec::new())
             }
         };
 
         // return the execution output
-        let execution_output = context_guard!(self).settle_slot();
+        let (execution_output, storage_cost, trace) = {
+            let mut context = context_guard!(self);
+            let storage_cost = context.get_storage_cost();
+            let trace = context.take_trace(true);
+            (context.settle_slot(), storage_cost, trace)
+        };
+        let balance_changes = self.get_balance_changes(
+            &execution_output.state_changes.ledger_changes,
+            req.is_final,
+            &req.state_overrides,
+        );
         Ok(ReadOnlyExecutionOutput {
             out: execution_output,
-            gas_cost: req.max_gas.saturating_sub(exec_response.remaining_gas),
-            call_result: exec_response.ret,
+            gas_cost,
+            call_result,
+            storage_cost,
+            balance_changes,
+            trace,
         })
     }
 
+    /// Computes the balance changes caused by ledger changes
+    /// applied on top of the latest final or candidate state
+    ///
+    /// # Arguments
+    /// * `ledger_changes`: the ledger changes to compare with the current state
+    /// * `from_final`: whether the changes apply to the final or candidate state
+    /// * `state_overrides`: overrides applied on top of that state
+    fn get_balance_changes(
+        &self,
+        ledger_changes: &LedgerChanges,
+        from_final: bool,
+        state_overrides: &BTreeMap<Address, StateOverride>,
+    ) -> BTreeMap<Address, BalanceChange> {
+        ledger_changes
+            .0
+            .iter()
+            .filter_map(|(addr, change)| {
+                let predicted = match change {
+                    SetUpdateOrDelete::Set(entry) => entry.balance,
+                    SetUpdateOrDelete::Update(update) => match update.balance {
+                        SetOrKeep::Set(balance) => balance,
+                        SetOrKeep::Keep => return None,
+                    },
+                    SetUpdateOrDelete::Delete => Amount::zero(),
+                };
+                let previous = match state_overrides.get(addr).and_then(|o| o.balance) {
+                    Some(balance) => balance,
+                    None => {
+                        let (final_balance, candidate_balance) =
+                            self.get_final_and_candidate_balance(addr);
+                        if from_final {
+                            final_balance
+                        } else {
+                            candidate_balance
+                        }
+                        .unwrap_or_default()
+                    }
+                };
+                (previous != predicted).then_some((
+                    *addr,
+                    BalanceChange {
+                        previous,
+                        predicted,
+                    },
+                ))
+            })
+            .collect()
+    }
+
     /// Gets a balance both at the latest final and candidate executed slots
     pub fn get_final_and_candidate_balance(
         &self,
@@ -1349,6 +1807,17 @@ impl ExecutionState {
         }
     }
 
+    /// Gets the final values of a batch of ledger keys along with proofs
+    /// that can be checked against the final state hash
+    pub fn get_final_ledger_proofs(&self, keys: &[LedgerKey]) -> Vec<FinalLedgerProof> {
+        self.final_state.read().get_ledger_proofs(keys)
+    }
+
+    /// Gets the call trace of a final operation, if it is still kept in the final trace store
+    pub fn get_operation_trace(&self, operation_id: &OperationId) -> Option<CallTrace> {
+        self.final_operation_traces.get(operation_id).cloned()
+    }
+
     /// Gets execution events optionally filtered by:
     /// * start slot
     /// * end slot
@@ -1358,11 +1827,7 @@ impl ExecutionState {
     /// * event state (final, candidate or both)
     pub fn get_filtered_sc_output_event(&self, filter: EventFilter) -> Vec<SCOutputEvent> {
         match filter.is_final {
-            Some(true) => self
-                .final_events
-                .get_filtered_sc_output_events(&filter)
-                .into_iter()
-                .collect(),
+            Some(true) => self.get_filtered_final_sc_output_event(&filter),
             Some(false) => self
                 .active_history
                 .read()
@@ -1371,8 +1836,7 @@ impl ExecutionState {
                 .flat_map(|item| item.events.get_filtered_sc_output_events(&filter))
                 .collect(),
             None => self
-                .final_events
-                .get_filtered_sc_output_events(&filter)
+                .get_filtered_final_sc_output_event(&filter)
                 .into_iter()
                 .chain(
                     self.active_history
@@ -1385,6 +1849,21 @@ impl ExecutionState {
         }
     }
 
+    /// Gets final execution events from the persistent store if enabled, or from the in-memory one.
+    /// Results from the persistent store are capped to `max_final_events`.
+    fn get_filtered_final_sc_output_event(&self, filter: &EventFilter) -> Vec<SCOutputEvent> {
+        match &self.final_events_db {
+            Some(final_events_db) => {
+                final_events_db.get_filtered_sc_output_events(filter, self.config.max_final_events)
+            }
+            None => self
+                .final_events
+                .get_filtered_sc_output_events(filter)
+                .into_iter()
+                .collect(),
+        }
+    }
+
     /// List which operations inside the provided list were not executed
     pub fn unexecuted_ops_among(
         &self,
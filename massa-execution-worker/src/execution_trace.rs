@@ -0,0 +1,104 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file provides the recording of execution traces as call trees,
+//! and the finite-size store keeping the traces of final operations.
+
+use massa_execution_exports::{CallTrace, ExecutionTraceStep};
+use massa_models::{address::Address, amount::Amount, operation::OperationId, prehash::PreHashMap};
+use std::collections::VecDeque;
+
+/// Records the call tree of an execution
+#[derive(Default)]
+pub(crate) struct ExecutionTracer {
+    /// calls that have not returned yet, outermost first
+    calls: Vec<CallTrace>,
+    /// outermost call, once it returned
+    root: Option<CallTrace>,
+}
+
+impl ExecutionTracer {
+    /// Records the beginning of a call, nested in the current one if any
+    pub fn enter_call(&mut self, address: Address, coins: Amount) {
+        self.calls.push(CallTrace::new(address, coins));
+    }
+
+    /// Records the successful return of the current call
+    pub fn exit_call(&mut self) {
+        let Some(mut call) = self.calls.pop() else {
+            return;
+        };
+        call.finished = true;
+        match self.calls.last_mut() {
+            Some(parent) => parent.steps.push(ExecutionTraceStep::Call(call)),
+            None => self.root = Some(call),
+        }
+    }
+
+    /// Records a step in the current call. Ignored if there is no current call.
+    pub fn record(&mut self, step: ExecutionTraceStep) {
+        if let Some(call) = self.calls.last_mut() {
+            call.steps.push(step);
+        }
+    }
+
+    /// Finishes the recording and returns the call tree, if a call was recorded.
+    /// Calls that did not return are kept in the tree as unfinished,
+    /// except the outermost one that is marked according to `success`.
+    pub fn finish(mut self, success: bool) -> Option<CallTrace> {
+        while let Some(call) = self.calls.pop() {
+            match self.calls.last_mut() {
+                Some(parent) => parent.steps.push(ExecutionTraceStep::Call(call)),
+                None => {
+                    self.root = Some(CallTrace {
+                        finished: success,
+                        ..call
+                    })
+                }
+            }
+        }
+        self.root
+    }
+}
+
+/// Finite-size store of the traces of final operations, oldest evicted first
+#[derive(Default)]
+pub(crate) struct OperationTraceStore {
+    /// traces indexed by operation
+    traces: PreHashMap<OperationId, CallTrace>,
+    /// operations in insertion order
+    order: VecDeque<OperationId>,
+    /// approximate number of bytes taken by the stored traces
+    size: usize,
+}
+
+impl OperationTraceStore {
+    /// Adds traces to the store, then evicts the oldest ones
+    /// until there are at most `max_traces` traces taking at most `max_size` bytes
+    pub fn extend(
+        &mut self,
+        traces: impl IntoIterator<Item = (OperationId, CallTrace)>,
+        max_traces: usize,
+        max_size: usize,
+    ) {
+        for (operation_id, trace) in traces {
+            self.size += trace.size();
+            match self.traces.insert(operation_id, trace) {
+                Some(replaced) => self.size -= replaced.size(),
+                None => self.order.push_back(operation_id),
+            }
+        }
+        while self.order.len() > max_traces || self.size > max_size {
+            let Some(operation_id) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(trace) = self.traces.remove(&operation_id) {
+                self.size -= trace.size();
+            }
+        }
+    }
+
+    /// Gets the trace of an operation
+    pub fn get(&self, operation_id: &OperationId) -> Option<&CallTrace> {
+        self.traces.get(operation_id)
+    }
+}
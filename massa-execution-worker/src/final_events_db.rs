@@ -0,0 +1,346 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This module provides a persistent store for final smart contract output events.
+//!
+//! Events are kept in a `RocksDB` database, sorted by slot and index in slot.
+//! Secondary indexes by emitter address, original caller address and origin operation id
+//! allow answering filtered queries without scanning the whole store.
+//! The store is pruned after each write according to a retention limit
+//! expressed in slot count and/or in approximate size.
+
+use massa_models::execution::EventFilter;
+use massa_models::output_event::{
+    SCOutputEvent, SCOutputEventDeserializer, SCOutputEventSerializer,
+};
+use massa_models::slot::{Slot, SLOT_KEY_SIZE};
+use massa_serialization::{DeserializeError, Deserializer, Serializer};
+use rocksdb::{ColumnFamilyDescriptor, Direction, IteratorMode, Options, WriteBatch, DB};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use tracing::debug;
+
+const EVENTS_CF: &str = "events";
+const EMITTER_INDEX_CF: &str = "emitter_index";
+const CALLER_INDEX_CF: &str = "caller_index";
+const OPERATION_INDEX_CF: &str = "operation_index";
+const METADATA_CF: &str = "metadata";
+const OPEN_ERROR: &str = "critical: rocksdb open operation failed";
+const CRUD_ERROR: &str = "critical: rocksdb crud operation failed";
+const CF_ERROR: &str = "critical: rocksdb column family operation failed";
+const EVENT_SER_ERROR: &str = "critical: event serialization failed";
+const EVENT_DESER_ERROR: &str = "critical: event deserialization failed";
+const SIZE_KEY: &[u8; 1] = b"z";
+const SLOT_KEY: &[u8; 1] = b"s";
+const EVENT_KEY_SIZE: usize = SLOT_KEY_SIZE + 8;
+
+/// Builds the sortable key of an event: slot key followed by the big endian index in slot
+fn event_key(slot: &Slot, index_in_slot: u64) -> Vec<u8> {
+    [&slot.to_bytes_key()[..], &index_in_slot.to_be_bytes()[..]].concat()
+}
+
+/// Lists the secondary index entries (column family, key) of an event
+fn index_keys(event: &SCOutputEvent, event_key: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+    let mut keys = Vec::with_capacity(3);
+    if let Some(emitter) = event.context.call_stack.front() {
+        keys.push((
+            EMITTER_INDEX_CF,
+            [&emitter.prefixed_bytes()[..], event_key].concat(),
+        ));
+    }
+    if let Some(caller) = event.context.call_stack.back() {
+        keys.push((
+            CALLER_INDEX_CF,
+            [&caller.prefixed_bytes()[..], event_key].concat(),
+        ));
+    }
+    if let Some(op_id) = event.context.origin_operation_id {
+        keys.push((
+            OPERATION_INDEX_CF,
+            [&op_id.to_bytes()[..], event_key].concat(),
+        ));
+    }
+    keys
+}
+
+/// Persistent and indexed store of final execution events
+pub(crate) struct FinalEventsDB {
+    /// RocksDB database
+    db: DB,
+    /// number of threads
+    thread_count: u8,
+    /// number of most recent slots for which events are kept, if limited
+    max_slots: Option<u64>,
+    /// approximate maximum size in bytes of the stored events, if limited
+    max_bytes: Option<u64>,
+    /// approximate size in bytes of the stored events, maintained in memory and in the metadata
+    total_size: u64,
+    event_serializer: SCOutputEventSerializer,
+    event_deserializer: SCOutputEventDeserializer,
+}
+
+impl FinalEventsDB {
+    /// Opens (or creates) a `FinalEventsDB`
+    ///
+    /// # Arguments
+    /// * `path`: path to the database directory
+    /// * `thread_count`: number of threads
+    /// * `max_slots`: optional number of most recent slots for which events are kept
+    /// * `max_bytes`: optional approximate maximum size of the stored events
+    pub fn new(
+        path: PathBuf,
+        thread_count: u8,
+        max_slots: Option<u64>,
+        max_bytes: Option<u64>,
+    ) -> Self {
+        let mut db_opts = Options::default();
+        db_opts.create_if_missing(true);
+        db_opts.create_missing_column_families(true);
+
+        let db = DB::open_cf_descriptors(
+            &db_opts,
+            path,
+            vec![
+                ColumnFamilyDescriptor::new(EVENTS_CF, Options::default()),
+                ColumnFamilyDescriptor::new(EMITTER_INDEX_CF, Options::default()),
+                ColumnFamilyDescriptor::new(CALLER_INDEX_CF, Options::default()),
+                ColumnFamilyDescriptor::new(OPERATION_INDEX_CF, Options::default()),
+                ColumnFamilyDescriptor::new(METADATA_CF, Options::default()),
+            ],
+        )
+        .expect(OPEN_ERROR);
+
+        let total_size = {
+            let handle = db.cf_handle(METADATA_CF).expect(CF_ERROR);
+            db.get_cf(handle, SIZE_KEY)
+                .expect(CRUD_ERROR)
+                .and_then(|bytes| bytes.try_into().ok().map(u64::from_be_bytes))
+                .unwrap_or_default()
+        };
+
+        FinalEventsDB {
+            db,
+            thread_count,
+            max_slots,
+            max_bytes,
+            total_size,
+            event_serializer: SCOutputEventSerializer::new(),
+            event_deserializer: SCOutputEventDeserializer::new(thread_count, u64::MAX, u64::MAX),
+        }
+    }
+
+    /// Get the latest slot for which events were written, if any
+    pub fn get_slot(&self) -> Option<Slot> {
+        let handle = self.db.cf_handle(METADATA_CF).expect(CF_ERROR);
+        self.db
+            .get_cf(handle, SLOT_KEY)
+            .expect(CRUD_ERROR)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(|bytes: [u8; SLOT_KEY_SIZE]| Slot::from_bytes_key(&bytes))
+    }
+
+    /// Get the approximate size in bytes of the stored events
+    #[cfg(test)]
+    pub fn get_size(&self) -> u64 {
+        self.total_size
+    }
+
+    /// Writes the final events of a slot to the store and prunes the events that exceed the retention limits
+    ///
+    /// # Arguments
+    /// * `events`: final events generated at `slot`
+    /// * `slot`: the newly finalized slot
+    pub fn apply_events(&mut self, events: &VecDeque<SCOutputEvent>, slot: Slot) {
+        let events_handle = self.db.cf_handle(EVENTS_CF).expect(CF_ERROR);
+        let metadata_handle = self.db.cf_handle(METADATA_CF).expect(CF_ERROR);
+        let mut batch = WriteBatch::default();
+        for event in events {
+            let key = event_key(&event.context.slot, event.context.index_in_slot);
+            let mut value = Vec::new();
+            self.event_serializer
+                .serialize(event, &mut value)
+                .expect(EVENT_SER_ERROR);
+            // the same final slot can be applied again, e.g. after a restart or a bootstrap:
+            // the stored event is replaced, so are its index entries and its share of the size
+            if let Some(previous) = self
+                .db
+                .get_pinned_cf(events_handle, &key)
+                .expect(CRUD_ERROR)
+            {
+                let (_, previous_event) = self
+                    .event_deserializer
+                    .deserialize::<DeserializeError>(&previous)
+                    .expect(EVENT_DESER_ERROR);
+                for (cf, index_key) in index_keys(&previous_event, &key) {
+                    batch.delete_cf(self.db.cf_handle(cf).expect(CF_ERROR), index_key);
+                }
+                self.total_size = self
+                    .total_size
+                    .saturating_sub((key.len() + previous.len()) as u64);
+            }
+            for (cf, index_key) in index_keys(event, &key) {
+                batch.put_cf(self.db.cf_handle(cf).expect(CF_ERROR), index_key, b"");
+            }
+            self.total_size = self
+                .total_size
+                .saturating_add((key.len() + value.len()) as u64);
+            batch.put_cf(events_handle, key, value);
+        }
+        batch.put_cf(metadata_handle, SIZE_KEY, self.total_size.to_be_bytes());
+        batch.put_cf(metadata_handle, SLOT_KEY, slot.to_bytes_key());
+        self.db.write(batch).expect(CRUD_ERROR);
+
+        self.prune(slot);
+    }
+
+    /// Removes the oldest events until both retention limits are satisfied
+    ///
+    /// # Arguments
+    /// * `last_slot`: latest final slot
+    fn prune(&mut self, last_slot: Slot) {
+        if self.max_slots.is_none() && self.max_bytes.is_none() {
+            return;
+        }
+
+        // events strictly before this slot are out of the slot retention window
+        let min_slot_key = self.max_slots.map(|max_slots| {
+            let thread_count = self.thread_count as u64;
+            let last_index = last_slot
+                .period
+                .saturating_mul(thread_count)
+                .saturating_add(last_slot.thread as u64);
+            let min_index = last_index.saturating_sub(max_slots.saturating_sub(1));
+            Slot::new(min_index / thread_count, (min_index % thread_count) as u8).to_bytes_key()
+        });
+
+        let events_handle = self.db.cf_handle(EVENTS_CF).expect(CF_ERROR);
+        let metadata_handle = self.db.cf_handle(METADATA_CF).expect(CF_ERROR);
+        let mut batch = WriteBatch::default();
+        let mut total_size = self.total_size;
+        let mut pruned_count: usize = 0;
+        for (key, value) in self
+            .db
+            .iterator_cf(events_handle, IteratorMode::Start)
+            .flatten()
+        {
+            let out_of_slots = min_slot_key
+                .map(|min_key| key[..SLOT_KEY_SIZE] < min_key[..])
+                .unwrap_or(false);
+            let out_of_bytes = self
+                .max_bytes
+                .map(|max_bytes| total_size > max_bytes)
+                .unwrap_or(false);
+            if !out_of_slots && !out_of_bytes {
+                break;
+            }
+            let (_, event) = self
+                .event_deserializer
+                .deserialize::<DeserializeError>(&value)
+                .expect(EVENT_DESER_ERROR);
+            for (cf, index_key) in index_keys(&event, &key) {
+                batch.delete_cf(self.db.cf_handle(cf).expect(CF_ERROR), index_key);
+            }
+            batch.delete_cf(events_handle, &key);
+            total_size = total_size.saturating_sub((key.len() + value.len()) as u64);
+            pruned_count += 1;
+        }
+
+        if pruned_count > 0 {
+            batch.put_cf(metadata_handle, SIZE_KEY, total_size.to_be_bytes());
+            self.db.write(batch).expect(CRUD_ERROR);
+            self.total_size = total_size;
+            debug!(
+                "pruned {} final events from the store, remaining size is {} bytes",
+                pruned_count, self.total_size
+            );
+        }
+    }
+
+    /// Get final events optionally filtered by:
+    /// * start slot
+    /// * end slot
+    /// * emitter address
+    /// * original caller address
+    /// * operation id
+    /// * is error
+    ///
+    /// The most selective available index is used: operation id, then emitter address,
+    /// then original caller address, then the slot range.
+    /// Like the in-memory event store, the most recent matching events are returned, sorted by slot.
+    ///
+    /// # Arguments
+    /// * `filter`: the event filter
+    /// * `max_count`: maximum number of returned events
+    pub fn get_filtered_sc_output_events(
+        &self,
+        filter: &EventFilter,
+        max_count: usize,
+    ) -> Vec<SCOutputEvent> {
+        let mut result = Vec::new();
+        if max_count == 0 || filter.is_final == Some(false) {
+            return result;
+        }
+
+        let (index_cf, prefix) = if let Some(op_id) = filter.original_operation_id {
+            (OPERATION_INDEX_CF, op_id.to_bytes().to_vec())
+        } else if let Some(emitter) = filter.emitter_address {
+            (EMITTER_INDEX_CF, emitter.prefixed_bytes())
+        } else if let Some(caller) = filter.original_caller_address {
+            (CALLER_INDEX_CF, caller.prefixed_bytes())
+        } else {
+            (EVENTS_CF, Vec::new())
+        };
+        // iterate backwards from the end of the range: the end slot is excluded,
+        // and without end slot every event key of the prefix is lower than the padded prefix
+        let end_key = [
+            &prefix[..],
+            &filter
+                .end
+                .map(|slot| slot.to_bytes_key().to_vec())
+                .unwrap_or_else(|| vec![u8::MAX; EVENT_KEY_SIZE])[..],
+        ]
+        .concat();
+        let start_key = filter.start.map(|slot| slot.to_bytes_key());
+
+        let events_handle = self.db.cf_handle(EVENTS_CF).expect(CF_ERROR);
+        let index_handle = self.db.cf_handle(index_cf).expect(CF_ERROR);
+        for (key, value) in self
+            .db
+            .iterator_cf(
+                index_handle,
+                IteratorMode::From(&end_key, Direction::Reverse),
+            )
+            .flatten()
+        {
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            if key.len() != prefix.len() + EVENT_KEY_SIZE {
+                continue;
+            }
+            let ev_key = &key[prefix.len()..];
+            if let Some(start_key) = start_key && ev_key[..SLOT_KEY_SIZE] < start_key[..] {
+                break;
+            }
+            let serialized_event = if index_cf == EVENTS_CF {
+                value.to_vec()
+            } else {
+                match self.db.get_cf(events_handle, ev_key).expect(CRUD_ERROR) {
+                    Some(serialized_event) => serialized_event,
+                    None => continue,
+                }
+            };
+            let (_, event) = self
+                .event_deserializer
+                .deserialize::<DeserializeError>(&serialized_event)
+                .expect(EVENT_DESER_ERROR);
+            if filter.matches(&event) {
+                result.push(event);
+                if result.len() >= max_count {
+                    break;
+                }
+            }
+        }
+        result.reverse();
+        result
+    }
+}
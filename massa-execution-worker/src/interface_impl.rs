@@ -10,6 +10,7 @@ use anyhow::{anyhow, bail, Result};
 use massa_async_pool::{AsyncMessage, AsyncMessageTrigger};
 use massa_execution_exports::ExecutionConfig;
 use massa_execution_exports::ExecutionStackElement;
+use massa_execution_exports::ExecutionTraceStep;
 use massa_models::bytecode::Bytecode;
 use massa_models::config::MAX_DATASTORE_KEY_LENGTH;
 use massa_models::{
@@ -192,6 +193,7 @@ impl Interface for InterfaceImpl {
             owned_addresses: vec![to_address],
             operation_datastore: None,
         });
+        context.trace_enter_call(to_address, coins);
 
         // return the target bytecode
         Ok(bytecode.0)
@@ -205,6 +207,7 @@ impl Interface for InterfaceImpl {
         if context.stack.pop().is_none() {
             bail!("call stack out of bounds")
         }
+        context.trace_exit_call();
 
         Ok(())
     }
@@ -324,6 +327,7 @@ impl Interface for InterfaceImpl {
         let addr = massa_models::address::Address::from_str(address)?;
         let mut context = context_guard!(self);
         context.set_data_entry(&addr, key.to_vec(), value.to_vec())?;
+        context.trace_step(ExecutionTraceStep::set_data(addr, key, value));
         Ok(())
     }
 
@@ -394,6 +398,7 @@ impl Interface for InterfaceImpl {
         let mut context = context_guard!(self);
         let addr = context.get_current_address()?;
         context.set_data_entry(&addr, key.to_vec(), value.to_vec())?;
+        context.trace_step(ExecutionTraceStep::set_data(addr, key, value));
         Ok(())
     }
 
@@ -594,6 +599,11 @@ impl Interface for InterfaceImpl {
         let mut context = context_guard!(self);
         let from_address = context.get_current_address()?;
         context.transfer_coins(Some(from_address), Some(to_address), amount, true)?;
+        context.trace_step(ExecutionTraceStep::TransferCoins {
+            from: from_address,
+            to: to_address,
+            amount,
+        });
         Ok(())
     }
 
@@ -614,6 +624,11 @@ impl Interface for InterfaceImpl {
         let amount = Amount::from_raw(raw_amount);
         let mut context = context_guard!(self);
         context.transfer_coins(Some(from_address), Some(to_address), amount, true)?;
+        context.trace_step(ExecutionTraceStep::TransferCoins {
+            from: from_address,
+            to: to_address,
+            amount,
+        });
         Ok(())
     }
 
@@ -733,13 +748,14 @@ impl Interface for InterfaceImpl {
         let sender = execution_context.get_current_address()?;
         let coins = Amount::from_raw(raw_coins);
         let fee = Amount::from_raw(raw_fee);
+        let destination = Address::from_str(target_address)?;
         execution_context.transfer_coins(Some(sender), None, coins, true)?;
         execution_context.transfer_coins(Some(sender), None, fee, true)?;
         execution_context.push_new_message(AsyncMessage::new_with_hash(
             emission_slot,
             emission_index,
             sender,
-            Address::from_str(target_address)?,
+            destination,
             target_handler.to_string(),
             max_gas,
             fee,
@@ -763,6 +779,13 @@ impl Interface for InterfaceImpl {
                 .transpose()?,
         ));
         execution_context.created_message_index += 1;
+        execution_context.trace_step(ExecutionTraceStep::SendMessage {
+            destination,
+            handler: target_handler.to_string(),
+            max_gas,
+            fee,
+            coins,
+        });
         Ok(())
     }
 
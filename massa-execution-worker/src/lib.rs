@@ -69,10 +69,19 @@
 //! ## `speculative_executed_ops.rs`
 //! A speculative (non-final) list of previously executed operations to prevent reuse.
 //!
+//! ## `speculative_executed_denunciations.rs`
+//! A speculative (non-final) list of previously executed denunciations to prevent reuse.
+//!
 //! ## `request_queue.rs`
 //! This module contains the implementation of a generic finite-size execution request queue.
 //! It handles requests that come with an MPSC to send back the result of their execution once it's done.
 //!
+//! ## `final_events_db.rs`
+//! A persistent store of final execution events, indexed by slot, emitter, original caller and operation.
+//!
+//! ## `execution_trace.rs`
+//! Records the call trees of smart contract executions and keeps those of final operations.
+//!
 //! ## `stats.rs`
 //! Defines a structure that gathers execution statistics.
 
@@ -87,10 +96,13 @@ mod active_history;
 mod context;
 mod controller;
 mod execution;
+mod execution_trace;
+mod final_events_db;
 mod interface_impl;
 mod request_queue;
 mod slot_sequencer;
 mod speculative_async_pool;
+mod speculative_executed_denunciations;
 mod speculative_executed_ops;
 mod speculative_ledger;
 mod speculative_roll_state;
@@ -0,0 +1,96 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Speculative list of previously executed denunciations, to prevent reuse.
+
+use crate::active_history::{ActiveHistory, HistorySearchResult};
+use massa_executed_ops::ExecutedDenunciationsChanges;
+use massa_final_state::FinalState;
+use massa_models::{denunciation::DenunciationId, slot::Slot};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Speculative state of executed denunciations
+pub(crate) struct SpeculativeExecutedDenunciations {
+    /// Thread-safe shared access to the final state. For reading only.
+    final_state: Arc<RwLock<FinalState>>,
+
+    /// History of the outputs of recently executed slots.
+    /// Slots should be consecutive, newest at the back.
+    active_history: Arc<RwLock<ActiveHistory>>,
+
+    /// executed denunciations: maps the denunciation ID to its denounced slot
+    executed_denunciations: ExecutedDenunciationsChanges,
+}
+
+impl SpeculativeExecutedDenunciations {
+    /// Creates a new `SpeculativeExecutedDenunciations`
+    ///
+    /// # Arguments
+    /// * `final_state`: thread-safe shared access the the final state
+    /// * `active_history`: thread-safe shared access the speculative execution history
+    pub fn new(
+        final_state: Arc<RwLock<FinalState>>,
+        active_history: Arc<RwLock<ActiveHistory>>,
+    ) -> Self {
+        SpeculativeExecutedDenunciations {
+            final_state,
+            active_history,
+            executed_denunciations: Default::default(),
+        }
+    }
+
+    /// Returns the set of denunciation IDs caused to the `SpeculativeExecutedDenunciations` since its creation,
+    /// and resets their local value to nothing
+    pub fn take(&mut self) -> ExecutedDenunciationsChanges {
+        std::mem::take(&mut self.executed_denunciations)
+    }
+
+    /// Takes a snapshot (clone) of the changes caused to the `SpeculativeExecutedDenunciations` since its creation
+    pub fn get_snapshot(&self) -> ExecutedDenunciationsChanges {
+        self.executed_denunciations.clone()
+    }
+
+    /// Resets the `SpeculativeExecutedDenunciations` to a snapshot (see `get_snapshot` method)
+    pub fn reset_to_snapshot(&mut self, snapshot: ExecutedDenunciationsChanges) {
+        self.executed_denunciations = snapshot;
+    }
+
+    /// Checks if a denunciation was executed previously
+    pub fn is_denunciation_executed(&self, de_id: &DenunciationId) -> bool {
+        // check in the current changes
+        if self.executed_denunciations.contains_key(de_id) {
+            return true;
+        }
+
+        // check in the active history, backwards
+        match self
+            .active_history
+            .read()
+            .fetch_executed_denunciation(de_id)
+        {
+            HistorySearchResult::Present(_) => {
+                return true;
+            }
+            HistorySearchResult::Absent => {
+                return false;
+            }
+            HistorySearchResult::NoInfo => {}
+        }
+
+        // check in the final state
+        self.final_state
+            .read()
+            .executed_denunciations
+            .contains(de_id)
+    }
+
+    /// Insert an executed denunciation.
+    /// Does not check for reuse, please use `SpeculativeExecutedDenunciations::is_denunciation_executed` before.
+    ///
+    /// # Arguments
+    /// * `de_id`: denunciation ID
+    /// * `de_slot`: slot of the denounced items
+    pub fn insert_executed_denunciation(&mut self, de_id: DenunciationId, de_slot: Slot) {
+        self.executed_denunciations.insert(de_id, de_slot);
+    }
+}
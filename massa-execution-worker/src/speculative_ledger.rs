@@ -7,13 +7,13 @@
 
 use crate::active_history::{ActiveHistory, HistorySearchResult};
 use massa_execution_exports::ExecutionError;
-use massa_execution_exports::StorageCostsConstants;
+use massa_execution_exports::{StateOverride, StorageCostsConstants};
 use massa_final_state::FinalState;
 use massa_ledger_exports::{Applicable, LedgerChanges, SetOrDelete, SetUpdateOrDelete};
 use massa_models::bytecode::Bytecode;
 use massa_models::{address::Address, amount::Amount};
 use parking_lot::RwLock;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use tracing::debug;
 
@@ -45,6 +45,10 @@ pub(crate) struct SpeculativeLedger {
     ))]
     pub added_changes: LedgerChanges,
 
+    /// ledger changes overriding the history and final state, applied below `added_changes`.
+    /// Only set for read-only executions simulating an alternative state.
+    overrides: LedgerChanges,
+
     /// max datastore key length
     max_datastore_key_length: u8,
 
@@ -56,6 +60,12 @@ pub(crate) struct SpeculativeLedger {
 
     /// storage cost constants
     storage_costs_constants: StorageCostsConstants,
+
+    /// coins spent on storage costs since the creation of this `SpeculativeLedger`
+    storage_costs_paid: Amount,
+
+    /// coins refunded from storage costs since the creation of this `SpeculativeLedger`
+    storage_costs_refunded: Amount,
 }
 
 impl SpeculativeLedger {
@@ -75,11 +85,14 @@ impl SpeculativeLedger {
         SpeculativeLedger {
             final_state,
             added_changes: Default::default(),
+            overrides: Default::default(),
             active_history,
             max_datastore_key_length,
             max_datastore_value_size,
             max_bytecode_size,
             storage_costs_constants,
+            storage_costs_paid: Amount::zero(),
+            storage_costs_refunded: Amount::zero(),
         }
     }
 
@@ -89,14 +102,82 @@ impl SpeculativeLedger {
         std::mem::take(&mut self.added_changes)
     }
 
-    /// Takes a snapshot (clone) of the changes caused to the `SpeculativeLedger` since its creation
-    pub fn get_snapshot(&self) -> LedgerChanges {
-        self.added_changes.clone()
+    /// Takes a snapshot (clone) of the changes caused to the `SpeculativeLedger` since its creation,
+    /// along with the storage costs paid and refunded so far
+    pub fn get_snapshot(&self) -> (LedgerChanges, Amount, Amount) {
+        (
+            self.added_changes.clone(),
+            self.storage_costs_paid,
+            self.storage_costs_refunded,
+        )
     }
 
     /// Resets the `SpeculativeLedger` to a snapshot (see `get_snapshot` method)
-    pub fn reset_to_snapshot(&mut self, snapshot: LedgerChanges) {
-        self.added_changes = snapshot;
+    pub fn reset_to_snapshot(&mut self, snapshot: (LedgerChanges, Amount, Amount)) {
+        (
+            self.added_changes,
+            self.storage_costs_paid,
+            self.storage_costs_refunded,
+        ) = snapshot;
+    }
+
+    /// Overrides the balance, bytecode and datastore entries of some addresses
+    /// for the lifetime of this `SpeculativeLedger`.
+    /// Addresses that do not exist are created with a zero balance if not overridden.
+    ///
+    /// # Arguments
+    /// * `state_overrides`: overrides to apply per address
+    pub fn set_overrides(&mut self, state_overrides: &BTreeMap<Address, StateOverride>) {
+        let mut overrides = LedgerChanges::default();
+        for (addr, state_override) in state_overrides {
+            if state_override.balance.is_none()
+                && state_override.bytecode.is_none()
+                && state_override.datastore.is_empty()
+            {
+                continue;
+            }
+            if !self.entry_exists(addr) {
+                overrides.create_address(addr);
+            }
+            if let Some(balance) = state_override.balance {
+                overrides.set_balance(*addr, balance);
+            }
+            if let Some(bytecode) = &state_override.bytecode {
+                overrides.set_bytecode(*addr, bytecode.clone());
+            }
+            for (key, value) in &state_override.datastore {
+                overrides.set_data_entry(*addr, key.clone(), value.clone());
+            }
+        }
+        self.overrides = overrides;
+    }
+
+    /// Gets the net amount of coins spent on storage costs since the creation of the `SpeculativeLedger`
+    pub fn get_storage_cost(&self) -> Amount {
+        self.storage_costs_paid
+            .saturating_sub(self.storage_costs_refunded)
+    }
+
+    /// Debits the storage costs of a change from the address paying for it
+    fn pay_storage_cost(
+        &mut self,
+        payer_addr: Address,
+        cost: Amount,
+    ) -> Result<(), ExecutionError> {
+        self.transfer_coins(Some(payer_addr), None, cost)?;
+        self.storage_costs_paid = self.storage_costs_paid.saturating_add(cost);
+        Ok(())
+    }
+
+    /// Credits the storage costs freed by a change back to the address that paid for it
+    fn refund_storage_cost(
+        &mut self,
+        payer_addr: Address,
+        cost: Amount,
+    ) -> Result<(), ExecutionError> {
+        self.transfer_coins(None, Some(payer_addr), cost)?;
+        self.storage_costs_refunded = self.storage_costs_refunded.saturating_add(cost);
+        Ok(())
     }
 
     /// Gets the effective balance of an address
@@ -107,13 +188,15 @@ impl SpeculativeLedger {
     /// # Returns
     /// Some(Amount) if the address was found, otherwise None
     pub fn get_balance(&self, addr: &Address) -> Option<Amount> {
-        // try to read from added changes > history > final_state
+        // try to read from added changes > overrides > history > final_state
         self.added_changes.get_balance_or_else(addr, || {
-            match self.active_history.read().fetch_balance(addr) {
-                HistorySearchResult::Present(par_balance) => Some(par_balance),
-                HistorySearchResult::NoInfo => self.final_state.read().ledger.get_balance(addr),
-                HistorySearchResult::Absent => None,
-            }
+            self.overrides.get_balance_or_else(addr, || {
+                match self.active_history.read().fetch_balance(addr) {
+                    HistorySearchResult::Present(par_balance) => Some(par_balance),
+                    HistorySearchResult::NoInfo => self.final_state.read().ledger.get_balance(addr),
+                    HistorySearchResult::Absent => None,
+                }
+            })
         })
     }
 
@@ -125,13 +208,17 @@ impl SpeculativeLedger {
     /// # Returns
     /// `Some(Bytecode)` if the address was found, otherwise None
     pub fn get_bytecode(&self, addr: &Address) -> Option<Bytecode> {
-        // try to read from added changes > history > final_state
+        // try to read from added changes > overrides > history > final_state
         self.added_changes.get_bytecode_or_else(addr, || {
-            match self.active_history.read().fetch_bytecode(addr) {
-                HistorySearchResult::Present(bytecode) => Some(bytecode),
-                HistorySearchResult::NoInfo => self.final_state.read().ledger.get_bytecode(addr),
-                HistorySearchResult::Absent => None,
-            }
+            self.overrides.get_bytecode_or_else(addr, || {
+                match self.active_history.read().fetch_bytecode(addr) {
+                    HistorySearchResult::Present(bytecode) => Some(bytecode),
+                    HistorySearchResult::NoInfo => {
+                        self.final_state.read().ledger.get_bytecode(addr)
+                    }
+                    HistorySearchResult::Absent => None,
+                }
+            })
         })
     }
 
@@ -151,6 +238,8 @@ impl SpeculativeLedger {
     ) -> Result<(), ExecutionError> {
         // init empty ledger changes
         let mut changes = LedgerChanges::default();
+        // storage cost of the creation of the crediting address, if any
+        let mut storage_cost = Amount::zero();
 
         // simulate spending coins from sender address (if any)
         if let Some(from_addr) = from_addr {
@@ -185,6 +274,7 @@ impl SpeculativeLedger {
                     //TODO: Remove when stabilized
                     debug!("Creating address {} from coins in transactions", to_addr);
                     if amount >= self.storage_costs_constants.ledger_entry_base_cost {
+                        storage_cost = self.storage_costs_constants.ledger_entry_base_cost;
                         changes.create_address(&to_addr);
                         changes.set_balance(
                             to_addr,
@@ -209,6 +299,7 @@ impl SpeculativeLedger {
                     debug!("Creating address {} from coins generated", to_addr);
                     // We have enough to create the address and transfer the rest.
                     if amount >= self.storage_costs_constants.ledger_entry_base_cost {
+                        storage_cost = self.storage_costs_constants.ledger_entry_base_cost;
                         changes.create_address(&to_addr);
                         changes.set_balance(
                             to_addr,
@@ -229,6 +320,7 @@ impl SpeculativeLedger {
 
         // apply the simulated changes to the speculative ledger
         self.added_changes.apply(changes);
+        self.storage_costs_paid = self.storage_costs_paid.saturating_add(storage_cost);
 
         Ok(())
     }
@@ -241,13 +333,17 @@ impl SpeculativeLedger {
     /// # Returns
     /// true if the address was found, otherwise false
     pub fn entry_exists(&self, addr: &Address) -> bool {
-        // try to read from added changes > history > final_state
+        // try to read from added changes > overrides > history > final_state
         self.added_changes.entry_exists_or_else(addr, || {
-            match self.active_history.read().fetch_balance(addr) {
-                HistorySearchResult::Present(_balance) => true,
-                HistorySearchResult::NoInfo => self.final_state.read().ledger.entry_exists(addr),
-                HistorySearchResult::Absent => false,
-            }
+            self.overrides.entry_exists_or_else(addr, || {
+                match self.active_history.read().fetch_balance(addr) {
+                    HistorySearchResult::Present(_balance) => true,
+                    HistorySearchResult::NoInfo => {
+                        self.final_state.read().ledger.entry_exists(addr)
+                    }
+                    HistorySearchResult::Absent => false,
+                }
+            })
         })
     }
 
@@ -309,7 +405,7 @@ impl SpeculativeLedger {
                 ExecutionError::RuntimeError("overflow in ledger cost for bytecode".to_string())
             })?;
 
-        self.transfer_coins(Some(creator_address), None, address_storage_cost)?;
+        self.pay_storage_cost(creator_address, address_storage_cost)?;
         self.added_changes.create_address(&addr);
         self.added_changes.set_bytecode(addr, bytecode);
         Ok(())
@@ -356,8 +452,8 @@ impl SpeculativeLedger {
                 })?;
 
             match diff_size_storage.signum() {
-                1 => self.transfer_coins(Some(*caller_addr), None, storage_cost_bytecode)?,
-                -1 => self.transfer_coins(None, Some(*caller_addr), storage_cost_bytecode)?,
+                1 => self.pay_storage_cost(*caller_addr, storage_cost_bytecode)?,
+                -1 => self.refund_storage_cost(*caller_addr, storage_cost_bytecode)?,
                 _ => {}
             };
         } else {
@@ -370,7 +466,7 @@ impl SpeculativeLedger {
                         "overflow when calculating storage cost of bytecode".to_string(),
                     )
                 })?;
-            self.transfer_coins(Some(*caller_addr), None, bytecode_storage_cost)?;
+            self.pay_storage_cost(*caller_addr, bytecode_storage_cost)?;
         }
         // set the bytecode of that address
         self.added_changes.set_bytecode(*addr, bytecode);
@@ -389,12 +485,13 @@ impl SpeculativeLedger {
         let mut keys: Option<BTreeSet<Vec<u8>>> =
             self.final_state.read().ledger.get_datastore_keys(addr);
 
-        // here, traverse the history from oldest to newest with added_changes at the end, applying additions and deletions
+        // here, traverse the history from oldest to newest with overrides and added_changes at the end, applying additions and deletions
         let active_history = self.active_history.read();
         let changes_iterator = active_history
             .0
             .iter()
             .map(|item| &item.state_changes.ledger_changes)
+            .chain(std::iter::once(&self.overrides))
             .chain(std::iter::once(&self.added_changes));
         for ledger_changes in changes_iterator {
             match ledger_changes.get(addr) {
@@ -436,19 +533,21 @@ impl SpeculativeLedger {
     /// # Returns
     /// `Some(Vec<u8>)` if the value was found, `None` if the address does not exist or if the key is not in its datastore.
     pub fn get_data_entry(&self, addr: &Address, key: &[u8]) -> Option<Vec<u8>> {
-        // try to read from added changes > history > final_state
+        // try to read from added changes > overrides > history > final_state
         self.added_changes.get_data_entry_or_else(addr, key, || {
-            match self
-                .active_history
-                .read()
-                .fetch_active_history_data_entry(addr, key)
-            {
-                HistorySearchResult::Present(entry) => Some(entry),
-                HistorySearchResult::NoInfo => {
-                    self.final_state.read().ledger.get_data_entry(addr, key)
+            self.overrides.get_data_entry_or_else(addr, key, || {
+                match self
+                    .active_history
+                    .read()
+                    .fetch_active_history_data_entry(addr, key)
+                {
+                    HistorySearchResult::Present(entry) => Some(entry),
+                    HistorySearchResult::NoInfo => {
+                        self.final_state.read().ledger.get_data_entry(addr, key)
+                    }
+                    HistorySearchResult::Absent => None,
                 }
-                HistorySearchResult::Absent => None,
-            }
+            })
         })
     }
 
@@ -461,22 +560,24 @@ impl SpeculativeLedger {
     /// # Returns
     /// true if the key exists in the address datastore, false otherwise
     pub fn has_data_entry(&self, addr: &Address, key: &[u8]) -> bool {
-        // try to read from added changes > history > final_state
+        // try to read from added changes > overrides > history > final_state
         self.added_changes.has_data_entry_or_else(addr, key, || {
-            match self
-                .active_history
-                .read()
-                .fetch_active_history_data_entry(addr, key)
-            {
-                HistorySearchResult::Present(_entry) => true,
-                HistorySearchResult::NoInfo => self
-                    .final_state
+            self.overrides.has_data_entry_or_else(addr, key, || {
+                match self
+                    .active_history
                     .read()
-                    .ledger
-                    .get_data_entry(addr, key)
-                    .is_some(),
-                HistorySearchResult::Absent => false,
-            }
+                    .fetch_active_history_data_entry(addr, key)
+                {
+                    HistorySearchResult::Present(_entry) => true,
+                    HistorySearchResult::NoInfo => self
+                        .final_state
+                        .read()
+                        .ledger
+                        .get_data_entry(addr, key)
+                        .is_some(),
+                    HistorySearchResult::Absent => false,
+                }
+            })
         })
     }
 
@@ -548,15 +649,14 @@ impl SpeculativeLedger {
                     )
                 })?;
             match diff_size_storage.signum() {
-                1 => self.transfer_coins(Some(*caller_addr), None, storage_cost_value)?,
-                -1 => self.transfer_coins(None, Some(*caller_addr), storage_cost_value)?,
+                1 => self.pay_storage_cost(*caller_addr, storage_cost_value)?,
+                -1 => self.refund_storage_cost(*caller_addr, storage_cost_value)?,
                 _ => {}
             };
         } else {
             let value_storage_cost = self.get_storage_cost_datastore_value(&value)?;
-            self.transfer_coins(
-                Some(*caller_addr),
-                None,
+            self.pay_storage_cost(
+                *caller_addr,
                 self.storage_costs_constants
                     .ledger_entry_datastore_base_cost
                     .checked_add(value_storage_cost)
@@ -591,9 +691,8 @@ impl SpeculativeLedger {
         // check if the entry exists
         if let Some(value) = self.get_data_entry(addr, key) {
             let value_storage_cost = self.get_storage_cost_datastore_value(&value)?;
-            self.transfer_coins(
-                None,
-                Some(*caller_addr),
+            self.refund_storage_cost(
+                *caller_addr,
                 self.storage_costs_constants
                     .ledger_entry_datastore_base_cost
                     .checked_add(value_storage_cost)
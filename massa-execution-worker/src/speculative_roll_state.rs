@@ -25,6 +25,10 @@ pub(crate) struct SpeculativeRollState {
 
     /// List of changes to the state after settling roll sell/buy
     pub(crate) added_changes: PoSChanges,
+
+    /// Roll counts overriding the history and final state, applied below `added_changes`.
+    /// Only set for read-only executions simulating an alternative state.
+    roll_overrides: PreHashMap<Address, u64>,
 }
 
 impl SpeculativeRollState {
@@ -40,6 +44,7 @@ impl SpeculativeRollState {
             final_state,
             active_history,
             added_changes: PoSChanges::default(),
+            roll_overrides: PreHashMap::default(),
         }
     }
 
@@ -59,18 +64,33 @@ impl SpeculativeRollState {
         self.added_changes = snapshot;
     }
 
+    /// Overrides the roll counts of some addresses for the lifetime of this `SpeculativeRollState`
+    ///
+    /// # Arguments
+    /// * `roll_overrides`: roll count to use for each address
+    pub fn set_roll_overrides(&mut self, roll_overrides: PreHashMap<Address, u64>) {
+        self.roll_overrides = roll_overrides;
+    }
+
     /// Internal function to retrieve the rolls of a given address
     fn get_rolls(&self, addr: &Address) -> u64 {
         self.added_changes
             .roll_changes
             .get(addr)
             .copied()
-            .unwrap_or_else(|| {
-                self.active_history
-                    .read()
-                    .fetch_roll_count(addr)
-                    .unwrap_or_else(|| self.final_state.read().pos_state.get_rolls_for(addr))
-            })
+            .unwrap_or_else(|| self.get_base_rolls(addr))
+    }
+
+    /// Internal function to retrieve the rolls of a given address before the added changes,
+    /// from: overrides > active history > final state
+    fn get_base_rolls(&self, addr: &Address) -> u64 {
+        if let Some(roll_count) = self.roll_overrides.get(addr) {
+            return *roll_count;
+        }
+        self.active_history
+            .read()
+            .fetch_roll_count(addr)
+            .unwrap_or_else(|| self.final_state.read().pos_state.get_rolls_for(addr))
     }
 
     /// Add `roll_count` rolls to the buyer address.
@@ -80,17 +100,8 @@ impl SpeculativeRollState {
     /// * `buyer_addr`: address that will receive the rolls
     /// * `roll_count`: number of rolls it will receive
     pub fn add_rolls(&mut self, buyer_addr: &Address, roll_count: u64) {
-        let count = self
-            .added_changes
-            .roll_changes
-            .entry(*buyer_addr)
-            .or_insert_with(|| {
-                self.active_history
-                    .read()
-                    .fetch_roll_count(buyer_addr)
-                    .unwrap_or_else(|| self.final_state.read().pos_state.get_rolls_for(buyer_addr))
-            });
-        *count = count.saturating_add(roll_count);
+        let count = self.get_rolls(buyer_addr).saturating_add(roll_count);
+        self.added_changes.roll_changes.insert(*buyer_addr, count);
     }
 
     /// Try to sell `roll_count` rolls from the seller address.
@@ -148,6 +159,69 @@ impl SpeculativeRollState {
         Ok(())
     }
 
+    /// Try to slash `roll_count` rolls from the denounced address. If not enough rolls,
+    /// slash the available amount of the future deferred credits of the address.
+    ///
+    /// # Arguments
+    /// * `addr`: address to slash the rolls from
+    /// * `roll_count`: number of rolls to slash
+    /// * `roll_price`: price of a roll
+    /// * `min_credit_slot`: deferred credits at or after this slot can be slashed
+    ///
+    /// # Returns
+    /// The slashed amount, which can be lower than the expected one if the address had not enough funds
+    pub fn try_slash_rolls(
+        &mut self,
+        addr: &Address,
+        roll_count: u64,
+        roll_price: Amount,
+        min_credit_slot: Slot,
+    ) -> Result<Amount, ExecutionError> {
+        // fetch the roll count from: current changes > active history > final state
+        let owned_count = self.get_rolls(addr);
+        let slashed_rolls = std::cmp::min(owned_count, roll_count);
+
+        // remove the slashed rolls
+        if slashed_rolls > 0 {
+            self.added_changes
+                .roll_changes
+                .insert(*addr, owned_count.saturating_sub(slashed_rolls));
+        }
+        let mut slashed_amount = roll_price.checked_mul_u64(slashed_rolls).ok_or_else(|| {
+            ExecutionError::SlashError(format!(
+                "overflow while computing the value of {} slashed rolls of {}",
+                slashed_rolls, addr
+            ))
+        })?;
+
+        // slash the remaining amount from the future deferred credits, earliest first
+        let mut amount_left = roll_price
+            .checked_mul_u64(roll_count.saturating_sub(slashed_rolls))
+            .ok_or_else(|| {
+                ExecutionError::SlashError(format!(
+                    "overflow while computing the value of {} rolls of {} to slash",
+                    roll_count, addr
+                ))
+            })?;
+        if !amount_left.is_zero() {
+            for (slot, credits) in self.get_address_deferred_credits(addr, min_credit_slot) {
+                let slashed_credits = std::cmp::min(credits, amount_left);
+                self.added_changes.deferred_credits.insert(
+                    slot,
+                    *addr,
+                    credits.saturating_sub(slashed_credits),
+                );
+                amount_left = amount_left.saturating_sub(slashed_credits);
+                slashed_amount = slashed_amount.saturating_add(slashed_credits);
+                if amount_left.is_zero() {
+                    break;
+                }
+            }
+        }
+
+        Ok(slashed_amount)
+    }
+
     /// Update production statistics of an address.
     ///
     /// # Arguments
@@ -1,5 +1,6 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
+use massa_metrics::{EXECUTION_FINAL_BLOCKS, EXECUTION_FINAL_OPERATIONS};
 use massa_models::slot::Slot;
 use massa_models::stats::ExecutionStats;
 use massa_time::MassaTime;
@@ -53,6 +54,7 @@ impl ExecutionStatsCounter {
         let current_time = MassaTime::now().expect("could not get current time");
         self.final_blocks.push_back((count, current_time));
         self.refresh(current_time);
+        EXECUTION_FINAL_BLOCKS.inc_by(count as u64);
     }
 
     /// register final executed operations
@@ -60,6 +62,7 @@ impl ExecutionStatsCounter {
         let current_time = MassaTime::now().expect("could not get current time");
         self.final_executed_ops.push_back((count, current_time));
         self.refresh(current_time);
+        EXECUTION_FINAL_OPERATIONS.inc_by(count as u64);
     }
 
     /// get statistics
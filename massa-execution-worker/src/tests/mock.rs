@@ -10,7 +10,9 @@ use massa_models::{
     amount::Amount,
     block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer},
+    block_id::BlockId,
     config::THREAD_COUNT,
+    denunciation::Denunciation,
     operation::SecureShareOperation,
     secure_share::SecureShareContent,
     slot::Slot,
@@ -117,6 +119,7 @@ pub fn get_sample_state(
         async_pool_config: default_config.async_pool_config,
         pos_config: default_config.pos_config,
         executed_ops_config: default_config.executed_ops_config,
+        executed_denunciations_config: default_config.executed_denunciations_config,
         final_history_length: 128,
         thread_count: THREAD_COUNT,
         initial_rolls_path: rolls_file.path().to_path_buf(),
@@ -131,8 +134,10 @@ pub fn get_sample_state(
             Box::new(ledger),
             selector_controller,
             last_start_period,
+            None,
         )
         .unwrap()
+        .0
     } else {
         FinalState::new(cfg, Box::new(ledger), selector_controller).unwrap()
     };
@@ -150,6 +155,16 @@ pub fn create_block(
     creator_keypair: KeyPair,
     operations: Vec<SecureShareOperation>,
     slot: Slot,
+) -> Result<SecureShareBlock, ExecutionError> {
+    create_block_with_denunciations(creator_keypair, operations, Vec::new(), slot)
+}
+
+/// Same as `create_block` but includes the given denunciations in the block header
+pub fn create_block_with_denunciations(
+    creator_keypair: KeyPair,
+    operations: Vec<SecureShareOperation>,
+    denunciations: Vec<Denunciation>,
+    slot: Slot,
 ) -> Result<SecureShareBlock, ExecutionError> {
     let operation_merkle_root = Hash::compute_from(
         &operations.iter().fold(Vec::new(), |acc, v| {
@@ -157,12 +172,17 @@ pub fn create_block(
         })[..],
     );
 
+    // genesis headers cannot contain denunciations
+    let parents = (0..THREAD_COUNT)
+        .map(|i| BlockId(Hash::compute_from(&[i])))
+        .collect();
     let header = BlockHeader::new_verifiable(
         BlockHeader {
             slot,
-            parents: vec![],
+            parents,
             operation_merkle_root,
             endorsements: vec![],
+            denunciations,
         },
         BlockHeaderSerializer::new(),
         &creator_keypair,
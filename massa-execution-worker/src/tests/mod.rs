@@ -14,6 +14,12 @@ mod scenarios_mandatories;
 #[cfg(all(not(feature = "gas_calibration"), not(feature = "benchmarking")))]
 mod tests_active_history;
 
+#[cfg(all(not(feature = "gas_calibration"), not(feature = "benchmarking")))]
+mod tests_final_events_db;
+
+#[cfg(all(not(feature = "gas_calibration"), not(feature = "benchmarking")))]
+mod tests_execution_trace;
+
 mod interface;
 
 #[cfg(any(
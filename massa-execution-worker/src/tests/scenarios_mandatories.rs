@@ -3,35 +3,59 @@
 mod tests {
     use crate::start_execution_worker;
     use crate::tests::mock::{
-        create_block, get_initials_vesting, get_random_address_full, get_sample_state,
+        create_block, create_block_with_denunciations, get_initials_vesting,
+        get_random_address_full, get_sample_state,
     };
     use massa_execution_exports::{
-        ExecutionConfig, ExecutionController, ExecutionError, ReadOnlyExecutionRequest,
-        ReadOnlyExecutionTarget,
+        BalanceChange, ExecutionChannels, ExecutionConfig, ExecutionController, ExecutionError,
+        ExecutionStackElement, ExecutionTraceStep, OperationEstimationRequest,
+        ReadOnlyExecutionRequest, ReadOnlyExecutionTarget, StateOverride,
     };
+    use massa_hash::Hash;
     use massa_models::config::{
-        LEDGER_ENTRY_BASE_SIZE, LEDGER_ENTRY_DATASTORE_BASE_SIZE, MIP_STORE_STATS_BLOCK_CONSIDERED,
-        MIP_STORE_STATS_COUNTERS_MAX,
+        DENUNCIATIONS_BLOCK_COMPONENT_VERSION, ENDORSEMENT_COUNT, LEDGER_ENTRY_BASE_SIZE,
+        LEDGER_ENTRY_DATASTORE_BASE_SIZE, MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+        MAX_OPERATIONS_PER_BLOCK, MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX,
+        THREAD_COUNT,
     };
     use massa_models::prehash::PreHashMap;
     use massa_models::{address::Address, amount::Amount, slot::Slot};
     use massa_models::{
+        block::{BlockDeserializer, BlockDeserializerArgs, SecureShareBlock},
         block_id::BlockId,
         datastore::Datastore,
+        denunciation::Denunciation,
+        endorsement::{Endorsement, EndorsementSerializer},
         execution::EventFilter,
-        operation::{Operation, OperationSerializer, OperationType, SecureShareOperation},
-        secure_share::SecureShareContent,
+        operation::{
+            Operation, OperationSerializer, OperationType, OperationTypeSerializer,
+            SecureShareOperation,
+        },
+        secure_share::{SecureShareContent, SecureShareDeserializer, SecureShareSerializer},
     };
+    use massa_pool_exports::{PoolChannels, PoolConfig};
+    use massa_pool_worker::start_pool_controller;
+    use massa_serialization::{DeserializeError, Deserializer, Serializer};
     use massa_signature::KeyPair;
     use massa_storage::Storage;
     use massa_time::MassaTime;
-    use massa_versioning_worker::versioning::{MipStatsConfig, MipStore};
+    use massa_versioning_worker::{
+        test_helpers::versioning_helpers::get_mip_store_with_active_component,
+        versioning::{MipComponent, MipStatsConfig, MipStore},
+    };
     use num::rational::Ratio;
     use serial_test::serial;
     use std::{
         cmp::Reverse, collections::BTreeMap, collections::HashMap, str::FromStr, time::Duration,
     };
 
+    /// Creates the channels used by the execution worker to broadcast its outputs
+    fn get_execution_channels() -> ExecutionChannels {
+        ExecutionChannels {
+            sc_event_sender: tokio::sync::broadcast::channel(5000).0,
+        }
+    }
+
     #[test]
     #[serial]
     fn test_execution_shutdown() {
@@ -53,6 +77,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         manager.stop();
     }
@@ -78,6 +103,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         controller.update_blockclique_status(
             Default::default(),
@@ -114,6 +140,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -127,6 +154,8 @@ mod tests {
                     include_bytes!("./wasm/event_test.wasm").to_vec(),
                 ),
                 is_final: true,
+                state_overrides: Default::default(),
+                with_trace: false,
             })
             .expect("readonly execution failed");
         assert_eq!(res.out.slot, Slot::new(1, 0));
@@ -141,6 +170,8 @@ mod tests {
                     include_bytes!("./wasm/event_test.wasm").to_vec(),
                 ),
                 is_final: false,
+                state_overrides: Default::default(),
+                with_trace: false,
             })
             .expect("readonly execution failed");
         assert!(res.out.slot.period > 8);
@@ -148,6 +179,176 @@ mod tests {
         manager.stop();
     }
 
+    #[test]
+    #[serial]
+    fn readonly_execution_with_trace() {
+        let vesting = get_initials_vesting(false);
+        // setup the period duration
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+        // init the storage
+        let storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        std::thread::sleep(Duration::from_millis(100));
+
+        let (address, _keypair) = get_random_address_full();
+        let request = |with_trace: bool| ReadOnlyExecutionRequest {
+            max_gas: 1_000_000,
+            call_stack: vec![ExecutionStackElement {
+                address,
+                coins: Default::default(),
+                owned_addresses: vec![address],
+                operation_datastore: None,
+            }],
+            target: ReadOnlyExecutionTarget::BytecodeExecution(
+                include_bytes!("./wasm/event_test.wasm").to_vec(),
+            ),
+            is_final: true,
+            state_overrides: Default::default(),
+            with_trace,
+        };
+
+        // no trace is recorded unless requested
+        let res = controller
+            .execute_readonly_request(request(false))
+            .expect("readonly execution failed");
+        assert!(res.trace.is_none());
+
+        // the trace is rooted at the executing address and ends with the remaining gas
+        let res = controller
+            .execute_readonly_request(request(true))
+            .expect("readonly execution failed");
+        let trace = res.trace.expect("missing execution trace");
+        assert_eq!(trace.address, address);
+        assert!(trace.finished);
+        assert_eq!(
+            trace.steps.last(),
+            Some(&ExecutionTraceStep::GasCheckpoint {
+                remaining_gas: 1_000_000 - res.gas_cost
+            })
+        );
+
+        manager.stop();
+    }
+
+    #[test]
+    #[serial]
+    fn readonly_execution_with_state_overrides() {
+        let vesting = get_initials_vesting(false);
+        // setup the period duration
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+        // init the storage
+        let storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        std::thread::sleep(Duration::from_millis(100));
+
+        // the sender does not exist in the ledger and has no rolls
+        let (sender_address, _keypair) = get_random_address_full();
+        let (recipient_address, _keypair) = get_random_address_full();
+        let request = |op: OperationType, state_overrides: BTreeMap<Address, StateOverride>| {
+            ReadOnlyExecutionRequest {
+                max_gas: 0,
+                call_stack: vec![ExecutionStackElement {
+                    address: sender_address,
+                    coins: Default::default(),
+                    owned_addresses: vec![sender_address],
+                    operation_datastore: None,
+                }],
+                target: ReadOnlyExecutionTarget::OperationExecution(op),
+                is_final: true,
+                state_overrides,
+                with_trace: false,
+            }
+        };
+        let transaction = OperationType::Transaction {
+            recipient_address,
+            amount: Amount::from_str("100").unwrap(),
+        };
+        let roll_sell = OperationType::RollSell { roll_count: 1 };
+
+        // without overrides, the sender cannot transfer coins nor sell rolls
+        assert!(controller
+            .execute_readonly_request(request(transaction.clone(), BTreeMap::new()))
+            .is_err());
+        assert!(controller
+            .execute_readonly_request(request(roll_sell.clone(), BTreeMap::new()))
+            .is_err());
+
+        // with overrides, the sender gets a balance and rolls for the execution only
+        let state_overrides = BTreeMap::from([(
+            sender_address,
+            StateOverride {
+                balance: Some(Amount::from_str("1000").unwrap()),
+                roll_count: Some(1),
+                ..Default::default()
+            },
+        )]);
+        let res = controller
+            .execute_readonly_request(request(transaction, state_overrides.clone()))
+            .expect("readonly execution failed");
+        assert_eq!(
+            res.balance_changes.get(&sender_address),
+            Some(&BalanceChange {
+                previous: Amount::from_str("1000").unwrap(),
+                predicted: Amount::from_str("900").unwrap(),
+            })
+        );
+        controller
+            .execute_readonly_request(request(roll_sell, state_overrides))
+            .expect("readonly execution failed");
+
+        // the overrides are not kept in the ledger
+        assert_eq!(
+            sample_state.read().ledger.get_balance(&sender_address),
+            None
+        );
+
+        manager.stop();
+    }
+
     /// Feeds the execution worker with genesis blocks to start it
     fn init_execution_worker(
         config: &ExecutionConfig,
@@ -207,6 +408,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -285,6 +487,7 @@ mod tests {
             address.as_bytes().to_vec(),
         )
         .unwrap();
+        let operation_id = operation.id;
         // Init new storage for this block
         let mut storage = Storage::create_root();
         storage.store_operations(vec![operation.clone()]);
@@ -313,6 +516,16 @@ mod tests {
             events.is_sorted_by_key(|event| Reverse(event.data.parse::<u64>().unwrap())),
             "Gas is not going down through the execution."
         );
+        // the trace of the final call is rooted at the called smart contract
+        let trace = controller
+            .get_operation_trace(&operation_id)
+            .expect("missing operation trace");
+        assert_eq!(trace.address, Address::from_str(&address).unwrap());
+        assert!(trace.finished);
+        assert!(matches!(
+            trace.steps.last(),
+            Some(ExecutionTraceStep::GasCheckpoint { .. })
+        ));
         // stop the execution controller
         manager.stop();
     }
@@ -348,6 +561,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -483,6 +697,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -580,6 +795,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -677,6 +893,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -792,6 +1009,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -892,6 +1110,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1043,6 +1262,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1102,6 +1322,253 @@ mod tests {
         manager.stop();
     }
 
+    #[test]
+    #[serial]
+    fn estimate_transaction() {
+        let vesting = get_initials_vesting(false);
+        // setup the period duration
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+
+        // init the storage
+        let storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        std::thread::sleep(Duration::from_millis(100));
+        // generate the sender_keypair and recipient_address
+        let sender_keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        let sender_address = Address::from_public_key(&sender_keypair.get_public_key());
+        let sender_balance = sample_state
+            .read()
+            .ledger
+            .get_balance(&sender_address)
+            .unwrap();
+        let (recipient_address, _keypair) = get_random_address_full();
+        let amount = Amount::from_str("100").unwrap();
+
+        // estimate the transaction against the final state
+        let op = OperationType::Transaction {
+            recipient_address,
+            amount,
+        };
+        let size_fee = estimated_size_fee(&exec_cfg, &op);
+        let estimation = controller
+            .estimate_operation(OperationEstimationRequest {
+                sender_address,
+                op,
+                is_final: true,
+            })
+            .expect("operation estimation failed");
+        // the creation of the recipient address is paid from the transferred coins
+        let address_cost = exec_cfg
+            .storage_costs_constants
+            .ledger_cost_per_byte
+            .saturating_mul_u64(LEDGER_ENTRY_BASE_SIZE as u64);
+        assert_eq!(estimation.gas_used, 0);
+        assert!(size_fee > Amount::zero());
+        assert_eq!(estimation.suggested_fee, size_fee);
+        assert_eq!(estimation.storage_cost, address_cost);
+        assert_eq!(
+            estimation.balance_changes,
+            BTreeMap::from([
+                (
+                    sender_address,
+                    BalanceChange {
+                        previous: sender_balance,
+                        predicted: sender_balance.saturating_sub(amount),
+                    }
+                ),
+                (
+                    recipient_address,
+                    BalanceChange {
+                        previous: Amount::zero(),
+                        predicted: amount.saturating_sub(address_cost),
+                    }
+                ),
+            ])
+        );
+        // the estimation does not change the ledger
+        assert_eq!(
+            sample_state.read().ledger.get_balance(&recipient_address),
+            None
+        );
+        // stop the execution controller
+        manager.stop();
+    }
+
+    /// Fee suggested for the size of an operation
+    fn estimated_size_fee(exec_cfg: &ExecutionConfig, op: &OperationType) -> Amount {
+        let mut op_bytes = Vec::new();
+        OperationTypeSerializer::new()
+            .serialize(op, &mut op_bytes)
+            .unwrap();
+        exec_cfg
+            .estimated_fee_per_byte
+            .saturating_mul_u64(op_bytes.len() as u64)
+    }
+
+    #[test]
+    #[serial]
+    fn estimate_roll_buy() {
+        let vesting = get_initials_vesting(false);
+        // setup the period duration
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+
+        // init the storage
+        let storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        std::thread::sleep(Duration::from_millis(100));
+        let keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        let address = Address::from_public_key(&keypair.get_public_key());
+        let balance = sample_state.read().ledger.get_balance(&address).unwrap();
+
+        // estimate the roll purchase against the final state
+        let op = OperationType::RollBuy { roll_count: 10 };
+        let size_fee = estimated_size_fee(&exec_cfg, &op);
+        let estimation = controller
+            .estimate_operation(OperationEstimationRequest {
+                sender_address: address,
+                op,
+                is_final: true,
+            })
+            .expect("operation estimation failed");
+        assert_eq!(estimation.gas_used, 0);
+        assert_eq!(estimation.storage_cost, Amount::zero());
+        assert!(size_fee > Amount::zero());
+        assert_eq!(estimation.suggested_fee, size_fee);
+        assert_eq!(
+            estimation.balance_changes,
+            BTreeMap::from([(
+                address,
+                BalanceChange {
+                    previous: balance,
+                    predicted: balance.saturating_sub(exec_cfg.roll_price.saturating_mul_u64(10)),
+                }
+            )])
+        );
+        // the estimation does not buy the rolls
+        assert_eq!(sample_state.read().pos_state.get_rolls_for(&address), 100);
+        // stop the execution controller
+        manager.stop();
+    }
+
+    #[test]
+    #[serial]
+    fn estimate_execute_sc() {
+        let vesting = get_initials_vesting(false);
+        // setup the period duration
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+
+        // init the storage
+        let storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        std::thread::sleep(Duration::from_millis(100));
+        let keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        let address = Address::from_public_key(&keypair.get_public_key());
+
+        // estimate the execution of a smart contract against the final state
+        // you can check the source code of the following wasm file in massa-unit-tests-src
+        let op = OperationType::ExecuteSC {
+            data: include_bytes!("./wasm/datastore.wasm").to_vec(),
+            max_gas: 100_000_000,
+            datastore: BTreeMap::from([(vec![65, 66], vec![255]), (vec![9], vec![10, 11])]),
+        };
+        let size_fee = estimated_size_fee(&exec_cfg, &op);
+        let estimation = controller
+            .estimate_operation(OperationEstimationRequest {
+                sender_address: address,
+                op,
+                is_final: true,
+            })
+            .expect("operation estimation failed");
+        // the fee covers both the gas used and the size of the bytecode
+        assert!(estimation.gas_used > 0);
+        assert!(estimation.gas_used <= 100_000_000);
+        assert_eq!(
+            estimation.suggested_fee,
+            exec_cfg
+                .estimated_fee_per_gas
+                .saturating_mul_u64(estimation.gas_used)
+                .saturating_add(size_fee)
+        );
+        // the events of the estimation are not kept
+        assert!(controller
+            .get_filtered_sc_output_event(EventFilter::default())
+            .is_empty());
+        // stop the execution controller
+        manager.stop();
+    }
+
     #[test]
     #[serial]
     fn vesting_transfer_coins() {
@@ -1131,6 +1598,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1227,6 +1695,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1281,6 +1750,86 @@ mod tests {
         manager.stop();
     }
 
+    #[test]
+    #[serial]
+    fn sc_events_broadcast() {
+        // setup the period duration
+        let vesting = get_initials_vesting(true);
+        let exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            ..ExecutionConfig::default()
+        };
+
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(0).unwrap();
+
+        // init the MIP store
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+
+        // init the storage
+        let mut storage = Storage::create_root();
+        // subscribe to the smart contract events broadcast and start the execution worker
+        let channels = get_execution_channels();
+        let mut event_receiver = channels.sc_event_sender.subscribe();
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            channels,
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        // generate the keypair
+        let keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        // create a roll buy operation failing because of the vesting constraint
+        let operation = Operation::new_verifiable(
+            Operation {
+                fee: Amount::zero(),
+                expire_period: 10,
+                op: OperationType::RollBuy { roll_count: 60 },
+            },
+            OperationSerializer::new(),
+            &keypair,
+        )
+        .unwrap();
+        // create the block containing the roll buy operation
+        storage.store_operations(vec![operation.clone()]);
+        let block = create_block(KeyPair::generate(), vec![operation], Slot::new(1, 0)).unwrap();
+        // store the block in storage
+        storage.store_block(block.clone());
+        // set our block as a final block so the purchase is processed
+        let mut finalized_blocks: HashMap<Slot, BlockId> = Default::default();
+        finalized_blocks.insert(block.content.header.content.slot, block.id);
+        let mut block_storage: PreHashMap<BlockId, Storage> = Default::default();
+        block_storage.insert(block.id, storage.clone());
+        controller.update_blockclique_status(
+            finalized_blocks,
+            Default::default(),
+            block_storage.clone(),
+        );
+        std::thread::sleep(Duration::from_millis(100));
+
+        // the event emitted by the execution error should have been broadcast as final
+        let mut broadcast_events = Vec::new();
+        while let Ok(event) = event_receiver.try_recv() {
+            broadcast_events.push(event);
+        }
+        assert!(broadcast_events.iter().any(|event| event.context.is_final
+            && event.context.is_error
+            && event.data.contains("massa_execution_error")));
+
+        // stop the execution controller
+        manager.stop();
+    }
+
     #[test]
     #[serial]
     pub fn roll_buy() {
@@ -1310,6 +1859,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1395,6 +1945,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1538,6 +2089,244 @@ mod tests {
         manager.stop();
     }
 
+    #[test]
+    #[serial]
+    pub fn roll_slash() {
+        let vesting = get_initials_vesting(false);
+        // Include the same denunciation twice in a block
+        // Check that the denounced address is slashed only once
+
+        // setup the period duration
+        let mut exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            periods_per_cycle: 2,
+            thread_count: 2,
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            last_start_period: 2,
+            ..Default::default()
+        };
+        // turn off roll selling on missed block opportunities
+        exec_cfg.max_miss_ratio = Ratio::new(1, 1);
+
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(2).unwrap();
+
+        // init the MIP store, with the block version that supports denunciations active
+        let mip_store = get_mip_store_with_active_component(
+            MipComponent::Block,
+            DENUNCIATIONS_BLOCK_COMPONENT_VERSION,
+        );
+
+        // init the storage
+        let mut storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        // generate the keypair and its corresponding address
+        let keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        let address = Address::from_public_key(&keypair.get_public_key());
+
+        // get initial roll count
+        let roll_count_initial = sample_state.read().pos_state.get_rolls_for(&address);
+
+        // create two conflicting endorsements for the same slot and index
+        let endorsement_1 = Endorsement::new_verifiable(
+            Endorsement {
+                slot: Slot::new(2, 0),
+                index: 0,
+                endorsed_block: BlockId(Hash::compute_from("blk1".as_bytes())),
+            },
+            EndorsementSerializer::new(),
+            &keypair,
+        )
+        .unwrap();
+        let endorsement_2 = Endorsement::new_verifiable(
+            Endorsement {
+                slot: Slot::new(2, 0),
+                index: 0,
+                endorsed_block: BlockId(Hash::compute_from("blk2".as_bytes())),
+            },
+            EndorsementSerializer::new(),
+            &keypair,
+        )
+        .unwrap();
+        let denunciation = Denunciation::try_from((&endorsement_1, &endorsement_2)).unwrap();
+
+        // create the block containing the denunciation twice
+        let block = create_block_with_denunciations(
+            KeyPair::generate(),
+            vec![],
+            vec![denunciation.clone(), denunciation],
+            Slot::new(3, 0),
+        )
+        .unwrap();
+        // store the block in storage
+        storage.store_block(block.clone());
+        // set the block as final so the slash is processed
+        let mut finalized_blocks: HashMap<Slot, BlockId> = Default::default();
+        finalized_blocks.insert(block.content.header.content.slot, block.id);
+        let mut block_storage: PreHashMap<BlockId, Storage> = Default::default();
+        block_storage.insert(block.id, storage.clone());
+        controller.update_blockclique_status(
+            finalized_blocks,
+            Default::default(),
+            block_storage.clone(),
+        );
+        std::thread::sleep(Duration::from_millis(1000));
+
+        // check that the rolls were slashed only once
+        let sample_read = sample_state.read();
+        assert_eq!(
+            sample_read.pos_state.get_rolls_for(&address),
+            roll_count_initial - exec_cfg.roll_count_to_slash_on_denunciation
+        );
+        assert_eq!(sample_read.executed_denunciations.len(), 1);
+
+        // stop the execution controller
+        manager.stop();
+    }
+
+    #[test]
+    #[serial]
+    pub fn roll_slash_from_pool_denunciation() {
+        let vesting = get_initials_vesting(false);
+        // Add a denunciation to the pool, put what the pool returns in a block header as the block factory does,
+        // and check that the denounced address is slashed once the block is final
+
+        // setup the period duration
+        let mut exec_cfg = ExecutionConfig {
+            t0: 100.into(),
+            periods_per_cycle: 2,
+            thread_count: 2,
+            cursor_delay: 0.into(),
+            initial_vesting_path: vesting.path().to_path_buf(),
+            last_start_period: 2,
+            ..Default::default()
+        };
+        // turn off roll selling on missed block opportunities
+        exec_cfg.max_miss_ratio = Ratio::new(1, 1);
+
+        // get a sample final state
+        let (sample_state, _keep_file, _keep_dir) = get_sample_state(2).unwrap();
+
+        // init the MIP store, with the block version that supports denunciations active
+        let mip_store = get_mip_store_with_active_component(
+            MipComponent::Block,
+            DENUNCIATIONS_BLOCK_COMPONENT_VERSION,
+        );
+
+        // init the storage
+        let mut storage = Storage::create_root();
+        // start the execution worker
+        let (mut manager, controller) = start_execution_worker(
+            exec_cfg.clone(),
+            sample_state.clone(),
+            sample_state.read().pos_state.selector.clone(),
+            mip_store,
+            get_execution_channels(),
+        );
+        // initialize the execution system with genesis blocks
+        init_execution_worker(&exec_cfg, &storage, controller.clone());
+        // start the pool
+        let (mut pool_manager, mut pool_controller) = start_pool_controller(
+            PoolConfig::default(),
+            &storage,
+            controller.clone(),
+            PoolChannels {
+                operation_sender: tokio::sync::broadcast::channel(5000).0,
+            },
+            crossbeam_channel::unbounded().0,
+        );
+
+        // generate the keypair and its corresponding address
+        let keypair =
+            KeyPair::from_str("S1JJeHiZv1C1zZN5GLFcbz6EXYiccmUPLkYuDFA3kayjxP39kFQ").unwrap();
+        let address = Address::from_public_key(&keypair.get_public_key());
+
+        // get initial roll count
+        let roll_count_initial = sample_state.read().pos_state.get_rolls_for(&address);
+
+        // denounce two conflicting endorsements for the same slot and index
+        let endorsement_1 = Endorsement::new_verifiable(
+            Endorsement {
+                slot: Slot::new(2, 0),
+                index: 0,
+                endorsed_block: BlockId(Hash::compute_from("blk1".as_bytes())),
+            },
+            EndorsementSerializer::new(),
+            &keypair,
+        )
+        .unwrap();
+        let endorsement_2 = Endorsement::new_verifiable(
+            Endorsement {
+                slot: Slot::new(2, 0),
+                index: 0,
+                endorsed_block: BlockId(Hash::compute_from("blk2".as_bytes())),
+            },
+            EndorsementSerializer::new(),
+            &keypair,
+        )
+        .unwrap();
+        let denunciation = Denunciation::try_from((&endorsement_1, &endorsement_2)).unwrap();
+        pool_controller.add_denunciation(denunciation.clone());
+        std::thread::sleep(Duration::from_millis(100));
+
+        // fill the block header with the denunciations of the pool
+        let block_slot = Slot::new(3, 0);
+        let denunciations = pool_controller.get_block_denunciations(&block_slot);
+        assert_eq!(denunciations, vec![denunciation]);
+        let block =
+            create_block_with_denunciations(KeyPair::generate(), vec![], denunciations, block_slot)
+                .unwrap();
+
+        // the block goes through the network encoding before being executed
+        let mut serialized_block = Vec::new();
+        SecureShareSerializer::new()
+            .serialize(&block, &mut serialized_block)
+            .unwrap();
+        let (_, block): (&[u8], SecureShareBlock) =
+            SecureShareDeserializer::new(BlockDeserializer::new(BlockDeserializerArgs {
+                thread_count: THREAD_COUNT,
+                max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
+                endorsement_count: ENDORSEMENT_COUNT,
+                max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+                last_start_period: None,
+            }))
+            .deserialize::<DeserializeError>(&serialized_block)
+            .unwrap();
+        storage.store_block(block.clone());
+
+        // set the block as final so the slash is processed
+        let mut finalized_blocks: HashMap<Slot, BlockId> = Default::default();
+        finalized_blocks.insert(block.content.header.content.slot, block.id);
+        let mut block_storage: PreHashMap<BlockId, Storage> = Default::default();
+        block_storage.insert(block.id, storage.clone());
+        controller.update_blockclique_status(finalized_blocks, Default::default(), block_storage);
+        std::thread::sleep(Duration::from_millis(1000));
+
+        // check that the rolls were slashed
+        let sample_read = sample_state.read();
+        assert_eq!(
+            sample_read.pos_state.get_rolls_for(&address),
+            roll_count_initial - exec_cfg.roll_count_to_slash_on_denunciation
+        );
+        assert_eq!(sample_read.executed_denunciations.len(), 1);
+        drop(sample_read);
+
+        // stop the pool and the execution controller
+        pool_manager.stop();
+        manager.stop();
+    }
+
     #[test]
     #[serial]
     fn sc_execution_error() {
@@ -1568,6 +2357,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1580,6 +2370,7 @@ mod tests {
         // create the block containing the erroneous smart contract execution operation
         let operation =
             create_execute_sc_operation(&keypair, bytecode, BTreeMap::default()).unwrap();
+        let operation_id = operation.id;
         storage.store_operations(vec![operation.clone()]);
         let block = create_block(KeyPair::generate(), vec![operation], Slot::new(1, 0)).unwrap();
         // store the block in storage
@@ -1609,6 +2400,15 @@ mod tests {
             .data
             .contains("runtime error when executing operation"));
         assert!(events[1].data.contains("address parsing error"));
+        // the trace of the failed operation is kept in the final trace store
+        let trace = controller
+            .get_operation_trace(&operation_id)
+            .expect("missing operation trace");
+        assert_eq!(
+            trace.address,
+            Address::from_public_key(&keypair.get_public_key())
+        );
+        assert!(!trace.finished);
         // stop the execution controller
         manager.stop();
     }
@@ -1643,6 +2443,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1715,6 +2516,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1787,6 +2589,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -1916,6 +2719,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -2010,6 +2814,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -2141,6 +2946,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
@@ -2222,6 +3028,7 @@ mod tests {
             sample_state.clone(),
             sample_state.read().pos_state.selector.clone(),
             mip_store,
+            get_execution_channels(),
         );
         // initialize the execution system with genesis blocks
         init_execution_worker(&exec_cfg, &storage, controller.clone());
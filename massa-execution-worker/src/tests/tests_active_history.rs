@@ -50,8 +50,10 @@ mod tests {
                     deferred_credits: credits,
                 },
                 executed_ops_changes: Default::default(),
+                executed_denunciations_changes: Default::default(),
             },
             events: Default::default(),
+            operation_traces: Default::default(),
         };
 
         let active_history = ActiveHistory {
@@ -0,0 +1,139 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+#[cfg(test)]
+mod tests {
+    use crate::execution_trace::{ExecutionTracer, OperationTraceStore};
+    use massa_execution_exports::{CallTrace, ExecutionTraceStep, MAX_TRACED_VALUE_LENGTH};
+    use massa_hash::Hash;
+    use massa_models::address::{Address, UserAddress};
+    use massa_models::amount::Amount;
+    use massa_models::operation::OperationId;
+
+    fn address(name: &str) -> Address {
+        Address::User(UserAddress(Hash::compute_from(name.as_bytes())))
+    }
+
+    fn operation_id(index: u8) -> OperationId {
+        OperationId::from_bytes(Hash::compute_from(&[index]).to_bytes())
+    }
+
+    #[test]
+    fn test_tracer_nested_calls() {
+        let (addr_a, addr_b, addr_c) = (address("A"), address("B"), address("C"));
+        let mut tracer = ExecutionTracer::default();
+        tracer.enter_call(addr_a, Amount::zero());
+        tracer.record(ExecutionTraceStep::set_data(addr_a, b"key", b"value"));
+        tracer.enter_call(addr_b, Amount::from_raw(10));
+        tracer.enter_call(addr_c, Amount::zero());
+        tracer.exit_call();
+        tracer.exit_call();
+        tracer.record(ExecutionTraceStep::GasCheckpoint { remaining_gas: 42 });
+        tracer.exit_call();
+
+        let expected_c = CallTrace {
+            finished: true,
+            ..CallTrace::new(addr_c, Amount::zero())
+        };
+        let expected_b = CallTrace {
+            steps: vec![ExecutionTraceStep::Call(expected_c)],
+            finished: true,
+            ..CallTrace::new(addr_b, Amount::from_raw(10))
+        };
+        let expected_a = CallTrace {
+            steps: vec![
+                ExecutionTraceStep::SetData {
+                    address: addr_a,
+                    key: b"key".to_vec(),
+                    value: b"value".to_vec(),
+                    value_length: 5,
+                },
+                ExecutionTraceStep::Call(expected_b),
+                ExecutionTraceStep::GasCheckpoint { remaining_gas: 42 },
+            ],
+            finished: true,
+            ..CallTrace::new(addr_a, Amount::zero())
+        };
+        assert_eq!(tracer.finish(true), Some(expected_a));
+    }
+
+    #[test]
+    fn test_tracer_failed_execution() {
+        let (addr_a, addr_b) = (address("A"), address("B"));
+        let mut tracer = ExecutionTracer::default();
+        tracer.enter_call(addr_a, Amount::zero());
+        tracer.enter_call(addr_b, Amount::zero());
+        tracer.record(ExecutionTraceStep::TransferCoins {
+            from: addr_b,
+            to: addr_a,
+            amount: Amount::from_raw(1),
+        });
+
+        // the calls interrupted by the failure are kept as unfinished
+        let trace = tracer.finish(false).expect("missing trace");
+        assert_eq!(trace.address, addr_a);
+        assert!(!trace.finished);
+        match trace.steps.as_slice() {
+            [ExecutionTraceStep::Call(call)] => {
+                assert_eq!(call.address, addr_b);
+                assert!(!call.finished);
+                assert_eq!(call.steps.len(), 1);
+            }
+            steps => panic!("unexpected steps {:?}", steps),
+        }
+
+        // nothing is recorded without a call
+        assert_eq!(ExecutionTracer::default().finish(true), None);
+    }
+
+    #[test]
+    fn test_traced_value_truncation() {
+        let value = vec![7u8; MAX_TRACED_VALUE_LENGTH * 4];
+        match ExecutionTraceStep::set_data(address("A"), b"key", &value) {
+            ExecutionTraceStep::SetData {
+                value: traced_value,
+                value_length,
+                ..
+            } => {
+                assert_eq!(traced_value, value[..MAX_TRACED_VALUE_LENGTH]);
+                assert_eq!(value_length, value.len() as u64);
+            }
+            step => panic!("unexpected step {:?}", step),
+        }
+    }
+
+    #[test]
+    fn test_operation_trace_store_bounds() {
+        let trace = |index: u8| {
+            let mut trace = CallTrace::new(address("A"), Amount::zero());
+            trace.steps.push(ExecutionTraceStep::set_data(
+                address("A"),
+                &[index],
+                &[0u8; MAX_TRACED_VALUE_LENGTH],
+            ));
+            trace
+        };
+        let trace_size = trace(0).size();
+
+        // bounded by count, oldest first
+        let mut store = OperationTraceStore::default();
+        store.extend((0..5).map(|i| (operation_id(i), trace(i))), 3, usize::MAX);
+        assert!(store.get(&operation_id(1)).is_none());
+        assert!(store.get(&operation_id(2)).is_some());
+        assert!(store.get(&operation_id(4)).is_some());
+
+        // bounded by size, oldest first
+        let mut store = OperationTraceStore::default();
+        store.extend(
+            (0..5).map(|i| (operation_id(i), trace(i))),
+            usize::MAX,
+            2 * trace_size,
+        );
+        assert!(store.get(&operation_id(2)).is_none());
+        assert_eq!(store.get(&operation_id(3)), Some(&trace(3)));
+        assert_eq!(store.get(&operation_id(4)), Some(&trace(4)));
+
+        // a trace larger than the store is not kept
+        store.extend([(operation_id(5), trace(5))], usize::MAX, trace_size - 1);
+        assert!(store.get(&operation_id(5)).is_none());
+    }
+}
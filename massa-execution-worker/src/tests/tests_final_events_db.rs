@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod tests {
+    use crate::final_events_db::FinalEventsDB;
+    use massa_execution_exports::EventStore;
+    use massa_hash::Hash;
+    use massa_models::address::{Address, UserAddress};
+    use massa_models::execution::EventFilter;
+    use massa_models::operation::OperationId;
+    use massa_models::output_event::{EventExecutionContext, SCOutputEvent};
+    use massa_models::secure_share::Id;
+    use massa_models::slot::Slot;
+    use std::collections::VecDeque;
+    use tempfile::TempDir;
+
+    fn make_event(
+        slot: Slot,
+        index_in_slot: u64,
+        call_stack: Vec<Address>,
+        origin_operation_id: Option<OperationId>,
+    ) -> SCOutputEvent {
+        SCOutputEvent {
+            context: EventExecutionContext {
+                slot,
+                block: None,
+                read_only: false,
+                index_in_slot,
+                call_stack: call_stack.into_iter().collect(),
+                origin_operation_id,
+                is_final: true,
+                is_error: false,
+            },
+            data: format!("{}-{}", slot, index_in_slot),
+        }
+    }
+
+    #[test]
+    fn test_final_events_db_indexes() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = FinalEventsDB::new(temp_dir.path().to_path_buf(), 2, None, None);
+
+        let addr1 = Address::User(UserAddress(Hash::compute_from("AU1".as_bytes())));
+        let addr2 = Address::User(UserAddress(Hash::compute_from("AU2".as_bytes())));
+        let op_id = OperationId::new(Hash::compute_from("op".as_bytes()));
+
+        let slot1 = Slot::new(1, 0);
+        let slot2 = Slot::new(1, 1);
+        db.apply_events(
+            &VecDeque::from(vec![
+                make_event(slot1, 0, vec![addr1], Some(op_id)),
+                make_event(slot1, 1, vec![addr2, addr1], None),
+            ]),
+            slot1,
+        );
+        db.apply_events(
+            &VecDeque::from(vec![make_event(slot2, 0, vec![addr2], None)]),
+            slot2,
+        );
+        assert_eq!(db.get_slot(), Some(slot2));
+
+        let all = db.get_filtered_sc_output_events(&EventFilter::default(), 100);
+        assert_eq!(all.len(), 3);
+
+        let by_emitter = db.get_filtered_sc_output_events(
+            &EventFilter {
+                emitter_address: Some(addr2),
+                ..Default::default()
+            },
+            100,
+        );
+        assert_eq!(by_emitter.len(), 2);
+
+        let by_caller = db.get_filtered_sc_output_events(
+            &EventFilter {
+                original_caller_address: Some(addr1),
+                ..Default::default()
+            },
+            100,
+        );
+        assert_eq!(by_caller.len(), 2);
+
+        let by_op = db.get_filtered_sc_output_events(
+            &EventFilter {
+                original_operation_id: Some(op_id),
+                ..Default::default()
+            },
+            100,
+        );
+        assert_eq!(by_op.len(), 1);
+        assert_eq!(by_op[0].data, format!("{}-0", slot1));
+
+        let by_range = db.get_filtered_sc_output_events(
+            &EventFilter {
+                emitter_address: Some(addr2),
+                start: Some(slot2),
+                ..Default::default()
+            },
+            100,
+        );
+        assert_eq!(by_range.len(), 1);
+        assert_eq!(by_range[0].context.slot, slot2);
+
+        let limited = db.get_filtered_sc_output_events(&EventFilter::default(), 2);
+        assert_eq!(limited.len(), 2);
+    }
+
+    #[test]
+    fn test_final_events_db_returns_newest_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = FinalEventsDB::new(temp_dir.path().to_path_buf(), 2, None, None);
+        let addr = Address::User(UserAddress(Hash::compute_from("AU1".as_bytes())));
+
+        let mut store = EventStore::default();
+        let mut slot = Slot::new(1, 0);
+        for _ in 0..5 {
+            let events = VecDeque::from(vec![
+                make_event(slot, 0, vec![addr], None),
+                make_event(slot, 1, vec![addr], None),
+            ]);
+            store.extend(EventStore(events.clone()));
+            db.apply_events(&events, slot);
+            slot = slot.get_next_slot(2).unwrap();
+        }
+
+        // the persistent store agrees with the in-memory store keeping the newest events
+        store.prune(3);
+        let expected: Vec<String> = store.0.iter().map(|event| event.data.clone()).collect();
+        for filter in [
+            EventFilter::default(),
+            EventFilter {
+                emitter_address: Some(addr),
+                ..Default::default()
+            },
+        ] {
+            let events: Vec<String> = db
+                .get_filtered_sc_output_events(&filter, 3)
+                .into_iter()
+                .map(|event| event.data)
+                .collect();
+            assert_eq!(events, expected);
+        }
+
+        // the range bounds are applied before the count limit, the end slot being excluded
+        let events = db.get_filtered_sc_output_events(
+            &EventFilter {
+                emitter_address: Some(addr),
+                start: Some(Slot::new(1, 1)),
+                end: Some(Slot::new(2, 1)),
+                ..Default::default()
+            },
+            100,
+        );
+        let slots: Vec<Slot> = events.iter().map(|event| event.context.slot).collect();
+        assert_eq!(
+            slots,
+            vec![
+                Slot::new(1, 1),
+                Slot::new(1, 1),
+                Slot::new(2, 0),
+                Slot::new(2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_final_events_db_reapply_slot() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = FinalEventsDB::new(temp_dir.path().to_path_buf(), 2, None, None);
+        let addr1 = Address::User(UserAddress(Hash::compute_from("AU1".as_bytes())));
+        let addr2 = Address::User(UserAddress(Hash::compute_from("AU2".as_bytes())));
+
+        let slot = Slot::new(1, 0);
+        let events = VecDeque::from(vec![
+            make_event(slot, 0, vec![addr1], None),
+            make_event(slot, 1, vec![addr1], None),
+        ]);
+        db.apply_events(&events, slot);
+        let size = db.get_size();
+        assert!(size > 0);
+
+        // applying the same slot again, e.g. after a restart, does not count its events twice
+        db.apply_events(&events, slot);
+        assert_eq!(db.get_size(), size);
+        drop(db);
+        let mut db = FinalEventsDB::new(temp_dir.path().to_path_buf(), 2, None, None);
+        assert_eq!(db.get_size(), size);
+
+        // a replaced event loses its previous index entries
+        db.apply_events(
+            &VecDeque::from(vec![make_event(slot, 1, vec![addr2], None)]),
+            slot,
+        );
+        assert_eq!(db.get_size(), size);
+        let by_emitter = |addr| {
+            db.get_filtered_sc_output_events(
+                &EventFilter {
+                    emitter_address: Some(addr),
+                    ..Default::default()
+                },
+                100,
+            )
+        };
+        assert_eq!(by_emitter(addr1).len(), 1);
+        assert_eq!(by_emitter(addr2).len(), 1);
+    }
+
+    #[test]
+    fn test_final_events_db_prune_by_slots() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut db = FinalEventsDB::new(temp_dir.path().to_path_buf(), 2, Some(3), None);
+        let addr = Address::User(UserAddress(Hash::compute_from("AU1".as_bytes())));
+
+        let mut slot = Slot::new(1, 0);
+        for _ in 0..6 {
+            db.apply_events(
+                &VecDeque::from(vec![make_event(slot, 0, vec![addr], None)]),
+                slot,
+            );
+            slot = slot.get_next_slot(2).unwrap();
+        }
+
+        // only the events of the 3 latest slots are kept, indexes included
+        let events = db.get_filtered_sc_output_events(
+            &EventFilter {
+                emitter_address: Some(addr),
+                ..Default::default()
+            },
+            100,
+        );
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].context.slot, Slot::new(2, 1));
+    }
+}
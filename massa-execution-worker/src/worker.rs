@@ -10,7 +10,7 @@ use crate::execution::ExecutionState;
 use crate::request_queue::RequestQueue;
 use crate::slot_sequencer::SlotSequencer;
 use massa_execution_exports::{
-    ExecutionConfig, ExecutionController, ExecutionError, ExecutionManager,
+    ExecutionChannels, ExecutionConfig, ExecutionController, ExecutionError, ExecutionManager,
     ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
 };
 use massa_final_state::FinalState;
@@ -23,7 +23,7 @@ use massa_versioning_worker::versioning::MipStore;
 use parking_lot::{Condvar, Mutex, RwLock};
 use std::sync::Arc;
 use std::thread;
-use tracing::debug;
+use tracing::{debug, field, info_span};
 
 /// Structure gathering all elements needed by the execution thread
 pub(crate) struct ExecutionThread {
@@ -178,6 +178,9 @@ impl ExecutionThread {
             // update the sequence of read-only requests
             self.update_readonly_requests(input_data.readonly_requests);
 
+            // the loop wakes up at least once per slot, which keeps the slot lag up to date
+            self.execution_state.read().update_metrics();
+
             if stop {
                 // we need to stop
                 break;
@@ -193,6 +196,16 @@ impl ExecutionThread {
             // ask the slot sequencer for a task to be executed in priority (final is higher priority than candidate)
             let run_result = self.slot_sequencer.run_task_with(
                 |is_final: bool, slot: &Slot, content: Option<&(BlockId, Storage)>| {
+                    let span = info_span!(
+                        "execute_slot",
+                        %slot,
+                        is_final,
+                        block_id = field::Empty
+                    );
+                    if let Some((block_id, _)) = content {
+                        span.record("block_id", field::display(block_id));
+                    }
+                    let _entered = span.entered();
                     if is_final {
                         self.execution_state.write().execute_final_slot(
                             slot,
@@ -237,6 +250,9 @@ impl ExecutionThread {
 /// # parameters
 /// * `config`: execution configuration
 /// * `final_state`: a thread-safe shared access to the final state for reading and writing
+/// * `selector`: access to the PoS selector
+/// * `mip_store`: versioning store
+/// * `channels`: channels used to broadcast execution outputs
 ///
 /// # Returns
 /// A pair `(execution_manager, execution_controller)` where:
@@ -247,12 +263,14 @@ pub fn start_execution_worker(
     final_state: Arc<RwLock<FinalState>>,
     selector: Box<dyn SelectorController>,
     mip_store: MipStore,
+    channels: ExecutionChannels,
 ) -> (Box<dyn ExecutionManager>, Box<dyn ExecutionController>) {
     // create an execution state
     let execution_state = Arc::new(RwLock::new(ExecutionState::new(
         config.clone(),
         final_state,
         mip_store,
+        channels,
     )));
 
     // define the input data interface
@@ -263,6 +281,7 @@ pub fn start_execution_worker(
 
     // create a controller
     let controller = ExecutionControllerImpl {
+        config: config.clone(),
         input_data: input_data.clone(),
         execution_state: execution_state.clone(),
     };
@@ -3,9 +3,10 @@
 use massa_factory_exports::{FactoryChannels, FactoryConfig};
 use massa_hash::Hash;
 use massa_models::{
-    block::{Block, BlockSerializer},
+    block::{Block, BlockSerializer, SecureShareBlock},
     block_header::{BlockHeader, BlockHeaderSerializer, SecuredHeader},
     block_id::BlockId,
+    config::DENUNCIATIONS_BLOCK_COMPONENT_VERSION,
     endorsement::SecureShareEndorsement,
     prehash::PreHashSet,
     secure_share::SecureShareContent,
@@ -13,8 +14,9 @@ use massa_models::{
     timeslots::{get_block_slot_timestamp, get_closest_slot_to_timestamp},
 };
 use massa_time::MassaTime;
-use massa_wallet::Wallet;
-use parking_lot::RwLock;
+use massa_versioning_worker::versioning::{MipComponent, MipStore};
+use massa_wallet::{Signer, SigningContext, SlashingProtection, WalletError};
+use parking_lot::Mutex;
 use std::{
     sync::{mpsc, Arc},
     thread,
@@ -25,8 +27,10 @@ use tracing::{debug, info, warn};
 /// Structure gathering all elements needed by the factory thread
 pub(crate) struct BlockFactoryWorker {
     cfg: FactoryConfig,
-    wallet: Arc<RwLock<Wallet>>,
+    signer: Arc<dyn Signer>,
+    slashing_protection: Arc<Mutex<SlashingProtection>>,
     channels: FactoryChannels,
+    mip_store: MipStore,
     factory_receiver: mpsc::Receiver<()>,
 }
 
@@ -35,8 +39,10 @@ impl BlockFactoryWorker {
     /// needed by the factory worker thread.
     pub(crate) fn spawn(
         cfg: FactoryConfig,
-        wallet: Arc<RwLock<Wallet>>,
+        signer: Arc<dyn Signer>,
+        slashing_protection: Arc<Mutex<SlashingProtection>>,
         channels: FactoryChannels,
+        mip_store: MipStore,
         factory_receiver: mpsc::Receiver<()>,
     ) -> thread::JoinHandle<()> {
         thread::Builder::new()
@@ -44,8 +50,10 @@ impl BlockFactoryWorker {
             .spawn(|| {
                 let mut this = Self {
                     cfg,
-                    wallet,
+                    signer,
+                    slashing_protection,
                     channels,
+                    mip_store,
                     factory_receiver,
                 };
                 this.run();
@@ -137,16 +145,19 @@ impl BlockFactoryWorker {
             slot, block_producer_addr
         );
 
-        // check if the block producer address is handled by the wallet
-        let block_producer_keypair_ref = self.wallet.read();
-        let block_producer_keypair = if let Some(kp) =
-            block_producer_keypair_ref.find_associated_keypair(&block_producer_addr)
-        {
+        // check if the block producer address is handled by the signer
+        let block_producer_public_key = match self.signer.get_public_key(&block_producer_addr) {
             // the selected block producer is managed locally => continue to attempt block production
-            kp
-        } else {
+            Ok(Some(public_key)) => public_key,
             // the selected block producer is not managed locally => quit
-            return;
+            Ok(None) => return,
+            Err(err) => {
+                warn!(
+                    "block factory could not get the public key of {} for slot {}: {}",
+                    block_producer_addr, slot, err
+                );
+                return;
+            }
         };
         // get best parents and their periods
         let parents: Vec<(BlockId, u64)> = self.channels.consensus.get_best_parents(); // Vec<(parent_id, parent_period)>
@@ -209,18 +220,64 @@ impl BlockFactoryWorker {
                 .collect::<Vec<u8>>(),
         );
 
+        // gather denunciations, once the block version that supports them is active
+        let slot_timestamp = match get_block_slot_timestamp(
+            self.cfg.thread_count,
+            self.cfg.t0,
+            self.cfg.genesis_timestamp,
+            slot,
+        ) {
+            Ok(timestamp) => timestamp,
+            Err(err) => {
+                warn!(
+                    "block factory could not get the timestamp of slot {}: {}",
+                    slot, err
+                );
+                return;
+            }
+        };
+        let denunciations = if self
+            .mip_store
+            .get_component_version_at(&MipComponent::Block, slot_timestamp)
+            >= DENUNCIATIONS_BLOCK_COMPONENT_VERSION
+        {
+            self.channels.pool.get_block_denunciations(&slot)
+        } else {
+            Vec::new()
+        };
+
         // create header
-        let header: SecuredHeader = BlockHeader::new_verifiable::<BlockHeaderSerializer, BlockId>(
+        let header: Result<SecuredHeader, WalletError> = BlockHeader::new_verifiable_with_signer(
             BlockHeader {
                 slot,
                 parents: parents.into_iter().map(|(id, _period)| id).collect(),
                 operation_merkle_root: global_operations_hash,
                 endorsements,
+                denunciations,
             },
             BlockHeaderSerializer::new(), // TODO reuse self.block_header_serializer
-            block_producer_keypair,
-        )
-        .expect("error while producing block header");
+            block_producer_public_key,
+            |hash| {
+                // never sign two different headers for the same slot, even across restarts
+                let context = SigningContext::BlockHeader { slot };
+                self.slashing_protection.lock().check_and_record(
+                    &block_producer_addr,
+                    hash,
+                    &context,
+                )?;
+                self.signer.sign(&block_producer_addr, hash, &context)
+            },
+        );
+        let header = match header {
+            Ok(header) => header,
+            Err(err) => {
+                warn!(
+                    "block factory could not sign the block header at slot {}: {}",
+                    slot, err
+                );
+                return;
+            }
+        };
 
         // create block
         let block_ = Block {
@@ -228,12 +285,25 @@ impl BlockFactoryWorker {
             operations: op_ids.into_iter().collect(),
         };
 
-        let block = Block::new_verifiable(
+        let block: Result<SecureShareBlock, WalletError> = Block::new_verifiable_with_signer(
             block_,
             BlockSerializer::new(), // TODO reuse self.block_serializer
-            block_producer_keypair,
-        )
-        .expect("error while producing block");
+            block_producer_public_key,
+            |hash| {
+                self.signer
+                    .sign(&block_producer_addr, hash, &SigningContext::Block { slot })
+            },
+        );
+        let block = match block {
+            Ok(block) => block,
+            Err(err) => {
+                warn!(
+                    "block factory could not sign the block at slot {}: {}",
+                    slot, err
+                );
+                return;
+            }
+        };
         let block_id = block.id;
         // store block in storage
         block_storage.store_block(block);
@@ -1,6 +1,7 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use massa_factory_exports::{FactoryChannels, FactoryConfig};
+use massa_models::address::Address;
 use massa_models::{
     block_id::BlockId,
     endorsement::{Endorsement, EndorsementSerializer, SecureShareEndorsement},
@@ -8,10 +9,10 @@ use massa_models::{
     slot::Slot,
     timeslots::{get_block_slot_timestamp, get_closest_slot_to_timestamp},
 };
-use massa_signature::KeyPair;
+use massa_signature::PublicKey;
 use massa_time::MassaTime;
-use massa_wallet::Wallet;
-use parking_lot::RwLock;
+use massa_wallet::{Signer, SigningContext, SlashingProtection, WalletError};
+use parking_lot::Mutex;
 use std::{
     sync::{mpsc, Arc},
     thread,
@@ -22,7 +23,8 @@ use tracing::{debug, warn};
 /// Structure gathering all elements needed by the factory thread
 pub(crate) struct EndorsementFactoryWorker {
     cfg: FactoryConfig,
-    wallet: Arc<RwLock<Wallet>>,
+    signer: Arc<dyn Signer>,
+    slashing_protection: Arc<Mutex<SlashingProtection>>,
     channels: FactoryChannels,
     factory_receiver: mpsc::Receiver<()>,
     half_t0: MassaTime,
@@ -34,7 +36,8 @@ impl EndorsementFactoryWorker {
     /// needed by the factory worker thread.
     pub(crate) fn spawn(
         cfg: FactoryConfig,
-        wallet: Arc<RwLock<Wallet>>,
+        signer: Arc<dyn Signer>,
+        slashing_protection: Arc<Mutex<SlashingProtection>>,
         channels: FactoryChannels,
         factory_receiver: mpsc::Receiver<()>,
     ) -> thread::JoinHandle<()> {
@@ -47,7 +50,8 @@ impl EndorsementFactoryWorker {
                         .checked_div_u64(2)
                         .expect("could not compute half_t0"),
                     cfg,
-                    wallet,
+                    signer,
+                    slashing_protection,
                     channels,
                     factory_receiver,
                     endorsement_serializer: EndorsementSerializer::new(),
@@ -137,22 +141,24 @@ impl EndorsementFactoryWorker {
             }
         };
 
-        // get creators if they are managed by our wallet
-        let mut producers_indices: Vec<(KeyPair, usize)> = Vec::new();
-        {
-            let wallet = self.wallet.read();
-            for (index, producer_addr) in producer_addrs.into_iter().enumerate() {
-                // check if the block producer address is handled by the wallet
-                let producer_keypair =
-                    if let Some(kp) = wallet.find_associated_keypair(&producer_addr) {
-                        // the selected block producer is managed locally => continue to attempt endorsement production
-                        kp.clone()
-                    } else {
-                        // the selected block producer is not managed locally => continue
-                        continue;
-                    };
-                producers_indices.push((producer_keypair, index));
-            }
+        // get creators if they are managed by our signer
+        let mut producers_indices: Vec<(Address, PublicKey, usize)> = Vec::new();
+        for (index, producer_addr) in producer_addrs.into_iter().enumerate() {
+            // check if the block producer address is handled by the signer
+            let producer_public_key = match self.signer.get_public_key(&producer_addr) {
+                // the selected block producer is managed locally => continue to attempt endorsement production
+                Ok(Some(public_key)) => public_key,
+                // the selected block producer is not managed locally => continue
+                Ok(None) => continue,
+                Err(err) => {
+                    warn!(
+                        "endorsement factory could not get the public key of {} for slot {}: {}",
+                        producer_addr, slot, err
+                    );
+                    continue;
+                }
+            };
+            producers_indices.push((producer_addr, producer_public_key, index));
         }
 
         // quit if there is nothing to produce
@@ -169,17 +175,38 @@ impl EndorsementFactoryWorker {
         // produce endorsements
         let mut endorsements: Vec<SecureShareEndorsement> =
             Vec::with_capacity(producers_indices.len());
-        for (keypair, index) in producers_indices {
-            let endorsement = Endorsement::new_verifiable(
-                Endorsement {
-                    slot,
-                    index: index as u32,
-                    endorsed_block,
-                },
-                self.endorsement_serializer.clone(),
-                &keypair,
-            )
-            .expect("could not create endorsement");
+        for (producer_addr, public_key, index) in producers_indices {
+            let index = index as u32;
+            let endorsement: Result<SecureShareEndorsement, WalletError> =
+                Endorsement::new_verifiable_with_signer(
+                    Endorsement {
+                        slot,
+                        index,
+                        endorsed_block,
+                    },
+                    self.endorsement_serializer.clone(),
+                    public_key,
+                    |hash| {
+                        // never sign two different endorsements for the same slot and index, even across restarts
+                        let context = SigningContext::Endorsement { slot, index };
+                        self.slashing_protection.lock().check_and_record(
+                            &producer_addr,
+                            hash,
+                            &context,
+                        )?;
+                        self.signer.sign(&producer_addr, hash, &context)
+                    },
+                );
+            let endorsement = match endorsement {
+                Ok(endorsement) => endorsement,
+                Err(err) => {
+                    warn!(
+                        "endorsement factory could not sign the endorsement {} at slot {}: {}",
+                        index, slot, err
+                    );
+                    continue;
+                }
+            };
 
             // log endorsement creation
             debug!(
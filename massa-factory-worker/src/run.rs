@@ -1,6 +1,5 @@
 //! Copyright (c) 2022 MASSA LABS <info@massa.net>
 
-use parking_lot::RwLock;
 use std::sync::{mpsc, Arc};
 
 use crossbeam_channel::{unbounded, Receiver};
@@ -12,21 +11,27 @@ use crate::{
 };
 use massa_factory_exports::{FactoryChannels, FactoryConfig, FactoryManager};
 use massa_models::denunciation::DenunciationPrecursor;
-use massa_wallet::Wallet;
+use massa_versioning_worker::versioning::MipStore;
+use massa_wallet::{Signer, SlashingProtection};
+use parking_lot::Mutex;
 
 /// Start factory
 ///
 /// # Arguments
 /// * `cfg`: factory configuration
-/// * `wallet`: atomic reference to the node wallet
+/// * `signer`: signer of the blocks and endorsements produced by the node
+/// * `slashing_protection`: records of the signed blocks and endorsements, checked before signing
 /// * `channels`: channels to communicate with other modules
+/// * `mip_store`: versioning store, telling which block version to produce
 ///
 /// # Return value
 /// Returns a factory manager allowing to stop the workers cleanly.
 pub fn start_factory(
     cfg: FactoryConfig,
-    wallet: Arc<RwLock<Wallet>>,
+    signer: Arc<dyn Signer>,
+    slashing_protection: Arc<Mutex<SlashingProtection>>,
     channels: FactoryChannels,
+    mip_store: MipStore,
     denunciation_factory_consensus_receiver: Receiver<DenunciationPrecursor>,
     denunciation_factory_endorsement_pool_receiver: Receiver<DenunciationPrecursor>,
 ) -> Box<dyn FactoryManager> {
@@ -42,15 +47,18 @@ pub fn start_factory(
     // start block factory worker
     let block_worker_handle = BlockFactoryWorker::spawn(
         cfg.clone(),
-        wallet.clone(),
+        signer.clone(),
+        slashing_protection.clone(),
         channels.clone(),
+        mip_store,
         block_worker_rx,
     );
 
     // start endorsement factory worker
     let endorsement_worker_handle = EndorsementFactoryWorker::spawn(
         cfg.clone(),
-        wallet,
+        signer,
+        slashing_protection,
         channels.clone(),
         endorsement_worker_rx,
     );
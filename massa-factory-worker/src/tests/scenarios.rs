@@ -1,5 +1,6 @@
 use super::TestFactory;
 use massa_models::address::Address;
+use massa_models::config::DENUNCIATIONS_BLOCK_COMPONENT_VERSION;
 use massa_models::denunciation::{Denunciation, DenunciationPrecursor};
 use massa_models::test_exports::{
     gen_block_headers_for_denunciation, gen_endorsements_for_denunciation,
@@ -10,6 +11,9 @@ use massa_models::{
     secure_share::SecureShareContent,
 };
 use massa_signature::KeyPair;
+use massa_versioning_worker::{
+    test_helpers::versioning_helpers::get_mip_store_with_active_component, versioning::MipComponent,
+};
 use std::str::FromStr;
 
 /// Creates a basic empty block with the factory.
@@ -18,7 +22,7 @@ use std::str::FromStr;
 fn basic_creation() {
     let keypair = KeyPair::generate();
     let mut test_factory = TestFactory::new(&keypair);
-    let (block_id, storage) = test_factory.get_next_created_block(None, None);
+    let (block_id, storage) = test_factory.get_next_created_block(None, None, None);
     assert_eq!(block_id, storage.read_blocks().get(&block_id).unwrap().id);
 }
 
@@ -36,7 +40,8 @@ fn basic_creation_with_operation() {
     };
     let operation =
         Operation::new_verifiable(content, OperationSerializer::new(), &keypair).unwrap();
-    let (block_id, storage) = test_factory.get_next_created_block(Some(vec![operation]), None);
+    let (block_id, storage) =
+        test_factory.get_next_created_block(Some(vec![operation]), None, None);
 
     let block = storage.read_blocks().get(&block_id).unwrap().clone();
     for op_id in block.content.operations.iter() {
@@ -60,7 +65,7 @@ fn basic_creation_with_multiple_operations() {
     let operation =
         Operation::new_verifiable(content, OperationSerializer::new(), &keypair).unwrap();
     let (block_id, storage) =
-        test_factory.get_next_created_block(Some(vec![operation.clone(), operation]), None);
+        test_factory.get_next_created_block(Some(vec![operation.clone(), operation]), None, None);
 
     let block = storage.read_blocks().get(&block_id).unwrap().clone();
     for op_id in block.content.operations.iter() {
@@ -69,6 +74,29 @@ fn basic_creation_with_multiple_operations() {
     assert_eq!(block.content.operations.len(), 2);
 }
 
+/// Creates a block with the denunciations of the pool in its header, once they are active.
+#[test]
+fn basic_creation_with_denunciation() {
+    let keypair = KeyPair::generate();
+    let mip_store = get_mip_store_with_active_component(
+        MipComponent::Block,
+        DENUNCIATIONS_BLOCK_COMPONENT_VERSION,
+    );
+    let mut test_factory = TestFactory::new_with_mip_store(&keypair, mip_store);
+
+    let (_slot, _keypair, secured_header_1, secured_header_2, _secured_header_3) =
+        gen_block_headers_for_denunciation();
+    let denunciation = Denunciation::try_from((&secured_header_1, &secured_header_2)).unwrap();
+    let (block_id, storage) =
+        test_factory.get_next_created_block(None, None, Some(vec![denunciation.clone()]));
+
+    let block = storage.read_blocks().get(&block_id).unwrap().clone();
+    assert_eq!(
+        block.content.header.content.denunciations,
+        vec![denunciation]
+    );
+}
+
 /// Send some block headers and check if 1 (and only 1) Denunciation is produced
 #[test]
 fn test_denunciation_factory_block_header_denunciation() {
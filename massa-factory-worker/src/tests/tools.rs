@@ -3,7 +3,7 @@ use crossbeam_channel::Sender;
 use massa_consensus_exports::test_exports::{
     ConsensusEventReceiver, MockConsensusController, MockConsensusControllerMessage,
 };
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::{sync::Arc, thread::sleep, time::Duration};
 
 use massa_factory_exports::{
@@ -11,9 +11,14 @@ use massa_factory_exports::{
 };
 use massa_models::denunciation::{Denunciation, DenunciationPrecursor};
 use massa_models::{
-    address::Address, block_id::BlockId, config::ENDORSEMENT_COUNT,
-    endorsement::SecureShareEndorsement, operation::SecureShareOperation, prehash::PreHashMap,
-    slot::Slot, test_exports::get_next_slot_instant,
+    address::Address,
+    block_id::BlockId,
+    config::{ENDORSEMENT_COUNT, MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX},
+    endorsement::SecureShareEndorsement,
+    operation::SecureShareOperation,
+    prehash::PreHashMap,
+    slot::Slot,
+    test_exports::get_next_slot_instant,
 };
 use massa_pool_exports::test_exports::{
     MockPoolController, MockPoolControllerMessage, PoolEventReceiver,
@@ -26,9 +31,10 @@ use massa_protocol_exports::test_exports::MockProtocolController;
 use massa_signature::KeyPair;
 use massa_storage::Storage;
 use massa_time::MassaTime;
+use massa_versioning_worker::versioning::{MipStatsConfig, MipStore};
 
 use crate::start_factory;
-use massa_wallet::test_exports::create_test_wallet;
+use massa_wallet::{test_exports::create_test_wallet, LocalSigner, SlashingProtection};
 
 /// This structure store all information and links to creates tests for the factory.
 /// The factory will ask that to the the pool, consensus and factory and then will send the block to the consensus.
@@ -56,6 +62,16 @@ impl TestFactory {
     /// Returns
     /// - `TestFactory`: the structure that will be used to manage the tests
     pub fn new(default_keypair: &KeyPair) -> TestFactory {
+        let mip_stats_config = MipStatsConfig {
+            block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+            counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+        };
+        let mip_store = MipStore::try_from(([], mip_stats_config)).unwrap();
+        TestFactory::new_with_mip_store(default_keypair, mip_store)
+    }
+
+    /// Initialize a new factory and all mocks, the factory produces the block versions active in `mip_store`
+    pub fn new_with_mip_store(default_keypair: &KeyPair, mip_store: MipStore) -> TestFactory {
         let (selector_controller, selector_receiver) = MockSelectorController::new_with_receiver();
         let (consensus_controller, consensus_event_receiver) =
             MockConsensusController::new_with_receiver();
@@ -86,7 +102,10 @@ impl TestFactory {
             .unwrap();
         let factory_manager = start_factory(
             factory_config.clone(),
-            Arc::new(RwLock::new(create_test_wallet(Some(accounts)))),
+            Arc::new(LocalSigner::new(Arc::new(RwLock::new(create_test_wallet(
+                Some(accounts),
+            ))))),
+            Arc::new(Mutex::new(SlashingProtection::default())),
             FactoryChannels {
                 selector: selector_controller.clone(),
                 consensus: consensus_controller,
@@ -94,6 +113,7 @@ impl TestFactory {
                 protocol: protocol_command_sender,
                 storage: storage.clone_without_refs(),
             },
+            mip_store,
             denunciation_factory_receiver,
             denunciation_factory_rx,
         );
@@ -118,10 +138,12 @@ impl TestFactory {
     /// Arguments:
     /// - `operations`: Optional list of operations to include in the block
     /// - `endorsements`: Optional list of endorsements to include in the block
+    /// - `denunciations`: Optional list of denunciations to include in the block, only asked to the pool once they are active
     pub fn get_next_created_block(
         &mut self,
         operations: Option<Vec<SecureShareOperation>>,
         endorsements: Option<Vec<SecureShareEndorsement>>,
+        denunciations: Option<Vec<Denunciation>>,
     ) -> (BlockId, Storage) {
         let now = MassaTime::now().expect("could not get current time");
         let next_slot_instant = get_next_slot_instant(
@@ -219,6 +241,21 @@ impl TestFactory {
             })
             .unwrap();
 
+        if let Some(denunciations) = denunciations {
+            self.pool_receiver
+                .wait_command(MassaTime::from_millis(100), |command| match command {
+                    MockPoolControllerMessage::GetBlockDenunciations {
+                        slot: _,
+                        response_tx,
+                    } => {
+                        response_tx.send(denunciations.clone()).unwrap();
+                        Some(())
+                    }
+                    _ => panic!("unexpected message"),
+                })
+                .unwrap();
+        }
+
         if let Some(consensus_event_receiver) = self.consensus_event_receiver.as_mut() {
             consensus_event_receiver
                 .wait_command(MassaTime::from_millis(100), |command| {
@@ -10,7 +10,9 @@ use massa_async_pool::{
     AsyncMessage, AsyncMessageId, AsyncPool, AsyncPoolChanges, AsyncPoolDeserializer,
     AsyncPoolSerializer, Change,
 };
-use massa_executed_ops::{ExecutedOps, ExecutedOpsDeserializer, ExecutedOpsSerializer};
+use massa_executed_ops::{
+    ExecutedDenunciations, ExecutedOps, ExecutedOpsDeserializer, ExecutedOpsSerializer,
+};
 use massa_hash::{Hash, HashDeserializer, HASH_SIZE_BYTES};
 use massa_ledger_exports::{Key as LedgerKey, LedgerChanges, LedgerController};
 use massa_models::{
@@ -30,12 +32,14 @@ use massa_pos_exports::{
     DeferredCreditsDeserializer, DeferredCreditsSerializer, PoSFinalState, SelectorController,
 };
 use massa_serialization::{Deserializer, SerializeError, Serializer};
+use massa_versioning_worker::versioning::MipStoreRaw;
 use nom::{error::context, sequence::tuple, IResult, Parser};
 use std::collections::{BTreeMap, VecDeque};
 use std::ops::Bound::{Excluded, Included};
+use std::path::Path;
 use tracing::{debug, info};
 
-/// Represents a final state `(ledger, async pool, executed_ops and the state of the PoS)`
+/// Represents a final state `(ledger, async pool, executed_ops, executed_de and the state of the PoS)`
 pub struct FinalState {
     /// execution state configuration
     pub(crate) config: FinalStateConfig,
@@ -49,11 +53,16 @@ pub struct FinalState {
     pub pos_state: PoSFinalState,
     /// executed operations
     pub executed_ops: ExecutedOps,
+    /// executed denunciations
+    pub executed_denunciations: ExecutedDenunciations,
     /// history of recent final state changes, useful for streaming bootstrap
     /// `front = oldest`, `back = newest`
     pub changes_history: VecDeque<(Slot, StateChanges)>,
     /// hash of the final state, it is computed on finality
     pub final_state_hash: Hash,
+    /// history of recent final state hashes, useful to cross-check a bootstrap between servers
+    /// `front = oldest`, `back = newest`
+    pub final_state_hash_history: VecDeque<(Slot, Hash)>,
     /// last_start_period
     /// * If start all new network: set to 0
     /// * If from snapshot: retrieve from args
@@ -94,6 +103,10 @@ impl FinalState {
         // create a default executed ops
         let executed_ops = ExecutedOps::new(config.executed_ops_config.clone());
 
+        // create a default executed denunciations
+        let executed_denunciations =
+            ExecutedDenunciations::new(config.executed_denunciations_config.clone());
+
         // create the final state
         Ok(FinalState {
             slot,
@@ -102,54 +115,69 @@ impl FinalState {
             pos_state,
             config,
             executed_ops,
+            executed_denunciations,
             changes_history: Default::default(), // no changes in history
             final_state_hash: Hash::from_bytes(FINAL_STATE_HASH_INITIAL_BYTES),
+            final_state_hash_history: Default::default(),
             last_start_period: 0,
         })
     }
 
-    /// Initializes a `FinalState` from a snapshot. Currently, we do not use the final_state from the ledger,
-    /// we just create a new one. This will be changed in the follow-up.
+    /// Initializes a `FinalState` from a snapshot.
+    ///
+    /// If a snapshot file is provided, the final state and the MIP store are restored from it.
+    /// Otherwise, the ledger kept on disk is reused and the PoS cycles are re-created from the initial rolls.
     ///
     /// # Arguments
     /// * `config`: the configuration of the final state to use for initialization
     /// * `ledger`: the instance of the ledger on disk. Used to apply changes to the ledger.
     /// * `selector`: the pos selector. Used to send draw inputs when a new cycle is completed.
     /// * `last_start_period`: at what period we should attach the final_state
+    /// * `snapshot_path`: optional path to a snapshot file created with `export_snapshot`
+    ///
+    /// # Returns
+    /// The final state, and the MIP store restored from the snapshot file if one was provided
     pub fn new_derived_from_snapshot(
         config: FinalStateConfig,
         ledger: Box<dyn LedgerController>,
         selector: Box<dyn SelectorController>,
         last_start_period: u64,
-    ) -> Result<Self, FinalStateError> {
+        snapshot_path: Option<&Path>,
+    ) -> Result<(Self, Option<MipStoreRaw>), FinalStateError> {
         info!("Restarting from snapshot");
 
         // FIRST, we recover the last known final_state
         let mut final_state = FinalState::new(config, ledger, selector)?;
-        let _final_state_hash_from_snapshot = Hash::from_bytes(FINAL_STATE_HASH_INITIAL_BYTES);
-        final_state.pos_state.create_initial_cycle();
+        let (current_slot, mip_store) = if let Some(snapshot_path) = snapshot_path {
+            let mip_store = final_state.import_snapshot(snapshot_path)?;
+            if final_state.slot.period > last_start_period {
+                return Err(FinalStateError::InvalidSlot(format!(
+                    "snapshot slot {} is after the last start period {}",
+                    final_state.slot, last_start_period
+                )));
+            }
+            (final_state.slot, Some(mip_store))
+        } else {
+            final_state.pos_state.create_initial_cycle();
 
-        // TODO: We recover the final_state from the RocksDB instance instead
-        /*let final_state_data = ledger
-        .get_final_state()
-        .expect("Cannot retrieve ledger final_state data");*/
+            final_state.slot = final_state.ledger.get_slot().map_err(|_| {
+                FinalStateError::InvalidSlot(String::from("Could not recover Slot in Ledger"))
+            })?;
 
-        final_state.slot = final_state.ledger.get_slot().map_err(|_| {
-            FinalStateError::InvalidSlot(String::from("Could not recover Slot in Ledger"))
-        })?;
+            debug!(
+                "Latest consistent slot found in snapshot data: {}",
+                final_state.slot
+            );
 
-        debug!(
-            "Latest consistent slot found in snapshot data: {}",
-            final_state.slot
-        );
+            final_state.compute_state_hash_at_slot(final_state.slot);
 
-        final_state.compute_state_hash_at_slot(final_state.slot);
-
-        // Check the hash to see if we correctly recovered the snapshot
-        // TODO: Redo this check when we get the final_state from the ledger
-        /*if final_state.final_state_hash != final_state_hash_from_snapshot {
-            warn!("The hash of the final_state recovered from the snapshot is different from the hash saved.");
-        }*/
+            // the PoS cycles were re-created from the initial rolls,
+            // so the downtime is interpolated from the last genesis slot
+            (
+                Slot::new(0, final_state.config.thread_count.saturating_sub(1)),
+                None,
+            )
+        };
 
         // Then, interpolate the downtime, to attach at end_slot;
         final_state.last_start_period = last_start_period;
@@ -159,9 +187,9 @@ impl FinalState {
         // We compute the draws here because we need to feed_cycles when interpolating
         final_state.compute_initial_draws()?;
 
-        final_state.interpolate_downtime()?;
+        final_state.interpolate_downtime(current_slot)?;
 
-        Ok(final_state)
+        Ok((final_state, mip_store))
     }
 
     /// Used after bootstrap, to set the initial ledger hash (used in initial draws)
@@ -181,10 +209,10 @@ impl FinalState {
 
     /// Once we created a FinalState from a snapshot, we need to edit it to attach at the end_slot and handle the downtime.
     /// This basically recreates the history of the final_state, without executing the slots.
-    fn interpolate_downtime(&mut self) -> Result<(), FinalStateError> {
-        // TODO: Change the current_slot when we deserialize the final state from RocksDB. Until then, final_state slot and the ledger slot are not consistent!
-        // let current_slot = self.slot;
-        let current_slot = Slot::new(0, self.config.thread_count.saturating_sub(1));
+    ///
+    /// # Arguments
+    /// * `current_slot`: the slot at which the PoS state of the final state is attached
+    fn interpolate_downtime(&mut self, current_slot: Slot) -> Result<(), FinalStateError> {
         let current_slot_cycle = current_slot.get_cycle(self.config.periods_per_cycle);
 
         let end_slot = Slot::new(
@@ -373,9 +401,11 @@ impl FinalState {
         self.async_pool.reset();
         self.pos_state.reset();
         self.executed_ops.reset();
+        self.executed_denunciations.reset();
         self.changes_history.clear();
         // reset the final state hash
         self.final_state_hash = Hash::from_bytes(FINAL_STATE_HASH_INITIAL_BYTES);
+        self.final_state_hash_history.clear();
     }
 
     /// Compute the current state hash.
@@ -383,35 +413,52 @@ impl FinalState {
     /// Used when finalizing a slot.
     /// Slot information is only used for logging.
     pub fn compute_state_hash_at_slot(&mut self, slot: Slot) {
-        // 1. init hash concatenation with the ledger hash
-        let ledger_hash = self.ledger.get_ledger_hash();
-        let mut hash_concat: Vec<u8> = ledger_hash.to_bytes().to_vec();
+        // compute and save final state hash
+        let hash_concat: Vec<u8> = self
+            .get_state_hash_components()
+            .iter()
+            .flat_map(|hash| *hash.to_bytes())
+            .collect();
+        self.final_state_hash = Hash::compute_from(&hash_concat);
+
+        info!(
+            "final_state hash at slot {}: {}",
+            slot, self.final_state_hash
+        );
+    }
+
+    /// Get the final state hash at a recent slot, if it is still in the history.
+    ///
+    /// Used by the bootstrap to cross-check the state assembled from several servers.
+    pub fn get_final_state_hash_at_slot(&self, slot: Slot) -> Option<Hash> {
+        if slot == self.slot {
+            return Some(self.final_state_hash);
+        }
+        self.final_state_hash_history
+            .iter()
+            .find(|(history_slot, _)| *history_slot == slot)
+            .map(|(_, hash)| *hash)
+    }
+
+    /// Get the hashes committed by the final state hash, in order
+    pub fn get_state_hash_components(&self) -> Vec<Hash> {
+        // 1. ledger hash
+        let mut components = vec![self.ledger.get_ledger_hash()];
         // 2. async_pool hash
-        hash_concat.extend(self.async_pool.hash.to_bytes());
+        components.push(self.async_pool.hash);
         // 3. pos deferred_credit hash
-        let deferred_credit_hash = match self.pos_state.deferred_credits.get_hash() {
-            Some(hash) => hash,
-            None => self
-                .pos_state
-                .deferred_credits
-                .enable_hash_tracker_and_compute_hash(),
-        };
-        hash_concat.extend(deferred_credit_hash.to_bytes());
+        components.push(self.pos_state.deferred_credits.get_or_compute_hash());
         // 4. pos cycle history hashes, skip the bootstrap safety cycle if there is one
         let n = (self.pos_state.cycle_history.len() == self.config.pos_config.cycle_history_length)
             as usize;
         for cycle_info in self.pos_state.cycle_history.iter().skip(n) {
-            hash_concat.extend(cycle_info.cycle_global_hash.to_bytes());
+            components.push(cycle_info.cycle_global_hash);
         }
         // 5. executed operations hash
-        hash_concat.extend(self.executed_ops.hash.to_bytes());
-        // 6. compute and save final state hash
-        self.final_state_hash = Hash::compute_from(&hash_concat);
-
-        info!(
-            "final_state hash at slot {}: {}",
-            slot, self.final_state_hash
-        );
+        components.push(self.executed_ops.hash);
+        // 6. executed denunciations hash
+        components.push(self.executed_denunciations.hash);
+        components
     }
 
     /// Performs the initial draws.
@@ -449,6 +496,8 @@ impl FinalState {
         // bootstrap again instead
         self.executed_ops
             .apply_changes(changes.executed_ops_changes.clone(), self.slot);
+        self.executed_denunciations
+            .apply_changes(changes.executed_denunciations_changes.clone(), self.slot);
 
         let mut final_state_data = None;
 
@@ -490,6 +539,15 @@ impl FinalState {
         // compute the final state hash
         self.compute_state_hash_at_slot(slot);
 
+        // push hash history element and limit history size
+        if self.config.final_history_length > 0 {
+            while self.final_state_hash_history.len() >= self.config.final_history_length {
+                self.final_state_hash_history.pop_front();
+            }
+            self.final_state_hash_history
+                .push_back((slot, self.final_state_hash));
+        }
+
         if cfg!(feature = "create_snapshot") {
             let /*mut*/ hash_buffer = Vec::new();
 
@@ -523,6 +581,7 @@ impl FinalState {
     /// * proof-of-stake deferred credits change that is after `slot` and before or equal to `credits_step` slot
     /// * proof-of-stake cycle history change if main bootstrap process is finished
     /// * executed ops change if main bootstrap process is finished
+    /// * executed denunciations change if main bootstrap process is finished
     ///
     /// Produces an error when the `slot` is too old for `self.changes_history`
     pub fn get_state_changes_part(
@@ -533,6 +592,7 @@ impl FinalState {
         cycle_step: StreamingStep<u64>,
         credits_step: StreamingStep<Slot>,
         ops_step: StreamingStep<Slot>,
+        de_step: StreamingStep<Slot>,
     ) -> Result<Vec<(Slot, StateChanges)>, FinalStateError> {
         let position_slot = if let Some((first_slot, _)) = self.changes_history.front() {
             // Safe because we checked that there is changes just above.
@@ -650,6 +710,12 @@ impl FinalState {
                 slot_changes.executed_ops_changes = changes.executed_ops_changes.clone();
             }
 
+            // Get executed denunciations changes if executed denunciations main bootstrap finished
+            if de_step.finished() {
+                slot_changes.executed_denunciations_changes =
+                    changes.executed_denunciations_changes.clone();
+            }
+
             // Push the slot changes
             res_changes.push((*slot, slot_changes));
         }
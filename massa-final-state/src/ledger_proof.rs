@@ -0,0 +1,70 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file defines the proof of the value of a final ledger key
+//! that can be checked against the final state hash.
+
+use crate::{error::FinalStateError, final_state::FinalState};
+use massa_hash::Hash;
+use massa_ledger_exports::{Key as LedgerKey, LedgerProof};
+use massa_models::slot::Slot;
+use serde::{Deserialize, Serialize};
+
+/// Proof of the value, or of the absence, of a ledger key in the final state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalLedgerProof {
+    /// final slot at which the proof was produced
+    pub slot: Slot,
+    /// serialized value of the key, `None` if the key is absent from the ledger
+    pub value: Option<Vec<u8>>,
+    /// proof of the value against the ledger hash
+    pub ledger_proof: LedgerProof,
+    /// hashes committed by the final state hash, starting with the ledger hash
+    pub state_hash_components: Vec<Hash>,
+    /// final state hash at `slot`
+    pub final_state_hash: Hash,
+}
+
+impl FinalLedgerProof {
+    /// Checks that the proof links the value of `key` to the final state hash
+    pub fn verify(&self, key: &LedgerKey) -> Result<(), FinalStateError> {
+        let ledger_hash = self
+            .ledger_proof
+            .compute_ledger_hash(key, self.value.as_deref(), &self.slot)
+            .map_err(|err| FinalStateError::LedgerError(err.to_string()))?;
+        if self.state_hash_components.first() != Some(&ledger_hash) {
+            return Err(FinalStateError::LedgerError(
+                "ledger proof does not match the committed ledger hash".to_string(),
+            ));
+        }
+        let hash_concat: Vec<u8> = self
+            .state_hash_components
+            .iter()
+            .flat_map(|hash| *hash.to_bytes())
+            .collect();
+        if Hash::compute_from(&hash_concat) != self.final_state_hash {
+            return Err(FinalStateError::LedgerError(
+                "state hash components do not match the final state hash".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl FinalState {
+    /// Gets the values of a batch of ledger keys along with proofs
+    /// that can be checked against the current final state hash
+    pub fn get_ledger_proofs(&self, keys: &[LedgerKey]) -> Vec<FinalLedgerProof> {
+        let state_hash_components = self.get_state_hash_components();
+        self.ledger
+            .get_ledger_proofs(keys)
+            .into_iter()
+            .map(|(value, ledger_proof)| FinalLedgerProof {
+                slot: self.slot,
+                value,
+                ledger_proof,
+                state_hash_components: state_hash_components.clone(),
+                final_state_hash: self.final_state_hash,
+            })
+            .collect()
+    }
+}
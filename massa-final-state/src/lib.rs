@@ -25,6 +25,12 @@
 //! Defines a structure to list and prune previously executed operations.
 //! Used to detect operation reuse.
 //!
+//! ## `ledger_proof.rs`
+//! Defines the proof of the value of a final ledger key against the final state hash.
+//! ## `snapshot.rs`
+//! Defines the versioned on-disk snapshot format of the final state,
+//! used to export it at a given final slot and to restore it without bootstrapping.
+//!
 //! ## `bootstrap.rs`
 //! Provides serializable structures and tools for bootstrapping the final state.
 //!
@@ -42,11 +48,15 @@
 mod config;
 mod error;
 mod final_state;
+mod ledger_proof;
+mod snapshot;
 mod state_changes;
 
 pub use config::FinalStateConfig;
 pub use error::FinalStateError;
 pub use final_state::FinalState;
+pub use ledger_proof::FinalLedgerProof;
+pub use snapshot::FINAL_STATE_SNAPSHOT_VERSION;
 pub use state_changes::{StateChanges, StateChangesDeserializer, StateChangesSerializer};
 
 #[cfg(test)]
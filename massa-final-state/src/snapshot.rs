@@ -0,0 +1,630 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file defines the on-disk snapshot format of the final state.
+//!
+//! A snapshot file starts with a magic number and the format version, followed by
+//! a sequence of parts, each made of a kind byte, a big endian length and the part data:
+//! * the header (slot, last start period and final state hash)
+//! * the ledger, async pool, cycle history, deferred credits, executed operations
+//!   and executed denunciations parts, as streamed by the bootstrap getters
+//! * the MIP store
+//! * the checksum, chained over every previous part starting from the header,
+//!   so that it is tied to the exported final state hash
+//!
+//! Importing a snapshot first verifies the checksum of the whole file, then replays the parts
+//! with the bootstrap setters and checks that the recomputed final state hash matches the exported one.
+//!
+//! Bootstrap checkpoints use the same format with their own magic number,
+//! to save a partially bootstrapped final state and resume the bootstrap after a restart.
+//! They do not contain the ledger, which is already on disk, nor the MIP store,
+//! but carry the data of the bootstrap client in an extra part.
+
+use crate::{error::FinalStateError, final_state::FinalState};
+use massa_async_pool::{AsyncPoolDeserializer, AsyncPoolSerializer};
+use massa_executed_ops::{
+    ExecutedDenunciationsDeserializer, ExecutedDenunciationsSerializer, ExecutedOpsDeserializer,
+    ExecutedOpsSerializer,
+};
+use massa_hash::{Hash, HashDeserializer, HASH_SIZE_BYTES};
+use massa_models::{
+    config::{
+        MAX_ASYNC_POOL_LENGTH, MAX_DATASTORE_KEY_LENGTH, MAX_DEFERRED_CREDITS_LENGTH,
+        MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+        MAX_EXECUTED_OPS_LENGTH, MAX_OPERATIONS_PER_BLOCK, MAX_PRODUCTION_STATS_LENGTH,
+        MAX_ROLLS_COUNT_LENGTH, MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX,
+    },
+    slot::{Slot, SlotDeserializer, SlotSerializer},
+    streaming_step::StreamingStep,
+};
+use massa_pos_exports::{
+    CycleInfoDeserializer, CycleInfoSerializer, DeferredCreditsDeserializer,
+    DeferredCreditsSerializer,
+};
+use massa_serialization::{
+    DeserializeError, Deserializer, OptionDeserializer, OptionSerializer, Serializer,
+    U64VarIntDeserializer, U64VarIntSerializer,
+};
+use massa_versioning_worker::{
+    versioning::MipStoreRaw,
+    versioning_ser_der::{MipStoreRawDeserializer, MipStoreRawSerializer},
+};
+use nom::{error::context, sequence::tuple, Parser};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::ops::Bound::{Excluded, Included};
+use std::path::Path;
+use tracing::info;
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"MASSASNP";
+const CHECKPOINT_MAGIC: &[u8; 8] = b"MASSABCP";
+const SNAPSHOT_CHECKSUM_INITIAL_BYTES: &[u8; 32] = &[0; HASH_SIZE_BYTES];
+
+/// Version of the final state snapshot format, bumped on every incompatible change
+pub const FINAL_STATE_SNAPSHOT_VERSION: u32 = 1;
+
+/// Kinds of the parts composing a snapshot file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum SnapshotPartKind {
+    Header = 0,
+    Ledger = 1,
+    AsyncPool = 2,
+    CycleHistory = 3,
+    DeferredCredits = 4,
+    ExecutedOps = 5,
+    ExecutedDenunciations = 6,
+    MipStore = 7,
+    Extra = 8,
+    Checksum = 255,
+}
+
+impl TryFrom<u8> for SnapshotPartKind {
+    type Error = FinalStateError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SnapshotPartKind::Header),
+            1 => Ok(SnapshotPartKind::Ledger),
+            2 => Ok(SnapshotPartKind::AsyncPool),
+            3 => Ok(SnapshotPartKind::CycleHistory),
+            4 => Ok(SnapshotPartKind::DeferredCredits),
+            5 => Ok(SnapshotPartKind::ExecutedOps),
+            6 => Ok(SnapshotPartKind::ExecutedDenunciations),
+            7 => Ok(SnapshotPartKind::MipStore),
+            8 => Ok(SnapshotPartKind::Extra),
+            255 => Ok(SnapshotPartKind::Checksum),
+            _ => Err(FinalStateError::SnapshotError(format!(
+                "unknown snapshot part kind: {}",
+                value
+            ))),
+        }
+    }
+}
+
+fn io_error(err: std::io::Error) -> FinalStateError {
+    FinalStateError::SnapshotError(format!("snapshot file error: {}", err))
+}
+
+fn ser_error<E: std::fmt::Display>(err: E) -> FinalStateError {
+    FinalStateError::SnapshotError(format!("snapshot serialization error: {}", err))
+}
+
+fn deser_error<E: std::fmt::Display>(kind: SnapshotPartKind, err: E) -> FinalStateError {
+    FinalStateError::SnapshotError(format!(
+        "snapshot {:?} part deserialization error: {}",
+        kind, err
+    ))
+}
+
+/// Chains the checksum of the previous parts with a new part
+fn chain_checksum(checksum: &Hash, kind: SnapshotPartKind, data: &[u8]) -> Hash {
+    Hash::compute_from(&[&checksum.to_bytes()[..], &[kind as u8], data].concat())
+}
+
+/// Writes snapshot parts to a file while maintaining the checksum
+struct SnapshotWriter {
+    file: BufWriter<File>,
+    checksum: Hash,
+}
+
+impl SnapshotWriter {
+    fn new(path: &Path, magic: &[u8; 8]) -> Result<Self, FinalStateError> {
+        let mut file = BufWriter::new(File::create(path).map_err(io_error)?);
+        file.write_all(magic).map_err(io_error)?;
+        file.write_all(&FINAL_STATE_SNAPSHOT_VERSION.to_be_bytes())
+            .map_err(io_error)?;
+        Ok(SnapshotWriter {
+            file,
+            checksum: Hash::from_bytes(SNAPSHOT_CHECKSUM_INITIAL_BYTES),
+        })
+    }
+
+    fn write_raw_part(
+        &mut self,
+        kind: SnapshotPartKind,
+        data: &[u8],
+    ) -> Result<(), FinalStateError> {
+        self.file.write_all(&[kind as u8]).map_err(io_error)?;
+        self.file
+            .write_all(&(data.len() as u64).to_be_bytes())
+            .map_err(io_error)?;
+        self.file.write_all(data).map_err(io_error)
+    }
+
+    fn write_part(&mut self, kind: SnapshotPartKind, data: &[u8]) -> Result<(), FinalStateError> {
+        self.checksum = chain_checksum(&self.checksum, kind, data);
+        self.write_raw_part(kind, data)
+    }
+
+    fn finish(mut self) -> Result<(), FinalStateError> {
+        let checksum = self.checksum;
+        self.write_raw_part(SnapshotPartKind::Checksum, checksum.to_bytes())?;
+        self.file.flush().map_err(io_error)
+    }
+}
+
+/// Content of a snapshot or checkpoint file, besides the final state parts
+struct SnapshotFileContent {
+    slot: Slot,
+    last_start_period: u64,
+    final_state_hash: Hash,
+    mip_store: Option<MipStoreRaw>,
+    extra: Option<Vec<u8>>,
+}
+
+/// Opens a snapshot or checkpoint file and checks its magic number and format version
+fn open_snapshot_file(
+    path: &Path,
+    expected_magic: &[u8; 8],
+) -> Result<BufReader<File>, FinalStateError> {
+    let mut reader = BufReader::new(File::open(path).map_err(io_error)?);
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic).map_err(io_error)?;
+    if &magic != expected_magic {
+        return Err(FinalStateError::SnapshotError(
+            "not a final state snapshot file".to_string(),
+        ));
+    }
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version).map_err(io_error)?;
+    let version = u32::from_be_bytes(version);
+    if version != FINAL_STATE_SNAPSHOT_VERSION {
+        return Err(FinalStateError::SnapshotError(format!(
+            "unsupported snapshot version {}, expected {}",
+            version, FINAL_STATE_SNAPSHOT_VERSION
+        )));
+    }
+    Ok(reader)
+}
+
+/// Checks the checksum of a snapshot or checkpoint file without interpreting its parts,
+/// so that a corrupted file is rejected before anything is written to the final state
+fn verify_snapshot_file(path: &Path, expected_magic: &[u8; 8]) -> Result<(), FinalStateError> {
+    let mut reader = open_snapshot_file(path, expected_magic)?;
+    let mut checksum = Hash::from_bytes(SNAPSHOT_CHECKSUM_INITIAL_BYTES);
+    loop {
+        let (kind, data) = read_part(&mut reader)?;
+        if kind == SnapshotPartKind::Checksum {
+            if data != checksum.to_bytes() {
+                return Err(FinalStateError::SnapshotError(
+                    "snapshot checksum mismatch".to_string(),
+                ));
+            }
+            return Ok(());
+        }
+        checksum = chain_checksum(&checksum, kind, &data);
+    }
+}
+
+/// Reads a snapshot part from a file
+fn read_part(reader: &mut impl Read) -> Result<(SnapshotPartKind, Vec<u8>), FinalStateError> {
+    let mut kind = [0u8; 1];
+    reader.read_exact(&mut kind).map_err(io_error)?;
+    let kind = SnapshotPartKind::try_from(kind[0])?;
+    let mut len = [0u8; 8];
+    reader.read_exact(&mut len).map_err(io_error)?;
+    let len = u64::from_be_bytes(len);
+    let mut data = Vec::new();
+    reader
+        .by_ref()
+        .take(len)
+        .read_to_end(&mut data)
+        .map_err(io_error)?;
+    if data.len() as u64 != len {
+        return Err(FinalStateError::SnapshotError(format!(
+            "truncated snapshot {:?} part",
+            kind
+        )));
+    }
+    Ok((kind, data))
+}
+
+impl FinalState {
+    /// Exports the final state and the MIP store to a snapshot file.
+    ///
+    /// The state is exported at the output of `self.slot`,
+    /// the caller is responsible for holding the final state lock during the export.
+    ///
+    /// # Arguments
+    /// * `mip_store`: the MIP store to save alongside the final state
+    /// * `path`: path of the snapshot file to create
+    pub fn export_snapshot(
+        &self,
+        mip_store: &MipStoreRaw,
+        path: &Path,
+    ) -> Result<(), FinalStateError> {
+        let mut writer = SnapshotWriter::new(path, SNAPSHOT_MAGIC)?;
+        self.write_state_parts(&mut writer, true)?;
+
+        // MIP store
+        let mut buffer = Vec::new();
+        MipStoreRawSerializer::new()
+            .serialize(mip_store, &mut buffer)
+            .map_err(ser_error)?;
+        writer.write_part(SnapshotPartKind::MipStore, &buffer)?;
+
+        writer.finish()?;
+
+        info!(
+            "final state snapshot exported at slot {} to {}",
+            self.slot,
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Saves a partially bootstrapped final state, without its ledger, to a checkpoint file.
+    /// The file is replaced atomically.
+    ///
+    /// # Arguments
+    /// * `path`: path of the checkpoint file
+    /// * `extra`: data of the bootstrap client to save alongside the final state
+    pub fn save_bootstrap_checkpoint(
+        &self,
+        path: &Path,
+        extra: &[u8],
+    ) -> Result<(), FinalStateError> {
+        let tmp_path = path.with_extension("tmp");
+        let mut writer = SnapshotWriter::new(&tmp_path, CHECKPOINT_MAGIC)?;
+        self.write_state_parts(&mut writer, false)?;
+        writer.write_part(SnapshotPartKind::Extra, extra)?;
+        writer.finish()?;
+        std::fs::rename(&tmp_path, path).map_err(io_error)
+    }
+
+    /// Restores a partially bootstrapped final state from a checkpoint file.
+    /// The ledger is left untouched.
+    ///
+    /// # Returns
+    /// The data of the bootstrap client saved alongside the final state
+    pub fn load_bootstrap_checkpoint(&mut self, path: &Path) -> Result<Vec<u8>, FinalStateError> {
+        let content = self.read_state_parts(path, CHECKPOINT_MAGIC, false)?;
+        self.slot = content.slot;
+        self.last_start_period = content.last_start_period;
+        content.extra.ok_or_else(|| {
+            FinalStateError::SnapshotError("checkpoint does not contain client data".to_string())
+        })
+    }
+
+    /// Writes the header and the final state parts, with or without the ledger
+    fn write_state_parts(
+        &self,
+        writer: &mut SnapshotWriter,
+        with_ledger: bool,
+    ) -> Result<(), FinalStateError> {
+        // header
+        let mut buffer = Vec::new();
+        SlotSerializer::new()
+            .serialize(&self.slot, &mut buffer)
+            .map_err(ser_error)?;
+        U64VarIntSerializer::new()
+            .serialize(&self.last_start_period, &mut buffer)
+            .map_err(ser_error)?;
+        buffer.extend(self.final_state_hash.to_bytes());
+        writer.write_part(SnapshotPartKind::Header, &buffer)?;
+
+        // ledger
+        let mut ledger_step = if with_ledger {
+            StreamingStep::Started
+        } else {
+            StreamingStep::Finished(None)
+        };
+        while !ledger_step.finished() {
+            let (data, new_step) = self
+                .ledger
+                .get_ledger_part(ledger_step)
+                .map_err(|err| FinalStateError::LedgerError(err.to_string()))?;
+            if !data.is_empty() {
+                writer.write_part(SnapshotPartKind::Ledger, &data)?;
+            }
+            ledger_step = new_step;
+        }
+
+        // async pool
+        let async_pool_serializer = AsyncPoolSerializer::new();
+        let mut pool_step = StreamingStep::Started;
+        while !pool_step.finished() {
+            let (part, new_step) = self.async_pool.get_pool_part(pool_step);
+            if !part.is_empty() {
+                buffer.clear();
+                async_pool_serializer
+                    .serialize(&part, &mut buffer)
+                    .map_err(ser_error)?;
+                writer.write_part(SnapshotPartKind::AsyncPool, &buffer)?;
+            }
+            pool_step = new_step;
+        }
+
+        // cycle history
+        let cycle_serializer = OptionSerializer::new(CycleInfoSerializer::new());
+        let mut cycle_step = StreamingStep::Started;
+        while !cycle_step.finished() {
+            let (part, new_step) = self
+                .pos_state
+                .get_cycle_history_part(cycle_step)
+                .map_err(|err| FinalStateError::PosError(err.to_string()))?;
+            if part.is_some() {
+                buffer.clear();
+                cycle_serializer
+                    .serialize(&part, &mut buffer)
+                    .map_err(ser_error)?;
+                writer.write_part(SnapshotPartKind::CycleHistory, &buffer)?;
+            }
+            cycle_step = new_step;
+        }
+
+        // deferred credits
+        let credits_serializer = DeferredCreditsSerializer::new();
+        let mut credits_step = StreamingStep::Started;
+        while !credits_step.finished() {
+            let (part, new_step) = self.pos_state.get_deferred_credits_part(credits_step);
+            if !part.credits.is_empty() {
+                buffer.clear();
+                credits_serializer
+                    .serialize(&part, &mut buffer)
+                    .map_err(ser_error)?;
+                writer.write_part(SnapshotPartKind::DeferredCredits, &buffer)?;
+            }
+            credits_step = new_step;
+        }
+
+        // executed operations
+        let ops_serializer = ExecutedOpsSerializer::new();
+        let mut ops_step = StreamingStep::Started;
+        while !ops_step.finished() {
+            let (part, new_step) = self.executed_ops.get_executed_ops_part(ops_step);
+            if !part.is_empty() {
+                buffer.clear();
+                ops_serializer
+                    .serialize(&part, &mut buffer)
+                    .map_err(ser_error)?;
+                writer.write_part(SnapshotPartKind::ExecutedOps, &buffer)?;
+            }
+            ops_step = new_step;
+        }
+
+        // executed denunciations
+        let de_serializer = ExecutedDenunciationsSerializer::new();
+        let mut de_step = StreamingStep::Started;
+        while !de_step.finished() {
+            let (part, new_step) = self
+                .executed_denunciations
+                .get_executed_denunciations_part(de_step);
+            if !part.is_empty() {
+                buffer.clear();
+                de_serializer
+                    .serialize(&part, &mut buffer)
+                    .map_err(ser_error)?;
+                writer.write_part(SnapshotPartKind::ExecutedDenunciations, &buffer)?;
+            }
+            de_step = new_step;
+        }
+        Ok(())
+    }
+
+    /// Resets the final state and restores it from a snapshot file.
+    ///
+    /// Fails if the snapshot version is not supported, if the checksum does not match,
+    /// or if the final state hash recomputed after the import differs from the exported one.
+    ///
+    /// # Arguments
+    /// * `path`: path of the snapshot file to import
+    ///
+    /// # Returns
+    /// The MIP store saved in the snapshot
+    pub fn import_snapshot(&mut self, path: &Path) -> Result<MipStoreRaw, FinalStateError> {
+        let SnapshotFileContent {
+            slot,
+            last_start_period,
+            final_state_hash,
+            mip_store,
+            ..
+        } = self.read_state_parts(path, SNAPSHOT_MAGIC, true)?;
+
+        // attach the state at the exported slot and check its integrity
+        self.slot = slot;
+        self.last_start_period = last_start_period;
+        self.ledger.set_initial_slot(slot);
+        self.compute_state_hash_at_slot(slot);
+        if self.final_state_hash != final_state_hash {
+            return Err(FinalStateError::SnapshotError(format!(
+                "final state hash mismatch after import: expected {}, got {}",
+                final_state_hash, self.final_state_hash
+            )));
+        }
+
+        info!(
+            "final state snapshot imported at slot {} from {}",
+            slot,
+            path.display()
+        );
+        mip_store.ok_or_else(|| {
+            FinalStateError::SnapshotError("snapshot does not contain a MIP store".to_string())
+        })
+    }
+
+    /// Resets the final state, with or without the ledger, and replays the parts of a snapshot or checkpoint file.
+    /// The file checksum is verified before the final state is reset.
+    fn read_state_parts(
+        &mut self,
+        path: &Path,
+        expected_magic: &[u8; 8],
+        with_ledger: bool,
+    ) -> Result<SnapshotFileContent, FinalStateError> {
+        // the whole file is checked first: the state, and especially the on-disk ledger,
+        // must not be reset for a file that turns out to be corrupted
+        verify_snapshot_file(path, expected_magic)?;
+        let mut reader = open_snapshot_file(path, expected_magic)?;
+
+        // header
+        let (kind, data) = read_part(&mut reader)?;
+        if kind != SnapshotPartKind::Header {
+            return Err(FinalStateError::SnapshotError(format!(
+                "expected snapshot header, got {:?} part",
+                kind
+            )));
+        }
+        let mut checksum = chain_checksum(
+            &Hash::from_bytes(SNAPSHOT_CHECKSUM_INITIAL_BYTES),
+            kind,
+            &data,
+        );
+        let slot_deserializer = SlotDeserializer::new(
+            (Included(u64::MIN), Included(u64::MAX)),
+            (Included(0), Excluded(self.config.thread_count)),
+        );
+        let period_deserializer =
+            U64VarIntDeserializer::new(Included(u64::MIN), Included(u64::MAX));
+        let hash_deserializer = HashDeserializer::new();
+        let (_, (slot, last_start_period, final_state_hash)) = context(
+            "Failed snapshot header deserialization",
+            tuple((
+                |input| slot_deserializer.deserialize(input),
+                |input| period_deserializer.deserialize(input),
+                |input| hash_deserializer.deserialize(input),
+            )),
+        )
+        .parse(&data[..])
+        .map_err(|err: nom::Err<DeserializeError>| deser_error(kind, err))?;
+
+        // parts
+        let async_pool_deserializer = AsyncPoolDeserializer::new(
+            self.config.thread_count,
+            MAX_ASYNC_POOL_LENGTH,
+            self.config.async_pool_config.max_async_message_data,
+            MAX_DATASTORE_KEY_LENGTH as u32,
+        );
+        let cycle_deserializer = OptionDeserializer::new(CycleInfoDeserializer::new(
+            MAX_ROLLS_COUNT_LENGTH,
+            MAX_PRODUCTION_STATS_LENGTH,
+        ));
+        let credits_deserializer = DeferredCreditsDeserializer::new(
+            self.config.thread_count,
+            MAX_DEFERRED_CREDITS_LENGTH,
+            false,
+        );
+        let ops_deserializer = ExecutedOpsDeserializer::new(
+            self.config.thread_count,
+            MAX_EXECUTED_OPS_LENGTH,
+            MAX_OPERATIONS_PER_BLOCK as u64,
+        );
+        let de_deserializer = ExecutedDenunciationsDeserializer::new(
+            self.config.thread_count,
+            MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+            MAX_DENUNCIATIONS_PER_BLOCK_HEADER as u64,
+        );
+        let mip_store_deserializer = MipStoreRawDeserializer::new(
+            MIP_STORE_STATS_BLOCK_CONSIDERED,
+            MIP_STORE_STATS_COUNTERS_MAX,
+        );
+
+        if with_ledger {
+            self.reset();
+        } else {
+            self.async_pool.reset();
+            self.pos_state.reset();
+            self.executed_ops.reset();
+            self.executed_denunciations.reset();
+            self.changes_history.clear();
+            self.final_state_hash_history.clear();
+        }
+        let mut mip_store = None;
+        let mut extra = None;
+        loop {
+            let (kind, data) = read_part(&mut reader)?;
+            if kind == SnapshotPartKind::Checksum {
+                // checked again in case the file changed since it was verified
+                if data != checksum.to_bytes() {
+                    return Err(FinalStateError::SnapshotError(
+                        "snapshot checksum mismatch".to_string(),
+                    ));
+                }
+                break;
+            }
+            checksum = chain_checksum(&checksum, kind, &data);
+            match kind {
+                SnapshotPartKind::Ledger if with_ledger => {
+                    self.ledger
+                        .set_ledger_part(data)
+                        .map_err(|err| FinalStateError::LedgerError(err.to_string()))?;
+                }
+                SnapshotPartKind::AsyncPool => {
+                    let (_, part) = async_pool_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    self.async_pool.set_pool_part(part);
+                }
+                SnapshotPartKind::CycleHistory => {
+                    let (_, part) = cycle_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    self.pos_state.set_cycle_history_part(part);
+                }
+                SnapshotPartKind::DeferredCredits => {
+                    let (_, part) = credits_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    self.pos_state.set_deferred_credits_part(part);
+                }
+                SnapshotPartKind::ExecutedOps => {
+                    let (_, part) = ops_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    self.executed_ops.set_executed_ops_part(part);
+                }
+                SnapshotPartKind::ExecutedDenunciations => {
+                    let (_, part) = de_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    self.executed_denunciations
+                        .set_executed_denunciations_part(part);
+                }
+                SnapshotPartKind::MipStore => {
+                    let (_, store) = mip_store_deserializer
+                        .deserialize::<DeserializeError>(&data)
+                        .map_err(|err| deser_error(kind, err))?;
+                    mip_store = Some(store);
+                }
+                SnapshotPartKind::Extra => {
+                    extra = Some(data);
+                }
+                SnapshotPartKind::Header
+                | SnapshotPartKind::Ledger
+                | SnapshotPartKind::Checksum => {
+                    return Err(FinalStateError::SnapshotError(format!(
+                        "unexpected snapshot {:?} part",
+                        kind
+                    )));
+                }
+            }
+        }
+
+        Ok(SnapshotFileContent {
+            slot,
+            last_start_period,
+            final_state_hash,
+            mip_store,
+            extra,
+        })
+    }
+}
@@ -5,7 +5,7 @@
 use std::collections::VecDeque;
 
 use massa_async_pool::AsyncPool;
-use massa_executed_ops::ExecutedOps;
+use massa_executed_ops::{ExecutedDenunciations, ExecutedOps};
 use massa_hash::{Hash, HASH_SIZE_BYTES};
 use massa_ledger_exports::LedgerController;
 use massa_models::slot::Slot;
@@ -22,6 +22,7 @@ pub fn create_final_state(
     changes_history: VecDeque<(Slot, StateChanges)>,
     pos_state: PoSFinalState,
     executed_ops: ExecutedOps,
+    executed_denunciations: ExecutedDenunciations,
 ) -> FinalState {
     FinalState {
         config,
@@ -31,7 +32,9 @@ pub fn create_final_state(
         changes_history,
         pos_state,
         executed_ops,
+        executed_denunciations,
         final_state_hash: Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+        final_state_hash_history: Default::default(),
         last_start_period: 0,
     }
 }
@@ -61,6 +64,15 @@ pub fn assert_eq_final_state(v1: &FinalState, v2: &FinalState) {
         v1.executed_ops.sorted_ops, v2.executed_ops.sorted_ops,
         "executed_ops.sorted_ops mismatch"
     );
+    assert_eq!(
+        v1.executed_denunciations.denunciations, v2.executed_denunciations.denunciations,
+        "executed_denunciations.denunciations mismatch"
+    );
+    assert_eq!(
+        v1.executed_denunciations.sorted_denunciations,
+        v2.executed_denunciations.sorted_denunciations,
+        "executed_denunciations.sorted_denunciations mismatch"
+    );
 }
 
 /// asserts that two `FinalState` hashes are equal
@@ -105,4 +117,8 @@ pub fn assert_eq_final_state_hash(v1: &FinalState, v2: &FinalState) {
         v1.executed_ops.hash, v2.executed_ops.hash,
         "executed ops hash mismatch"
     );
+    assert_eq!(
+        v1.executed_denunciations.hash, v2.executed_denunciations.hash,
+        "executed denunciations hash mismatch"
+    );
 }
@@ -6,14 +6,17 @@ use std::path::PathBuf;
 
 use crate::{FinalState, FinalStateConfig};
 use massa_async_pool::{AsyncPool, AsyncPoolConfig};
-use massa_executed_ops::{ExecutedOps, ExecutedOpsConfig};
+use massa_executed_ops::{
+    ExecutedDenunciations, ExecutedDenunciationsConfig, ExecutedOps, ExecutedOpsConfig,
+};
 use massa_hash::{Hash, HASH_SIZE_BYTES};
 use massa_ledger_exports::LedgerConfig;
 use massa_ledger_worker::FinalLedger;
 use massa_models::{
     config::{
-        DEFERRED_CREDITS_BOOTSTRAP_PART_SIZE, EXECUTED_OPS_BOOTSTRAP_PART_SIZE, PERIODS_PER_CYCLE,
-        POS_SAVED_CYCLES, THREAD_COUNT,
+        DEFERRED_CREDITS_BOOTSTRAP_PART_SIZE, DENUNCIATION_EXPIRE_PERIODS,
+        EXECUTED_DENUNCIATIONS_BOOTSTRAP_PART_SIZE, EXECUTED_OPS_BOOTSTRAP_PART_SIZE,
+        PERIODS_PER_CYCLE, POS_SAVED_CYCLES, THREAD_COUNT,
     },
     slot::Slot,
 };
@@ -28,9 +31,13 @@ impl FinalState {
             async_pool: AsyncPool::new(config.async_pool_config.clone()),
             pos_state,
             executed_ops: ExecutedOps::new(config.executed_ops_config.clone()),
+            executed_denunciations: ExecutedDenunciations::new(
+                config.executed_denunciations_config.clone(),
+            ),
             changes_history: Default::default(),
             config,
             final_state_hash: Hash::from_bytes(&[0; HASH_SIZE_BYTES]),
+            final_state_hash_history: Default::default(),
             last_start_period: 0,
         }
     }
@@ -46,6 +53,10 @@ impl Default for FinalStateConfig {
                 thread_count: THREAD_COUNT,
                 bootstrap_part_size: EXECUTED_OPS_BOOTSTRAP_PART_SIZE,
             },
+            executed_denunciations_config: ExecutedDenunciationsConfig {
+                denunciation_expire_periods: DENUNCIATION_EXPIRE_PERIODS,
+                bootstrap_part_size: EXECUTED_DENUNCIATIONS_BOOTSTRAP_PART_SIZE,
+            },
             pos_config: PoSConfig {
                 periods_per_cycle: PERIODS_PER_CYCLE,
                 thread_count: THREAD_COUNT,
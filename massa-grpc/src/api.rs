@@ -1,85 +1,673 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
+use crate::config::GrpcConfig;
 use crate::error::GrpcError;
+use crate::rate_limit::RateLimitedClient;
 use crate::server::MassaGrpc;
-use itertools::izip;
+use itertools::{izip, Itertools};
+use massa_consensus_exports::block_status::DiscardReason;
+use massa_execution_exports::{
+    CallTrace, ExecutionError, ExecutionStackElement, ExecutionTraceStep,
+    OperationEstimationRequest, ReadOnlyExecutionOutput, ReadOnlyExecutionRequest,
+    ReadOnlyExecutionTarget, StateOverride,
+};
+use massa_ledger_exports::{Key as LedgerKey, KeyType};
 use massa_models::address::Address;
+use massa_models::amount::Amount;
+use massa_models::block::BlockGraphStatus;
+use massa_models::block_id::BlockId;
+use massa_models::bytecode::Bytecode;
+use massa_models::config::CompactConfig;
+use massa_models::datastore::DatastoreDeserializer;
+use massa_models::endorsement::{EndorsementId, SecureShareEndorsement};
+use massa_models::execution::EventFilter;
+use massa_models::operation::{OperationId, OperationType, SecureShareOperation};
+use massa_models::prehash::{PreHashMap, PreHashSet};
 use massa_models::slot::Slot;
-use massa_models::timeslots;
+use massa_models::timeslots::{self, get_latest_block_slot_at_timestamp, time_range_to_slot_range};
 use massa_proto::massa::api::v1 as grpc;
+use massa_serialization::{DeserializeError, Deserializer};
+use massa_signature::KeyPair;
+use massa_time::MassaTime;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use tracing::log::warn;
 
-/// get blocks by slots
-pub(crate) fn get_blocks_by_slots(
+/// estimate the costs of an unsigned operation
+pub(crate) fn estimate_operation(
     grpc: &MassaGrpc,
-    request: tonic::Request<grpc::GetBlocksBySlotsRequest>,
-) -> Result<grpc::GetBlocksBySlotsResponse, GrpcError> {
+    request: tonic::Request<grpc::EstimateOperationRequest>,
+) -> Result<grpc::EstimateOperationResponse, GrpcError> {
     let inner_req = request.into_inner();
-    let storage = grpc.storage.clone_without_refs();
+    let sender_address = Address::from_str(&inner_req.sender_address)?;
+    let op = match inner_req.op {
+        Some(op) => to_operation_type(op)?,
+        None => {
+            return Err(GrpcError::InvalidArgument(
+                "operation is missing".to_string(),
+            ))
+        }
+    };
 
-    let mut blocks = Vec::new();
+    let estimation = grpc
+        .execution_controller
+        .estimate_operation(OperationEstimationRequest {
+            sender_address,
+            op,
+            is_final: inner_req.is_final,
+        })?;
 
-    for slot in inner_req.slots.into_iter() {
-        let Some(block_id) = grpc.consensus_controller.get_blockclique_block_at_slot(Slot {
-            period: slot.period,
-            thread: slot.thread as u8,
-        }) else {
-            continue;
-        };
+    Ok(grpc::EstimateOperationResponse {
+        id: inner_req.id,
+        slot: Some(estimation.executed_at.into()),
+        gas_used: estimation.gas_used,
+        storage_cost: estimation.storage_cost.to_raw(),
+        suggested_fee: estimation.suggested_fee.to_raw(),
+        balance_changes: estimation
+            .balance_changes
+            .into_iter()
+            .map(|(address, change)| grpc::BalanceChange {
+                address: address.to_string(),
+                previous: change.previous.to_raw(),
+                predicted: change.predicted.to_raw(),
+            })
+            .collect(),
+    })
+}
 
-        let res = storage.read_blocks().get(&block_id).map(|b| {
-            // TODO rework ?
-            let header = b.clone().content.header;
-            // transform to grpc struct
-            let parents = header
-                .content
-                .parents
+/// Converts a gRPC slot, whose thread must be lower than `thread_count`
+fn to_slot(slot: grpc::Slot, thread_count: u8) -> Result<Slot, GrpcError> {
+    match u8::try_from(slot.thread) {
+        Ok(thread) if thread < thread_count => Ok(Slot::new(slot.period, thread)),
+        _ => Err(GrpcError::InvalidArgument(format!(
+            "invalid thread {} in slot, there are {} threads",
+            slot.thread, thread_count
+        ))),
+    }
+}
+
+/// Converts the gRPC operation content, which must have exactly one type set
+fn to_operation_type(op: grpc::OperationType) -> Result<OperationType, GrpcError> {
+    match (
+        op.transaction,
+        op.roll_buy,
+        op.roll_sell,
+        op.execut_sc,
+        op.call_sc,
+    ) {
+        (Some(transaction), None, None, None, None) => Ok(OperationType::Transaction {
+            recipient_address: Address::from_str(&transaction.recipient_address)?,
+            amount: Amount::from_raw(transaction.amount),
+        }),
+        (None, Some(roll_buy), None, None, None) => Ok(OperationType::RollBuy {
+            roll_count: roll_buy.roll_count,
+        }),
+        (None, None, Some(roll_sell), None, None) => Ok(OperationType::RollSell {
+            roll_count: roll_sell.roll_count,
+        }),
+        (None, None, None, Some(execute_sc), None) => Ok(OperationType::ExecuteSC {
+            data: execute_sc.data,
+            max_gas: execute_sc.max_gas,
+            datastore: execute_sc
+                .datastore
                 .into_iter()
-                .map(|p| p.to_string())
-                .collect();
+                .map(|entry| (entry.key, entry.value))
+                .collect(),
+        }),
+        (None, None, None, None, Some(call_sc)) => Ok(OperationType::CallSC {
+            target_addr: Address::from_str(&call_sc.target_addr)?,
+            target_func: call_sc.target_func,
+            param: call_sc.param,
+            max_gas: call_sc.max_gas,
+            coins: Amount::from_raw(call_sc.coins),
+        }),
+        _ => Err(GrpcError::InvalidArgument(
+            "exactly one operation type must be set".to_string(),
+        )),
+    }
+}
 
-            let endorsements = header
-                .content
-                .endorsements
+/// execute read only bytecodes
+pub(crate) fn execute_read_only_bytecode(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::ExecuteReadOnlyBytecodeRequest>,
+) -> Result<grpc::ExecuteReadOnlyBytecodeResponse, GrpcError> {
+    let rate_limited_client = request.extensions().get::<RateLimitedClient>().cloned();
+    let inner_req = request.into_inner();
+    if inner_req.bytecode_executions.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    let mut outputs = Vec::with_capacity(inner_req.bytecode_executions.len());
+    for execution in inner_req.bytecode_executions {
+        let address = match execution.address {
+            Some(address) => Address::from_str(&address)?,
+            // if no address is provided, use a random one
+            None => Address::from_public_key(&KeyPair::generate().get_public_key()),
+        };
+
+        let operation_datastore = match execution.operation_datastore {
+            Some(datastore) => {
+                let deserializer = DatastoreDeserializer::new(
+                    grpc.grpc_config.max_op_datastore_entry_count,
+                    grpc.grpc_config.max_op_datastore_key_length,
+                    grpc.grpc_config.max_op_datastore_value_length,
+                );
+                let (_, datastore) = deserializer
+                    .deserialize::<DeserializeError>(&datastore)
+                    .map_err(|e| {
+                        GrpcError::InvalidArgument(format!("operation datastore error: {}", e))
+                    })?;
+                Some(datastore)
+            }
+            None => None,
+        };
+
+        let result = grpc
+            .execution_controller
+            .execute_readonly_request(ReadOnlyExecutionRequest {
+                max_gas: execution.max_gas,
+                target: ReadOnlyExecutionTarget::BytecodeExecution(execution.bytecode),
+                call_stack: vec![ExecutionStackElement {
+                    address,
+                    coins: Default::default(),
+                    owned_addresses: vec![address],
+                    operation_datastore,
+                }],
+                is_final: execution.is_final,
+                state_overrides: Default::default(),
+                with_trace: execution.with_trace,
+            });
+        outputs.push(to_read_only_output(result));
+    }
+
+    if let Some(client) = rate_limited_client {
+        client.charge_gas(outputs.iter().map(|output| output.gas_cost).sum());
+    }
+
+    Ok(grpc::ExecuteReadOnlyBytecodeResponse {
+        id: inner_req.id,
+        outputs,
+    })
+}
+
+/// checks that the state overrides of a read-only call fit in the limits of the API
+pub(crate) fn check_state_overrides(
+    config: &GrpcConfig,
+    state_overrides: &[grpc::StateOverride],
+) -> Result<(), GrpcError> {
+    if state_overrides.len() as u64 > config.max_arguments {
+        return Err(GrpcError::InvalidArgument(
+            "too many state overrides".to_string(),
+        ));
+    }
+    for state_override in state_overrides {
+        if let Some(bytecode) = &state_override.bytecode {
+            if bytecode.len() as u64 > config.max_datastore_value_length {
+                return Err(GrpcError::InvalidArgument(format!(
+                    "overridden bytecode of {} is too large",
+                    state_override.address
+                )));
+            }
+        }
+        if state_override.datastore.len() as u64 > config.max_op_datastore_entry_count {
+            return Err(GrpcError::InvalidArgument(format!(
+                "too many overridden datastore entries for {}",
+                state_override.address
+            )));
+        }
+        for entry in &state_override.datastore {
+            if entry.key.len() > config.max_op_datastore_key_length as usize {
+                return Err(GrpcError::InvalidArgument(format!(
+                    "overridden datastore key of {} is too long",
+                    state_override.address
+                )));
+            }
+            if entry.value.len() as u64 > config.max_datastore_value_length {
+                return Err(GrpcError::InvalidArgument(format!(
+                    "overridden datastore value of {} is too large",
+                    state_override.address
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// execute read only smart contract calls
+pub(crate) fn execute_read_only_call(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::ExecuteReadOnlyCallRequest>,
+) -> Result<grpc::ExecuteReadOnlyCallResponse, GrpcError> {
+    let rate_limited_client = request.extensions().get::<RateLimitedClient>().cloned();
+    let inner_req = request.into_inner();
+    if inner_req.calls.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    for call in inner_req.calls.iter() {
+        check_state_overrides(&grpc.grpc_config, &call.state_overrides)?;
+    }
+
+    let mut outputs = Vec::with_capacity(inner_req.calls.len());
+    for call in inner_req.calls {
+        let target_address = Address::from_str(&call.target_address)?;
+        let caller_address = match call.caller_address {
+            Some(address) => Address::from_str(&address)?,
+            // if no address is provided, use a random one
+            None => Address::from_public_key(&KeyPair::generate().get_public_key()),
+        };
+
+        let state_overrides = call
+            .state_overrides
+            .into_iter()
+            .map(|state_override| {
+                Ok((
+                    Address::from_str(&state_override.address)?,
+                    StateOverride {
+                        balance: state_override.balance.map(Amount::from_raw),
+                        bytecode: state_override.bytecode.map(Bytecode),
+                        datastore: state_override
+                            .datastore
+                            .into_iter()
+                            .map(|entry| (entry.key, entry.value))
+                            .collect(),
+                        roll_count: state_override.roll_count,
+                    },
+                ))
+            })
+            .collect::<Result<_, GrpcError>>()?;
+
+        let result = grpc
+            .execution_controller
+            .execute_readonly_request(ReadOnlyExecutionRequest {
+                max_gas: call.max_gas,
+                target: ReadOnlyExecutionTarget::FunctionCall {
+                    target_func: call.target_function,
+                    target_addr: target_address,
+                    parameter: call.parameter,
+                },
+                call_stack: vec![
+                    ExecutionStackElement {
+                        address: caller_address,
+                        coins: Default::default(),
+                        owned_addresses: vec![caller_address],
+                        operation_datastore: None, // should always be None
+                    },
+                    ExecutionStackElement {
+                        address: target_address,
+                        coins: Default::default(),
+                        owned_addresses: vec![target_address],
+                        operation_datastore: None, // should always be None
+                    },
+                ],
+                is_final: call.is_final,
+                state_overrides,
+                with_trace: call.with_trace,
+            });
+        outputs.push(to_read_only_output(result));
+    }
+
+    if let Some(client) = rate_limited_client {
+        client.charge_gas(outputs.iter().map(|output| output.gas_cost).sum());
+    }
+
+    Ok(grpc::ExecuteReadOnlyCallResponse {
+        id: inner_req.id,
+        outputs,
+    })
+}
+
+/// Converts the result of a read only execution, failures being reported in the `error` field
+fn to_read_only_output(
+    result: Result<ReadOnlyExecutionOutput, ExecutionError>,
+) -> grpc::ReadOnlyExecutionOutput {
+    match result {
+        Ok(output) => grpc::ReadOnlyExecutionOutput {
+            executed_at: Some(output.out.slot.into()),
+            call_result: output.call_result,
+            error: None,
+            gas_cost: output.gas_cost,
+            output_events: output
+                .out
+                .events
+                .0
                 .into_iter()
-                .map(|endorsement| endorsement.into())
-                .collect();
-
-            let block_header = grpc::BlockHeader {
-                slot: Some(grpc::Slot {
-                    period: header.content.slot.period,
-                    thread: header.content.slot.thread as u32,
-                }),
-                parents,
-                operation_merkle_root: header.content.operation_merkle_root.to_string(),
-                endorsements,
-            };
+                .map(|event| event.into())
+                .collect(),
+            trace: output.trace.map(to_call_trace),
+        },
+        Err(e) => grpc::ReadOnlyExecutionOutput {
+            executed_at: Some(Slot::new(0, 0).into()),
+            error: Some(format!("readonly call failed: {}", e)),
+            ..Default::default()
+        },
+    }
+}
 
-            let operations: Vec<String> = b
-                .content
-                .operations
-                .iter()
-                .map(|ope| ope.to_string())
-                .collect();
-
-            (
-                grpc::SignedBlockHeader {
-                    content: Some(block_header),
-                    signature: header.signature.to_string(),
-                    content_creator_pub_key: header.content_creator_pub_key.to_string(),
-                    content_creator_address: header.content_creator_address.to_string(),
-                    id: header.id.to_string(),
+/// Converts a call trace and its nested calls
+fn to_call_trace(trace: CallTrace) -> grpc::CallTrace {
+    grpc::CallTrace {
+        address: trace.address.to_string(),
+        coins: trace.coins.to_raw(),
+        steps: trace
+            .steps
+            .into_iter()
+            .map(|step| match step {
+                ExecutionTraceStep::Call(call) => grpc::ExecutionTraceStep {
+                    call: Some(to_call_trace(call)),
+                    ..Default::default()
+                },
+                ExecutionTraceStep::TransferCoins { from, to, amount } => {
+                    grpc::ExecutionTraceStep {
+                        transfer_coins: Some(grpc::TraceTransferCoins {
+                            from_address: from.to_string(),
+                            to_address: to.to_string(),
+                            amount: amount.to_raw(),
+                        }),
+                        ..Default::default()
+                    }
+                }
+                ExecutionTraceStep::SetData {
+                    address,
+                    key,
+                    value,
+                    ..
+                } => grpc::ExecutionTraceStep {
+                    set_data: Some(grpc::TraceSetData {
+                        address: address.to_string(),
+                        key,
+                        value,
+                    }),
+                    ..Default::default()
+                },
+                ExecutionTraceStep::SendMessage {
+                    destination,
+                    handler,
+                    max_gas,
+                    fee,
+                    coins,
+                } => grpc::ExecutionTraceStep {
+                    send_message: Some(grpc::TraceSendMessage {
+                        destination: destination.to_string(),
+                        handler,
+                        max_gas,
+                        fee: fee.to_raw(),
+                        coins: coins.to_raw(),
+                    }),
+                    ..Default::default()
+                },
+                ExecutionTraceStep::GasCheckpoint { remaining_gas } => grpc::ExecutionTraceStep {
+                    gas_checkpoint: Some(grpc::TraceGasCheckpoint { remaining_gas }),
+                    ..Default::default()
                 },
-                operations,
-            )
+            })
+            .collect(),
+        finished: trace.finished,
+    }
+}
+
+/// get addresses
+pub(crate) fn get_addresses(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetAddressesRequest>,
+) -> Result<grpc::GetAddressesResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    if inner_req.addresses.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    let addresses = inner_req
+        .addresses
+        .iter()
+        .map(|address| Address::from_str(address))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // get info from storage about which blocks the addresses have created
+    let created_blocks: Vec<PreHashSet<BlockId>> = {
+        let lck = grpc.storage.read_blocks();
+        addresses
+            .iter()
+            .map(|address| {
+                lck.get_blocks_created_by(address)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+
+    // get info from storage about which operations the addresses have created
+    let created_operations: Vec<PreHashSet<OperationId>> = {
+        let lck = grpc.storage.read_operations();
+        addresses
+            .iter()
+            .map(|address| {
+                lck.get_operations_created_by(address)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+
+    // get info from storage about which endorsements the addresses have created
+    let created_endorsements: Vec<PreHashSet<EndorsementId>> = {
+        let lck = grpc.storage.read_endorsements();
+        addresses
+            .iter()
+            .map(|address| {
+                lck.get_endorsements_created_by(address)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+
+    // get execution info
+    let execution_infos = grpc.execution_controller.get_addresses_infos(&addresses);
+
+    // get future draws from selector
+    let selection_draws = {
+        let cur_slot = match timeslots::get_current_latest_block_slot(
+            grpc.grpc_config.thread_count,
+            grpc.grpc_config.t0,
+            grpc.grpc_config.genesis_timestamp,
+        ) {
+            Ok(slot) => slot.unwrap_or_else(Slot::min),
+            Err(e) => {
+                warn!("failed to get current slot with error: {}", e);
+                Slot::min()
+            }
+        };
+
+        let slot_end = Slot::new(
+            cur_slot
+                .period
+                .saturating_add(grpc.grpc_config.draw_lookahead_period_count),
+            cur_slot.thread,
+        );
+        addresses
+            .iter()
+            .map(|addr| {
+                grpc.selector_controller
+                    .get_address_selections(addr, cur_slot, slot_end)
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // compile results
+    let mut res = Vec::with_capacity(addresses.len());
+    let iterator = izip!(
+        addresses.into_iter(),
+        created_blocks.into_iter(),
+        created_operations.into_iter(),
+        created_endorsements.into_iter(),
+        execution_infos.into_iter(),
+        selection_draws.into_iter(),
+    );
+    for (
+        address,
+        created_blocks,
+        created_operations,
+        created_endorsements,
+        execution_infos,
+        (next_block_draws, next_endorsement_draws),
+    ) in iterator
+    {
+        res.push(grpc::AddressInfo {
+            // general address info
+            address: address.to_string(),
+            thread: address.get_thread(grpc.grpc_config.thread_count) as u32,
+
+            // final execution info
+            final_balance: execution_infos.final_balance.to_raw(),
+            final_roll_count: execution_infos.final_roll_count,
+            final_datastore_keys: execution_infos.final_datastore_keys.into_iter().collect(),
+
+            // candidate execution info
+            candidate_balance: execution_infos.candidate_balance.to_raw(),
+            candidate_roll_count: execution_infos.candidate_roll_count,
+            candidate_datastore_keys: execution_infos
+                .candidate_datastore_keys
+                .into_iter()
+                .collect(),
+
+            // deferred credits
+            deferred_credits: execution_infos
+                .future_deferred_credits
+                .into_iter()
+                .map(|(slot, amount)| grpc::SlotAmount {
+                    slot: Some(slot.into()),
+                    amount: amount.to_raw(),
+                })
+                .collect(),
+
+            // selector info
+            next_block_draws: next_block_draws.into_iter().map(|s| s.into()).collect(),
+            next_endorsement_draws: next_endorsement_draws
+                .into_iter()
+                .map(|s| s.into())
+                .collect(),
+
+            // created objects
+            created_blocks: created_blocks
+                .into_iter()
+                .map(|id| id.to_string())
+                .collect(),
+            created_operations: created_operations
+                .into_iter()
+                .map(|id| id.to_string())
+                .collect(),
+            created_endorsements: created_endorsements
+                .into_iter()
+                .map(|id| id.to_string())
+                .collect(),
+
+            // cycle infos
+            cycle_infos: execution_infos
+                .cycle_infos
+                .into_iter()
+                .map(|info| info.into())
+                .collect(),
         });
+    }
 
-        if let Some(block) = res {
-            blocks.push(grpc::Block {
-                header: Some(block.0),
-                operations: block.1,
-            });
+    Ok(grpc::GetAddressesResponse {
+        id: inner_req.id,
+        addresses: res,
+    })
+}
+
+/// get blocks by ids, final blocks no longer in memory being looked up in the archive (if enabled)
+pub(crate) fn get_blocks(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetBlocksRequest>,
+) -> Result<grpc::GetBlocksResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    if inner_req.block_ids.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    let block_ids = inner_req
+        .block_ids
+        .iter()
+        .map(|id| BlockId::from_str(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let storage = grpc.storage.clone_without_refs();
+    let archive = grpc.consensus_channels.archive.as_ref();
+
+    let blocks = block_ids
+        .into_iter()
+        .filter_map(|id| {
+            // the storage lock is released before reading the archive
+            let in_memory = storage.read_blocks().get(&id).map(|b| b.content.clone());
+            let Some(content) = in_memory else {
+                // the block may have been pruned from memory after becoming final,
+                // final blocks are part of the blockclique
+                let archived_block = archive?.get_block(&id)?;
+                return Some(grpc::BlockInfo {
+                    block_id: id.to_string(),
+                    is_final: true,
+                    is_in_blockclique: true,
+                    is_candidate: false,
+                    is_discarded: false,
+                    block: Some(archived_block.content.into()),
+                });
+            };
+
+            let graph_status = grpc
+                .consensus_controller
+                .get_block_statuses(&[id])
+                .into_iter()
+                .next()?;
+
+            Some(grpc::BlockInfo {
+                block_id: id.to_string(),
+                is_final: graph_status == BlockGraphStatus::Final,
+                // final blocks are part of the blockclique
+                is_in_blockclique: graph_status == BlockGraphStatus::ActiveInBlockclique
+                    || graph_status == BlockGraphStatus::Final,
+                is_candidate: graph_status == BlockGraphStatus::ActiveInBlockclique
+                    || graph_status == BlockGraphStatus::ActiveInAlternativeCliques,
+                is_discarded: graph_status == BlockGraphStatus::Discarded,
+                block: Some(content.into()),
+            })
+        })
+        .collect();
+
+    Ok(grpc::GetBlocksResponse {
+        id: inner_req.id,
+        blocks,
+    })
+}
+
+/// get blocks by slots, final blocks no longer in memory being looked up in the archive (if enabled)
+pub(crate) fn get_blocks_by_slots(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetBlocksBySlotsRequest>,
+) -> Result<grpc::GetBlocksBySlotsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let storage = grpc.storage.clone_without_refs();
+    let archive = grpc.consensus_channels.archive.as_ref();
+
+    let mut blocks = Vec::new();
+
+    for slot in inner_req.slots.into_iter() {
+        let slot = to_slot(slot, grpc.grpc_config.thread_count)?;
+
+        let block = grpc
+            .consensus_controller
+            .get_blockclique_block_at_slot(slot)
+            .and_then(|block_id| {
+                storage
+                    .read_blocks()
+                    .get(&block_id)
+                    .map(|b| b.content.clone())
+            })
+            .or_else(|| archive?.get_block_by_slot(&slot).map(|b| b.content));
+
+        if let Some(block) = block {
+            blocks.push(block.into());
         }
     }
 
@@ -89,6 +677,24 @@ pub(crate) fn get_blocks_by_slots(
     })
 }
 
+/// get cliques
+pub(crate) fn get_cliques(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetCliquesRequest>,
+) -> Result<grpc::GetCliquesResponse, GrpcError> {
+    let cliques = grpc
+        .consensus_controller
+        .get_cliques()
+        .into_iter()
+        .map(|clique| clique.into())
+        .collect();
+
+    Ok(grpc::GetCliquesResponse {
+        id: request.into_inner().id,
+        cliques,
+    })
+}
+
 /// get multiple datastore entries
 pub(crate) fn get_datastore_entries(
     grpc: &MassaGrpc,
@@ -121,6 +727,233 @@ pub(crate) fn get_datastore_entries(
     Ok(grpc::GetDatastoreEntriesResponse { id, entries })
 }
 
+/// get endorsements, final endorsements no longer in memory being looked up in the archive (if enabled)
+pub(crate) fn get_endorsements(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetEndorsementsRequest>,
+) -> Result<grpc::GetEndorsementsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    if inner_req.endorsement_ids.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    let endorsement_ids = inner_req
+        .endorsement_ids
+        .iter()
+        .map(|id| EndorsementId::from_str(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let archive = grpc.consensus_channels.archive.as_ref();
+
+    // get the endorsements and the list of blocks that contain them from storage,
+    // falling back to the archive (if enabled) for final endorsements no longer in memory
+    let mut archived_eds: PreHashSet<EndorsementId> = PreHashSet::default();
+    let in_memory: Vec<(
+        EndorsementId,
+        Option<(SecureShareEndorsement, PreHashSet<BlockId>)>,
+    )> = {
+        let read_blocks = grpc.storage.read_blocks();
+        let read_endos = grpc.storage.read_endorsements();
+        endorsement_ids
+            .iter()
+            .map(|id| {
+                let info = read_endos.get(id).map(|ed| {
+                    (
+                        ed.clone(),
+                        read_blocks
+                            .get_blocks_by_endorsement(id)
+                            .cloned()
+                            .unwrap_or_default(),
+                    )
+                });
+                (*id, info)
+            })
+            .collect()
+    };
+    // the storage locks are released before reading the archive
+    let storage_info: Vec<(SecureShareEndorsement, PreHashSet<BlockId>)> = in_memory
+        .into_iter()
+        .filter_map(|(id, info)| {
+            if info.is_some() {
+                return info;
+            }
+            let (ed, block_id) = archive?.get_endorsement(&id)?;
+            archived_eds.insert(ed.id);
+            Some((ed, std::iter::once(block_id).collect()))
+        })
+        .collect();
+
+    // keep only the endorsements found
+    let eds: Vec<EndorsementId> = storage_info.iter().map(|(ed, _)| ed.id).collect();
+
+    // ask pool whether it carries the endorsements
+    let in_pool = grpc.pool_command_sender.contains_endorsements(&eds);
+
+    // check finality by cross-referencing Consensus and looking for final blocks that contain the endorsement
+    let is_final: Vec<bool> = {
+        let involved_blocks: Vec<BlockId> = storage_info
+            .iter()
+            .flat_map(|(_ed, bs)| bs.iter())
+            .unique()
+            .cloned()
+            .collect();
+
+        let involved_block_statuses = grpc
+            .consensus_controller
+            .get_block_statuses(&involved_blocks);
+
+        let block_statuses: PreHashMap<BlockId, BlockGraphStatus> = involved_blocks
+            .into_iter()
+            .zip(involved_block_statuses.into_iter())
+            .collect();
+        storage_info
+            .iter()
+            .map(|(ed, bs)| {
+                archived_eds.contains(&ed.id)
+                    || bs
+                        .iter()
+                        .any(|b| block_statuses.get(b) == Some(&BlockGraphStatus::Final))
+            })
+            .collect()
+    };
+
+    // gather all values into a vector of EndorsementInfo instances
+    let mut endorsements = Vec::with_capacity(eds.len());
+    let zipped_iterator = izip!(
+        eds.into_iter(),
+        storage_info.into_iter(),
+        in_pool.into_iter(),
+        is_final.into_iter()
+    );
+    for (id, (endorsement, in_blocks), in_pool, is_final) in zipped_iterator {
+        endorsements.push(grpc::EndorsementInfo {
+            endorsement_id: id.to_string(),
+            in_pool,
+            in_blocks: in_blocks.into_iter().map(|id| id.to_string()).collect(),
+            is_final,
+            endorsement: Some(endorsement.into()),
+        });
+    }
+
+    Ok(grpc::GetEndorsementsResponse {
+        id: inner_req.id,
+        endorsements,
+    })
+}
+
+/// get an interval of the block graph, with time filtering
+pub(crate) fn get_graph_interval(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetGraphIntervalRequest>,
+) -> Result<grpc::GetGraphIntervalResponse, GrpcError> {
+    let inner_req = request.into_inner();
+
+    // filter blocks from graph_export
+    let (start_slot, end_slot) = time_range_to_slot_range(
+        grpc.grpc_config.thread_count,
+        grpc.grpc_config.t0,
+        grpc.grpc_config.genesis_timestamp,
+        inner_req.start.map(MassaTime::from_millis),
+        inner_req.end.map(MassaTime::from_millis),
+    )?;
+
+    let graph = grpc
+        .consensus_controller
+        .get_block_graph_status(start_slot, end_slot)?;
+
+    let blockclique = graph
+        .max_cliques
+        .iter()
+        .find(|clique| clique.is_blockclique)
+        .ok_or_else(|| GrpcError::InternalServerError("missing blockclique".to_string()))?;
+
+    let mut block_summaries = Vec::with_capacity(graph.active_blocks.len());
+    for (id, exported_block) in graph.active_blocks.iter() {
+        block_summaries.push(grpc::BlockSummary {
+            block_id: id.to_string(),
+            is_final: exported_block.is_final,
+            is_stale: false,
+            is_in_blockclique: blockclique.block_ids.contains(id),
+            slot: Some(exported_block.header.content.slot.into()),
+            creator: exported_block.header.content_creator_address.to_string(),
+            parents: exported_block
+                .header
+                .content
+                .parents
+                .iter()
+                .map(|parent| parent.to_string())
+                .collect(),
+        });
+    }
+    for (id, (reason, (slot, creator, parents))) in graph.discarded_blocks.into_iter() {
+        if reason == DiscardReason::Stale {
+            block_summaries.push(grpc::BlockSummary {
+                block_id: id.to_string(),
+                is_final: false,
+                is_stale: true,
+                is_in_blockclique: false,
+                slot: Some(slot.into()),
+                creator: creator.to_string(),
+                parents: parents
+                    .into_iter()
+                    .map(|parent| parent.to_string())
+                    .collect(),
+            });
+        }
+    }
+
+    Ok(grpc::GetGraphIntervalResponse {
+        id: inner_req.id,
+        block_summaries,
+    })
+}
+
+/// get the final value of a ledger entry along with its proof against the final state hash
+pub(crate) fn get_ledger_proof(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetLedgerProofRequest>,
+) -> Result<grpc::GetLedgerProofResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let address = Address::from_str(&inner_req.address)?;
+    let key_type = match grpc::LedgerProofKeyType::from_i32(inner_req.key_type) {
+        Some(grpc::LedgerProofKeyType::Balance) => KeyType::BALANCE,
+        Some(grpc::LedgerProofKeyType::Bytecode) => KeyType::BYTECODE,
+        Some(grpc::LedgerProofKeyType::Datastore) => KeyType::DATASTORE(inner_req.datastore_key),
+        _ => {
+            return Err(GrpcError::InvalidArgument(
+                "unknown ledger proof key type".to_string(),
+            ))
+        }
+    };
+
+    let proof = grpc
+        .execution_controller
+        .get_final_ledger_proofs(vec![LedgerKey::new(&address, key_type)])
+        .pop()
+        .ok_or_else(|| GrpcError::InternalServerError("no ledger proof produced".to_string()))?;
+
+    Ok(grpc::GetLedgerProofResponse {
+        id: inner_req.id,
+        proof: Some(grpc::FinalLedgerProof {
+            slot: Some(proof.slot.into()),
+            value: proof.value,
+            siblings: proof
+                .ledger_proof
+                .siblings
+                .iter()
+                .map(|hash| hash.to_string())
+                .collect(),
+            leaf_key_hash: proof.ledger_proof.leaf.map(|(key, _)| key.to_string()),
+            leaf_value_hash: proof.ledger_proof.leaf.map(|(_, value)| value.to_string()),
+            state_hash_components: proof
+                .state_hash_components
+                .iter()
+                .map(|hash| hash.to_string())
+                .collect(),
+            final_state_hash: proof.final_state_hash.to_string(),
+        }),
+    })
+}
+
 /// get next block best parents
 pub(crate) fn get_next_block_best_parents(
     grpc: &MassaGrpc,
@@ -142,6 +975,239 @@ pub(crate) fn get_next_block_best_parents(
     })
 }
 
+/// get the call trace of a final smart contract operation
+pub(crate) fn get_operation_trace(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetOperationTraceRequest>,
+) -> Result<grpc::GetOperationTraceResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let operation_id = OperationId::from_str(&inner_req.operation_id)?;
+
+    Ok(grpc::GetOperationTraceResponse {
+        id: inner_req.id,
+        trace: grpc
+            .execution_controller
+            .get_operation_trace(&operation_id)
+            .map(to_call_trace),
+    })
+}
+
+/// get operations, final operations no longer in memory being looked up in the archive (if enabled)
+pub(crate) fn get_operations(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetOperationsRequest>,
+) -> Result<grpc::GetOperationsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    if inner_req.operation_ids.len() as u64 > grpc.grpc_config.max_arguments {
+        return Err(GrpcError::InvalidArgument("too many arguments".to_string()));
+    }
+
+    let operation_ids = inner_req
+        .operation_ids
+        .iter()
+        .map(|id| OperationId::from_str(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let archive = grpc.consensus_channels.archive.as_ref();
+
+    // get the operations and the list of blocks that contain them from storage,
+    // falling back to the archive (if enabled) for final operations no longer in memory
+    let mut archived_ops: PreHashSet<OperationId> = PreHashSet::default();
+    let in_memory: Vec<(
+        OperationId,
+        Option<(SecureShareOperation, PreHashSet<BlockId>)>,
+    )> = {
+        let read_blocks = grpc.storage.read_blocks();
+        let read_ops = grpc.storage.read_operations();
+        operation_ids
+            .iter()
+            .map(|id| {
+                let info = read_ops.get(id).map(|op| {
+                    (
+                        op.clone(),
+                        read_blocks
+                            .get_blocks_by_operation(id)
+                            .cloned()
+                            .unwrap_or_default(),
+                    )
+                });
+                (*id, info)
+            })
+            .collect()
+    };
+    // the storage locks are released before reading the archive
+    let storage_info: Vec<(SecureShareOperation, PreHashSet<BlockId>)> = in_memory
+        .into_iter()
+        .filter_map(|(id, info)| {
+            if info.is_some() {
+                return info;
+            }
+            let (op, block_id) = archive?.get_operation(&id)?;
+            archived_ops.insert(op.id);
+            Some((op, std::iter::once(block_id).collect()))
+        })
+        .collect();
+
+    // keep only the operations found
+    let ops: Vec<OperationId> = storage_info.iter().map(|(op, _)| op.id).collect();
+
+    // ask pool whether it carries the operations
+    let in_pool = grpc.pool_command_sender.contains_operations(&ops);
+
+    let (speculative_op_exec_statuses, final_op_exec_statuses) =
+        grpc.execution_controller.get_op_exec_status();
+
+    // gather all values into a vector of OperationInfo instances
+    let mut operations = Vec::with_capacity(ops.len());
+    let zipped_iterator = izip!(
+        ops.into_iter(),
+        storage_info.into_iter(),
+        in_pool.into_iter()
+    );
+    for (id, (operation, in_blocks), in_pool) in zipped_iterator {
+        // an operation is final if its status is in the final statuses or if it comes from the archive,
+        // in which case its status is no longer known
+        let (is_operation_final, op_exec_status) = match (
+            final_op_exec_statuses.get(&id),
+            speculative_op_exec_statuses.get(&id),
+        ) {
+            (Some(status), _) => (Some(true), Some(*status)),
+            (None, _) if archived_ops.contains(&id) => (Some(true), None),
+            (None, Some(status)) => (Some(false), Some(*status)),
+            (None, None) => (None, None),
+        };
+
+        operations.push(grpc::OperationInfo {
+            operation_id: id.to_string(),
+            in_blocks: in_blocks.into_iter().map(|id| id.to_string()).collect(),
+            in_pool,
+            is_operation_final,
+            thread: operation
+                .content_creator_address
+                .get_thread(grpc.grpc_config.thread_count) as u32,
+            operation: Some(operation.into()),
+            op_exec_status,
+        });
+    }
+
+    Ok(grpc::GetOperationsResponse {
+        id: inner_req.id,
+        operations,
+    })
+}
+
+/// get operations sent or received by an address
+pub(crate) fn get_operations_by_address(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetOperationsByAddressRequest>,
+) -> Result<grpc::GetOperationsByAddressResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let address = Address::from_str(inner_req.address.as_str())?;
+    let thread_count = grpc.grpc_config.thread_count;
+    let start = inner_req
+        .start_slot
+        .map(|slot| to_slot(slot, thread_count))
+        .transpose()?;
+    let end = inner_req
+        .end_slot
+        .map(|slot| to_slot(slot, thread_count))
+        .transpose()?;
+    // a single call returns at most max_arguments operations, which is also the default
+    let max_limit = grpc.grpc_config.max_arguments;
+    let limit = if inner_req.limit == 0 {
+        max_limit
+    } else {
+        inner_req.limit.min(max_limit)
+    };
+    let page_range = inner_req.offset..inner_req.offset.saturating_add(limit);
+
+    // the entries are counted while walking the index, only the requested page is kept
+    let archive = grpc.consensus_channels.archive.as_deref();
+    let mut total_count: u64 = 0;
+    let mut page: Vec<(Slot, OperationId)> = Vec::new();
+    for entry in
+        massa_archive::get_operations_by_address(&grpc.storage, archive, &address, start, end)
+    {
+        if page_range.contains(&total_count) {
+            page.push(entry);
+        }
+        total_count += 1;
+    }
+    let in_memory: Vec<(Slot, OperationId, Option<SecureShareOperation>)> = {
+        let read_ops = grpc.storage.read_operations();
+        page.into_iter()
+            .map(|(slot, op_id)| (slot, op_id, read_ops.get(&op_id).cloned()))
+            .collect()
+    };
+    // the storage lock is released before reading the archive
+    let operations = in_memory
+        .into_iter()
+        .filter_map(|(slot, op_id, operation)| {
+            let operation = operation.or_else(|| {
+                archive
+                    .and_then(|archive| archive.get_operation(&op_id))
+                    .map(|(operation, _)| operation)
+            })?;
+            Some(grpc::AddressOperation {
+                slot: Some(slot.into()),
+                operation: Some(operation.into()),
+            })
+        })
+        .collect();
+
+    Ok(grpc::GetOperationsByAddressResponse {
+        id: inner_req.id,
+        operations,
+        total_count,
+    })
+}
+
+/// get smart contract execution events, optionally filtered
+pub(crate) fn get_sc_execution_events(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetScExecutionEventsRequest>,
+) -> Result<grpc::GetScExecutionEventsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let filter = inner_req.filter.unwrap_or_default();
+
+    let thread_count = grpc.grpc_config.thread_count;
+    let event_filter = EventFilter {
+        start: filter
+            .start_slot
+            .map(|slot| to_slot(slot, thread_count))
+            .transpose()?,
+        end: filter
+            .end_slot
+            .map(|slot| to_slot(slot, thread_count))
+            .transpose()?,
+        emitter_address: filter
+            .emitter_address
+            .map(|address| Address::from_str(&address))
+            .transpose()?,
+        original_caller_address: filter
+            .original_caller_address
+            .map(|address| Address::from_str(&address))
+            .transpose()?,
+        original_operation_id: filter
+            .original_operation_id
+            .map(|id| OperationId::from_str(&id))
+            .transpose()?,
+        is_final: filter.is_final,
+        is_error: filter.is_error,
+    };
+
+    let events = grpc
+        .execution_controller
+        .get_filtered_sc_output_event(event_filter)
+        .into_iter()
+        .map(|event| event.into())
+        .collect();
+
+    Ok(grpc::GetScExecutionEventsResponse {
+        id: inner_req.id,
+        events,
+    })
+}
+
 pub(crate) fn get_selector_draws(
     grpc: &MassaGrpc,
     request: tonic::Request<grpc::GetSelectorDrawsRequest>,
@@ -216,6 +1282,136 @@ pub(crate) fn get_selector_draws(
     })
 }
 
+/// get the stakers of the current cycle, sorted by decreasing active roll count
+pub(crate) fn get_stakers(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetStakersRequest>,
+) -> Result<grpc::GetStakersResponse, GrpcError> {
+    let inner_req = request.into_inner();
+
+    let cur_cycle = timeslots::get_current_latest_block_slot(
+        grpc.grpc_config.thread_count,
+        grpc.grpc_config.t0,
+        grpc.grpc_config.genesis_timestamp,
+    )?
+    .unwrap_or_else(Slot::min)
+    .get_cycle(grpc.grpc_config.periods_per_cycle);
+
+    let mut stakers = grpc
+        .execution_controller
+        .get_cycle_active_rolls(cur_cycle)
+        .into_iter()
+        .collect::<Vec<(Address, u64)>>();
+    stakers.sort_by(|&(_, roll_counts_a), &(_, roll_counts_b)| roll_counts_b.cmp(&roll_counts_a));
+    let total_count = stakers.len() as u64;
+
+    let limit = if inner_req.limit == 0 {
+        usize::MAX
+    } else {
+        inner_req.limit as usize
+    };
+    let stakers = stakers
+        .into_iter()
+        .skip(inner_req.offset as usize)
+        .take(limit)
+        .map(|(address, rolls)| grpc::StakerEntry {
+            address: address.to_string(),
+            rolls,
+        })
+        .collect();
+
+    Ok(grpc::GetStakersResponse {
+        id: inner_req.id,
+        stakers,
+        total_count,
+    })
+}
+
+/// get the node status
+pub(crate) async fn get_status(
+    grpc: &MassaGrpc,
+    request: tonic::Request<grpc::GetStatusRequest>,
+) -> Result<grpc::GetStatusResponse, GrpcError> {
+    let config = &grpc.grpc_config;
+    let now = MassaTime::now()?;
+
+    let last_slot = get_latest_block_slot_at_timestamp(
+        config.thread_count,
+        config.t0,
+        config.genesis_timestamp,
+        now,
+    )?;
+    let next_slot = last_slot
+        .unwrap_or_else(Slot::min)
+        .get_next_slot(config.thread_count)?;
+
+    let execution_stats = grpc.execution_controller.get_stats();
+    let consensus_stats = grpc.consensus_controller.get_stats()?;
+
+    let (network_stats, peers) = tokio::join!(
+        grpc.network_command_sender.get_network_stats(),
+        grpc.network_command_sender.get_peers()
+    );
+    let network_stats = network_stats?;
+    let connected_nodes = peers?
+        .peers
+        .iter()
+        .flat_map(|(ip, peer)| {
+            peer.active_nodes
+                .iter()
+                .map(move |(id, is_outgoing)| (*id, (*ip, *is_outgoing)))
+        })
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(id, (ip, is_outgoing))| grpc::ConnectedNode {
+            node_id: id.to_string(),
+            node_ip: ip.to_string(),
+            is_outgoing,
+        })
+        .collect();
+
+    let current_cycle = last_slot
+        .unwrap_or_else(Slot::min)
+        .get_cycle(config.periods_per_cycle);
+    let cycle_duration = config.t0.checked_mul(config.periods_per_cycle)?;
+    let current_cycle_time = if current_cycle == 0 {
+        config.genesis_timestamp
+    } else {
+        cycle_duration
+            .checked_mul(current_cycle)
+            .and_then(|elapsed_time_before_current_cycle| {
+                config
+                    .genesis_timestamp
+                    .checked_add(elapsed_time_before_current_cycle)
+            })?
+    };
+    let next_cycle_time = current_cycle_time.checked_add(cycle_duration)?;
+
+    Ok(grpc::GetStatusResponse {
+        id: request.into_inner().id,
+        status: Some(grpc::NodeStatus {
+            node_id: grpc.node_id.to_string(),
+            node_ip: grpc.node_ip.map(|ip| ip.to_string()),
+            version: grpc.version.to_string(),
+            current_time: now.to_millis(),
+            current_cycle,
+            current_cycle_time: current_cycle_time.to_millis(),
+            next_cycle_time: next_cycle_time.to_millis(),
+            connected_nodes,
+            last_slot: last_slot.map(|slot| slot.into()),
+            next_slot: Some(next_slot.into()),
+            execution_stats: Some(execution_stats.into()),
+            consensus_stats: Some(consensus_stats.into()),
+            network_stats: Some(network_stats.into()),
+            pool_stats: Some(grpc::PoolStats {
+                operations_count: grpc.pool_command_sender.get_operation_count() as u64,
+                endorsements_count: grpc.pool_command_sender.get_endorsement_count() as u64,
+            }),
+            config: Some(CompactConfig::default().into()),
+        }),
+    })
+}
+
 /// get transactions throughput
 pub(crate) fn get_transactions_throughput(
     grpc: &MassaGrpc,
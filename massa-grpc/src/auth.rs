@@ -0,0 +1,71 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use futures_util::future::BoxFuture;
+use hyper::Body;
+use massa_api_exports::auth::AuthTokens;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tonic::body::BoxBody;
+use tonic::codegen::http::{header, Request, Response};
+use tonic::codegen::Service;
+use tonic::server::NamedService;
+
+/// gRPC service only forwarding the calls allowed by the token sent in the `authorization` metadata
+///
+/// Tokens are scoped by gRPC method name, e.g. `StopNode`.
+#[derive(Clone)]
+pub(crate) struct AuthService<S> {
+    inner: S,
+    tokens: Arc<AuthTokens>,
+}
+
+impl<S> AuthService<S> {
+    pub(crate) fn new(inner: S, tokens: AuthTokens) -> Self {
+        AuthService {
+            inner,
+            tokens: Arc::new(tokens),
+        }
+    }
+}
+
+impl<S> Service<Request<Body>> for AuthService<S>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let token = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| self.tokens.authenticate(value));
+        // paths are of the form `/massa.api.v1.MassaPrivateService/StopNode`
+        let method = request.uri().path().rsplit('/').next().unwrap_or_default();
+
+        let status = match token {
+            None => tonic::Status::unauthenticated("missing or invalid API token"),
+            Some(token) if !token.allows(method) => tonic::Status::permission_denied(format!(
+                "this API token is not allowed to call {}",
+                method
+            )),
+            Some(_) => return Box::pin(self.inner.call(request)),
+        };
+        Box::pin(async move { Ok(status.to_http()) })
+    }
+}
+
+impl<S: NamedService> NamedService for AuthService<S> {
+    const NAME: &'static str = S::NAME;
+}
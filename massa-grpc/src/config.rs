@@ -1,8 +1,9 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
+use massa_api_exports::rate_limit::RateLimitConfig;
 use massa_time::MassaTime;
 use serde::Deserialize;
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
 
 /// gRPC configuration.
 /// the gRPC configuration
@@ -10,14 +11,26 @@ use std::{net::SocketAddr, time::Duration};
 pub struct GrpcConfig {
     /// whether to enable gRPC
     pub enabled: bool,
-    /// whether to accept HTTP/1.1 requests
+    /// whether to accept HTTP/1.1 requests on the public API
     pub accept_http1: bool,
     /// whether to enable CORS. Works only if `accept_http1` is true
     pub enable_cors: bool,
-    /// whether to enable gRPC reflection
+    /// whether to enable gRPC reflection on the public API
     pub enable_reflection: bool,
     /// bind for the Massa gRPC API
     pub bind: SocketAddr,
+    /// bind for the Massa private gRPC API, used to manage the node
+    pub bind_private: SocketAddr,
+    /// path to the PEM certificate chain served over TLS. TLS is disabled when not set
+    pub tls_cert_path: Option<PathBuf>,
+    /// path to the PEM private key of the TLS certificate
+    pub tls_key_path: Option<PathBuf>,
+    /// path to the PEM CA certificates used to verify client certificates. Client certificates are not required when not set
+    pub tls_client_ca_path: Option<PathBuf>,
+    /// path to the JSON file listing the tokens allowed to call the private gRPC API. Authentication is disabled when not set
+    pub auth_tokens_path: Option<PathBuf>,
+    /// rate limiting of the public gRPC API clients
+    pub rate_limit: RateLimitConfig,
     /// which compression encodings does the server accept for requests
     pub accept_compressed: Option<String>,
     /// which compression encodings might the server use for responses
@@ -56,6 +69,8 @@ pub struct GrpcConfig {
     pub endorsement_count: u32,
     /// max endorsements per message
     pub max_endorsements_per_message: u32,
+    /// max denunciations in block header
+    pub max_denunciations_per_block_header: u32,
     /// max datastore value length
     pub max_datastore_value_length: u64,
     /// max op datastore entry
@@ -80,4 +95,12 @@ pub struct GrpcConfig {
     pub draw_lookahead_period_count: u64,
     /// last_start_period of the network, used to deserialize blocks
     pub last_start_period: u64,
+    /// max number of arguments per request
+    pub max_arguments: u64,
+    /// periods per cycle
+    pub periods_per_cycle: u64,
+    /// bootstrap whitelist path
+    pub bootstrap_whitelist_path: PathBuf,
+    /// bootstrap blacklist path
+    pub bootstrap_blacklist_path: PathBuf,
 }
@@ -39,6 +39,10 @@ pub enum GrpcError {
     InternalServerError(String),
     /// Invalid argument error: {0}
     InvalidArgument(String),
+    /// Transport error: {0}
+    TransportError(#[from] tonic::transport::Error),
+    /// IO error: {0}
+    IoError(#[from] std::io::Error),
 }
 
 impl From<GrpcError> for tonic::Status {
@@ -55,6 +59,8 @@ impl From<GrpcError> for tonic::Status {
             GrpcError::InternalServerError(e) => tonic::Status::internal(e),
             GrpcError::ReflectionError(e) => tonic::Status::internal(e.to_string()),
             GrpcError::InvalidArgument(e) => tonic::Status::invalid_argument(e),
+            GrpcError::TransportError(e) => tonic::Status::internal(e.to_string()),
+            GrpcError::IoError(e) => tonic::Status::internal(e.to_string()),
         }
     }
 }
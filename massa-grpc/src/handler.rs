@@ -3,14 +3,25 @@
 use massa_proto::massa::api::v1 as grpc;
 
 use crate::api::{
-    get_blocks_by_slots, get_datastore_entries, get_next_block_best_parents, get_selector_draws,
-    get_transactions_throughput, get_version,
+    estimate_operation, execute_read_only_bytecode, execute_read_only_call, get_addresses,
+    get_blocks, get_blocks_by_slots, get_cliques, get_datastore_entries, get_endorsements,
+    get_graph_interval, get_ledger_proof, get_next_block_best_parents, get_operation_trace,
+    get_operations, get_operations_by_address, get_sc_execution_events, get_selector_draws,
+    get_stakers, get_status, get_transactions_throughput, get_version,
 };
-use crate::server::MassaGrpc;
+use crate::private::{
+    add_staking_secret_keys, add_to_bootstrap_blacklist, add_to_bootstrap_whitelist,
+    add_to_peers_whitelist, allow_everyone_to_bootstrap, ban_nodes_by_ids, ban_nodes_by_ips,
+    get_bootstrap_blacklist, get_bootstrap_whitelist, get_peers_whitelist, get_staking_addresses,
+    remove_from_bootstrap_blacklist, remove_from_bootstrap_whitelist, remove_from_peers_whitelist,
+    remove_staking_addresses, sign_message, stop_node, unban_nodes_by_ids, unban_nodes_by_ips,
+};
+use crate::server::{MassaGrpc, MassaPrivateGrpc};
 use crate::stream::new_blocks::{new_blocks, NewBlocksStream};
 use crate::stream::new_blocks_headers::{new_blocks_headers, NewBlocksHeadersStream};
 use crate::stream::new_filled_blocks::{new_filled_blocks, NewFilledBlocksStream};
 use crate::stream::new_operations::{new_operations, NewOperationsStream};
+use crate::stream::new_sc_events::{new_sc_events, NewScEventsStream};
 use crate::stream::tx_throughput::{transactions_throughput, TransactionsThroughputStream};
 use crate::stream::{
     send_blocks::{send_blocks, SendBlocksStream},
@@ -20,6 +31,17 @@ use crate::stream::{
 
 #[tonic::async_trait]
 impl grpc::massa_service_server::MassaService for MassaGrpc {
+    /// handler for estimate operation
+    async fn estimate_operation(
+        &self,
+        request: tonic::Request<grpc::EstimateOperationRequest>,
+    ) -> Result<tonic::Response<grpc::EstimateOperationResponse>, tonic::Status> {
+        match estimate_operation(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// handler for get multiple datastore entries.
     async fn get_datastore_entries(
         &self,
@@ -31,6 +53,17 @@ impl grpc::massa_service_server::MassaService for MassaGrpc {
         }
     }
 
+    /// handler for get operations by address
+    async fn get_operations_by_address(
+        &self,
+        request: tonic::Request<grpc::GetOperationsByAddressRequest>,
+    ) -> Result<tonic::Response<grpc::GetOperationsByAddressResponse>, tonic::Status> {
+        match get_operations_by_address(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// handler for get selector draws
     async fn get_selector_draws(
         &self,
@@ -73,6 +106,149 @@ impl grpc::massa_service_server::MassaService for MassaGrpc {
         }
     }
 
+    /// handler for execute read only bytecode
+    async fn execute_read_only_bytecode(
+        &self,
+        request: tonic::Request<grpc::ExecuteReadOnlyBytecodeRequest>,
+    ) -> Result<tonic::Response<grpc::ExecuteReadOnlyBytecodeResponse>, tonic::Status> {
+        match execute_read_only_bytecode(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for execute read only call
+    async fn execute_read_only_call(
+        &self,
+        request: tonic::Request<grpc::ExecuteReadOnlyCallRequest>,
+    ) -> Result<tonic::Response<grpc::ExecuteReadOnlyCallResponse>, tonic::Status> {
+        match execute_read_only_call(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get addresses
+    async fn get_addresses(
+        &self,
+        request: tonic::Request<grpc::GetAddressesRequest>,
+    ) -> Result<tonic::Response<grpc::GetAddressesResponse>, tonic::Status> {
+        match get_addresses(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get blocks
+    async fn get_blocks(
+        &self,
+        request: tonic::Request<grpc::GetBlocksRequest>,
+    ) -> Result<tonic::Response<grpc::GetBlocksResponse>, tonic::Status> {
+        match get_blocks(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get cliques
+    async fn get_cliques(
+        &self,
+        request: tonic::Request<grpc::GetCliquesRequest>,
+    ) -> Result<tonic::Response<grpc::GetCliquesResponse>, tonic::Status> {
+        match get_cliques(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get endorsements
+    async fn get_endorsements(
+        &self,
+        request: tonic::Request<grpc::GetEndorsementsRequest>,
+    ) -> Result<tonic::Response<grpc::GetEndorsementsResponse>, tonic::Status> {
+        match get_endorsements(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get graph interval
+    async fn get_graph_interval(
+        &self,
+        request: tonic::Request<grpc::GetGraphIntervalRequest>,
+    ) -> Result<tonic::Response<grpc::GetGraphIntervalResponse>, tonic::Status> {
+        match get_graph_interval(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get ledger proof
+    async fn get_ledger_proof(
+        &self,
+        request: tonic::Request<grpc::GetLedgerProofRequest>,
+    ) -> Result<tonic::Response<grpc::GetLedgerProofResponse>, tonic::Status> {
+        match get_ledger_proof(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get operation trace
+    async fn get_operation_trace(
+        &self,
+        request: tonic::Request<grpc::GetOperationTraceRequest>,
+    ) -> Result<tonic::Response<grpc::GetOperationTraceResponse>, tonic::Status> {
+        match get_operation_trace(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get operations
+    async fn get_operations(
+        &self,
+        request: tonic::Request<grpc::GetOperationsRequest>,
+    ) -> Result<tonic::Response<grpc::GetOperationsResponse>, tonic::Status> {
+        match get_operations(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get smart contract execution events
+    async fn get_sc_execution_events(
+        &self,
+        request: tonic::Request<grpc::GetScExecutionEventsRequest>,
+    ) -> Result<tonic::Response<grpc::GetScExecutionEventsResponse>, tonic::Status> {
+        match get_sc_execution_events(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get stakers
+    async fn get_stakers(
+        &self,
+        request: tonic::Request<grpc::GetStakersRequest>,
+    ) -> Result<tonic::Response<grpc::GetStakersResponse>, tonic::Status> {
+        match get_stakers(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get status
+    async fn get_status(
+        &self,
+        request: tonic::Request<grpc::GetStatusRequest>,
+    ) -> Result<tonic::Response<grpc::GetStatusResponse>, tonic::Status> {
+        match get_status(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// handler for get version
     async fn get_version(
         &self,
@@ -151,6 +327,19 @@ impl grpc::massa_service_server::MassaService for MassaGrpc {
         }
     }
 
+    type NewScEventsStream = NewScEventsStream;
+
+    /// handler for subscribe new smart contract events stream
+    async fn new_sc_events(
+        &self,
+        request: tonic::Request<tonic::Streaming<grpc::NewScEventsRequest>>,
+    ) -> Result<tonic::Response<Self::NewScEventsStream>, tonic::Status> {
+        match new_sc_events(self, request).await {
+            Ok(res) => Ok(tonic::Response::new(res)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     type NewBlocksStream = NewBlocksStream;
 
     /// handler for subscribe new blocks
@@ -190,3 +379,215 @@ impl grpc::massa_service_server::MassaService for MassaGrpc {
         }
     }
 }
+
+#[tonic::async_trait]
+impl grpc::massa_private_service_server::MassaPrivateService for MassaPrivateGrpc {
+    /// handler for add staking secret keys
+    async fn add_staking_secret_keys(
+        &self,
+        request: tonic::Request<grpc::AddStakingSecretKeysRequest>,
+    ) -> Result<tonic::Response<grpc::AddStakingSecretKeysResponse>, tonic::Status> {
+        match add_staking_secret_keys(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for add to bootstrap blacklist
+    async fn add_to_bootstrap_blacklist(
+        &self,
+        request: tonic::Request<grpc::AddToBootstrapBlacklistRequest>,
+    ) -> Result<tonic::Response<grpc::AddToBootstrapBlacklistResponse>, tonic::Status> {
+        match add_to_bootstrap_blacklist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for add to bootstrap whitelist
+    async fn add_to_bootstrap_whitelist(
+        &self,
+        request: tonic::Request<grpc::AddToBootstrapWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::AddToBootstrapWhitelistResponse>, tonic::Status> {
+        match add_to_bootstrap_whitelist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for add to peers whitelist
+    async fn add_to_peers_whitelist(
+        &self,
+        request: tonic::Request<grpc::AddToPeersWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::AddToPeersWhitelistResponse>, tonic::Status> {
+        match add_to_peers_whitelist(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for allow everyone to bootstrap
+    async fn allow_everyone_to_bootstrap(
+        &self,
+        request: tonic::Request<grpc::AllowEveryoneToBootstrapRequest>,
+    ) -> Result<tonic::Response<grpc::AllowEveryoneToBootstrapResponse>, tonic::Status> {
+        match allow_everyone_to_bootstrap(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for ban nodes by ids
+    async fn ban_nodes_by_ids(
+        &self,
+        request: tonic::Request<grpc::BanNodesByIdsRequest>,
+    ) -> Result<tonic::Response<grpc::BanNodesByIdsResponse>, tonic::Status> {
+        match ban_nodes_by_ids(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for ban nodes by ips
+    async fn ban_nodes_by_ips(
+        &self,
+        request: tonic::Request<grpc::BanNodesByIpsRequest>,
+    ) -> Result<tonic::Response<grpc::BanNodesByIpsResponse>, tonic::Status> {
+        match ban_nodes_by_ips(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get bootstrap blacklist
+    async fn get_bootstrap_blacklist(
+        &self,
+        request: tonic::Request<grpc::GetBootstrapBlacklistRequest>,
+    ) -> Result<tonic::Response<grpc::GetBootstrapBlacklistResponse>, tonic::Status> {
+        match get_bootstrap_blacklist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get bootstrap whitelist
+    async fn get_bootstrap_whitelist(
+        &self,
+        request: tonic::Request<grpc::GetBootstrapWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::GetBootstrapWhitelistResponse>, tonic::Status> {
+        match get_bootstrap_whitelist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get peers whitelist
+    async fn get_peers_whitelist(
+        &self,
+        request: tonic::Request<grpc::GetPeersWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::GetPeersWhitelistResponse>, tonic::Status> {
+        match get_peers_whitelist(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for get staking addresses
+    async fn get_staking_addresses(
+        &self,
+        request: tonic::Request<grpc::GetStakingAddressesRequest>,
+    ) -> Result<tonic::Response<grpc::GetStakingAddressesResponse>, tonic::Status> {
+        match get_staking_addresses(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for remove from bootstrap blacklist
+    async fn remove_from_bootstrap_blacklist(
+        &self,
+        request: tonic::Request<grpc::RemoveFromBootstrapBlacklistRequest>,
+    ) -> Result<tonic::Response<grpc::RemoveFromBootstrapBlacklistResponse>, tonic::Status> {
+        match remove_from_bootstrap_blacklist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for remove from bootstrap whitelist
+    async fn remove_from_bootstrap_whitelist(
+        &self,
+        request: tonic::Request<grpc::RemoveFromBootstrapWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::RemoveFromBootstrapWhitelistResponse>, tonic::Status> {
+        match remove_from_bootstrap_whitelist(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for remove from peers whitelist
+    async fn remove_from_peers_whitelist(
+        &self,
+        request: tonic::Request<grpc::RemoveFromPeersWhitelistRequest>,
+    ) -> Result<tonic::Response<grpc::RemoveFromPeersWhitelistResponse>, tonic::Status> {
+        match remove_from_peers_whitelist(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for remove staking addresses
+    async fn remove_staking_addresses(
+        &self,
+        request: tonic::Request<grpc::RemoveStakingAddressesRequest>,
+    ) -> Result<tonic::Response<grpc::RemoveStakingAddressesResponse>, tonic::Status> {
+        match remove_staking_addresses(self, request) {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for sign message
+    async fn sign_message(
+        &self,
+        request: tonic::Request<grpc::SignMessageRequest>,
+    ) -> Result<tonic::Response<grpc::SignMessageResponse>, tonic::Status> {
+        match sign_message(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for stop node
+    async fn stop_node(
+        &self,
+        request: tonic::Request<grpc::StopNodeRequest>,
+    ) -> Result<tonic::Response<grpc::StopNodeResponse>, tonic::Status> {
+        match stop_node(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for unban nodes by ids
+    async fn unban_nodes_by_ids(
+        &self,
+        request: tonic::Request<grpc::UnbanNodesByIdsRequest>,
+    ) -> Result<tonic::Response<grpc::UnbanNodesByIdsResponse>, tonic::Status> {
+        match unban_nodes_by_ids(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// handler for unban nodes by ips
+    async fn unban_nodes_by_ips(
+        &self,
+        request: tonic::Request<grpc::UnbanNodesByIpsRequest>,
+    ) -> Result<tonic::Response<grpc::UnbanNodesByIpsResponse>, tonic::Status> {
+        match unban_nodes_by_ips(self, request).await {
+            Ok(response) => Ok(tonic::Response::new(response)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -8,7 +8,10 @@
 //! ## **Structure**
 //!
 //! * `api.rs`: implements gRPC service methods without streams.
+//! * `auth.rs`: checks the API tokens of the calls to the private gRPC service.
 //! * `handler.rs`: defines the logic for handling incoming gRPC requests.
+//! * `private.rs`: implements the private gRPC service methods, used to manage the node.
+//! * `rate_limit.rs`: charges the calls to the public gRPC service to the budget of their client.
 //! * `server`: initializes the gRPC service and serve It.
 //! * `stream/`: contains the gRPC streaming methods implementations files.
 
@@ -22,12 +25,18 @@ use tonic_web as _;
 
 /// business code for non stream methods
 pub mod api;
+/// token authentication of the private methods
+mod auth;
 /// gRPC configuration
 pub mod config;
 /// models error
 pub mod error;
 /// gRPC API implementation
 pub mod handler;
+/// business code for private methods
+pub mod private;
+/// rate limiting of the public methods
+mod rate_limit;
 /// gRPC service initialization and serve
 pub mod server;
 /// business code for stream methods
@@ -0,0 +1,435 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use crate::error::GrpcError;
+use crate::server::MassaPrivateGrpc;
+use itertools::Itertools;
+use massa_models::address::Address;
+use massa_models::node::NodeId;
+use massa_proto::massa::api::v1 as grpc;
+use massa_signature::KeyPair;
+use massa_wallet::{Wallet, WalletError};
+use parking_lot::RwLock;
+use std::collections::BTreeSet;
+use std::fs::{remove_file, OpenOptions};
+use std::net::IpAddr;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// add staking secret keys to the node wallet
+pub(crate) fn add_staking_secret_keys(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::AddStakingSecretKeysRequest>,
+) -> Result<grpc::AddStakingSecretKeysResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let keypairs = inner_req
+        .secret_keys
+        .iter()
+        .map(|key| KeyPair::from_str(key))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| GrpcError::InvalidArgument(e.to_string()))?;
+
+    get_node_wallet(grpc)?.write().add_keypairs(keypairs)?;
+
+    Ok(grpc::AddStakingSecretKeysResponse { id: inner_req.id })
+}
+
+/// add IP addresses to the bootstrap blacklist
+pub(crate) fn add_to_bootstrap_blacklist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::AddToBootstrapBlacklistRequest>,
+) -> Result<grpc::AddToBootstrapBlacklistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    add_to_bootstrap_list(
+        &grpc.grpc_config.bootstrap_blacklist_path,
+        parse_ips(&inner_req.ips)?,
+        "blacklist",
+    )?;
+
+    Ok(grpc::AddToBootstrapBlacklistResponse { id: inner_req.id })
+}
+
+/// add IP addresses to the bootstrap whitelist
+pub(crate) fn add_to_bootstrap_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::AddToBootstrapWhitelistRequest>,
+) -> Result<grpc::AddToBootstrapWhitelistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    add_to_bootstrap_list(
+        &grpc.grpc_config.bootstrap_whitelist_path,
+        parse_ips(&inner_req.ips)?,
+        "whitelist",
+    )?;
+
+    Ok(grpc::AddToBootstrapWhitelistResponse { id: inner_req.id })
+}
+
+/// add IP addresses to the peers whitelist
+pub(crate) async fn add_to_peers_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::AddToPeersWhitelistRequest>,
+) -> Result<grpc::AddToPeersWhitelistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .add_to_whitelist(parse_ips(&inner_req.ips)?)
+        .await?;
+
+    Ok(grpc::AddToPeersWhitelistResponse { id: inner_req.id })
+}
+
+/// allow everyone to bootstrap from the node by removing the bootstrap whitelist
+pub(crate) fn allow_everyone_to_bootstrap(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::AllowEveryoneToBootstrapRequest>,
+) -> Result<grpc::AllowEveryoneToBootstrapResponse, GrpcError> {
+    remove_file(&grpc.grpc_config.bootstrap_whitelist_path).map_err(|e| {
+        GrpcError::InternalServerError(format!(
+            "failed to delete bootstrap whitelist configuration file: {}",
+            e
+        ))
+    })?;
+
+    Ok(grpc::AllowEveryoneToBootstrapResponse {
+        id: request.into_inner().id,
+    })
+}
+
+/// ban nodes by their ids
+pub(crate) async fn ban_nodes_by_ids(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::BanNodesByIdsRequest>,
+) -> Result<grpc::BanNodesByIdsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .node_ban_by_ids(parse_node_ids(&inner_req.node_ids)?)
+        .await?;
+
+    Ok(grpc::BanNodesByIdsResponse { id: inner_req.id })
+}
+
+/// ban nodes by their IP addresses
+pub(crate) async fn ban_nodes_by_ips(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::BanNodesByIpsRequest>,
+) -> Result<grpc::BanNodesByIpsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .node_ban_by_ips(parse_ips(&inner_req.ips)?)
+        .await?;
+
+    Ok(grpc::BanNodesByIpsResponse { id: inner_req.id })
+}
+
+/// get the bootstrap blacklist
+pub(crate) fn get_bootstrap_blacklist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::GetBootstrapBlacklistRequest>,
+) -> Result<grpc::GetBootstrapBlacklistResponse, GrpcError> {
+    let ips = read_ips_from_jsonfile(&grpc.grpc_config.bootstrap_blacklist_path, "blacklist")?;
+
+    Ok(grpc::GetBootstrapBlacklistResponse {
+        id: request.into_inner().id,
+        ips: ips.into_iter().map(|ip| ip.to_string()).collect(),
+    })
+}
+
+/// get the bootstrap whitelist
+pub(crate) fn get_bootstrap_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::GetBootstrapWhitelistRequest>,
+) -> Result<grpc::GetBootstrapWhitelistResponse, GrpcError> {
+    let ips = read_ips_from_jsonfile(&grpc.grpc_config.bootstrap_whitelist_path, "whitelist")?;
+
+    Ok(grpc::GetBootstrapWhitelistResponse {
+        id: request.into_inner().id,
+        ips: ips.into_iter().map(|ip| ip.to_string()).collect(),
+    })
+}
+
+/// get the peers whitelist
+pub(crate) async fn get_peers_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::GetPeersWhitelistRequest>,
+) -> Result<grpc::GetPeersWhitelistResponse, GrpcError> {
+    let peers = grpc.network_command_sender.get_peers().await?;
+
+    Ok(grpc::GetPeersWhitelistResponse {
+        id: request.into_inner().id,
+        ips: peers
+            .peers
+            .into_keys()
+            .sorted()
+            .map(|ip| ip.to_string())
+            .collect(),
+    })
+}
+
+/// get the staking addresses of the node wallet
+pub(crate) fn get_staking_addresses(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::GetStakingAddressesRequest>,
+) -> Result<grpc::GetStakingAddressesResponse, GrpcError> {
+    let addresses = get_node_wallet(grpc)?.read().get_wallet_address_list();
+
+    Ok(grpc::GetStakingAddressesResponse {
+        id: request.into_inner().id,
+        addresses: addresses
+            .into_iter()
+            .map(|address| address.to_string())
+            .collect(),
+    })
+}
+
+/// remove IP addresses from the bootstrap blacklist
+pub(crate) fn remove_from_bootstrap_blacklist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::RemoveFromBootstrapBlacklistRequest>,
+) -> Result<grpc::RemoveFromBootstrapBlacklistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    remove_from_bootstrap_list(
+        &grpc.grpc_config.bootstrap_blacklist_path,
+        parse_ips(&inner_req.ips)?,
+        "blacklist",
+    )?;
+
+    Ok(grpc::RemoveFromBootstrapBlacklistResponse { id: inner_req.id })
+}
+
+/// remove IP addresses from the bootstrap whitelist
+pub(crate) fn remove_from_bootstrap_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::RemoveFromBootstrapWhitelistRequest>,
+) -> Result<grpc::RemoveFromBootstrapWhitelistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    remove_from_bootstrap_list(
+        &grpc.grpc_config.bootstrap_whitelist_path,
+        parse_ips(&inner_req.ips)?,
+        "whitelist",
+    )?;
+
+    Ok(grpc::RemoveFromBootstrapWhitelistResponse { id: inner_req.id })
+}
+
+/// remove IP addresses from the peers whitelist
+pub(crate) async fn remove_from_peers_whitelist(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::RemoveFromPeersWhitelistRequest>,
+) -> Result<grpc::RemoveFromPeersWhitelistResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .remove_from_whitelist(parse_ips(&inner_req.ips)?)
+        .await?;
+
+    Ok(grpc::RemoveFromPeersWhitelistResponse { id: inner_req.id })
+}
+
+/// remove addresses from the node wallet
+pub(crate) fn remove_staking_addresses(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::RemoveStakingAddressesRequest>,
+) -> Result<grpc::RemoveStakingAddressesResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let addresses = inner_req
+        .addresses
+        .iter()
+        .map(|address| Address::from_str(address))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    get_node_wallet(grpc)?
+        .write()
+        .remove_addresses(&addresses)?;
+
+    Ok(grpc::RemoveStakingAddressesResponse { id: inner_req.id })
+}
+
+/// sign a message with the node keypair
+pub(crate) async fn sign_message(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::SignMessageRequest>,
+) -> Result<grpc::SignMessageResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    let signed = grpc
+        .network_command_sender
+        .node_sign_message(inner_req.message)
+        .await?;
+
+    Ok(grpc::SignMessageResponse {
+        id: inner_req.id,
+        public_key: signed.public_key.to_string(),
+        signature: signed.signature.to_string(),
+    })
+}
+
+/// ask the node to stop
+pub(crate) async fn stop_node(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::StopNodeRequest>,
+) -> Result<grpc::StopNodeResponse, GrpcError> {
+    grpc.stop_node_channel
+        .send(())
+        .await
+        .map_err(|e| GrpcError::InternalServerError(format!("error sending stop signal {}", e)))?;
+
+    Ok(grpc::StopNodeResponse {
+        id: request.into_inner().id,
+    })
+}
+
+/// unban nodes by their ids
+pub(crate) async fn unban_nodes_by_ids(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::UnbanNodesByIdsRequest>,
+) -> Result<grpc::UnbanNodesByIdsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .node_unban_by_ids(parse_node_ids(&inner_req.node_ids)?)
+        .await?;
+
+    Ok(grpc::UnbanNodesByIdsResponse { id: inner_req.id })
+}
+
+/// unban nodes by their IP addresses
+pub(crate) async fn unban_nodes_by_ips(
+    grpc: &MassaPrivateGrpc,
+    request: tonic::Request<grpc::UnbanNodesByIpsRequest>,
+) -> Result<grpc::UnbanNodesByIpsResponse, GrpcError> {
+    let inner_req = request.into_inner();
+    grpc.network_command_sender
+        .node_unban_ips(parse_ips(&inner_req.ips)?)
+        .await?;
+
+    Ok(grpc::UnbanNodesByIpsResponse { id: inner_req.id })
+}
+
+/// Parses a list of IP addresses
+fn parse_ips(ips: &[String]) -> Result<Vec<IpAddr>, GrpcError> {
+    ips.iter()
+        .map(|ip| {
+            IpAddr::from_str(ip)
+                .map_err(|e| GrpcError::InvalidArgument(format!("invalid IP {}: {}", ip, e)))
+        })
+        .collect()
+}
+
+/// Parses a list of node ids
+fn parse_node_ids(ids: &[String]) -> Result<Vec<NodeId>, GrpcError> {
+    ids.iter()
+        .map(|id| NodeId::from_str(id).map_err(|e| e.into()))
+        .collect()
+}
+
+/// Adds IP addresses to a bootstrap list file, creating it if needed
+fn add_to_bootstrap_list(
+    bootstrap_list_file: &Path,
+    ips: Vec<IpAddr>,
+    list_type: &str,
+) -> Result<(), GrpcError> {
+    let mut list_ips = if get_file_len(bootstrap_list_file, list_type, true)? == 0 {
+        BTreeSet::new()
+    } else {
+        read_ips_from_jsonfile(bootstrap_list_file, list_type)?
+    };
+    list_ips.extend(ips);
+    write_ips_to_jsonfile(bootstrap_list_file, list_ips, list_type)
+}
+
+/// Removes IP addresses from an existing bootstrap list file
+fn remove_from_bootstrap_list(
+    bootstrap_list_file: &Path,
+    ips: Vec<IpAddr>,
+    list_type: &str,
+) -> Result<(), GrpcError> {
+    if get_file_len(bootstrap_list_file, list_type, false)? == 0 {
+        return Err(GrpcError::InternalServerError(format!(
+            "failed, bootstrap {} configuration file is empty",
+            list_type
+        )));
+    }
+    let mut list_ips = read_ips_from_jsonfile(bootstrap_list_file, list_type)?;
+    if list_ips.is_empty() {
+        return Err(GrpcError::InternalServerError(format!(
+            "failed to execute delete operation, bootstrap {} is empty",
+            list_type
+        )));
+    }
+    for ip in ips {
+        list_ips.remove(&ip);
+    }
+    write_ips_to_jsonfile(bootstrap_list_file, list_ips, list_type)
+}
+
+/// Get length of the given file if it exists(or create it if requested)
+fn get_file_len(
+    bootstrap_list_file: &Path,
+    list_type: &str,
+    create: bool,
+) -> Result<u64, GrpcError> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(create)
+        .open(bootstrap_list_file)
+        .map_err(|e| {
+            GrpcError::InternalServerError(format!(
+                "failed to read bootstrap {} configuration file: {}",
+                list_type, e
+            ))
+        })?;
+    file.metadata().map(|metadata| metadata.len()).map_err(|e| {
+        GrpcError::InternalServerError(format!(
+            "failed to read bootstrap {} configuration file metadata: {}",
+            list_type, e
+        ))
+    })
+}
+
+/// Read bootstrap list IP(s) from json file
+fn read_ips_from_jsonfile(
+    bootstrap_list_file: &Path,
+    list_type: &str,
+) -> Result<BTreeSet<IpAddr>, GrpcError> {
+    let bootstrap_list_str = std::fs::read_to_string(bootstrap_list_file).map_err(|e| {
+        GrpcError::InternalServerError(format!(
+            "failed to read bootstrap {} configuration file: {}",
+            list_type, e
+        ))
+    })?;
+    serde_json::from_str(&bootstrap_list_str).map_err(|e| {
+        GrpcError::InternalServerError(format!(
+            "failed to parse bootstrap {} configuration file: {}",
+            list_type, e
+        ))
+    })
+}
+
+/// Write bootstrap list IP(s) to json file
+fn write_ips_to_jsonfile(
+    bootstrap_list_file: &Path,
+    ips: BTreeSet<IpAddr>,
+    list_type: &str,
+) -> Result<(), GrpcError> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(bootstrap_list_file)
+        .map_err(|e| {
+            GrpcError::InternalServerError(format!(
+                "failed to create bootstrap {} configuration file: {}",
+                list_type, e
+            ))
+        })?;
+    serde_json::to_writer_pretty(file, &ips).map_err(|e| {
+        GrpcError::InternalServerError(format!(
+            "failed to write bootstrap {} configuration file: {}",
+            list_type, e
+        ))
+    })
+}
+
+/// get the staking wallet of the node, that is not loaded when the staking keys are held by an external signer
+fn get_node_wallet(grpc: &MassaPrivateGrpc) -> Result<Arc<RwLock<Wallet>>, GrpcError> {
+    grpc.node_wallet
+        .clone()
+        .ok_or(GrpcError::WalletError(WalletError::KeysHeldByRemoteSigner))
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use futures_util::future::BoxFuture;
+use hyper::Body;
+use massa_api_exports::auth::AuthTokens;
+use massa_api_exports::rate_limit::{forwarded_ip_client_id, ip_client_id, RateLimiter};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tonic::body::BoxBody;
+use tonic::codegen::http::{header, Request, Response};
+use tonic::codegen::Service;
+use tonic::server::NamedService;
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+
+/// client of a rate limited call, stored in the request extensions to charge the work done by the call
+#[derive(Clone)]
+pub(crate) struct RateLimitedClient {
+    limiter: Arc<RateLimiter>,
+    client: String,
+}
+
+impl RateLimitedClient {
+    /// charge the gas used by the read-only executions of the call
+    pub(crate) fn charge_gas(&self, gas: u64) {
+        self.limiter.charge_gas(&self.client, gas);
+    }
+}
+
+/// gRPC service rejecting the calls of the clients that exceeded their budget
+///
+/// Clients are identified by their API token if it is a known one, then by the IP forwarded by a trusted reverse proxy,
+/// then by their remote IP. Calls are charged the cost of their method, e.g. `ExecuteReadOnlyCall`.
+#[derive(Clone)]
+pub(crate) struct RateLimitService<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+    tokens: Arc<AuthTokens>,
+}
+
+impl<S> RateLimitService<S> {
+    pub(crate) fn new(inner: S, limiter: RateLimiter, tokens: Option<AuthTokens>) -> Self {
+        RateLimitService {
+            inner,
+            limiter: Arc::new(limiter),
+            tokens: Arc::new(tokens.unwrap_or_default()),
+        }
+    }
+
+    /// identify the client sending a request
+    fn client_id(&self, request: &Request<Body>) -> String {
+        let headers = request.headers();
+        if let Some(token) = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| self.tokens.authenticate(value))
+        {
+            return format!("token:{}", token.token);
+        }
+        // proxies append the IP they forward for to the list, so only the last entry was set by the trusted proxy
+        if let Some(ip) = self
+            .limiter
+            .config()
+            .client_ip_header
+            .as_ref()
+            .and_then(|name| headers.get_all(name.as_str()).iter().last())
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+        {
+            return forwarded_ip_client_id(ip);
+        }
+        remote_addr(request)
+            .map(|addr| ip_client_id(addr.ip()))
+            .unwrap_or_else(|| "anonymous".to_string())
+    }
+}
+
+/// remote address of the connection of a request
+fn remote_addr(request: &Request<Body>) -> Option<SocketAddr> {
+    let extensions = request.extensions();
+    extensions
+        .get::<TcpConnectInfo>()
+        .and_then(|info| info.remote_addr())
+        .or_else(|| {
+            extensions
+                .get::<TlsConnectInfo<TcpConnectInfo>>()
+                .and_then(|info| info.get_ref().remote_addr())
+        })
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        let client = self.client_id(&request);
+        // paths are of the form `/massa.api.v1.MassaService/ExecuteReadOnlyCall`
+        let method = request.uri().path().rsplit('/').next().unwrap_or_default();
+
+        if let Err(e) = self
+            .limiter
+            .charge(&client, self.limiter.method_cost(method))
+        {
+            let status = tonic::Status::resource_exhausted(e.to_string());
+            return Box::pin(async move { Ok(status.to_http()) });
+        }
+
+        request.extensions_mut().insert(RateLimitedClient {
+            limiter: self.limiter.clone(),
+            client,
+        });
+        Box::pin(self.inner.call(request))
+    }
+}
+
+impl<S: NamedService> NamedService for RateLimitService<S> {
+    const NAME: &'static str = S::NAME;
+}
@@ -1,19 +1,36 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
+use crate::auth::AuthService;
 use crate::config::GrpcConfig;
 use crate::error::GrpcError;
+use crate::rate_limit::RateLimitService;
 use futures_util::FutureExt;
-use hyper::Method;
+use hyper::{Body, Method};
+use massa_api_exports::auth::AuthTokens;
+use massa_api_exports::rate_limit::RateLimiter;
 use massa_consensus_exports::{ConsensusChannels, ConsensusController};
-use massa_execution_exports::ExecutionController;
+use massa_execution_exports::{ExecutionChannels, ExecutionController};
+use massa_models::node::NodeId;
+use massa_network_exports::NetworkCommandSender;
 use massa_pool_exports::{PoolChannels, PoolController};
 use massa_pos_exports::SelectorController;
+use massa_proto::massa::api::v1::massa_private_service_server::MassaPrivateServiceServer;
 use massa_proto::massa::api::v1::massa_service_server::MassaServiceServer;
 use massa_proto::massa::api::v1::FILE_DESCRIPTOR_SET;
 use massa_protocol_exports::ProtocolCommandSender;
 use massa_storage::Storage;
-use tokio::sync::oneshot;
+use massa_wallet::Wallet;
+use parking_lot::RwLock;
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+use tonic::body::BoxBody;
 use tonic::codec::CompressionEncoding;
+use tonic::codegen::http::{Request, Response};
+use tonic::codegen::Service;
+use tonic::server::NamedService;
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
 use tonic_web::GrpcWebLayer;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::log::{info, warn};
@@ -26,6 +43,8 @@ pub struct MassaGrpc {
     pub consensus_channels: ConsensusChannels,
     /// link to the execution component
     pub execution_controller: Box<dyn ExecutionController>,
+    /// link(channels) to the execution component
+    pub execution_channels: ExecutionChannels,
     /// link(channels) to the pool component
     pub pool_channels: PoolChannels,
     /// link to the pool component
@@ -34,12 +53,30 @@ pub struct MassaGrpc {
     pub protocol_command_sender: ProtocolCommandSender,
     /// link to the selector component
     pub selector_controller: Box<dyn SelectorController>,
+    /// link to the network component
+    pub network_command_sender: NetworkCommandSender,
     /// link to the storage component
     pub storage: Storage,
     /// gRPC configuration
     pub grpc_config: GrpcConfig,
     /// node version
     pub version: massa_models::version::Version,
+    /// node id
+    pub node_id: NodeId,
+    /// node routable ip, if any
+    pub node_ip: Option<IpAddr>,
+}
+
+/// private gRPC API content, used to manage the node
+pub struct MassaPrivateGrpc {
+    /// link to the network component
+    pub network_command_sender: NetworkCommandSender,
+    /// node staking wallet, `None` when the staking keys are held by an external signer
+    pub node_wallet: Option<Arc<RwLock<Wallet>>>,
+    /// channel used to ask the node to stop
+    pub stop_node_channel: mpsc::Sender<()>,
+    /// gRPC configuration
+    pub grpc_config: GrpcConfig,
 }
 
 impl MassaGrpc {
@@ -61,83 +98,186 @@ impl MassaGrpc {
             };
         }
 
-        let (shutdown_send, shutdown_recv) = oneshot::channel::<()>();
-
-        let mut server_builder = tonic::transport::Server::builder()
-            .concurrency_limit_per_connection(config.concurrency_limit_per_connection)
-            .timeout(config.timeout)
-            .initial_stream_window_size(config.initial_stream_window_size)
-            .initial_connection_window_size(config.initial_connection_window_size)
-            .max_concurrent_streams(config.max_concurrent_streams)
-            .tcp_keepalive(config.tcp_keepalive)
-            .tcp_nodelay(config.tcp_nodelay)
-            .http2_keepalive_interval(config.http2_keepalive_interval)
-            .http2_keepalive_timeout(config.http2_keepalive_timeout)
-            .http2_adaptive_window(config.http2_adaptive_window)
-            .max_frame_size(config.max_frame_size);
-
-        if config.accept_http1 {
-            if config.enable_cors {
-                let cors = CorsLayer::new()
-                    // Allow `GET`, `POST` and `OPTIONS` when accessing the resource
-                    .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-                    // Allow requests from any origin
-                    .allow_origin(Any)
-                    .allow_headers([hyper::header::CONTENT_TYPE]);
-
-                let mut router_with_http1 = server_builder
-                    .accept_http1(true)
-                    .layer(cors)
-                    .layer(GrpcWebLayer::new())
-                    .add_service(svc);
-
-                if config.enable_reflection {
-                    let reflection_service = tonic_reflection::server::Builder::configure()
-                        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
-                        .build()?;
-
-                    router_with_http1 = router_with_http1.add_service(reflection_service);
-                }
-
-                tokio::spawn(
-                    router_with_http1.serve_with_shutdown(config.bind, shutdown_recv.map(drop)),
-                );
-            } else {
-                let mut router_with_http1 = server_builder
-                    .accept_http1(true)
-                    .layer(GrpcWebLayer::new())
-                    .add_service(svc);
-
-                if config.enable_reflection {
-                    let reflection_service = tonic_reflection::server::Builder::configure()
-                        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
-                        .build()?;
-
-                    router_with_http1 = router_with_http1.add_service(reflection_service);
-                }
-
-                tokio::spawn(
-                    router_with_http1.serve_with_shutdown(config.bind, shutdown_recv.map(drop)),
-                );
+        if config.rate_limit.enabled {
+            // known tokens identify their clients, even though the public API does not require them
+            let tokens = match &config.auth_tokens_path {
+                Some(path) => Some(AuthTokens::from_file(path)?),
+                None => None,
+            };
+            let limiter = RateLimiter::new(config.rate_limit.clone());
+            serve_service(
+                RateLimitService::new(svc, limiter, tokens),
+                config,
+                config.bind,
+                true,
+            )
+        } else {
+            serve_service(svc, config, config.bind, true)
+        }
+    }
+}
+
+impl MassaPrivateGrpc {
+    /// generate a new private gRPC API, along with the receiver of its stop node requests
+    pub fn new(
+        network_command_sender: NetworkCommandSender,
+        node_wallet: Option<Arc<RwLock<Wallet>>>,
+        grpc_config: GrpcConfig,
+    ) -> (Self, mpsc::Receiver<()>) {
+        let (stop_node_channel, rx) = mpsc::channel(1);
+        (
+            MassaPrivateGrpc {
+                network_command_sender,
+                node_wallet,
+                stop_node_channel,
+                grpc_config,
+            },
+            rx,
+        )
+    }
+
+    /// Start the private gRPC API
+    pub async fn serve(self, config: &GrpcConfig) -> Result<StopHandle, GrpcError> {
+        let mut svc = MassaPrivateServiceServer::new(self)
+            .max_decoding_message_size(config.max_decoding_message_size)
+            .max_encoding_message_size(config.max_encoding_message_size);
+
+        if let Some(encoding) = &config.accept_compressed {
+            if encoding.eq_ignore_ascii_case("Gzip") {
+                svc = svc.accept_compressed(CompressionEncoding::Gzip);
+            };
+        }
+
+        if let Some(encoding) = &config.send_compressed {
+            if encoding.eq_ignore_ascii_case("Gzip") {
+                svc = svc.send_compressed(CompressionEncoding::Gzip);
+            };
+        }
+
+        match &config.auth_tokens_path {
+            Some(path) => serve_service(
+                AuthService::new(svc, AuthTokens::from_file(path)?),
+                config,
+                config.bind_private,
+                false,
+            ),
+            None => serve_service(svc, config, config.bind_private, false),
+        }
+    }
+}
+
+/// Spawns a server for the given gRPC service on `bind`, with the transport options of `config`.
+///
+/// HTTP/1.1, gRPC-Web, CORS and reflection are only enabled on the `public` server:
+/// the private API manages the node and is not meant to be reached from a browser.
+fn serve_service<S>(
+    svc: S,
+    config: &GrpcConfig,
+    bind: SocketAddr,
+    public: bool,
+) -> Result<StopHandle, GrpcError>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible>
+        + NamedService
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    let (shutdown_send, shutdown_recv) = oneshot::channel::<()>();
+
+    let mut server_builder = tonic::transport::Server::builder()
+        .concurrency_limit_per_connection(config.concurrency_limit_per_connection)
+        .timeout(config.timeout)
+        .initial_stream_window_size(config.initial_stream_window_size)
+        .initial_connection_window_size(config.initial_connection_window_size)
+        .max_concurrent_streams(config.max_concurrent_streams)
+        .tcp_keepalive(config.tcp_keepalive)
+        .tcp_nodelay(config.tcp_nodelay)
+        .http2_keepalive_interval(config.http2_keepalive_interval)
+        .http2_keepalive_timeout(config.http2_keepalive_timeout)
+        .http2_adaptive_window(config.http2_adaptive_window)
+        .max_frame_size(config.max_frame_size);
+
+    if let Some(tls_config) = tls_config(config)? {
+        server_builder = server_builder.tls_config(tls_config)?;
+    }
+
+    if public && config.accept_http1 {
+        if config.enable_cors {
+            let cors = CorsLayer::new()
+                // Allow `GET`, `POST` and `OPTIONS` when accessing the resource
+                .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+                // Allow requests from any origin
+                .allow_origin(Any)
+                .allow_headers([hyper::header::CONTENT_TYPE, hyper::header::AUTHORIZATION]);
+
+            let mut router_with_http1 = server_builder
+                .accept_http1(true)
+                .layer(cors)
+                .layer(GrpcWebLayer::new())
+                .add_service(svc);
+
+            if config.enable_reflection {
+                let reflection_service = tonic_reflection::server::Builder::configure()
+                    .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+                    .build()?;
+
+                router_with_http1 = router_with_http1.add_service(reflection_service);
             }
+
+            tokio::spawn(router_with_http1.serve_with_shutdown(bind, shutdown_recv.map(drop)));
         } else {
-            let mut router = server_builder.add_service(svc);
+            let mut router_with_http1 = server_builder
+                .accept_http1(true)
+                .layer(GrpcWebLayer::new())
+                .add_service(svc);
 
             if config.enable_reflection {
                 let reflection_service = tonic_reflection::server::Builder::configure()
                     .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
                     .build()?;
 
-                router = router.add_service(reflection_service);
+                router_with_http1 = router_with_http1.add_service(reflection_service);
             }
 
-            tokio::spawn(router.serve_with_shutdown(config.bind, shutdown_recv.map(drop)));
+            tokio::spawn(router_with_http1.serve_with_shutdown(bind, shutdown_recv.map(drop)));
+        }
+    } else {
+        let mut router = server_builder.add_service(svc);
+
+        if public && config.enable_reflection {
+            let reflection_service = tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+                .build()?;
+
+            router = router.add_service(reflection_service);
         }
 
-        Ok(StopHandle {
-            stop_cmd_sender: shutdown_send,
-        })
+        tokio::spawn(router.serve_with_shutdown(bind, shutdown_recv.map(drop)));
     }
+
+    Ok(StopHandle {
+        stop_cmd_sender: shutdown_send,
+    })
+}
+
+/// Builds the TLS configuration of the servers, if TLS is enabled
+fn tls_config(config: &GrpcConfig) -> Result<Option<ServerTlsConfig>, GrpcError> {
+    let Some(cert_path) = &config.tls_cert_path else {
+        return Ok(None);
+    };
+    let key_path = config.tls_key_path.as_ref().ok_or_else(|| {
+        GrpcError::InvalidArgument("`tls_key_path` is required when `tls_cert_path` is set".into())
+    })?;
+
+    let identity = Identity::from_pem(std::fs::read(cert_path)?, std::fs::read(key_path)?);
+    let mut tls_config = ServerTlsConfig::new().identity(identity);
+    if let Some(ca_path) = &config.tls_client_ca_path {
+        tls_config = tls_config.client_ca_root(Certificate::from_pem(std::fs::read(ca_path)?));
+    }
+
+    Ok(Some(tls_config))
 }
 
 /// Used to be able to stop the gRPC API
@@ -8,6 +8,8 @@ pub mod new_blocks_headers;
 pub mod new_filled_blocks;
 /// subscribe new operations
 pub mod new_operations;
+/// subscribe new smart contract events
+pub mod new_sc_events;
 /// send_blocks streaming
 pub mod send_blocks;
 /// send endorsements
@@ -0,0 +1,148 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use crate::error::GrpcError;
+use crate::server::MassaGrpc;
+use futures_util::StreamExt;
+use massa_models::address::Address;
+use massa_models::execution::EventFilter;
+use massa_models::operation::OperationId;
+use massa_proto::massa::api::v1 as grpc;
+use std::pin::Pin;
+use std::str::FromStr;
+use tokio::select;
+use tokio::sync::broadcast::error::RecvError;
+use tonic::codegen::futures_core;
+use tonic::{Request, Streaming};
+use tracing::log::{error, warn};
+
+/// Type declaration for NewScEvents
+pub type NewScEventsStream = Pin<
+    Box<
+        dyn futures_core::Stream<Item = Result<grpc::NewScEventsResponse, tonic::Status>>
+            + Send
+            + 'static,
+    >,
+>;
+
+/// Creates a new stream of new smart contract events
+pub(crate) async fn new_sc_events(
+    grpc: &MassaGrpc,
+    request: Request<Streaming<grpc::NewScEventsRequest>>,
+) -> Result<NewScEventsStream, GrpcError> {
+    // Create a channel to handle communication with the client
+    let (tx, rx) = tokio::sync::mpsc::channel(grpc.grpc_config.max_channel_size);
+    // Get the inner stream from the request
+    let mut in_stream = request.into_inner();
+    // Subscribe to the new smart contract events channel
+    let mut subscriber = grpc.execution_channels.sc_event_sender.subscribe();
+
+    tokio::spawn(async move {
+        if let Some(Ok(request)) = in_stream.next().await {
+            let mut request_id = request.id;
+            let mut filter = match to_event_filter(request.query.and_then(|q| q.filter)) {
+                Ok(filter) => filter,
+                Err(e) => {
+                    // Report the invalid filter to the client
+                    if let Err(e) = tx.send(Err(e.into())).await {
+                        error!("failed to send back new_sc_events error response: {}", e);
+                    }
+                    return;
+                }
+            };
+
+            loop {
+                select! {
+                    // Receive a new smart contract event from the subscriber
+                    event = subscriber.recv() => {
+                        match event {
+                            Ok(event) => {
+                                // Check if the event should be sent
+                                if !filter.matches(&event) {
+                                    continue;
+                                }
+                                // Send the new event through the channel
+                                if let Err(e) = tx.send(Ok(grpc::NewScEventsResponse {
+                                    id: request_id.clone(),
+                                    event: Some(event.into())
+                                })).await {
+                                    error!("failed to send smart contract event : {}", e);
+                                    break;
+                                }
+                            },
+                            Err(RecvError::Lagged(skipped)) => {
+                                // The subscriber is too slow, the oldest events were dropped
+                                warn!("new_sc_events subscriber lagged, {} smart contract events skipped", skipped);
+                            },
+                            Err(RecvError::Closed) => {
+                                // The execution worker stopped
+                                break;
+                            }
+                        }
+                    },
+                    // Receive a new message from the in_stream
+                    res = in_stream.next() => {
+                        match res {
+                            Some(res) => {
+                                match res {
+                                    Ok(data) => {
+                                        // Update current filter && request id
+                                        match to_event_filter(data.query.and_then(|q| q.filter)) {
+                                            Ok(new_filter) => {
+                                                filter = new_filter;
+                                                request_id = data.id;
+                                            },
+                                            Err(e) => {
+                                                // Report the invalid filter and keep the current one
+                                                if let Err(e) = tx.send(Err(e.into())).await {
+                                                    error!("failed to send back new_sc_events error response: {}", e);
+                                                    break;
+                                                }
+                                            }
+                                        }
+                                    },
+                                    Err(e) => {
+                                        error!("{}", e);
+                                        break;
+                                    }
+                                }
+                            },
+                            None => {
+                                // Client disconnected
+                                break;
+                            },
+                        }
+                    }
+                }
+            }
+        } else {
+            error!("empty request");
+        }
+    });
+
+    let out_stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+    Ok(Box::pin(out_stream) as NewScEventsStream)
+}
+
+/// Converts the gRPC filter into an event filter, an absent filter matching every event
+fn to_event_filter(filter_opt: Option<grpc::NewScEventsFilter>) -> Result<EventFilter, GrpcError> {
+    let Some(filter) = filter_opt else {
+        return Ok(EventFilter::default());
+    };
+    Ok(EventFilter {
+        emitter_address: filter
+            .emitter_address
+            .map(|address| Address::from_str(&address))
+            .transpose()?,
+        original_caller_address: filter
+            .original_caller_address
+            .map(|address| Address::from_str(&address))
+            .transpose()?,
+        original_operation_id: filter
+            .original_operation_id
+            .map(|id| OperationId::from_str(&id))
+            .transpose()?,
+        is_final: filter.is_final,
+        is_error: filter.is_error,
+        ..Default::default()
+    })
+}
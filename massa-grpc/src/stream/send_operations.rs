@@ -94,6 +94,7 @@ pub(crate) async fn send_operations(
                                             let (rest, res_operation): (&[u8], SecureShareOperation) = tuple;
                                             if rest.is_empty() {
                                                 res_operation.verify_signature()
+                                                    .and_then(|_| res_operation.verify_multisig())
                                                     .map(|_| (res_operation.id.to_string(), res_operation))
                                                     .map_err(|e| e.into())
                                             } else {
@@ -1,60 +1,92 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
+use crate::api::{
+    check_state_overrides, get_blocks_by_slots, get_operations_by_address, get_sc_execution_events,
+};
 use crate::config::GrpcConfig;
-use crate::server::MassaGrpc;
-use massa_consensus_exports::test_exports::MockConsensusController;
+use crate::error::GrpcError;
+use crate::private::{
+    add_staking_secret_keys, add_to_bootstrap_blacklist, ban_nodes_by_ips, get_bootstrap_blacklist,
+    get_staking_addresses, remove_from_bootstrap_blacklist, remove_staking_addresses, stop_node,
+};
+use crate::rate_limit::{RateLimitService, RateLimitedClient};
+use crate::server::{MassaGrpc, MassaPrivateGrpc};
+use futures_util::future::{ready, Ready};
+use massa_api_exports::rate_limit::{RateLimitConfig, RateLimiter};
+use massa_consensus_exports::test_exports::{
+    ConsensusEventReceiver, MockConsensusController, MockConsensusControllerMessage,
+};
 use massa_consensus_exports::ConsensusChannels;
-use massa_execution_exports::test_exports::MockExecutionController;
+use massa_execution_exports::test_exports::{
+    MockExecutionController, MockExecutionControllerMessage,
+};
+use massa_execution_exports::ExecutionChannels;
+use massa_models::address::Address;
 use massa_models::config::{
-    ENDORSEMENT_COUNT, GENESIS_TIMESTAMP, MAX_DATASTORE_VALUE_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE,
-    MAX_FUNCTION_NAME_LENGTH, MAX_OPERATIONS_PER_BLOCK, MAX_OPERATIONS_PER_MESSAGE,
-    MAX_OPERATION_DATASTORE_ENTRY_COUNT, MAX_OPERATION_DATASTORE_KEY_LENGTH,
-    MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE, PROTOCOL_CONTROLLER_CHANNEL_SIZE,
-    T0, THREAD_COUNT, VERSION,
+    ENDORSEMENT_COUNT, GENESIS_TIMESTAMP, MAX_DATASTORE_VALUE_LENGTH,
+    MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_ENDORSEMENTS_PER_MESSAGE, MAX_FUNCTION_NAME_LENGTH,
+    MAX_OPERATIONS_PER_BLOCK, MAX_OPERATIONS_PER_MESSAGE, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+    MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE,
+    NETWORK_CONTROLLER_CHANNEL_SIZE, PERIODS_PER_CYCLE, PROTOCOL_CONTROLLER_CHANNEL_SIZE, T0,
+    THREAD_COUNT, VERSION,
 };
+use massa_models::node::NodeId;
+use massa_models::slot::Slot;
+use massa_network_exports::{NetworkCommand, NetworkCommandSender};
 use massa_pool_exports::test_exports::MockPoolController;
 use massa_pool_exports::PoolChannels;
 use massa_pos_exports::test_exports::MockSelectorController;
+use massa_proto::massa::api::v1::massa_private_service_client::MassaPrivateServiceClient;
 use massa_proto::massa::api::v1::massa_service_client::MassaServiceClient;
+use massa_proto::massa::api::v1::{
+    self as grpc, AddStakingSecretKeysRequest, AddToBootstrapBlacklistRequest,
+    BanNodesByIpsRequest, BytesMapFieldEntry, GetBlocksBySlotsRequest,
+    GetBootstrapBlacklistRequest, GetOperationsByAddressRequest, GetScExecutionEventsRequest,
+    GetStakingAddressesRequest, RemoveFromBootstrapBlacklistRequest, RemoveStakingAddressesRequest,
+    ScExecutionEventsFilter, StateOverride, StopNodeRequest,
+};
 use massa_protocol_exports::{ProtocolCommand, ProtocolCommandSender};
+use massa_signature::KeyPair;
+use massa_time::MassaTime;
+use massa_wallet::test_exports::create_test_wallet;
+use massa_wallet::WalletError;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::io::Write;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio::sync::mpsc;
+use tonic::body::BoxBody;
+use tonic::codegen::http::{Request, Response};
+use tonic::codegen::Service;
 
-#[tokio::test]
-async fn test_start_grpc_server() {
-    let consensus_controller = MockConsensusController::new_with_receiver();
-    let execution_ctrl = MockExecutionController::new_with_receiver();
-    let shared_storage: massa_storage::Storage = massa_storage::Storage::create_root();
-    let selector_ctrl = MockSelectorController::new_with_receiver();
-    let pool_ctrl = MockPoolController::new_with_receiver();
-
-    let (consensus_event_sender, _consensus_event_receiver) = crossbeam::channel::bounded(1024);
-    let (denunciation_factory_sender, _denunciation_factory_receiver) =
-        crossbeam::channel::bounded(1024);
-
-    let (protocol_command_sender, _protocol_command_receiver) =
-        mpsc::channel::<ProtocolCommand>(PROTOCOL_CONTROLLER_CHANNEL_SIZE);
-
-    let consensus_channels = ConsensusChannels {
-        execution_controller: execution_ctrl.0.clone(),
-        selector_controller: selector_ctrl.0.clone(),
-        pool_command_sender: pool_ctrl.0.clone(),
-        controller_event_tx: consensus_event_sender,
-        protocol_command_sender: ProtocolCommandSender(protocol_command_sender.clone()),
-        block_sender: tokio::sync::broadcast::channel(100).0,
-        block_header_sender: tokio::sync::broadcast::channel(100).0,
-        filled_block_sender: tokio::sync::broadcast::channel(100).0,
-        denunciation_factory_sender: denunciation_factory_sender,
-    };
-
-    let operation_sender = tokio::sync::broadcast::channel(5000).0;
-
-    let grpc_config = GrpcConfig {
+/// gRPC configuration used by the tests
+fn grpc_config() -> GrpcConfig {
+    GrpcConfig {
         enabled: true,
         accept_http1: true,
         enable_cors: true,
         enable_reflection: true,
         bind: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8888),
+        bind_private: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8889),
+        tls_cert_path: None,
+        tls_key_path: None,
+        tls_client_ca_path: None,
+        auth_tokens_path: None,
+        rate_limit: RateLimitConfig {
+            enabled: false,
+            refill_per_second: 100,
+            burst: 1000,
+            gas_per_cost_unit: 1_000_000,
+            max_clients: 10_000,
+            client_ip_header: None,
+            method_costs: Default::default(),
+        },
         accept_compressed: None,
         send_compressed: None,
         max_decoding_message_size: 4194304,
@@ -74,6 +106,7 @@ async fn test_start_grpc_server() {
         max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
         endorsement_count: ENDORSEMENT_COUNT,
         max_endorsements_per_message: MAX_ENDORSEMENTS_PER_MESSAGE,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
         max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
         max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -86,20 +119,79 @@ async fn test_start_grpc_server() {
         max_channel_size: 128,
         draw_lookahead_period_count: 10,
         last_start_period: 0,
+        max_arguments: 128,
+        periods_per_cycle: PERIODS_PER_CYCLE,
+        bootstrap_whitelist_path: PathBuf::from(
+            "../massa-node/base_config/bootstrap_whitelist.json",
+        ),
+        bootstrap_blacklist_path: PathBuf::from(
+            "../massa-node/base_config/bootstrap_blacklist.json",
+        ),
+    }
+}
+
+/// public gRPC API backed by mocked components, along with the receivers of the consensus and execution calls
+fn grpc_service(
+    grpc_config: &GrpcConfig,
+) -> (
+    MassaGrpc,
+    ConsensusEventReceiver,
+    std::sync::mpsc::Receiver<MockExecutionControllerMessage>,
+) {
+    let consensus_controller = MockConsensusController::new_with_receiver();
+    let execution_ctrl = MockExecutionController::new_with_receiver();
+    let shared_storage: massa_storage::Storage = massa_storage::Storage::create_root();
+    let selector_ctrl = MockSelectorController::new_with_receiver();
+    let pool_ctrl = MockPoolController::new_with_receiver();
+
+    let (consensus_event_sender, _consensus_event_receiver) = crossbeam::channel::bounded(1024);
+    let (denunciation_factory_sender, _denunciation_factory_receiver) =
+        crossbeam::channel::bounded(1024);
+
+    let (protocol_command_sender, _protocol_command_receiver) =
+        mpsc::channel::<ProtocolCommand>(PROTOCOL_CONTROLLER_CHANNEL_SIZE);
+    let (network_command_sender, _network_command_receiver) =
+        mpsc::channel::<NetworkCommand>(NETWORK_CONTROLLER_CHANNEL_SIZE);
+
+    let consensus_channels = ConsensusChannels {
+        execution_controller: execution_ctrl.0.clone(),
+        selector_controller: selector_ctrl.0.clone(),
+        pool_command_sender: pool_ctrl.0.clone(),
+        controller_event_tx: consensus_event_sender,
+        protocol_command_sender: ProtocolCommandSender(protocol_command_sender.clone()),
+        block_sender: tokio::sync::broadcast::channel(100).0,
+        block_header_sender: tokio::sync::broadcast::channel(100).0,
+        filled_block_sender: tokio::sync::broadcast::channel(100).0,
+        denunciation_factory_sender,
+        archive: None,
     };
 
+    let operation_sender = tokio::sync::broadcast::channel(5000).0;
+    let sc_event_sender = tokio::sync::broadcast::channel(5000).0;
+
     let service = MassaGrpc {
         consensus_controller: consensus_controller.0,
         consensus_channels,
         execution_controller: execution_ctrl.0,
+        execution_channels: ExecutionChannels { sc_event_sender },
         pool_channels: PoolChannels { operation_sender },
         pool_command_sender: pool_ctrl.0,
-        protocol_command_sender: ProtocolCommandSender(protocol_command_sender.clone()),
+        protocol_command_sender: ProtocolCommandSender(protocol_command_sender),
         selector_controller: selector_ctrl.0,
+        network_command_sender: NetworkCommandSender(network_command_sender),
         storage: shared_storage,
         grpc_config: grpc_config.clone(),
         version: *VERSION,
+        node_id: NodeId::new(KeyPair::generate().get_public_key()),
+        node_ip: None,
     };
+    (service, consensus_controller.1, execution_ctrl.1)
+}
+
+#[tokio::test]
+async fn test_start_grpc_server() {
+    let grpc_config = grpc_config();
+    let (service, _consensus_receiver, _execution_receiver) = grpc_service(&grpc_config);
 
     let stop_handle = service.serve(&grpc_config).await.unwrap();
     // std::thread::sleep(Duration::from_millis(100));
@@ -113,3 +205,567 @@ async fn test_start_grpc_server() {
     let _res = MassaServiceClient::new(channel);
     stop_handle.stop();
 }
+
+#[tokio::test]
+async fn test_start_private_grpc_server() {
+    let (network_command_sender, _network_command_receiver) =
+        mpsc::channel::<NetworkCommand>(NETWORK_CONTROLLER_CHANNEL_SIZE);
+    let node_wallet = Some(Arc::new(RwLock::new(create_test_wallet(None))));
+
+    let mut grpc_config = grpc_config();
+    grpc_config.bind_private = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8890);
+
+    let (service, _stop_node_rx) = MassaPrivateGrpc::new(
+        NetworkCommandSender(network_command_sender),
+        node_wallet,
+        grpc_config.clone(),
+    );
+
+    let stop_handle = service.serve(&grpc_config).await.unwrap();
+
+    // start private grpc client and connect to the server
+    let channel = tonic::transport::Channel::from_static("grpc://localhost:8890")
+        .connect()
+        .await
+        .unwrap();
+
+    let _res = MassaPrivateServiceClient::new(channel);
+    stop_handle.stop();
+}
+
+#[tokio::test]
+async fn test_private_grpc_server_auth() {
+    let (network_command_sender, _network_command_receiver) =
+        mpsc::channel::<NetworkCommand>(NETWORK_CONTROLLER_CHANNEL_SIZE);
+    let node_wallet = Some(Arc::new(RwLock::new(create_test_wallet(None))));
+
+    let mut tokens_file = tempfile::NamedTempFile::new().unwrap();
+    tokens_file
+        .write_all(br#"[{"token": "monitoring", "methods": ["GetStakingAddresses"]}]"#)
+        .unwrap();
+
+    let mut grpc_config = grpc_config();
+    grpc_config.bind_private = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8891);
+    grpc_config.concurrency_limit_per_connection = 32;
+    grpc_config.timeout = Duration::from_secs(10);
+    grpc_config.auth_tokens_path = Some(tokens_file.path().to_path_buf());
+
+    let (service, _stop_node_rx) = MassaPrivateGrpc::new(
+        NetworkCommandSender(network_command_sender),
+        node_wallet,
+        grpc_config.clone(),
+    );
+
+    let stop_handle = service.serve(&grpc_config).await.unwrap();
+
+    let channel = tonic::transport::Channel::from_static("grpc://localhost:8891")
+        .connect()
+        .await
+        .unwrap();
+    let mut client = MassaPrivateServiceClient::new(channel);
+
+    // no token
+    let status = client
+        .get_staking_addresses(GetStakingAddressesRequest { id: "1".into() })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+    // method allowed by the token
+    let mut request = tonic::Request::new(GetStakingAddressesRequest { id: "2".into() });
+    request
+        .metadata_mut()
+        .insert("authorization", "Bearer monitoring".parse().unwrap());
+    let response = client.get_staking_addresses(request).await.unwrap();
+    assert_eq!(response.into_inner().id, "2");
+
+    // method out of the token scopes
+    let mut request = tonic::Request::new(StopNodeRequest { id: "3".into() });
+    request
+        .metadata_mut()
+        .insert("authorization", "Bearer monitoring".parse().unwrap());
+    let status = client.stop_node(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::PermissionDenied);
+
+    stop_handle.stop();
+}
+
+#[test]
+fn test_get_blocks_by_slots() {
+    let grpc_config = grpc_config();
+    let (service, mut consensus_receiver, _execution_receiver) = grpc_service(&grpc_config);
+
+    // threads out of range are refused, instead of being truncated to another thread
+    for thread in [THREAD_COUNT as u32, 256] {
+        let request = tonic::Request::new(GetBlocksBySlotsRequest {
+            id: "1".into(),
+            slots: vec![grpc::Slot { period: 1, thread }],
+        });
+        assert!(matches!(
+            get_blocks_by_slots(&service, request),
+            Err(GrpcError::InvalidArgument(_))
+        ));
+    }
+
+    // no block in the blockclique at the slot, and no archive to look it up
+    let responder = std::thread::spawn(move || {
+        consensus_receiver.wait_command(MassaTime::from_millis(1000), |cmd| match cmd {
+            MockConsensusControllerMessage::GetBlockcliqueBlockAtSlot { slot, response_tx } => {
+                response_tx.send(None).unwrap();
+                Some(slot)
+            }
+            _ => None,
+        })
+    });
+    let request = tonic::Request::new(GetBlocksBySlotsRequest {
+        id: "2".into(),
+        slots: vec![grpc::Slot {
+            period: 1,
+            thread: 1,
+        }],
+    });
+    let response = get_blocks_by_slots(&service, request).unwrap();
+    assert_eq!(responder.join().unwrap(), Some(Slot::new(1, 1)));
+    assert_eq!(response.id, "2");
+    assert!(response.blocks.is_empty());
+}
+
+#[test]
+fn test_get_sc_execution_events() {
+    let grpc_config = grpc_config();
+    let (service, _consensus_receiver, execution_receiver) = grpc_service(&grpc_config);
+    let address = "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x".to_string();
+
+    let request = tonic::Request::new(GetScExecutionEventsRequest {
+        id: "1".into(),
+        filter: Some(ScExecutionEventsFilter {
+            start_slot: Some(grpc::Slot {
+                period: 1,
+                thread: THREAD_COUNT as u32,
+            }),
+            ..Default::default()
+        }),
+    });
+    assert!(matches!(
+        get_sc_execution_events(&service, request),
+        Err(GrpcError::InvalidArgument(_))
+    ));
+
+    // the filter is converted and passed to execution
+    let responder = std::thread::spawn(move || {
+        match execution_receiver
+            .recv_timeout(Duration::from_millis(1000))
+            .unwrap()
+        {
+            MockExecutionControllerMessage::GetFilteredScOutputEvent {
+                filter,
+                response_tx,
+            } => {
+                response_tx.send(Vec::new()).unwrap();
+                filter
+            }
+            _ => panic!("unexpected execution call"),
+        }
+    });
+    let request = tonic::Request::new(GetScExecutionEventsRequest {
+        id: "2".into(),
+        filter: Some(ScExecutionEventsFilter {
+            start_slot: Some(grpc::Slot {
+                period: 1,
+                thread: 0,
+            }),
+            end_slot: Some(grpc::Slot {
+                period: 2,
+                thread: 1,
+            }),
+            emitter_address: Some(address.clone()),
+            is_final: Some(true),
+            ..Default::default()
+        }),
+    });
+    let response = get_sc_execution_events(&service, request).unwrap();
+    let filter = responder.join().unwrap();
+    assert_eq!(response.id, "2");
+    assert!(response.events.is_empty());
+    assert_eq!(filter.start, Some(Slot::new(1, 0)));
+    assert_eq!(filter.end, Some(Slot::new(2, 1)));
+    assert_eq!(
+        filter.emitter_address,
+        Some(Address::from_str(&address).unwrap())
+    );
+    assert_eq!(filter.is_final, Some(true));
+    assert_eq!(filter.is_error, None);
+}
+
+#[test]
+fn test_get_operations_by_address() {
+    let grpc_config = grpc_config();
+    let (service, _consensus_receiver, _execution_receiver) = grpc_service(&grpc_config);
+    let address = "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x".to_string();
+
+    let request = tonic::Request::new(GetOperationsByAddressRequest {
+        id: "1".into(),
+        address: address.clone(),
+        end_slot: Some(grpc::Slot {
+            period: 1,
+            thread: 300,
+        }),
+        ..Default::default()
+    });
+    assert!(matches!(
+        get_operations_by_address(&service, request),
+        Err(GrpcError::InvalidArgument(_))
+    ));
+
+    let request = tonic::Request::new(GetOperationsByAddressRequest {
+        id: "2".into(),
+        address: "invalid".into(),
+        ..Default::default()
+    });
+    assert!(get_operations_by_address(&service, request).is_err());
+
+    let request = tonic::Request::new(GetOperationsByAddressRequest {
+        id: "3".into(),
+        address,
+        start_slot: Some(grpc::Slot {
+            period: 1,
+            thread: 0,
+        }),
+        ..Default::default()
+    });
+    let response = get_operations_by_address(&service, request).unwrap();
+    assert_eq!(response.id, "3");
+    assert_eq!(response.total_count, 0);
+    assert!(response.operations.is_empty());
+}
+
+/// private gRPC API with a test wallet, along with the receivers of its network commands and stop requests
+fn private_grpc_service(
+    grpc_config: &GrpcConfig,
+) -> (
+    MassaPrivateGrpc,
+    mpsc::Receiver<NetworkCommand>,
+    mpsc::Receiver<()>,
+) {
+    let (network_command_sender, network_command_receiver) =
+        mpsc::channel::<NetworkCommand>(NETWORK_CONTROLLER_CHANNEL_SIZE);
+    let (service, stop_node_rx) = MassaPrivateGrpc::new(
+        NetworkCommandSender(network_command_sender),
+        Some(Arc::new(RwLock::new(create_test_wallet(None)))),
+        grpc_config.clone(),
+    );
+    (service, network_command_receiver, stop_node_rx)
+}
+
+#[test]
+fn test_private_bootstrap_lists() {
+    let list_dir = tempfile::tempdir().unwrap();
+    let mut grpc_config = grpc_config();
+    grpc_config.bootstrap_blacklist_path = list_dir.path().join("bootstrap_blacklist.json");
+    let (service, _network_receiver, _stop_node_rx) = private_grpc_service(&grpc_config);
+
+    // the list file is created on the first addition
+    add_to_bootstrap_blacklist(
+        &service,
+        tonic::Request::new(AddToBootstrapBlacklistRequest {
+            id: "1".into(),
+            ips: vec!["192.168.0.2".into(), "10.0.0.1".into()],
+        }),
+    )
+    .unwrap();
+    let response = get_bootstrap_blacklist(
+        &service,
+        tonic::Request::new(GetBootstrapBlacklistRequest { id: "2".into() }),
+    )
+    .unwrap();
+    assert_eq!(response.id, "2");
+    assert_eq!(response.ips, vec!["10.0.0.1", "192.168.0.2"]);
+
+    remove_from_bootstrap_blacklist(
+        &service,
+        tonic::Request::new(RemoveFromBootstrapBlacklistRequest {
+            id: "3".into(),
+            ips: vec!["10.0.0.1".into()],
+        }),
+    )
+    .unwrap();
+    let response = get_bootstrap_blacklist(
+        &service,
+        tonic::Request::new(GetBootstrapBlacklistRequest { id: "4".into() }),
+    )
+    .unwrap();
+    assert_eq!(response.ips, vec!["192.168.0.2"]);
+
+    // invalid IPs are refused and the list is left as is
+    assert!(matches!(
+        add_to_bootstrap_blacklist(
+            &service,
+            tonic::Request::new(AddToBootstrapBlacklistRequest {
+                id: "5".into(),
+                ips: vec!["not an ip".into()],
+            }),
+        ),
+        Err(GrpcError::InvalidArgument(_))
+    ));
+    let response = get_bootstrap_blacklist(
+        &service,
+        tonic::Request::new(GetBootstrapBlacklistRequest { id: "6".into() }),
+    )
+    .unwrap();
+    assert_eq!(response.ips, vec!["192.168.0.2"]);
+}
+
+#[test]
+fn test_private_staking_addresses() {
+    let grpc_config = grpc_config();
+    let (service, _network_receiver, _stop_node_rx) = private_grpc_service(&grpc_config);
+    let keypair = KeyPair::generate();
+    let address = Address::from_public_key(&keypair.get_public_key()).to_string();
+
+    add_staking_secret_keys(
+        &service,
+        tonic::Request::new(AddStakingSecretKeysRequest {
+            id: "1".into(),
+            secret_keys: vec![keypair.to_string()],
+        }),
+    )
+    .unwrap();
+    let response = get_staking_addresses(
+        &service,
+        tonic::Request::new(GetStakingAddressesRequest { id: "2".into() }),
+    )
+    .unwrap();
+    assert_eq!(response.addresses, vec![address.clone()]);
+
+    remove_staking_addresses(
+        &service,
+        tonic::Request::new(RemoveStakingAddressesRequest {
+            id: "3".into(),
+            addresses: vec![address],
+        }),
+    )
+    .unwrap();
+    let response = get_staking_addresses(
+        &service,
+        tonic::Request::new(GetStakingAddressesRequest { id: "4".into() }),
+    )
+    .unwrap();
+    assert!(response.addresses.is_empty());
+
+    assert!(matches!(
+        add_staking_secret_keys(
+            &service,
+            tonic::Request::new(AddStakingSecretKeysRequest {
+                id: "5".into(),
+                secret_keys: vec!["invalid".into()],
+            }),
+        ),
+        Err(GrpcError::InvalidArgument(_))
+    ));
+
+    // the staking keys are not managed by the node when they are held by a remote signer
+    let (network_command_sender, _network_command_receiver) =
+        mpsc::channel::<NetworkCommand>(NETWORK_CONTROLLER_CHANNEL_SIZE);
+    let (service, _stop_node_rx) = MassaPrivateGrpc::new(
+        NetworkCommandSender(network_command_sender),
+        None,
+        grpc_config,
+    );
+    assert!(matches!(
+        get_staking_addresses(
+            &service,
+            tonic::Request::new(GetStakingAddressesRequest { id: "6".into() }),
+        ),
+        Err(GrpcError::WalletError(WalletError::KeysHeldByRemoteSigner))
+    ));
+}
+
+#[tokio::test]
+async fn test_private_node_commands() {
+    let grpc_config = grpc_config();
+    let (service, mut network_receiver, mut stop_node_rx) = private_grpc_service(&grpc_config);
+
+    ban_nodes_by_ips(
+        &service,
+        tonic::Request::new(BanNodesByIpsRequest {
+            id: "1".into(),
+            ips: vec!["10.0.0.1".into()],
+        }),
+    )
+    .await
+    .unwrap();
+    match network_receiver.recv().await {
+        Some(NetworkCommand::NodeBanByIps(ips)) => {
+            assert_eq!(ips, vec![IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))])
+        }
+        _ => panic!("expected a ban by IPs command"),
+    }
+
+    let response = stop_node(
+        &service,
+        tonic::Request::new(StopNodeRequest { id: "2".into() }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.id, "2");
+    assert_eq!(stop_node_rx.recv().await, Some(()));
+}
+
+#[test]
+fn test_check_state_overrides() {
+    let grpc_config = grpc_config();
+    let address = "AU12dG5xP1RDEB5ocdHkymNVvvSJmUL9BgHwCksDowqmGWxfpm93x".to_string();
+    let state_override = StateOverride {
+        address: address.clone(),
+        balance: Some(1),
+        bytecode: Some(vec![0; 10]),
+        datastore: vec![BytesMapFieldEntry {
+            key: vec![1],
+            value: vec![2],
+        }],
+        roll_count: None,
+    };
+    assert!(check_state_overrides(&grpc_config, &[state_override.clone()]).is_ok());
+
+    // too many overrides
+    let state_overrides = vec![state_override.clone(); grpc_config.max_arguments as usize + 1];
+    assert!(check_state_overrides(&grpc_config, &state_overrides).is_err());
+
+    // bytecode too large
+    let mut large_bytecode = state_override.clone();
+    large_bytecode.bytecode = Some(vec![0; grpc_config.max_datastore_value_length as usize + 1]);
+    assert!(check_state_overrides(&grpc_config, &[large_bytecode]).is_err());
+
+    // too many datastore entries
+    let mut many_entries = state_override.clone();
+    many_entries.datastore = (0..=grpc_config.max_op_datastore_entry_count)
+        .map(|i| BytesMapFieldEntry {
+            key: i.to_be_bytes().to_vec(),
+            value: vec![],
+        })
+        .collect();
+    assert!(check_state_overrides(&grpc_config, &[many_entries]).is_err());
+
+    // datastore key too long
+    let mut long_key = state_override.clone();
+    long_key.datastore[0].key = vec![0; grpc_config.max_op_datastore_key_length as usize + 1];
+    assert!(check_state_overrides(&grpc_config, &[long_key]).is_err());
+
+    // datastore value too large
+    let mut large_value = state_override;
+    large_value.datastore[0].value = vec![0; grpc_config.max_datastore_value_length as usize + 1];
+    assert!(check_state_overrides(&grpc_config, &[large_value]).is_err());
+}
+
+/// service answering every call, read-only executions using 300 gas
+#[derive(Clone)]
+struct ReadOnlyService;
+
+impl Service<Request<hyper::Body>> for ReadOnlyService {
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<hyper::Body>) -> Self::Future {
+        let client = request
+            .extensions()
+            .get::<RateLimitedClient>()
+            .expect("rate limited calls carry their client");
+        if request.uri().path().ends_with("/ExecuteReadOnlyCall") {
+            client.charge_gas(300);
+        }
+        ready(Ok(Response::new(tonic::body::empty_body())))
+    }
+}
+
+/// call `method` through the rate limiter, returning the status of the call
+async fn rate_limited_call(
+    service: &mut RateLimitService<ReadOnlyService>,
+    method: &str,
+    headers: &[(&'static str, &str)],
+) -> tonic::Code {
+    let mut request = Request::builder().uri(format!("/massa.api.v1.MassaService/{}", method));
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let response = service
+        .call(request.body(hyper::Body::empty()).unwrap())
+        .await
+        .unwrap();
+    tonic::Status::from_header_map(response.headers())
+        .map_or(tonic::Code::Ok, |status| status.code())
+}
+
+#[tokio::test]
+async fn test_rate_limit_service() {
+    let tokens = serde_json::from_str(r#"[{"token": "indexer", "methods": ["*"]}]"#).unwrap();
+    let limiter = RateLimiter::new(RateLimitConfig {
+        enabled: true,
+        refill_per_second: 0,
+        burst: 10,
+        gas_per_cost_unit: 100,
+        max_clients: 100,
+        client_ip_header: Some("x-forwarded-for".to_string()),
+        method_costs: HashMap::from([("ExecuteReadOnlyCall".to_string(), 4)]),
+    });
+    let mut service = RateLimitService::new(ReadOnlyService, limiter, Some(tokens));
+    let forwarded = |value| [("x-forwarded-for", value)];
+
+    // the read-only call costs 4 units, then 3 more for its gas
+    let code = rate_limited_call(
+        &mut service,
+        "ExecuteReadOnlyCall",
+        &forwarded("1.1.1.1, 2.2.2.2"),
+    )
+    .await;
+    assert_eq!(code, tonic::Code::Ok);
+    let code = rate_limited_call(
+        &mut service,
+        "ExecuteReadOnlyCall",
+        &forwarded("1.1.1.1, 2.2.2.2"),
+    )
+    .await;
+    assert_eq!(code, tonic::Code::ResourceExhausted);
+
+    // only the last forwarded IP, appended by the trusted proxy, identifies the client
+    let code = rate_limited_call(
+        &mut service,
+        "ExecuteReadOnlyCall",
+        &forwarded("9.9.9.9, 2.2.2.2"),
+    )
+    .await;
+    assert_eq!(code, tonic::Code::ResourceExhausted);
+    let code = rate_limited_call(
+        &mut service,
+        "ExecuteReadOnlyCall",
+        &forwarded("2.2.2.2, 3.3.3.3"),
+    )
+    .await;
+    assert_eq!(code, tonic::Code::Ok);
+
+    // known tokens identify their client first
+    let code = rate_limited_call(
+        &mut service,
+        "ExecuteReadOnlyCall",
+        &[
+            ("x-forwarded-for", "2.2.2.2"),
+            ("authorization", "Bearer indexer"),
+        ],
+    )
+    .await;
+    assert_eq!(code, tonic::Code::Ok);
+
+    // the other methods cost one unit
+    for _ in 0..10 {
+        assert_eq!(
+            rate_limited_call(&mut service, "GetStatus", &[]).await,
+            tonic::Code::Ok
+        );
+    }
+    let code = rate_limited_call(&mut service, "GetStatus", &[]).await;
+    assert_eq!(code, tonic::Code::ResourceExhausted);
+}
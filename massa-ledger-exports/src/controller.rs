@@ -6,7 +6,7 @@ use massa_models::{
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 
-use crate::{Key, LedgerChanges, LedgerError};
+use crate::{Key, LedgerChanges, LedgerError, LedgerProof};
 
 pub trait LedgerController: Send + Sync + Debug {
     /// Allows applying `LedgerChanges` to the final ledger
@@ -58,6 +58,13 @@ pub trait LedgerController: Send + Sync + Debug {
     /// Get the current disk ledger hash
     fn get_ledger_hash(&self) -> Hash;
 
+    /// Gets the values of a batch of ledger keys along with the proofs of their presence,
+    /// or absence, in the ledger tree
+    ///
+    /// # Returns
+    /// For each key, the serialized value, or `None` if the key was not found, and the associated proof
+    fn get_ledger_proofs(&self, keys: &[Key]) -> Vec<(Option<Vec<u8>>, LedgerProof)>;
+
     /// Get a part of the ledger
     /// Used for bootstrap
     /// Return: Tuple with data and last key
@@ -66,11 +73,31 @@ pub trait LedgerController: Send + Sync + Debug {
         last_key: StreamingStep<Key>,
     ) -> Result<(Vec<u8>, StreamingStep<Key>), ModelsError>;
 
+    /// Get a part of the ledger, restricted to the addresses in `[start, end)`
+    /// Used for bootstrap from several servers
+    /// Return: Tuple with data and last key
+    fn get_ledger_range_part(
+        &self,
+        last_key: StreamingStep<Key>,
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<(Vec<u8>, StreamingStep<Key>), ModelsError>;
+
     /// Set a part of the ledger
     /// Used for bootstrap
     /// Return: Last key inserted
     fn set_ledger_part(&self, data: Vec<u8>) -> Result<StreamingStep<Key>, ModelsError>;
 
+    /// Set a part of the ledger, refusing the keys out of the addresses in `[start, end)`
+    /// Used for bootstrap from several servers
+    /// Return: Last key inserted
+    fn set_ledger_range_part(
+        &self,
+        data: Vec<u8>,
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<StreamingStep<Key>, ModelsError>;
+
     /// Reset the ledger
     ///
     /// USED FOR BOOTSTRAP ONLY
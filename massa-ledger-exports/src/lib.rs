@@ -10,6 +10,7 @@ mod error;
 mod key;
 mod ledger_changes;
 mod ledger_entry;
+mod proof;
 mod types;
 
 pub use config::LedgerConfig;
@@ -25,6 +26,10 @@ pub use ledger_changes::{
     LedgerEntryUpdateDeserializer, LedgerEntryUpdateSerializer,
 };
 pub use ledger_entry::{LedgerEntry, LedgerEntryDeserializer, LedgerEntrySerializer};
+pub use proof::{
+    ledger_tree_internal_hash, ledger_tree_key_bit, ledger_tree_leaf_hash, ledger_value_hash,
+    LedgerProof, LEDGER_TREE_EMPTY_HASH,
+};
 pub use types::{Applicable, SetOrDelete, SetOrKeep, SetUpdateOrDelete};
 
 #[cfg(feature = "testing")]
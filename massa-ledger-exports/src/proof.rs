@@ -0,0 +1,157 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! This file defines the authenticated structure committing the ledger:
+//! a sparse Merkle tree indexed by the hash of the ledger keys, in which subtrees
+//! containing a single leaf are shortened to that leaf, and the associated proofs.
+//!
+//! * the hash of an empty subtree is `LEDGER_TREE_EMPTY_HASH`
+//! * the hash of a leaf is `H(0x00 || key hash || value hash)`
+//! * the hash of an internal node is `H(0x01 || left child hash || right child hash)`
+//! * the ledger hash is the root hash XORed with the hash of the serialized ledger slot
+
+use crate::{Key, KeySerializer, LedgerError};
+use massa_hash::{Hash, HASH_SIZE_BYTES};
+use massa_models::slot::{Slot, SlotSerializer};
+use massa_serialization::{Serializer, U64VarIntSerializer};
+use serde::{Deserialize, Serialize};
+
+/// Hash of an empty ledger tree or subtree
+pub const LEDGER_TREE_EMPTY_HASH: &[u8; HASH_SIZE_BYTES] = &[0; HASH_SIZE_BYTES];
+
+const LEAF_PREFIX: u8 = 0;
+const INTERNAL_PREFIX: u8 = 1;
+
+/// Computes the hash of a ledger tree leaf
+pub fn ledger_tree_leaf_hash(key_hash: &Hash, value_hash: &Hash) -> Hash {
+    Hash::compute_from(
+        &[
+            &[LEAF_PREFIX][..],
+            &key_hash.to_bytes()[..],
+            &value_hash.to_bytes()[..],
+        ]
+        .concat(),
+    )
+}
+
+/// Computes the hash of a ledger tree internal node
+pub fn ledger_tree_internal_hash(left: &Hash, right: &Hash) -> Hash {
+    Hash::compute_from(
+        &[
+            &[INTERNAL_PREFIX][..],
+            &left.to_bytes()[..],
+            &right.to_bytes()[..],
+        ]
+        .concat(),
+    )
+}
+
+/// Returns the direction taken at `depth` by the path of a key hash in the ledger tree:
+/// `false` for the left child, `true` for the right one
+pub fn ledger_tree_key_bit(key_hash: &Hash, depth: usize) -> bool {
+    (key_hash.to_bytes()[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+/// Computes the hash of the value of a ledger key as it is committed in the ledger tree
+///
+/// # Arguments
+/// * `db_key`: the ledger key serialized without the datastore key length, as stored on disk
+/// * `value`: the serialized value
+pub fn ledger_value_hash(db_key: &[u8], value: &[u8]) -> Hash {
+    let mut len_bytes = Vec::new();
+    // varint serialization never fails
+    U64VarIntSerializer::new()
+        .serialize(&(db_key.len() as u64), &mut len_bytes)
+        .unwrap();
+    Hash::compute_from(&[&len_bytes[..], db_key, value].concat())
+}
+
+/// Proof of inclusion, or non-inclusion, of a key in the ledger tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerProof {
+    /// hashes of the siblings met on the path from the root to the position of the key, root first
+    pub siblings: Vec<Hash>,
+    /// leaf found at the position of the key as `(key hash, value hash)`, if any.
+    /// For a non-inclusion proof, it is either absent or the leaf of another key sharing the same path.
+    pub leaf: Option<(Hash, Hash)>,
+}
+
+impl LedgerProof {
+    /// Computes the root of the ledger tree for which the proof holds,
+    /// given the claimed value of the key
+    ///
+    /// # Arguments
+    /// * `key`: the proven ledger key
+    /// * `value`: the claimed serialized value, `None` to check the absence of the key
+    pub fn compute_root(&self, key: &Key, value: Option<&[u8]>) -> Result<Hash, LedgerError> {
+        let mut db_key = Vec::new();
+        KeySerializer::new(false)
+            .serialize(key, &mut db_key)
+            .map_err(|err| LedgerError::InvalidProof(err.to_string()))?;
+        let key_hash = Hash::compute_from(&db_key);
+        if self.siblings.len() > HASH_SIZE_BYTES * 8 {
+            return Err(LedgerError::InvalidProof("proof is too long".into()));
+        }
+
+        let mut hash = match (value, &self.leaf) {
+            (Some(value), Some((leaf_key_hash, leaf_value_hash))) => {
+                if *leaf_key_hash != key_hash
+                    || *leaf_value_hash != ledger_value_hash(&db_key, value)
+                {
+                    return Err(LedgerError::InvalidProof(
+                        "proof leaf does not match the claimed value".into(),
+                    ));
+                }
+                ledger_tree_leaf_hash(leaf_key_hash, leaf_value_hash)
+            }
+            (Some(_), None) => {
+                return Err(LedgerError::InvalidProof(
+                    "proof of absence given for a present value".into(),
+                ))
+            }
+            (None, Some((leaf_key_hash, leaf_value_hash))) => {
+                // the other leaf must be found on the path of the key
+                if *leaf_key_hash == key_hash
+                    || (0..self.siblings.len()).any(|depth| {
+                        ledger_tree_key_bit(leaf_key_hash, depth)
+                            != ledger_tree_key_bit(&key_hash, depth)
+                    })
+                {
+                    return Err(LedgerError::InvalidProof(
+                        "proof leaf does not prove the absence of the key".into(),
+                    ));
+                }
+                ledger_tree_leaf_hash(leaf_key_hash, leaf_value_hash)
+            }
+            (None, None) => Hash::from_bytes(LEDGER_TREE_EMPTY_HASH),
+        };
+
+        for (depth, sibling) in self.siblings.iter().enumerate().rev() {
+            hash = if ledger_tree_key_bit(&key_hash, depth) {
+                ledger_tree_internal_hash(sibling, &hash)
+            } else {
+                ledger_tree_internal_hash(&hash, sibling)
+            };
+        }
+        Ok(hash)
+    }
+
+    /// Computes the ledger hash at `slot` for which the proof holds,
+    /// given the claimed value of the key
+    ///
+    /// # Arguments
+    /// * `key`: the proven ledger key
+    /// * `value`: the claimed serialized value, `None` to check the absence of the key
+    /// * `slot`: the slot associated to the ledger
+    pub fn compute_ledger_hash(
+        &self,
+        key: &Key,
+        value: Option<&[u8]>,
+        slot: &Slot,
+    ) -> Result<Hash, LedgerError> {
+        let mut slot_bytes = Vec::new();
+        SlotSerializer::new()
+            .serialize(slot, &mut slot_bytes)
+            .map_err(|err| LedgerError::InvalidProof(err.to_string()))?;
+        Ok(self.compute_root(key, value)? ^ Hash::compute_from(&slot_bytes))
+    }
+}
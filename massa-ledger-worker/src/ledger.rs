@@ -5,7 +5,7 @@
 use crate::ledger_db::{LedgerDB, LedgerSubEntry};
 use massa_hash::Hash;
 use massa_ledger_exports::{
-    Key, LedgerChanges, LedgerConfig, LedgerController, LedgerEntry, LedgerError,
+    Key, LedgerChanges, LedgerConfig, LedgerController, LedgerEntry, LedgerError, LedgerProof,
 };
 use massa_models::{
     address::Address,
@@ -163,6 +163,12 @@ impl LedgerController for FinalLedger {
         self.sorted_ledger.get_ledger_hash()
     }
 
+    /// Gets the values of a batch of ledger keys along with the proofs of their presence,
+    /// or absence, in the ledger tree
+    fn get_ledger_proofs(&self, keys: &[Key]) -> Vec<(Option<Vec<u8>>, LedgerProof)> {
+        self.sorted_ledger.get_ledger_proofs(keys)
+    }
+
     /// Get a part of the disk ledger.
     ///
     /// Solely used by the bootstrap.
@@ -176,6 +182,22 @@ impl LedgerController for FinalLedger {
         self.sorted_ledger.get_ledger_part(last_key)
     }
 
+    /// Get a part of the disk ledger, restricted to the addresses in `[start, end)`.
+    ///
+    /// Solely used by the bootstrap.
+    ///
+    /// # Returns
+    /// A tuple containing the data and the last returned key
+    fn get_ledger_range_part(
+        &self,
+        last_key: StreamingStep<Key>,
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<(Vec<u8>, StreamingStep<Key>), ModelsError> {
+        self.sorted_ledger
+            .get_ledger_range_part(last_key, start, end)
+    }
+
     /// Set a part of the disk ledger.
     ///
     /// Solely used by the bootstrap.
@@ -186,6 +208,22 @@ impl LedgerController for FinalLedger {
         self.sorted_ledger.set_ledger_part(data.as_bytes())
     }
 
+    /// Set a part of the disk ledger, refusing the keys out of the addresses in `[start, end)`.
+    ///
+    /// Solely used by the bootstrap.
+    ///
+    /// # Returns
+    /// The last key inserted
+    fn set_ledger_range_part(
+        &self,
+        data: Vec<u8>,
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<StreamingStep<Key>, ModelsError> {
+        self.sorted_ledger
+            .set_ledger_range_part(data.as_bytes(), start, end)
+    }
+
     /// Reset the disk ledger.
     ///
     /// USED FOR BOOTSTRAP ONLY
@@ -13,7 +13,7 @@ use massa_models::{
     slot::{Slot, SlotDeserializer, SlotSerializer},
     streaming_step::StreamingStep,
 };
-use massa_serialization::{DeserializeError, Deserializer, Serializer, U64VarIntSerializer};
+use massa_serialization::{DeserializeError, Deserializer, Serializer};
 use nom::multi::many0;
 use nom::sequence::tuple;
 use rocksdb::{
@@ -34,6 +34,7 @@ use tracing::{debug, info};
 use massa_models::amount::{Amount, AmountDeserializer};
 
 const LEDGER_CF: &str = "ledger";
+const LEDGER_TREE_CF: &str = "ledger_tree";
 const METADATA_CF: &str = "metadata";
 const FINAL_STATE_CF: &str = "final_state";
 const OPEN_ERROR: &str = "critical: rocksdb open operation failed";
@@ -42,12 +43,19 @@ const CF_ERROR: &str = "critical: rocksdb column family operation failed";
 const LEDGER_HASH_ERROR: &str = "critical: saved ledger hash is corrupted";
 const KEY_DESER_ERROR: &str = "critical: key deserialization failed";
 const KEY_SER_ERROR: &str = "critical: key serialization failed";
-const KEY_LEN_SER_ERROR: &str = "critical: key length serialization failed";
+const TREE_NODE_ERROR: &str = "critical: saved ledger tree node is corrupted";
 const SLOT_KEY: &[u8; 1] = b"s";
 const LEDGER_HASH_KEY: &[u8; 1] = b"h";
 const LEDGER_FINAL_STATE_KEY: &[u8; 2] = b"fs";
 const LEDGER_FINAL_STATE_HASH_KEY: &[u8; 3] = b"fsh";
+const LEDGER_TREE_REBUILD_KEY: &[u8; 2] = b"tr";
 const LEDGER_HASH_INITIAL_BYTES: &[u8; 32] = &[0; HASH_SIZE_BYTES];
+/// Number of ledger tree levels read at once when walking down the path of a key
+const TREE_READ_LEVELS: usize = 8;
+/// Depth of the ledger tree leaves once all the bits of their key hash are used
+const TREE_MAX_DEPTH: usize = HASH_SIZE_BYTES * 8;
+/// Number of ledger keys inserted per write batch when rebuilding the ledger tree
+const TREE_REBUILD_BATCH_SIZE: usize = 10_000;
 
 /// Ledger sub entry enum
 pub enum LedgerSubEntry {
@@ -69,6 +77,77 @@ impl LedgerSubEntry {
     }
 }
 
+/// Node of the ledger tree, as stored on disk
+#[derive(Clone)]
+enum TreeNode {
+    /// leaf holding the hashes of a ledger key and of its value
+    Leaf { key_hash: Hash, value_hash: Hash },
+    /// internal node holding the hashes of its children
+    Internal { left: Hash, right: Hash },
+}
+
+impl TreeNode {
+    fn hash(&self) -> Hash {
+        match self {
+            TreeNode::Leaf {
+                key_hash,
+                value_hash,
+            } => ledger_tree_leaf_hash(key_hash, value_hash),
+            TreeNode::Internal { left, right } => ledger_tree_internal_hash(left, right),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let (tag, first, second) = match self {
+            TreeNode::Leaf {
+                key_hash,
+                value_hash,
+            } => (0u8, key_hash, value_hash),
+            TreeNode::Internal { left, right } => (1u8, left, right),
+        };
+        [&[tag][..], &first.to_bytes()[..], &second.to_bytes()[..]].concat()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        if bytes.len() != 1 + 2 * HASH_SIZE_BYTES {
+            panic!("{}", TREE_NODE_ERROR);
+        }
+        let first = Hash::from_bytes(bytes[1..1 + HASH_SIZE_BYTES].try_into().unwrap());
+        let second = Hash::from_bytes(bytes[1 + HASH_SIZE_BYTES..].try_into().unwrap());
+        match bytes[0] {
+            0 => TreeNode::Leaf {
+                key_hash: first,
+                value_hash: second,
+            },
+            1 => TreeNode::Internal {
+                left: first,
+                right: second,
+            },
+            _ => panic!("{}", TREE_NODE_ERROR),
+        }
+    }
+}
+
+/// Builds the disk key of the ledger tree node found at `depth` on the path of `key_hash`:
+/// the big endian depth followed by the first `depth` bits of `key_hash`
+fn tree_node_path(key_hash: &Hash, depth: usize) -> Vec<u8> {
+    let mut path = (depth as u16).to_be_bytes().to_vec();
+    path.extend(&key_hash.to_bytes()[..(depth + 7) / 8]);
+    if depth % 8 != 0 {
+        if let Some(last) = path.last_mut() {
+            *last &= 0xff << (8 - depth % 8);
+        }
+    }
+    path
+}
+
+/// Builds the disk key of the sibling of the ledger tree node found at `depth + 1` on the path of `key_hash`
+fn tree_sibling_path(key_hash: &Hash, depth: usize) -> Vec<u8> {
+    let mut bytes = *key_hash.to_bytes();
+    bytes[depth / 8] ^= 1 << (7 - depth % 8);
+    tree_node_path(&Hash::from_bytes(&bytes), depth + 1)
+}
+
 /// Disk ledger DB module
 ///
 /// Contains a `RocksDB` DB instance
@@ -83,7 +162,6 @@ pub(crate) struct LedgerDB {
     bytecode_serializer: BytecodeSerializer,
     slot_serializer: SlotSerializer,
     slot_deserializer: SlotDeserializer,
-    len_serializer: U64VarIntSerializer,
     ledger_part_size_message_bytes: u64,
     #[cfg(feature = "testing")]
     amount_deserializer: AmountDeserializer,
@@ -101,8 +179,12 @@ pub struct LedgerBatch {
     write_batch: WriteBatch,
     // Ledger hash state in the current batch
     ledger_hash: Hash,
-    // Added entry hashes in the current batch
-    aeh_list: BTreeMap<Vec<u8>, Hash>,
+    // Ledger tree nodes written (`Some`) or deleted (`None`) in the current batch
+    tree_nodes: BTreeMap<Vec<u8>, Option<TreeNode>>,
+    // Ledger tree nodes read from the disk by the current batch, `None` for empty subtrees
+    tree_node_cache: HashMap<Vec<u8>, Option<TreeNode>>,
+    // Hash of the ledger tree root before the first tree update of the batch
+    initial_tree_root_hash: Option<Hash>,
 }
 
 impl LedgerBatch {
@@ -110,7 +192,9 @@ impl LedgerBatch {
         Self {
             write_batch: WriteBatch::default(),
             ledger_hash,
-            aeh_list: BTreeMap::new(),
+            tree_nodes: BTreeMap::new(),
+            tree_node_cache: HashMap::new(),
+            initial_tree_root_hash: None,
         }
     }
 }
@@ -140,6 +224,7 @@ impl LedgerDB {
                 path,
                 vec![
                     ColumnFamilyDescriptor::new(LEDGER_CF, Options::default()),
+                    ColumnFamilyDescriptor::new(LEDGER_TREE_CF, Options::default()),
                     ColumnFamilyDescriptor::new(METADATA_CF, Options::default()),
                     ColumnFamilyDescriptor::new(FINAL_STATE_CF, Options::default()),
                 ],
@@ -151,13 +236,14 @@ impl LedgerDB {
                 path,
                 vec![
                     ColumnFamilyDescriptor::new(LEDGER_CF, Options::default()),
+                    ColumnFamilyDescriptor::new(LEDGER_TREE_CF, Options::default()),
                     ColumnFamilyDescriptor::new(METADATA_CF, Options::default()),
                 ],
             )
             .expect(OPEN_ERROR)
         };
 
-        LedgerDB {
+        let ledger_db = LedgerDB {
             db,
             thread_count,
             key_serializer: KeySerializer::new(true),
@@ -171,14 +257,82 @@ impl LedgerDB {
                 (Bound::Included(u64::MIN), Bound::Included(u64::MAX)),
                 (Bound::Included(0_u8), Bound::Excluded(thread_count)),
             ),
-            len_serializer: U64VarIntSerializer::new(),
             ledger_part_size_message_bytes,
             #[cfg(feature = "testing")]
             amount_deserializer: AmountDeserializer::new(
                 Bound::Included(Amount::MIN),
                 Bound::Included(Amount::MAX),
             ),
+        };
+        ledger_db.rebuild_tree_if_missing();
+        ledger_db
+    }
+
+    /// Builds the ledger tree of a ledger written before it was committed in a tree,
+    /// when its hash was the XOR of the hashes of its entries.
+    /// An interrupted rebuild is started over on the next opening.
+    fn rebuild_tree_if_missing(&self) {
+        let ledger_handle = self.db.cf_handle(LEDGER_CF).expect(CF_ERROR);
+        let tree_handle = self.db.cf_handle(LEDGER_TREE_CF).expect(CF_ERROR);
+        let metadata_handle = self.db.cf_handle(METADATA_CF).expect(CF_ERROR);
+        let interrupted = self
+            .db
+            .get_pinned_cf(metadata_handle, LEDGER_TREE_REBUILD_KEY)
+            .expect(CRUD_ERROR)
+            .is_some();
+        let tree_is_empty = self
+            .db
+            .iterator_cf(tree_handle, IteratorMode::Start)
+            .next()
+            .is_none();
+        let ledger_is_empty = self
+            .db
+            .iterator_cf(ledger_handle, IteratorMode::Start)
+            .next()
+            .is_none();
+        if !interrupted && (!tree_is_empty || ledger_is_empty) {
+            return;
+        }
+        info!("rebuilding the ledger tree, this may take a while");
+
+        // start over from an empty tree: tree node paths start with their big endian depth
+        let mut write_batch = WriteBatch::default();
+        write_batch.delete_range_cf(
+            tree_handle,
+            0u16.to_be_bytes(),
+            (TREE_MAX_DEPTH as u16 + 1).to_be_bytes(),
+        );
+        write_batch.put_cf(metadata_handle, LEDGER_TREE_REBUILD_KEY, b"");
+        self.db.write(write_batch).expect(CRUD_ERROR);
+
+        // the hash of a ledger with an empty tree only commits its slot
+        let mut ledger_hash = Hash::from_bytes(LEDGER_HASH_INITIAL_BYTES);
+        if let Some(slot_bytes) = self
+            .db
+            .get_pinned_cf(metadata_handle, SLOT_KEY)
+            .expect(CRUD_ERROR)
+        {
+            ledger_hash ^= Hash::compute_from(&slot_bytes);
+        }
+        let mut batch = LedgerBatch::new(ledger_hash);
+        let mut key_count = 0;
+        for (key, value) in self
+            .db
+            .iterator_cf(ledger_handle, IteratorMode::Start)
+            .flatten()
+        {
+            self.update_tree(&mut batch, &key, Some(&value));
+            key_count += 1;
+            if key_count % TREE_REBUILD_BATCH_SIZE == 0 {
+                self.write_batch(batch);
+                batch = LedgerBatch::new(self.get_ledger_hash());
+            }
         }
+        batch
+            .write_batch
+            .delete_cf(metadata_handle, LEDGER_TREE_REBUILD_KEY);
+        self.write_batch(batch);
+        info!("ledger tree rebuilt from {} ledger keys", key_count);
     }
 
     pub fn set_initial_slot(&mut self, slot: Slot) {
@@ -328,6 +482,61 @@ impl LedgerDB {
         Some(iter.collect())
     }
 
+    /// Gets the values of a batch of ledger keys along with the proofs of their presence,
+    /// or absence, in the ledger tree
+    ///
+    /// # Returns
+    /// For each key, the serialized value, or `None` if the key was not found, and the associated proof
+    pub fn get_ledger_proofs(&self, keys: &[Key]) -> Vec<(Option<Vec<u8>>, LedgerProof)> {
+        let handle = self.db.cf_handle(LEDGER_CF).expect(CF_ERROR);
+        let serialized_keys: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|key| {
+                let mut serialized_key = Vec::new();
+                self.key_serializer_db
+                    .serialize(key, &mut serialized_key)
+                    .expect(KEY_SER_ERROR);
+                serialized_key
+            })
+            .collect();
+        let values = self
+            .db
+            .multi_get_cf(serialized_keys.iter().map(|key| (handle, key)));
+
+        // the nodes close to the root are shared by the paths of the keys: they are read once
+        let no_pending_nodes = BTreeMap::new();
+        let mut cache = HashMap::new();
+        serialized_keys
+            .iter()
+            .zip(values)
+            .map(|(serialized_key, value)| {
+                // walk down the path of the key, collecting the hashes of the siblings
+                let key_hash = Hash::compute_from(serialized_key);
+                self.load_tree_path(&no_pending_nodes, &mut cache, &key_hash);
+                let mut siblings = Vec::new();
+                let leaf = loop {
+                    let depth = siblings.len();
+                    let path = tree_node_path(&key_hash, depth);
+                    match self.lookup_tree_node(&no_pending_nodes, &cache, &path) {
+                        Some(TreeNode::Internal { left, right }) => {
+                            siblings.push(if ledger_tree_key_bit(&key_hash, depth) {
+                                left
+                            } else {
+                                right
+                            });
+                        }
+                        Some(TreeNode::Leaf {
+                            key_hash,
+                            value_hash,
+                        }) => break Some((key_hash, value_hash)),
+                        None => break None,
+                    }
+                };
+                (value.expect(CRUD_ERROR), LedgerProof { siblings, leaf })
+            })
+            .collect()
+    }
+
     /// Get a part of the disk Ledger.
     /// Mainly used in the bootstrap process.
     ///
@@ -342,17 +551,62 @@ impl LedgerDB {
         &self,
         cursor: StreamingStep<Key>,
     ) -> Result<(Vec<u8>, StreamingStep<Key>), ModelsError> {
+        self.get_ledger_range_part(cursor, None, None)
+    }
+
+    /// Get a part of the ledger, restricted to the addresses in `[start, end)`.
+    /// Used to bootstrap disjoint ranges of the ledger from several servers.
+    ///
+    /// # Arguments
+    /// * `cursor`: key where the part retrieving must start
+    /// * `start`: first address of the range, `None` to start at the beginning of the ledger
+    /// * `end`: address at which the range ends, `None` to go up to the end of the ledger
+    ///
+    /// # Returns
+    /// A tuple containing:
+    /// * The ledger part as bytes
+    /// * The last taken key, or `Finished` once the end of the range is reached
+    pub fn get_ledger_range_part(
+        &self,
+        cursor: StreamingStep<Key>,
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<(Vec<u8>, StreamingStep<Key>), ModelsError> {
+        if let StreamingStep::Ongoing(last_key) = &cursor {
+            if !is_in_range(&last_key.address, start, end) {
+                return Err(ModelsError::InvalidLedgerChange(format!(
+                    "ledger cursor {} is out of the requested range",
+                    last_key.address
+                )));
+            }
+        }
         let handle = self.db.cf_handle(LEDGER_CF).expect(CF_ERROR);
         let opt = ReadOptions::default();
         let ser = VecU8Serializer::new();
         let mut ledger_part = Vec::new();
 
-        // Creates an iterator from the next element after the last if defined, otherwise initialize it at the first key of the ledger.
+        // Creates an iterator from the next element after the last if defined, otherwise initialize it at the first key of the range.
         let (db_iterator, mut new_cursor) = match cursor {
-            StreamingStep::Started => (
-                self.db.iterator_cf_opt(handle, opt, IteratorMode::Start),
-                StreamingStep::<Key>::Started,
-            ),
+            StreamingStep::Started => match start {
+                Some(start) => {
+                    // the balance is the first key of an address
+                    let mut serialized_key = Vec::new();
+                    self.key_serializer_db
+                        .serialize(&Key::new(start, KeyType::BALANCE), &mut serialized_key)?;
+                    (
+                        self.db.iterator_cf_opt(
+                            handle,
+                            opt,
+                            IteratorMode::From(&serialized_key, Direction::Forward),
+                        ),
+                        StreamingStep::Finished(None),
+                    )
+                }
+                None => (
+                    self.db.iterator_cf_opt(handle, opt, IteratorMode::Start),
+                    StreamingStep::<Key>::Started,
+                ),
+            },
             StreamingStep::Ongoing(last_key) => {
                 let mut serialized_key = Vec::new();
                 self.key_serializer_db
@@ -374,6 +628,10 @@ impl LedgerDB {
                 // We deserialize and re-serialize the key to change the key format from the
                 // database one to a format we can use outside of the ledger.
                 let (_, key) = self.key_deserializer_db.deserialize(&key)?;
+                if matches!(end, Some(end) if key.address >= *end) {
+                    new_cursor = StreamingStep::Finished(None);
+                    break;
+                }
                 self.key_serializer.serialize(&key, &mut ledger_part)?;
                 ser.serialize(&entry.to_vec(), &mut ledger_part)?;
                 new_cursor = StreamingStep::Ongoing(key);
@@ -393,7 +651,27 @@ impl LedgerDB {
     ///
     /// # Returns
     /// The last key of the inserted entry (this is an optimization to easily keep a reference to the last key)
-    pub fn set_ledger_part<'a>(&self, data: &'a [u8]) -> Result<StreamingStep<Key>, ModelsError> {
+    pub fn set_ledger_part(&self, data: &[u8]) -> Result<StreamingStep<Key>, ModelsError> {
+        self.set_ledger_range_part(data, None, None)
+    }
+
+    /// Set a part of the ledger in the database, restricted to the addresses in `[start, end)`.
+    /// Nothing is written if the part contains a key out of the range.
+    /// Used to bootstrap disjoint ranges of the ledger from several servers.
+    ///
+    /// # Arguments
+    /// * data: must be the serialized version provided by `get_ledger_range_part`
+    /// * `start`: first address of the range, `None` to start at the beginning of the ledger
+    /// * `end`: address at which the range ends, `None` to go up to the end of the ledger
+    ///
+    /// # Returns
+    /// The last key of the inserted entry
+    pub fn set_ledger_range_part<'a>(
+        &self,
+        data: &'a [u8],
+        start: Option<&Address>,
+        end: Option<&Address>,
+    ) -> Result<StreamingStep<Key>, ModelsError> {
         let handle = self.db.cf_handle(LEDGER_CF).expect(CF_ERROR);
         let vec_u8_deserializer =
             VecU8Deserializer::new(Bound::Included(0), Bound::Excluded(u64::MAX));
@@ -406,13 +684,26 @@ impl LedgerDB {
                 |input| self.key_deserializer.deserialize(input),
                 |input| vec_u8_deserializer.deserialize(input),
             ))(input)?;
+            if !is_in_range(&key.address, start, end) {
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )));
+            }
             *Rc::get_mut(&mut last_key).ok_or_else(|| {
                 nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Fail))
             })? = Some(key.clone());
             self.put_entry_value(handle, &mut batch, &key, &value);
             Ok((rest, ()))
         })(data)
-        .map_err(|_| ModelsError::SerializeError("Error in deserialization".to_string()))?;
+        .map_err(|err| match err {
+            nom::Err::Failure(err) if err.code == nom::error::ErrorKind::Verify => {
+                ModelsError::InvalidLedgerChange(
+                    "ledger part contains a key out of the requested range".to_string(),
+                )
+            }
+            _ => ModelsError::SerializeError("Error in deserialization".to_string()),
+        })?;
 
         match last_key.as_ref() {
             Some(last_key) => {
@@ -433,6 +724,9 @@ impl LedgerDB {
         self.db
             .drop_cf(LEDGER_CF)
             .expect("Error dropping ledger cf");
+        self.db
+            .drop_cf(LEDGER_TREE_CF)
+            .expect("Error dropping ledger tree cf");
         self.db
             .drop_cf(METADATA_CF)
             .expect("Error dropping metadata cf");
@@ -441,6 +735,9 @@ impl LedgerDB {
         self.db
             .create_cf(LEDGER_CF, &db_opts)
             .expect("Error creating ledger cf");
+        self.db
+            .create_cf(LEDGER_TREE_CF, &db_opts)
+            .expect("Error creating ledger tree cf");
         self.db
             .create_cf(METADATA_CF, &db_opts)
             .expect("Error creating metadata cf");
@@ -476,6 +773,18 @@ impl LedgerDB {
 impl LedgerDB {
     /// Apply the given operation batch to the disk ledger
     fn write_batch(&self, mut batch: LedgerBatch) {
+        // the tree root is only read before and after the batch, not once per updated key
+        if let Some(initial_root_hash) = batch.initial_tree_root_hash {
+            batch.ledger_hash ^= initial_root_hash;
+            batch.ledger_hash ^= self.get_tree_root_hash(&batch);
+        }
+        let tree_handle = self.db.cf_handle(LEDGER_TREE_CF).expect(CF_ERROR);
+        for (path, node) in std::mem::take(&mut batch.tree_nodes) {
+            match node {
+                Some(node) => batch.write_batch.put_cf(tree_handle, path, node.to_bytes()),
+                None => batch.write_batch.delete_cf(tree_handle, path),
+            }
+        }
         let handle = self.db.cf_handle(METADATA_CF).expect(CF_ERROR);
         batch
             .write_batch
@@ -517,7 +826,236 @@ impl LedgerDB {
         Ok(slot)
     }
 
-    /// Internal function to put a key & value and perform the ledger hash XORs
+    /// Reads a ledger tree node from the disk
+    fn read_tree_node(&self, path: &[u8]) -> Option<TreeNode> {
+        let handle = self.db.cf_handle(LEDGER_TREE_CF).expect(CF_ERROR);
+        self.db
+            .get_pinned_cf(handle, path)
+            .expect(CRUD_ERROR)
+            .map(|bytes| TreeNode::from_bytes(&bytes))
+    }
+
+    /// Reads a ledger tree node, looking first at the `pending` changes of a batch,
+    /// then at the nodes already read from the disk
+    fn lookup_tree_node(
+        &self,
+        pending: &BTreeMap<Vec<u8>, Option<TreeNode>>,
+        cache: &HashMap<Vec<u8>, Option<TreeNode>>,
+        path: &[u8],
+    ) -> Option<TreeNode> {
+        match pending.get(path).or_else(|| cache.get(path)) {
+            Some(node) => node.clone(),
+            None => self.read_tree_node(path),
+        }
+    }
+
+    /// Reads a ledger tree node, taking the pending changes of the batch into account
+    fn get_tree_node(&self, batch: &LedgerBatch, path: &[u8]) -> Option<TreeNode> {
+        self.lookup_tree_node(&batch.tree_nodes, &batch.tree_node_cache, path)
+    }
+
+    /// Reads the nodes on the path of `key_hash` that are neither pending nor cached into `cache`,
+    /// with one disk access per `TREE_READ_LEVELS` levels, until a leaf or an empty subtree is met
+    fn load_tree_path(
+        &self,
+        pending: &BTreeMap<Vec<u8>, Option<TreeNode>>,
+        cache: &mut HashMap<Vec<u8>, Option<TreeNode>>,
+        key_hash: &Hash,
+    ) {
+        let handle = self.db.cf_handle(LEDGER_TREE_CF).expect(CF_ERROR);
+        let mut depth = 0;
+        while depth <= TREE_MAX_DEPTH {
+            let paths: Vec<Vec<u8>> = (depth..=(depth + TREE_READ_LEVELS - 1).min(TREE_MAX_DEPTH))
+                .map(|depth| tree_node_path(key_hash, depth))
+                .collect();
+            let missing: Vec<&Vec<u8>> = paths
+                .iter()
+                .filter(|path| !pending.contains_key(*path) && !cache.contains_key(*path))
+                .collect();
+            let nodes = self
+                .db
+                .multi_get_cf(missing.iter().map(|path| (handle, path)));
+            for (path, node) in missing.into_iter().zip(nodes) {
+                let node = node
+                    .expect(CRUD_ERROR)
+                    .map(|bytes| TreeNode::from_bytes(&bytes));
+                cache.insert(path.clone(), node);
+            }
+            // the path goes on below the last level read only through an internal node
+            match paths
+                .last()
+                .and_then(|path| self.lookup_tree_node(pending, cache, path))
+            {
+                Some(TreeNode::Internal { .. }) => depth += TREE_READ_LEVELS,
+                _ => return,
+            }
+        }
+    }
+
+    /// Get the hash of the ledger tree root, taking the pending changes of the batch into account
+    fn get_tree_root_hash(&self, batch: &LedgerBatch) -> Hash {
+        self.get_tree_node(
+            batch,
+            &tree_node_path(&Hash::from_bytes(LEDGER_TREE_EMPTY_HASH), 0),
+        )
+        .map(|node| node.hash())
+        .unwrap_or_else(|| Hash::from_bytes(LEDGER_TREE_EMPTY_HASH))
+    }
+
+    /// Sets (`Some`) or removes (`None`) the value of a serialized key in the ledger tree.
+    /// The ledger hash of the batch is updated with the new tree root when the batch is written.
+    fn update_tree(&self, batch: &mut LedgerBatch, serialized_key: &[u8], value: Option<&[u8]>) {
+        if batch.initial_tree_root_hash.is_none() {
+            batch.initial_tree_root_hash = Some(self.get_tree_root_hash(batch));
+        }
+        let key_hash = Hash::compute_from(serialized_key);
+        self.load_tree_path(&batch.tree_nodes, &mut batch.tree_node_cache, &key_hash);
+        match value {
+            Some(value) => {
+                self.tree_insert(
+                    batch,
+                    &key_hash,
+                    &ledger_value_hash(serialized_key, value),
+                    0,
+                );
+            }
+            None => {
+                self.tree_remove(batch, &key_hash, 0);
+            }
+        }
+    }
+
+    /// Inserts or updates a leaf in the ledger subtree found at `depth` on the path of `key_hash`
+    ///
+    /// # Returns
+    /// The new hash of the subtree
+    fn tree_insert(
+        &self,
+        batch: &mut LedgerBatch,
+        key_hash: &Hash,
+        value_hash: &Hash,
+        depth: usize,
+    ) -> Hash {
+        let path = tree_node_path(key_hash, depth);
+        let node = match self.get_tree_node(batch, &path) {
+            Some(TreeNode::Leaf {
+                key_hash: other_key_hash,
+                value_hash: other_value_hash,
+            }) if other_key_hash != *key_hash => {
+                // push the other leaf one level down, then insert the new leaf next to it
+                let other_leaf = TreeNode::Leaf {
+                    key_hash: other_key_hash,
+                    value_hash: other_value_hash,
+                };
+                let other_leaf_hash = other_leaf.hash();
+                batch
+                    .tree_nodes
+                    .insert(tree_node_path(&other_key_hash, depth + 1), Some(other_leaf));
+                let child_hash = self.tree_insert(batch, key_hash, value_hash, depth + 1);
+                let mut children = [Hash::from_bytes(LEDGER_TREE_EMPTY_HASH); 2];
+                children[ledger_tree_key_bit(&other_key_hash, depth) as usize] = other_leaf_hash;
+                children[ledger_tree_key_bit(key_hash, depth) as usize] = child_hash;
+                TreeNode::Internal {
+                    left: children[0],
+                    right: children[1],
+                }
+            }
+            Some(TreeNode::Internal { left, right }) => {
+                let child_hash = self.tree_insert(batch, key_hash, value_hash, depth + 1);
+                if ledger_tree_key_bit(key_hash, depth) {
+                    TreeNode::Internal {
+                        left,
+                        right: child_hash,
+                    }
+                } else {
+                    TreeNode::Internal {
+                        left: child_hash,
+                        right,
+                    }
+                }
+            }
+            // empty subtree, or leaf of the same key
+            _ => TreeNode::Leaf {
+                key_hash: *key_hash,
+                value_hash: *value_hash,
+            },
+        };
+        let hash = node.hash();
+        batch.tree_nodes.insert(path, Some(node));
+        hash
+    }
+
+    /// Removes a leaf from the ledger subtree found at `depth` on the path of `key_hash`.
+    /// Subtrees left with a single leaf are shortened to that leaf.
+    ///
+    /// # Returns
+    /// The new root node of the subtree, `None` if it is empty
+    fn tree_remove(
+        &self,
+        batch: &mut LedgerBatch,
+        key_hash: &Hash,
+        depth: usize,
+    ) -> Option<TreeNode> {
+        let path = tree_node_path(key_hash, depth);
+        match self.get_tree_node(batch, &path) {
+            Some(TreeNode::Leaf {
+                key_hash: leaf_key_hash,
+                ..
+            }) if leaf_key_hash == *key_hash => {
+                batch.tree_nodes.insert(path, None);
+                None
+            }
+            Some(TreeNode::Internal { left, right }) => {
+                let empty_hash = Hash::from_bytes(LEDGER_TREE_EMPTY_HASH);
+                let bit = ledger_tree_key_bit(key_hash, depth);
+                let child = self.tree_remove(batch, key_hash, depth + 1);
+                let child_hash = child.as_ref().map(|node| node.hash()).unwrap_or(empty_hash);
+                let sibling_hash = if bit { left } else { right };
+
+                // look for a single remaining leaf to move up
+                let single_leaf = if sibling_hash == empty_hash {
+                    match child {
+                        Some(TreeNode::Leaf { .. }) => {
+                            child.map(|leaf| (tree_node_path(key_hash, depth + 1), leaf))
+                        }
+                        _ => None,
+                    }
+                } else if child_hash == empty_hash {
+                    let sibling_path = tree_sibling_path(key_hash, depth);
+                    match self.get_tree_node(batch, &sibling_path) {
+                        Some(leaf @ TreeNode::Leaf { .. }) => Some((sibling_path, leaf)),
+                        _ => None,
+                    }
+                } else {
+                    None
+                };
+
+                let node = if let Some((leaf_path, leaf)) = single_leaf {
+                    batch.tree_nodes.insert(leaf_path, None);
+                    leaf
+                } else if child_hash == empty_hash && sibling_hash == empty_hash {
+                    batch.tree_nodes.insert(path, None);
+                    return None;
+                } else if bit {
+                    TreeNode::Internal {
+                        left,
+                        right: child_hash,
+                    }
+                } else {
+                    TreeNode::Internal {
+                        left: child_hash,
+                        right,
+                    }
+                };
+                batch.tree_nodes.insert(path, Some(node.clone()));
+                Some(node)
+            }
+            // the key is not in the subtree
+            node => node,
+        }
+    }
+
+    /// Internal function to put a key & value and update the ledger tree
     fn put_entry_value(
         &self,
         handle: &ColumnFamily,
@@ -529,13 +1067,7 @@ impl LedgerDB {
         self.key_serializer_db
             .serialize(key, &mut serialized_key)
             .expect(KEY_SER_ERROR);
-        let mut len_bytes = Vec::new();
-        self.len_serializer
-            .serialize(&(serialized_key.len() as u64), &mut len_bytes)
-            .expect(KEY_LEN_SER_ERROR);
-        let hash = Hash::compute_from(&[&len_bytes, &serialized_key, value].concat());
-        batch.ledger_hash ^= hash;
-        batch.aeh_list.insert(serialized_key.clone(), hash);
+        self.update_tree(batch, &serialized_key, Some(value));
         batch.write_batch.put_cf(handle, serialized_key, value);
     }
 
@@ -585,39 +1117,6 @@ impl LedgerDB {
         }
     }
 
-    /// Internal function to update a key & value and perform the ledger hash XORs
-    fn update_key_value(
-        &self,
-        handle: &ColumnFamily,
-        batch: &mut LedgerBatch,
-        key: &Key,
-        value: &[u8],
-    ) {
-        let mut serialized_key = Vec::new();
-        self.key_serializer_db
-            .serialize(key, &mut serialized_key)
-            .expect(KEY_SER_ERROR);
-
-        let mut len_bytes = Vec::new();
-        self.len_serializer
-            .serialize(&(serialized_key.len() as u64), &mut len_bytes)
-            .expect(KEY_LEN_SER_ERROR);
-        if let Some(added_hash) = batch.aeh_list.get(&serialized_key) {
-            batch.ledger_hash ^= *added_hash;
-        } else if let Some(prev_bytes) = self
-            .db
-            .get_pinned_cf(handle, &serialized_key)
-            .expect(CRUD_ERROR)
-        {
-            batch.ledger_hash ^=
-                Hash::compute_from(&[&len_bytes, &serialized_key, &prev_bytes[..]].concat());
-        }
-        let hash = Hash::compute_from(&[&len_bytes, &serialized_key, value].concat());
-        batch.ledger_hash ^= hash;
-        batch.aeh_list.insert(serialized_key.clone(), hash);
-        batch.write_batch.put_cf(handle, serialized_key, value);
-    }
-
     /// Update the ledger entry of a given address.
     ///
     /// # Arguments
@@ -640,7 +1139,7 @@ impl LedgerDB {
                 .unwrap();
 
             let balance_key = Key::new(addr, KeyType::BALANCE);
-            self.update_key_value(handle, batch, &balance_key, &bytes);
+            self.put_entry_value(handle, batch, &balance_key, &bytes);
         }
 
         // bytecode
@@ -651,7 +1150,7 @@ impl LedgerDB {
                 .unwrap();
 
             let bytecode_key = Key::new(addr, KeyType::BYTECODE);
-            self.update_key_value(handle, batch, &bytecode_key, &bytes);
+            self.put_entry_value(handle, batch, &bytecode_key, &bytes);
         }
 
         // datastore
@@ -659,33 +1158,20 @@ impl LedgerDB {
             let datastore_key = Key::new(addr, KeyType::DATASTORE(hash));
             match update {
                 SetOrDelete::Set(entry) => {
-                    self.update_key_value(handle, batch, &datastore_key, &entry)
+                    self.put_entry_value(handle, batch, &datastore_key, &entry)
                 }
                 SetOrDelete::Delete => self.delete_key(handle, batch, &datastore_key),
             }
         }
     }
 
-    /// Internal function to delete a key and perform the ledger hash XOR
+    /// Internal function to delete a key and update the ledger tree
     fn delete_key(&self, handle: &ColumnFamily, batch: &mut LedgerBatch, key: &Key) {
         let mut serialized_key = Vec::new();
         self.key_serializer_db
             .serialize(key, &mut serialized_key)
             .expect(KEY_SER_ERROR);
-        if let Some(added_hash) = batch.aeh_list.get(&serialized_key) {
-            batch.ledger_hash ^= *added_hash;
-        } else if let Some(prev_bytes) = self
-            .db
-            .get_pinned_cf(handle, &serialized_key)
-            .expect(CRUD_ERROR)
-        {
-            let mut len_bytes = Vec::new();
-            self.len_serializer
-                .serialize(&(serialized_key.len() as u64), &mut len_bytes)
-                .expect(KEY_LEN_SER_ERROR);
-            batch.ledger_hash ^=
-                Hash::compute_from(&[&len_bytes, &serialized_key, &prev_bytes[..]].concat());
-        }
+        self.update_tree(batch, &serialized_key, None);
         batch.write_batch.delete_cf(handle, serialized_key);
     }
 
@@ -817,6 +1303,11 @@ fn end_prefix(prefix: &[u8]) -> Option<Vec<u8>> {
     }
 }
 
+/// Whether an address belongs to the range `[start, end)`, unbounded sides being `None`
+fn is_in_range(address: &Address, start: Option<&Address>, end: Option<&Address>) -> bool {
+    start.map_or(true, |start| address >= start) && end.map_or(true, |end| address < end)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -914,6 +1405,189 @@ mod tests {
         db.set_ledger_part(&res.0[..]).unwrap();
     }
 
+    #[test]
+    fn test_ledger_range_parts() {
+        let pub_a = KeyPair::generate().get_public_key();
+        let a = Address::from_public_key(&pub_a);
+        let (db, _) = init_test_ledger(a);
+        let (part, _) = db.get_ledger_part(StreamingStep::Started).unwrap();
+
+        // the range ending at the address does not contain it
+        let (before, step) = db
+            .get_ledger_range_part(StreamingStep::Started, None, Some(&a))
+            .unwrap();
+        assert!(before.is_empty());
+        assert!(step.finished());
+
+        // the range starting at the address contains all its entries
+        let (from, step) = db
+            .get_ledger_range_part(StreamingStep::Started, Some(&a), None)
+            .unwrap();
+        assert_eq!(from, part);
+        assert!(matches!(step, StreamingStep::Ongoing(ref key) if key.address == a));
+
+        // a cursor out of the range is refused
+        assert!(db.get_ledger_range_part(step, None, Some(&a)).is_err());
+
+        // keys out of the range are refused and nothing is written
+        assert!(matches!(
+            db.set_ledger_range_part(&part[..], None, Some(&a)),
+            Err(ModelsError::InvalidLedgerChange(_))
+        ));
+        assert!(db.set_ledger_range_part(&part[..], Some(&a), None).is_ok());
+    }
+
+    #[test]
+    fn test_ledger_proofs() {
+        let addr = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let (mut db, data) = init_test_ledger(addr);
+        let slot = Slot::new(1, 0);
+        db.set_initial_slot(slot);
+        let ledger_hash = db.get_ledger_hash();
+
+        // inclusion proofs
+        for (datastore_key, value) in data {
+            let key = Key::new(&addr, KeyType::DATASTORE(datastore_key));
+            let (proven_value, proof) = db.get_ledger_proofs(&[key.clone()]).pop().unwrap();
+            assert_eq!(proven_value, Some(value.clone()));
+            assert_eq!(
+                proof
+                    .compute_ledger_hash(&key, Some(&value), &slot)
+                    .unwrap(),
+                ledger_hash
+            );
+            assert!(proof
+                .compute_ledger_hash(&key, Some(b"wrong"), &slot)
+                .is_err());
+            assert!(proof.compute_ledger_hash(&key, None, &slot).is_err());
+        }
+
+        // non-inclusion proof
+        let key = Key::new(&addr, KeyType::DATASTORE(b"4".to_vec()));
+        let (proven_value, proof) = db.get_ledger_proofs(&[key.clone()]).pop().unwrap();
+        assert!(proven_value.is_none());
+        assert_eq!(
+            proof.compute_ledger_hash(&key, None, &slot).unwrap(),
+            ledger_hash
+        );
+        assert!(proof.compute_ledger_hash(&key, Some(b"d"), &slot).is_err());
+    }
+
+    #[test]
+    fn test_ledger_tree_history_independence() {
+        let addr = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let keys: Vec<Key> = (0u8..20)
+            .map(|i| Key::new(&addr, KeyType::DATASTORE(vec![i])))
+            .collect();
+        let initial_hash = Hash::from_bytes(LEDGER_HASH_INITIAL_BYTES);
+
+        // insert the keys in order, and in reverse order with extra keys deleted afterwards
+        let temp_dir_a = TempDir::new().unwrap();
+        let db_a = LedgerDB::new(temp_dir_a.path().to_path_buf(), 32, 255, 1_000_000, false);
+        let handle_a = db_a.db.cf_handle(LEDGER_CF).unwrap();
+        let mut batch = LedgerBatch::new(initial_hash);
+        for key in &keys {
+            db_a.put_entry_value(handle_a, &mut batch, key, b"value");
+        }
+        db_a.write_batch(batch);
+
+        let temp_dir_b = TempDir::new().unwrap();
+        let db_b = LedgerDB::new(temp_dir_b.path().to_path_buf(), 32, 255, 1_000_000, false);
+        let handle_b = db_b.db.cf_handle(LEDGER_CF).unwrap();
+        let mut batch = LedgerBatch::new(initial_hash);
+        for key in keys.iter().rev() {
+            db_b.put_entry_value(handle_b, &mut batch, key, b"value");
+        }
+        for i in 20u8..30 {
+            db_b.put_entry_value(
+                handle_b,
+                &mut batch,
+                &Key::new(&addr, KeyType::DATASTORE(vec![i])),
+                b"value",
+            );
+        }
+        db_b.write_batch(batch);
+        let mut batch = LedgerBatch::new(db_b.get_ledger_hash());
+        for i in 20u8..30 {
+            db_b.delete_key(
+                handle_b,
+                &mut batch,
+                &Key::new(&addr, KeyType::DATASTORE(vec![i])),
+            );
+        }
+        db_b.write_batch(batch);
+        assert_eq!(db_a.get_ledger_hash(), db_b.get_ledger_hash());
+
+        // deleting every key empties the tree
+        let mut batch = LedgerBatch::new(db_a.get_ledger_hash());
+        for key in &keys {
+            db_a.delete_key(handle_a, &mut batch, key);
+        }
+        db_a.write_batch(batch);
+        assert_eq!(db_a.get_ledger_hash(), initial_hash);
+        assert_eq!(
+            db_a.db
+                .iterator_cf(
+                    db_a.db.cf_handle(LEDGER_TREE_CF).unwrap(),
+                    IteratorMode::Start
+                )
+                .count(),
+            0
+        );
+    }
+
+    /// A ledger written without its tree gets its tree rebuilt when opened
+    #[test]
+    fn test_ledger_tree_rebuild() {
+        let addr = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let temp_dir = TempDir::new().unwrap();
+        let db = LedgerDB::new(temp_dir.path().to_path_buf(), 32, 255, 1_000_000, false);
+        let handle = db.db.cf_handle(LEDGER_CF).unwrap();
+        let mut batch = LedgerBatch::new(Hash::from_bytes(LEDGER_HASH_INITIAL_BYTES));
+        for i in 0u8..10 {
+            db.put_entry_value(
+                handle,
+                &mut batch,
+                &Key::new(&addr, KeyType::DATASTORE(vec![i])),
+                &[i],
+            );
+        }
+        db.write_batch(batch);
+        let ledger_hash = db.get_ledger_hash();
+
+        // emulate a ledger written before the tree: no tree nodes and an unrelated hash
+        let tree_handle = db.db.cf_handle(LEDGER_TREE_CF).unwrap();
+        let tree_keys: Vec<_> = db
+            .db
+            .iterator_cf(tree_handle, IteratorMode::Start)
+            .flatten()
+            .map(|(key, _)| key)
+            .collect();
+        assert!(!tree_keys.is_empty());
+        for key in tree_keys {
+            db.db.delete_cf(tree_handle, key).unwrap();
+        }
+        db.db
+            .put_cf(
+                db.db.cf_handle(METADATA_CF).unwrap(),
+                LEDGER_HASH_KEY,
+                Hash::compute_from(b"xor hash").to_bytes(),
+            )
+            .unwrap();
+        drop(db);
+
+        let db = LedgerDB::new(temp_dir.path().to_path_buf(), 32, 255, 1_000_000, false);
+        assert_eq!(db.get_ledger_hash(), ledger_hash);
+        assert!(db
+            .db
+            .get_cf(
+                db.db.cf_handle(METADATA_CF).unwrap(),
+                LEDGER_TREE_REBUILD_KEY
+            )
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_end_prefix() {
         assert_eq!(end_prefix(&[5, 6, 7]), Some(vec![5, 6, 8]));
@@ -4,8 +4,15 @@
 #![warn(missing_docs)]
 #[macro_export]
 /// tracing with some context
+///
+/// The event and its parameters are recorded as fields, so that they are kept apart in JSON logs.
 macro_rules! massa_trace {
     ($evt:expr, $params:tt) => {
-        tracing::trace!("massa:{}:{}", $evt, serde_json::json!($params));
+        tracing::trace!(
+            event = $evt,
+            params = %serde_json::json!($params),
+            "massa:{}",
+            $evt
+        );
     };
 }
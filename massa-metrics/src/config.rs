@@ -0,0 +1,13 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+/// Metrics server configuration
+#[derive(Debug, Clone)]
+pub struct MetricsConfig {
+    /// address the `/metrics` endpoint listens on
+    pub bind: SocketAddr,
+    /// RocksDB databases whose size on disk is reported, by name
+    pub databases: Vec<(String, PathBuf)>,
+}
@@ -0,0 +1,12 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Errors of the metrics server
+#[non_exhaustive]
+#[derive(Display, Error, Debug)]
+pub enum MetricsError {
+    /// HTTP server error: {0}
+    HyperError(#[from] hyper::Error),
+}
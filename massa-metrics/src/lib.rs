@@ -0,0 +1,28 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! # General description
+//!
+//! The node's workers record counters and gauges about their activity in the metrics of this crate.
+//! When enabled, those metrics are exposed in the Prometheus text format on an HTTP `/metrics` endpoint.
+//!
+//! The metrics are global so that each worker can update them without being given a handle,
+//! and updating them is cheap enough to be done unconditionally.
+//!
+//! # Structure
+//! * `metrics.rs`: the metrics recorded by the workers
+//! * `server.rs`: the HTTP server exposing the metrics
+//! * `config.rs`: configuration of the server
+//! * `error.rs`: errors of the server
+
+#![warn(missing_docs)]
+#![warn(unused_crate_dependencies)]
+
+mod config;
+mod error;
+mod metrics;
+mod server;
+
+pub use config::*;
+pub use error::*;
+pub use metrics::*;
+pub use server::*;
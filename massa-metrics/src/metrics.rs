@@ -0,0 +1,133 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use lazy_static::lazy_static;
+use prometheus::{
+    register_int_counter, register_int_counter_vec, register_int_gauge, register_int_gauge_vec,
+    IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+};
+
+const REGISTER_ERROR: &str = "critical: could not register metric";
+
+lazy_static! {
+    /// period of the execution cursors, by `cursor` (`final` or `active`)
+    pub static ref EXECUTION_CURSOR_PERIOD: IntGaugeVec = register_int_gauge_vec!(
+        "massa_execution_cursor_period",
+        "period of the last slot executed by the execution cursor",
+        &["cursor"]
+    )
+    .expect(REGISTER_ERROR);
+    /// thread of the execution cursors, by `cursor` (`final` or `active`)
+    pub static ref EXECUTION_CURSOR_THREAD: IntGaugeVec = register_int_gauge_vec!(
+        "massa_execution_cursor_thread",
+        "thread of the last slot executed by the execution cursor",
+        &["cursor"]
+    )
+    .expect(REGISTER_ERROR);
+    /// number of slots the execution cursors are behind the current slot, by `cursor` (`final` or `active`)
+    pub static ref EXECUTION_SLOT_LAG: IntGaugeVec = register_int_gauge_vec!(
+        "massa_execution_slot_lag",
+        "number of slots between the current slot and the execution cursor",
+        &["cursor"]
+    )
+    .expect(REGISTER_ERROR);
+    /// final blocks executed
+    pub static ref EXECUTION_FINAL_BLOCKS: IntCounter = register_int_counter!(
+        "massa_execution_final_blocks_total",
+        "number of final blocks executed"
+    )
+    .expect(REGISTER_ERROR);
+    /// operations executed in final blocks
+    pub static ref EXECUTION_FINAL_OPERATIONS: IntCounter = register_int_counter!(
+        "massa_execution_final_operations_total",
+        "number of operations executed in final blocks"
+    )
+    .expect(REGISTER_ERROR);
+
+    /// blocks in the blockclique
+    pub static ref CONSENSUS_BLOCKCLIQUE_SIZE: IntGauge = register_int_gauge!(
+        "massa_consensus_blockclique_size",
+        "number of blocks in the blockclique"
+    )
+    .expect(REGISTER_ERROR);
+    /// cliques of the block graph
+    pub static ref CONSENSUS_CLIQUES: IntGauge = register_int_gauge!(
+        "massa_consensus_cliques",
+        "number of cliques in the block graph"
+    )
+    .expect(REGISTER_ERROR);
+    /// active blocks of the block graph
+    pub static ref CONSENSUS_ACTIVE_BLOCKS: IntGauge = register_int_gauge!(
+        "massa_consensus_active_blocks",
+        "number of active blocks in the block graph"
+    )
+    .expect(REGISTER_ERROR);
+    /// blocks that became final
+    pub static ref CONSENSUS_FINAL_BLOCKS: IntCounter = register_int_counter!(
+        "massa_consensus_final_blocks_total",
+        "number of blocks that became final"
+    )
+    .expect(REGISTER_ERROR);
+    /// blocks that became stale
+    pub static ref CONSENSUS_STALE_BLOCKS: IntCounter = register_int_counter!(
+        "massa_consensus_stale_blocks_total",
+        "number of blocks that became stale"
+    )
+    .expect(REGISTER_ERROR);
+
+    /// items in the pools, by `pool` (`operations`, `endorsements` or `denunciations`)
+    pub static ref POOL_SIZE: IntGaugeVec = register_int_gauge_vec!(
+        "massa_pool_size",
+        "number of items in the pool",
+        &["pool"]
+    )
+    .expect(REGISTER_ERROR);
+
+    /// peer connections, by `peer_type` and `direction` (`in`, `out` or `out_attempt`)
+    pub static ref NETWORK_CONNECTIONS: IntGaugeVec = register_int_gauge_vec!(
+        "massa_network_connections",
+        "number of peer connections",
+        &["peer_type", "direction"]
+    )
+    .expect(REGISTER_ERROR);
+
+    /// attempts to bootstrap from a server
+    pub static ref BOOTSTRAP_ATTEMPTS: IntCounter = register_int_counter!(
+        "massa_bootstrap_attempts_total",
+        "number of attempts to bootstrap from a server"
+    )
+    .expect(REGISTER_ERROR);
+    /// parts of the final state and consensus received while bootstrapping
+    pub static ref BOOTSTRAP_PARTS: IntCounter = register_int_counter!(
+        "massa_bootstrap_parts_received_total",
+        "number of final state and consensus parts received while bootstrapping"
+    )
+    .expect(REGISTER_ERROR);
+    /// period of the final state slot received while bootstrapping
+    pub static ref BOOTSTRAP_SLOT_PERIOD: IntGauge = register_int_gauge!(
+        "massa_bootstrap_slot_period",
+        "period of the final state slot received while bootstrapping"
+    )
+    .expect(REGISTER_ERROR);
+    /// 1 once the node bootstrapped from a server, 0 before
+    pub static ref BOOTSTRAP_FINISHED: IntGauge = register_int_gauge!(
+        "massa_bootstrap_finished",
+        "whether the node bootstrapped from a server"
+    )
+    .expect(REGISTER_ERROR);
+
+    /// lookups of compiled modules, by `result` (`lru_hit`, `hd_hit` or `miss`)
+    pub static ref MODULE_CACHE_LOOKUPS: IntCounterVec = register_int_counter_vec!(
+        "massa_module_cache_lookups_total",
+        "number of lookups of compiled modules in the module cache",
+        &["result"]
+    )
+    .expect(REGISTER_ERROR);
+
+    /// size on disk of the RocksDB databases, by `db`
+    pub static ref ROCKSDB_SIZE: IntGaugeVec = register_int_gauge_vec!(
+        "massa_rocksdb_size_bytes",
+        "size on disk of the RocksDB database",
+        &["db"]
+    )
+    .expect(REGISTER_ERROR);
+}
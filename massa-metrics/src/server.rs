@@ -0,0 +1,168 @@
+//! Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+use crate::{MetricsConfig, MetricsError, ROCKSDB_SIZE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use prometheus::{Encoder, TextEncoder};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::oneshot;
+use tracing::{info, warn};
+
+/// Start the HTTP server exposing the metrics on `/metrics`
+pub fn start_metrics_server(config: MetricsConfig) -> Result<StopHandle, MetricsError> {
+    let databases = Arc::new(config.databases);
+    let make_service = make_service_fn(move |_| {
+        let databases = databases.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                serve_request(request, databases.clone())
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&config.bind)?.serve(make_service);
+    let local_addr = server.local_addr();
+    let (stop_cmd_sender, stop_cmd_receiver) = oneshot::channel::<()>();
+    tokio::spawn(async move {
+        let server = server.with_graceful_shutdown(async {
+            stop_cmd_receiver.await.ok();
+        });
+        if let Err(e) = server.await {
+            warn!("metrics server error: {}", e);
+        }
+    });
+
+    Ok(StopHandle {
+        stop_cmd_sender,
+        local_addr,
+    })
+}
+
+/// Answer a request to the metrics server
+async fn serve_request(
+    request: Request<Body>,
+    databases: Arc<Vec<(String, PathBuf)>>,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::GET || request.uri().path() != "/metrics" {
+        let mut response = Response::new(Body::from("not found"));
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return Ok(response);
+    }
+
+    // database sizes are only needed when scraped, there is no point in tracking them on every write.
+    // Walking the database directories blocks, so it is kept off the async workers
+    let sizes = tokio::task::spawn_blocking(move || {
+        databases
+            .iter()
+            .map(|(name, path)| (name.clone(), directory_size(path)))
+            .collect::<Vec<_>>()
+    })
+    .await;
+    match sizes {
+        Ok(sizes) => {
+            for (name, size) in sizes {
+                ROCKSDB_SIZE.with_label_values(&[&name]).set(size as i64);
+            }
+        }
+        Err(e) => warn!("could not compute the database sizes: {}", e),
+    }
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(e) = encoder.encode(&prometheus::gather(), &mut buffer) {
+        warn!("could not encode metrics: {}", e);
+        let mut response = Response::new(Body::from(e.to_string()));
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        return Ok(response);
+    }
+    let mut response = Response::new(Body::from(buffer));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(prometheus::TEXT_FORMAT),
+    );
+    Ok(response)
+}
+
+/// total size of the files in a directory and its subdirectories, missing or unreadable files count as empty
+fn directory_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Used to be able to stop the metrics server
+pub struct StopHandle {
+    stop_cmd_sender: oneshot::Sender<()>,
+    local_addr: SocketAddr,
+}
+
+impl StopHandle {
+    /// address the metrics server listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// stop the metrics server gracefully
+    pub fn stop(self) {
+        if self.stop_cmd_sender.send(()).is_err() {
+            warn!("metrics server thread panicked");
+        } else {
+            info!("metrics server stop signal sent successfully");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXECUTION_FINAL_BLOCKS;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    async fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET {} HTTP/1.0\r\n\r\n", path).as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_metrics_server() {
+        let db_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(db_dir.path().join("cf")).unwrap();
+        std::fs::write(db_dir.path().join("cf").join("000001.sst"), [0u8; 5]).unwrap();
+        std::fs::write(db_dir.path().join("CURRENT"), [0u8; 3]).unwrap();
+
+        let handle = start_metrics_server(MetricsConfig {
+            bind: "127.0.0.1:0".parse().unwrap(),
+            databases: vec![("test".to_string(), db_dir.path().to_path_buf())],
+        })
+        .unwrap();
+        EXECUTION_FINAL_BLOCKS.inc();
+
+        let response = get(handle.local_addr(), "/metrics").await;
+        assert!(response.starts_with("HTTP/1.0 200"));
+        assert!(response.contains("massa_rocksdb_size_bytes{db=\"test\"} 8"));
+        assert!(response.contains("massa_execution_final_blocks_total"));
+
+        let response = get(handle.local_addr(), "/status").await;
+        assert!(response.starts_with("HTTP/1.0 404"));
+
+        handle.stop();
+    }
+}
@@ -6,7 +6,7 @@ use massa_hash::{Hash, HashDeserializer};
 use massa_serialization::{
     DeserializeError, Deserializer, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
 };
-use massa_signature::PublicKey;
+use massa_signature::{MultiSigPublicKey, PublicKey};
 use nom::branch::alt;
 use nom::combinator::verify;
 use nom::error::{context, ContextError, ParseError};
@@ -19,13 +19,15 @@ use std::str::FromStr;
 /// Size of a serialized address, in bytes
 pub const ADDRESS_SIZE_BYTES: usize = massa_hash::HASH_SIZE_BYTES + 1;
 
-/// Top level address representation that can differentiate between User and SC address
+/// Top level address representation that can differentiate between User, SC and MultiSig address
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Address {
     #[allow(missing_docs)]
     User(UserAddress),
     #[allow(missing_docs)]
     SC(SCAddress),
+    #[allow(missing_docs)]
+    MultiSig(MultiSigAddress),
 }
 
 /// In the near future, this will encapsulate slot, idx, and is_write
@@ -36,6 +38,10 @@ pub struct SCAddress(pub Hash);
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct UserAddress(pub Hash);
 
+/// Derived from a multi-signature public key: a set of public keys and a threshold.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MultiSigAddress(pub Hash);
+
 /// TODO: This conversion will need re-writing/removal when SCAddress is optimised
 impl From<SCAddress> for UserAddress {
     fn from(value: SCAddress) -> Self {
@@ -53,6 +59,7 @@ const ADDRESS_PREFIX: char = 'A';
 // serialized with varint
 const USER_PREFIX: u64 = 0;
 const SC_PREFIX: u64 = 1;
+const MULTISIG_PREFIX: u64 = 2;
 const ADDRESS_VERSION: u64 = 0;
 
 impl std::fmt::Display for Address {
@@ -71,6 +78,7 @@ impl std::fmt::Display for Address {
             match self {
                 Address::User(_) => 'U',
                 Address::SC(_) => 'S',
+                Address::MultiSig(_) => 'M',
             },
             bs58::encode(bytes).with_check().into_string()
         )
@@ -104,7 +112,7 @@ impl<'de> ::serde::Deserialize<'de> for Address {
                 type Value = Address;
 
                 fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                    formatter.write_str("A + {U | S} + base58::encode(version + hash)")
+                    formatter.write_str("A + {U | S | M} + base58::encode(version + hash)")
                 }
 
                 fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
@@ -170,7 +178,7 @@ impl FromStr for Address {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let err = Err(ModelsError::AddressParseError(s.to_string()));
 
-        // Handle the prefix ("A{U|S}")
+        // Handle the prefix ("A{U|S|M}")
         let mut chars = s.chars();
         let Some(ADDRESS_PREFIX) = chars.next() else {
             return err;
@@ -201,6 +209,7 @@ impl FromStr for Address {
         let res = match pref {
             'U' => Address::User(res),
             'S' => Address::SC(res.into()),
+            'M' => Address::MultiSig(MultiSigAddress(res.0)),
             _ => return err,
         };
         Ok(res)
@@ -221,6 +230,7 @@ impl Address {
         match self {
             Address::User(addr) => addr.0.to_bytes(),
             Address::SC(addr) => addr.0.to_bytes(),
+            Address::MultiSig(addr) => addr.0.to_bytes(),
         }
     }
 
@@ -229,6 +239,11 @@ impl Address {
         Address::User(UserAddress(Hash::compute_from(public_key.to_bytes())))
     }
 
+    /// Computes address associated with given multi-signature public key
+    pub fn from_multisig_public_key(public_key: &MultiSigPublicKey) -> Self {
+        Address::MultiSig(MultiSigAddress(Hash::compute_from(&public_key.to_bytes())))
+    }
+
     /// Inner implementation for serializer. Mostly made available for the benefit of macros.
     pub fn prefixed_bytes(&self) -> Vec<u8> {
         let mut buff = vec![];
@@ -236,6 +251,7 @@ impl Address {
         let val = match self {
             Address::User(_) => USER_PREFIX,
             Address::SC(_) => SC_PREFIX,
+            Address::MultiSig(_) => MULTISIG_PREFIX,
         };
         pref_ser
             .serialize(&val, &mut buff)
@@ -308,7 +324,7 @@ impl AddressDeserializer {
     pub const fn new() -> Self {
         Self {
             hash_deserializer: HashDeserializer::new(),
-            int_deserializer: U64VarIntDeserializer::new(Included(0), Included(1)),
+            int_deserializer: U64VarIntDeserializer::new(Included(0), Included(2)),
         }
     }
 }
@@ -335,6 +351,7 @@ impl Deserializer<Address> for AddressDeserializer {
             alt((
                 |input| user_parser(&self.int_deserializer, &self.hash_deserializer, input),
                 |input| sc_parser(&self.int_deserializer, &self.hash_deserializer, input),
+                |input| multisig_parser(&self.int_deserializer, &self.hash_deserializer, input),
             ))
             .parse(input)
         })
@@ -380,6 +397,25 @@ fn sc_parser<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
     .map(|hash| Address::SC(SCAddress(hash)))
     .parse(input)
 }
+// used to make the `alt(...)` more readable
+fn multisig_parser<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+    pref_deser: &U64VarIntDeserializer,
+    deser: &HashDeserializer,
+    input: &'a [u8],
+) -> IResult<&'a [u8], Address, E> {
+    context(
+        "Failed attempt to deserialise MultiSig Address",
+        preceded(
+            verify(
+                |input| pref_deser.deserialize(input),
+                |val| *val == MULTISIG_PREFIX,
+            ),
+            |input| deser.deserialize(input),
+        ),
+    )
+    .map(|hash| Address::MultiSig(MultiSigAddress(hash)))
+    .parse(input)
+}
 /// Info for a given address on a given cycle
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ExecutionAddressCycleInfo {
@@ -409,4 +445,23 @@ mod test {
         let b = Address::from_str(&a).unwrap();
         assert_eq!(address, b);
     }
+
+    #[test]
+    fn test_multisig_address_format() {
+        use massa_signature::KeyPair;
+
+        let public_keys = (0..3)
+            .map(|_| KeyPair::generate().get_public_key())
+            .collect();
+        let address =
+            Address::from_multisig_public_key(&MultiSigPublicKey::new(2, public_keys).unwrap());
+        let a = address.to_string();
+        assert!(a.starts_with("AM"));
+        assert_eq!(address, Address::from_str(&a).unwrap());
+        let (rest, b) = AddressDeserializer::new()
+            .deserialize::<DeserializeError>(&address.prefixed_bytes())
+            .unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(address, b);
+    }
 }
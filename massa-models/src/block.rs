@@ -183,6 +183,7 @@ impl Serializer<Block> for BlockSerializer {
     ///             )
     ///             .unwrap(),
     ///         ],
+    ///         denunciations: Vec::new(),
     ///     },
     ///     BlockHeaderSerializer::new(),
     ///     &keypair,
@@ -214,6 +215,8 @@ pub struct BlockDeserializerArgs {
     pub max_operations_per_block: u32,
     /// Number of endorsements in a block
     pub endorsement_count: u32,
+    /// Maximum number of denunciations in a block header
+    pub max_denunciations_per_block_header: u32,
     /// If Some(lsp), this will through if trying to deserialize a block with a period before the genesis blocks
     pub last_start_period: Option<u64>,
 }
@@ -231,6 +234,7 @@ impl BlockDeserializer {
             header_deserializer: SecureShareDeserializer::new(BlockHeaderDeserializer::new(
                 args.thread_count,
                 args.endorsement_count,
+                args.max_denunciations_per_block_header,
                 args.last_start_period,
             )),
             op_ids_deserializer: OperationIdsDeserializer::new(args.max_operations_per_block),
@@ -280,6 +284,7 @@ impl Deserializer<Block> for BlockDeserializer {
     ///             )
     ///             .unwrap(),
     ///         ],
+    ///         denunciations: Vec::new(),
     ///     },
     ///     BlockHeaderSerializer::new(),
     ///     &keypair,
@@ -294,7 +299,7 @@ impl Deserializer<Block> for BlockDeserializer {
     ///
     /// let mut buffer = Vec::new();
     /// BlockSerializer::new().serialize(&orig_block, &mut buffer).unwrap();
-    /// let args = BlockDeserializerArgs { thread_count: THREAD_COUNT, max_operations_per_block: 100, endorsement_count: 9,last_start_period: Some(0),};
+    /// let args = BlockDeserializerArgs { thread_count: THREAD_COUNT, max_operations_per_block: 100, endorsement_count: 9, max_denunciations_per_block_header: 10, last_start_period: Some(0),};
     /// let (rest, res_block) = BlockDeserializer::new(args).deserialize::<DeserializeError>(&mut buffer).unwrap();
     ///
     /// assert!(rest.is_empty());
@@ -404,10 +409,15 @@ mod test {
     use super::*;
     use crate::{
         block_header::BlockHeaderSerializer,
-        config::{ENDORSEMENT_COUNT, MAX_OPERATIONS_PER_BLOCK, THREAD_COUNT},
+        config::{
+            ENDORSEMENT_COUNT, MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_OPERATIONS_PER_BLOCK,
+            THREAD_COUNT,
+        },
+        denunciation::Denunciation,
         endorsement::Endorsement,
         endorsement::EndorsementSerializer,
         slot::Slot,
+        test_exports::gen_block_headers_for_denunciation,
     };
     use massa_hash::Hash;
     use massa_serialization::DeserializeError;
@@ -463,6 +473,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![endo1, endo2],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -488,6 +499,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let (rest, res_block): (&[u8], SecureShareBlock) =
@@ -526,6 +538,63 @@ mod test {
         res_block.content.header.assert_invariants().unwrap();
     }
 
+    #[test]
+    #[serial]
+    fn test_block_header_denunciations_serialization() {
+        let (_slot, keypair, s_block_header_1, s_block_header_2, _s_block_header_3) =
+            gen_block_headers_for_denunciation();
+        let denunciation: Denunciation = (&s_block_header_1, &s_block_header_2).try_into().unwrap();
+        let parents: Vec<BlockId> = (0..THREAD_COUNT)
+            .map(|i| BlockId(Hash::compute_from(&[i])))
+            .collect();
+        let mut header = BlockHeader {
+            slot: Slot::new(5, 1),
+            parents,
+            operation_merkle_root: Hash::compute_from("mno".as_bytes()),
+            endorsements: Vec::new(),
+            denunciations: Vec::new(),
+        };
+        let deserializer = BlockHeaderDeserializer::new(
+            THREAD_COUNT,
+            ENDORSEMENT_COUNT,
+            MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+            Some(0),
+        );
+
+        // without denunciations, the header keeps the legacy encoding (and thus the legacy hash)
+        let mut legacy_buffer = Vec::new();
+        BlockHeaderSerializer::new()
+            .serialize(&header, &mut legacy_buffer)
+            .unwrap();
+        let slot_len = legacy_buffer.len() - 1 - THREAD_COUNT as usize * 32 - 32 - 1;
+        assert_eq!(legacy_buffer[slot_len], 1);
+        assert_eq!(*legacy_buffer.last().unwrap(), 0);
+
+        // with denunciations, the header is tagged and the denunciations are appended
+        header.denunciations = vec![denunciation];
+        let mut buffer = Vec::new();
+        BlockHeaderSerializer::new()
+            .serialize(&header, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer[slot_len], 2);
+        let (rest, deserialized) = deserializer
+            .deserialize::<DeserializeError>(&buffer)
+            .unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(deserialized.denunciations, header.denunciations);
+        let secured =
+            BlockHeader::new_verifiable(header, BlockHeaderSerializer::new(), &keypair).unwrap();
+        assert_eq!(secured.content.denunciations.len(), 1);
+
+        // a tagged header with an empty denunciation list would be a second encoding of the same header
+        let mut empty_tagged_buffer = legacy_buffer;
+        empty_tagged_buffer[slot_len] = 2;
+        empty_tagged_buffer.push(0);
+        assert!(deserializer
+            .deserialize::<DeserializeError>(&empty_tagged_buffer)
+            .is_err());
+    }
+
     #[test]
     #[serial]
     fn test_genesis_block_serialization() {
@@ -539,6 +608,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -564,6 +634,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let (rest, res_block): (&[u8], SecureShareBlock) =
@@ -624,6 +695,7 @@ mod test {
                     &keypair,
                 )
                 .unwrap()],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -649,6 +721,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -677,6 +750,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -702,6 +776,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -725,6 +800,7 @@ mod test {
                 parents: vec![],
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -750,6 +826,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -776,6 +853,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -801,6 +879,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -849,6 +928,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements,
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -874,6 +954,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let (_, res): (&[u8], SecureShareBlock) =
@@ -899,6 +980,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -924,6 +1006,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -964,6 +1047,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements,
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -989,6 +1073,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -1034,6 +1119,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![endo1],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -1059,6 +1145,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -1116,6 +1203,7 @@ mod test {
                 parents,
                 operation_merkle_root: Hash::compute_from("mno".as_bytes()),
                 endorsements: vec![endo1, endo2],
+                denunciations: Vec::new(),
             },
             BlockHeaderSerializer::new(),
             &keypair,
@@ -1141,6 +1229,7 @@ mod test {
             thread_count: THREAD_COUNT,
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             last_start_period: Some(0),
         };
         let res: Result<(&[u8], SecureShareBlock), _> =
@@ -1,5 +1,6 @@
 use crate::block_id::BlockId;
 use crate::config::THREAD_COUNT;
+use crate::denunciation::{Denunciation, DenunciationDeserializer, DenunciationSerializer};
 use crate::endorsement::{
     Endorsement, EndorsementDeserializerLW, EndorsementId, EndorsementSerializer,
     EndorsementSerializerLW, SecureShareEndorsement,
@@ -35,6 +36,8 @@ pub struct BlockHeader {
     pub operation_merkle_root: Hash,
     /// endorsements
     pub endorsements: Vec<SecureShareEndorsement>,
+    /// denunciations
+    pub denunciations: Vec<Denunciation>,
 }
 
 // TODO: gh-issue #3398
@@ -120,6 +123,7 @@ pub struct BlockHeaderSerializer {
     slot_serializer: SlotSerializer,
     endorsement_serializer: SecureShareSerializer,
     endorsement_content_serializer: EndorsementSerializerLW,
+    denunciation_serializer: DenunciationSerializer,
     u32_serializer: U32VarIntSerializer,
 }
 
@@ -131,6 +135,7 @@ impl BlockHeaderSerializer {
             endorsement_serializer: SecureShareSerializer::new(),
             u32_serializer: U32VarIntSerializer::new(),
             endorsement_content_serializer: EndorsementSerializerLW::new(),
+            denunciation_serializer: DenunciationSerializer::new(),
         }
     }
 }
@@ -182,6 +187,7 @@ impl Serializer<BlockHeader> for BlockHeaderSerializer {
     ///     )
     ///     .unwrap(),
     ///    ],
+    ///   denunciations: Vec::new(),
     /// };
     /// let mut buffer = vec![];
     /// BlockHeaderSerializer::new().serialize(&header, &mut buffer).unwrap();
@@ -189,10 +195,18 @@ impl Serializer<BlockHeader> for BlockHeaderSerializer {
     fn serialize(&self, value: &BlockHeader, buffer: &mut Vec<u8>) -> Result<(), SerializeError> {
         self.slot_serializer.serialize(&value.slot, buffer)?;
         // parents (note: there should be none if slot period=0)
+        // headers without denunciations keep the legacy tag so that their encoding and hash are unchanged
         if value.parents.is_empty() {
+            if !value.denunciations.is_empty() {
+                return Err(SerializeError::GeneralError(
+                    "genesis header cannot contain denunciations".to_string(),
+                ));
+            }
             buffer.push(0);
-        } else {
+        } else if value.denunciations.is_empty() {
             buffer.push(1);
+        } else {
+            buffer.push(2);
         }
         for parent_h in value.parents.iter() {
             buffer.extend(parent_h.0.to_bytes());
@@ -214,6 +228,19 @@ impl Serializer<BlockHeader> for BlockHeaderSerializer {
                 buffer,
             )?;
         }
+
+        if !value.denunciations.is_empty() {
+            self.u32_serializer.serialize(
+                &value.denunciations.len().try_into().map_err(|err| {
+                    SerializeError::GeneralError(format!("too many denunciations: {}", err))
+                })?,
+                buffer,
+            )?;
+            for denunciation in value.denunciations.iter() {
+                self.denunciation_serializer
+                    .serialize(denunciation, buffer)?;
+            }
+        }
         Ok(())
     }
 }
@@ -223,6 +250,8 @@ pub struct BlockHeaderDeserializer {
     slot_deserializer: SlotDeserializer,
     endorsement_serializer: EndorsementSerializer,
     length_endorsements_deserializer: U32VarIntDeserializer,
+    length_denunciations_deserializer: U32VarIntDeserializer,
+    denunciation_deserializer: DenunciationDeserializer,
     hash_deserializer: HashDeserializer,
     thread_count: u8,
     endorsement_count: u32,
@@ -235,6 +264,7 @@ impl BlockHeaderDeserializer {
     pub const fn new(
         thread_count: u8,
         endorsement_count: u32,
+        max_denunciations_per_block_header: u32,
         last_start_period: Option<u64>,
     ) -> Self {
         Self {
@@ -247,12 +277,40 @@ impl BlockHeaderDeserializer {
                 Included(0),
                 Included(endorsement_count),
             ),
+            // an empty list is encoded with the legacy parents tag, not as an empty section
+            length_denunciations_deserializer: U32VarIntDeserializer::new(
+                Included(1),
+                Included(max_denunciations_per_block_header),
+            ),
+            denunciation_deserializer: DenunciationDeserializer::new(
+                thread_count,
+                endorsement_count,
+            ),
             hash_deserializer: HashDeserializer::new(),
             thread_count,
             endorsement_count,
             last_start_period,
         }
     }
+
+    /// Deserializes the length-prefixed denunciations that end a header serialized with parents tag 2
+    fn deserialize_denunciations<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], Vec<Denunciation>, E> {
+        context(
+            "Failed denunciations deserialization",
+            length_count(
+                context("Failed length deserialization", |input| {
+                    self.length_denunciations_deserializer.deserialize(input)
+                }),
+                context("Failed denunciation deserialization", |input| {
+                    self.denunciation_deserializer.deserialize(input)
+                }),
+            ),
+        )
+        .parse(buffer)
+    }
 }
 
 impl Deserializer<BlockHeader> for BlockHeaderDeserializer {
@@ -296,10 +354,11 @@ impl Deserializer<BlockHeader> for BlockHeaderDeserializer {
     ///     )
     ///     .unwrap(),
     ///    ],
+    ///   denunciations: Vec::new(),
     /// };
     /// let mut buffer = vec![];
     /// BlockHeaderSerializer::new().serialize(&header, &mut buffer).unwrap();
-    /// let (rest, deserialized_header) = BlockHeaderDeserializer::new(32, 9, Some(0)).deserialize::<DeserializeError>(&buffer).unwrap();
+    /// let (rest, deserialized_header) = BlockHeaderDeserializer::new(32, 9, 10, Some(0)).deserialize::<DeserializeError>(&buffer).unwrap();
     /// assert_eq!(rest.len(), 0);
     /// let mut buffer2 = Vec::new();
     /// BlockHeaderSerializer::new().serialize(&deserialized_header, &mut buffer2).unwrap();
@@ -309,61 +368,71 @@ impl Deserializer<BlockHeader> for BlockHeaderDeserializer {
         &self,
         buffer: &'a [u8],
     ) -> IResult<&'a [u8], BlockHeader, E> {
-        let (rest, (slot, parents, operation_merkle_root)): (&[u8], (Slot, Vec<BlockId>, Hash)) =
-            context("Failed BlockHeader deserialization", |input| {
-                let (rest, (slot, parents)) = tuple((
-                    context("Failed slot deserialization", |input| {
-                        self.slot_deserializer.deserialize(input)
-                    }),
-                    context(
-                        "Failed parents deserialization",
-                        alt((
-                            preceded(tag(&[0]), |input| Ok((input, Vec::new()))),
-                            preceded(
-                                tag(&[1]),
-                                count(
-                                    context("Failed block_id deserialization", |input| {
-                                        self.hash_deserializer
-                                            .deserialize(input)
-                                            .map(|(rest, hash)| (rest, BlockId(hash)))
-                                    }),
-                                    self.thread_count as usize,
-                                ),
-                            ),
-                        )),
-                    ),
-                ))
-                .parse(input)?;
-
-                // validate the parent/slot invariants before moving on to other fields
-                if let Some(last_start_period) = self.last_start_period {
-                    if slot.period == last_start_period && !parents.is_empty() {
-                        return Err(nom::Err::Failure(ContextError::add_context(
-                            rest,
-                            "Genesis block cannot contain parents",
-                            ParseError::from_error_kind(rest, nom::error::ErrorKind::Fail),
-                        )));
-                    } else if slot.period != last_start_period
-                        && parents.len() != THREAD_COUNT as usize
-                    {
-                        return Err(nom::Err::Failure(ContextError::add_context(
-                            rest,
-                            const_format::formatcp!(
-                                "Non-genesis block must have {} parents",
-                                THREAD_COUNT
-                            ),
-                            ParseError::from_error_kind(rest, nom::error::ErrorKind::Fail),
-                        )));
-                    }
+        let parents_deserializer = |input: &'a [u8]| -> IResult<&'a [u8], Vec<BlockId>, E> {
+            count(
+                context("Failed block_id deserialization", |input| {
+                    self.hash_deserializer
+                        .deserialize(input)
+                        .map(|(rest, hash)| (rest, BlockId(hash)))
+                }),
+                self.thread_count as usize,
+            )
+            .parse(input)
+        };
+        #[allow(clippy::type_complexity)]
+        let (rest, (slot, (parents, has_denunciations), operation_merkle_root)): (
+            &[u8],
+            (Slot, (Vec<BlockId>, bool), Hash),
+        ) = context("Failed BlockHeader deserialization", |input| {
+            let (rest, (slot, (parents, has_denunciations))) = tuple((
+                context("Failed slot deserialization", |input| {
+                    self.slot_deserializer.deserialize(input)
+                }),
+                context(
+                    "Failed parents deserialization",
+                    alt((
+                        preceded(tag(&[0]), |input| Ok((input, (Vec::new(), false)))),
+                        preceded(tag(&[1]), |input| {
+                            parents_deserializer(input)
+                                .map(|(rest, parents)| (rest, (parents, false)))
+                        }),
+                        preceded(tag(&[2]), |input| {
+                            parents_deserializer(input)
+                                .map(|(rest, parents)| (rest, (parents, true)))
+                        }),
+                    )),
+                ),
+            ))
+            .parse(input)?;
+
+            // validate the parent/slot invariants before moving on to other fields
+            if let Some(last_start_period) = self.last_start_period {
+                if slot.period == last_start_period && !parents.is_empty() {
+                    return Err(nom::Err::Failure(ContextError::add_context(
+                        rest,
+                        "Genesis block cannot contain parents",
+                        ParseError::from_error_kind(rest, nom::error::ErrorKind::Fail),
+                    )));
+                } else if slot.period != last_start_period && parents.len() != THREAD_COUNT as usize
+                {
+                    return Err(nom::Err::Failure(ContextError::add_context(
+                        rest,
+                        const_format::formatcp!(
+                            "Non-genesis block must have {} parents",
+                            THREAD_COUNT
+                        ),
+                        ParseError::from_error_kind(rest, nom::error::ErrorKind::Fail),
+                    )));
                 }
+            }
 
-                let (rest, merkle) = context("Failed operation_merkle_root", |input| {
-                    self.hash_deserializer.deserialize(input)
-                })
-                .parse(rest)?;
-                Ok((rest, (slot, parents, merkle)))
+            let (rest, merkle) = context("Failed operation_merkle_root", |input| {
+                self.hash_deserializer.deserialize(input)
             })
-            .parse(buffer)?;
+            .parse(rest)?;
+            Ok((rest, (slot, (parents, has_denunciations), merkle)))
+        })
+        .parse(buffer)?;
 
         if parents.is_empty() {
             let res = BlockHeader {
@@ -371,6 +440,7 @@ impl Deserializer<BlockHeader> for BlockHeaderDeserializer {
                 parents,
                 operation_merkle_root,
                 endorsements: Vec::new(),
+                denunciations: Vec::new(),
             };
 
             // TODO: gh-issue #3398
@@ -427,11 +497,18 @@ impl Deserializer<BlockHeader> for BlockHeaderDeserializer {
             }
         }
 
+        let (rest, denunciations) = if has_denunciations {
+            self.deserialize_denunciations(rest)?
+        } else {
+            (rest, Vec::new())
+        };
+
         let header = BlockHeader {
             slot,
             parents,
             operation_merkle_root,
             endorsements,
+            denunciations,
         };
 
         // TODO: gh-issue #3398
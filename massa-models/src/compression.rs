@@ -0,0 +1,245 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Frame compression negotiated between nodes.
+//!
+//! Once both sides of a connection agreed on a `Compression`, every frame starts with a flag byte
+//! telling whether the rest of the frame is compressed. Small or incompressible payloads are sent as is.
+//! Decompression is bounded: a frame expanding beyond the allowed message size is rejected
+//! before more than that many bytes are allocated.
+
+use crate::error::ModelsError;
+use massa_serialization::{Deserializer, SerializeError, Serializer};
+use nom::{
+    error::{ContextError, ParseError},
+    IResult,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, io::Read};
+
+/// Payloads smaller than this are never compressed
+pub const COMPRESSION_MIN_SIZE: usize = 1024;
+
+/// zstd compression level: favors speed over ratio
+const ZSTD_LEVEL: i32 = 3;
+
+/// Largest zstd window accepted when decompressing, as a power of two.
+/// Frames compressed at `ZSTD_LEVEL` never use a larger window, and a peer cannot make us
+/// allocate the default decoder window (up to 128 MiB) with a crafted frame header.
+const ZSTD_WINDOW_LOG_MAX: u32 = 21;
+
+/// Compression algorithms supported for frames
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive, Serialize, Deserialize,
+)]
+#[repr(u8)]
+pub enum Compression {
+    /// zstd
+    Zstd = 1,
+}
+
+/// First byte of a frame once compression is negotiated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+enum FrameFlag {
+    Raw = 0,
+    Zstd = 1,
+}
+
+/// Returns the compression both sides can use, if any
+pub fn negotiate_compression(
+    ours: Option<Compression>,
+    theirs: Option<Compression>,
+) -> Option<Compression> {
+    match (ours, theirs) {
+        (Some(ours), Some(theirs)) if ours == theirs => Some(ours),
+        _ => None,
+    }
+}
+
+/// Builds a frame from a serialized message, compressing it if that makes it smaller
+pub fn encode_frame(compression: Compression, payload: &[u8]) -> Result<Vec<u8>, ModelsError> {
+    if payload.len() >= COMPRESSION_MIN_SIZE {
+        let compressed = match compression {
+            Compression::Zstd => zstd::bulk::compress(payload, ZSTD_LEVEL).map_err(|err| {
+                ModelsError::SerializeError(format!("zstd compression failed: {}", err))
+            })?,
+        };
+        if compressed.len() < payload.len() {
+            let mut frame = Vec::with_capacity(compressed.len() + 1);
+            frame.push(u8::from(FrameFlag::Zstd));
+            frame.extend(compressed);
+            return Ok(frame);
+        }
+    }
+    let mut frame = Vec::with_capacity(payload.len() + 1);
+    frame.push(u8::from(FrameFlag::Raw));
+    frame.extend_from_slice(payload);
+    Ok(frame)
+}
+
+/// Extracts the serialized message of a frame.
+///
+/// Fails if the decompressed message would be larger than `max_size` bytes.
+pub fn decode_frame(frame: &[u8], max_size: usize) -> Result<Cow<'_, [u8]>, ModelsError> {
+    let (flag, data) = frame
+        .split_first()
+        .ok_or_else(|| ModelsError::DeserializeError("empty frame".into()))?;
+    let flag = FrameFlag::try_from(*flag).map_err(|_| {
+        ModelsError::DeserializeError(format!("unknown frame compression flag {}", flag))
+    })?;
+    match flag {
+        FrameFlag::Raw => {
+            if data.len() > max_size {
+                return Err(ModelsError::DeserializeError("frame too large".into()));
+            }
+            Ok(Cow::Borrowed(data))
+        }
+        FrameFlag::Zstd => {
+            // read at most one byte past the limit so that the output never grows beyond it
+            let mut decoder = zstd::stream::read::Decoder::new(data).map_err(|err| {
+                ModelsError::DeserializeError(format!("zstd decompression failed: {}", err))
+            })?;
+            decoder.window_log_max(ZSTD_WINDOW_LOG_MAX).map_err(|err| {
+                ModelsError::DeserializeError(format!("zstd decompression failed: {}", err))
+            })?;
+            let mut payload = Vec::new();
+            decoder
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut payload)
+                .map_err(|err| {
+                    ModelsError::DeserializeError(format!("zstd decompression failed: {}", err))
+                })?;
+            if payload.len() > max_size {
+                return Err(ModelsError::DeserializeError(
+                    "decompressed frame too large".into(),
+                ));
+            }
+            Ok(Cow::Owned(payload))
+        }
+    }
+}
+
+/// Serializer for a compression advertised at the end of a handshake message.
+///
+/// Nothing is written for `None`, so that peers unaware of compression read the same message as before.
+#[derive(Default)]
+pub struct CompressionSerializer;
+
+impl CompressionSerializer {
+    /// Creates a `CompressionSerializer`
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Serializer<Option<Compression>> for CompressionSerializer {
+    fn serialize(
+        &self,
+        value: &Option<Compression>,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), SerializeError> {
+        if let Some(compression) = value {
+            buffer.push(u8::from(*compression));
+        }
+        Ok(())
+    }
+}
+
+/// Deserializer for a compression advertised at the end of a handshake message.
+///
+/// A missing field (older peer) or an unknown algorithm (newer peer) both mean no compression.
+#[derive(Default)]
+pub struct CompressionDeserializer;
+
+impl CompressionDeserializer {
+    /// Creates a `CompressionDeserializer`
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Deserializer<Option<Compression>> for CompressionDeserializer {
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], Option<Compression>, E> {
+        match buffer.split_first() {
+            None => Ok((buffer, None)),
+            Some((value, rest)) => Ok((rest, Compression::try_from(*value).ok())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_serialization::DeserializeError;
+    use std::io::Write;
+
+    #[test]
+    fn test_frame_roundtrip() {
+        let small = b"small payload".to_vec();
+        let frame = encode_frame(Compression::Zstd, &small).unwrap();
+        assert_eq!(frame[0], u8::from(FrameFlag::Raw));
+        assert_eq!(decode_frame(&frame, small.len()).unwrap(), &small[..]);
+
+        let large = vec![42u8; 100_000];
+        let frame = encode_frame(Compression::Zstd, &large).unwrap();
+        assert_eq!(frame[0], u8::from(FrameFlag::Zstd));
+        assert!(frame.len() < large.len());
+        assert_eq!(decode_frame(&frame, large.len()).unwrap(), &large[..]);
+    }
+
+    #[test]
+    fn test_frame_decompression_limit() {
+        let large = vec![0u8; 10_000_000];
+        let frame = encode_frame(Compression::Zstd, &large).unwrap();
+        assert!(frame.len() < 10_000);
+        assert!(decode_frame(&frame, 1_000_000).is_err());
+        assert!(decode_frame(&[], 1_000_000).is_err());
+        assert!(decode_frame(&[42], 1_000_000).is_err());
+    }
+
+    #[test]
+    fn test_frame_window_limit() {
+        // a frame whose header asks for a window larger than ours is refused, whatever its size
+        let payload = vec![7u8; 10_000];
+        let mut encoder = zstd::stream::write::Encoder::new(Vec::new(), ZSTD_LEVEL).unwrap();
+        encoder.window_log(ZSTD_WINDOW_LOG_MAX + 3).unwrap();
+        encoder.write_all(&payload).unwrap();
+        let mut frame = vec![u8::from(FrameFlag::Zstd)];
+        frame.extend(encoder.finish().unwrap());
+        assert!(decode_frame(&frame, payload.len()).is_err());
+
+        let mut encoder = zstd::stream::write::Encoder::new(Vec::new(), ZSTD_LEVEL).unwrap();
+        encoder.window_log(ZSTD_WINDOW_LOG_MAX).unwrap();
+        encoder.write_all(&payload).unwrap();
+        let mut frame = vec![u8::from(FrameFlag::Zstd)];
+        frame.extend(encoder.finish().unwrap());
+        assert_eq!(decode_frame(&frame, payload.len()).unwrap(), &payload[..]);
+    }
+
+    #[test]
+    fn test_compression_advertisement() {
+        let mut buffer = Vec::new();
+        CompressionSerializer::new()
+            .serialize(&Some(Compression::Zstd), &mut buffer)
+            .unwrap();
+        let (rest, compression) = CompressionDeserializer::new()
+            .deserialize::<DeserializeError>(&buffer)
+            .unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(compression, Some(Compression::Zstd));
+
+        let (_, compression) = CompressionDeserializer::new()
+            .deserialize::<DeserializeError>(&[])
+            .unwrap();
+        assert_eq!(compression, None);
+        let (_, compression) = CompressionDeserializer::new()
+            .deserialize::<DeserializeError>(&[200])
+            .unwrap();
+        assert_eq!(compression, None);
+        assert_eq!(negotiate_compression(Some(Compression::Zstd), None), None);
+    }
+}
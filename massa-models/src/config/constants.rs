@@ -119,6 +119,8 @@ pub const ENDORSEMENT_COUNT: u32 = 16;
 pub const DELTA_F0: u64 = 64 * (ENDORSEMENT_COUNT as u64 + 1);
 /// Maximum number of operations per block
 pub const MAX_OPERATIONS_PER_BLOCK: u32 = 5000;
+/// Maximum number of denunciations in a block header
+pub const MAX_DENUNCIATIONS_PER_BLOCK_HEADER: u32 = 128;
 /// Maximum block size in bytes
 pub const MAX_BLOCK_SIZE: u32 = 1_000_000;
 /// Maximum capacity of the asynchronous messages pool
@@ -141,6 +143,8 @@ pub const ASYNC_POOL_BOOTSTRAP_PART_SIZE: u64 = 100;
 pub const DEFERRED_CREDITS_BOOTSTRAP_PART_SIZE: u64 = 100;
 /// Maximum executed ops per slot in a bootstrap batch
 pub const EXECUTED_OPS_BOOTSTRAP_PART_SIZE: u64 = 10;
+/// Maximum executed denunciations per slot in a bootstrap batch
+pub const EXECUTED_DENUNCIATIONS_BOOTSTRAP_PART_SIZE: u64 = 10;
 /// Maximum number of consensus blocks in a bootstrap batch
 pub const CONSENSUS_BOOTSTRAP_PART_SIZE: u64 = 50;
 /// Maximum number of consensus block ids when sending a bootstrap cursor from the client
@@ -155,6 +159,10 @@ pub const MAX_DEFERRED_CREDITS_LENGTH: u64 = 10_000;
 pub const MAX_EXECUTED_OPS_LENGTH: u64 = 1_000;
 /// Maximum size of executed ops changes
 pub const MAX_EXECUTED_OPS_CHANGES_LENGTH: u64 = 20_000;
+/// Maximum size of executed denunciations
+pub const MAX_EXECUTED_DENUNCIATIONS_LENGTH: u64 = 1_000;
+/// Maximum size of executed denunciations changes
+pub const MAX_DENUNCIATION_CHANGES_LENGTH: u64 = 1_000;
 /// Maximum length of a datastore key
 pub const MAX_DATASTORE_KEY_LENGTH: u8 = 255;
 /// Maximum length of an operation datastore key
@@ -264,6 +272,12 @@ pub const MIP_STORE_STATS_COUNTERS_MAX: usize = 10;
 pub const DENUNCIATION_EXPIRE_PERIODS: u64 = PERIODS_PER_CYCLE;
 /// Cycle delta to accept items in denunciation factory
 pub const DENUNCIATION_ITEMS_MAX_CYCLE_DELTA: u64 = 1;
+/// Number of rolls to slash when a denunciation is executed
+pub const ROLL_COUNT_TO_SLASH_ON_DENUNCIATION: u64 = 1;
+/// Version of the block component (see the versioning MIPs) from which block headers can include denunciations
+pub const DENUNCIATIONS_BLOCK_COMPONENT_VERSION: u32 = 1;
+/// Version of the address component (see the versioning MIPs) from which multi-signature addresses and operations are accepted
+pub const MULTISIG_ADDRESS_COMPONENT_VERSION: u32 = 1;
 
 // Some checks at compile time that should not be ignored!
 #[allow(clippy::assertions_on_constants)]
@@ -9,6 +9,7 @@ use nom::{
     IResult, Parser,
 };
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::block_header::{
@@ -33,7 +34,7 @@ use massa_signature::{
 
 /// A Variant of Denunciation enum for endorsement
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EndorsementDenunciation {
     public_key: PublicKey,
     slot: Slot,
@@ -74,7 +75,7 @@ impl EndorsementDenunciation {
 
 /// A Variant of Denunciation enum for block header
 #[allow(dead_code)]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockHeaderDenunciation {
     public_key: PublicKey,
     slot: Slot,
@@ -112,7 +113,7 @@ impl BlockHeaderDenunciation {
 }
 
 /// A denunciation enum
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum Denunciation {
     Endorsement(EndorsementDenunciation),
@@ -253,6 +254,14 @@ impl Denunciation {
         }
     }
 
+    /// Get Denunciation public key ref (the public key of the denounced staker)
+    pub fn get_public_key(&self) -> &PublicKey {
+        match self {
+            Denunciation::Endorsement(endo_de) => &endo_de.public_key,
+            Denunciation::BlockHeader(blkh_de) => &blkh_de.public_key,
+        }
+    }
+
     /// For a given slot (and given the slot at now()), check if it can be denounced
     /// Can be used to check if block header | endorsement is not too old (at reception or too cleanup cache)
     pub fn is_expired(
@@ -819,6 +828,55 @@ impl From<&Denunciation> for DenunciationId {
     }
 }
 
+/// Serializer for `DenunciationId`
+#[derive(Default, Clone)]
+pub struct DenunciationIdSerializer;
+
+impl DenunciationIdSerializer {
+    /// Creates a new serializer for `DenunciationId`
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Serializer<DenunciationId> for DenunciationIdSerializer {
+    fn serialize(
+        &self,
+        value: &DenunciationId,
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), SerializeError> {
+        buffer.extend(value.to_bytes());
+        Ok(())
+    }
+}
+
+/// Deserializer for `DenunciationId`
+#[derive(Default, Clone)]
+pub struct DenunciationIdDeserializer {
+    hash_deserializer: HashDeserializer,
+}
+
+impl DenunciationIdDeserializer {
+    /// Creates a new deserializer for `DenunciationId`
+    pub fn new() -> Self {
+        Self {
+            hash_deserializer: HashDeserializer::new(),
+        }
+    }
+}
+
+impl Deserializer<DenunciationId> for DenunciationIdDeserializer {
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], DenunciationId, E> {
+        context("Failed DenunciationId deserialization", |input| {
+            let (rest, hash) = self.hash_deserializer.deserialize(input)?;
+            Ok((rest, DenunciationId(hash)))
+        })(buffer)
+    }
+}
+
 // End Denunciation Id
 
 // Denunciation interest
@@ -62,6 +62,8 @@ pub enum ModelsError {
     OutdatedBootstrapCursor,
     /// Error raised {0}
     ErrorRaised(String),
+    /// multi-signature error: {0}
+    MultiSigError(String),
 }
 
 impl From<nom::Err<nom::error::Error<&[u8]>>> for ModelsError {
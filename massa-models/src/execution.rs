@@ -1,6 +1,7 @@
 // Copyright (c) 2022 MASSA LABS <info@massa.net>
 
 use crate::amount::Amount;
+use crate::output_event::SCOutputEvent;
 use crate::{address::Address, operation::OperationId, slot::Slot};
 use massa_time::MassaTime;
 use serde::{Deserialize, Serialize};
@@ -32,6 +33,54 @@ pub struct EventFilter {
     pub is_error: Option<bool>,
 }
 
+impl EventFilter {
+    /// Returns true if the given event matches all the criteria of the filter
+    pub fn matches(&self, event: &SCOutputEvent) -> bool {
+        if let Some(start) = self.start {
+            if event.context.slot < start {
+                return false;
+            }
+        }
+        if let Some(end) = self.end {
+            if event.context.slot >= end {
+                return false;
+            }
+        }
+        if let Some(is_final) = self.is_final {
+            if event.context.is_final != is_final {
+                return false;
+            }
+        }
+        if let Some(is_error) = self.is_error {
+            if event.context.is_error != is_error {
+                return false;
+            }
+        }
+        match (self.emitter_address, event.context.call_stack.front()) {
+            (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
+            (Some(_), None) => return false,
+            _ => (),
+        }
+        match (
+            self.original_caller_address,
+            event.context.call_stack.back(),
+        ) {
+            (Some(addr1), Some(addr2)) if addr1 != *addr2 => return false,
+            (Some(_), None) => return false,
+            _ => (),
+        }
+        match (
+            self.original_operation_id,
+            event.context.origin_operation_id,
+        ) {
+            (Some(addr1), Some(addr2)) if addr1 != addr2 => return false,
+            (Some(_), None) => return false,
+            _ => (),
+        }
+        true
+    }
+}
+
 /// Used for Deserialize
 #[derive(Clone, Copy, Deserialize, Serialize, Debug)]
 pub struct TempFileVestingRange {
@@ -28,6 +28,8 @@ pub mod bytecode;
 pub mod clique;
 /// various structures
 pub mod composite;
+/// frame compression negotiated between nodes
+pub mod compression;
 /// node configuration
 pub mod config;
 /// datastore serialization / deserialization
@@ -1,10 +1,15 @@
 // Copyright (c) 2023 MASSA LABS <info@massa.net>
 
+use crate::address::ExecutionAddressCycleInfo;
 use crate::block::{Block, FilledBlock, SecureShareBlock};
 use crate::block_header::{BlockHeader, SecuredHeader};
+use crate::clique::Clique;
+use crate::config::CompactConfig;
 use crate::endorsement::{Endorsement, SecureShareEndorsement};
 use crate::operation::{Operation, OperationType, SecureShareOperation};
+use crate::output_event::{EventExecutionContext, SCOutputEvent};
 use crate::slot::{IndexedSlot, Slot};
+use crate::stats::{ConsensusStats, ExecutionStats, NetworkStats};
 use massa_proto::massa::api::v1 as grpc;
 
 impl From<Block> for grpc::Block {
@@ -152,6 +157,9 @@ impl From<OperationType> for grpc::OperationType {
                 };
                 grpc_operation_type.call_sc = Some(call_sc);
             }
+            // multi-signature operations are shown as the operation they execute,
+            // on behalf of the multi-signature address of the signed operation
+            OperationType::MultiSig { op, .. } => return (*op).into(),
         }
 
         grpc_operation_type
@@ -176,6 +184,7 @@ impl From<OperationType> for grpc::OpType {
             OperationType::RollSell { .. } => grpc::OpType::RollSell,
             OperationType::ExecuteSC { .. } => grpc::OpType::ExecuteSc,
             OperationType::CallSC { .. } => grpc::OpType::CallSc,
+            OperationType::MultiSig { op, .. } => (*op).into(),
         }
     }
 }
@@ -209,3 +218,110 @@ impl From<Slot> for grpc::Slot {
         }
     }
 }
+
+impl From<EventExecutionContext> for grpc::ScExecutionEventContext {
+    fn from(value: EventExecutionContext) -> Self {
+        grpc::ScExecutionEventContext {
+            slot: Some(value.slot.into()),
+            block_id: value.block.map(|id| id.to_string()),
+            index_in_slot: value.index_in_slot,
+            call_stack: value
+                .call_stack
+                .into_iter()
+                .map(|address| address.to_string())
+                .collect(),
+            origin_operation_id: value.origin_operation_id.map(|id| id.to_string()),
+            is_final: value.is_final,
+            read_only: value.read_only,
+            is_error: value.is_error,
+        }
+    }
+}
+
+impl From<SCOutputEvent> for grpc::ScExecutionEvent {
+    fn from(value: SCOutputEvent) -> Self {
+        grpc::ScExecutionEvent {
+            context: Some(value.context.into()),
+            data: value.data,
+        }
+    }
+}
+
+impl From<Clique> for grpc::Clique {
+    fn from(value: Clique) -> Self {
+        grpc::Clique {
+            block_ids: value
+                .block_ids
+                .into_iter()
+                .map(|block_id| block_id.to_string())
+                .collect(),
+            fitness: value.fitness,
+            is_blockclique: value.is_blockclique,
+        }
+    }
+}
+
+impl From<ExecutionAddressCycleInfo> for grpc::AddressCycleInfo {
+    fn from(value: ExecutionAddressCycleInfo) -> Self {
+        grpc::AddressCycleInfo {
+            cycle: value.cycle,
+            is_final: value.is_final,
+            ok_count: value.ok_count,
+            nok_count: value.nok_count,
+            active_rolls: value.active_rolls,
+        }
+    }
+}
+
+impl From<ExecutionStats> for grpc::ExecutionStats {
+    fn from(value: ExecutionStats) -> Self {
+        grpc::ExecutionStats {
+            time_window_start: value.time_window_start.to_millis(),
+            time_window_end: value.time_window_end.to_millis(),
+            final_block_count: value.final_block_count as u64,
+            final_executed_operations_count: value.final_executed_operations_count as u64,
+            active_cursor: Some(value.active_cursor.into()),
+        }
+    }
+}
+
+impl From<ConsensusStats> for grpc::ConsensusStats {
+    fn from(value: ConsensusStats) -> Self {
+        grpc::ConsensusStats {
+            start_timespan: value.start_timespan.to_millis(),
+            end_timespan: value.end_timespan.to_millis(),
+            final_block_count: value.final_block_count,
+            stale_block_count: value.stale_block_count,
+            clique_count: value.clique_count,
+        }
+    }
+}
+
+impl From<NetworkStats> for grpc::NetworkStats {
+    fn from(value: NetworkStats) -> Self {
+        grpc::NetworkStats {
+            in_connection_count: value.in_connection_count,
+            out_connection_count: value.out_connection_count,
+            known_peer_count: value.known_peer_count,
+            banned_peer_count: value.banned_peer_count,
+            active_node_count: value.active_node_count,
+        }
+    }
+}
+
+impl From<CompactConfig> for grpc::CompactConfig {
+    fn from(value: CompactConfig) -> Self {
+        grpc::CompactConfig {
+            genesis_timestamp: value.genesis_timestamp.to_millis(),
+            end_timestamp: value.end_timestamp.map(|time| time.to_millis()),
+            thread_count: value.thread_count as u32,
+            t0: value.t0.to_millis(),
+            delta_f0: value.delta_f0,
+            operation_validity_periods: value.operation_validity_periods,
+            periods_per_cycle: value.periods_per_cycle,
+            block_reward: value.block_reward.to_raw(),
+            roll_price: value.roll_price.to_raw(),
+            max_block_size: value.max_block_size,
+        }
+    }
+}
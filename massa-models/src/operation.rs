@@ -18,6 +18,11 @@ use massa_serialization::{
     U16VarIntSerializer, U32VarIntDeserializer, U32VarIntSerializer, U64VarIntDeserializer,
     U64VarIntSerializer,
 };
+use massa_signature::{
+    verify_signature_batch, MultiSigPublicKey, MultiSigPublicKeyDeserializer, MultiSignature,
+    MultiSignatureDeserializer, PublicKey, Signature,
+};
+use nom::combinator::verify;
 use nom::error::context;
 use nom::multi::length_count;
 use nom::sequence::tuple;
@@ -48,6 +53,8 @@ pub struct OperationId(Hash);
 
 const OPERATIONID_PREFIX: char = 'O';
 const OPERATIONID_VERSION: u64 = 0;
+/// prefix of the data hashed in `Operation::compute_multisig_hash`
+const MULTISIG_HASH_PREFIX: &[u8] = b"massa_multisig";
 
 /// Left part of the operation id hash stored in a vector of size [`OPERATION_ID_PREFIX_SIZE_BYTES`]
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -240,6 +247,7 @@ enum OperationTypeId {
     RollSell = 2,
     ExecuteSC = 3,
     CallSC = 4,
+    MultiSig = 5,
 }
 
 /// the operation as sent in the network
@@ -267,7 +275,40 @@ impl std::fmt::Display for Operation {
 /// signed operation
 pub type SecureShareOperation = SecureShare<Operation, OperationId>;
 
-impl SecureShareContent for Operation {}
+impl Operation {
+    /// Computes the hash signed by the members of a multi-signature operation, `None` for other operations.
+    /// It does not depend on the collected signatures nor on the member sending the operation,
+    /// and is prefixed so that it can never be mistaken for the hash of another signed payload.
+    pub fn compute_multisig_hash(&self) -> Option<Hash> {
+        let OperationType::MultiSig { public_key, op, .. } = &self.op else {
+            return None;
+        };
+        let mut hash_data = MULTISIG_HASH_PREFIX.to_vec();
+        hash_data.extend(Address::from_multisig_public_key(public_key).prefixed_bytes());
+        AmountSerializer::new()
+            .serialize(&self.fee, &mut hash_data)
+            .expect("impl always returns Ok(())");
+        U64VarIntSerializer::new()
+            .serialize(&self.expire_period, &mut hash_data)
+            .expect("impl always returns Ok(())");
+        OperationTypeSerializer::new()
+            .serialize(op, &mut hash_data)
+            .expect("impl always returns Ok(())");
+        Some(Hash::compute_from(&hash_data))
+    }
+}
+
+impl SecureShareContent for Operation {
+    /// Multi-signature operations are sent on behalf of the multi-signature address
+    fn compute_creator_address(content: &Self, content_creator_pub_key: &PublicKey) -> Address {
+        match &content.op {
+            OperationType::MultiSig { public_key, .. } => {
+                Address::from_multisig_public_key(public_key)
+            }
+            _ => Address::from_public_key(content_creator_pub_key),
+        }
+    }
+}
 
 /// Serializer for `Operation`
 pub struct OperationSerializer {
@@ -465,6 +506,26 @@ pub enum OperationType {
         /// Extra coins that are spent from the caller's balance and transferred to the target
         coins: Amount,
     },
+    /// Operation sent on behalf of a multi-signature address,
+    /// signed by at least `threshold` of its members
+    MultiSig {
+        /// public keys and threshold of the multi-signature address
+        public_key: MultiSigPublicKey,
+        /// signatures of the members, see `Operation::compute_multisig_hash`
+        signatures: MultiSignature,
+        /// operation to execute. Cannot be a multi-signature operation.
+        op: Box<OperationType>,
+    },
+}
+
+impl OperationType {
+    /// Gets the operation that is executed: the wrapped one for multi-signature operations
+    pub fn executed_op(&self) -> &OperationType {
+        match self {
+            OperationType::MultiSig { op, .. } => op,
+            op => op,
+        }
+    }
 }
 
 impl std::fmt::Display for OperationType {
@@ -508,6 +569,21 @@ impl std::fmt::Display for OperationType {
                 writeln!(f, "\t- max_gas:{}", max_gas)?;
                 writeln!(f, "\t- coins:{}", coins)?;
             }
+            OperationType::MultiSig {
+                public_key,
+                signatures,
+                op,
+            } => {
+                writeln!(f, "MultiSig:")?;
+                writeln!(f, "\t- address:{}", Address::from_multisig_public_key(public_key))?;
+                writeln!(
+                    f,
+                    "\t- signatures:{}/{}",
+                    signatures.get_signatures().len(),
+                    public_key.get_threshold()
+                )?;
+                write!(f, "{}", op)?;
+            }
         }
         Ok(())
     }
@@ -612,6 +688,17 @@ impl Serializer<OperationType> for OperationTypeSerializer {
                     .serialize(target_func, buffer)?;
                 self.vec_u8_serializer.serialize(param, buffer)?;
             }
+            OperationType::MultiSig {
+                public_key,
+                signatures,
+                op,
+            } => {
+                self.u32_serializer
+                    .serialize(&u32::from(OperationTypeId::MultiSig), buffer)?;
+                buffer.extend(public_key.to_bytes());
+                buffer.extend(signatures.to_bytes());
+                self.serialize(op, buffer)?;
+            }
         }
         Ok(())
     }
@@ -628,6 +715,8 @@ pub struct OperationTypeDeserializer {
     function_name_deserializer: StringDeserializer<U16VarIntDeserializer, u16>,
     parameter_deserializer: VecU8Deserializer,
     datastore_deserializer: DatastoreDeserializer,
+    multisig_public_key_deserializer: MultiSigPublicKeyDeserializer,
+    multisig_signature_deserializer: MultiSignatureDeserializer,
 }
 
 impl OperationTypeDeserializer {
@@ -666,6 +755,8 @@ impl OperationTypeDeserializer {
                 max_op_datastore_key_length,
                 max_op_datastore_value_length,
             ),
+            multisig_public_key_deserializer: MultiSigPublicKeyDeserializer::new(),
+            multisig_signature_deserializer: MultiSignatureDeserializer::new(),
         }
     }
 }
@@ -791,6 +882,37 @@ impl Deserializer<OperationType> for OperationTypeDeserializer {
                     },
                 )
                 .parse(input),
+                OperationTypeId::MultiSig => context(
+                    "Failed MultiSig deserialization",
+                    tuple((
+                        context("Failed public_key deserialization", |input| {
+                            self.multisig_public_key_deserializer.deserialize(input)
+                        }),
+                        context("Failed signatures deserialization", |input| {
+                            self.multisig_signature_deserializer.deserialize(input)
+                        }),
+                        context(
+                            "Failed op deserialization",
+                            verify(
+                                |input| self.deserialize(input),
+                                |op: &OperationType| {
+                                    !matches!(
+                                        op,
+                                        OperationType::MultiSig { .. }
+                                            | OperationType::RollBuy { .. }
+                                            | OperationType::RollSell { .. }
+                                    )
+                                },
+                            ),
+                        ),
+                    )),
+                )
+                .map(|(public_key, signatures, op)| OperationType::MultiSig {
+                    public_key,
+                    signatures,
+                    op: Box::new(op),
+                })
+                .parse(input),
             }
         })
         .parse(buffer)
@@ -810,21 +932,21 @@ impl SecureShareOperation {
 
     /// Get the max amount of gas used by the operation (`max_gas`)
     pub fn get_gas_usage(&self) -> u64 {
-        match &self.content.op {
+        match self.content.op.executed_op() {
             OperationType::ExecuteSC { max_gas, .. } => *max_gas,
             OperationType::CallSC { max_gas, .. } => *max_gas,
             OperationType::RollBuy { .. } => 0,
             OperationType::RollSell { .. } => 0,
             OperationType::Transaction { .. } => 0,
+            OperationType::MultiSig { .. } => 0,
         }
     }
 
     /// get the addresses that are involved in this operation from a ledger point of view
     pub fn get_ledger_involved_addresses(&self) -> PreHashSet<Address> {
         let mut res = PreHashSet::<Address>::default();
-        let emitter_address = Address::from_public_key(&self.content_creator_pub_key);
-        res.insert(emitter_address);
-        match &self.content.op {
+        res.insert(self.content_creator_address);
+        match self.content.op.executed_op() {
             OperationType::Transaction {
                 recipient_address, ..
             } => {
@@ -836,6 +958,7 @@ impl SecureShareOperation {
             OperationType::CallSC { target_addr, .. } => {
                 res.insert(*target_addr);
             }
+            OperationType::MultiSig { .. } => {}
         }
         res
     }
@@ -843,12 +966,13 @@ impl SecureShareOperation {
     /// Gets the maximal amount of coins that may be spent by this operation (incl. fee)
     pub fn get_max_spending(&self, roll_price: Amount) -> Amount {
         // compute the max amount of coins spent outside of the fees
-        let max_non_fee_seq_spending = match &self.content.op {
+        let max_non_fee_seq_spending = match self.content.op.executed_op() {
             OperationType::Transaction { amount, .. } => *amount,
             OperationType::RollBuy { roll_count } => roll_price.saturating_mul_u64(*roll_count),
             OperationType::RollSell { .. } => Amount::zero(),
             OperationType::ExecuteSC { .. } => Amount::zero(),
             OperationType::CallSC { coins, .. } => *coins,
+            OperationType::MultiSig { .. } => Amount::zero(),
         };
 
         // add all fees and return
@@ -858,19 +982,57 @@ impl SecureShareOperation {
     /// get the addresses that are involved in this operation from a rolls point of view
     pub fn get_roll_involved_addresses(&self) -> Result<PreHashSet<Address>, ModelsError> {
         let mut res = PreHashSet::<Address>::default();
-        match self.content.op {
+        match self.content.op.executed_op() {
             OperationType::Transaction { .. } => {}
             OperationType::RollBuy { .. } => {
-                res.insert(Address::from_public_key(&self.content_creator_pub_key));
+                res.insert(self.content_creator_address);
             }
             OperationType::RollSell { .. } => {
-                res.insert(Address::from_public_key(&self.content_creator_pub_key));
+                res.insert(self.content_creator_address);
             }
             OperationType::ExecuteSC { .. } => {}
             OperationType::CallSC { .. } => {}
+            OperationType::MultiSig { .. } => {}
         }
         Ok(res)
     }
+
+    /// Gets the `(hash, signature, public_key)` triplets proving that a multi-signature operation
+    /// was signed by enough members, to be verified along with the operation signature.
+    /// Empty for other operations.
+    pub fn get_multisig_signature_batch(
+        &self,
+    ) -> Result<Vec<(Hash, Signature, PublicKey)>, ModelsError> {
+        let OperationType::MultiSig {
+            public_key,
+            signatures,
+            ..
+        } = &self.content.op
+        else {
+            return Ok(Vec::new());
+        };
+        if public_key
+            .get_index(&self.content_creator_pub_key)
+            .is_none()
+        {
+            return Err(ModelsError::MultiSigError(
+                "the operation is not sent by a member of the multi-signature address".to_string(),
+            ));
+        }
+        let hash = self
+            .content
+            .compute_multisig_hash()
+            .expect("multi-signature operation");
+        Ok(public_key.get_signature_batch(&hash, signatures)?)
+    }
+
+    /// Checks that a multi-signature operation was signed by enough members.
+    /// Does nothing for other operations.
+    pub fn verify_multisig(&self) -> Result<(), ModelsError> {
+        Ok(verify_signature_batch(
+            &self.get_multisig_signature_batch()?,
+        )?)
+    }
 }
 
 /// Set of operation id's prefix
@@ -1509,4 +1671,124 @@ mod tests {
 
         assert_eq!(op.get_validity_range(10), 40..=50);
     }
+
+    #[test]
+    #[serial]
+    fn test_multisig() {
+        let member_keypairs: Vec<KeyPair> = (0..3).map(|_| KeyPair::generate()).collect();
+        let recv_keypair = KeyPair::generate();
+        let multisig_key = MultiSigPublicKey::new(
+            2,
+            member_keypairs.iter().map(|k| k.get_public_key()).collect(),
+        )
+        .unwrap();
+        let multisig_address = Address::from_multisig_public_key(&multisig_key);
+
+        let mut content = Operation {
+            fee: Amount::from_str("20").unwrap(),
+            op: OperationType::MultiSig {
+                public_key: multisig_key.clone(),
+                signatures: MultiSignature::default(),
+                op: Box::new(OperationType::Transaction {
+                    recipient_address: Address::from_public_key(&recv_keypair.get_public_key()),
+                    amount: Amount::from_str("300").unwrap(),
+                }),
+            },
+            expire_period: 50,
+        };
+        let hash = content.compute_multisig_hash().unwrap();
+        let OperationType::MultiSig { signatures, .. } = &mut content.op else {
+            unreachable!();
+        };
+        for keypair in &member_keypairs[1..] {
+            let index = multisig_key.get_index(&keypair.get_public_key()).unwrap();
+            signatures.add_signature(index, keypair.sign(&hash).unwrap());
+        }
+        // collecting signatures does not change the signed hash
+        assert_eq!(content.compute_multisig_hash(), Some(hash));
+
+        let op = Operation::new_verifiable::<_, OperationId>(
+            content,
+            OperationSerializer::new(),
+            &member_keypairs[1],
+        )
+        .unwrap();
+        // the operation id covers the collected signatures, unlike the signed hash
+        assert_ne!(op.id.get_hash(), &hash);
+        assert_eq!(op.content_creator_address, multisig_address);
+        assert!(op
+            .get_ledger_involved_addresses()
+            .contains(&multisig_address));
+
+        let mut ser_op = Vec::new();
+        SecureShareSerializer::new()
+            .serialize(&op, &mut ser_op)
+            .unwrap();
+        let (_, res_op): (&[u8], SecureShareOperation) =
+            SecureShareDeserializer::new(OperationDeserializer::new(
+                MAX_DATASTORE_VALUE_LENGTH,
+                MAX_FUNCTION_NAME_LENGTH,
+                MAX_PARAMETERS_SIZE,
+                MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+                MAX_OPERATION_DATASTORE_KEY_LENGTH,
+                MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+            ))
+            .deserialize::<DeserializeError>(&ser_op)
+            .unwrap();
+        assert_eq!(res_op, op);
+        res_op.verify_signature().unwrap();
+        res_op.verify_multisig().unwrap();
+
+        // the operation cannot be sent by a key that is not a member
+        let mut content = op.content.clone();
+        let op = Operation::new_verifiable::<_, OperationId>(
+            content.clone(),
+            OperationSerializer::new(),
+            &recv_keypair,
+        )
+        .unwrap();
+        assert!(op.verify_multisig().is_err());
+
+        // a single signature is below the threshold
+        let OperationType::MultiSig { signatures, .. } = &mut content.op else {
+            unreachable!();
+        };
+        *signatures = MultiSignature::default();
+        let index = multisig_key
+            .get_index(&member_keypairs[0].get_public_key())
+            .unwrap();
+        signatures.add_signature(index, member_keypairs[0].sign(&hash).unwrap());
+        let op = Operation::new_verifiable::<_, OperationId>(
+            content,
+            OperationSerializer::new(),
+            &member_keypairs[0],
+        )
+        .unwrap();
+        assert!(op.verify_multisig().is_err());
+
+        // multi-signature addresses cannot buy or sell rolls
+        let content = Operation {
+            fee: Amount::from_str("20").unwrap(),
+            op: OperationType::MultiSig {
+                public_key: multisig_key,
+                signatures: MultiSignature::default(),
+                op: Box::new(OperationType::RollBuy { roll_count: 1 }),
+            },
+            expire_period: 50,
+        };
+        let mut ser_content = Vec::new();
+        OperationSerializer::new()
+            .serialize(&content, &mut ser_content)
+            .unwrap();
+        assert!(OperationDeserializer::new(
+            MAX_DATASTORE_VALUE_LENGTH,
+            MAX_FUNCTION_NAME_LENGTH,
+            MAX_PARAMETERS_SIZE,
+            MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+            MAX_OPERATION_DATASTORE_KEY_LENGTH,
+            MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        )
+        .deserialize::<DeserializeError>(&ser_content)
+        .is_err());
+    }
 }
@@ -1,5 +1,22 @@
-use crate::{address::Address, block_id::BlockId, operation::OperationId, slot::Slot};
+use crate::{
+    address::{Address, AddressDeserializer, AddressSerializer},
+    block_id::{BlockId, BlockIdDeserializer, BlockIdSerializer},
+    operation::{OperationId, OperationIdDeserializer, OperationIdSerializer},
+    serialization::{StringDeserializer, StringSerializer},
+    slot::{Slot, SlotDeserializer, SlotSerializer},
+};
+use massa_serialization::{
+    BoolDeserializer, BoolSerializer, Deserializer, OptionDeserializer, OptionSerializer,
+    SerializeError, Serializer, U64VarIntDeserializer, U64VarIntSerializer,
+};
+use nom::{
+    error::{context, ContextError, ParseError},
+    multi::length_count,
+    sequence::tuple,
+    IResult, Parser,
+};
 use serde::{Deserialize, Serialize};
+use std::ops::Bound::{Excluded, Included};
 use std::{collections::VecDeque, fmt::Display};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,3 +86,221 @@ impl Display for EventExecutionContext {
         )
     }
 }
+
+/// Serializer for `SCOutputEvent`
+pub struct SCOutputEventSerializer {
+    slot_serializer: SlotSerializer,
+    block_id_serializer: OptionSerializer<BlockId, BlockIdSerializer>,
+    bool_serializer: BoolSerializer,
+    u64_serializer: U64VarIntSerializer,
+    address_serializer: AddressSerializer,
+    operation_id_serializer: OptionSerializer<OperationId, OperationIdSerializer>,
+    data_serializer: StringSerializer<U64VarIntSerializer, u64>,
+}
+
+impl SCOutputEventSerializer {
+    /// Creates a new `SCOutputEventSerializer`
+    pub fn new() -> Self {
+        Self {
+            slot_serializer: SlotSerializer::new(),
+            block_id_serializer: OptionSerializer::new(BlockIdSerializer::new()),
+            bool_serializer: BoolSerializer::new(),
+            u64_serializer: U64VarIntSerializer::new(),
+            address_serializer: AddressSerializer::new(),
+            operation_id_serializer: OptionSerializer::new(OperationIdSerializer::new()),
+            data_serializer: StringSerializer::new(U64VarIntSerializer::new()),
+        }
+    }
+}
+
+impl Default for SCOutputEventSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Serializer<SCOutputEvent> for SCOutputEventSerializer {
+    /// ## Example:
+    /// ```rust
+    /// use massa_models::output_event::{EventExecutionContext, SCOutputEvent, SCOutputEventSerializer};
+    /// use massa_models::slot::Slot;
+    /// use massa_serialization::Serializer;
+    /// use std::collections::VecDeque;
+    ///
+    /// let event = SCOutputEvent {
+    ///     context: EventExecutionContext {
+    ///         slot: Slot::new(1, 0),
+    ///         block: None,
+    ///         read_only: false,
+    ///         index_in_slot: 0,
+    ///         call_stack: VecDeque::new(),
+    ///         origin_operation_id: None,
+    ///         is_final: true,
+    ///         is_error: false,
+    ///     },
+    ///     data: "hello".to_string(),
+    /// };
+    /// let mut buffer = Vec::new();
+    /// SCOutputEventSerializer::new().serialize(&event, &mut buffer).unwrap();
+    /// ```
+    fn serialize(&self, value: &SCOutputEvent, buffer: &mut Vec<u8>) -> Result<(), SerializeError> {
+        let ctx = &value.context;
+        self.slot_serializer.serialize(&ctx.slot, buffer)?;
+        self.block_id_serializer.serialize(&ctx.block, buffer)?;
+        self.bool_serializer.serialize(&ctx.read_only, buffer)?;
+        self.u64_serializer.serialize(&ctx.index_in_slot, buffer)?;
+        self.u64_serializer
+            .serialize(&(ctx.call_stack.len() as u64), buffer)?;
+        for address in ctx.call_stack.iter() {
+            self.address_serializer.serialize(address, buffer)?;
+        }
+        self.operation_id_serializer
+            .serialize(&ctx.origin_operation_id, buffer)?;
+        self.bool_serializer.serialize(&ctx.is_final, buffer)?;
+        self.bool_serializer.serialize(&ctx.is_error, buffer)?;
+        self.data_serializer.serialize(&value.data, buffer)?;
+        Ok(())
+    }
+}
+
+/// Deserializer for `SCOutputEvent`
+pub struct SCOutputEventDeserializer {
+    slot_deserializer: SlotDeserializer,
+    block_id_deserializer: OptionDeserializer<BlockId, BlockIdDeserializer>,
+    bool_deserializer: BoolDeserializer,
+    index_deserializer: U64VarIntDeserializer,
+    call_stack_length_deserializer: U64VarIntDeserializer,
+    address_deserializer: AddressDeserializer,
+    operation_id_deserializer: OptionDeserializer<OperationId, OperationIdDeserializer>,
+    data_deserializer: StringDeserializer<U64VarIntDeserializer, u64>,
+}
+
+impl SCOutputEventDeserializer {
+    /// Creates a new `SCOutputEventDeserializer`
+    ///
+    /// # Arguments
+    /// * `thread_count`: number of threads
+    /// * `max_call_stack_length`: maximum number of addresses in the call stack of an event
+    /// * `max_data_length`: maximum length of the data of an event
+    pub fn new(thread_count: u8, max_call_stack_length: u64, max_data_length: u64) -> Self {
+        Self {
+            slot_deserializer: SlotDeserializer::new(
+                (Included(u64::MIN), Included(u64::MAX)),
+                (Included(0), Excluded(thread_count)),
+            ),
+            block_id_deserializer: OptionDeserializer::new(BlockIdDeserializer::new()),
+            bool_deserializer: BoolDeserializer::new(),
+            index_deserializer: U64VarIntDeserializer::new(Included(0), Included(u64::MAX)),
+            call_stack_length_deserializer: U64VarIntDeserializer::new(
+                Included(0),
+                Included(max_call_stack_length),
+            ),
+            address_deserializer: AddressDeserializer::new(),
+            operation_id_deserializer: OptionDeserializer::new(OperationIdDeserializer::new()),
+            data_deserializer: StringDeserializer::new(U64VarIntDeserializer::new(
+                Included(0),
+                Included(max_data_length),
+            )),
+        }
+    }
+}
+
+impl Deserializer<SCOutputEvent> for SCOutputEventDeserializer {
+    /// ## Example:
+    /// ```rust
+    /// use massa_models::output_event::{EventExecutionContext, SCOutputEvent, SCOutputEventSerializer, SCOutputEventDeserializer};
+    /// use massa_models::slot::Slot;
+    /// use massa_serialization::{Serializer, Deserializer, DeserializeError};
+    /// use std::collections::VecDeque;
+    ///
+    /// let event = SCOutputEvent {
+    ///     context: EventExecutionContext {
+    ///         slot: Slot::new(1, 0),
+    ///         block: None,
+    ///         read_only: false,
+    ///         index_in_slot: 3,
+    ///         call_stack: VecDeque::new(),
+    ///         origin_operation_id: None,
+    ///         is_final: true,
+    ///         is_error: false,
+    ///     },
+    ///     data: "hello".to_string(),
+    /// };
+    /// let mut buffer = Vec::new();
+    /// SCOutputEventSerializer::new().serialize(&event, &mut buffer).unwrap();
+    /// let (rest, event_der) = SCOutputEventDeserializer::new(32, 100, 1000)
+    ///     .deserialize::<DeserializeError>(&buffer)
+    ///     .unwrap();
+    /// assert!(rest.is_empty());
+    /// assert_eq!(event_der.context.slot, event.context.slot);
+    /// assert_eq!(event_der.context.index_in_slot, event.context.index_in_slot);
+    /// assert_eq!(event_der.data, event.data);
+    /// ```
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], SCOutputEvent, E> {
+        context(
+            "Failed SCOutputEvent deserialization",
+            tuple((
+                context("Failed slot deserialization", |input| {
+                    self.slot_deserializer.deserialize(input)
+                }),
+                context("Failed block deserialization", |input| {
+                    self.block_id_deserializer.deserialize(input)
+                }),
+                context("Failed read_only deserialization", |input| {
+                    self.bool_deserializer.deserialize(input)
+                }),
+                context("Failed index_in_slot deserialization", |input| {
+                    self.index_deserializer.deserialize(input)
+                }),
+                context(
+                    "Failed call_stack deserialization",
+                    length_count(
+                        |input| self.call_stack_length_deserializer.deserialize(input),
+                        |input| self.address_deserializer.deserialize(input),
+                    ),
+                ),
+                context("Failed origin_operation_id deserialization", |input| {
+                    self.operation_id_deserializer.deserialize(input)
+                }),
+                context("Failed is_final deserialization", |input| {
+                    self.bool_deserializer.deserialize(input)
+                }),
+                context("Failed is_error deserialization", |input| {
+                    self.bool_deserializer.deserialize(input)
+                }),
+                context("Failed data deserialization", |input| {
+                    self.data_deserializer.deserialize(input)
+                }),
+            )),
+        )
+        .map(
+            |(
+                slot,
+                block,
+                read_only,
+                index_in_slot,
+                call_stack,
+                origin_operation_id,
+                is_final,
+                is_error,
+                data,
+            )| SCOutputEvent {
+                context: EventExecutionContext {
+                    slot,
+                    block,
+                    read_only,
+                    index_in_slot,
+                    call_stack: call_stack.into_iter().collect(),
+                    origin_operation_id,
+                    is_final,
+                    is_error,
+                },
+                data,
+            },
+        )
+        .parse(buffer)
+    }
+}
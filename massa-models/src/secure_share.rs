@@ -63,13 +63,36 @@ where
         content_serializer: Ser,
         keypair: &KeyPair,
     ) -> Result<SecureShare<Self, ID>, ModelsError> {
+        Self::new_verifiable_with_signer(
+            content,
+            content_serializer,
+            keypair.get_public_key(),
+            |hash| Ok(keypair.sign(hash)?),
+        )
+    }
+
+    /// Same as `new_verifiable`, but the hash is signed by the provided closure,
+    /// allowing to sign with keys that are not held in the process.
+    fn new_verifiable_with_signer<Ser, ID, E, F>(
+        content: Self,
+        content_serializer: Ser,
+        public_key: PublicKey,
+        sign: F,
+    ) -> Result<SecureShare<Self, ID>, E>
+    where
+        Ser: Serializer<Self>,
+        ID: Id,
+        E: From<ModelsError>,
+        F: FnOnce(&Hash) -> Result<Signature, E>,
+    {
         let mut content_serialized = Vec::new();
-        content_serializer.serialize(&content, &mut content_serialized)?;
-        let public_key = keypair.get_public_key();
+        content_serializer
+            .serialize(&content, &mut content_serialized)
+            .map_err(ModelsError::from)?;
         let hash = Self::compute_hash(&content, &content_serialized, &public_key);
-        let creator_address = Address::from_public_key(&public_key);
+        let creator_address = Self::compute_creator_address(&content, &public_key);
         Ok(SecureShare {
-            signature: keypair.sign(&hash)?,
+            signature: sign(&hash)?,
             content_creator_pub_key: public_key,
             content_creator_address: creator_address,
             content,
@@ -91,6 +114,12 @@ where
         Hash::compute_from(&hash_data)
     }
 
+    /// Compute the address on behalf of which the content is shared
+    #[allow(unused_variables)]
+    fn compute_creator_address(content: &Self, content_creator_pub_key: &PublicKey) -> Address {
+        Address::from_public_key(content_creator_pub_key)
+    }
+
     /// Serialize the secured structure
     fn serialize(
         signature: &Signature,
@@ -144,7 +173,7 @@ where
             // Avoid getting the rest of the data in the serialized data
             serialized_data[..serialized_data.len() - rest.len()].to_vec()
         };
-        let creator_address = Address::from_public_key(&creator_public_key);
+        let creator_address = Self::compute_creator_address(&content, &creator_public_key);
         let hash = Self::compute_hash(&content, &content_serialized, &creator_public_key);
 
         Ok((
@@ -86,6 +86,7 @@ pub fn gen_block_headers_for_denunciation(
         parents: parents_1,
         operation_merkle_root: Hash::compute_from("mno".as_bytes()),
         endorsements: vec![s_endorsement_1.clone()],
+        denunciations: Vec::new(),
     };
 
     // create header
@@ -101,6 +102,7 @@ pub fn gen_block_headers_for_denunciation(
         parents: parents_2,
         operation_merkle_root: Hash::compute_from("mno".as_bytes()),
         endorsements: vec![s_endorsement_1.clone()],
+        denunciations: Vec::new(),
     };
 
     // create header
@@ -116,6 +118,7 @@ pub fn gen_block_headers_for_denunciation(
         parents: parents_3,
         operation_merkle_root: Hash::compute_from("mno".as_bytes()),
         endorsements: vec![s_endorsement_1.clone()],
+        denunciations: Vec::new(),
     };
 
     // create header
@@ -1,4 +1,5 @@
 use massa_hash::Hash;
+use massa_metrics::MODULE_CACHE_LOOKUPS;
 use massa_models::prehash::BuildHashMapper;
 use massa_sc_runtime::{Compiler, RuntimeModule};
 use schnellru::{ByLength, LruMap};
@@ -97,16 +98,19 @@ impl ModuleCache {
         let hash = Hash::compute_from(bytecode);
         if let Some(lru_module_info) = self.lru_cache.get(hash) {
             debug!("load_module: {} present in lru", hash);
+            MODULE_CACHE_LOOKUPS.with_label_values(&["lru_hit"]).inc();
             lru_module_info
         } else if let Some(hd_module_info) =
             self.hd_cache
                 .get(hash, self.cfg.compilation_gas, self.cfg.gas_costs.clone())
         {
             debug!("load_module: {} missing in lru but present in hd", hash);
+            MODULE_CACHE_LOOKUPS.with_label_values(&["hd_hit"]).inc();
             self.lru_cache.insert(hash, hd_module_info.clone());
             hd_module_info
         } else {
             debug!("load_module: {} missing", hash);
+            MODULE_CACHE_LOOKUPS.with_label_values(&["miss"]).inc();
             let module_info = self.compile_cached(bytecode, hash);
             self.hd_cache.insert(hash, module_info.clone());
             self.lru_cache.insert(hash, module_info.clone());
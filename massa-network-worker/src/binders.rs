@@ -5,13 +5,14 @@ use crate::messages::{MessageDeserializer, MessageSerializer};
 
 use super::messages::Message;
 use massa_models::{
+    compression::{decode_frame, encode_frame, Compression},
     error::ModelsError,
     serialization::{DeserializeMinBEInt, SerializeMinBEInt},
 };
 use massa_network_exports::{NetworkError, ReadHalf, WriteHalf};
 use massa_serialization::Serializer;
 use massa_serialization::{DeserializeError, Deserializer};
-use std::convert::TryInto;
+use std::{borrow::Cow, convert::TryInto};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tracing::warn;
 
@@ -20,6 +21,7 @@ pub struct WriteBinder {
     pub(crate) write_half: WriteHalf,
     message_index: u64,
     max_message_size: u32,
+    compression: Option<Compression>,
 }
 
 impl WriteBinder {
@@ -33,9 +35,15 @@ impl WriteBinder {
             write_half,
             message_index: 0,
             max_message_size,
+            compression: None,
         }
     }
 
+    /// Compresses the frames sent from now on, once negotiated with the peer.
+    pub fn set_compression(&mut self, compression: Option<Compression>) {
+        self.compression = compression;
+    }
+
     /// Sends a serialized message.
     ///
     /// # Argument
@@ -44,6 +52,9 @@ impl WriteBinder {
         //        massa_trace!("binder.send", { "msg": msg });
         let mut buf = Vec::new();
         MessageSerializer::new().serialize(msg, &mut buf)?;
+        if let Some(compression) = self.compression {
+            buf = encode_frame(compression, &buf)?;
+        }
         let msg_size: u32 = buf
             .len()
             .try_into()
@@ -71,6 +82,7 @@ pub struct ReadBinder {
     msg_size: Option<u32>,
     max_message_size: u32,
     message_deserializer: MessageDeserializer,
+    compression: Option<Compression>,
 }
 
 impl ReadBinder {
@@ -93,9 +105,15 @@ impl ReadBinder {
             msg_size: None,
             max_message_size,
             message_deserializer,
+            compression: None,
         }
     }
 
+    /// Expects compressed frames from now on, once negotiated with the peer.
+    pub fn set_compression(&mut self, compression: Option<Compression>) {
+        self.compression = compression;
+    }
+
     /// Awaits the next incoming message and deserializes it. Asynchronous cancel-safe.
     /// Returns the message, as well as the serialized object in the case of a block.
     ///
@@ -169,9 +187,14 @@ impl ReadBinder {
                 }
             }
         }
+        // decompressed messages are bounded by the same size as raw ones
+        let payload = match self.compression {
+            Some(_) => decode_frame(&self.buf, self.max_message_size as usize)?,
+            None => Cow::Borrowed(&self.buf[..]),
+        };
         let (_, res_msg) = self
             .message_deserializer
-            .deserialize::<DeserializeError>(&self.buf)
+            .deserialize::<DeserializeError>(&payload)
             .map_err(|err| {
                 warn!("error deserializing message: {:?}", err);
                 NetworkError::ModelsError(ModelsError::DeserializeError(err.to_string()))
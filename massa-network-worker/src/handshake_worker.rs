@@ -12,6 +12,7 @@ use futures::future::try_join;
 use massa_hash::Hash;
 use massa_logging::massa_trace;
 use massa_models::{
+    compression::{negotiate_compression, Compression},
     config::{
         constants::{MAX_DATASTORE_VALUE_LENGTH, MAX_FUNCTION_NAME_LENGTH, MAX_PARAMETERS_SIZE},
         ENDORSEMENT_COUNT, MAX_ADVERTISE_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE, MAX_MESSAGE_SIZE,
@@ -140,6 +141,7 @@ impl HandshakeWorker {
             public_key: self.self_node_id.get_public_key(),
             random_bytes: self_random_bytes,
             version: self.version,
+            compression: Some(Compression::Zstd),
         };
         let send_init_fut = self.writer.send(&msg);
 
@@ -147,7 +149,7 @@ impl HandshakeWorker {
         let recv_init_fut = self.reader.next();
 
         // join send_init_fut and recv_init_fut with a timeout, and match result
-        let (other_node_id, other_random_bytes, other_version) = match timeout(
+        let (other_node_id, other_random_bytes, other_version, other_compression) = match timeout(
             self.timeout_duration.to_duration(),
             try_join(send_init_fut, recv_init_fut),
         )
@@ -161,7 +163,8 @@ impl HandshakeWorker {
                     public_key: pk,
                     random_bytes: rb,
                     version,
-                } => (NodeId::new(pk), rb, version),
+                    compression,
+                } => (NodeId::new(pk), rb, version, compression),
                 Message::PeerList(list) => throw!(PeerListReceived, list),
                 _ => throw!(HandshakeWrongMessage),
             },
@@ -214,6 +217,11 @@ impl HandshakeWorker {
                 NetworkError::HandshakeError(HandshakeErrorType::HandshakeInvalidSignature)
             })?;
 
+        // both sides exchanged their replies uncompressed: frames are compressed from now on if we both support it
+        let compression = negotiate_compression(Some(Compression::Zstd), other_compression);
+        self.reader.set_compression(compression);
+        self.writer.set_compression(compression);
+
         Ok((other_node_id, self.reader, self.writer))
     }
 }
@@ -18,6 +18,8 @@ use massa_network_exports::{
     NetworkEvent, NetworkEventReceiver, NetworkManagementCommand, NetworkManager,
 };
 use massa_signature::KeyPair;
+use massa_wallet::{LocalSigner, Signer};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
@@ -38,11 +40,13 @@ pub mod tests;
 ///
 /// # Arguments
 /// * `cfg`: network configuration
+/// * `node_signer`: signer of the messages signed on behalf of the node address, the node key if `None`
 pub async fn start_network_controller(
     network_settings: &NetworkConfig,
     mut establisher: Establisher,
     initial_peers: Option<BootstrapPeers>,
     version: Version,
+    node_signer: Option<Arc<dyn Signer>>,
 ) -> Result<
     (
         NetworkCommandSender,
@@ -109,10 +113,13 @@ pub async fn start_network_controller(
     let (manager_tx, controller_manager_rx) = mpsc::channel::<NetworkManagementCommand>(1);
     let cfg_copy = network_settings.clone();
     let keypair_cloned = keypair.clone();
+    let node_signer =
+        node_signer.unwrap_or_else(|| Arc::new(LocalSigner::from_keypair(keypair.clone())));
     let join_handle = tokio::spawn(async move {
         let res = NetworkWorker::new(
             cfg_copy,
             keypair_cloned,
+            node_signer,
             listener,
             establisher,
             peer_info_db,
@@ -4,7 +4,8 @@ use massa_hash::HashDeserializer;
 use massa_models::{
     block_header::{BlockHeader, BlockHeaderDeserializer, SecuredHeader},
     block_id::BlockId,
-    config::HANDSHAKE_RANDOMNESS_SIZE_BYTES,
+    compression::{Compression, CompressionDeserializer, CompressionSerializer},
+    config::{HANDSHAKE_RANDOMNESS_SIZE_BYTES, MAX_DENUNCIATIONS_PER_BLOCK_HEADER},
     endorsement::{Endorsement, EndorsementDeserializer, SecureShareEndorsement},
     operation::{
         OperationIdsDeserializer, OperationIdsSerializer, OperationPrefixIds,
@@ -46,6 +47,9 @@ pub enum Message {
         /// let us know their public key.
         random_bytes: [u8; HANDSHAKE_RANDOMNESS_SIZE_BYTES],
         version: Version,
+        /// Frame compression we support, if any.
+        /// Sent last so that nodes unaware of it ignore it.
+        compression: Option<Compression>,
     },
     /// Reply to a handshake initiation message.
     HandshakeReply {
@@ -103,6 +107,7 @@ pub(crate) enum BlockInfoType {
 /// Basic serializer for `Message`.
 pub struct MessageSerializer {
     version_serializer: VersionSerializer,
+    compression_serializer: CompressionSerializer,
     u32_serializer: U32VarIntSerializer,
     secure_serializer: SecureShareSerializer,
     operation_prefix_ids_serializer: OperationPrefixIdsSerializer,
@@ -116,6 +121,7 @@ impl MessageSerializer {
     pub fn new() -> Self {
         MessageSerializer {
             version_serializer: VersionSerializer::new(),
+            compression_serializer: CompressionSerializer::new(),
             u32_serializer: U32VarIntSerializer::new(),
             secure_serializer: SecureShareSerializer::new(),
             operation_prefix_ids_serializer: OperationPrefixIdsSerializer::new(),
@@ -141,12 +147,14 @@ impl Serializer<Message> for MessageSerializer {
                 public_key,
                 random_bytes,
                 version,
+                compression,
             } => {
                 self.u32_serializer
                     .serialize(&(MessageTypeId::HandshakeInitiation as u32), buffer)?;
                 buffer.extend(public_key.to_bytes());
                 buffer.extend(random_bytes);
                 self.version_serializer.serialize(version, buffer)?;
+                self.compression_serializer.serialize(compression, buffer)?;
             }
             Message::HandshakeReply { signature } => {
                 self.u32_serializer
@@ -252,6 +260,7 @@ pub struct MessageDeserializer {
     public_key_deserializer: PublicKeyDeserializer,
     signature_deserializer: SignatureDeserializer,
     version_deserializer: VersionDeserializer,
+    compression_deserializer: CompressionDeserializer,
     id_deserializer: U32VarIntDeserializer,
     ask_block_number_deserializer: U32VarIntDeserializer,
     peer_list_length_deserializer: U32VarIntDeserializer,
@@ -289,6 +298,7 @@ impl MessageDeserializer {
             public_key_deserializer: PublicKeyDeserializer::new(),
             signature_deserializer: SignatureDeserializer::new(),
             version_deserializer: VersionDeserializer::new(),
+            compression_deserializer: CompressionDeserializer::new(),
             id_deserializer: U32VarIntDeserializer::new(Included(0), Included(u32::MAX)),
             ask_block_number_deserializer: U32VarIntDeserializer::new(
                 Included(0),
@@ -320,6 +330,7 @@ impl MessageDeserializer {
             block_header_deserializer: SecureShareDeserializer::new(BlockHeaderDeserializer::new(
                 thread_count,
                 endorsement_count,
+                MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
                 last_start_period,
             )),
             endorsements_length_deserializer: U32VarIntDeserializer::new(
@@ -366,15 +377,21 @@ impl Deserializer<Message> for MessageDeserializer {
                         context("Failed version deserialization", |input| {
                             self.version_deserializer.deserialize(input)
                         }),
+                        context("Failed compression deserialization", |input| {
+                            self.compression_deserializer.deserialize(input)
+                        }),
                     ))
-                    .map(|(public_key, random_bytes, version)| {
-                        // Unwrap safety: we checked above that we took enough bytes
-                        Message::HandshakeInitiation {
-                            public_key,
-                            random_bytes: array_from_slice(random_bytes).unwrap(),
-                            version,
-                        }
-                    }),
+                    .map(
+                        |(public_key, random_bytes, version, compression)| {
+                            // Unwrap safety: we checked above that we took enough bytes
+                            Message::HandshakeInitiation {
+                                public_key,
+                                random_bytes: array_from_slice(random_bytes).unwrap(),
+                                version,
+                                compression,
+                            }
+                        },
+                    ),
                 )
                 .parse(input),
                 MessageTypeId::HandshakeReply => {
@@ -589,6 +606,7 @@ mod tests {
             public_key: keypair.get_public_key(),
             random_bytes,
             version: Version::from_str("TEST.1.10").unwrap(),
+            compression: Some(Compression::Zstd),
         };
         let mut ser = Vec::new();
         message_serializer.serialize(&msg, &mut ser).unwrap();
@@ -601,16 +619,19 @@ mod tests {
                     public_key: pk1,
                     random_bytes: rb1,
                     version: v1,
+                    compression: c1,
                 },
                 Message::HandshakeInitiation {
                     public_key,
                     random_bytes,
                     version,
+                    compression,
                 },
             ) => {
                 assert_eq!(pk1, public_key);
                 assert_eq!(rb1, random_bytes);
                 assert_eq!(v1, version);
+                assert_eq!(c1, compression);
             }
             _ => panic!("unexpected message"),
         }
@@ -24,6 +24,7 @@ use futures::{stream::FuturesUnordered, StreamExt};
 use massa_hash::Hash;
 use massa_logging::massa_trace;
 use massa_models::{
+    address::Address,
     block_header::SecuredHeader,
     block_id::BlockId,
     composite::PubkeySig,
@@ -36,6 +37,7 @@ use massa_network_exports::{
     AskForBlocksInfo, BlockInfoReply, BootstrapPeers, ConnectionClosureReason, ConnectionId,
     NetworkError, NodeCommand, Peer, Peers,
 };
+use massa_wallet::SigningContext;
 use std::{
     collections::{HashMap, HashSet},
     net::IpAddr,
@@ -280,10 +282,21 @@ pub async fn on_node_sign_message_cmd(
         "network_worker.manage_network_command receive NetworkCommand::NodeSignMessage",
         { "mdg": msg }
     );
-    let signature = worker.keypair.sign(&Hash::compute_from(&msg))?;
+    let public_key = worker.keypair.get_public_key();
+    let signature = match worker.node_signer.sign(
+        &Address::from_public_key(&public_key),
+        &Hash::compute_from(&msg),
+        &SigningContext::Message,
+    ) {
+        Ok(signature) => signature,
+        Err(err) => {
+            warn!("network: could not sign message: {}", err);
+            return Ok(());
+        }
+    };
     if response_tx
         .send(PubkeySig {
-            public_key: worker.keypair.get_public_key(),
+            public_key,
             signature,
         })
         .is_err()
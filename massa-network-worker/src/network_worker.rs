@@ -19,9 +19,11 @@ use massa_network_exports::{
     NetworkManagementCommand, NodeCommand, NodeEvent, NodeEventType, ReadHalf, WriteHalf,
 };
 use massa_signature::KeyPair;
+use massa_wallet::Signer;
 use std::{
     collections::{hash_map, HashMap, HashSet},
     net::{IpAddr, SocketAddr},
+    sync::Arc,
 };
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
@@ -33,6 +35,8 @@ pub struct NetworkWorker {
     cfg: NetworkConfig,
     /// Our keypair.
     pub(crate) keypair: KeyPair,
+    /// Signer of the messages signed on behalf of the node.
+    pub(crate) node_signer: Arc<dyn Signer>,
     /// Our node id.
     pub(crate) self_node_id: NodeId,
     /// Listener part of the establisher.
@@ -77,6 +81,8 @@ impl NetworkWorker {
     ///
     /// # Arguments
     /// * `cfg`: Network configuration.
+    /// * `keypair`: Node keypair.
+    /// * `node_signer`: Signer of the messages signed on behalf of the node.
     /// * `listener`: Listener part of the establisher.
     /// * `establisher`: The connection establisher.
     /// * `peer_info_db`: Database with peer information.
@@ -87,6 +93,7 @@ impl NetworkWorker {
     pub fn new(
         cfg: NetworkConfig,
         keypair: KeyPair,
+        node_signer: Arc<dyn Signer>,
         listener: Listener,
         establisher: Establisher,
         peer_info_db: PeerInfoDatabase,
@@ -105,6 +112,7 @@ impl NetworkWorker {
         NetworkWorker {
             cfg,
             self_node_id,
+            node_signer,
             keypair,
             listener,
             establisher,
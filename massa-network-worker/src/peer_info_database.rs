@@ -3,6 +3,7 @@
 use enum_map::EnumMap;
 use itertools::Itertools;
 use massa_logging::massa_trace;
+use massa_metrics::NETWORK_CONNECTIONS;
 use massa_network_exports::settings::PeerTypeConnectionConfig;
 use massa_network_exports::ConnectionCount;
 use massa_network_exports::NetworkConfig;
@@ -862,6 +863,22 @@ impl PeerInfoDatabase {
         Ok(res.into_iter().map(|p| p.ip).collect())
     }
 
+    /// update the metrics of the connections of a peer type
+    fn update_connection_metrics(&self, peer_type: PeerType) {
+        let count = &self.peer_types_connection_count[peer_type];
+        // the `Display` of `PeerType` is its documentation, use its name instead
+        let peer_type = format!("{:?}", peer_type);
+        for (direction, value) in [
+            ("in", count.active_in_connections),
+            ("out", count.active_out_connections),
+            ("out_attempt", count.active_out_connection_attempts),
+        ] {
+            NETWORK_CONNECTIONS
+                .with_label_values(&[&peer_type, direction])
+                .set(value as i64);
+        }
+    }
+
     fn get_peer_type(&self, ip: &IpAddr) -> Option<PeerType> {
         Some(self.peers.get(ip)?.peer_type)
     }
@@ -885,6 +902,7 @@ impl PeerInfoDatabase {
             ));
         }
         self.peer_types_connection_count[peer_type].active_out_connection_attempts += 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -899,6 +917,7 @@ impl PeerInfoDatabase {
             ));
         }
         self.peer_types_connection_count[peer_type].active_out_connection_attempts -= 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -917,6 +936,7 @@ impl PeerInfoDatabase {
             ));
         }
         self.peer_types_connection_count[peer_type].active_out_connections -= 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -925,6 +945,7 @@ impl PeerInfoDatabase {
         peer_type: PeerType,
     ) -> Result<(), NetworkError> {
         self.peer_types_connection_count[peer_type].active_out_connections += 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -943,6 +964,7 @@ impl PeerInfoDatabase {
             ));
         }
         self.peer_types_connection_count[peer_type].active_in_connections -= 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -951,6 +973,7 @@ impl PeerInfoDatabase {
         peer_type: PeerType,
     ) -> Result<(), NetworkError> {
         self.peer_types_connection_count[peer_type].active_in_connections += 1;
+        self.update_connection_metrics(peer_type);
         Ok(())
     }
 
@@ -2,6 +2,7 @@
 
 // To start alone RUST_BACKTRACE=1 cargo test -- --nocapture --test-threads=1
 use super::tools;
+use crate::handshake_worker::HandshakeWorker;
 use crate::messages::{Message, MessageDeserializer};
 use crate::node_worker::NodeWorker;
 use crate::tests::tools::{get_dummy_block_id, get_transaction};
@@ -15,18 +16,21 @@ use enum_map::enum_map;
 use enum_map::EnumMap;
 use massa_hash::Hash;
 use massa_models::config::{
-    ENDORSEMENT_COUNT, MAX_ADVERTISE_LENGTH, MAX_ASK_BLOCKS_PER_MESSAGE,
-    MAX_DATASTORE_VALUE_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE, MAX_FUNCTION_NAME_LENGTH,
-    MAX_MESSAGE_SIZE, MAX_OPERATIONS_PER_BLOCK, MAX_OPERATIONS_PER_MESSAGE,
-    MAX_OPERATION_DATASTORE_ENTRY_COUNT, MAX_OPERATION_DATASTORE_KEY_LENGTH,
-    MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE, THREAD_COUNT,
+    ENDORSEMENT_COUNT, HANDSHAKE_RANDOMNESS_SIZE_BYTES, MAX_ADVERTISE_LENGTH,
+    MAX_ASK_BLOCKS_PER_MESSAGE, MAX_DATASTORE_VALUE_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE,
+    MAX_FUNCTION_NAME_LENGTH, MAX_MESSAGE_SIZE, MAX_OPERATIONS_PER_BLOCK,
+    MAX_OPERATIONS_PER_MESSAGE, MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+    MAX_OPERATION_DATASTORE_KEY_LENGTH, MAX_OPERATION_DATASTORE_VALUE_LENGTH, MAX_PARAMETERS_SIZE,
+    THREAD_COUNT,
 };
 use massa_models::{
     block_id::BlockId,
+    compression::Compression,
     endorsement::{Endorsement, EndorsementSerializer},
     node::NodeId,
     secure_share::SecureShareContent,
     slot::Slot,
+    version::Version,
 };
 use massa_network_exports::{settings::PeerTypeConnectionConfig, NodeCommand, NodeEvent};
 use massa_network_exports::{
@@ -37,6 +41,7 @@ use massa_signature::KeyPair;
 use massa_time::MassaTime;
 use serial_test::serial;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     time::{Duration, Instant},
@@ -141,6 +146,104 @@ async fn test_node_worker_shutdown() {
     node_fn_handle.await.unwrap().unwrap();
 }
 
+/// Test that frames are compressed only with the peers advertising compression in their handshake,
+/// and that peers unaware of compression, which advertise nothing, still complete the handshake.
+#[tokio::test]
+#[serial]
+async fn test_handshake_compression_negotiation() {
+    for peer_compression in [Some(Compression::Zstd), None] {
+        let (duplex_controller, duplex_mock) = tokio::io::duplex(MAX_MESSAGE_SIZE as usize);
+        let (controller_read, controller_write) = tokio::io::split(duplex_controller);
+        let (mock_read, mock_write) = tokio::io::split(duplex_mock);
+        let version = Version::from_str("TEST.1.10").unwrap();
+
+        let keypair = KeyPair::generate();
+        let handshake = HandshakeWorker::spawn(
+            controller_read,
+            controller_write,
+            NodeId::new(keypair.get_public_key()),
+            keypair,
+            1000.into(),
+            version,
+            ConnectionId(0),
+            f64::INFINITY,
+            f64::INFINITY,
+            0,
+        );
+
+        // the mock peer performs the handshake by hand
+        let mut reader = ReadBinder::new(
+            mock_read,
+            f64::INFINITY,
+            MAX_MESSAGE_SIZE,
+            MessageDeserializer::new(
+                THREAD_COUNT,
+                ENDORSEMENT_COUNT,
+                MAX_ADVERTISE_LENGTH,
+                MAX_ASK_BLOCKS_PER_MESSAGE,
+                MAX_OPERATIONS_PER_BLOCK,
+                MAX_OPERATIONS_PER_MESSAGE,
+                MAX_ENDORSEMENTS_PER_MESSAGE,
+                MAX_DATASTORE_VALUE_LENGTH,
+                MAX_FUNCTION_NAME_LENGTH,
+                MAX_PARAMETERS_SIZE,
+                MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+                MAX_OPERATION_DATASTORE_KEY_LENGTH,
+                MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+                Some(0),
+            ),
+        );
+        let mut writer = WriteBinder::new(mock_write, f64::INFINITY, MAX_MESSAGE_SIZE);
+        let mock_keypair = KeyPair::generate();
+        writer
+            .send(&Message::HandshakeInitiation {
+                public_key: mock_keypair.get_public_key(),
+                random_bytes: [0u8; HANDSHAKE_RANDOMNESS_SIZE_BYTES],
+                version,
+                compression: peer_compression,
+            })
+            .await
+            .unwrap();
+        let Some((_, Message::HandshakeInitiation { random_bytes, compression, .. })) = reader.next().await.unwrap() else {
+            panic!("expected a handshake initiation");
+        };
+        assert_eq!(compression, Some(Compression::Zstd));
+        let signature = mock_keypair
+            .sign(&Hash::compute_from(&random_bytes))
+            .unwrap();
+        writer
+            .send(&Message::HandshakeReply { signature })
+            .await
+            .unwrap();
+        let Some((_, Message::HandshakeReply { .. })) = reader.next().await.unwrap() else {
+            panic!("expected a handshake reply");
+        };
+        let (_, mut controller_reader, mut controller_writer) =
+            handshake.await.unwrap().1.expect("handshake failed");
+
+        // a frame compressed, or not, against the negotiation cannot be read by the other side
+        reader.set_compression(peer_compression);
+        writer.set_compression(peer_compression);
+        let peers = vec![IpAddr::V4(Ipv4Addr::new(169, 202, 0, 11)); 1000];
+        controller_writer
+            .send(&Message::PeerList(peers.clone()))
+            .await
+            .unwrap();
+        match reader.next().await.unwrap() {
+            Some((_, Message::PeerList(list))) => assert_eq!(list, peers),
+            _ => panic!("expected a peer list"),
+        }
+        writer
+            .send(&Message::PeerList(peers.clone()))
+            .await
+            .unwrap();
+        match controller_reader.next().await.unwrap() {
+            Some((_, Message::PeerList(list))) => assert_eq!(list, peers),
+            _ => panic!("expected a peer list"),
+        }
+    }
+}
+
 /// Test that a node worker can send an operations message.
 #[tokio::test]
 #[serial]
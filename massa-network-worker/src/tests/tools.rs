@@ -375,6 +375,7 @@ pub async fn network_test<F, V>(
             establisher,
             None,
             Version::from_str("TEST.1.10").unwrap(),
+            None,
         )
         .await
         .expect("could not start network controller");
@@ -0,0 +1,208 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Logging subsystem of the node
+//!
+//! Logs are written to the standard output and optionally to a rotated file, as text or JSON.
+//! Only the logs of the massa crates are kept, at `level`, unless `directives` set other levels for some modules or crates.
+//! The tracing spans (slot executions, block processing, bootstrap sessions) can be exported to an OTLP collector.
+
+use crate::settings::{LogFormat, LogRotation, LoggingSettings};
+use opentelemetry::sdk::{trace, Resource};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use std::path::Path;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Layered, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+type BoxedLayer = Box<dyn Layer<Layered<EnvFilter, Registry>> + Send + Sync>;
+
+/// Keeps the logging subsystem running, the pending logs and spans are flushed when it is dropped
+pub(crate) struct LoggingGuard {
+    _file_guard: Option<WorkerGuard>,
+    otlp: bool,
+}
+
+impl Drop for LoggingGuard {
+    fn drop(&mut self) {
+        if self.otlp {
+            opentelemetry::global::shutdown_tracer_provider();
+        }
+    }
+}
+
+/// Set up the logging subsystem of the node
+pub(crate) fn init_logging(settings: &LoggingSettings) -> anyhow::Result<LoggingGuard> {
+    let filter = log_filter(settings)?;
+
+    let mut layers: Vec<BoxedLayer> = vec![fmt_layer(settings.format, std::io::stdout, true)];
+
+    let file_guard = match &settings.file_path {
+        Some(path) => {
+            let rotation = match settings.file_rotation {
+                LogRotation::Minutely => Rotation::MINUTELY,
+                LogRotation::Hourly => Rotation::HOURLY,
+                LogRotation::Daily => Rotation::DAILY,
+                LogRotation::Never => Rotation::NEVER,
+            };
+            let directory = path.parent().unwrap_or_else(|| Path::new("."));
+            let file_name = path
+                .file_name()
+                .ok_or_else(|| anyhow::anyhow!("invalid log file path {}", path.display()))?;
+            let file_name = file_name
+                .to_str()
+                .ok_or_else(|| anyhow::anyhow!("invalid log file path {}", path.display()))?;
+            let mut appender = RollingFileAppender::builder()
+                .rotation(rotation)
+                .filename_prefix(file_name);
+            if let Some(max_log_files) = settings.max_log_files {
+                appender = appender.max_log_files(max_log_files);
+            }
+            let (writer, guard) = tracing_appender::non_blocking(appender.build(directory)?);
+            layers.push(fmt_layer(settings.format, writer, false));
+            Some(guard)
+        }
+        None => None,
+    };
+
+    if let Some(endpoint) = &settings.otlp_endpoint {
+        let exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(endpoint);
+        let resource = Resource::new(vec![KeyValue::new("service.name", "massa-node")]);
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(exporter)
+            .with_trace_config(trace::config().with_resource(resource))
+            .install_batch(opentelemetry::runtime::Tokio)?;
+        layers.push(tracing_opentelemetry::layer().with_tracer(tracer).boxed());
+    }
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(layers)
+        .try_init()?;
+
+    Ok(LoggingGuard {
+        _file_guard: file_guard,
+        otlp: settings.otlp_endpoint.is_some(),
+    })
+}
+
+/// filter keeping the logs of the massa crates at `level`, overridden by `directives`
+///
+/// Directive targets are matched by prefix: `massa` covers every massa crate, as the former `starts_with("massa")` filter did,
+/// and the most specific directive matching a target applies, e.g. `massa_execution_worker=debug` over `massa=info`.
+/// A directive without target would set the level of every crate, such directives are refused
+/// so that the logs of the other crates are only written when a directive enables them by name.
+fn log_filter(settings: &LoggingSettings) -> anyhow::Result<EnvFilter> {
+    let level = match settings.level {
+        4 => LevelFilter::TRACE,
+        3 => LevelFilter::DEBUG,
+        2 => LevelFilter::INFO,
+        1 => LevelFilter::WARN,
+        _ => LevelFilter::ERROR,
+    };
+    for directive in &settings.directives {
+        // directives are of the form `target[span{field=value}]=level`, a lone level has no target
+        let target = directive
+            .split(['[', '='])
+            .next()
+            .unwrap_or_default()
+            .trim();
+        let bare_level = !directive.contains('=') && target.parse::<LevelFilter>().is_ok();
+        if target.is_empty() || bare_level {
+            anyhow::bail!(
+                "logging directive `{}` has no target, set `level` for the massa crates or use `<crate>=<level>`",
+                directive
+            );
+        }
+    }
+    let directives = std::iter::once(format!("massa={}", level))
+        .chain(settings.directives.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(",");
+    Ok(EnvFilter::builder().parse(directives)?)
+}
+
+/// layer formatting the logs to `writer`
+fn fmt_layer<W>(format: LogFormat, writer: W, ansi: bool) -> BoxedLayer
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(ansi);
+    match format {
+        LogFormat::Text => layer.boxed(),
+        // the fields of the spans allow correlating the logs of a slot, block or bootstrap session
+        LogFormat::Json => layer
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::Level;
+
+    fn settings(level: usize, directives: &[&str]) -> LoggingSettings {
+        LoggingSettings {
+            level,
+            format: LogFormat::Text,
+            directives: directives.iter().map(|d| d.to_string()).collect(),
+            file_path: None,
+            file_rotation: LogRotation::Never,
+            max_log_files: None,
+            otlp_endpoint: None,
+        }
+    }
+
+    /// run `f` with the filter built from `settings` as the only layer
+    fn with_filter(settings: LoggingSettings, f: impl FnOnce()) {
+        let subscriber = tracing_subscriber::registry().with(log_filter(&settings).unwrap());
+        tracing::subscriber::with_default(subscriber, f);
+    }
+
+    #[test]
+    fn test_log_filter() {
+        // every massa crate is logged at `level`, the other crates are not logged
+        with_filter(settings(2, &[]), || {
+            assert!(tracing::enabled!(target: "massa_execution_worker::execution", Level::INFO));
+            assert!(!tracing::enabled!(target: "massa_execution_worker::execution", Level::DEBUG));
+            assert!(tracing::enabled!(target: "massa_node", Level::INFO));
+            assert!(!tracing::enabled!(target: "hyper::proto", Level::ERROR));
+        });
+
+        // directives override `level` for their modules, and enable other crates by name
+        let directives = [
+            "massa_execution_worker=debug",
+            "massa_bootstrap=error",
+            "hyper=warn",
+        ];
+        with_filter(settings(2, &directives), || {
+            assert!(tracing::enabled!(target: "massa_execution_worker::execution", Level::DEBUG));
+            assert!(!tracing::enabled!(target: "massa_execution_worker::execution", Level::TRACE));
+            assert!(tracing::enabled!(target: "massa_bootstrap::client", Level::ERROR));
+            assert!(!tracing::enabled!(target: "massa_bootstrap::client", Level::WARN));
+            assert!(tracing::enabled!(target: "massa_pool_worker", Level::INFO));
+            assert!(!tracing::enabled!(target: "massa_pool_worker", Level::DEBUG));
+            assert!(tracing::enabled!(target: "hyper::proto", Level::WARN));
+            assert!(!tracing::enabled!(target: "hyper::proto", Level::INFO));
+            assert!(!tracing::enabled!(target: "tokio::runtime", Level::ERROR));
+        });
+
+        // directives without target would enable every crate
+        assert!(log_filter(&settings(2, &["debug"])).is_err());
+        assert!(log_filter(&settings(2, &["[bootstrap]=debug"])).is_err());
+        // a target without level enables all its logs
+        assert!(log_filter(&settings(2, &["massa_network_worker"])).is_ok());
+    }
+}
@@ -5,12 +5,14 @@
 #![warn(unused_crate_dependencies)]
 extern crate massa_logging;
 
+use crate::logging::init_logging;
 use crate::settings::SETTINGS;
 
 use crossbeam_channel::{Receiver, TryRecvError};
 use dialoguer::Password;
 use massa_api::{ApiServer, ApiV2, Private, Public, RpcServer, StopHandle, API};
 use massa_api_exports::config::APIConfig;
+use massa_archive::{ArchiveConfig, ArchiveDB};
 use massa_async_pool::AsyncPoolConfig;
 use massa_bootstrap::{
     get_state, start_bootstrap_server, BootstrapConfig, BootstrapManager, DefaultConnector,
@@ -19,29 +21,34 @@ use massa_bootstrap::{
 use massa_consensus_exports::events::ConsensusEvent;
 use massa_consensus_exports::{ConsensusChannels, ConsensusConfig, ConsensusManager};
 use massa_consensus_worker::start_consensus_worker;
-use massa_executed_ops::ExecutedOpsConfig;
-use massa_execution_exports::{ExecutionConfig, ExecutionManager, GasCosts, StorageCostsConstants};
+use massa_executed_ops::{ExecutedDenunciationsConfig, ExecutedOpsConfig};
+use massa_execution_exports::{
+    ExecutionChannels, ExecutionConfig, ExecutionManager, GasCosts, StorageCostsConstants,
+};
 use massa_execution_worker::start_execution_worker;
 use massa_factory_exports::{FactoryChannels, FactoryConfig, FactoryManager};
 use massa_factory_worker::start_factory;
 use massa_final_state::{FinalState, FinalStateConfig};
 use massa_grpc::config::GrpcConfig;
-use massa_grpc::server::MassaGrpc;
+use massa_grpc::server::{MassaGrpc, MassaPrivateGrpc};
 use massa_ledger_exports::LedgerConfig;
 use massa_ledger_worker::FinalLedger;
 use massa_logging::massa_trace;
+use massa_metrics::{start_metrics_server, MetricsConfig};
 use massa_models::address::Address;
 use massa_models::config::constants::{
     ASYNC_POOL_BOOTSTRAP_PART_SIZE, BLOCK_REWARD, BOOTSTRAP_RANDOMNESS_SIZE_BYTES, CHANNEL_SIZE,
     DEFERRED_CREDITS_BOOTSTRAP_PART_SIZE, DELTA_F0, ENDORSEMENT_COUNT, END_TIMESTAMP,
-    EXECUTED_OPS_BOOTSTRAP_PART_SIZE, GENESIS_KEY, GENESIS_TIMESTAMP, INITIAL_DRAW_SEED,
-    LEDGER_COST_PER_BYTE, LEDGER_ENTRY_BASE_SIZE, LEDGER_ENTRY_DATASTORE_BASE_SIZE,
-    LEDGER_PART_SIZE_MESSAGE_BYTES, MAX_ADVERTISE_LENGTH, MAX_ASK_BLOCKS_PER_MESSAGE,
-    MAX_ASYNC_GAS, MAX_ASYNC_MESSAGE_DATA, MAX_ASYNC_POOL_LENGTH, MAX_BLOCK_SIZE,
-    MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_BOOTSTRAP_BLOCKS, MAX_BOOTSTRAP_ERROR_LENGTH,
-    MAX_BOOTSTRAP_FINAL_STATE_PARTS_SIZE, MAX_BOOTSTRAP_MESSAGE_SIZE, MAX_BYTECODE_LENGTH,
-    MAX_CONSENSUS_BLOCKS_IDS, MAX_DATASTORE_ENTRY_COUNT, MAX_DATASTORE_KEY_LENGTH,
-    MAX_DATASTORE_VALUE_LENGTH, MAX_DEFERRED_CREDITS_LENGTH, MAX_ENDORSEMENTS_PER_MESSAGE,
+    EXECUTED_DENUNCIATIONS_BOOTSTRAP_PART_SIZE, EXECUTED_OPS_BOOTSTRAP_PART_SIZE, GENESIS_KEY,
+    GENESIS_TIMESTAMP, INITIAL_DRAW_SEED, LEDGER_COST_PER_BYTE, LEDGER_ENTRY_BASE_SIZE,
+    LEDGER_ENTRY_DATASTORE_BASE_SIZE, LEDGER_PART_SIZE_MESSAGE_BYTES, MAX_ADVERTISE_LENGTH,
+    MAX_ASK_BLOCKS_PER_MESSAGE, MAX_ASYNC_GAS, MAX_ASYNC_MESSAGE_DATA, MAX_ASYNC_POOL_LENGTH,
+    MAX_BLOCK_SIZE, MAX_BOOTSTRAP_ASYNC_POOL_CHANGES, MAX_BOOTSTRAP_BLOCKS,
+    MAX_BOOTSTRAP_ERROR_LENGTH, MAX_BOOTSTRAP_FINAL_STATE_PARTS_SIZE, MAX_BOOTSTRAP_MESSAGE_SIZE,
+    MAX_BYTECODE_LENGTH, MAX_CONSENSUS_BLOCKS_IDS, MAX_DATASTORE_ENTRY_COUNT,
+    MAX_DATASTORE_KEY_LENGTH, MAX_DATASTORE_VALUE_LENGTH, MAX_DEFERRED_CREDITS_LENGTH,
+    MAX_DENUNCIATIONS_PER_BLOCK_HEADER, MAX_DENUNCIATION_CHANGES_LENGTH,
+    MAX_ENDORSEMENTS_PER_MESSAGE, MAX_EXECUTED_DENUNCIATIONS_LENGTH,
     MAX_EXECUTED_OPS_CHANGES_LENGTH, MAX_EXECUTED_OPS_LENGTH, MAX_FUNCTION_NAME_LENGTH,
     MAX_GAS_PER_BLOCK, MAX_LEDGER_CHANGES_COUNT, MAX_MESSAGE_SIZE, MAX_OPERATIONS_PER_BLOCK,
     MAX_OPERATION_DATASTORE_ENTRY_COUNT, MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -50,14 +57,15 @@ use massa_models::config::constants::{
     NETWORK_CONTROLLER_CHANNEL_SIZE, NETWORK_EVENT_CHANNEL_SIZE, NETWORK_NODE_COMMAND_CHANNEL_SIZE,
     NETWORK_NODE_EVENT_CHANNEL_SIZE, OPERATION_VALIDITY_PERIODS, PERIODS_PER_CYCLE,
     POOL_CONTROLLER_CHANNEL_SIZE, POS_MISS_RATE_DEACTIVATION_THRESHOLD, POS_SAVED_CYCLES,
-    PROTOCOL_CONTROLLER_CHANNEL_SIZE, PROTOCOL_EVENT_CHANNEL_SIZE, ROLL_PRICE, T0, THREAD_COUNT,
-    VERSION,
+    PROTOCOL_CONTROLLER_CHANNEL_SIZE, PROTOCOL_EVENT_CHANNEL_SIZE,
+    ROLL_COUNT_TO_SLASH_ON_DENUNCIATION, ROLL_PRICE, T0, THREAD_COUNT, VERSION,
 };
 use massa_models::config::{
     CONSENSUS_BOOTSTRAP_PART_SIZE, DENUNCIATION_EXPIRE_PERIODS, DENUNCIATION_ITEMS_MAX_CYCLE_DELTA,
     MAX_OPERATIONS_PER_MESSAGE,
 };
 use massa_models::denunciation::DenunciationPrecursor;
+use massa_models::slot::Slot;
 use massa_network_exports::{Establisher, NetworkConfig, NetworkManager};
 use massa_network_worker::start_network_controller;
 use massa_pool_exports::{PoolChannels, PoolConfig, PoolManager};
@@ -72,8 +80,11 @@ use massa_protocol_worker::start_protocol_controller;
 use massa_storage::Storage;
 use massa_time::MassaTime;
 use massa_versioning_worker::versioning::{MipStatsConfig, MipStore};
-use massa_wallet::Wallet;
-use parking_lot::RwLock;
+use massa_wallet::{
+    Argon2Params, LocalSigner, RemoteSigner, Signer, SlashingProtection, SlashingProtectionHistory,
+    Wallet,
+};
+use parking_lot::{Mutex, RwLock};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread::sleep;
@@ -84,13 +95,15 @@ use tokio::net::TcpStream;
 use tokio::signal;
 use tokio::sync::{broadcast, mpsc};
 use tracing::{error, info, warn};
-use tracing_subscriber::filter::{filter_fn, LevelFilter};
 
+mod logging;
 mod settings;
 
 async fn launch(
     args: &Args,
-    node_wallet: Arc<RwLock<Wallet>>,
+    node_wallet: Option<Arc<RwLock<Wallet>>>,
+    node_signer: Arc<dyn Signer>,
+    slashing_protection: Arc<Mutex<SlashingProtection>>,
 ) -> (
     Receiver<ConsensusEvent>,
     Option<BootstrapManager<TcpStream>>,
@@ -106,6 +119,7 @@ async fn launch(
     StopHandle,
     StopHandle,
     Option<massa_grpc::server::StopHandle>,
+    Option<(massa_grpc::server::StopHandle, mpsc::Receiver<()>)>,
 ) {
     info!("Node version : {}", *VERSION);
     let now = MassaTime::now().expect("could not get now time");
@@ -190,11 +204,16 @@ async fn launch(
         thread_count: THREAD_COUNT,
         bootstrap_part_size: EXECUTED_OPS_BOOTSTRAP_PART_SIZE,
     };
+    let executed_denunciations_config = ExecutedDenunciationsConfig {
+        denunciation_expire_periods: DENUNCIATION_EXPIRE_PERIODS,
+        bootstrap_part_size: EXECUTED_DENUNCIATIONS_BOOTSTRAP_PART_SIZE,
+    };
     let final_state_config = FinalStateConfig {
         ledger_config: ledger_config.clone(),
         async_pool_config,
         pos_config,
         executed_ops_config,
+        executed_denunciations_config,
         final_history_length: SETTINGS.ledger.final_history_length,
         thread_count: THREAD_COUNT,
         periods_per_cycle: PERIODS_PER_CYCLE,
@@ -204,8 +223,17 @@ async fn launch(
 
     // Remove current disk ledger if there is one and we don't want to restart from snapshot
     // NOTE: this is temporary, since we cannot currently handle bootstrap from remaining ledger
-    if args.keep_ledger || args.restart_from_snapshot_at_period.is_some() {
+    // When a snapshot file is imported, the whole ledger is restored from it
+    // An interrupted bootstrap resumes on top of the ledger it already received
+    if args.import_snapshot.is_none()
+        && (args.keep_ledger || args.restart_from_snapshot_at_period.is_some())
+    {
         info!("Loading old ledger for next episode");
+    } else if args.import_snapshot.is_none()
+        && args.restart_from_snapshot_at_period.is_none()
+        && SETTINGS.bootstrap.bootstrap_checkpoint_path.is_file()
+    {
+        info!("Keeping the partially bootstrapped ledger to resume the bootstrap");
     } else if SETTINGS.ledger.disk_ledger_path.exists() {
         std::fs::remove_dir_all(SETTINGS.ledger.disk_ledger_path.clone())
             .expect("disk ledger delete failed");
@@ -229,23 +257,26 @@ async fn launch(
     .expect("could not start selector worker");
 
     // Create final state, either from a snapshot, or from scratch
-    let final_state = Arc::new(parking_lot::RwLock::new(
-        match args.restart_from_snapshot_at_period {
-            Some(last_start_period) => FinalState::new_derived_from_snapshot(
-                final_state_config,
-                Box::new(ledger),
-                selector_controller.clone(),
-                last_start_period,
-            )
-            .expect("could not init final state"),
-            None => FinalState::new(
+    let (final_state, snapshot_mip_store) = match args.restart_from_snapshot_at_period {
+        Some(last_start_period) => FinalState::new_derived_from_snapshot(
+            final_state_config,
+            Box::new(ledger),
+            selector_controller.clone(),
+            last_start_period,
+            args.import_snapshot.as_deref(),
+        )
+        .expect("could not init final state"),
+        None => (
+            FinalState::new(
                 final_state_config,
                 Box::new(ledger),
                 selector_controller.clone(),
             )
             .expect("could not init final state"),
-        },
-    ));
+            None,
+        ),
+    };
+    let final_state = Arc::new(parking_lot::RwLock::new(final_state));
 
     // interrupt signal listener
     let stop_signal = signal::ctrl_c();
@@ -272,6 +303,9 @@ async fn launch(
         per_ip_min_interval: SETTINGS.bootstrap.per_ip_min_interval,
         ip_list_max_size: SETTINGS.bootstrap.ip_list_max_size,
         max_bytes_read_write: SETTINGS.bootstrap.max_bytes_read_write,
+        bootstrap_checkpoint_path: SETTINGS.bootstrap.bootstrap_checkpoint_path.clone(),
+        bootstrap_checkpoint_interval: SETTINGS.bootstrap.bootstrap_checkpoint_interval,
+        max_parallel_bootstrap_servers: SETTINGS.bootstrap.max_parallel_bootstrap_servers,
         max_bootstrap_message_size: MAX_BOOTSTRAP_MESSAGE_SIZE,
         max_datastore_key_length: MAX_DATASTORE_KEY_LENGTH,
         randomness_size_bytes: BOOTSTRAP_RANDOMNESS_SIZE_BYTES,
@@ -300,6 +334,9 @@ async fn launch(
         max_credits_length: MAX_DEFERRED_CREDITS_LENGTH,
         max_executed_ops_length: MAX_EXECUTED_OPS_LENGTH,
         max_ops_changes_length: MAX_EXECUTED_OPS_CHANGES_LENGTH,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+        max_executed_denunciations_length: MAX_EXECUTED_DENUNCIATIONS_LENGTH,
+        max_denunciation_changes_length: MAX_DENUNCIATION_CHANGES_LENGTH,
         consensus_bootstrap_part_size: CONSENSUS_BOOTSTRAP_PART_SIZE,
         max_consensus_block_ids: MAX_CONSENSUS_BLOCKS_IDS,
         mip_store_stats_block_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
@@ -377,6 +414,12 @@ async fn launch(
             Establisher::new(),
             bootstrap_state.peers,
             *VERSION,
+            // with an external signer, the messages of the node are also signed by it, on behalf of the node address
+            SETTINGS
+                .factory
+                .remote_signer
+                .is_some()
+                .then(|| node_signer.clone()),
         )
         .await
         .expect("could not start network controller");
@@ -415,6 +458,13 @@ async fn launch(
             .update_with(&bootstrap_mip_store)
             .expect("Cannot update MIP store with bootstrap mip store");
     }
+    if let Some(snapshot_mip_store) = snapshot_mip_store {
+        mip_store
+            .0
+            .write()
+            .update_with(&snapshot_mip_store)
+            .expect("Cannot update MIP store with snapshot mip store");
+    }
 
     // launch execution module
     let execution_config = ExecutionConfig {
@@ -430,6 +480,8 @@ async fn launch(
         block_reward: BLOCK_REWARD,
         endorsement_count: ENDORSEMENT_COUNT as u64,
         operation_validity_period: OPERATION_VALIDITY_PERIODS,
+        roll_count_to_slash_on_denunciation: ROLL_COUNT_TO_SLASH_ON_DENUNCIATION,
+        denunciation_expire_periods: DENUNCIATION_EXPIRE_PERIODS,
         periods_per_cycle: PERIODS_PER_CYCLE,
         stats_time_window_duration: SETTINGS.execution.stats_time_window_duration,
         max_miss_ratio: *POS_MISS_RATE_DEACTIVATION_THRESHOLD,
@@ -438,6 +490,8 @@ async fn launch(
         max_datastore_value_size: MAX_DATASTORE_VALUE_LENGTH,
         storage_costs_constants,
         max_read_only_gas: SETTINGS.execution.max_read_only_gas,
+        estimated_fee_per_gas: SETTINGS.execution.estimated_fee_per_gas,
+        estimated_fee_per_byte: SETTINGS.execution.estimated_fee_per_byte,
         initial_vesting_path: SETTINGS.execution.initial_vesting_path.clone(),
         gas_costs: GasCosts::new(
             SETTINGS.execution.abi_gas_costs_file.clone(),
@@ -449,12 +503,27 @@ async fn launch(
         lru_cache_size: SETTINGS.execution.lru_cache_size,
         hd_cache_size: SETTINGS.execution.hd_cache_size,
         snip_amount: SETTINGS.execution.snip_amount,
+        final_events_db_enabled: SETTINGS.execution.final_events_db_enabled,
+        final_events_db_path: SETTINGS.execution.final_events_db_path.clone(),
+        final_events_db_max_slots: SETTINGS.execution.final_events_db_max_slots,
+        final_events_db_max_bytes: SETTINGS.execution.final_events_db_max_bytes,
+        snapshot_export_slot: args.export_snapshot_at_slot,
+        snapshot_export_path: args.export_snapshot_path.clone(),
+        broadcast_enabled: SETTINGS.api.enable_broadcast,
+        broadcast_sc_events_capacity: SETTINGS.execution.broadcast_sc_events_capacity,
+        operation_traces_enabled: SETTINGS.execution.operation_traces_enabled,
+        max_final_operation_traces: SETTINGS.execution.max_final_operation_traces,
+        max_final_operation_traces_size: SETTINGS.execution.max_final_operation_traces_size,
+    };
+    let execution_channels = ExecutionChannels {
+        sc_event_sender: broadcast::channel(execution_config.broadcast_sc_events_capacity).0,
     };
     let (execution_manager, execution_controller) = start_execution_worker(
         execution_config,
         final_state.clone(),
         selector_controller.clone(),
         mip_store.clone(),
+        execution_channels.clone(),
     );
 
     // launch pool controller
@@ -475,6 +544,7 @@ async fn launch(
         t0: T0,
         periods_per_cycle: PERIODS_PER_CYCLE,
         denunciation_expire_periods: DENUNCIATION_EXPIRE_PERIODS,
+        max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
     };
 
     let pool_channels = PoolChannels {
@@ -523,6 +593,25 @@ async fn launch(
         last_start_period: final_state.read().last_start_period,
     };
 
+    // open the persistent archive of final blocks, if archive mode is enabled
+    let archive = if SETTINGS.archive.enabled {
+        Some(Arc::new(ArchiveDB::new(ArchiveConfig {
+            path: SETTINGS.archive.path.clone(),
+            thread_count: THREAD_COUNT,
+            max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
+            endorsement_count: ENDORSEMENT_COUNT,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
+            max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
+            max_function_name_length: MAX_FUNCTION_NAME_LENGTH,
+            max_parameters_size: MAX_PARAMETERS_SIZE,
+            max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
+            max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
+            max_op_datastore_value_length: MAX_OPERATION_DATASTORE_VALUE_LENGTH,
+        })))
+    } else {
+        None
+    };
+
     let (consensus_event_sender, consensus_event_receiver) =
         crossbeam_channel::bounded(CHANNEL_SIZE);
     let (denunciation_factory_sender, denunciation_factory_receiver) =
@@ -539,6 +628,7 @@ async fn launch(
         filled_block_sender: broadcast::channel(consensus_config.broadcast_filled_blocks_capacity)
             .0,
         denunciation_factory_sender,
+        archive: archive.clone(),
     };
 
     let (consensus_controller, consensus_manager) = start_consensus_worker(
@@ -625,8 +715,10 @@ async fn launch(
     };
     let factory_manager = start_factory(
         factory_config,
-        node_wallet.clone(),
+        node_signer,
+        slashing_protection,
         factory_channels,
+        mip_store.clone(),
         denunciation_factory_receiver,
         denunciation_factory_rx,
     );
@@ -667,6 +759,11 @@ async fn launch(
         ping_interval: SETTINGS.api.ping_interval,
         enable_http: SETTINGS.api.enable_http,
         enable_ws: SETTINGS.api.enable_ws,
+        tls_cert_path: SETTINGS.api.tls_cert_path.clone(),
+        tls_key_path: SETTINGS.api.tls_key_path.clone(),
+        tls_client_ca_path: SETTINGS.api.tls_client_ca_path.clone(),
+        auth_tokens_path: SETTINGS.api.auth_tokens_path.clone(),
+        rate_limit: SETTINGS.api.rate_limit.clone(),
         max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
         max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
         max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -684,6 +781,7 @@ async fn launch(
         consensus_controller.clone(),
         consensus_channels.clone(),
         execution_controller.clone(),
+        execution_channels.clone(),
         pool_channels.clone(),
         api_config.clone(),
         *VERSION,
@@ -703,13 +801,19 @@ async fn launch(
     api_config.enable_ws = false;
 
     // Whether to spawn gRPC API
-    let grpc_handle = if SETTINGS.grpc.enabled {
+    let (grpc_handle, grpc_private) = if SETTINGS.grpc.enabled {
         let grpc_config = GrpcConfig {
             enabled: SETTINGS.grpc.enabled,
             accept_http1: SETTINGS.grpc.accept_http1,
             enable_cors: SETTINGS.grpc.enable_cors,
             enable_reflection: SETTINGS.grpc.enable_reflection,
             bind: SETTINGS.grpc.bind,
+            bind_private: SETTINGS.grpc.bind_private,
+            tls_cert_path: SETTINGS.grpc.tls_cert_path.clone(),
+            tls_key_path: SETTINGS.grpc.tls_key_path.clone(),
+            tls_client_ca_path: SETTINGS.grpc.tls_client_ca_path.clone(),
+            auth_tokens_path: SETTINGS.grpc.auth_tokens_path.clone(),
+            rate_limit: SETTINGS.grpc.rate_limit.clone(),
             accept_compressed: SETTINGS.grpc.accept_compressed.clone(),
             send_compressed: SETTINGS.grpc.send_compressed.clone(),
             max_decoding_message_size: SETTINGS.grpc.max_decoding_message_size,
@@ -735,6 +839,7 @@ async fn launch(
             max_operations_per_block: MAX_OPERATIONS_PER_BLOCK,
             endorsement_count: ENDORSEMENT_COUNT,
             max_endorsements_per_message: MAX_ENDORSEMENTS_PER_MESSAGE,
+            max_denunciations_per_block_header: MAX_DENUNCIATIONS_PER_BLOCK_HEADER,
             max_datastore_value_length: MAX_DATASTORE_VALUE_LENGTH,
             max_op_datastore_entry_count: MAX_OPERATION_DATASTORE_ENTRY_COUNT,
             max_op_datastore_key_length: MAX_OPERATION_DATASTORE_KEY_LENGTH,
@@ -747,23 +852,31 @@ async fn launch(
             max_channel_size: SETTINGS.grpc.max_channel_size,
             draw_lookahead_period_count: SETTINGS.grpc.draw_lookahead_period_count,
             last_start_period: final_state.read().last_start_period,
+            max_arguments: SETTINGS.grpc.max_arguments,
+            periods_per_cycle: PERIODS_PER_CYCLE,
+            bootstrap_whitelist_path: SETTINGS.bootstrap.bootstrap_whitelist_path.clone(),
+            bootstrap_blacklist_path: SETTINGS.bootstrap.bootstrap_blacklist_path.clone(),
         };
 
         let grpc_api = MassaGrpc {
             consensus_controller: consensus_controller.clone(),
             consensus_channels: consensus_channels.clone(),
             execution_controller: execution_controller.clone(),
+            execution_channels,
             pool_channels,
             pool_command_sender: pool_controller.clone(),
             protocol_command_sender: ProtocolCommandSender(protocol_command_sender.clone()),
             selector_controller: selector_controller.clone(),
+            network_command_sender: network_command_sender.clone(),
             storage: shared_storage.clone(),
             grpc_config: grpc_config.clone(),
             version: *VERSION,
+            node_id,
+            node_ip: SETTINGS.network.routable_ip,
         };
 
         // HACK maybe should remove timeout later
-        if let Ok(result) =
+        let grpc_handle = if let Ok(result) =
             tokio::time::timeout(Duration::from_secs(3), grpc_api.serve(&grpc_config)).await
         {
             match result {
@@ -779,9 +892,39 @@ async fn launch(
         } else {
             error!("Timeout on start grpc API");
             None
-        }
+        };
+
+        let (grpc_private_api, grpc_private_stop_rx) = MassaPrivateGrpc::new(
+            network_command_sender.clone(),
+            node_wallet.clone(),
+            grpc_config.clone(),
+        );
+
+        // the stop receiver is only kept if the private gRPC API is running
+        let grpc_private = if let Ok(result) =
+            tokio::time::timeout(Duration::from_secs(3), grpc_private_api.serve(&grpc_config)).await
+        {
+            match result {
+                Ok(stop) => {
+                    info!(
+                        "API | PRIVATE gRPC | listening on: {}",
+                        grpc_config.bind_private
+                    );
+                    Some((stop, grpc_private_stop_rx))
+                }
+                Err(e) => {
+                    error!("{}", e);
+                    None
+                }
+            }
+        } else {
+            error!("Timeout on start private grpc API");
+            None
+        };
+
+        (grpc_handle, grpc_private)
     } else {
-        None
+        (None, None)
     };
 
     // spawn private API
@@ -813,6 +956,7 @@ async fn launch(
         network_command_sender.clone(),
         node_id,
         shared_storage.clone(),
+        archive,
     );
     let api_public_handle = api_public
         .serve(&SETTINGS.api.bind_public, &api_config)
@@ -867,6 +1011,7 @@ async fn launch(
         api_public_handle,
         api_handle,
         grpc_handle,
+        grpc_private,
     )
 }
 
@@ -897,6 +1042,7 @@ async fn stop(
     api_public_handle: StopHandle,
     api_handle: StopHandle,
     grpc_handle: Option<massa_grpc::server::StopHandle>,
+    grpc_private_handle: Option<massa_grpc::server::StopHandle>,
 ) {
     // stop bootstrap
     if let Some(bootstrap_manager) = bootstrap_manager {
@@ -913,6 +1059,11 @@ async fn stop(
         handle.stop();
     }
 
+    // stop Massa private gRPC API
+    if let Some(handle) = grpc_private_handle {
+        handle.stop();
+    }
+
     // stop Massa API
     api_handle.stop().await;
     info!("API | EXPERIMENTAL JsonRPC | stopped");
@@ -971,6 +1122,35 @@ struct Args {
     #[structopt(long = "restart-from-snapshot-at-period")]
     restart_from_snapshot_at_period: Option<u64>,
 
+    /// Final state snapshot file to restore when restarting from a snapshot
+    #[structopt(
+        long = "import-snapshot",
+        parse(from_os_str),
+        requires = "restart-from-snapshot-at-period"
+    )]
+    import_snapshot: Option<PathBuf>,
+
+    /// Export a snapshot of the final state at the output of this final slot, formatted as `period,thread`
+    #[structopt(long = "export-snapshot-at-slot")]
+    export_snapshot_at_slot: Option<Slot>,
+
+    /// Path of the exported final state snapshot file
+    #[structopt(
+        long = "export-snapshot-path",
+        parse(from_os_str),
+        default_value = "final_state.snapshot"
+    )]
+    export_snapshot_path: PathBuf,
+
+    /// Export the records of the signed blocks and endorsements to this file and exit,
+    /// to import them on the machine the staking keys are moved to
+    #[structopt(long = "export-slashing-protection", parse(from_os_str))]
+    export_slashing_protection: Option<PathBuf>,
+
+    /// Import records of signed blocks and endorsements exported by another node
+    #[structopt(long = "import-slashing-protection", parse(from_os_str))]
+    import_slashing_protection: Option<PathBuf>,
+
     #[cfg(feature = "deadlock_detection")]
     /// Deadlocks detector
     #[structopt(
@@ -984,7 +1164,11 @@ struct Args {
 }
 
 /// Load wallet, asking for passwords if necessary
-fn load_wallet(password: Option<String>, path: &Path) -> anyhow::Result<Arc<RwLock<Wallet>>> {
+fn load_wallet(
+    password: Option<String>,
+    path: &Path,
+    argon2_params: Argon2Params,
+) -> anyhow::Result<Arc<RwLock<Wallet>>> {
     let password = if path.is_file() {
         password.unwrap_or_else(|| {
             Password::new()
@@ -1001,9 +1185,20 @@ fn load_wallet(password: Option<String>, path: &Path) -> anyhow::Result<Arc<RwLo
                 .expect("IO error: Password reading failed, staking keys file couldn't be created")
         })
     };
+    // files encrypted with a previous cipher version or other Argon2id parameters
+    // are re-encrypted in place with the configured parameters when loading the wallet
+    if path.is_file() {
+        let content = std::fs::read(path)?;
+        if massa_cipher::get_version(&content)? != massa_cipher::VERSION
+            || massa_cipher::get_argon2_header(&content)?.params != argon2_params
+        {
+            info!("Upgrading the encryption of the staking keys file");
+        }
+    }
     Ok(Arc::new(RwLock::new(Wallet::new(
         PathBuf::from(path),
         password,
+        argon2_params,
     )?)))
 }
 
@@ -1024,24 +1219,8 @@ fn main(args: Args) -> anyhow::Result<()> {
 
 async fn run(args: Args) -> anyhow::Result<()> {
     let mut cur_args = args;
-    use tracing_subscriber::prelude::*;
-    // spawn the console server in the background, returning a `Layer`:
-    let tracing_layer = tracing_subscriber::fmt::layer()
-        .with_filter(match SETTINGS.logging.level {
-            4 => LevelFilter::TRACE,
-            3 => LevelFilter::DEBUG,
-            2 => LevelFilter::INFO,
-            1 => LevelFilter::WARN,
-            _ => LevelFilter::ERROR,
-        })
-        .with_filter(filter_fn(|metadata| {
-            metadata.target().starts_with("massa") // ignore non-massa logs
-        }));
-    // build a `Subscriber` by combining layers with a `tracing_subscriber::Registry`:
-    tracing_subscriber::registry()
-        // add the console layer to the subscriber or default layers...
-        .with(tracing_layer)
-        .init();
+    // kept until the node exits, to flush the logs and spans
+    let _logging_guard = init_logging(&SETTINGS.logging)?;
 
     // Setup panic handlers,
     // and when a panic occurs,
@@ -1053,11 +1232,87 @@ async fn run(args: Args) -> anyhow::Result<()> {
         std::process::exit(1);
     }));
 
-    // load or create wallet, asking for password if necessary
-    let node_wallet = load_wallet(
-        cur_args.password.clone(),
-        &SETTINGS.factory.staking_wallet_path,
-    )?;
+    // load the records of the signed blocks and endorsements, and import or export them if requested
+    let mut slashing_protection =
+        SlashingProtection::open(&SETTINGS.factory.slashing_protection_path)?;
+    if let Some(path) = &cur_args.export_slashing_protection {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(&slashing_protection.export())?,
+        )?;
+        info!("Slashing-protection history exported to {}", path.display());
+        return Ok(());
+    }
+    if let Some(path) = &cur_args.import_slashing_protection {
+        let history: SlashingProtectionHistory = serde_json::from_slice(&std::fs::read(path)?)?;
+        let count = slashing_protection.import(history)?;
+        info!(
+            "Imported {} slashing-protection records from {}",
+            count,
+            path.display()
+        );
+    }
+    let slashing_protection = Arc::new(Mutex::new(slashing_protection));
+
+    // blocks and endorsements are signed by the external signer if there is one,
+    // otherwise with the staking keys of the wallet, loaded or created and asking for password if necessary
+    let (node_wallet, node_signer): (_, Arc<dyn Signer>) = match &SETTINGS.factory.remote_signer {
+        Some(endpoint) => {
+            info!(
+                "Signing blocks and endorsements with the external signer {:?}",
+                endpoint
+            );
+            (
+                None,
+                Arc::new(RemoteSigner::new(
+                    endpoint.clone(),
+                    SETTINGS.factory.remote_signer_timeout.to_duration(),
+                )),
+            )
+        }
+        None => {
+            let node_wallet = load_wallet(
+                cur_args.password.clone(),
+                &SETTINGS.factory.staking_wallet_path,
+                SETTINGS.factory.staking_wallet_argon2,
+            )?;
+            (
+                Some(node_wallet.clone()),
+                Arc::new(LocalSigner::new(node_wallet)),
+            )
+        }
+    };
+
+    // the metrics server outlives the restarts of the node, which are visible in the metrics
+    let metrics_handle = if SETTINGS.metrics.enabled {
+        let mut databases = vec![
+            (
+                "ledger".to_string(),
+                SETTINGS.ledger.disk_ledger_path.clone(),
+            ),
+            (
+                "module_cache".to_string(),
+                SETTINGS.execution.hd_cache_path.clone(),
+            ),
+        ];
+        if SETTINGS.execution.final_events_db_enabled {
+            databases.push((
+                "final_events".to_string(),
+                SETTINGS.execution.final_events_db_path.clone(),
+            ));
+        }
+        if SETTINGS.archive.enabled {
+            databases.push(("archive".to_string(), SETTINGS.archive.path.clone()));
+        }
+        let handle = start_metrics_server(MetricsConfig {
+            bind: SETTINGS.metrics.bind,
+            databases,
+        })?;
+        info!("METRICS | listening on: {}", handle.local_addr());
+        Some(handle)
+    } else {
+        None
+    };
 
     loop {
         let (
@@ -1075,7 +1330,15 @@ async fn run(args: Args) -> anyhow::Result<()> {
             api_public_handle,
             api_handle,
             grpc_handle,
-        ) = launch(&cur_args, node_wallet.clone()).await;
+            grpc_private,
+        ) = launch(
+            &cur_args,
+            node_wallet.clone(),
+            node_signer.clone(),
+            slashing_protection.clone(),
+        )
+        .await;
+        let (grpc_private_handle, mut grpc_private_stop_rx) = grpc_private.unzip();
 
         // interrupt signal listener
         let (tx, rx) = crossbeam_channel::bounded(1);
@@ -1115,6 +1378,19 @@ async fn run(args: Args) -> anyhow::Result<()> {
                 }
                 _ => {}
             }
+            if let Some(grpc_private_stop_rx) = grpc_private_stop_rx.as_mut() {
+                match grpc_private_stop_rx.try_recv() {
+                    Ok(_) => {
+                        info!("stop command received from private gRPC API");
+                        break false;
+                    }
+                    Err(tokio::sync::mpsc::error::TryRecvError::Disconnected) => {
+                        error!("grpc_private_stop_rx disconnected");
+                        break false;
+                    }
+                    _ => {}
+                }
+            }
             match rx.try_recv() {
                 Ok(_) => {
                     info!("interrupt signal received");
@@ -1144,6 +1420,7 @@ async fn run(args: Args) -> anyhow::Result<()> {
             api_public_handle,
             api_handle,
             grpc_handle,
+            grpc_private_handle,
         )
         .await;
 
@@ -1152,7 +1429,12 @@ async fn run(args: Args) -> anyhow::Result<()> {
         }
         // If we restart because of a desync, then we do not want to restart from a snapshot
         cur_args.restart_from_snapshot_at_period = None;
+        cur_args.import_snapshot = None;
         interrupt_signal_listener.abort();
     }
+
+    if let Some(handle) = metrics_handle {
+        handle.stop();
+    }
     Ok(())
 }
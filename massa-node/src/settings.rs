@@ -4,9 +4,11 @@
 use std::path::PathBuf;
 
 use enum_map::EnumMap;
+use massa_api_exports::rate_limit::RateLimitConfig;
 use massa_bootstrap::IpType;
-use massa_models::{config::build_massa_settings, node::NodeId};
+use massa_models::{amount::Amount, config::build_massa_settings, node::NodeId};
 use massa_time::MassaTime;
+use massa_wallet::{Argon2Params, RemoteSignerEndpoint};
 use serde::Deserialize;
 use std::net::{IpAddr, SocketAddr};
 
@@ -16,9 +18,37 @@ lazy_static::lazy_static! {
     pub static ref SETTINGS: Settings = build_massa_settings("massa-node", "MASSA_NODE");
 }
 
+/// Format of the logs
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per line, including the fields of the current spans
+    Json,
+}
+
+/// How often the log file is rotated
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum LogRotation {
+    /// A new file every minute
+    Minutely,
+    /// A new file every hour
+    Hourly,
+    /// A new file every day
+    Daily,
+    /// A single file
+    Never,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct LoggingSettings {
     pub level: usize,
+    pub format: LogFormat,
+    pub directives: Vec<String>,
+    pub file_path: Option<PathBuf>,
+    pub file_rotation: LogRotation,
+    pub max_log_files: Option<usize>,
+    pub otlp_endpoint: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -28,6 +58,8 @@ pub struct ExecutionSettings {
     pub cursor_delay: MassaTime,
     pub stats_time_window_duration: MassaTime,
     pub max_read_only_gas: u64,
+    pub estimated_fee_per_gas: Amount,
+    pub estimated_fee_per_byte: Amount,
     pub abi_gas_costs_file: PathBuf,
     pub wasm_gas_costs_file: PathBuf,
     pub initial_vesting_path: PathBuf,
@@ -35,6 +67,14 @@ pub struct ExecutionSettings {
     pub lru_cache_size: u32,
     pub hd_cache_size: usize,
     pub snip_amount: usize,
+    pub final_events_db_enabled: bool,
+    pub final_events_db_path: PathBuf,
+    pub final_events_db_max_slots: Option<u64>,
+    pub final_events_db_max_bytes: Option<u64>,
+    pub broadcast_sc_events_capacity: usize,
+    pub operation_traces_enabled: bool,
+    pub max_final_operation_traces: usize,
+    pub max_final_operation_traces_size: usize,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -50,6 +90,18 @@ pub struct LedgerSettings {
     pub final_history_length: usize,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct ArchiveSettings {
+    pub enabled: bool,
+    pub path: PathBuf,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MetricsSettings {
+    pub enabled: bool,
+    pub bind: SocketAddr,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct NetworkSettings {
     pub bind: SocketAddr,
@@ -100,6 +152,12 @@ pub struct BootstrapSettings {
     pub max_bytes_read_write: f64,
     /// Allocated time with which to manage the bootstrap process
     pub bootstrap_timeout: MassaTime,
+    /// Checkpoint allowing an interrupted bootstrap to resume after a restart
+    pub bootstrap_checkpoint_path: PathBuf,
+    /// Minimal delay between two bootstrap checkpoints
+    pub bootstrap_checkpoint_interval: MassaTime,
+    /// Max number of servers the ledger is bootstrapped from at once
+    pub max_parallel_bootstrap_servers: usize,
 }
 
 /// Factory settings
@@ -109,6 +167,14 @@ pub struct FactorySettings {
     pub initial_delay: MassaTime,
     /// Staking wallet file
     pub staking_wallet_path: PathBuf,
+    /// `Argon2id` parameters the staking wallet file is encrypted with
+    pub staking_wallet_argon2: Argon2Params,
+    /// External signer holding the staking keys, instead of the staking wallet
+    pub remote_signer: Option<RemoteSignerEndpoint>,
+    /// Timeout of the requests to the external signer
+    pub remote_signer_timeout: MassaTime,
+    /// Records of the signed blocks and endorsements
+    pub slashing_protection_path: PathBuf,
 }
 
 /// Pool configuration, read from a file configuration
@@ -143,6 +209,11 @@ pub struct APISettings {
     pub enable_ws: bool,
     // whether to broadcast for blocks, endorsement and operations
     pub enable_broadcast: bool,
+    pub tls_cert_path: Option<PathBuf>,
+    pub tls_key_path: Option<PathBuf>,
+    pub tls_client_ca_path: Option<PathBuf>,
+    pub auth_tokens_path: Option<PathBuf>,
+    pub rate_limit: RateLimitConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -156,9 +227,11 @@ pub struct Settings {
     pub pool: PoolSettings,
     pub execution: ExecutionSettings,
     pub ledger: LedgerSettings,
+    pub archive: ArchiveSettings,
     pub selector: SelectionSettings,
     pub factory: FactorySettings,
     pub grpc: GrpcSettings,
+    pub metrics: MetricsSettings,
 }
 
 /// Consensus configuration
@@ -248,6 +321,18 @@ pub struct GrpcSettings {
     pub enable_reflection: bool,
     /// bind for the Massa gRPC API
     pub bind: SocketAddr,
+    /// bind for the Massa private gRPC API, used to manage the node
+    pub bind_private: SocketAddr,
+    /// path to the PEM certificate chain served over TLS. TLS is disabled when not set
+    pub tls_cert_path: Option<PathBuf>,
+    /// path to the PEM private key of the TLS certificate
+    pub tls_key_path: Option<PathBuf>,
+    /// path to the PEM CA certificates used to verify client certificates. Client certificates are not required when not set
+    pub tls_client_ca_path: Option<PathBuf>,
+    /// path to the JSON file listing the tokens allowed to call the private gRPC API. Authentication is disabled when not set
+    pub auth_tokens_path: Option<PathBuf>,
+    /// rate limiting of the public gRPC API clients
+    pub rate_limit: RateLimitConfig,
     /// which compression encodings does the server accept for requests
     pub accept_compressed: Option<String>,
     /// which compression encodings might the server use for responses
@@ -282,6 +367,8 @@ pub struct GrpcSettings {
     pub max_frame_size: Option<u32>,
     /// when looking for next draw we want to look at max `draw_lookahead_period_count`
     pub draw_lookahead_period_count: u64,
+    /// max number of arguments per gRPC request
+    pub max_arguments: u64,
 }
 
 #[cfg(test)]
@@ -45,6 +45,9 @@ pub trait PoolController: Send + Sync {
     /// Get the number of denunciations in the pool
     fn get_denunciation_count(&self) -> usize;
 
+    /// Get denunciations for a block.
+    fn get_block_denunciations(&self, target_slot: &Slot) -> Vec<Denunciation>;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn PoolController>`.
     fn clone_box(&self) -> Box<dyn PoolController>;
@@ -74,6 +74,13 @@ pub enum MockPoolControllerMessage {
         /// Response channel
         response_tx: mpsc::Sender<usize>,
     },
+    /// Get block denunciations
+    GetBlockDenunciations {
+        /// Slot of the block
+        slot: Slot,
+        /// Response channel
+        response_tx: mpsc::Sender<Vec<Denunciation>>,
+    },
     /// Contains endorsements
     ContainsEndorsements {
         /// ids to search
@@ -276,4 +283,17 @@ impl PoolController for MockPoolController {
             .unwrap();
         response_rx.recv().unwrap()
     }
+
+    fn get_block_denunciations(&self, target_slot: &Slot) -> Vec<Denunciation> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.q
+            .lock()
+            .unwrap()
+            .send(MockPoolControllerMessage::GetBlockDenunciations {
+                slot: *target_slot,
+                response_tx,
+            })
+            .unwrap();
+        response_rx.recv().unwrap()
+    }
 }
@@ -204,6 +204,13 @@ impl PoolController for PoolControllerImpl {
         self.denunciation_pool.read().len()
     }
 
+    /// Get denunciations for a block
+    fn get_block_denunciations(&self, target_slot: &Slot) -> Vec<Denunciation> {
+        self.denunciation_pool
+            .read()
+            .get_block_denunciations(target_slot)
+    }
+
     /// Get final consensus periods
     fn get_final_cs_periods(&self) -> &Vec<u64> {
         &self.last_cs_final_periods
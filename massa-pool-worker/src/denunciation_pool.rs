@@ -1,5 +1,6 @@
 use massa_models::denunciation::{Denunciation, DenunciationId};
 use massa_models::prehash::PreHashMap;
+use massa_models::slot::Slot;
 
 use massa_pool_exports::PoolConfig;
 
@@ -48,16 +49,25 @@ impl DenunciationPool {
         }
     }
 
-    // In next PR
-    /*
-    pub fn get_block_denunciations(
-        &self,
-        slot: &Slot,
-        target_block: &BlockId,
-    ) -> Vec<DenunciationId> {
-        todo!()
+    /// Get the denunciations that can be included in a block at the given slot, oldest first
+    pub fn get_block_denunciations(&self, target_slot: &Slot) -> Vec<Denunciation> {
+        let mut denunciations: Vec<&Denunciation> = self
+            .denunciations_cache
+            .values()
+            .filter(|de| {
+                let de_slot = de.get_slot();
+                de_slot <= target_slot
+                    && target_slot.period.saturating_sub(de_slot.period)
+                        <= self.config.denunciation_expire_periods
+            })
+            .collect();
+        denunciations.sort_unstable_by_key(|de| *de.get_slot());
+        denunciations
+            .into_iter()
+            .take(self.config.max_denunciations_per_block_header as usize)
+            .cloned()
+            .collect()
     }
-    */
 
     pub(crate) fn notify_final_cs_periods(&mut self, final_cs_periods: &[u64]) {
         // update internal final CS period counter
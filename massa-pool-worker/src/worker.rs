@@ -8,6 +8,7 @@ use crate::operation_pool::OperationPool;
 use crate::{controller_impl::PoolControllerImpl, endorsement_pool::EndorsementPool};
 use crossbeam_channel::Sender;
 use massa_execution_exports::ExecutionController;
+use massa_metrics::POOL_SIZE;
 use massa_models::denunciation::DenunciationPrecursor;
 use massa_pool_exports::PoolConfig;
 use massa_pool_exports::{PoolChannels, PoolController, PoolManager};
@@ -64,7 +65,10 @@ impl EndorsementPoolThread {
                     warn!("EndorsementPoolThread received an unexpected command");
                     continue;
                 }
-            }
+            };
+            POOL_SIZE
+                .with_label_values(&["endorsements"])
+                .set(self.endorsement_pool.read().len() as i64);
         }
     }
 }
@@ -113,6 +117,9 @@ impl OperationPoolThread {
                     continue;
                 }
             };
+            POOL_SIZE
+                .with_label_values(&["operations"])
+                .set(self.operation_pool.read().len() as i64);
         }
     }
 }
@@ -161,6 +168,9 @@ impl DenunciationPoolThread {
                     continue;
                 }
             };
+            POOL_SIZE
+                .with_label_values(&["denunciations"])
+                .set(self.denunciation_pool.read().len() as i64);
         }
     }
 }
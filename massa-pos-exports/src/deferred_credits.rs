@@ -118,6 +118,20 @@ impl DeferredCredits {
         }
     }
 
+    /// Get the hash from the tracker, or compute it without enabling the tracker
+    pub fn get_or_compute_hash(&self) -> Hash {
+        if let Some(hash) = self.get_hash() {
+            return *hash;
+        }
+        let mut hash_tracker = DeferredCreditsHashTracker::new();
+        for (slot, credits) in &self.credits {
+            for (address, amount) in credits {
+                hash_tracker.toggle_entry(slot, address, amount);
+            }
+        }
+        *hash_tracker.get_hash()
+    }
+
     /// Enables the hash tracker (and compute the hash if absent)
     pub fn enable_hash_tracker_and_compute_hash(&mut self) -> &Hash {
         if self.hash_tracker.is_none() {
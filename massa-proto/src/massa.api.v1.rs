@@ -291,504 +291,2909 @@ pub struct SignedBlockHeader {
     #[prost(string, tag = "5")]
     pub id: ::prost::alloc::string::String,
 }
-/// GetBlocksBySlotsRequest holds request for GetBlocksBySlots
+/// Event emitted by a smart contract execution
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetBlocksBySlotsRequest {
-    /// Request id
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Slots
-    #[prost(message, repeated, tag = "2")]
-    pub slots: ::prost::alloc::vec::Vec<Slot>,
+pub struct ScExecutionEvent {
+    /// Execution context of the event
+    #[prost(message, optional, tag = "1")]
+    pub context: ::core::option::Option<ScExecutionEventContext>,
+    /// Json data string
+    #[prost(string, tag = "2")]
+    pub data: ::prost::alloc::string::String,
 }
-/// GetBlocksBySlotsResponse holds response from GetBlocksBySlots
+/// Execution context of a smart contract event
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetBlocksBySlotsResponse {
-    /// Request id
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Blocks
-    #[prost(message, repeated, tag = "2")]
-    pub blocks: ::prost::alloc::vec::Vec<Block>,
+pub struct ScExecutionEventContext {
+    /// Slot at which the event was generated
+    #[prost(message, optional, tag = "1")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Block id if there was a block at that slot
+    #[prost(string, optional, tag = "2")]
+    pub block_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Index of the event in the slot
+    #[prost(fixed64, tag = "3")]
+    pub index_in_slot: u64,
+    /// Call stack addresses, most recent at the end
+    #[prost(string, repeated, tag = "4")]
+    pub call_stack: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Origin operation id
+    #[prost(string, optional, tag = "5")]
+    pub origin_operation_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the event is final
+    #[prost(bool, tag = "6")]
+    pub is_final: bool,
+    /// Whether the event was generated during a read only execution
+    #[prost(bool, tag = "7")]
+    pub read_only: bool,
+    /// Whether the smart contract that emitted the event failed
+    #[prost(bool, tag = "8")]
+    pub is_error: bool,
 }
-/// GetDatastoreEntriesRequest holds request from GetDatastoreEntries
+/// Trace of a smart contract call
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetDatastoreEntriesRequest {
-    /// Request id
+pub struct CallTrace {
+    /// Called address
     #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Queries
-    #[prost(message, repeated, tag = "2")]
-    pub queries: ::prost::alloc::vec::Vec<DatastoreEntriesQuery>,
+    pub address: ::prost::alloc::string::String,
+    /// Coins transferred to the called address
+    #[prost(fixed64, tag = "2")]
+    pub coins: u64,
+    /// Steps of the call, in execution order
+    #[prost(message, repeated, tag = "3")]
+    pub steps: ::prost::alloc::vec::Vec<ExecutionTraceStep>,
+    /// Whether the call returned without error
+    #[prost(bool, tag = "4")]
+    pub finished: bool,
 }
-/// DatastoreEntries Query
+/// Step recorded in the trace of a call, exactly one field is set
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DatastoreEntriesQuery {
-    /// Filter
+pub struct ExecutionTraceStep {
+    /// Nested call to another smart contract
     #[prost(message, optional, tag = "1")]
-    pub filter: ::core::option::Option<DatastoreEntryFilter>,
+    pub call: ::core::option::Option<CallTrace>,
+    /// Coins transferred by the smart contract
+    #[prost(message, optional, tag = "2")]
+    pub transfer_coins: ::core::option::Option<TraceTransferCoins>,
+    /// Datastore entry written by the smart contract
+    #[prost(message, optional, tag = "3")]
+    pub set_data: ::core::option::Option<TraceSetData>,
+    /// Asynchronous message sent by the smart contract
+    #[prost(message, optional, tag = "4")]
+    pub send_message: ::core::option::Option<TraceSendMessage>,
+    /// Gas remaining when the control came back from the VM
+    #[prost(message, optional, tag = "5")]
+    pub gas_checkpoint: ::core::option::Option<TraceGasCheckpoint>,
 }
+/// Coins transferred by a smart contract
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DatastoreEntryFilter {
-    /// / Associated address of the entry
+pub struct TraceTransferCoins {
+    /// Sending address
+    #[prost(string, tag = "1")]
+    pub from_address: ::prost::alloc::string::String,
+    /// Receiving address
+    #[prost(string, tag = "2")]
+    pub to_address: ::prost::alloc::string::String,
+    /// Transferred amount
+    #[prost(fixed64, tag = "3")]
+    pub amount: u64,
+}
+/// Datastore entry written by a smart contract
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TraceSetData {
+    /// Address owning the datastore
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
     /// Datastore key
     #[prost(bytes = "vec", tag = "2")]
     pub key: ::prost::alloc::vec::Vec<u8>,
+    /// Written value, truncated to its first 256 bytes
+    #[prost(bytes = "vec", tag = "3")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
 }
-/// GetDatastoreEntriesResponse holds response from GetDatastoreEntries
+/// Asynchronous message sent by a smart contract
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetDatastoreEntriesResponse {
-    /// Request id
+pub struct TraceSendMessage {
+    /// Destination address of the message
     #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Datastore entries
-    #[prost(message, repeated, tag = "2")]
-    pub entries: ::prost::alloc::vec::Vec<DatastoreEntry>,
+    pub destination: ::prost::alloc::string::String,
+    /// Handler function of the message
+    #[prost(string, tag = "2")]
+    pub handler: ::prost::alloc::string::String,
+    /// Maximum gas of the message execution
+    #[prost(fixed64, tag = "3")]
+    pub max_gas: u64,
+    /// Fee paid for the message
+    #[prost(fixed64, tag = "4")]
+    pub fee: u64,
+    /// Coins sent with the message
+    #[prost(fixed64, tag = "5")]
+    pub coins: u64,
 }
-/// DatastoreEntry
+/// Gas remaining when the control came back from the VM
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DatastoreEntry {
-    /// final datastore entry value
-    #[prost(bytes = "vec", tag = "1")]
-    pub final_value: ::prost::alloc::vec::Vec<u8>,
-    /// candidate_value datastore entry value
-    #[prost(bytes = "vec", tag = "2")]
-    pub candidate_value: ::prost::alloc::vec::Vec<u8>,
+pub struct TraceGasCheckpoint {
+    /// Remaining gas
+    #[prost(fixed64, tag = "1")]
+    pub remaining_gas: u64,
 }
-/// GetNextBlockBestParentsRequest holds request for GetNextBlockBestParents
+/// EstimateOperationRequest holds request for EstimateOperation
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetNextBlockBestParentsRequest {
+pub struct EstimateOperationRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Address sending the operation
+    #[prost(string, tag = "2")]
+    pub sender_address: ::prost::alloc::string::String,
+    /// Unsigned operation content
+    #[prost(message, optional, tag = "3")]
+    pub op: ::core::option::Option<OperationType>,
+    /// Whether to simulate against the final state instead of the candidate one
+    #[prost(bool, tag = "4")]
+    pub is_final: bool,
 }
-/// GetNextBlockBestParentsResponse holds response from GetNextBlockBestParents
+/// EstimateOperationResponse holds response from EstimateOperation
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetNextBlockBestParentsResponse {
+pub struct EstimateOperationResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Best parents
-    #[prost(message, repeated, tag = "2")]
-    pub parents: ::prost::alloc::vec::Vec<BlockParent>,
+    /// Slot at which the operation was simulated
+    #[prost(message, optional, tag = "2")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Gas used by the operation
+    #[prost(fixed64, tag = "3")]
+    pub gas_used: u64,
+    /// Coins spent on ledger storage
+    #[prost(fixed64, tag = "4")]
+    pub storage_cost: u64,
+    /// Suggested fee for the operation
+    #[prost(fixed64, tag = "5")]
+    pub suggested_fee: u64,
+    /// Predicted balance changes
+    #[prost(message, repeated, tag = "6")]
+    pub balance_changes: ::prost::alloc::vec::Vec<BalanceChange>,
 }
-/// Block parent tuple
+/// Predicted balance change of an address
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BlockParent {
-    /// Block id
+pub struct BalanceChange {
+    /// Address
     #[prost(string, tag = "1")]
-    pub block_id: ::prost::alloc::string::String,
-    /// Period
+    pub address: ::prost::alloc::string::String,
+    /// Balance before the operation
     #[prost(fixed64, tag = "2")]
-    pub period: u64,
+    pub previous: u64,
+    /// Predicted balance after the operation
+    #[prost(fixed64, tag = "3")]
+    pub predicted: u64,
 }
-/// GetSelectorDrawsRequest holds request from GetSelectorDraws
+/// ExecuteReadOnlyBytecodeRequest holds request for ExecuteReadOnlyBytecode
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetSelectorDrawsRequest {
+pub struct ExecuteReadOnlyBytecodeRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Queries
+    /// Bytecode executions
     #[prost(message, repeated, tag = "2")]
-    pub queries: ::prost::alloc::vec::Vec<SelectorDrawsQuery>,
+    pub bytecode_executions: ::prost::alloc::vec::Vec<ReadOnlyBytecodeExecution>,
 }
-/// SelectorDraws Query
+/// Read-only execution of a bytecode
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SelectorDrawsQuery {
-    /// Filter
-    #[prost(message, optional, tag = "1")]
-    pub filter: ::core::option::Option<SelectorDrawsFilter>,
+pub struct ReadOnlyBytecodeExecution {
+    /// Maximum gas to spend in the execution
+    #[prost(fixed64, tag = "1")]
+    pub max_gas: u64,
+    /// Bytecode to execute
+    #[prost(bytes = "vec", tag = "2")]
+    pub bytecode: ::prost::alloc::vec::Vec<u8>,
+    /// Caller address, a random one is used if not set
+    #[prost(string, optional, tag = "3")]
+    pub address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Serialized operation datastore
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub operation_datastore: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Whether to execute on top of the final state instead of the candidate one
+    #[prost(bool, tag = "5")]
+    pub is_final: bool,
+    /// Whether to record the call trace of the execution
+    #[prost(bool, tag = "6")]
+    pub with_trace: bool,
 }
-/// SelectorDraws Filter
+/// ExecuteReadOnlyBytecodeResponse holds response from ExecuteReadOnlyBytecode
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SelectorDrawsFilter {
-    /// Address
+pub struct ExecuteReadOnlyBytecodeResponse {
+    /// Request id
     #[prost(string, tag = "1")]
-    pub address: ::prost::alloc::string::String,
+    pub id: ::prost::alloc::string::String,
+    /// Execution outputs, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub outputs: ::prost::alloc::vec::Vec<ReadOnlyExecutionOutput>,
 }
-/// GetSelectorDrawsResponse holds response from GetSelectorDraws
+/// Result of a read-only execution
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetSelectorDrawsResponse {
+pub struct ReadOnlyExecutionOutput {
+    /// Slot at which the execution happened
+    #[prost(message, optional, tag = "1")]
+    pub executed_at: ::core::option::Option<Slot>,
+    /// Value returned by the execution
+    #[prost(bytes = "vec", tag = "2")]
+    pub call_result: ::prost::alloc::vec::Vec<u8>,
+    /// Error message if the execution failed
+    #[prost(string, optional, tag = "3")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+    /// Gas cost of the execution
+    #[prost(fixed64, tag = "4")]
+    pub gas_cost: u64,
+    /// Events emitted by the execution
+    #[prost(message, repeated, tag = "5")]
+    pub output_events: ::prost::alloc::vec::Vec<ScExecutionEvent>,
+    /// Call trace of the execution, if requested
+    #[prost(message, optional, tag = "6")]
+    pub trace: ::core::option::Option<CallTrace>,
+}
+/// ExecuteReadOnlyCallRequest holds request for ExecuteReadOnlyCall
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteReadOnlyCallRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Selector draws
+    /// Function calls
     #[prost(message, repeated, tag = "2")]
-    pub selector_draws: ::prost::alloc::vec::Vec<SelectorDraws>,
+    pub calls: ::prost::alloc::vec::Vec<ReadOnlyCall>,
 }
-/// Selector draws
+/// Read-only call of a smart contract function
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SelectorDraws {
-    /// Address
+pub struct ReadOnlyCall {
+    /// Maximum gas to spend in the execution
+    #[prost(fixed64, tag = "1")]
+    pub max_gas: u64,
+    /// Target smart contract address
+    #[prost(string, tag = "2")]
+    pub target_address: ::prost::alloc::string::String,
+    /// Target function name
+    #[prost(string, tag = "3")]
+    pub target_function: ::prost::alloc::string::String,
+    /// Parameter to pass to the target function
+    #[prost(bytes = "vec", tag = "4")]
+    pub parameter: ::prost::alloc::vec::Vec<u8>,
+    /// Caller address, a random one is used if not set
+    #[prost(string, optional, tag = "5")]
+    pub caller_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to execute on top of the final state instead of the candidate one
+    #[prost(bool, tag = "6")]
+    pub is_final: bool,
+    /// State overrides applied for this call only
+    #[prost(message, repeated, tag = "7")]
+    pub state_overrides: ::prost::alloc::vec::Vec<StateOverride>,
+    /// Whether to record the call trace of the execution
+    #[prost(bool, tag = "8")]
+    pub with_trace: bool,
+}
+/// State override of an address for a read-only call
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StateOverride {
+    /// Overridden address
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
-    /// Next block draws
-    #[prost(message, repeated, tag = "2")]
-    pub next_block_draws: ::prost::alloc::vec::Vec<Slot>,
-    /// Next endorsements draws
-    #[prost(message, repeated, tag = "3")]
-    pub next_endorsement_draws: ::prost::alloc::vec::Vec<IndexedSlot>,
+    /// Balance to use
+    #[prost(fixed64, optional, tag = "2")]
+    pub balance: ::core::option::Option<u64>,
+    /// Bytecode to use
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub bytecode: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Datastore entries to set
+    #[prost(message, repeated, tag = "4")]
+    pub datastore: ::prost::alloc::vec::Vec<BytesMapFieldEntry>,
+    /// Roll count to use
+    #[prost(fixed64, optional, tag = "5")]
+    pub roll_count: ::core::option::Option<u64>,
 }
-/// GetTransactionsThroughputRequest holds request for GetTransactionsThroughput
+/// ExecuteReadOnlyCallResponse holds response from ExecuteReadOnlyCall
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetTransactionsThroughputRequest {
+pub struct ExecuteReadOnlyCallResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Execution outputs, in the order of the request
+    #[prost(message, repeated, tag = "2")]
+    pub outputs: ::prost::alloc::vec::Vec<ReadOnlyExecutionOutput>,
 }
-/// GetTransactionsThroughputResponse holds response from GetTransactionsThroughput
+/// GetAddressesRequest holds request for GetAddresses
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetTransactionsThroughputResponse {
+pub struct GetAddressesRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Transactions throughput
-    #[prost(fixed32, tag = "2")]
-    pub throughput: u32,
+    /// Addresses
+    #[prost(string, repeated, tag = "2")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-/// GetVersionRequest holds request from GetVersion
+/// GetAddressesResponse holds response from GetAddresses
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetVersionRequest {
+pub struct GetAddressesResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Addresses information
+    #[prost(message, repeated, tag = "2")]
+    pub addresses: ::prost::alloc::vec::Vec<AddressInfo>,
 }
-/// GetVersionResponse holds response from GetVersion
+/// Address information
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetVersionResponse {
-    /// Request id
+pub struct AddressInfo {
+    /// Address
     #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Version
-    #[prost(string, tag = "2")]
-    pub version: ::prost::alloc::string::String,
+    pub address: ::prost::alloc::string::String,
+    /// Thread of the address
+    #[prost(fixed32, tag = "2")]
+    pub thread: u32,
+    /// Final balance
+    #[prost(fixed64, tag = "3")]
+    pub final_balance: u64,
+    /// Final roll count
+    #[prost(fixed64, tag = "4")]
+    pub final_roll_count: u64,
+    /// Final datastore keys
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub final_datastore_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Candidate balance
+    #[prost(fixed64, tag = "6")]
+    pub candidate_balance: u64,
+    /// Candidate roll count
+    #[prost(fixed64, tag = "7")]
+    pub candidate_roll_count: u64,
+    /// Candidate datastore keys
+    #[prost(bytes = "vec", repeated, tag = "8")]
+    pub candidate_datastore_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// Deferred credits
+    #[prost(message, repeated, tag = "9")]
+    pub deferred_credits: ::prost::alloc::vec::Vec<SlotAmount>,
+    /// Next block draws
+    #[prost(message, repeated, tag = "10")]
+    pub next_block_draws: ::prost::alloc::vec::Vec<Slot>,
+    /// Next endorsements draws
+    #[prost(message, repeated, tag = "11")]
+    pub next_endorsement_draws: ::prost::alloc::vec::Vec<IndexedSlot>,
+    /// Ids of the blocks created by the address
+    #[prost(string, repeated, tag = "12")]
+    pub created_blocks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Ids of the operations created by the address
+    #[prost(string, repeated, tag = "13")]
+    pub created_operations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Ids of the endorsements created by the address
+    #[prost(string, repeated, tag = "14")]
+    pub created_endorsements: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Production statistics per cycle
+    #[prost(message, repeated, tag = "15")]
+    pub cycle_infos: ::prost::alloc::vec::Vec<AddressCycleInfo>,
 }
-/// NewBlocksRequest holds request for NewBlocks
+/// Amount credited at a given slot
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewBlocksRequest {
-    /// Request id
-    #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
+pub struct SlotAmount {
+    /// Slot
+    #[prost(message, optional, tag = "1")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Amount
+    #[prost(fixed64, tag = "2")]
+    pub amount: u64,
 }
-/// NewBlocksResponse holds response from NewBlocks
+/// Production statistics of an address during a cycle
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewBlocksResponse {
+pub struct AddressCycleInfo {
+    /// Cycle number
+    #[prost(fixed64, tag = "1")]
+    pub cycle: u64,
+    /// Whether the cycle is final
+    #[prost(bool, tag = "2")]
+    pub is_final: bool,
+    /// Number of blocks created by the address during the cycle
+    #[prost(fixed64, tag = "3")]
+    pub ok_count: u64,
+    /// Number of blocks missed by the address during the cycle
+    #[prost(fixed64, tag = "4")]
+    pub nok_count: u64,
+    /// Number of active rolls of the address at that cycle, if still available
+    #[prost(fixed64, optional, tag = "5")]
+    pub active_rolls: ::core::option::Option<u64>,
+}
+/// GetBlocksRequest holds request for GetBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlocksRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Signed block
-    #[prost(message, optional, tag = "2")]
-    pub block: ::core::option::Option<SignedBlock>,
+    /// Block ids
+    #[prost(string, repeated, tag = "2")]
+    pub block_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-/// NewBlocksHeadersRequest holds request for NewBlocksHeaders
+/// GetBlocksResponse holds response from GetBlocks
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewBlocksHeadersRequest {
+pub struct GetBlocksResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Blocks found, final blocks are only found in archive mode once pruned
+    #[prost(message, repeated, tag = "2")]
+    pub blocks: ::prost::alloc::vec::Vec<BlockInfo>,
 }
-/// NewBlocksHeadersResponse holds response from NewBlocksHeaders
+/// Block with its graph status
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewBlocksHeadersResponse {
-    /// Request id
+pub struct BlockInfo {
+    /// Block id
     #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    /// Signed block header
-    #[prost(message, optional, tag = "2")]
-    pub block_header: ::core::option::Option<SignedBlockHeader>,
+    pub block_id: ::prost::alloc::string::String,
+    /// Whether the block is final
+    #[prost(bool, tag = "2")]
+    pub is_final: bool,
+    /// Whether the block is in the blockclique
+    #[prost(bool, tag = "3")]
+    pub is_in_blockclique: bool,
+    /// Whether the block is active and not final
+    #[prost(bool, tag = "4")]
+    pub is_candidate: bool,
+    /// Whether the block was discarded
+    #[prost(bool, tag = "5")]
+    pub is_discarded: bool,
+    /// Block
+    #[prost(message, optional, tag = "6")]
+    pub block: ::core::option::Option<Block>,
 }
-/// NewFilledBlocksRequest holds request for NewFilledBlocks
+/// GetBlocksBySlotsRequest holds request for GetBlocksBySlots
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewFilledBlocksRequest {
+pub struct GetBlocksBySlotsRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// Slots
+    #[prost(message, repeated, tag = "2")]
+    pub slots: ::prost::alloc::vec::Vec<Slot>,
 }
-/// NewFilledBlocksResponse holds response from NewFilledBlocks
+/// GetBlocksBySlotsResponse holds response from GetBlocksBySlots
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewFilledBlocksResponse {
+pub struct GetBlocksBySlotsResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Block with operations content
-    #[prost(message, optional, tag = "2")]
-    pub filled_block: ::core::option::Option<FilledBlock>,
+    /// Blocks
+    #[prost(message, repeated, tag = "2")]
+    pub blocks: ::prost::alloc::vec::Vec<Block>,
 }
-/// NewOperationsRequest holds request for NewOperations
+/// GetCliquesRequest holds request for GetCliques
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewOperationsRequest {
+pub struct GetCliquesRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Query
-    #[prost(message, optional, tag = "2")]
-    pub query: ::core::option::Option<NewOperationsQuery>,
 }
-/// NewOperations Query
+/// GetCliquesResponse holds response from GetCliques
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewOperationsQuery {
-    /// Filter
-    #[prost(message, optional, tag = "1")]
-    pub filter: ::core::option::Option<NewOperationsFilter>,
+pub struct GetCliquesResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Cliques
+    #[prost(message, repeated, tag = "2")]
+    pub cliques: ::prost::alloc::vec::Vec<Clique>,
 }
-/// NewOperations Filter
+/// Mutually compatible blocks of the graph
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewOperationsFilter {
-    /// Operation type enum
-    #[prost(enumeration = "OpType", repeated, tag = "1")]
-    pub types: ::prost::alloc::vec::Vec<i32>,
+pub struct Clique {
+    /// Ids of the blocks in the clique
+    #[prost(string, repeated, tag = "1")]
+    pub block_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Fitness of the clique, used to compute finality
+    #[prost(fixed64, tag = "2")]
+    pub fitness: u64,
+    /// Whether the clique is the one of highest fitness
+    #[prost(bool, tag = "3")]
+    pub is_blockclique: bool,
 }
-/// NewOperationsResponse holds response from NewOperations
+/// GetDatastoreEntriesRequest holds request from GetDatastoreEntries
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NewOperationsResponse {
+pub struct GetDatastoreEntriesRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Signed operation
-    #[prost(message, optional, tag = "2")]
-    pub operation: ::core::option::Option<SignedOperation>,
+    /// Queries
+    #[prost(message, repeated, tag = "2")]
+    pub queries: ::prost::alloc::vec::Vec<DatastoreEntriesQuery>,
 }
-/// SendBlocksRequest holds parameters to SendBlocks
+/// DatastoreEntries Query
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendBlocksRequest {
+pub struct DatastoreEntriesQuery {
+    /// Filter
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<DatastoreEntryFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DatastoreEntryFilter {
+    /// / Associated address of the entry
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// Datastore key
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetDatastoreEntriesResponse holds response from GetDatastoreEntries
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDatastoreEntriesResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Secure shared block
-    #[prost(message, optional, tag = "2")]
-    pub block: ::core::option::Option<SecureShare>,
+    /// Datastore entries
+    #[prost(message, repeated, tag = "2")]
+    pub entries: ::prost::alloc::vec::Vec<DatastoreEntry>,
 }
-/// SendBlocksResponse holds response from SendBlocks
+/// DatastoreEntry
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendBlocksResponse {
+pub struct DatastoreEntry {
+    /// final datastore entry value
+    #[prost(bytes = "vec", tag = "1")]
+    pub final_value: ::prost::alloc::vec::Vec<u8>,
+    /// candidate_value datastore entry value
+    #[prost(bytes = "vec", tag = "2")]
+    pub candidate_value: ::prost::alloc::vec::Vec<u8>,
+}
+/// GetEndorsementsRequest holds request for GetEndorsements
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEndorsementsRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Block result or a gRPC status
-    #[prost(oneof = "send_blocks_response::Message", tags = "2, 3")]
-    pub message: ::core::option::Option<send_blocks_response::Message>,
+    /// Endorsement ids
+    #[prost(string, repeated, tag = "2")]
+    pub endorsement_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-/// Nested message and enum types in `SendBlocksResponse`.
-pub mod send_blocks_response {
-    /// Block result or a gRPC status
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Message {
-        /// Block result
-        #[prost(message, tag = "2")]
-        Result(super::BlockResult),
-        /// gRPC error(status)
-        #[prost(message, tag = "3")]
-        Error(super::super::super::super::google::rpc::Status),
-    }
+/// GetEndorsementsResponse holds response from GetEndorsements
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEndorsementsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Endorsements found
+    #[prost(message, repeated, tag = "2")]
+    pub endorsements: ::prost::alloc::vec::Vec<EndorsementInfo>,
 }
-/// Holds Block response
+/// Endorsement with its status
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct BlockResult {
+pub struct EndorsementInfo {
+    /// Endorsement id
+    #[prost(string, tag = "1")]
+    pub endorsement_id: ::prost::alloc::string::String,
+    /// Whether the endorsement is in the pool
+    #[prost(bool, tag = "2")]
+    pub in_pool: bool,
+    /// Ids of the blocks including the endorsement
+    #[prost(string, repeated, tag = "3")]
+    pub in_blocks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether the endorsement is in a final block
+    #[prost(bool, tag = "4")]
+    pub is_final: bool,
+    /// Endorsement
+    #[prost(message, optional, tag = "5")]
+    pub endorsement: ::core::option::Option<SignedEndorsement>,
+}
+/// GetGraphIntervalRequest holds request for GetGraphInterval
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGraphIntervalRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Optional start timestamp in milliseconds (included)
+    #[prost(fixed64, optional, tag = "2")]
+    pub start: ::core::option::Option<u64>,
+    /// Optional end timestamp in milliseconds (excluded)
+    #[prost(fixed64, optional, tag = "3")]
+    pub end: ::core::option::Option<u64>,
+}
+/// GetGraphIntervalResponse holds response from GetGraphInterval
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGraphIntervalResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Active and stale blocks of the interval
+    #[prost(message, repeated, tag = "2")]
+    pub block_summaries: ::prost::alloc::vec::Vec<BlockSummary>,
+}
+/// Summary of a block of the graph
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockSummary {
     /// Block id
     #[prost(string, tag = "1")]
     pub block_id: ::prost::alloc::string::String,
+    /// Whether the block is final
+    #[prost(bool, tag = "2")]
+    pub is_final: bool,
+    /// Whether the block is stale
+    #[prost(bool, tag = "3")]
+    pub is_stale: bool,
+    /// Whether the block is in the blockclique
+    #[prost(bool, tag = "4")]
+    pub is_in_blockclique: bool,
+    /// Slot of the block
+    #[prost(message, optional, tag = "5")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Address of the block creator
+    #[prost(string, tag = "6")]
+    pub creator: ::prost::alloc::string::String,
+    /// Ids of the block parents
+    #[prost(string, repeated, tag = "7")]
+    pub parents: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-/// SendEndorsementsRequest holds parameters to SendEndorsements
+/// GetLedgerProofRequest holds request for GetLedgerProof
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendEndorsementsRequest {
+pub struct GetLedgerProofRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Secure shared endorsements
-    #[prost(message, repeated, tag = "2")]
-    pub endorsements: ::prost::alloc::vec::Vec<SecureShare>,
+    /// Address owning the ledger entry
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    /// Ledger entry to prove
+    #[prost(enumeration = "LedgerProofKeyType", tag = "3")]
+    pub key_type: i32,
+    /// Datastore key, used when proving a datastore entry
+    #[prost(bytes = "vec", tag = "4")]
+    pub datastore_key: ::prost::alloc::vec::Vec<u8>,
 }
-/// SendEndorsementsResponse holds response from SendEndorsements
+/// GetLedgerProofResponse holds response from GetLedgerProof
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendEndorsementsResponse {
+pub struct GetLedgerProofResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Endorsement result or gRPC status
-    #[prost(oneof = "send_endorsements_response::Message", tags = "2, 3")]
-    pub message: ::core::option::Option<send_endorsements_response::Message>,
+    /// Final value of the ledger entry and its proof
+    #[prost(message, optional, tag = "2")]
+    pub proof: ::core::option::Option<FinalLedgerProof>,
 }
-/// Nested message and enum types in `SendEndorsementsResponse`.
-pub mod send_endorsements_response {
-    /// Endorsement result or gRPC status
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Message {
-        /// Endorsement result
-        #[prost(message, tag = "2")]
-        Result(super::EndorsementResult),
-        /// gRPC error(status)
-        #[prost(message, tag = "3")]
-        Error(super::super::super::super::google::rpc::Status),
-    }
+/// Proof of the value, or of the absence, of a ledger entry in the final state
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinalLedgerProof {
+    /// Final slot at which the proof was produced
+    #[prost(message, optional, tag = "1")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Serialized value of the entry, not set if the entry is absent
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub value: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Hashes of the siblings met on the path from the ledger root to the entry, root first
+    #[prost(string, repeated, tag = "3")]
+    pub siblings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Key hash of the leaf found at the position of the entry, if any
+    #[prost(string, optional, tag = "4")]
+    pub leaf_key_hash: ::core::option::Option<::prost::alloc::string::String>,
+    /// Value hash of the leaf found at the position of the entry, if any
+    #[prost(string, optional, tag = "5")]
+    pub leaf_value_hash: ::core::option::Option<::prost::alloc::string::String>,
+    /// Hashes committed by the final state hash, starting with the ledger hash
+    #[prost(string, repeated, tag = "6")]
+    pub state_hash_components: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Final state hash at `slot`
+    #[prost(string, tag = "7")]
+    pub final_state_hash: ::prost::alloc::string::String,
 }
-/// Holds Endorsement response
+/// GetNextBlockBestParentsRequest holds request for GetNextBlockBestParents
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EndorsementResult {
-    /// Endorsements ids
-    #[prost(string, repeated, tag = "1")]
-    pub endorsements_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+pub struct GetNextBlockBestParentsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
 }
-/// SendOperationsRequest holds parameters to SendOperations
+/// GetNextBlockBestParentsResponse holds response from GetNextBlockBestParents
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendOperationsRequest {
+pub struct GetNextBlockBestParentsResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Secured shared operations
+    /// Best parents
     #[prost(message, repeated, tag = "2")]
-    pub operations: ::prost::alloc::vec::Vec<SecureShare>,
+    pub parents: ::prost::alloc::vec::Vec<BlockParent>,
 }
-/// SendOperationsResponse holds response from SendOperations
+/// Block parent tuple
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SendOperationsResponse {
+pub struct BlockParent {
+    /// Block id
+    #[prost(string, tag = "1")]
+    pub block_id: ::prost::alloc::string::String,
+    /// Period
+    #[prost(fixed64, tag = "2")]
+    pub period: u64,
+}
+/// GetOperationTraceRequest holds request for GetOperationTrace
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOperationTraceRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Operation result or gRPC status
-    #[prost(oneof = "send_operations_response::Message", tags = "2, 3")]
-    pub message: ::core::option::Option<send_operations_response::Message>,
-}
-/// Nested message and enum types in `SendOperationsResponse`.
-pub mod send_operations_response {
-    /// Operation result or gRPC status
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Message {
-        /// Operation result
-        #[prost(message, tag = "2")]
-        Result(super::OperationResult),
-        /// gRPC error(status)
-        #[prost(message, tag = "3")]
-        Error(super::super::super::super::google::rpc::Status),
-    }
+    /// Operation id
+    #[prost(string, tag = "2")]
+    pub operation_id: ::prost::alloc::string::String,
 }
-/// Holds Operation response
+/// GetOperationTraceResponse holds response from GetOperationTrace
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OperationResult {
-    /// Operations ids
-    #[prost(string, repeated, tag = "1")]
-    pub operations_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+pub struct GetOperationTraceResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Call trace, not set if traces are disabled or no longer kept
+    #[prost(message, optional, tag = "2")]
+    pub trace: ::core::option::Option<CallTrace>,
 }
-/// TransactionsThroughputRequest holds request for TransactionsThroughput
+/// GetOperationsRequest holds request for GetOperations
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionsThroughputRequest {
+pub struct GetOperationsRequest {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Optional timer interval in sec. Defaults to 10s
-    #[prost(fixed64, optional, tag = "2")]
-    pub interval: ::core::option::Option<u64>,
+    /// Operation ids
+    #[prost(string, repeated, tag = "2")]
+    pub operation_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-/// TransactionsThroughputResponse holds response from TransactionsThroughput
+/// GetOperationsResponse holds response from GetOperations
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionsThroughputResponse {
+pub struct GetOperationsResponse {
     /// Request id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Transactions throughput
-    #[prost(fixed32, tag = "2")]
-    pub throughput: u32,
+    /// Operations found
+    #[prost(message, repeated, tag = "2")]
+    pub operations: ::prost::alloc::vec::Vec<OperationInfo>,
 }
-/// Operation type enum
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum OpType {
-    /// Defaut enum value
-    Unspecified = 0,
-    /// Transaction
-    Transaction = 1,
-    /// Roll buy
-    RollBuy = 2,
-    /// Roll sell
-    RollSell = 3,
-    /// Execute smart contract
-    ExecuteSc = 4,
-    /// Call smart contract
-    CallSc = 5,
+/// Operation with its status
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperationInfo {
+    /// Operation id
+    #[prost(string, tag = "1")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// Ids of the blocks including the operation
+    #[prost(string, repeated, tag = "2")]
+    pub in_blocks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether the operation is in the pool
+    #[prost(bool, tag = "3")]
+    pub in_pool: bool,
+    /// Whether the operation is final, not set if unknown
+    #[prost(bool, optional, tag = "4")]
+    pub is_operation_final: ::core::option::Option<bool>,
+    /// Thread of the operation creator
+    #[prost(fixed32, tag = "5")]
+    pub thread: u32,
+    /// Operation
+    #[prost(message, optional, tag = "6")]
+    pub operation: ::core::option::Option<SignedOperation>,
+    /// Whether the operation executed successfully, not set if unknown
+    #[prost(bool, optional, tag = "7")]
+    pub op_exec_status: ::core::option::Option<bool>,
 }
-impl OpType {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            OpType::Unspecified => "OP_TYPE_UNSPECIFIED",
-            OpType::Transaction => "OP_TYPE_TRANSACTION",
-            OpType::RollBuy => "OP_TYPE_ROLL_BUY",
-            OpType::RollSell => "OP_TYPE_ROLL_SELL",
-            OpType::ExecuteSc => "OP_TYPE_EXECUTE_SC",
-            OpType::CallSc => "OP_TYPE_CALL_SC",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "OP_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
-            "OP_TYPE_TRANSACTION" => Some(Self::Transaction),
-            "OP_TYPE_ROLL_BUY" => Some(Self::RollBuy),
-            "OP_TYPE_ROLL_SELL" => Some(Self::RollSell),
-            "OP_TYPE_EXECUTE_SC" => Some(Self::ExecuteSc),
-            "OP_TYPE_CALL_SC" => Some(Self::CallSc),
-            _ => None,
-        }
-    }
+/// GetOperationsByAddressRequest holds request for GetOperationsByAddress
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOperationsByAddressRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Sender, recipient or call target of the operations
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    /// Optional start slot (included)
+    #[prost(message, optional, tag = "3")]
+    pub start_slot: ::core::option::Option<Slot>,
+    /// Optional end slot (excluded)
+    #[prost(message, optional, tag = "4")]
+    pub end_slot: ::core::option::Option<Slot>,
+    /// Number of operations to skip
+    #[prost(fixed64, tag = "5")]
+    pub offset: u64,
+    /// Maximum number of operations to return, capped at (and defaulting to if 0) the server max_arguments
+    #[prost(fixed64, tag = "6")]
+    pub limit: u64,
 }
-/// Generated client implementations.
-pub mod massa_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
+/// GetOperationsByAddressResponse holds response from GetOperationsByAddress
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOperationsByAddressResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Operations sorted by slot of the including block
+    #[prost(message, repeated, tag = "2")]
+    pub operations: ::prost::alloc::vec::Vec<AddressOperation>,
+    /// Total number of operations matching the request
+    #[prost(fixed64, tag = "3")]
+    pub total_count: u64,
+}
+/// Operation sent or received by an address
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressOperation {
+    /// Slot of the block including the operation
+    #[prost(message, optional, tag = "1")]
+    pub slot: ::core::option::Option<Slot>,
+    /// Operation
+    #[prost(message, optional, tag = "2")]
+    pub operation: ::core::option::Option<SignedOperation>,
+}
+/// GetScExecutionEventsRequest holds request for GetScExecutionEvents
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetScExecutionEventsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Filter
+    #[prost(message, optional, tag = "2")]
+    pub filter: ::core::option::Option<ScExecutionEventsFilter>,
+}
+/// Smart contract execution events filter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScExecutionEventsFilter {
+    /// Optional start slot (included)
+    #[prost(message, optional, tag = "1")]
+    pub start_slot: ::core::option::Option<Slot>,
+    /// Optional end slot (included)
+    #[prost(message, optional, tag = "2")]
+    pub end_slot: ::core::option::Option<Slot>,
+    /// Emitter address
+    #[prost(string, optional, tag = "3")]
+    pub emitter_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Original caller address
+    #[prost(string, optional, tag = "4")]
+    pub original_caller_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Original operation id
+    #[prost(string, optional, tag = "5")]
+    pub original_operation_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to get only final (true) or only candidate (false) events
+    #[prost(bool, optional, tag = "6")]
+    pub is_final: ::core::option::Option<bool>,
+    /// Whether to get only events of failed (true) or succeeded (false) executions
+    #[prost(bool, optional, tag = "7")]
+    pub is_error: ::core::option::Option<bool>,
+}
+/// GetScExecutionEventsResponse holds response from GetScExecutionEvents
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetScExecutionEventsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Smart contract execution events
+    #[prost(message, repeated, tag = "2")]
+    pub events: ::prost::alloc::vec::Vec<ScExecutionEvent>,
+}
+/// GetSelectorDrawsRequest holds request from GetSelectorDraws
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSelectorDrawsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Queries
+    #[prost(message, repeated, tag = "2")]
+    pub queries: ::prost::alloc::vec::Vec<SelectorDrawsQuery>,
+}
+/// SelectorDraws Query
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectorDrawsQuery {
+    /// Filter
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<SelectorDrawsFilter>,
+}
+/// SelectorDraws Filter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectorDrawsFilter {
+    /// Address
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+}
+/// GetSelectorDrawsResponse holds response from GetSelectorDraws
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSelectorDrawsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Selector draws
+    #[prost(message, repeated, tag = "2")]
+    pub selector_draws: ::prost::alloc::vec::Vec<SelectorDraws>,
+}
+/// Selector draws
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectorDraws {
+    /// Address
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// Next block draws
+    #[prost(message, repeated, tag = "2")]
+    pub next_block_draws: ::prost::alloc::vec::Vec<Slot>,
+    /// Next endorsements draws
+    #[prost(message, repeated, tag = "3")]
+    pub next_endorsement_draws: ::prost::alloc::vec::Vec<IndexedSlot>,
+}
+/// GetStakersRequest holds request for GetStakers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakersRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Number of stakers to skip
+    #[prost(fixed64, tag = "2")]
+    pub offset: u64,
+    /// Maximum number of stakers to return, no limit if 0
+    #[prost(fixed64, tag = "3")]
+    pub limit: u64,
+}
+/// GetStakersResponse holds response from GetStakers
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakersResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Stakers
+    #[prost(message, repeated, tag = "2")]
+    pub stakers: ::prost::alloc::vec::Vec<StakerEntry>,
+    /// Total number of stakers
+    #[prost(fixed64, tag = "3")]
+    pub total_count: u64,
+}
+/// Staker and its active roll count
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StakerEntry {
+    /// Address
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    /// Active roll count in the current cycle
+    #[prost(fixed64, tag = "2")]
+    pub rolls: u64,
+}
+/// GetStatusRequest holds request for GetStatus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStatusRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetStatusResponse holds response from GetStatus
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStatusResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Node status
+    #[prost(message, optional, tag = "2")]
+    pub status: ::core::option::Option<NodeStatus>,
+}
+/// Node status
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeStatus {
+    /// Node id
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Routable IP of the node, if any
+    #[prost(string, optional, tag = "2")]
+    pub node_ip: ::core::option::Option<::prost::alloc::string::String>,
+    /// Node version
+    #[prost(string, tag = "3")]
+    pub version: ::prost::alloc::string::String,
+    /// Current time in milliseconds
+    #[prost(fixed64, tag = "4")]
+    pub current_time: u64,
+    /// Current cycle
+    #[prost(fixed64, tag = "5")]
+    pub current_cycle: u64,
+    /// Start time of the current cycle in milliseconds
+    #[prost(fixed64, tag = "6")]
+    pub current_cycle_time: u64,
+    /// Start time of the next cycle in milliseconds
+    #[prost(fixed64, tag = "7")]
+    pub next_cycle_time: u64,
+    /// Connected nodes
+    #[prost(message, repeated, tag = "8")]
+    pub connected_nodes: ::prost::alloc::vec::Vec<ConnectedNode>,
+    /// Latest slot, not set before genesis
+    #[prost(message, optional, tag = "9")]
+    pub last_slot: ::core::option::Option<Slot>,
+    /// Next slot
+    #[prost(message, optional, tag = "10")]
+    pub next_slot: ::core::option::Option<Slot>,
+    /// Execution statistics
+    #[prost(message, optional, tag = "11")]
+    pub execution_stats: ::core::option::Option<ExecutionStats>,
+    /// Consensus statistics
+    #[prost(message, optional, tag = "12")]
+    pub consensus_stats: ::core::option::Option<ConsensusStats>,
+    /// Network statistics
+    #[prost(message, optional, tag = "13")]
+    pub network_stats: ::core::option::Option<NetworkStats>,
+    /// Pool statistics
+    #[prost(message, optional, tag = "14")]
+    pub pool_stats: ::core::option::Option<PoolStats>,
+    /// Compact configuration
+    #[prost(message, optional, tag = "15")]
+    pub config: ::core::option::Option<CompactConfig>,
+}
+/// Node connected to this node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectedNode {
+    /// Node id
+    #[prost(string, tag = "1")]
+    pub node_id: ::prost::alloc::string::String,
+    /// Node IP
+    #[prost(string, tag = "2")]
+    pub node_ip: ::prost::alloc::string::String,
+    /// Whether the connection is outgoing
+    #[prost(bool, tag = "3")]
+    pub is_outgoing: bool,
+}
+/// Execution statistics
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionStats {
+    /// Time window start in milliseconds
+    #[prost(fixed64, tag = "1")]
+    pub time_window_start: u64,
+    /// Time window end in milliseconds
+    #[prost(fixed64, tag = "2")]
+    pub time_window_end: u64,
+    /// Number of final blocks in the time window
+    #[prost(fixed64, tag = "3")]
+    pub final_block_count: u64,
+    /// Number of final executed operations in the time window
+    #[prost(fixed64, tag = "4")]
+    pub final_executed_operations_count: u64,
+    /// Active execution cursor slot
+    #[prost(message, optional, tag = "5")]
+    pub active_cursor: ::core::option::Option<Slot>,
+}
+/// Consensus statistics
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusStats {
+    /// Start of the time span in milliseconds
+    #[prost(fixed64, tag = "1")]
+    pub start_timespan: u64,
+    /// End of the time span in milliseconds
+    #[prost(fixed64, tag = "2")]
+    pub end_timespan: u64,
+    /// Number of final blocks
+    #[prost(fixed64, tag = "3")]
+    pub final_block_count: u64,
+    /// Number of stale blocks in memory
+    #[prost(fixed64, tag = "4")]
+    pub stale_block_count: u64,
+    /// Number of active cliques
+    #[prost(fixed64, tag = "5")]
+    pub clique_count: u64,
+}
+/// Network statistics
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetworkStats {
+    /// Incoming connections count
+    #[prost(fixed64, tag = "1")]
+    pub in_connection_count: u64,
+    /// Outgoing connections count
+    #[prost(fixed64, tag = "2")]
+    pub out_connection_count: u64,
+    /// Known peers count
+    #[prost(fixed64, tag = "3")]
+    pub known_peer_count: u64,
+    /// Banned peers count
+    #[prost(fixed64, tag = "4")]
+    pub banned_peer_count: u64,
+    /// Active nodes count
+    #[prost(fixed64, tag = "5")]
+    pub active_node_count: u64,
+}
+/// Pool statistics
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolStats {
+    /// Number of operations in the pool
+    #[prost(fixed64, tag = "1")]
+    pub operations_count: u64,
+    /// Number of endorsements in the pool
+    #[prost(fixed64, tag = "2")]
+    pub endorsements_count: u64,
+}
+/// Compact configuration of the network
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompactConfig {
+    /// Genesis timestamp in milliseconds
+    #[prost(fixed64, tag = "1")]
+    pub genesis_timestamp: u64,
+    /// End timestamp in milliseconds, if any
+    #[prost(fixed64, optional, tag = "2")]
+    pub end_timestamp: ::core::option::Option<u64>,
+    /// Number of threads
+    #[prost(fixed32, tag = "3")]
+    pub thread_count: u32,
+    /// Time between two periods of the same thread in milliseconds
+    #[prost(fixed64, tag = "4")]
+    pub t0: u64,
+    /// Fitness threshold
+    #[prost(fixed64, tag = "5")]
+    pub delta_f0: u64,
+    /// Maximum operation validity period count
+    #[prost(fixed64, tag = "6")]
+    pub operation_validity_periods: u64,
+    /// Cycle duration in periods
+    #[prost(fixed64, tag = "7")]
+    pub periods_per_cycle: u64,
+    /// Block creation reward
+    #[prost(fixed64, tag = "8")]
+    pub block_reward: u64,
+    /// Roll price
+    #[prost(fixed64, tag = "9")]
+    pub roll_price: u64,
+    /// Maximum block size in bytes
+    #[prost(fixed32, tag = "10")]
+    pub max_block_size: u32,
+}
+/// GetTransactionsThroughputRequest holds request for GetTransactionsThroughput
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionsThroughputRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetTransactionsThroughputResponse holds response from GetTransactionsThroughput
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionsThroughputResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Transactions throughput
+    #[prost(fixed32, tag = "2")]
+    pub throughput: u32,
+}
+/// GetVersionRequest holds request from GetVersion
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVersionRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetVersionResponse holds response from GetVersion
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVersionResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Version
+    #[prost(string, tag = "2")]
+    pub version: ::prost::alloc::string::String,
+}
+/// NewBlocksRequest holds request for NewBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewBlocksRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// NewBlocksResponse holds response from NewBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewBlocksResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Signed block
+    #[prost(message, optional, tag = "2")]
+    pub block: ::core::option::Option<SignedBlock>,
+}
+/// NewBlocksHeadersRequest holds request for NewBlocksHeaders
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewBlocksHeadersRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// NewBlocksHeadersResponse holds response from NewBlocksHeaders
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewBlocksHeadersResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Signed block header
+    #[prost(message, optional, tag = "2")]
+    pub block_header: ::core::option::Option<SignedBlockHeader>,
+}
+/// NewFilledBlocksRequest holds request for NewFilledBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewFilledBlocksRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// NewFilledBlocksResponse holds response from NewFilledBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewFilledBlocksResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Block with operations content
+    #[prost(message, optional, tag = "2")]
+    pub filled_block: ::core::option::Option<FilledBlock>,
+}
+/// NewOperationsRequest holds request for NewOperations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewOperationsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Query
+    #[prost(message, optional, tag = "2")]
+    pub query: ::core::option::Option<NewOperationsQuery>,
+}
+/// NewOperations Query
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewOperationsQuery {
+    /// Filter
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<NewOperationsFilter>,
+}
+/// NewOperations Filter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewOperationsFilter {
+    /// Operation type enum
+    #[prost(enumeration = "OpType", repeated, tag = "1")]
+    pub types: ::prost::alloc::vec::Vec<i32>,
+}
+/// NewOperationsResponse holds response from NewOperations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewOperationsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Signed operation
+    #[prost(message, optional, tag = "2")]
+    pub operation: ::core::option::Option<SignedOperation>,
+}
+/// NewScEventsRequest holds request for NewScEvents
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewScEventsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Query
+    #[prost(message, optional, tag = "2")]
+    pub query: ::core::option::Option<NewScEventsQuery>,
+}
+/// NewScEvents Query
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewScEventsQuery {
+    /// Filter
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<NewScEventsFilter>,
+}
+/// NewScEvents Filter
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewScEventsFilter {
+    /// Emitter address
+    #[prost(string, optional, tag = "1")]
+    pub emitter_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Original caller address
+    #[prost(string, optional, tag = "2")]
+    pub original_caller_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// Original operation id
+    #[prost(string, optional, tag = "3")]
+    pub original_operation_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether to stream only final (true) or only candidate (false) events
+    #[prost(bool, optional, tag = "4")]
+    pub is_final: ::core::option::Option<bool>,
+    /// Whether to stream only events of failed (true) or succeeded (false) executions
+    #[prost(bool, optional, tag = "5")]
+    pub is_error: ::core::option::Option<bool>,
+}
+/// NewScEventsResponse holds response from NewScEvents
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewScEventsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Smart contract execution event
+    #[prost(message, optional, tag = "2")]
+    pub event: ::core::option::Option<ScExecutionEvent>,
+}
+/// SendBlocksRequest holds parameters to SendBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendBlocksRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Secure shared block
+    #[prost(message, optional, tag = "2")]
+    pub block: ::core::option::Option<SecureShare>,
+}
+/// SendBlocksResponse holds response from SendBlocks
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendBlocksResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Block result or a gRPC status
+    #[prost(oneof = "send_blocks_response::Message", tags = "2, 3")]
+    pub message: ::core::option::Option<send_blocks_response::Message>,
+}
+/// Nested message and enum types in `SendBlocksResponse`.
+pub mod send_blocks_response {
+    /// Block result or a gRPC status
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Message {
+        /// Block result
+        #[prost(message, tag = "2")]
+        Result(super::BlockResult),
+        /// gRPC error(status)
+        #[prost(message, tag = "3")]
+        Error(super::super::super::super::google::rpc::Status),
+    }
+}
+/// Holds Block response
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockResult {
+    /// Block id
+    #[prost(string, tag = "1")]
+    pub block_id: ::prost::alloc::string::String,
+}
+/// SendEndorsementsRequest holds parameters to SendEndorsements
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendEndorsementsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Secure shared endorsements
+    #[prost(message, repeated, tag = "2")]
+    pub endorsements: ::prost::alloc::vec::Vec<SecureShare>,
+}
+/// SendEndorsementsResponse holds response from SendEndorsements
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendEndorsementsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Endorsement result or gRPC status
+    #[prost(oneof = "send_endorsements_response::Message", tags = "2, 3")]
+    pub message: ::core::option::Option<send_endorsements_response::Message>,
+}
+/// Nested message and enum types in `SendEndorsementsResponse`.
+pub mod send_endorsements_response {
+    /// Endorsement result or gRPC status
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Message {
+        /// Endorsement result
+        #[prost(message, tag = "2")]
+        Result(super::EndorsementResult),
+        /// gRPC error(status)
+        #[prost(message, tag = "3")]
+        Error(super::super::super::super::google::rpc::Status),
+    }
+}
+/// Holds Endorsement response
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndorsementResult {
+    /// Endorsements ids
+    #[prost(string, repeated, tag = "1")]
+    pub endorsements_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// SendOperationsRequest holds parameters to SendOperations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendOperationsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Secured shared operations
+    #[prost(message, repeated, tag = "2")]
+    pub operations: ::prost::alloc::vec::Vec<SecureShare>,
+}
+/// SendOperationsResponse holds response from SendOperations
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SendOperationsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Operation result or gRPC status
+    #[prost(oneof = "send_operations_response::Message", tags = "2, 3")]
+    pub message: ::core::option::Option<send_operations_response::Message>,
+}
+/// Nested message and enum types in `SendOperationsResponse`.
+pub mod send_operations_response {
+    /// Operation result or gRPC status
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Message {
+        /// Operation result
+        #[prost(message, tag = "2")]
+        Result(super::OperationResult),
+        /// gRPC error(status)
+        #[prost(message, tag = "3")]
+        Error(super::super::super::super::google::rpc::Status),
+    }
+}
+/// Holds Operation response
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperationResult {
+    /// Operations ids
+    #[prost(string, repeated, tag = "1")]
+    pub operations_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// TransactionsThroughputRequest holds request for TransactionsThroughput
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsThroughputRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Optional timer interval in sec. Defaults to 10s
+    #[prost(fixed64, optional, tag = "2")]
+    pub interval: ::core::option::Option<u64>,
+}
+/// TransactionsThroughputResponse holds response from TransactionsThroughput
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionsThroughputResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Transactions throughput
+    #[prost(fixed32, tag = "2")]
+    pub throughput: u32,
+}
+/// Ledger entry type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LedgerProofKeyType {
+    /// Default enum value
+    Unspecified = 0,
+    /// Balance of the address
+    Balance = 1,
+    /// Bytecode of the address
+    Bytecode = 2,
+    /// Datastore entry of the address
+    Datastore = 3,
+}
+impl LedgerProofKeyType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LedgerProofKeyType::Unspecified => "LEDGER_PROOF_KEY_TYPE_UNSPECIFIED",
+            LedgerProofKeyType::Balance => "LEDGER_PROOF_KEY_TYPE_BALANCE",
+            LedgerProofKeyType::Bytecode => "LEDGER_PROOF_KEY_TYPE_BYTECODE",
+            LedgerProofKeyType::Datastore => "LEDGER_PROOF_KEY_TYPE_DATASTORE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LEDGER_PROOF_KEY_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "LEDGER_PROOF_KEY_TYPE_BALANCE" => Some(Self::Balance),
+            "LEDGER_PROOF_KEY_TYPE_BYTECODE" => Some(Self::Bytecode),
+            "LEDGER_PROOF_KEY_TYPE_DATASTORE" => Some(Self::Datastore),
+            _ => None,
+        }
+    }
+}
+/// Operation type enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OpType {
+    /// Defaut enum value
+    Unspecified = 0,
+    /// Transaction
+    Transaction = 1,
+    /// Roll buy
+    RollBuy = 2,
+    /// Roll sell
+    RollSell = 3,
+    /// Execute smart contract
+    ExecuteSc = 4,
+    /// Call smart contract
+    CallSc = 5,
+}
+impl OpType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OpType::Unspecified => "OP_TYPE_UNSPECIFIED",
+            OpType::Transaction => "OP_TYPE_TRANSACTION",
+            OpType::RollBuy => "OP_TYPE_ROLL_BUY",
+            OpType::RollSell => "OP_TYPE_ROLL_SELL",
+            OpType::ExecuteSc => "OP_TYPE_EXECUTE_SC",
+            OpType::CallSc => "OP_TYPE_CALL_SC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OP_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "OP_TYPE_TRANSACTION" => Some(Self::Transaction),
+            "OP_TYPE_ROLL_BUY" => Some(Self::RollBuy),
+            "OP_TYPE_ROLL_SELL" => Some(Self::RollSell),
+            "OP_TYPE_EXECUTE_SC" => Some(Self::ExecuteSc),
+            "OP_TYPE_CALL_SC" => Some(Self::CallSc),
+            _ => None,
+        }
+    }
+}
+/// AddStakingSecretKeysRequest holds request for AddStakingSecretKeys
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddStakingSecretKeysRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Secret keys
+    #[prost(string, repeated, tag = "2")]
+    pub secret_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// AddStakingSecretKeysResponse holds response from AddStakingSecretKeys
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddStakingSecretKeysResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// AddToBootstrapBlacklistRequest holds request for AddToBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToBootstrapBlacklistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// AddToBootstrapBlacklistResponse holds response from AddToBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToBootstrapBlacklistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// AddToBootstrapWhitelistRequest holds request for AddToBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToBootstrapWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// AddToBootstrapWhitelistResponse holds response from AddToBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToBootstrapWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// AddToPeersWhitelistRequest holds request for AddToPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToPeersWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// AddToPeersWhitelistResponse holds response from AddToPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToPeersWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// AllowEveryoneToBootstrapRequest holds request for AllowEveryoneToBootstrap
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowEveryoneToBootstrapRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// AllowEveryoneToBootstrapResponse holds response from AllowEveryoneToBootstrap
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowEveryoneToBootstrapResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// BanNodesByIdsRequest holds request for BanNodesByIds
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BanNodesByIdsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Node ids
+    #[prost(string, repeated, tag = "2")]
+    pub node_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// BanNodesByIdsResponse holds response from BanNodesByIds
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BanNodesByIdsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// BanNodesByIpsRequest holds request for BanNodesByIps
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BanNodesByIpsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// BanNodesByIpsResponse holds response from BanNodesByIps
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BanNodesByIpsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetBootstrapBlacklistRequest holds request for GetBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBootstrapBlacklistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetBootstrapBlacklistResponse holds response from GetBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBootstrapBlacklistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// GetBootstrapWhitelistRequest holds request for GetBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBootstrapWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetBootstrapWhitelistResponse holds response from GetBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBootstrapWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// GetPeersWhitelistRequest holds request for GetPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPeersWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetPeersWhitelistResponse holds response from GetPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPeersWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// GetStakingAddressesRequest holds request for GetStakingAddresses
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakingAddressesRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// GetStakingAddressesResponse holds response from GetStakingAddresses
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStakingAddressesResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Staking addresses
+    #[prost(string, repeated, tag = "2")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RemoveFromBootstrapBlacklistRequest holds request for RemoveFromBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromBootstrapBlacklistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RemoveFromBootstrapBlacklistResponse holds response from RemoveFromBootstrapBlacklist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromBootstrapBlacklistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// RemoveFromBootstrapWhitelistRequest holds request for RemoveFromBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromBootstrapWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RemoveFromBootstrapWhitelistResponse holds response from RemoveFromBootstrapWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromBootstrapWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// RemoveFromPeersWhitelistRequest holds request for RemoveFromPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromPeersWhitelistRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RemoveFromPeersWhitelistResponse holds response from RemoveFromPeersWhitelist
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromPeersWhitelistResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// RemoveStakingAddressesRequest holds request for RemoveStakingAddresses
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveStakingAddressesRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Addresses
+    #[prost(string, repeated, tag = "2")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// RemoveStakingAddressesResponse holds response from RemoveStakingAddresses
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveStakingAddressesResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// SignMessageRequest holds request for SignMessage
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignMessageRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Message to sign
+    #[prost(bytes = "vec", tag = "2")]
+    pub message: ::prost::alloc::vec::Vec<u8>,
+}
+/// SignMessageResponse holds response from SignMessage
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignMessageResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Public key of the node
+    #[prost(string, tag = "2")]
+    pub public_key: ::prost::alloc::string::String,
+    /// Signature of the message
+    #[prost(string, tag = "3")]
+    pub signature: ::prost::alloc::string::String,
+}
+/// StopNodeRequest holds request for StopNode
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopNodeRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// StopNodeResponse holds response from StopNode
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopNodeResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// UnbanNodesByIdsRequest holds request for UnbanNodesByIds
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbanNodesByIdsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Node ids
+    #[prost(string, repeated, tag = "2")]
+    pub node_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// UnbanNodesByIdsResponse holds response from UnbanNodesByIds
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbanNodesByIdsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// UnbanNodesByIpsRequest holds request for UnbanNodesByIps
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbanNodesByIpsRequest {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// IP addresses
+    #[prost(string, repeated, tag = "2")]
+    pub ips: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// UnbanNodesByIpsResponse holds response from UnbanNodesByIps
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbanNodesByIpsResponse {
+    /// Request id
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Generated client implementations.
+pub mod massa_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
     /// Massa gRPC service
     #[derive(Debug, Clone)]
-    pub struct MassaServiceClient<T> {
+    pub struct MassaServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl MassaServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MassaServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> MassaServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            MassaServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Estimate the costs of an unsigned operation
+        pub async fn estimate_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateOperationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateOperationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/EstimateOperation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "EstimateOperation"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Execute bytecodes in read-only mode
+        pub async fn execute_read_only_bytecode(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExecuteReadOnlyBytecodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteReadOnlyBytecodeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/ExecuteReadOnlyBytecode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "ExecuteReadOnlyBytecode"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Call smart contract functions in read-only mode
+        pub async fn execute_read_only_call(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExecuteReadOnlyCallRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteReadOnlyCallResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/ExecuteReadOnlyCall",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "ExecuteReadOnlyCall"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get addresses
+        pub async fn get_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAddressesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetAddresses"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get blocks by ids
+        pub async fn get_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetBlocks"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get blocks by slots
+        pub async fn get_blocks_by_slots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlocksBySlotsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksBySlotsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetBlocksBySlots",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaService", "GetBlocksBySlots"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get cliques
+        pub async fn get_cliques(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCliquesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCliquesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetCliques",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetCliques"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get datastore entries
+        pub async fn get_datastore_entries(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDatastoreEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDatastoreEntriesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetDatastoreEntries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaService", "GetDatastoreEntries"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get endorsements by ids
+        pub async fn get_endorsements(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetEndorsementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEndorsementsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetEndorsements",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetEndorsements"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a summary of the blocks of the graph within a time interval
+        pub async fn get_graph_interval(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetGraphIntervalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetGraphIntervalResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetGraphInterval",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetGraphInterval"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the final value of a ledger entry along with its proof against the final state hash
+        pub async fn get_ledger_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLedgerProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLedgerProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetLedgerProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetLedgerProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get next block best parents
+        pub async fn get_next_block_best_parents(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNextBlockBestParentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextBlockBestParentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetNextBlockBestParents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "massa.api.v1.MassaService",
+                        "GetNextBlockBestParents",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the call trace of a final smart contract operation
+        pub async fn get_operation_trace(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOperationTraceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationTraceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetOperationTrace",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetOperationTrace"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get operations by ids
+        pub async fn get_operations(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOperationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetOperations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetOperations"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get operations sent or received by an address
+        pub async fn get_operations_by_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOperationsByAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationsByAddressResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetOperationsByAddress",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetOperationsByAddress"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get smart contract execution events
+        pub async fn get_sc_execution_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetScExecutionEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetScExecutionEventsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetScExecutionEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetScExecutionEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get selector draws
+        pub async fn get_selector_draws(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSelectorDrawsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSelectorDrawsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetSelectorDraws",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaService", "GetSelectorDraws"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get stakers sorted by decreasing active roll count
+        pub async fn get_stakers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStakersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStakersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetStakers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetStakers"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get node status
+        pub async fn get_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get transactions throughput
+        pub async fn get_transactions_throughput(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTransactionsThroughputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransactionsThroughputResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetTransactionsThroughput",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "massa.api.v1.MassaService",
+                        "GetTransactionsThroughput",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get node version
+        pub async fn get_version(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetVersionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVersionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/GetVersion",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetVersion"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// New received and produced blocks
+        pub async fn new_blocks(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::NewBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NewBlocksResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/NewBlocks",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewBlocks"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// New received and produced blocks headers
+        pub async fn new_blocks_headers(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::NewBlocksHeadersRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NewBlocksHeadersResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/NewBlocksHeaders",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaService", "NewBlocksHeaders"),
+                );
+            self.inner.streaming(req, path, codec).await
+        }
+        /// New received and produced blocks with operations
+        pub async fn new_filled_blocks(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::NewFilledBlocksRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NewFilledBlocksResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/NewFilledBlocks",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewFilledBlocks"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// New received and produced perations
+        pub async fn new_operations(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::NewOperationsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NewOperationsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/NewOperations",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewOperations"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// New smart contract execution events, filtered by emitter, caller, operation and status
+        pub async fn new_sc_events(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::NewScEventsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NewScEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/NewScEvents",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewScEvents"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Send blocks
+        pub async fn send_blocks(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::SendBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SendBlocksResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/SendBlocks",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "SendBlocks"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Send endorsements
+        pub async fn send_endorsements(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::SendEndorsementsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SendEndorsementsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/SendEndorsements",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaService", "SendEndorsements"),
+                );
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Send operations
+        pub async fn send_operations(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::SendOperationsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SendOperationsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/SendOperations",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaService", "SendOperations"));
+            self.inner.streaming(req, path, codec).await
+        }
+        /// Transactions throughput per second
+        pub async fn transactions_throughput(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::TransactionsThroughputRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::TransactionsThroughputResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaService/TransactionsThroughput",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "massa.api.v1.MassaService",
+                        "TransactionsThroughput",
+                    ),
+                );
+            self.inner.streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod massa_private_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Massa private gRPC service, to manage the node
+    #[derive(Debug, Clone)]
+    pub struct MassaPrivateServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl MassaServiceClient<tonic::transport::Channel> {
+    impl MassaPrivateServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -799,53 +3204,943 @@ pub mod massa_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> MassaServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
+    impl<T> MassaPrivateServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> MassaPrivateServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            MassaPrivateServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Add staking secret keys to the node wallet
+        pub async fn add_staking_secret_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddStakingSecretKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddStakingSecretKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/AddStakingSecretKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "AddStakingSecretKeys"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Add IP addresses to the bootstrap blacklist
+        pub async fn add_to_bootstrap_blacklist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddToBootstrapBlacklistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToBootstrapBlacklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/AddToBootstrapBlacklist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "AddToBootstrapBlacklist"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Add IP addresses to the bootstrap whitelist
+        pub async fn add_to_bootstrap_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddToBootstrapWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToBootstrapWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/AddToBootstrapWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "AddToBootstrapWhitelist"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Add IP addresses to the peers whitelist
+        pub async fn add_to_peers_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddToPeersWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToPeersWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/AddToPeersWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "AddToPeersWhitelist"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Allow everyone to bootstrap from the node by removing the bootstrap whitelist
+        pub async fn allow_everyone_to_bootstrap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AllowEveryoneToBootstrapRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AllowEveryoneToBootstrapResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/AllowEveryoneToBootstrap",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "AllowEveryoneToBootstrap"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Ban nodes by ids
+        pub async fn ban_nodes_by_ids(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BanNodesByIdsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BanNodesByIdsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/BanNodesByIds",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "BanNodesByIds"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Ban nodes by IP addresses
+        pub async fn ban_nodes_by_ips(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BanNodesByIpsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BanNodesByIpsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/BanNodesByIps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "BanNodesByIps"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the bootstrap blacklist
+        pub async fn get_bootstrap_blacklist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBootstrapBlacklistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBootstrapBlacklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/GetBootstrapBlacklist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "GetBootstrapBlacklist"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the bootstrap whitelist
+        pub async fn get_bootstrap_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBootstrapWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBootstrapWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/GetBootstrapWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "GetBootstrapWhitelist"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the peers whitelist
+        pub async fn get_peers_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPeersWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPeersWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/GetPeersWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "GetPeersWhitelist"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the staking addresses of the node wallet
+        pub async fn get_staking_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStakingAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStakingAddressesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/GetStakingAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "GetStakingAddresses"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Remove IP addresses from the bootstrap blacklist
+        pub async fn remove_from_bootstrap_blacklist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveFromBootstrapBlacklistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromBootstrapBlacklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/RemoveFromBootstrapBlacklist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "massa.api.v1.MassaPrivateService",
+                        "RemoveFromBootstrapBlacklist",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Remove IP addresses from the bootstrap whitelist
+        pub async fn remove_from_bootstrap_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveFromBootstrapWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromBootstrapWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/RemoveFromBootstrapWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "massa.api.v1.MassaPrivateService",
+                        "RemoveFromBootstrapWhitelist",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Remove IP addresses from the peers whitelist
+        pub async fn remove_from_peers_whitelist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveFromPeersWhitelistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromPeersWhitelistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/RemoveFromPeersWhitelist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "RemoveFromPeersWhitelist"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Remove staking addresses from the node wallet
+        pub async fn remove_staking_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveStakingAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveStakingAddressesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/RemoveStakingAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("massa.api.v1.MassaPrivateService", "RemoveStakingAddresses"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Sign a message with the node key
+        pub async fn sign_message(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignMessageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignMessageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/SignMessage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "SignMessage"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Gracefully stop the node
+        pub async fn stop_node(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StopNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StopNodeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/StopNode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "StopNode"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Unban nodes by ids
+        pub async fn unban_nodes_by_ids(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnbanNodesByIdsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnbanNodesByIdsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/UnbanNodesByIds",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "UnbanNodesByIds"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Unban nodes by IP addresses
+        pub async fn unban_nodes_by_ips(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnbanNodesByIpsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UnbanNodesByIpsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/massa.api.v1.MassaPrivateService/UnbanNodesByIps",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("massa.api.v1.MassaPrivateService", "UnbanNodesByIps"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod massa_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MassaServiceServer.
+    #[async_trait]
+    pub trait MassaService: Send + Sync + 'static {
+        /// Estimate the costs of an unsigned operation
+        async fn estimate_operation(
+            &self,
+            request: tonic::Request<super::EstimateOperationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateOperationResponse>,
+            tonic::Status,
+        >;
+        /// Execute bytecodes in read-only mode
+        async fn execute_read_only_bytecode(
+            &self,
+            request: tonic::Request<super::ExecuteReadOnlyBytecodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteReadOnlyBytecodeResponse>,
+            tonic::Status,
+        >;
+        /// Call smart contract functions in read-only mode
+        async fn execute_read_only_call(
+            &self,
+            request: tonic::Request<super::ExecuteReadOnlyCallRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteReadOnlyCallResponse>,
+            tonic::Status,
+        >;
+        /// Get addresses
+        async fn get_addresses(
+            &self,
+            request: tonic::Request<super::GetAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAddressesResponse>,
+            tonic::Status,
+        >;
+        /// Get blocks by ids
+        async fn get_blocks(
+            &self,
+            request: tonic::Request<super::GetBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksResponse>,
+            tonic::Status,
+        >;
+        /// Get blocks by slots
+        async fn get_blocks_by_slots(
+            &self,
+            request: tonic::Request<super::GetBlocksBySlotsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlocksBySlotsResponse>,
+            tonic::Status,
+        >;
+        /// Get cliques
+        async fn get_cliques(
+            &self,
+            request: tonic::Request<super::GetCliquesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCliquesResponse>,
+            tonic::Status,
+        >;
+        /// Get datastore entries
+        async fn get_datastore_entries(
+            &self,
+            request: tonic::Request<super::GetDatastoreEntriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDatastoreEntriesResponse>,
+            tonic::Status,
+        >;
+        /// Get endorsements by ids
+        async fn get_endorsements(
+            &self,
+            request: tonic::Request<super::GetEndorsementsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEndorsementsResponse>,
+            tonic::Status,
+        >;
+        /// Get a summary of the blocks of the graph within a time interval
+        async fn get_graph_interval(
+            &self,
+            request: tonic::Request<super::GetGraphIntervalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetGraphIntervalResponse>,
+            tonic::Status,
+        >;
+        /// Get the final value of a ledger entry along with its proof against the final state hash
+        async fn get_ledger_proof(
+            &self,
+            request: tonic::Request<super::GetLedgerProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLedgerProofResponse>,
+            tonic::Status,
+        >;
+        /// Get next block best parents
+        async fn get_next_block_best_parents(
+            &self,
+            request: tonic::Request<super::GetNextBlockBestParentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextBlockBestParentsResponse>,
+            tonic::Status,
+        >;
+        /// Get the call trace of a final smart contract operation
+        async fn get_operation_trace(
+            &self,
+            request: tonic::Request<super::GetOperationTraceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationTraceResponse>,
+            tonic::Status,
+        >;
+        /// Get operations by ids
+        async fn get_operations(
+            &self,
+            request: tonic::Request<super::GetOperationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationsResponse>,
+            tonic::Status,
+        >;
+        /// Get operations sent or received by an address
+        async fn get_operations_by_address(
+            &self,
+            request: tonic::Request<super::GetOperationsByAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetOperationsByAddressResponse>,
+            tonic::Status,
+        >;
+        /// Get smart contract execution events
+        async fn get_sc_execution_events(
+            &self,
+            request: tonic::Request<super::GetScExecutionEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetScExecutionEventsResponse>,
+            tonic::Status,
+        >;
+        /// Get selector draws
+        async fn get_selector_draws(
+            &self,
+            request: tonic::Request<super::GetSelectorDrawsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSelectorDrawsResponse>,
+            tonic::Status,
+        >;
+        /// Get stakers sorted by decreasing active roll count
+        async fn get_stakers(
+            &self,
+            request: tonic::Request<super::GetStakersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStakersResponse>,
+            tonic::Status,
+        >;
+        /// Get node status
+        async fn get_status(
+            &self,
+            request: tonic::Request<super::GetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStatusResponse>,
+            tonic::Status,
+        >;
+        /// Get transactions throughput
+        async fn get_transactions_throughput(
+            &self,
+            request: tonic::Request<super::GetTransactionsThroughputRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransactionsThroughputResponse>,
+            tonic::Status,
+        >;
+        /// Get node version
+        async fn get_version(
+            &self,
+            request: tonic::Request<super::GetVersionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetVersionResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the NewBlocks method.
+        type NewBlocksStream: futures_core::Stream<
+                Item = std::result::Result<super::NewBlocksResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// New received and produced blocks
+        async fn new_blocks(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::NewBlocksRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::NewBlocksStream>, tonic::Status>;
+        /// Server streaming response type for the NewBlocksHeaders method.
+        type NewBlocksHeadersStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::NewBlocksHeadersResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// New received and produced blocks headers
+        async fn new_blocks_headers(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::NewBlocksHeadersRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NewBlocksHeadersStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the NewFilledBlocks method.
+        type NewFilledBlocksStream: futures_core::Stream<
+                Item = std::result::Result<super::NewFilledBlocksResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// New received and produced blocks with operations
+        async fn new_filled_blocks(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::NewFilledBlocksRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NewFilledBlocksStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the NewOperations method.
+        type NewOperationsStream: futures_core::Stream<
+                Item = std::result::Result<super::NewOperationsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// New received and produced perations
+        async fn new_operations(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::NewOperationsRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NewOperationsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the NewScEvents method.
+        type NewScEventsStream: futures_core::Stream<
+                Item = std::result::Result<super::NewScEventsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// New smart contract execution events, filtered by emitter, caller, operation and status
+        async fn new_sc_events(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::NewScEventsRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::NewScEventsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SendBlocks method.
+        type SendBlocksStream: futures_core::Stream<
+                Item = std::result::Result<super::SendBlocksResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Send blocks
+        async fn send_blocks(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::SendBlocksRequest>>,
+        ) -> std::result::Result<tonic::Response<Self::SendBlocksStream>, tonic::Status>;
+        /// Server streaming response type for the SendEndorsements method.
+        type SendEndorsementsStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::SendEndorsementsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Send endorsements
+        async fn send_endorsements(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::SendEndorsementsRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SendEndorsementsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SendOperations method.
+        type SendOperationsStream: futures_core::Stream<
+                Item = std::result::Result<super::SendOperationsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Send operations
+        async fn send_operations(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::SendOperationsRequest>>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SendOperationsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the TransactionsThroughput method.
+        type TransactionsThroughputStream: futures_core::Stream<
+                Item = std::result::Result<
+                    super::TransactionsThroughputResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Transactions throughput per second
+        async fn transactions_throughput(
+            &self,
+            request: tonic::Request<
+                tonic::Streaming<super::TransactionsThroughputRequest>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<Self::TransactionsThroughputStream>,
+            tonic::Status,
+        >;
+    }
+    /// Massa gRPC service
+    #[derive(Debug)]
+    pub struct MassaServiceServer<T: MassaService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: MassaService> MassaServiceServer<T> {
         pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
+            Self::from_arc(Arc::new(inner))
         }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
         }
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> MassaServiceClient<InterceptedService<T, F>>
+        ) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
         {
-            MassaServiceClient::new(InterceptedService::new(inner, interceptor))
+            InterceptedService::new(Self::new(inner), interceptor)
         }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
+        /// Enable decompressing requests with the given encoding.
         #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
             self
         }
-        /// Enable decompressing responses.
+        /// Compress responses with the given encoding, if the client supports it.
         #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
             self
         }
         /// Limits the maximum size of a decoded message.
@@ -853,598 +4148,1623 @@ pub mod massa_service_client {
         /// Default: `4MB`
         #[must_use]
         pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
+            self.max_decoding_message_size = Some(limit);
             self
         }
-        /// Get blocks by slots
-        pub async fn get_blocks_by_slots(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetBlocksBySlotsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetBlocksBySlotsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetBlocksBySlots",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("massa.api.v1.MassaService", "GetBlocksBySlots"),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        /// Get datastore entries
-        pub async fn get_datastore_entries(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetDatastoreEntriesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetDatastoreEntriesResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetDatastoreEntries",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("massa.api.v1.MassaService", "GetDatastoreEntries"),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        /// Get next block best parents
-        pub async fn get_next_block_best_parents(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetNextBlockBestParentsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetNextBlockBestParentsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetNextBlockBestParents",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "massa.api.v1.MassaService",
-                        "GetNextBlockBestParents",
-                    ),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        /// Get selector draws
-        pub async fn get_selector_draws(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetSelectorDrawsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetSelectorDrawsResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetSelectorDraws",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("massa.api.v1.MassaService", "GetSelectorDraws"),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        /// Get transactions throughput
-        pub async fn get_transactions_throughput(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetTransactionsThroughputRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetTransactionsThroughputResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetTransactionsThroughput",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "massa.api.v1.MassaService",
-                        "GetTransactionsThroughput",
-                    ),
-                );
-            self.inner.unary(req, path, codec).await
-        }
-        /// Get node version
-        pub async fn get_version(
-            &mut self,
-            request: impl tonic::IntoRequest<super::GetVersionRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetVersionResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/GetVersion",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "GetVersion"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// New received and produced blocks
-        pub async fn new_blocks(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::NewBlocksRequest>,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NewBlocksResponse>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/NewBlocks",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewBlocks"));
-            self.inner.streaming(req, path, codec).await
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MassaServiceServer<T>
+    where
+        T: MassaService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/massa.api.v1.MassaService/EstimateOperation" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateOperationSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::EstimateOperationRequest>
+                    for EstimateOperationSvc<T> {
+                        type Response = super::EstimateOperationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EstimateOperationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).estimate_operation(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EstimateOperationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/ExecuteReadOnlyBytecode" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecuteReadOnlyBytecodeSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::ExecuteReadOnlyBytecodeRequest>
+                    for ExecuteReadOnlyBytecodeSvc<T> {
+                        type Response = super::ExecuteReadOnlyBytecodeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExecuteReadOnlyBytecodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).execute_read_only_bytecode(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExecuteReadOnlyBytecodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/ExecuteReadOnlyCall" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecuteReadOnlyCallSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::ExecuteReadOnlyCallRequest>
+                    for ExecuteReadOnlyCallSvc<T> {
+                        type Response = super::ExecuteReadOnlyCallResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExecuteReadOnlyCallRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).execute_read_only_call(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExecuteReadOnlyCallSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetAddresses" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAddressesSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetAddressesRequest>
+                    for GetAddressesSvc<T> {
+                        type Response = super::GetAddressesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAddressesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_addresses(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetAddressesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetBlocksRequest>
+                    for GetBlocksSvc<T> {
+                        type Response = super::GetBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_blocks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetBlocksBySlots" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlocksBySlotsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetBlocksBySlotsRequest>
+                    for GetBlocksBySlotsSvc<T> {
+                        type Response = super::GetBlocksBySlotsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlocksBySlotsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blocks_by_slots(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlocksBySlotsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetCliques" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCliquesSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetCliquesRequest>
+                    for GetCliquesSvc<T> {
+                        type Response = super::GetCliquesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCliquesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_cliques(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCliquesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetDatastoreEntries" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDatastoreEntriesSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetDatastoreEntriesRequest>
+                    for GetDatastoreEntriesSvc<T> {
+                        type Response = super::GetDatastoreEntriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetDatastoreEntriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_datastore_entries(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetDatastoreEntriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetEndorsements" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetEndorsementsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetEndorsementsRequest>
+                    for GetEndorsementsSvc<T> {
+                        type Response = super::GetEndorsementsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetEndorsementsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_endorsements(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetEndorsementsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetGraphInterval" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetGraphIntervalSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetGraphIntervalRequest>
+                    for GetGraphIntervalSvc<T> {
+                        type Response = super::GetGraphIntervalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetGraphIntervalRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_graph_interval(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetGraphIntervalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetLedgerProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLedgerProofSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetLedgerProofRequest>
+                    for GetLedgerProofSvc<T> {
+                        type Response = super::GetLedgerProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLedgerProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_ledger_proof(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLedgerProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetNextBlockBestParents" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNextBlockBestParentsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetNextBlockBestParentsRequest>
+                    for GetNextBlockBestParentsSvc<T> {
+                        type Response = super::GetNextBlockBestParentsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetNextBlockBestParentsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_next_block_best_parents(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNextBlockBestParentsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetOperationTrace" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOperationTraceSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetOperationTraceRequest>
+                    for GetOperationTraceSvc<T> {
+                        type Response = super::GetOperationTraceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOperationTraceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_operation_trace(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOperationTraceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetOperations" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOperationsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetOperationsRequest>
+                    for GetOperationsSvc<T> {
+                        type Response = super::GetOperationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOperationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_operations(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOperationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetOperationsByAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOperationsByAddressSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetOperationsByAddressRequest>
+                    for GetOperationsByAddressSvc<T> {
+                        type Response = super::GetOperationsByAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOperationsByAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_operations_by_address(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetOperationsByAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetScExecutionEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetScExecutionEventsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetScExecutionEventsRequest>
+                    for GetScExecutionEventsSvc<T> {
+                        type Response = super::GetScExecutionEventsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetScExecutionEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_sc_execution_events(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetScExecutionEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetSelectorDraws" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSelectorDrawsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetSelectorDrawsRequest>
+                    for GetSelectorDrawsSvc<T> {
+                        type Response = super::GetSelectorDrawsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSelectorDrawsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_selector_draws(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetSelectorDrawsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetStakers" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStakersSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetStakersRequest>
+                    for GetStakersSvc<T> {
+                        type Response = super::GetStakersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStakersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_stakers(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStakersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStatusSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetStatusRequest>
+                    for GetStatusSvc<T> {
+                        type Response = super::GetStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_status(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetTransactionsThroughput" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransactionsThroughputSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<
+                        super::GetTransactionsThroughputRequest,
+                    > for GetTransactionsThroughputSvc<T> {
+                        type Response = super::GetTransactionsThroughputResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetTransactionsThroughputRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_transactions_throughput(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTransactionsThroughputSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/GetVersion" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetVersionSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::UnaryService<super::GetVersionRequest>
+                    for GetVersionSvc<T> {
+                        type Response = super::GetVersionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetVersionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_version(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetVersionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/NewBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct NewBlocksSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::NewBlocksRequest>
+                    for NewBlocksSvc<T> {
+                        type Response = super::NewBlocksResponse;
+                        type ResponseStream = T::NewBlocksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::NewBlocksRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).new_blocks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NewBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/NewBlocksHeaders" => {
+                    #[allow(non_camel_case_types)]
+                    struct NewBlocksHeadersSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::NewBlocksHeadersRequest>
+                    for NewBlocksHeadersSvc<T> {
+                        type Response = super::NewBlocksHeadersResponse;
+                        type ResponseStream = T::NewBlocksHeadersStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::NewBlocksHeadersRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).new_blocks_headers(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NewBlocksHeadersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/NewFilledBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct NewFilledBlocksSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::NewFilledBlocksRequest>
+                    for NewFilledBlocksSvc<T> {
+                        type Response = super::NewFilledBlocksResponse;
+                        type ResponseStream = T::NewFilledBlocksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::NewFilledBlocksRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).new_filled_blocks(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NewFilledBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/NewOperations" => {
+                    #[allow(non_camel_case_types)]
+                    struct NewOperationsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::NewOperationsRequest>
+                    for NewOperationsSvc<T> {
+                        type Response = super::NewOperationsResponse;
+                        type ResponseStream = T::NewOperationsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::NewOperationsRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).new_operations(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NewOperationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/NewScEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct NewScEventsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::NewScEventsRequest>
+                    for NewScEventsSvc<T> {
+                        type Response = super::NewScEventsResponse;
+                        type ResponseStream = T::NewScEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::NewScEventsRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).new_sc_events(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NewScEventsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/SendBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendBlocksSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::SendBlocksRequest>
+                    for SendBlocksSvc<T> {
+                        type Response = super::SendBlocksResponse;
+                        type ResponseStream = T::SendBlocksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::SendBlocksRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).send_blocks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SendBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/SendEndorsements" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendEndorsementsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::SendEndorsementsRequest>
+                    for SendEndorsementsSvc<T> {
+                        type Response = super::SendEndorsementsResponse;
+                        type ResponseStream = T::SendEndorsementsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::SendEndorsementsRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).send_endorsements(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SendEndorsementsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/SendOperations" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendOperationsSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<super::SendOperationsRequest>
+                    for SendOperationsSvc<T> {
+                        type Response = super::SendOperationsResponse;
+                        type ResponseStream = T::SendOperationsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::SendOperationsRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).send_operations(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SendOperationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaService/TransactionsThroughput" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionsThroughputSvc<T: MassaService>(pub Arc<T>);
+                    impl<
+                        T: MassaService,
+                    > tonic::server::StreamingService<
+                        super::TransactionsThroughputRequest,
+                    > for TransactionsThroughputSvc<T> {
+                        type Response = super::TransactionsThroughputResponse;
+                        type ResponseStream = T::TransactionsThroughputStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::TransactionsThroughputRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).transactions_throughput(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TransactionsThroughputSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
         }
-        /// New received and produced blocks headers
-        pub async fn new_blocks_headers(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::NewBlocksHeadersRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NewBlocksHeadersResponse>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/NewBlocksHeaders",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("massa.api.v1.MassaService", "NewBlocksHeaders"),
-                );
-            self.inner.streaming(req, path, codec).await
+    }
+    impl<T: MassaService> Clone for MassaServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
         }
-        /// New received and produced blocks with operations
-        pub async fn new_filled_blocks(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::NewFilledBlocksRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NewFilledBlocksResponse>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/NewFilledBlocks",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewFilledBlocks"));
-            self.inner.streaming(req, path, codec).await
+    }
+    impl<T: MassaService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
         }
-        /// New received and produced perations
-        pub async fn new_operations(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::NewOperationsRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NewOperationsResponse>>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/NewOperations",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "NewOperations"));
-            self.inner.streaming(req, path, codec).await
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
         }
-        /// Send blocks
-        pub async fn send_blocks(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<Message = super::SendBlocksRequest>,
+    }
+    impl<T: MassaService> tonic::server::NamedService for MassaServiceServer<T> {
+        const NAME: &'static str = "massa.api.v1.MassaService";
+    }
+}
+/// Generated server implementations.
+pub mod massa_private_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MassaPrivateServiceServer.
+    #[async_trait]
+    pub trait MassaPrivateService: Send + Sync + 'static {
+        /// Add staking secret keys to the node wallet
+        async fn add_staking_secret_keys(
+            &self,
+            request: tonic::Request<super::AddStakingSecretKeysRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::SendBlocksResponse>>,
+            tonic::Response<super::AddStakingSecretKeysResponse>,
             tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/SendBlocks",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "SendBlocks"));
-            self.inner.streaming(req, path, codec).await
-        }
-        /// Send endorsements
-        pub async fn send_endorsements(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::SendEndorsementsRequest,
-            >,
+        >;
+        /// Add IP addresses to the bootstrap blacklist
+        async fn add_to_bootstrap_blacklist(
+            &self,
+            request: tonic::Request<super::AddToBootstrapBlacklistRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::SendEndorsementsResponse>>,
+            tonic::Response<super::AddToBootstrapBlacklistResponse>,
             tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/SendEndorsements",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("massa.api.v1.MassaService", "SendEndorsements"),
-                );
-            self.inner.streaming(req, path, codec).await
-        }
-        /// Send operations
-        pub async fn send_operations(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::SendOperationsRequest,
-            >,
+        >;
+        /// Add IP addresses to the bootstrap whitelist
+        async fn add_to_bootstrap_whitelist(
+            &self,
+            request: tonic::Request<super::AddToBootstrapWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::SendOperationsResponse>>,
+            tonic::Response<super::AddToBootstrapWhitelistResponse>,
             tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/SendOperations",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("massa.api.v1.MassaService", "SendOperations"));
-            self.inner.streaming(req, path, codec).await
-        }
-        /// Transactions throughput per second
-        pub async fn transactions_throughput(
-            &mut self,
-            request: impl tonic::IntoStreamingRequest<
-                Message = super::TransactionsThroughputRequest,
-            >,
+        >;
+        /// Add IP addresses to the peers whitelist
+        async fn add_to_peers_whitelist(
+            &self,
+            request: tonic::Request<super::AddToPeersWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<
-                tonic::codec::Streaming<super::TransactionsThroughputResponse>,
-            >,
+            tonic::Response<super::AddToPeersWhitelistResponse>,
             tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/massa.api.v1.MassaService/TransactionsThroughput",
-            );
-            let mut req = request.into_streaming_request();
-            req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "massa.api.v1.MassaService",
-                        "TransactionsThroughput",
-                    ),
-                );
-            self.inner.streaming(req, path, codec).await
-        }
-    }
-}
-/// Generated server implementations.
-pub mod massa_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with MassaServiceServer.
-    #[async_trait]
-    pub trait MassaService: Send + Sync + 'static {
-        /// Get blocks by slots
-        async fn get_blocks_by_slots(
+        >;
+        /// Allow everyone to bootstrap from the node by removing the bootstrap whitelist
+        async fn allow_everyone_to_bootstrap(
+            &self,
+            request: tonic::Request<super::AllowEveryoneToBootstrapRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AllowEveryoneToBootstrapResponse>,
+            tonic::Status,
+        >;
+        /// Ban nodes by ids
+        async fn ban_nodes_by_ids(
             &self,
-            request: tonic::Request<super::GetBlocksBySlotsRequest>,
+            request: tonic::Request<super::BanNodesByIdsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetBlocksBySlotsResponse>,
+            tonic::Response<super::BanNodesByIdsResponse>,
             tonic::Status,
         >;
-        /// Get datastore entries
-        async fn get_datastore_entries(
+        /// Ban nodes by IP addresses
+        async fn ban_nodes_by_ips(
             &self,
-            request: tonic::Request<super::GetDatastoreEntriesRequest>,
+            request: tonic::Request<super::BanNodesByIpsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetDatastoreEntriesResponse>,
+            tonic::Response<super::BanNodesByIpsResponse>,
             tonic::Status,
         >;
-        /// Get next block best parents
-        async fn get_next_block_best_parents(
+        /// Get the bootstrap blacklist
+        async fn get_bootstrap_blacklist(
             &self,
-            request: tonic::Request<super::GetNextBlockBestParentsRequest>,
+            request: tonic::Request<super::GetBootstrapBlacklistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetNextBlockBestParentsResponse>,
+            tonic::Response<super::GetBootstrapBlacklistResponse>,
             tonic::Status,
         >;
-        /// Get selector draws
-        async fn get_selector_draws(
+        /// Get the bootstrap whitelist
+        async fn get_bootstrap_whitelist(
             &self,
-            request: tonic::Request<super::GetSelectorDrawsRequest>,
+            request: tonic::Request<super::GetBootstrapWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetSelectorDrawsResponse>,
+            tonic::Response<super::GetBootstrapWhitelistResponse>,
             tonic::Status,
         >;
-        /// Get transactions throughput
-        async fn get_transactions_throughput(
+        /// Get the peers whitelist
+        async fn get_peers_whitelist(
             &self,
-            request: tonic::Request<super::GetTransactionsThroughputRequest>,
+            request: tonic::Request<super::GetPeersWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetTransactionsThroughputResponse>,
+            tonic::Response<super::GetPeersWhitelistResponse>,
             tonic::Status,
         >;
-        /// Get node version
-        async fn get_version(
+        /// Get the staking addresses of the node wallet
+        async fn get_staking_addresses(
             &self,
-            request: tonic::Request<super::GetVersionRequest>,
+            request: tonic::Request<super::GetStakingAddressesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetVersionResponse>,
+            tonic::Response<super::GetStakingAddressesResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the NewBlocks method.
-        type NewBlocksStream: futures_core::Stream<
-                Item = std::result::Result<super::NewBlocksResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// New received and produced blocks
-        async fn new_blocks(
+        /// Remove IP addresses from the bootstrap blacklist
+        async fn remove_from_bootstrap_blacklist(
             &self,
-            request: tonic::Request<tonic::Streaming<super::NewBlocksRequest>>,
-        ) -> std::result::Result<tonic::Response<Self::NewBlocksStream>, tonic::Status>;
-        /// Server streaming response type for the NewBlocksHeaders method.
-        type NewBlocksHeadersStream: futures_core::Stream<
-                Item = std::result::Result<
-                    super::NewBlocksHeadersResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// New received and produced blocks headers
-        async fn new_blocks_headers(
+            request: tonic::Request<super::RemoveFromBootstrapBlacklistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromBootstrapBlacklistResponse>,
+            tonic::Status,
+        >;
+        /// Remove IP addresses from the bootstrap whitelist
+        async fn remove_from_bootstrap_whitelist(
             &self,
-            request: tonic::Request<tonic::Streaming<super::NewBlocksHeadersRequest>>,
+            request: tonic::Request<super::RemoveFromBootstrapWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::NewBlocksHeadersStream>,
+            tonic::Response<super::RemoveFromBootstrapWhitelistResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the NewFilledBlocks method.
-        type NewFilledBlocksStream: futures_core::Stream<
-                Item = std::result::Result<super::NewFilledBlocksResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// New received and produced blocks with operations
-        async fn new_filled_blocks(
+        /// Remove IP addresses from the peers whitelist
+        async fn remove_from_peers_whitelist(
             &self,
-            request: tonic::Request<tonic::Streaming<super::NewFilledBlocksRequest>>,
+            request: tonic::Request<super::RemoveFromPeersWhitelistRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::NewFilledBlocksStream>,
+            tonic::Response<super::RemoveFromPeersWhitelistResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the NewOperations method.
-        type NewOperationsStream: futures_core::Stream<
-                Item = std::result::Result<super::NewOperationsResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// New received and produced perations
-        async fn new_operations(
+        /// Remove staking addresses from the node wallet
+        async fn remove_staking_addresses(
             &self,
-            request: tonic::Request<tonic::Streaming<super::NewOperationsRequest>>,
+            request: tonic::Request<super::RemoveStakingAddressesRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::NewOperationsStream>,
+            tonic::Response<super::RemoveStakingAddressesResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the SendBlocks method.
-        type SendBlocksStream: futures_core::Stream<
-                Item = std::result::Result<super::SendBlocksResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Send blocks
-        async fn send_blocks(
+        /// Sign a message with the node key
+        async fn sign_message(
             &self,
-            request: tonic::Request<tonic::Streaming<super::SendBlocksRequest>>,
-        ) -> std::result::Result<tonic::Response<Self::SendBlocksStream>, tonic::Status>;
-        /// Server streaming response type for the SendEndorsements method.
-        type SendEndorsementsStream: futures_core::Stream<
-                Item = std::result::Result<
-                    super::SendEndorsementsResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// Send endorsements
-        async fn send_endorsements(
+            request: tonic::Request<super::SignMessageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignMessageResponse>,
+            tonic::Status,
+        >;
+        /// Gracefully stop the node
+        async fn stop_node(
             &self,
-            request: tonic::Request<tonic::Streaming<super::SendEndorsementsRequest>>,
+            request: tonic::Request<super::StopNodeRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::SendEndorsementsStream>,
+            tonic::Response<super::StopNodeResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the SendOperations method.
-        type SendOperationsStream: futures_core::Stream<
-                Item = std::result::Result<super::SendOperationsResponse, tonic::Status>,
-            >
-            + Send
-            + 'static;
-        /// Send operations
-        async fn send_operations(
+        /// Unban nodes by ids
+        async fn unban_nodes_by_ids(
             &self,
-            request: tonic::Request<tonic::Streaming<super::SendOperationsRequest>>,
+            request: tonic::Request<super::UnbanNodesByIdsRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::SendOperationsStream>,
+            tonic::Response<super::UnbanNodesByIdsResponse>,
             tonic::Status,
         >;
-        /// Server streaming response type for the TransactionsThroughput method.
-        type TransactionsThroughputStream: futures_core::Stream<
-                Item = std::result::Result<
-                    super::TransactionsThroughputResponse,
-                    tonic::Status,
-                >,
-            >
-            + Send
-            + 'static;
-        /// Transactions throughput per second
-        async fn transactions_throughput(
+        /// Unban nodes by IP addresses
+        async fn unban_nodes_by_ips(
             &self,
-            request: tonic::Request<
-                tonic::Streaming<super::TransactionsThroughputRequest>,
-            >,
+            request: tonic::Request<super::UnbanNodesByIpsRequest>,
         ) -> std::result::Result<
-            tonic::Response<Self::TransactionsThroughputStream>,
+            tonic::Response<super::UnbanNodesByIpsResponse>,
             tonic::Status,
         >;
     }
-    /// Massa gRPC service
+    /// Massa private gRPC service, to manage the node
     #[derive(Debug)]
-    pub struct MassaServiceServer<T: MassaService> {
+    pub struct MassaPrivateServiceServer<T: MassaPrivateService> {
         inner: _Inner<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
@@ -1452,7 +5772,7 @@ pub mod massa_service_server {
         max_encoding_message_size: Option<usize>,
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: MassaService> MassaServiceServer<T> {
+    impl<T: MassaPrivateService> MassaPrivateServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -1504,9 +5824,9 @@ pub mod massa_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for MassaServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MassaPrivateServiceServer<T>
     where
-        T: MassaService,
+        T: MassaPrivateService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -1522,25 +5842,207 @@ pub mod massa_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/massa.api.v1.MassaService/GetBlocksBySlots" => {
+                "/massa.api.v1.MassaPrivateService/AddStakingSecretKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddStakingSecretKeysSvc<T: MassaPrivateService>(pub Arc<T>);
+                    impl<
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::AddStakingSecretKeysRequest>
+                    for AddStakingSecretKeysSvc<T> {
+                        type Response = super::AddStakingSecretKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddStakingSecretKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_staking_secret_keys(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddStakingSecretKeysSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaPrivateService/AddToBootstrapBlacklist" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddToBootstrapBlacklistSvc<T: MassaPrivateService>(pub Arc<T>);
+                    impl<
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::AddToBootstrapBlacklistRequest>
+                    for AddToBootstrapBlacklistSvc<T> {
+                        type Response = super::AddToBootstrapBlacklistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddToBootstrapBlacklistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_to_bootstrap_blacklist(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddToBootstrapBlacklistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaPrivateService/AddToBootstrapWhitelist" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddToBootstrapWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
+                    impl<
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::AddToBootstrapWhitelistRequest>
+                    for AddToBootstrapWhitelistSvc<T> {
+                        type Response = super::AddToBootstrapWhitelistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddToBootstrapWhitelistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).add_to_bootstrap_whitelist(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddToBootstrapWhitelistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaPrivateService/AddToPeersWhitelist" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddToPeersWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
+                    impl<
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::AddToPeersWhitelistRequest>
+                    for AddToPeersWhitelistSvc<T> {
+                        type Response = super::AddToPeersWhitelistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddToPeersWhitelistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).add_to_peers_whitelist(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddToPeersWhitelistSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaPrivateService/AllowEveryoneToBootstrap" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlocksBySlotsSvc<T: MassaService>(pub Arc<T>);
+                    struct AllowEveryoneToBootstrapSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<super::GetBlocksBySlotsRequest>
-                    for GetBlocksBySlotsSvc<T> {
-                        type Response = super::GetBlocksBySlotsResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::AllowEveryoneToBootstrapRequest>
+                    for AllowEveryoneToBootstrapSvc<T> {
+                        type Response = super::AllowEveryoneToBootstrapResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetBlocksBySlotsRequest>,
+                            request: tonic::Request<super::AllowEveryoneToBootstrapRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blocks_by_slots(request).await
+                                (*inner).allow_everyone_to_bootstrap(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1552,7 +6054,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlocksBySlotsSvc(inner);
+                        let method = AllowEveryoneToBootstrapSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1568,26 +6070,24 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/GetDatastoreEntries" => {
+                "/massa.api.v1.MassaPrivateService/BanNodesByIds" => {
                     #[allow(non_camel_case_types)]
-                    struct GetDatastoreEntriesSvc<T: MassaService>(pub Arc<T>);
+                    struct BanNodesByIdsSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<super::GetDatastoreEntriesRequest>
-                    for GetDatastoreEntriesSvc<T> {
-                        type Response = super::GetDatastoreEntriesResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::BanNodesByIdsRequest>
+                    for BanNodesByIdsSvc<T> {
+                        type Response = super::BanNodesByIdsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetDatastoreEntriesRequest>,
+                            request: tonic::Request<super::BanNodesByIdsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).get_datastore_entries(request).await
-                            };
+                            let fut = async move { (*inner).ban_nodes_by_ids(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1598,7 +6098,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetDatastoreEntriesSvc(inner);
+                        let method = BanNodesByIdsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1614,28 +6114,24 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/GetNextBlockBestParents" => {
+                "/massa.api.v1.MassaPrivateService/BanNodesByIps" => {
                     #[allow(non_camel_case_types)]
-                    struct GetNextBlockBestParentsSvc<T: MassaService>(pub Arc<T>);
+                    struct BanNodesByIpsSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<super::GetNextBlockBestParentsRequest>
-                    for GetNextBlockBestParentsSvc<T> {
-                        type Response = super::GetNextBlockBestParentsResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::BanNodesByIpsRequest>
+                    for BanNodesByIpsSvc<T> {
+                        type Response = super::BanNodesByIpsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::GetNextBlockBestParentsRequest,
-                            >,
+                            request: tonic::Request<super::BanNodesByIpsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).get_next_block_best_parents(request).await
-                            };
+                            let fut = async move { (*inner).ban_nodes_by_ips(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1646,7 +6142,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetNextBlockBestParentsSvc(inner);
+                        let method = BanNodesByIpsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1662,25 +6158,25 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/GetSelectorDraws" => {
+                "/massa.api.v1.MassaPrivateService/GetBootstrapBlacklist" => {
                     #[allow(non_camel_case_types)]
-                    struct GetSelectorDrawsSvc<T: MassaService>(pub Arc<T>);
+                    struct GetBootstrapBlacklistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<super::GetSelectorDrawsRequest>
-                    for GetSelectorDrawsSvc<T> {
-                        type Response = super::GetSelectorDrawsResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::GetBootstrapBlacklistRequest>
+                    for GetBootstrapBlacklistSvc<T> {
+                        type Response = super::GetBootstrapBlacklistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetSelectorDrawsRequest>,
+                            request: tonic::Request<super::GetBootstrapBlacklistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_selector_draws(request).await
+                                (*inner).get_bootstrap_blacklist(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1692,7 +6188,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetSelectorDrawsSvc(inner);
+                        let method = GetBootstrapBlacklistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1708,28 +6204,25 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/GetTransactionsThroughput" => {
+                "/massa.api.v1.MassaPrivateService/GetBootstrapWhitelist" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTransactionsThroughputSvc<T: MassaService>(pub Arc<T>);
+                    struct GetBootstrapWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<
-                        super::GetTransactionsThroughputRequest,
-                    > for GetTransactionsThroughputSvc<T> {
-                        type Response = super::GetTransactionsThroughputResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::GetBootstrapWhitelistRequest>
+                    for GetBootstrapWhitelistSvc<T> {
+                        type Response = super::GetBootstrapWhitelistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::GetTransactionsThroughputRequest,
-                            >,
+                            request: tonic::Request<super::GetBootstrapWhitelistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_transactions_throughput(request).await
+                                (*inner).get_bootstrap_whitelist(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1741,7 +6234,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTransactionsThroughputSvc(inner);
+                        let method = GetBootstrapWhitelistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1757,24 +6250,24 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/GetVersion" => {
+                "/massa.api.v1.MassaPrivateService/GetPeersWhitelist" => {
                     #[allow(non_camel_case_types)]
-                    struct GetVersionSvc<T: MassaService>(pub Arc<T>);
+                    struct GetPeersWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::UnaryService<super::GetVersionRequest>
-                    for GetVersionSvc<T> {
-                        type Response = super::GetVersionResponse;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::GetPeersWhitelistRequest>
+                    for GetPeersWhitelistSvc<T> {
+                        type Response = super::GetPeersWhitelistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetVersionRequest>,
+                            request: tonic::Request<super::GetPeersWhitelistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_version(request).await };
+                            let fut = async move { (*inner).get_peers_whitelist(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1785,7 +6278,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetVersionSvc(inner);
+                        let method = GetPeersWhitelistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1801,27 +6294,24 @@ pub mod massa_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/NewBlocks" => {
+                "/massa.api.v1.MassaPrivateService/GetStakingAddresses" => {
                     #[allow(non_camel_case_types)]
-                    struct NewBlocksSvc<T: MassaService>(pub Arc<T>);
+                    struct GetStakingAddressesSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::NewBlocksRequest>
-                    for NewBlocksSvc<T> {
-                        type Response = super::NewBlocksResponse;
-                        type ResponseStream = T::NewBlocksStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::GetStakingAddressesRequest>
+                    for GetStakingAddressesSvc<T> {
+                        type Response = super::GetStakingAddressesResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::NewBlocksRequest>,
-                            >,
+                            request: tonic::Request<super::GetStakingAddressesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).new_blocks(request).await };
+                            let fut = async move { (*inner).get_staking_addresses(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1832,7 +6322,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NewBlocksSvc(inner);
+                        let method = GetStakingAddressesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1843,33 +6333,30 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/NewBlocksHeaders" => {
+                "/massa.api.v1.MassaPrivateService/RemoveFromBootstrapBlacklist" => {
                     #[allow(non_camel_case_types)]
-                    struct NewBlocksHeadersSvc<T: MassaService>(pub Arc<T>);
+                    struct RemoveFromBootstrapBlacklistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::NewBlocksHeadersRequest>
-                    for NewBlocksHeadersSvc<T> {
-                        type Response = super::NewBlocksHeadersResponse;
-                        type ResponseStream = T::NewBlocksHeadersStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::RemoveFromBootstrapBlacklistRequest>
+                    for RemoveFromBootstrapBlacklistSvc<T> {
+                        type Response = super::RemoveFromBootstrapBlacklistResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::NewBlocksHeadersRequest>,
-                            >,
+                            request: tonic::Request<super::RemoveFromBootstrapBlacklistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).new_blocks_headers(request).await
+                                (*inner).remove_from_bootstrap_blacklist(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1881,7 +6368,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NewBlocksHeadersSvc(inner);
+                        let method = RemoveFromBootstrapBlacklistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1892,33 +6379,30 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/NewFilledBlocks" => {
+                "/massa.api.v1.MassaPrivateService/RemoveFromBootstrapWhitelist" => {
                     #[allow(non_camel_case_types)]
-                    struct NewFilledBlocksSvc<T: MassaService>(pub Arc<T>);
+                    struct RemoveFromBootstrapWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::NewFilledBlocksRequest>
-                    for NewFilledBlocksSvc<T> {
-                        type Response = super::NewFilledBlocksResponse;
-                        type ResponseStream = T::NewFilledBlocksStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::RemoveFromBootstrapWhitelistRequest>
+                    for RemoveFromBootstrapWhitelistSvc<T> {
+                        type Response = super::RemoveFromBootstrapWhitelistResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::NewFilledBlocksRequest>,
-                            >,
+                            request: tonic::Request<super::RemoveFromBootstrapWhitelistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).new_filled_blocks(request).await
+                                (*inner).remove_from_bootstrap_whitelist(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1930,7 +6414,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NewFilledBlocksSvc(inner);
+                        let method = RemoveFromBootstrapWhitelistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1941,33 +6425,30 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/NewOperations" => {
+                "/massa.api.v1.MassaPrivateService/RemoveFromPeersWhitelist" => {
                     #[allow(non_camel_case_types)]
-                    struct NewOperationsSvc<T: MassaService>(pub Arc<T>);
+                    struct RemoveFromPeersWhitelistSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::NewOperationsRequest>
-                    for NewOperationsSvc<T> {
-                        type Response = super::NewOperationsResponse;
-                        type ResponseStream = T::NewOperationsStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::RemoveFromPeersWhitelistRequest>
+                    for RemoveFromPeersWhitelistSvc<T> {
+                        type Response = super::RemoveFromPeersWhitelistResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::NewOperationsRequest>,
-                            >,
+                            request: tonic::Request<super::RemoveFromPeersWhitelistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).new_operations(request).await
+                                (*inner).remove_from_peers_whitelist(request).await
                             };
                             Box::pin(fut)
                         }
@@ -1979,7 +6460,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NewOperationsSvc(inner);
+                        let method = RemoveFromPeersWhitelistSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1990,32 +6471,31 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/SendBlocks" => {
+                "/massa.api.v1.MassaPrivateService/RemoveStakingAddresses" => {
                     #[allow(non_camel_case_types)]
-                    struct SendBlocksSvc<T: MassaService>(pub Arc<T>);
+                    struct RemoveStakingAddressesSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::SendBlocksRequest>
-                    for SendBlocksSvc<T> {
-                        type Response = super::SendBlocksResponse;
-                        type ResponseStream = T::SendBlocksStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::RemoveStakingAddressesRequest>
+                    for RemoveStakingAddressesSvc<T> {
+                        type Response = super::RemoveStakingAddressesResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::SendBlocksRequest>,
-                            >,
+                            request: tonic::Request<super::RemoveStakingAddressesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).send_blocks(request).await };
+                            let fut = async move {
+                                (*inner).remove_staking_addresses(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -2026,7 +6506,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SendBlocksSvc(inner);
+                        let method = RemoveStakingAddressesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2037,34 +6517,29 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/SendEndorsements" => {
+                "/massa.api.v1.MassaPrivateService/SignMessage" => {
                     #[allow(non_camel_case_types)]
-                    struct SendEndorsementsSvc<T: MassaService>(pub Arc<T>);
+                    struct SignMessageSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::SendEndorsementsRequest>
-                    for SendEndorsementsSvc<T> {
-                        type Response = super::SendEndorsementsResponse;
-                        type ResponseStream = T::SendEndorsementsStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::SignMessageRequest>
+                    for SignMessageSvc<T> {
+                        type Response = super::SignMessageResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::SendEndorsementsRequest>,
-                            >,
+                            request: tonic::Request<super::SignMessageRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).send_endorsements(request).await
-                            };
+                            let fut = async move { (*inner).sign_message(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2075,7 +6550,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SendEndorsementsSvc(inner);
+                        let method = SignMessageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2086,34 +6561,29 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/SendOperations" => {
+                "/massa.api.v1.MassaPrivateService/StopNode" => {
                     #[allow(non_camel_case_types)]
-                    struct SendOperationsSvc<T: MassaService>(pub Arc<T>);
+                    struct StopNodeSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<super::SendOperationsRequest>
-                    for SendOperationsSvc<T> {
-                        type Response = super::SendOperationsResponse;
-                        type ResponseStream = T::SendOperationsStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::StopNodeRequest>
+                    for StopNodeSvc<T> {
+                        type Response = super::StopNodeResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::SendOperationsRequest>,
-                            >,
+                            request: tonic::Request<super::StopNodeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).send_operations(request).await
-                            };
+                            let fut = async move { (*inner).stop_node(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2124,7 +6594,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SendOperationsSvc(inner);
+                        let method = StopNodeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2135,35 +6605,29 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/massa.api.v1.MassaService/TransactionsThroughput" => {
+                "/massa.api.v1.MassaPrivateService/UnbanNodesByIds" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionsThroughputSvc<T: MassaService>(pub Arc<T>);
+                    struct UnbanNodesByIdsSvc<T: MassaPrivateService>(pub Arc<T>);
                     impl<
-                        T: MassaService,
-                    > tonic::server::StreamingService<
-                        super::TransactionsThroughputRequest,
-                    > for TransactionsThroughputSvc<T> {
-                        type Response = super::TransactionsThroughputResponse;
-                        type ResponseStream = T::TransactionsThroughputStream;
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::UnbanNodesByIdsRequest>
+                    for UnbanNodesByIdsSvc<T> {
+                        type Response = super::UnbanNodesByIdsResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                tonic::Streaming<super::TransactionsThroughputRequest>,
-                            >,
+                            request: tonic::Request<super::UnbanNodesByIdsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).transactions_throughput(request).await
-                            };
+                            let fut = async move { (*inner).unban_nodes_by_ids(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2174,7 +6638,7 @@ pub mod massa_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionsThroughputSvc(inner);
+                        let method = UnbanNodesByIdsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2185,7 +6649,51 @@ pub mod massa_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/massa.api.v1.MassaPrivateService/UnbanNodesByIps" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnbanNodesByIpsSvc<T: MassaPrivateService>(pub Arc<T>);
+                    impl<
+                        T: MassaPrivateService,
+                    > tonic::server::UnaryService<super::UnbanNodesByIpsRequest>
+                    for UnbanNodesByIpsSvc<T> {
+                        type Response = super::UnbanNodesByIpsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnbanNodesByIpsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).unban_nodes_by_ips(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnbanNodesByIpsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -2205,7 +6713,7 @@ pub mod massa_service_server {
             }
         }
     }
-    impl<T: MassaService> Clone for MassaServiceServer<T> {
+    impl<T: MassaPrivateService> Clone for MassaPrivateServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2217,7 +6725,7 @@ pub mod massa_service_server {
             }
         }
     }
-    impl<T: MassaService> Clone for _Inner<T> {
+    impl<T: MassaPrivateService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(Arc::clone(&self.0))
         }
@@ -2227,7 +6735,7 @@ pub mod massa_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: MassaService> tonic::server::NamedService for MassaServiceServer<T> {
-        const NAME: &'static str = "massa.api.v1.MassaService";
+    impl<T: MassaPrivateService> tonic::server::NamedService for MassaPrivateServiceServer<T> {
+        const NAME: &'static str = "massa.api.v1.MassaPrivateService";
     }
 }
@@ -67,6 +67,7 @@ pub fn create_block(keypair: &KeyPair) -> SecureShareBlock {
             ],
             operation_merkle_root: Hash::compute_from(&Vec::new()),
             endorsements: Vec::new(),
+            denunciations: Vec::new(),
         },
         BlockHeaderSerializer::new(),
         keypair,
@@ -108,6 +109,7 @@ pub fn create_block_with_operations(
             ],
             operation_merkle_root,
             endorsements: Vec::new(),
+            denunciations: Vec::new(),
         },
         BlockHeaderSerializer::new(),
         keypair,
@@ -145,6 +147,7 @@ pub fn create_block_with_endorsements(
             ],
             operation_merkle_root: Hash::compute_from(&Vec::new()),
             endorsements,
+            denunciations: Vec::new(),
         },
         BlockHeaderSerializer::new(),
         keypair,
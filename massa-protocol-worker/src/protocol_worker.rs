@@ -916,13 +916,19 @@ impl ProtocolWorker {
             };
         }
 
-        // optimized signature verification
-        verify_sigs_batch(
-            &new_operations
-                .iter()
-                .map(|(op_id, op)| (*op_id.get_hash(), op.signature, op.content_creator_pub_key))
-                .collect::<Vec<_>>(),
-        )?;
+        // optimized signature verification,
+        // including the signatures of the members of multi-signature operations
+        let mut signatures = Vec::with_capacity(new_operations.len());
+        for (op_id, op) in new_operations.iter() {
+            signatures.push((*op_id.get_hash(), op.signature, op.content_creator_pub_key));
+            signatures.extend(op.get_multisig_signature_batch().map_err(|err| {
+                ProtocolError::InvalidOperationError(format!(
+                    "Operation {} has invalid multi-signatures: {}",
+                    op_id, err
+                ))
+            })?);
+        }
+        verify_sigs_batch(&signatures)?;
 
         // add to checked operations
         self.checked_operations
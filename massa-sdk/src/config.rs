@@ -20,6 +20,10 @@ pub struct ClientConfig {
     pub max_log_length: u32,
     /// custom headers to pass with every request.
     pub headers: Vec<(String, String)>,
+    /// whether to connect to the node over TLS (`https` / `wss`).
+    pub tls: bool,
+    /// API token sent in the `Authorization` header of the requests to the private API, if any.
+    pub auth_token: Option<String>,
 }
 
 /// Http client settings.
@@ -4,7 +4,7 @@
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
 
-use http::header::HeaderName;
+use http::header::{HeaderName, AUTHORIZATION};
 use jsonrpsee::core::client::{ClientT, IdKind, Subscription, SubscriptionClientT};
 use jsonrpsee::http_client::transport::HttpBackend;
 use jsonrpsee::http_client::HttpClient;
@@ -12,16 +12,20 @@ use jsonrpsee::rpc_params;
 use jsonrpsee::types::error::CallError;
 use jsonrpsee::types::ErrorObject;
 use jsonrpsee::ws_client::{HeaderMap, HeaderValue, WsClient, WsClientBuilder};
-use massa_api_exports::page::PagedVecV2;
+use massa_api_exports::page::{PageRequest, PagedVecV2};
 use massa_api_exports::ApiRequest;
 use massa_api_exports::{
     address::AddressInfo,
     block::{BlockInfo, BlockSummary},
     datastore::{DatastoreEntryInput, DatastoreEntryOutput},
     endorsement::EndorsementInfo,
-    execution::{ExecuteReadOnlyResponse, ReadOnlyBytecodeExecution, ReadOnlyCall},
+    execution::{
+        CallTraceOutput, ExecuteReadOnlyResponse, OperationEstimationInput,
+        OperationEstimationOutput, ReadOnlyBytecodeExecution, ReadOnlyCall,
+    },
+    ledger::{LedgerProofInput, LedgerProofOutput},
     node::NodeStatus,
-    operation::{OperationInfo, OperationInput},
+    operation::{AddressOperationsFilter, OperationInfo, OperationInput},
     TimeInterval,
 };
 use massa_models::secure_share::SecureShare;
@@ -71,10 +75,18 @@ impl Client {
     ) -> Client {
         let public_socket_addr = SocketAddr::new(ip, public_port);
         let private_socket_addr = SocketAddr::new(ip, private_port);
-        let public_url = format!("http://{}", public_socket_addr);
-        let private_url = format!("http://{}", private_socket_addr);
+        let scheme = if http_config.client_config.tls {
+            "https"
+        } else {
+            "http"
+        };
+        let public_url = format!("{}://{}", scheme, public_socket_addr);
+        let private_url = format!("{}://{}", scheme, private_socket_addr);
+        // the API token is only meant for the private API, it is not disclosed to the public one
+        let mut public_config = http_config.clone();
+        public_config.client_config.auth_token = None;
         Client {
-            public: RpcClient::from_url(&public_url, http_config).await,
+            public: RpcClient::from_url(&public_url, &public_config).await,
             private: RpcClient::from_url(&private_url, http_config).await,
         }
     }
@@ -264,6 +276,20 @@ impl RpcClient {
             .await
     }
 
+    /// Returns the operations sent or received by an address, sorted by slot of the including block
+    pub async fn get_operations_by_address(
+        &self,
+        filter: AddressOperationsFilter,
+        page_request: Option<PageRequest>,
+    ) -> RpcResult<Vec<OperationInfo>> {
+        self.http_client
+            .request(
+                "get_operations_by_address",
+                rpc_params![filter, page_request],
+            )
+            .await
+    }
+
     /// Returns endorsement(s) information associated to a given list of endorsement(s) ID(s)
     pub async fn get_endorsements(
         &self,
@@ -291,6 +317,16 @@ impl RpcClient {
             .await
     }
 
+    /// Get the call trace of a final smart contract operation
+    pub async fn get_operation_trace(
+        &self,
+        operation_id: OperationId,
+    ) -> RpcResult<Option<CallTraceOutput>> {
+        self.http_client
+            .request("get_operation_trace", rpc_params![operation_id])
+            .await
+    }
+
     /// Get the block graph within the specified time interval.
     /// Optional parameters: from `<time_start>` (included) and to `<time_end>` (excluded) millisecond timestamp
     pub(crate) async fn _get_graph_interval(
@@ -319,6 +355,16 @@ impl RpcClient {
             .await
     }
 
+    /// Get the final values of ledger sub-entries with their proofs against the final state hash
+    pub async fn get_ledger_proofs(
+        &self,
+        inputs: Vec<LedgerProofInput>,
+    ) -> RpcResult<Vec<LedgerProofOutput>> {
+        self.http_client
+            .request("get_ledger_proofs", rpc_params![inputs])
+            .await
+    }
+
     // User (interaction with the node)
 
     /// Adds operations to pool. Returns operations that were ok and sent to pool.
@@ -364,6 +410,16 @@ impl RpcClient {
                 JsonRpseeError::Custom("missing return value on execute_read_only_call".into())
             })
     }
+
+    /// estimate the costs of an unsigned operation
+    pub async fn estimate_operation(
+        &self,
+        estimation_input: OperationEstimationInput,
+    ) -> RpcResult<OperationEstimationOutput> {
+        self.http_client
+            .request("estimate_operation", rpc_params![estimation_input])
+            .await
+    }
 }
 
 /// Client V2
@@ -400,8 +456,16 @@ impl RpcClientV2 {
         http_config: &HttpConfig,
         ws_config: &WsConfig,
     ) -> RpcClientV2 {
-        let http_url = format!("http://{}", socket_addr);
-        let ws_url = format!("ws://{}", socket_addr);
+        let http_url = if http_config.client_config.tls {
+            format!("https://{}", socket_addr)
+        } else {
+            format!("http://{}", socket_addr)
+        };
+        let ws_url = if ws_config.client_config.tls {
+            format!("wss://{}", socket_addr)
+        } else {
+            format!("ws://{}", socket_addr)
+        };
 
         if http_config.enabled && !ws_config.enabled {
             let http_client = http_client_from_url(&http_url, http_config);
@@ -561,6 +625,29 @@ impl RpcClientV2 {
             .into())
         }
     }
+
+    /// New smart contract events matching the filter.
+    pub async fn subscribe_new_sc_events(
+        &self,
+        filter: EventFilter,
+    ) -> Result<Subscription<SCOutputEvent>, jsonrpsee::core::Error> {
+        if let Some(client) = self.ws_client.as_ref() {
+            client
+                .subscribe(
+                    "subscribe_new_sc_events",
+                    rpc_params![filter],
+                    "unsubscribe_new_sc_events",
+                )
+                .await
+        } else {
+            Err(CallError::Custom(ErrorObject::owned(
+                -32080,
+                "error, no WebSocket client instance found".to_owned(),
+                None::<()>,
+            ))
+            .into())
+        }
+    }
 }
 
 fn http_client_from_url(url: &str, http_config: &HttpConfig) -> HttpClient<HttpBackend> {
@@ -569,7 +656,7 @@ fn http_client_from_url(url: &str, http_config: &HttpConfig) -> HttpClient<HttpB
         .request_timeout(http_config.client_config.request_timeout.to_duration())
         .max_concurrent_requests(http_config.client_config.max_concurrent_requests)
         .id_format(get_id_kind(http_config.client_config.id_kind.as_str()))
-        .set_headers(get_headers(&http_config.client_config.headers));
+        .set_headers(get_headers(&http_config.client_config));
 
     match http_config.client_config.certificate_store.as_str() {
         "Native" => builder = builder.use_native_rustls(),
@@ -591,7 +678,7 @@ where
         .request_timeout(ws_config.client_config.request_timeout.to_duration())
         .max_concurrent_requests(ws_config.client_config.max_concurrent_requests)
         .id_format(get_id_kind(ws_config.client_config.id_kind.as_str()))
-        .set_headers(get_headers(&ws_config.client_config.headers))
+        .set_headers(get_headers(&ws_config.client_config))
         .max_buffer_capacity_per_subscription(ws_config.max_notifs_per_subscription)
         .max_redirections(ws_config.max_redirections);
 
@@ -615,9 +702,9 @@ fn get_id_kind(id_kind: &str) -> IdKind {
     }
 }
 
-fn get_headers(headers: &[(String, String)]) -> HeaderMap {
+fn get_headers(client_config: &ClientConfig) -> HeaderMap {
     let mut headers_map = HeaderMap::new();
-    headers.iter().for_each(|(key, value)| {
+    client_config.headers.iter().for_each(|(key, value)| {
         let header_name = match HeaderName::from_str(key.as_str()) {
             Ok(header_name) => header_name,
             Err(_) => panic!("invalid header name: {:?}", key),
@@ -629,5 +716,13 @@ fn get_headers(headers: &[(String, String)]) -> HeaderMap {
         headers_map.insert(header_name, header_value);
     });
 
+    if let Some(auth_token) = &client_config.auth_token {
+        let header_value = match HeaderValue::from_str(&format!("Bearer {}", auth_token)) {
+            Ok(header_value) => header_value,
+            Err(_) => panic!("invalid API token"),
+        };
+        headers_map.insert(AUTHORIZATION, header_value);
+    }
+
     headers_map
 }
@@ -4,9 +4,14 @@
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
 mod error;
+mod multisig;
 mod signature_impl;
 
 pub use error::MassaSignatureError;
+pub use multisig::{
+    MultiSigPublicKey, MultiSigPublicKeyDeserializer, MultiSignature, MultiSignatureDeserializer,
+    MAX_MULTISIG_PUBLIC_KEYS,
+};
 pub use signature_impl::{
     verify_signature_batch, KeyPair, PublicKey, PublicKeyDeserializer, Signature,
     SignatureDeserializer, PUBLIC_KEY_SIZE_BYTES, SECRET_KEY_BYTES_SIZE, SIGNATURE_SIZE_BYTES,
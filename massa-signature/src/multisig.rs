@@ -0,0 +1,338 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! M-of-N multi-signature scheme built on top of ed25519 signatures
+
+use crate::error::MassaSignatureError;
+use crate::signature_impl::{
+    verify_signature_batch, PublicKey, PublicKeyDeserializer, Signature, SignatureDeserializer,
+    PUBLIC_KEY_SIZE_BYTES, SIGNATURE_SIZE_BYTES,
+};
+use massa_hash::Hash;
+use massa_serialization::Deserializer;
+use nom::error::{context, ContextError, ParseError};
+use nom::multi::length_count;
+use nom::number::complete::u8 as parse_u8;
+use nom::sequence::tuple;
+use nom::{IResult, Parser};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of public keys in a multi-signature public key
+pub const MAX_MULTISIG_PUBLIC_KEYS: usize = 16;
+
+/// Set of public keys of which at least `threshold` must sign
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultiSigPublicKey {
+    /// minimal number of signatures
+    threshold: u8,
+    /// public keys, sorted and without duplicates
+    public_keys: Vec<PublicKey>,
+}
+
+impl MultiSigPublicKey {
+    /// Creates a multi-signature public key requiring `threshold` signatures among `public_keys`.
+    /// Public keys are sorted so that the same set always gives the same key.
+    pub fn new(
+        threshold: u8,
+        mut public_keys: Vec<PublicKey>,
+    ) -> Result<MultiSigPublicKey, MassaSignatureError> {
+        public_keys.sort_unstable();
+        let key_count = public_keys.len();
+        public_keys.dedup();
+        if public_keys.len() != key_count {
+            return Err(MassaSignatureError::MultiSigError(
+                "duplicate public keys".to_string(),
+            ));
+        }
+        if public_keys.len() > MAX_MULTISIG_PUBLIC_KEYS {
+            return Err(MassaSignatureError::MultiSigError(format!(
+                "at most {} public keys are allowed, got {}",
+                MAX_MULTISIG_PUBLIC_KEYS,
+                public_keys.len()
+            )));
+        }
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(MassaSignatureError::MultiSigError(format!(
+                "threshold must be between 1 and {}, got {}",
+                public_keys.len(),
+                threshold
+            )));
+        }
+        Ok(MultiSigPublicKey {
+            threshold,
+            public_keys,
+        })
+    }
+
+    /// Gets the minimal number of signatures
+    pub fn get_threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Gets the sorted public keys
+    pub fn get_public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Gets the index of a public key in the multi-signature public key
+    pub fn get_index(&self, public_key: &PublicKey) -> Option<u8> {
+        self.public_keys
+            .binary_search(public_key)
+            .ok()
+            .map(|index| index as u8)
+    }
+
+    /// Serializes as `threshold | key count | keys`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.public_keys.len() * PUBLIC_KEY_SIZE_BYTES);
+        bytes.push(self.threshold);
+        bytes.push(self.public_keys.len() as u8);
+        for public_key in &self.public_keys {
+            bytes.extend(public_key.to_bytes());
+        }
+        bytes
+    }
+
+    /// Checks that `signatures` contains at least `threshold` signatures from distinct keys,
+    /// and returns the `(hash, signature, public_key)` triplets to verify.
+    /// The signatures themselves are not verified, allowing to batch them with other ones.
+    pub fn get_signature_batch(
+        &self,
+        hash: &Hash,
+        signatures: &MultiSignature,
+    ) -> Result<Vec<(Hash, Signature, PublicKey)>, MassaSignatureError> {
+        if signatures.signatures.len() < self.threshold as usize {
+            return Err(MassaSignatureError::MultiSigError(format!(
+                "{} signatures are required, got {}",
+                self.threshold,
+                signatures.signatures.len()
+            )));
+        }
+        let mut previous_index = None;
+        signatures
+            .signatures
+            .iter()
+            .map(|(index, signature)| {
+                if previous_index.map_or(false, |previous| *index <= previous) {
+                    return Err(MassaSignatureError::MultiSigError(
+                        "signatures must be sorted by key index without duplicates".to_string(),
+                    ));
+                }
+                previous_index = Some(*index);
+                let public_key = self.public_keys.get(*index as usize).ok_or_else(|| {
+                    MassaSignatureError::MultiSigError(format!("unknown key index {}", index))
+                })?;
+                Ok((*hash, *signature, *public_key))
+            })
+            .collect()
+    }
+
+    /// Checks that `signatures` contains at least `threshold` valid signatures of `hash`
+    pub fn verify_signature(
+        &self,
+        hash: &Hash,
+        signatures: &MultiSignature,
+    ) -> Result<(), MassaSignatureError> {
+        verify_signature_batch(&self.get_signature_batch(hash, signatures)?)
+    }
+}
+
+/// Signatures of a hash by some of the keys of a `MultiSigPublicKey`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiSignature {
+    /// signatures with the index of the signing key, sorted by index
+    signatures: Vec<(u8, Signature)>,
+}
+
+impl MultiSignature {
+    /// Adds the signature of the key at `index`, replacing the previous one if any
+    pub fn add_signature(&mut self, index: u8, signature: Signature) {
+        match self.signatures.binary_search_by_key(&index, |(i, _)| *i) {
+            Ok(position) => self.signatures[position].1 = signature,
+            Err(position) => self.signatures.insert(position, (index, signature)),
+        }
+    }
+
+    /// Merges the signatures collected separately by several signers
+    pub fn merge(&mut self, other: &MultiSignature) {
+        for (index, signature) in &other.signatures {
+            self.add_signature(*index, *signature);
+        }
+    }
+
+    /// Gets the signatures with the index of the signing key
+    pub fn get_signatures(&self) -> &[(u8, Signature)] {
+        &self.signatures
+    }
+
+    /// Serializes as `signature count | (key index | signature)*`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.signatures.len() * (1 + SIGNATURE_SIZE_BYTES));
+        bytes.push(self.signatures.len() as u8);
+        for (index, signature) in &self.signatures {
+            bytes.push(*index);
+            bytes.extend(signature.to_bytes());
+        }
+        bytes
+    }
+}
+
+/// Deserializer for `MultiSigPublicKey`
+#[derive(Default)]
+pub struct MultiSigPublicKeyDeserializer {
+    public_key_deserializer: PublicKeyDeserializer,
+}
+
+impl MultiSigPublicKeyDeserializer {
+    /// Creates a `MultiSigPublicKeyDeserializer`
+    pub const fn new() -> Self {
+        Self {
+            public_key_deserializer: PublicKeyDeserializer::new(),
+        }
+    }
+}
+
+impl Deserializer<MultiSigPublicKey> for MultiSigPublicKeyDeserializer {
+    /// ```
+    /// use massa_signature::{KeyPair, MultiSigPublicKey, MultiSigPublicKeyDeserializer};
+    /// use massa_serialization::{DeserializeError, Deserializer};
+    ///
+    /// let public_keys = (0..3).map(|_| KeyPair::generate().get_public_key()).collect();
+    /// let multisig_key = MultiSigPublicKey::new(2, public_keys).unwrap();
+    /// let serialized = multisig_key.to_bytes();
+    /// let (rest, deserialized) = MultiSigPublicKeyDeserializer::new().deserialize::<DeserializeError>(&serialized).unwrap();
+    /// assert!(rest.is_empty());
+    /// assert_eq!(multisig_key, deserialized);
+    /// ```
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], MultiSigPublicKey, E> {
+        let (rest, (threshold, public_keys)) = context(
+            "Failed MultiSigPublicKey deserialization",
+            tuple((
+                context("Failed threshold deserialization", parse_u8),
+                context(
+                    "Failed public keys deserialization",
+                    length_count(parse_u8, |input| {
+                        self.public_key_deserializer.deserialize(input)
+                    }),
+                ),
+            )),
+        )
+        .parse(buffer)?;
+        // only accept the canonical form, so that a set of keys has a single address
+        let multisig_key = MultiSigPublicKey::new(threshold, public_keys.clone())
+            .ok()
+            .filter(|multisig_key| multisig_key.public_keys == public_keys)
+            .ok_or_else(|| {
+                nom::Err::Error(ParseError::from_error_kind(
+                    buffer,
+                    nom::error::ErrorKind::Verify,
+                ))
+            })?;
+        Ok((rest, multisig_key))
+    }
+}
+
+/// Deserializer for `MultiSignature`
+#[derive(Default)]
+pub struct MultiSignatureDeserializer {
+    signature_deserializer: SignatureDeserializer,
+}
+
+impl MultiSignatureDeserializer {
+    /// Creates a `MultiSignatureDeserializer`
+    pub const fn new() -> Self {
+        Self {
+            signature_deserializer: SignatureDeserializer::new(),
+        }
+    }
+}
+
+impl Deserializer<MultiSignature> for MultiSignatureDeserializer {
+    /// ```
+    /// use massa_signature::{KeyPair, MultiSignature, MultiSignatureDeserializer};
+    /// use massa_serialization::{DeserializeError, Deserializer};
+    /// use massa_hash::Hash;
+    ///
+    /// let hash = Hash::compute_from("Hello World!".as_bytes());
+    /// let mut signatures = MultiSignature::default();
+    /// signatures.add_signature(1, KeyPair::generate().sign(&hash).unwrap());
+    /// let serialized = signatures.to_bytes();
+    /// let (rest, deserialized) = MultiSignatureDeserializer::new().deserialize::<DeserializeError>(&serialized).unwrap();
+    /// assert!(rest.is_empty());
+    /// assert_eq!(signatures, deserialized);
+    /// ```
+    fn deserialize<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+        &self,
+        buffer: &'a [u8],
+    ) -> IResult<&'a [u8], MultiSignature, E> {
+        context(
+            "Failed MultiSignature deserialization",
+            length_count(
+                parse_u8,
+                tuple((
+                    context("Failed key index deserialization", parse_u8),
+                    context("Failed signature deserialization", |input| {
+                        self.signature_deserializer.deserialize(input)
+                    }),
+                )),
+            ),
+        )
+        .map(|signatures| MultiSignature { signatures })
+        .parse(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyPair;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_multisig_threshold() {
+        let keypairs: Vec<KeyPair> = (0..3).map(|_| KeyPair::generate()).collect();
+        let multisig_key =
+            MultiSigPublicKey::new(2, keypairs.iter().map(|k| k.get_public_key()).collect())
+                .unwrap();
+        let hash = Hash::compute_from("Hello World!".as_bytes());
+
+        // a single signature is not enough
+        let mut signatures = MultiSignature::default();
+        let index = multisig_key
+            .get_index(&keypairs[2].get_public_key())
+            .unwrap();
+        signatures.add_signature(index, keypairs[2].sign(&hash).unwrap());
+        assert!(multisig_key.verify_signature(&hash, &signatures).is_err());
+
+        // signing twice with the same key does not count twice
+        signatures.add_signature(index, keypairs[2].sign(&hash).unwrap());
+        assert!(multisig_key.verify_signature(&hash, &signatures).is_err());
+
+        // partial signatures collected separately are merged
+        let mut other_signatures = MultiSignature::default();
+        let index = multisig_key
+            .get_index(&keypairs[0].get_public_key())
+            .unwrap();
+        other_signatures.add_signature(index, keypairs[0].sign(&hash).unwrap());
+        signatures.merge(&other_signatures);
+        assert!(multisig_key.verify_signature(&hash, &signatures).is_ok());
+
+        // a wrong signature fails the whole verification
+        let other_hash = Hash::compute_from("Goodbye World!".as_bytes());
+        signatures.add_signature(index, keypairs[0].sign(&other_hash).unwrap());
+        assert!(multisig_key.verify_signature(&hash, &signatures).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_multisig_public_key_validation() {
+        let public_key = KeyPair::generate().get_public_key();
+        assert!(MultiSigPublicKey::new(1, vec![public_key, public_key]).is_err());
+        assert!(MultiSigPublicKey::new(0, vec![public_key]).is_err());
+        assert!(MultiSigPublicKey::new(2, vec![public_key]).is_err());
+        assert!(MultiSigPublicKey::new(1, vec![public_key]).is_ok());
+    }
+}
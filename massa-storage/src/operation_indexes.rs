@@ -14,6 +14,8 @@ pub struct OperationIndexes {
     operations: PreHashMap<OperationId, SecureShareOperation>,
     /// Structure mapping creators with the created operations
     index_by_creator: PreHashMap<Address, PreHashSet<OperationId>>,
+    /// Structure mapping addresses with the operations they are involved in (creator, recipient or call target)
+    index_by_involved_address: PreHashMap<Address, PreHashSet<OperationId>>,
     /// Structure indexing operations by ID prefix
     index_by_prefix: PreHashMap<OperationPrefixId, PreHashSet<OperationId>>,
 }
@@ -29,6 +31,13 @@ impl OperationIndexes {
                 .entry(o.content_creator_address)
                 .or_default()
                 .insert(o.id);
+            // update involved address index
+            for address in o.get_ledger_involved_addresses() {
+                self.index_by_involved_address
+                    .entry(address)
+                    .or_default()
+                    .insert(o.id);
+            }
             // update prefix index
             self.index_by_prefix
                 .entry(o.id.prefix())
@@ -51,6 +60,17 @@ impl OperationIndexes {
                     occ.remove();
                 }
             }
+            // update involved address index
+            for address in o.get_ledger_involved_addresses() {
+                if let hash_map::Entry::Occupied(mut occ) =
+                    self.index_by_involved_address.entry(address)
+                {
+                    occ.get_mut().remove(&o.id);
+                    if occ.get().is_empty() {
+                        occ.remove();
+                    }
+                }
+            }
             // update prefix index
             if let hash_map::Entry::Occupied(mut occ) = self.index_by_prefix.entry(o.id.prefix()) {
                 occ.get_mut().remove(&o.id);
@@ -83,6 +103,16 @@ impl OperationIndexes {
         self.index_by_creator.get(address)
     }
 
+    /// Get operations involving an address: created by it, sent to it or calling it
+    /// Arguments:
+    /// * `address`: the address to get the operations involving
+    ///
+    /// Returns:
+    /// - optional reference to a set of operations involving that address
+    pub fn get_operations_involving(&self, address: &Address) -> Option<&PreHashSet<OperationId>> {
+        self.index_by_involved_address.get(address)
+    }
+
     /// Get operations by prefix
     /// Arguments:
     /// * `prefix`: the prefix to look up
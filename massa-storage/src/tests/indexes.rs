@@ -1,6 +1,12 @@
 use crate::Storage;
 use massa_factory_exports::test_exports::create_empty_block;
-use massa_models::{address::Address, slot::Slot};
+use massa_models::{
+    address::Address,
+    amount::Amount,
+    operation::{Operation, OperationSerializer, OperationType},
+    secure_share::SecureShareContent,
+    slot::Slot,
+};
 use massa_signature::KeyPair;
 
 #[test]
@@ -46,3 +52,41 @@ fn test_block_fail_find() {
         .get_blocks_created_by(&Address::from_public_key(&keypair2.get_public_key()))
         .is_none());
 }
+
+#[test]
+fn test_operation_index_by_involved_address() {
+    let mut storage = Storage::create_root();
+    let keypair = KeyPair::generate();
+    let creator = Address::from_public_key(&keypair.get_public_key());
+    let recipient = Address::from_public_key(&KeyPair::generate().get_public_key());
+    let operation = Operation::new_verifiable(
+        Operation {
+            fee: Amount::from_raw(10),
+            expire_period: 10,
+            op: OperationType::Transaction {
+                recipient_address: recipient,
+                amount: Amount::from_raw(1000),
+            },
+        },
+        OperationSerializer::new(),
+        &keypair,
+    )
+    .unwrap();
+
+    storage.store_operations(vec![operation.clone()]);
+    {
+        let operations = storage.read_operations();
+        for address in [creator, recipient] {
+            let involved = operations.get_operations_involving(&address).unwrap();
+            assert_eq!(involved.len(), 1);
+            assert!(involved.contains(&operation.id));
+        }
+    }
+
+    // the index is cleaned up when the operation is dropped
+    storage.drop_operation_refs(&storage.get_op_refs().clone());
+    assert!(storage
+        .read_operations()
+        .get_operations_involving(&recipient)
+        .is_none());
+}
@@ -1,6 +1,13 @@
-use crate::versioning::{Advance, ComponentState, MipInfo, MipState};
+use std::collections::HashMap;
 
-use massa_models::config::VERSIONING_THRESHOLD_TRANSITION_ACCEPTED;
+use crate::versioning::{
+    Advance, ComponentState, MipComponent, MipInfo, MipState, MipStatsConfig, MipStore,
+};
+
+use massa_models::config::{
+    MIP_STORE_STATS_BLOCK_CONSIDERED, MIP_STORE_STATS_COUNTERS_MAX,
+    VERSIONING_THRESHOLD_TRANSITION_ACCEPTED,
+};
 use massa_time::MassaTime;
 
 // TODO: rename versioning_info
@@ -58,3 +65,25 @@ pub fn advance_state_until(at_state: ComponentState, versioning_info: &MipInfo)
     // Active
     state
 }
+
+/// A MIP store with a single MIP, active a few milliseconds after the epoch, for the given component version
+pub fn get_mip_store_with_active_component(
+    component: MipComponent,
+    component_version: u32,
+) -> MipStore {
+    let mip_info = MipInfo {
+        name: "MIP-TEST".to_string(),
+        version: 1,
+        components: HashMap::from([(component, component_version)]),
+        start: MassaTime::from(2),
+        timeout: MassaTime::from(10),
+        activation_delay: MassaTime::from(2),
+    };
+    let mip_state = advance_state_until(ComponentState::active(), &mip_info);
+    let mip_stats_config = MipStatsConfig {
+        block_count_considered: MIP_STORE_STATS_BLOCK_CONSIDERED,
+        counters_max: MIP_STORE_STATS_COUNTERS_MAX,
+    };
+    MipStore::try_from(([(mip_info, mip_state)], mip_stats_config))
+        .expect("could not create the MIP store")
+}
@@ -459,6 +459,24 @@ impl MipStore {
             .unwrap_or(0)
     }
 
+    /// Retrieve the version of a component that is active at the given timestamp (e.g. a slot)
+    /// return 0 if no MIP concerning this component is active
+    pub fn get_component_version_at(&self, component: &MipComponent, ts: MassaTime) -> u32 {
+        let lock = self.0.read();
+        let store = lock.deref();
+        let state_active = ComponentState::active();
+        store
+            .store
+            .iter()
+            .rev()
+            .filter(|(mi, ms)| mi.components.contains_key(component) && ms.state == state_active)
+            .find_map(|(mi, ms)| match ms.state_at(ts, mi.start, mi.timeout) {
+                Ok(ComponentStateTypeId::Active) => mi.components.get(component).copied(),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
     pub fn update_network_version_stats(
         &mut self,
         slot_timestamp: MassaTime,
@@ -1030,6 +1048,45 @@ mod test {
         assert_eq!(vs.get_network_version_to_announce(), 0);
     }
 
+    #[test]
+    fn test_versioning_store_component_version_at() {
+        // Test MipStore::get_component_version_at()
+
+        let mi = MipInfo {
+            name: "MIP-0003".to_string(),
+            version: 3,
+            components: HashMap::from([(MipComponent::Block, 1)]),
+            start: MassaTime::from(2),
+            timeout: MassaTime::from(50),
+            activation_delay: MassaTime::from(2),
+        };
+        let ms = advance_state_until(ComponentState::active(), &mi);
+        let mip_stats_cfg = MipStatsConfig {
+            block_count_considered: 10,
+            counters_max: 5,
+        };
+        let vs_raw = MipStoreRaw {
+            store: BTreeMap::from([(mi.clone(), ms)]),
+            stats: MipStoreStats::new(mip_stats_cfg),
+        };
+        let vs = MipStore(Arc::new(RwLock::new(vs_raw)));
+
+        // Defined at start, Active after the activation delay
+        assert_eq!(
+            vs.get_component_version_at(&MipComponent::Block, mi.start),
+            0
+        );
+        assert_eq!(
+            vs.get_component_version_at(&MipComponent::Block, MassaTime::from(100)),
+            1
+        );
+        // Other components are not concerned by this MIP
+        assert_eq!(
+            vs.get_component_version_at(&MipComponent::Address, MassaTime::from(100)),
+            0
+        );
+    }
+
     #[test]
     fn test_is_coherent_with() {
         // Test MipStateHistory::is_coherent_with
@@ -22,4 +22,16 @@ pub enum WalletError {
     MissingKeyError(Address),
     /// `MassaCipher` error: {0}
     MassaCipherError(#[from] massa_cipher::CipherError),
+    /// `MassaSignature` error: {0}
+    MassaSignatureError(#[from] massa_signature::MassaSignatureError),
+    /// Seed phrase error: {0}
+    MnemonicError(String),
+    /// Keystore error: {0}
+    KeystoreError(String),
+    /// Slashing protection error: {0}
+    SlashingProtectionError(String),
+    /// Remote signer error: {0}
+    RemoteSignerError(String),
+    /// Staking keys are held by the external signer, not by the node
+    KeysHeldByRemoteSigner,
 }
@@ -0,0 +1,232 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Hierarchical deterministic key derivation from a BIP-39 seed phrase.
+//! Ed25519 keys are derived following SLIP-0010, along the path
+//! `m/44'/632'/account'/0'/0'`, 632 being the SLIP-0044 coin type of Massa.
+
+use crate::WalletError;
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use massa_signature::{KeyPair, SECRET_KEY_BYTES_SIZE};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use std::ops::Range;
+
+/// Number of words of the generated seed phrases
+pub const MNEMONIC_WORD_COUNT: usize = 24;
+
+/// SLIP-0044 coin type of Massa
+const MASSA_COIN_TYPE: u32 = 632;
+
+/// Offset of the hardened indexes. SLIP-0010 only allows hardened derivation for ed25519.
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// HMAC key of the SLIP-0010 master key derivation for ed25519
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+/// Seed phrase from which the accounts of the wallet are derived
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HdSeed {
+    /// BIP-39 seed phrase
+    mnemonic: String,
+    /// optional BIP-39 passphrase, empty if none
+    #[serde(default)]
+    passphrase: String,
+    /// index of the next account to derive
+    next_account: u32,
+}
+
+impl HdSeed {
+    /// Generates a new random seed phrase, protected by an optional BIP-39 passphrase
+    pub fn generate(passphrase: &str) -> Result<HdSeed, WalletError> {
+        let mnemonic = Mnemonic::generate(MNEMONIC_WORD_COUNT)
+            .map_err(|err| WalletError::MnemonicError(err.to_string()))?;
+        Ok(HdSeed {
+            mnemonic: mnemonic.to_string(),
+            passphrase: passphrase.to_string(),
+            next_account: 0,
+        })
+    }
+
+    /// Imports an existing seed phrase, with the BIP-39 passphrase it was created with (empty if none)
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<HdSeed, WalletError> {
+        let mnemonic =
+            Mnemonic::parse(phrase).map_err(|err| WalletError::MnemonicError(err.to_string()))?;
+        Ok(HdSeed {
+            mnemonic: mnemonic.to_string(),
+            passphrase: passphrase.to_string(),
+            next_account: 0,
+        })
+    }
+
+    /// Gets the seed phrase
+    pub fn get_mnemonic(&self) -> &str {
+        &self.mnemonic
+    }
+
+    /// Checks whether both seeds derive the same accounts
+    pub fn same_seed(&self, other: &HdSeed) -> bool {
+        self.mnemonic == other.mnemonic && self.passphrase == other.passphrase
+    }
+
+    /// Gets the index of the next account to derive
+    pub fn get_next_account(&self) -> u32 {
+        self.next_account
+    }
+
+    /// Derives the keypairs of the given accounts.
+    /// The seed is stretched by PBKDF2 only once for all of them.
+    pub fn derive_keypairs(&self, accounts: Range<u32>) -> Result<Vec<KeyPair>, WalletError> {
+        if accounts.end > HARDENED_OFFSET {
+            return Err(WalletError::MnemonicError(format!(
+                "account index {} is too large",
+                accounts.end - 1
+            )));
+        }
+        let seed = Mnemonic::parse(&self.mnemonic)
+            .map_err(|err| WalletError::MnemonicError(err.to_string()))?
+            .to_seed(&self.passphrase);
+        accounts
+            .map(|account| {
+                let secret_key =
+                    derive_ed25519_secret_key(&seed, &[44, MASSA_COIN_TYPE, account, 0, 0]);
+                Ok(KeyPair::from_bytes(&secret_key)?)
+            })
+            .collect()
+    }
+
+    /// Derives the keypair of the given account
+    pub fn derive_keypair(&self, account: u32) -> Result<KeyPair, WalletError> {
+        let end = account.checked_add(1).ok_or_else(|| {
+            WalletError::MnemonicError(format!("account index {} is too large", account))
+        })?;
+        Ok(self
+            .derive_keypairs(account..end)?
+            .pop()
+            .expect("one account derived"))
+    }
+
+    /// Derives the keypair of the next account, and moves on to the following one
+    pub fn derive_next_keypair(&mut self) -> Result<KeyPair, WalletError> {
+        let keypair = self.derive_keypair(self.next_account)?;
+        self.next_account += 1;
+        Ok(keypair)
+    }
+
+    /// Makes sure the accounts below `account_count` are not derived again
+    pub fn skip_accounts(&mut self, account_count: u32) {
+        self.next_account = self.next_account.max(account_count);
+    }
+}
+
+/// Derives an ed25519 secret key from a seed along a path of hardened indexes (SLIP-0010)
+fn derive_ed25519_secret_key(seed: &[u8], path: &[u32]) -> [u8; SECRET_KEY_BYTES_SIZE] {
+    let (mut secret_key, mut chain_code) = hmac_sha512(ED25519_SEED_KEY, &[seed]);
+    for index in path {
+        (secret_key, chain_code) = hmac_sha512(
+            &chain_code,
+            &[
+                &[0u8][..],
+                &secret_key[..],
+                &(index | HARDENED_OFFSET).to_be_bytes()[..],
+            ],
+        );
+    }
+    secret_key
+}
+
+/// Computes an HMAC-SHA512 and splits it into its left and right halves
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    let result = mac.finalize().into_bytes();
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&result[..32]);
+    right.copy_from_slice(&result[32..]);
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_slip10_vectors() {
+        // test vector 1 of SLIP-0010 for ed25519
+        let seed = from_hex("000102030405060708090a0b0c0d0e0f");
+        assert_eq!(
+            derive_ed25519_secret_key(&seed, &[]).to_vec(),
+            from_hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7")
+        );
+        assert_eq!(
+            derive_ed25519_secret_key(&seed, &[0]).to_vec(),
+            from_hex("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3")
+        );
+    }
+
+    #[test]
+    fn test_restore_from_mnemonic() {
+        let mut seed = HdSeed::generate("").unwrap();
+        let first = seed.derive_next_keypair().unwrap();
+        let second = seed.derive_next_keypair().unwrap();
+        assert_ne!(first.get_public_key(), second.get_public_key());
+
+        let restored = HdSeed::from_mnemonic(seed.get_mnemonic(), "").unwrap();
+        assert_eq!(
+            restored.derive_keypair(1).unwrap().get_public_key(),
+            second.get_public_key()
+        );
+        let restored_keys: Vec<_> = restored
+            .derive_keypairs(0..2)
+            .unwrap()
+            .iter()
+            .map(KeyPair::get_public_key)
+            .collect();
+        assert_eq!(
+            restored_keys,
+            vec![first.get_public_key(), second.get_public_key()]
+        );
+        assert!(HdSeed::from_mnemonic("not a valid seed phrase", "").is_err());
+    }
+
+    #[test]
+    fn test_passphrase() {
+        // BIP-39 test vector with the "TREZOR" passphrase, and the SLIP-0010 master key of its seed
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = Mnemonic::parse(phrase).unwrap().to_seed("TREZOR");
+        assert_eq!(
+            seed.to_vec(),
+            from_hex("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04")
+        );
+
+        let with_passphrase = HdSeed::from_mnemonic(phrase, "TREZOR").unwrap();
+        let without_passphrase = HdSeed::from_mnemonic(phrase, "").unwrap();
+        assert!(!with_passphrase.same_seed(&without_passphrase));
+        assert_ne!(
+            with_passphrase.derive_keypair(0).unwrap().get_public_key(),
+            without_passphrase
+                .derive_keypair(0)
+                .unwrap()
+                .get_public_key()
+        );
+        assert_eq!(
+            with_passphrase.derive_keypair(0).unwrap().to_bytes(),
+            KeyPair::from_bytes(&derive_ed25519_secret_key(
+                &seed,
+                &[44, MASSA_COIN_TYPE, 0, 0, 0]
+            ))
+            .unwrap()
+            .to_bytes()
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Per-account keystore files, allowing to move a single account between tools
+//! without exposing its secret key in plain text.
+
+use crate::WalletError;
+use massa_cipher::{decrypt, encrypt_with_params, get_argon2_header, Argon2Params};
+use massa_models::address::Address;
+use massa_signature::{KeyPair, PublicKey, SECRET_KEY_BYTES_SIZE};
+use serde::{Deserialize, Serialize};
+
+/// Version of the keystore format
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// Cipher used to encrypt the secret key
+const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+
+/// Key derivation function used to derive the cipher key from the password
+const KEYSTORE_KDF: &str = "argon2id";
+
+/// Account whose secret key is encrypted with its own password
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Keystore {
+    /// version of the keystore format
+    pub version: u32,
+    /// address of the account
+    pub address: Address,
+    /// public key of the account
+    pub public_key: PublicKey,
+    /// encrypted secret key
+    pub crypto: KeystoreCrypto,
+}
+
+/// Encrypted secret key of a keystore
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct KeystoreCrypto {
+    /// cipher used to encrypt the secret key
+    pub cipher: String,
+    /// key derivation function used to derive the cipher key from the password
+    pub kdf: String,
+    /// parameters of the key derivation function
+    pub kdf_params: Argon2Params,
+    /// salt of the key derivation function, base58 encoded
+    pub salt: String,
+    /// nonce of the cipher, base58 encoded
+    pub nonce: String,
+    /// secret key encrypted by `massa_cipher`, base58 encoded.
+    /// Its header repeats the KDF parameters, the salt and the nonce, which must match the fields above.
+    pub ciphertext: String,
+}
+
+impl Keystore {
+    /// Encrypts the secret key of an account with the given password
+    pub fn encrypt(keypair: &KeyPair, password: &str) -> Result<Keystore, WalletError> {
+        let kdf_params = Argon2Params::default();
+        let ciphertext = encrypt_with_params(password, keypair.to_bytes(), &kdf_params)?;
+        let header = get_argon2_header(&ciphertext)?;
+        let public_key = keypair.get_public_key();
+        Ok(Keystore {
+            version: KEYSTORE_VERSION,
+            address: Address::from_public_key(&public_key),
+            public_key,
+            crypto: KeystoreCrypto {
+                cipher: KEYSTORE_CIPHER.to_string(),
+                kdf: KEYSTORE_KDF.to_string(),
+                kdf_params,
+                salt: bs58::encode(header.salt).into_string(),
+                nonce: bs58::encode(header.nonce).into_string(),
+                ciphertext: bs58::encode(ciphertext).into_string(),
+            },
+        })
+    }
+
+    /// Decrypts the secret key of the account, and checks that it matches the address and public key
+    pub fn decrypt(&self, password: &str) -> Result<KeyPair, WalletError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(WalletError::KeystoreError(format!(
+                "unsupported keystore version {}",
+                self.version
+            )));
+        }
+        if self.crypto.cipher != KEYSTORE_CIPHER || self.crypto.kdf != KEYSTORE_KDF {
+            return Err(WalletError::KeystoreError(format!(
+                "unsupported cipher {} or key derivation function {}",
+                self.crypto.cipher, self.crypto.kdf
+            )));
+        }
+        let ciphertext = bs58::decode(&self.crypto.ciphertext)
+            .into_vec()
+            .map_err(|err| WalletError::KeystoreError(err.to_string()))?;
+        // the key is derived from the header of the ciphertext, which must match the declared parameters
+        let header = get_argon2_header(&ciphertext)?;
+        if header.params != self.crypto.kdf_params
+            || bs58::encode(header.salt).into_string() != self.crypto.salt
+            || bs58::encode(header.nonce).into_string() != self.crypto.nonce
+        {
+            return Err(WalletError::KeystoreError(
+                "the key derivation parameters, salt or nonce do not match the ciphertext"
+                    .to_string(),
+            ));
+        }
+        let (_version, secret_key) = decrypt(password, &ciphertext)?;
+        let secret_key: [u8; SECRET_KEY_BYTES_SIZE] = secret_key
+            .try_into()
+            .map_err(|_| WalletError::KeystoreError("invalid secret key size".to_string()))?;
+        let keypair = KeyPair::from_bytes(&secret_key)?;
+        if keypair.get_public_key() != self.public_key
+            || Address::from_public_key(&self.public_key) != self.address
+        {
+            return Err(WalletError::KeystoreError(
+                "the secret key does not match the address".to_string(),
+            ));
+        }
+        Ok(keypair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let keypair = KeyPair::generate();
+        let keystore = Keystore::encrypt(&keypair, "password").unwrap();
+        let json = serde_json::to_string(&keystore).unwrap();
+        let keystore: Keystore = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            keystore.decrypt("password").unwrap().get_public_key(),
+            keypair.get_public_key()
+        );
+        assert!(keystore.decrypt("wrong password").is_err());
+
+        let mut other = keystore.clone();
+        other.address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        assert!(other.decrypt("password").is_err());
+
+        // the declared parameters must be the ones used to encrypt the secret key
+        let mut other = keystore.clone();
+        other.crypto.kdf_params.iterations += 1;
+        assert!(other.decrypt("password").is_err());
+        let mut other = keystore;
+        other.crypto.salt = bs58::encode([0u8; 16]).into_string();
+        assert!(other.decrypt("password").is_err());
+    }
+}
@@ -6,49 +6,102 @@
 #![feature(map_try_insert)]
 
 pub use error::WalletError;
+pub use hd::{HdSeed, MNEMONIC_WORD_COUNT};
+pub use keystore::{Keystore, KeystoreCrypto, KEYSTORE_VERSION};
+pub use massa_cipher::Argon2Params;
+pub use signer::{
+    LocalSigner, RemoteSigner, RemoteSignerEndpoint, RemoteSignerRequest, RemoteSignerResponse,
+    Signer, SigningContext,
+};
+pub use slashing_protection::{
+    SignedRecord, SlashingProtection, SlashingProtectionHistory, SLASHING_PROTECTION_VERSION,
+};
 
-use massa_cipher::{decrypt, encrypt};
+use massa_cipher::{decrypt, encrypt_with_params, get_argon2_header, VERSION as CIPHER_VERSION};
 use massa_hash::Hash;
 use massa_models::address::Address;
 use massa_models::composite::PubkeySig;
-use massa_models::operation::{Operation, OperationSerializer, SecureShareOperation};
+use massa_models::error::ModelsError;
+use massa_models::operation::{
+    Operation, OperationSerializer, OperationType, SecureShareOperation,
+};
 use massa_models::prehash::{PreHashMap, PreHashSet};
 use massa_models::secure_share::SecureShareContent;
 use massa_signature::{KeyPair, PublicKey};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 mod error;
+mod hd;
+mod keystore;
+mod signer;
+mod slashing_protection;
 
 /// Contains the keypairs created in the wallet.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Wallet {
     /// Keypairs and addresses
     pub keys: PreHashMap<Address, KeyPair>,
+    /// Seed phrase from which accounts are derived, if any
+    pub hd_seed: Option<HdSeed>,
     /// Path to the file containing the keypairs (encrypted)
     pub wallet_path: PathBuf,
     /// Password
     pub password: String,
+    /// `Argon2id` parameters the wallet file is encrypted with
+    pub argon2_params: Argon2Params,
+}
+
+/// Content of the encrypted wallet file.
+/// Wallets without seed phrase keep the original format: a map of the keypairs.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum WalletFileContent {
+    Keys(PreHashMap<Address, KeyPair>),
+    HdKeys {
+        keys: PreHashMap<Address, KeyPair>,
+        hd_seed: HdSeed,
+    },
 }
 
 impl Wallet {
-    /// Generates a new wallet initialized with the provided file content
-    pub fn new(path: PathBuf, password: String) -> Result<Wallet, WalletError> {
+    /// Generates a new wallet initialized with the provided file content.
+    /// Files encrypted with a previous cipher version or other `Argon2id` parameters
+    /// are re-encrypted with the current version and the given parameters.
+    pub fn new(
+        path: PathBuf,
+        password: String,
+        argon2_params: Argon2Params,
+    ) -> Result<Wallet, WalletError> {
         if path.is_file() {
             let content = &std::fs::read(&path)?[..];
-            let (_version, decrypted_content) = decrypt(&password, content)?;
-            let keys =
-                serde_json::from_slice::<PreHashMap<Address, KeyPair>>(&decrypted_content[..])?;
-            Ok(Wallet {
+            let (version, decrypted_content) = decrypt(&password, content)?;
+            let (keys, hd_seed) =
+                match serde_json::from_slice::<WalletFileContent>(&decrypted_content[..])? {
+                    WalletFileContent::Keys(keys) => (keys, None),
+                    WalletFileContent::HdKeys { keys, hd_seed } => (keys, Some(hd_seed)),
+                };
+            let wallet = Wallet {
                 keys,
+                hd_seed,
                 wallet_path: path,
                 password,
-            })
+                argon2_params,
+            };
+            if version != CIPHER_VERSION
+                || get_argon2_header(content)?.params != wallet.argon2_params
+            {
+                wallet.save()?;
+            }
+            Ok(wallet)
         } else {
             let wallet = Wallet {
                 keys: PreHashMap::default(),
+                hd_seed: None,
                 wallet_path: path,
                 password,
+                argon2_params,
             };
             wallet.save()?;
             Ok(wallet)
@@ -106,6 +159,93 @@ impl Wallet {
         Ok(())
     }
 
+    /// Generates a new seed phrase from which the next accounts of the wallet will be derived,
+    /// protected by an optional BIP-39 passphrase (empty if none).
+    /// Fails if the wallet already has a seed phrase.
+    /// The wallet file is updated.
+    pub fn generate_hd_seed(&mut self, passphrase: &str) -> Result<&str, WalletError> {
+        if self.hd_seed.is_some() {
+            return Err(WalletError::MnemonicError(
+                "the wallet already has a seed phrase".to_string(),
+            ));
+        }
+        self.hd_seed = Some(HdSeed::generate(passphrase)?);
+        self.save()?;
+        Ok(self.get_mnemonic().unwrap_or_default())
+    }
+
+    /// Gets the seed phrase of the wallet, if any
+    pub fn get_mnemonic(&self) -> Option<&str> {
+        self.hd_seed.as_ref().map(|hd_seed| hd_seed.get_mnemonic())
+    }
+
+    /// Derives the next account from the seed phrase of the wallet and adds it, returns its address.
+    /// The wallet file is updated.
+    pub fn derive_next_account(&mut self) -> Result<Address, WalletError> {
+        let hd_seed = self.hd_seed.as_mut().ok_or_else(|| {
+            WalletError::MnemonicError("the wallet has no seed phrase".to_string())
+        })?;
+        let keypair = hd_seed.derive_next_keypair()?;
+        let addr = Address::from_public_key(&keypair.get_public_key());
+        self.keys.insert(addr, keypair);
+        self.save()?;
+        Ok(addr)
+    }
+
+    /// Restores the first `account_count` accounts derived from a seed phrase
+    /// and its BIP-39 passphrase (empty if none), returns their addresses.
+    /// Fails if the wallet already has another seed phrase or passphrase.
+    /// The wallet file is updated.
+    pub fn restore_from_mnemonic(
+        &mut self,
+        phrase: &str,
+        passphrase: &str,
+        account_count: u32,
+    ) -> Result<Vec<Address>, WalletError> {
+        let mut hd_seed = HdSeed::from_mnemonic(phrase, passphrase)?;
+        if let Some(current) = &self.hd_seed {
+            if !current.same_seed(&hd_seed) {
+                return Err(WalletError::MnemonicError(
+                    "the wallet already has another seed phrase".to_string(),
+                ));
+            }
+            hd_seed = current.clone();
+        }
+        let mut addrs = Vec::with_capacity(account_count as usize);
+        for keypair in hd_seed.derive_keypairs(0..account_count)? {
+            let addr = Address::from_public_key(&keypair.get_public_key());
+            self.keys.insert(addr, keypair);
+            addrs.push(addr);
+        }
+        hd_seed.skip_accounts(account_count);
+        self.hd_seed = Some(hd_seed);
+        self.save()?;
+        Ok(addrs)
+    }
+
+    /// Exports an account of the wallet as a keystore encrypted with the given password
+    pub fn export_keystore(
+        &self,
+        address: &Address,
+        password: &str,
+    ) -> Result<Keystore, WalletError> {
+        let keypair = self
+            .find_associated_keypair(address)
+            .ok_or_else(|| WalletError::MissingKeyError(*address))?;
+        Keystore::encrypt(keypair, password)
+    }
+
+    /// Imports the account of a keystore encrypted with the given password, returns its address.
+    /// The wallet file is updated.
+    pub fn import_keystore(
+        &mut self,
+        keystore: &Keystore,
+        password: &str,
+    ) -> Result<Address, WalletError> {
+        let keypair = keystore.decrypt(password)?;
+        Ok(self.add_keypairs(vec![keypair])?[0])
+    }
+
     /// Finds the keypair associated with given address
     pub fn find_associated_keypair(&self, address: &Address) -> Option<&KeyPair> {
         self.keys.get(address)
@@ -124,12 +264,19 @@ impl Wallet {
     }
 
     /// Save the wallet in json format in a file
-    /// Only the keypair is dumped
+    /// Only the keypairs and the seed phrase are dumped
     fn save(&self) -> Result<(), WalletError> {
-        let ser_keys = serde_json::to_string(&self.keys)?;
-        let encrypted_content = encrypt(&self.password, ser_keys.as_bytes())?;
-        std::fs::write(&self.wallet_path, encrypted_content)?;
-        Ok(())
+        let content = match &self.hd_seed {
+            Some(hd_seed) => WalletFileContent::HdKeys {
+                keys: self.keys.clone(),
+                hd_seed: hd_seed.clone(),
+            },
+            None => WalletFileContent::Keys(self.keys.clone()),
+        };
+        let ser_keys = serde_json::to_string(&content)?;
+        let encrypted_content =
+            encrypt_with_params(&self.password, ser_keys.as_bytes(), &self.argon2_params)?;
+        write_file_atomically(&self.wallet_path, &encrypted_content)
     }
 
     /// Export keys and addresses
@@ -137,19 +284,97 @@ impl Wallet {
         &self.keys
     }
 
-    /// Signs an operation with the keypair corresponding to the given address
+    /// Signs an operation with the keypair corresponding to the given address.
+    /// Operations of a multi-signature address are signed by any member of the address in the wallet.
     pub fn create_operation(
         &self,
         content: Operation,
         address: Address,
     ) -> Result<SecureShareOperation, WalletError> {
-        let sender_keypair = self
+        let sender_keypair = match &content.op {
+            OperationType::MultiSig { public_key, .. }
+                if address == Address::from_multisig_public_key(public_key) =>
+            {
+                public_key
+                    .get_public_keys()
+                    .iter()
+                    .find_map(|member_public_key| {
+                        self.find_associated_keypair(&Address::from_public_key(member_public_key))
+                    })
+            }
+            _ => self.find_associated_keypair(&address),
+        }
+        .ok_or_else(|| WalletError::MissingKeyError(address))?;
+        Ok(Operation::new_verifiable(content, OperationSerializer::new(), sender_keypair).unwrap())
+    }
+
+    /// Adds the signature of the member at the given address to a multi-signature operation
+    pub fn sign_multisig_operation(
+        &self,
+        content: &mut Operation,
+        address: Address,
+    ) -> Result<(), WalletError> {
+        let keypair = self
             .find_associated_keypair(&address)
             .ok_or_else(|| WalletError::MissingKeyError(address))?;
-        Ok(Operation::new_verifiable(content, OperationSerializer::new(), sender_keypair).unwrap())
+        let Some(hash) = content.compute_multisig_hash() else {
+            return Err(ModelsError::MultiSigError(
+                "not a multi-signature operation".to_string(),
+            )
+            .into());
+        };
+        let OperationType::MultiSig {
+            public_key,
+            signatures,
+            ..
+        } = &mut content.op
+        else {
+            return Err(ModelsError::MultiSigError(
+                "not a multi-signature operation".to_string(),
+            )
+            .into());
+        };
+        let index = public_key
+            .get_index(&keypair.get_public_key())
+            .ok_or_else(|| {
+                ModelsError::MultiSigError(format!(
+                    "{} is not a member of the multi-signature address",
+                    address
+                ))
+            })?;
+        signatures.add_signature(index, keypair.sign(&hash).map_err(ModelsError::from)?);
+        Ok(())
     }
 }
 
+/// Replaces the content of a file so that an interruption leaves either the previous or the new content:
+/// the content is written to a temporary file that is synced to disk, then renamed over the file.
+pub(crate) fn write_file_atomically(path: &Path, content: &[u8]) -> Result<(), WalletError> {
+    let mut tmp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_file_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_file_name);
+    let mut file = std::fs::File::create(&tmp_path)?;
+    file.write_all(content)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp_path, path)?;
+    sync_parent_dir(path)
+}
+
+/// Syncs the directory containing a file, so that its creation or renaming survives a crash
+pub(crate) fn sync_parent_dir(path: &Path) -> Result<(), WalletError> {
+    #[cfg(unix)]
+    {
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        std::fs::File::open(parent)?.sync_all()?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
 impl std::fmt::Display for Wallet {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(f)?;
@@ -165,3 +390,57 @@ impl std::fmt::Display for Wallet {
 /// Test utils
 #[cfg(feature = "testing")]
 pub mod test_exports;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_pbkdf2_wallet() {
+        // empty wallet "{}" encrypted with "password" by the version 0 cipher (PBKDF2)
+        let encrypted = [
+            0x00, 0x62, 0x57, 0x46, 0x7a, 0x63, 0x32, 0x46, 0x7a, 0x59, 0x57, 0x78, 0x30, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0xf1, 0x6e, 0xb8,
+            0x6c, 0x75, 0xd1, 0x2e, 0x07, 0x69, 0x78, 0xca, 0x10, 0x8d, 0x92, 0x96, 0x81, 0x19,
+            0xfd,
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.dat");
+        std::fs::write(&path, encrypted).unwrap();
+
+        let argon2_params = Argon2Params {
+            memory_cost_kib: 8 * 1024,
+            iterations: 1,
+            parallelism: 1,
+        };
+        let wallet = Wallet::new(path.clone(), "password".to_string(), argon2_params).unwrap();
+        assert!(wallet.keys.is_empty());
+        // the file was re-encrypted with the current cipher version and the given parameters,
+        // without leftover temporary file
+        let upgraded = std::fs::read(&path).unwrap();
+        assert_eq!(
+            massa_cipher::get_version(&upgraded).unwrap(),
+            CIPHER_VERSION
+        );
+        assert_eq!(get_argon2_header(&upgraded).unwrap().params, argon2_params);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // the file is left untouched when the parameters are unchanged
+        Wallet::new(path.clone(), "password".to_string(), argon2_params).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), upgraded);
+
+        // and re-encrypted when they change
+        Wallet::new(
+            path.clone(),
+            "password".to_string(),
+            Argon2Params::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_argon2_header(&std::fs::read(&path).unwrap())
+                .unwrap()
+                .params,
+            Argon2Params::default()
+        );
+    }
+}
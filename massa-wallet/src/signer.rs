@@ -0,0 +1,416 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Signing of the content produced by the node, either with keys held in the process
+//! or by an external signer process, so that the staking keys can live in an HSM-backed process.
+//!
+//! Signers do not check what they sign: the factories check the persisted `SlashingProtection` records
+//! before signing block headers and endorsements. The signing context is passed along so that an external
+//! signer can keep its own records.
+//!
+//! The external signer is reached over a local Unix socket (`unix:/path/to/socket`),
+//! where each request is a line of JSON answered by a line of JSON,
+//! or over HTTP (`http://host:port/path`), where each request is a POST of a JSON body.
+//! Requests are `RemoteSignerRequest` and responses are `RemoteSignerResponse`.
+
+use crate::{Wallet, WalletError};
+use massa_hash::Hash;
+use massa_models::{address::Address, prehash::PreHashMap, slot::Slot};
+use massa_signature::{KeyPair, PublicKey, Signature};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// How long an address that the external signer does not manage is not asked about again.
+/// The factories look up the producers of every slot, most of which are not managed by the signer.
+const UNKNOWN_ADDRESS_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// What is being signed, used to enforce slashing protection
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SigningContext {
+    /// header of the block produced at a slot
+    BlockHeader {
+        /// slot of the block
+        slot: Slot,
+    },
+    /// block produced at a slot, wrapping an already signed header
+    Block {
+        /// slot of the block
+        slot: Slot,
+    },
+    /// endorsement of a slot
+    Endorsement {
+        /// endorsed slot
+        slot: Slot,
+        /// index of the endorsement in the slot
+        index: u32,
+    },
+    /// arbitrary message
+    Message,
+}
+
+/// Signs on behalf of the addresses it manages
+pub trait Signer: Send + Sync {
+    /// Gets the public key of an address, if it is managed by the signer
+    fn get_public_key(&self, address: &Address) -> Result<Option<PublicKey>, WalletError>;
+
+    /// Signs a hash on behalf of an address
+    fn sign(
+        &self,
+        address: &Address,
+        hash: &Hash,
+        context: &SigningContext,
+    ) -> Result<Signature, WalletError>;
+}
+
+/// Keys held by a local signer
+enum LocalKeys {
+    Wallet(Arc<RwLock<Wallet>>),
+    KeyPair(KeyPair),
+}
+
+/// Signer using keys held in the process
+pub struct LocalSigner {
+    keys: LocalKeys,
+}
+
+impl LocalSigner {
+    /// Signs with the keys of a wallet
+    pub fn new(wallet: Arc<RwLock<Wallet>>) -> LocalSigner {
+        LocalSigner {
+            keys: LocalKeys::Wallet(wallet),
+        }
+    }
+
+    /// Signs with a single keypair
+    pub fn from_keypair(keypair: KeyPair) -> LocalSigner {
+        LocalSigner {
+            keys: LocalKeys::KeyPair(keypair),
+        }
+    }
+
+    fn sign_with<T>(&self, address: &Address, f: impl FnOnce(&KeyPair) -> T) -> Option<T> {
+        match &self.keys {
+            LocalKeys::Wallet(wallet) => wallet.read().find_associated_keypair(address).map(f),
+            LocalKeys::KeyPair(keypair) => (Address::from_public_key(&keypair.get_public_key())
+                == *address)
+                .then(|| f(keypair)),
+        }
+    }
+}
+
+impl Signer for LocalSigner {
+    fn get_public_key(&self, address: &Address) -> Result<Option<PublicKey>, WalletError> {
+        Ok(self.sign_with(address, |keypair| keypair.get_public_key()))
+    }
+
+    fn sign(
+        &self,
+        address: &Address,
+        hash: &Hash,
+        _context: &SigningContext,
+    ) -> Result<Signature, WalletError> {
+        let signature = self
+            .sign_with(address, |keypair| keypair.sign(hash))
+            .ok_or(WalletError::MissingKeyError(*address))??;
+        Ok(signature)
+    }
+}
+
+/// Location of an external signer
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum RemoteSignerEndpoint {
+    /// local Unix socket
+    Unix(PathBuf),
+    /// HTTP URL
+    Http(String),
+}
+
+impl FromStr for RemoteSignerEndpoint {
+    type Err = WalletError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("unix:") {
+            Ok(RemoteSignerEndpoint::Unix(PathBuf::from(path)))
+        } else if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(RemoteSignerEndpoint::Http(s.to_string()))
+        } else {
+            Err(WalletError::RemoteSignerError(format!(
+                "invalid signer endpoint {}, expected unix:<path> or an HTTP URL",
+                s
+            )))
+        }
+    }
+}
+
+impl TryFrom<String> for RemoteSignerEndpoint {
+    type Error = WalletError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Request sent to an external signer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RemoteSignerRequest {
+    /// get the public key of an address
+    GetPublicKey {
+        /// address
+        address: Address,
+    },
+    /// sign a hash on behalf of an address
+    Sign {
+        /// address
+        address: Address,
+        /// hash to sign
+        hash: Hash,
+        /// what is being signed
+        context: SigningContext,
+    },
+}
+
+/// Response of an external signer
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RemoteSignerResponse {
+    /// public key of the address, if it is managed by the signer
+    #[serde(default)]
+    pub public_key: Option<PublicKey>,
+    /// signature of the hash
+    #[serde(default)]
+    pub signature: Option<Signature>,
+    /// reason of the failure of the request
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Signer delegating the signatures to an external process
+pub struct RemoteSigner {
+    endpoint: RemoteSignerEndpoint,
+    timeout: Duration,
+    public_keys: RwLock<PreHashMap<Address, PublicKey>>,
+    /// addresses the signer answered it does not manage, with the time of the answer
+    unknown_addresses: RwLock<PreHashMap<Address, Instant>>,
+}
+
+impl RemoteSigner {
+    /// Creates a signer calling the external signer at `endpoint`
+    pub fn new(endpoint: RemoteSignerEndpoint, timeout: Duration) -> RemoteSigner {
+        RemoteSigner {
+            endpoint,
+            timeout,
+            public_keys: Default::default(),
+            unknown_addresses: Default::default(),
+        }
+    }
+
+    fn request(&self, request: &RemoteSignerRequest) -> Result<RemoteSignerResponse, WalletError> {
+        let response = match &self.endpoint {
+            RemoteSignerEndpoint::Http(url) => ureq::post(url)
+                .timeout(self.timeout)
+                .send_json(request)
+                .map_err(|err| WalletError::RemoteSignerError(err.to_string()))?
+                .into_json()?,
+            RemoteSignerEndpoint::Unix(path) => request_unix_socket(path, self.timeout, request)?,
+        };
+        match response.error {
+            Some(error) => Err(WalletError::RemoteSignerError(error)),
+            None => Ok(response),
+        }
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn get_public_key(&self, address: &Address) -> Result<Option<PublicKey>, WalletError> {
+        if let Some(public_key) = self.public_keys.read().get(address) {
+            return Ok(Some(*public_key));
+        }
+        if let Some(answered_at) = self.unknown_addresses.read().get(address) {
+            if answered_at.elapsed() < UNKNOWN_ADDRESS_RETRY_DELAY {
+                return Ok(None);
+            }
+        }
+        let public_key = self
+            .request(&RemoteSignerRequest::GetPublicKey { address: *address })?
+            .public_key;
+        match public_key {
+            Some(public_key) => {
+                if Address::from_public_key(&public_key) != *address {
+                    return Err(WalletError::RemoteSignerError(format!(
+                        "the signer returned a public key that does not match {}",
+                        address
+                    )));
+                }
+                self.unknown_addresses.write().remove(address);
+                self.public_keys.write().insert(*address, public_key);
+            }
+            None => {
+                self.unknown_addresses
+                    .write()
+                    .insert(*address, Instant::now());
+            }
+        }
+        Ok(public_key)
+    }
+
+    fn sign(
+        &self,
+        address: &Address,
+        hash: &Hash,
+        context: &SigningContext,
+    ) -> Result<Signature, WalletError> {
+        let public_key = self
+            .get_public_key(address)?
+            .ok_or(WalletError::MissingKeyError(*address))?;
+        let signature = self
+            .request(&RemoteSignerRequest::Sign {
+                address: *address,
+                hash: *hash,
+                context: context.clone(),
+            })?
+            .signature
+            .ok_or_else(|| {
+                WalletError::RemoteSignerError("the signer returned no signature".to_string())
+            })?;
+        public_key.verify_signature(hash, &signature)?;
+        Ok(signature)
+    }
+}
+
+/// Sends a request as a line of JSON over a Unix socket, and reads the response line
+#[cfg(unix)]
+fn request_unix_socket(
+    path: &std::path::Path,
+    timeout: Duration,
+    request: &RemoteSignerRequest,
+) -> Result<RemoteSignerResponse, WalletError> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let mut line = serde_json::to_vec(request)?;
+    line.push(b'\n');
+    stream.write_all(&line)?;
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+    Ok(serde_json::from_str(&response)?)
+}
+
+#[cfg(not(unix))]
+fn request_unix_socket(
+    _path: &std::path::Path,
+    _timeout: Duration,
+    _request: &RemoteSignerRequest,
+) -> Result<RemoteSignerResponse, WalletError> {
+    Err(WalletError::RemoteSignerError(
+        "Unix sockets are not supported on this platform".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_signer() {
+        let keypair = KeyPair::generate();
+        let address = Address::from_public_key(&keypair.get_public_key());
+        let signer = LocalSigner::from_keypair(keypair.clone());
+        let hash = Hash::compute_from(b"message");
+
+        assert_eq!(
+            signer.get_public_key(&address).unwrap(),
+            Some(keypair.get_public_key())
+        );
+        let signature = signer
+            .sign(&address, &hash, &SigningContext::Message)
+            .unwrap();
+        keypair
+            .get_public_key()
+            .verify_signature(&hash, &signature)
+            .unwrap();
+
+        let other = Address::from_public_key(&KeyPair::generate().get_public_key());
+        assert_eq!(signer.get_public_key(&other).unwrap(), None);
+        assert!(signer
+            .sign(&other, &hash, &SigningContext::Message)
+            .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remote_signer_over_unix_socket() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixListener;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket_path = dir.path().join("signer.sock");
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let keypair = KeyPair::generate();
+        let address = Address::from_public_key(&keypair.get_public_key());
+        let server_keypair = keypair.clone();
+        let request_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let server_request_count = request_count.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut line = String::new();
+                BufReader::new(&stream).read_line(&mut line).unwrap();
+                server_request_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let response = match serde_json::from_str(&line).unwrap() {
+                    RemoteSignerRequest::GetPublicKey { address } => RemoteSignerResponse {
+                        public_key: (address
+                            == Address::from_public_key(&server_keypair.get_public_key()))
+                        .then(|| server_keypair.get_public_key()),
+                        ..Default::default()
+                    },
+                    RemoteSignerRequest::Sign { hash, .. } => RemoteSignerResponse {
+                        signature: Some(server_keypair.sign(&hash).unwrap()),
+                        ..Default::default()
+                    },
+                };
+                let mut response = serde_json::to_vec(&response).unwrap();
+                response.push(b'\n');
+                stream.write_all(&response).unwrap();
+            }
+        });
+
+        let signer = RemoteSigner::new(
+            format!("unix:{}", socket_path.display()).parse().unwrap(),
+            Duration::from_secs(5),
+        );
+        assert_eq!(
+            signer.get_public_key(&address).unwrap(),
+            Some(keypair.get_public_key())
+        );
+
+        // known and unknown addresses are only asked about once
+        let other = Address::from_public_key(&KeyPair::generate().get_public_key());
+        assert_eq!(signer.get_public_key(&other).unwrap(), None);
+        assert_eq!(signer.get_public_key(&other).unwrap(), None);
+        assert_eq!(
+            signer.get_public_key(&address).unwrap(),
+            Some(keypair.get_public_key())
+        );
+        assert_eq!(request_count.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let hash = Hash::compute_from(b"block header");
+        let slot = Slot::new(3, 1);
+        let signature = signer
+            .sign(&address, &hash, &SigningContext::BlockHeader { slot })
+            .unwrap();
+        keypair
+            .get_public_key()
+            .verify_signature(&hash, &signature)
+            .unwrap();
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright (c) 2023 MASSA LABS <info@massa.net>
+
+//! Records of the block headers and endorsements signed by each address,
+//! used to refuse signing two different block headers, or two different endorsements, for the same slot.
+//!
+//! The records can be kept in memory only, or in a file where each signature is appended as a line of JSON
+//! before the content is signed, so that they survive restarts.
+//! Records older than `DENUNCIATION_EXPIRE_PERIODS` can no longer be denounced and are forgotten,
+//! and signing content at their slots is refused.
+
+use crate::{write_file_atomically, SigningContext, WalletError};
+use massa_hash::Hash;
+use massa_models::{
+    address::Address, config::DENUNCIATION_EXPIRE_PERIODS, prehash::PreHashMap, slot::Slot,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// Version of the exported slashing-protection history format
+pub const SLASHING_PROTECTION_VERSION: u32 = 1;
+
+/// Content signed by an address
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedRecord {
+    /// address that signed the content
+    pub address: Address,
+    /// what was signed
+    pub context: SigningContext,
+    /// hash of the signed content
+    pub hash: Hash,
+}
+
+/// Exportable history of the content signed by the addresses,
+/// to be imported on the machine the keys are moved to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SlashingProtectionHistory {
+    /// version of the format
+    pub version: u32,
+    /// content signed by the addresses
+    pub records: Vec<SignedRecord>,
+}
+
+/// Hashes signed by an address
+#[derive(Debug, Default)]
+struct AddressRecords {
+    block_headers: BTreeMap<Slot, Hash>,
+    endorsements: BTreeMap<(Slot, u32), Hash>,
+}
+
+/// Records of the block headers and endorsements signed by each address
+#[derive(Debug, Default)]
+pub struct SlashingProtection {
+    records: PreHashMap<Address, AddressRecords>,
+    /// latest slot of the records
+    latest_slot: Option<Slot>,
+    /// file the records are appended to, if they are persisted
+    file: Option<(PathBuf, File)>,
+}
+
+impl SlashingProtection {
+    /// Opens the records persisted in a file, creating it if it does not exist.
+    /// Forgotten records are removed from the file.
+    pub fn open(path: &Path) -> Result<SlashingProtection, WalletError> {
+        let mut protection = SlashingProtection::default();
+        if path.is_file() {
+            let lines = BufReader::new(File::open(path)?)
+                .lines()
+                .collect::<Result<Vec<_>, _>>()?;
+            let line_count = lines.len();
+            for (index, line) in lines.into_iter().enumerate() {
+                match serde_json::from_str::<SignedRecord>(&line) {
+                    Ok(record) => {
+                        protection.insert(record);
+                    }
+                    // the last line may have been interrupted while being written
+                    Err(_) if index + 1 == line_count => {}
+                    Err(err) => {
+                        return Err(WalletError::SlashingProtectionError(format!(
+                            "invalid record in {}: {}",
+                            path.display(),
+                            err
+                        )))
+                    }
+                }
+            }
+        }
+
+        // rewrite the file without the forgotten records
+        let mut content = Vec::new();
+        for record in protection.get_records() {
+            serde_json::to_writer(&mut content, &record)?;
+            content.push(b'\n');
+        }
+        write_file_atomically(path, &content)?;
+
+        let file = OpenOptions::new().append(true).open(path)?;
+        protection.file = Some((path.to_path_buf(), file));
+        Ok(protection)
+    }
+
+    /// Checks that signing the hash does not conflict with previously signed content, and records it
+    pub fn check_and_record(
+        &mut self,
+        address: &Address,
+        hash: &Hash,
+        context: &SigningContext,
+    ) -> Result<(), WalletError> {
+        let slot = match context {
+            SigningContext::BlockHeader { slot } | SigningContext::Endorsement { slot, .. } => {
+                *slot
+            }
+            SigningContext::Block { .. } | SigningContext::Message => return Ok(()),
+        };
+        if matches!(self.get_oldest_slot(), Some(oldest) if slot < oldest) {
+            return Err(WalletError::SlashingProtectionError(format!(
+                "{} refused to sign {:?}: the slot is too old",
+                address, context
+            )));
+        }
+        match self.get_signed_hash(address, context) {
+            // signing the same content again is harmless
+            Some(signed) if signed == hash => Ok(()),
+            Some(_) => Err(WalletError::SlashingProtectionError(format!(
+                "{} refused to sign {:?}: conflicting content was already signed",
+                address, context
+            ))),
+            None => self.persist(SignedRecord {
+                address: *address,
+                context: context.clone(),
+                hash: *hash,
+            }),
+        }
+    }
+
+    /// Exports the records, to be imported on another machine
+    pub fn export(&self) -> SlashingProtectionHistory {
+        SlashingProtectionHistory {
+            version: SLASHING_PROTECTION_VERSION,
+            records: self.get_records(),
+        }
+    }
+
+    /// Imports records exported on another machine, in addition to the existing ones.
+    /// Returns the number of imported records.
+    /// Nothing is imported if some records conflict with the existing ones,
+    /// as it means that both machines signed different content for the same slot.
+    pub fn import(&mut self, history: SlashingProtectionHistory) -> Result<usize, WalletError> {
+        if history.version != SLASHING_PROTECTION_VERSION {
+            return Err(WalletError::SlashingProtectionError(format!(
+                "unsupported slashing-protection history version {}",
+                history.version
+            )));
+        }
+        let conflicts = history
+            .records
+            .iter()
+            .filter(|record| {
+                matches!(self.get_signed_hash(&record.address, &record.context), Some(hash) if *hash != record.hash)
+            })
+            .map(|record| format!("{} {:?}", record.address, record.context))
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() {
+            return Err(WalletError::SlashingProtectionError(format!(
+                "{} imported records conflict with the existing ones: {}",
+                conflicts.len(),
+                conflicts.join(", ")
+            )));
+        }
+        let mut count = 0;
+        for record in history.records {
+            if !self.contains(&record) {
+                self.persist(record)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Gets the hash of the content signed by an address in the same context, if any
+    fn get_signed_hash(&self, address: &Address, context: &SigningContext) -> Option<&Hash> {
+        let records = self.records.get(address)?;
+        match context {
+            SigningContext::BlockHeader { slot } => records.block_headers.get(slot),
+            SigningContext::Endorsement { slot, index } => {
+                records.endorsements.get(&(*slot, *index))
+            }
+            SigningContext::Block { .. } | SigningContext::Message => None,
+        }
+    }
+
+    /// Slots before this one are too old to be denounced, and signing content at them is refused
+    fn get_oldest_slot(&self) -> Option<Slot> {
+        self.latest_slot
+            .map(|latest| Slot::new(latest.period.saturating_sub(DENUNCIATION_EXPIRE_PERIODS), 0))
+    }
+
+    /// Whether content was already signed at the slot of the record, or the slot is too old
+    fn contains(&self, record: &SignedRecord) -> bool {
+        let records = self.records.get(&record.address);
+        match &record.context {
+            SigningContext::BlockHeader { slot } => {
+                records.map_or(false, |records| records.block_headers.contains_key(slot))
+                    || matches!(self.get_oldest_slot(), Some(oldest) if *slot < oldest)
+            }
+            SigningContext::Endorsement { slot, index } => {
+                records.map_or(false, |records| {
+                    records.endorsements.contains_key(&(*slot, *index))
+                }) || matches!(self.get_oldest_slot(), Some(oldest) if *slot < oldest)
+            }
+            SigningContext::Block { .. } | SigningContext::Message => true,
+        }
+    }
+
+    /// Writes a record to the file, if any, and adds it
+    fn persist(&mut self, record: SignedRecord) -> Result<(), WalletError> {
+        if let Some((path, file)) = &mut self.file {
+            let mut line = serde_json::to_vec(&record)?;
+            line.push(b'\n');
+            file.write_all(&line)
+                .and_then(|_| file.sync_data())
+                .map_err(|err| {
+                    WalletError::SlashingProtectionError(format!(
+                        "could not write to {}: {}",
+                        path.display(),
+                        err
+                    ))
+                })?;
+        }
+        self.insert(record);
+        Ok(())
+    }
+
+    /// Adds a record, and forgets the records that became too old
+    fn insert(&mut self, record: SignedRecord) {
+        let records = self.records.entry(record.address).or_default();
+        let slot = match record.context {
+            SigningContext::BlockHeader { slot } => {
+                records.block_headers.insert(slot, record.hash);
+                slot
+            }
+            SigningContext::Endorsement { slot, index } => {
+                records.endorsements.insert((slot, index), record.hash);
+                slot
+            }
+            SigningContext::Block { .. } | SigningContext::Message => return,
+        };
+        if self.latest_slot.map_or(true, |latest| slot > latest) {
+            self.latest_slot = Some(slot);
+            if let Some(oldest) = self.get_oldest_slot() {
+                self.records.retain(|_, records| {
+                    records.block_headers.retain(|slot, _| *slot >= oldest);
+                    records.endorsements.retain(|(slot, _), _| *slot >= oldest);
+                    !records.block_headers.is_empty() || !records.endorsements.is_empty()
+                });
+            }
+        }
+    }
+
+    /// Gets all the records
+    fn get_records(&self) -> Vec<SignedRecord> {
+        let mut result = Vec::new();
+        for (address, records) in self.records.iter() {
+            result.extend(
+                records
+                    .block_headers
+                    .iter()
+                    .map(|(slot, hash)| SignedRecord {
+                        address: *address,
+                        context: SigningContext::BlockHeader { slot: *slot },
+                        hash: *hash,
+                    }),
+            );
+            result.extend(
+                records
+                    .endorsements
+                    .iter()
+                    .map(|((slot, index), hash)| SignedRecord {
+                        address: *address,
+                        context: SigningContext::Endorsement {
+                            slot: *slot,
+                            index: *index,
+                        },
+                        hash: *hash,
+                    }),
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use massa_signature::KeyPair;
+
+    #[test]
+    fn test_slashing_protection_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("slashing_protection.json");
+        let address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let first = Hash::compute_from(b"first");
+        let second = Hash::compute_from(b"second");
+        let header = SigningContext::BlockHeader {
+            slot: Slot::new(10, 0),
+        };
+        let endorsement = SigningContext::Endorsement {
+            slot: Slot::new(10, 1),
+            index: 3,
+        };
+
+        {
+            let mut protection = SlashingProtection::open(&path).unwrap();
+            protection
+                .check_and_record(&address, &first, &header)
+                .unwrap();
+            protection
+                .check_and_record(&address, &first, &endorsement)
+                .unwrap();
+        }
+
+        // the records survive a restart
+        let mut protection = SlashingProtection::open(&path).unwrap();
+        protection
+            .check_and_record(&address, &first, &header)
+            .unwrap();
+        assert!(protection
+            .check_and_record(&address, &second, &header)
+            .is_err());
+        assert!(protection
+            .check_and_record(&address, &second, &endorsement)
+            .is_err());
+
+        // records that can no longer be denounced are forgotten, and their slots refused
+        let later = SigningContext::BlockHeader {
+            slot: Slot::new(11 + DENUNCIATION_EXPIRE_PERIODS, 0),
+        };
+        protection
+            .check_and_record(&address, &second, &later)
+            .unwrap();
+        assert_eq!(protection.export().records.len(), 1);
+        assert!(protection
+            .check_and_record(&address, &second, &endorsement)
+            .is_err());
+    }
+
+    #[test]
+    fn test_slashing_protection_export_import() {
+        let address = Address::from_public_key(&KeyPair::generate().get_public_key());
+        let first = Hash::compute_from(b"first");
+        let header = SigningContext::BlockHeader {
+            slot: Slot::new(10, 0),
+        };
+        let mut protection = SlashingProtection::default();
+        protection
+            .check_and_record(&address, &first, &header)
+            .unwrap();
+        let history: SlashingProtectionHistory =
+            serde_json::from_str(&serde_json::to_string(&protection.export()).unwrap()).unwrap();
+
+        let mut other = SlashingProtection::default();
+        assert_eq!(other.import(history.clone()).unwrap(), 1);
+        assert_eq!(other.import(history).unwrap(), 0);
+        assert!(other
+            .check_and_record(&address, &Hash::compute_from(b"second"), &header)
+            .is_err());
+
+        // histories conflicting with the existing records are rejected as a whole
+        let endorsement = SigningContext::Endorsement {
+            slot: Slot::new(10, 0),
+            index: 0,
+        };
+        let mut conflicting = SlashingProtection::default();
+        conflicting
+            .check_and_record(&address, &Hash::compute_from(b"second"), &header)
+            .unwrap();
+        conflicting
+            .check_and_record(&address, &first, &endorsement)
+            .unwrap();
+        let mut other = SlashingProtection::default();
+        other.check_and_record(&address, &first, &header).unwrap();
+        assert!(other.import(conflicting.export()).is_err());
+        assert!(other
+            .check_and_record(&address, &Hash::compute_from(b"second"), &endorsement)
+            .is_ok());
+    }
+}
@@ -5,7 +5,7 @@ use massa_models::{address::Address, prehash::PreHashMap};
 use massa_signature::KeyPair;
 use tempfile::NamedTempFile;
 
-use crate::Wallet;
+use crate::{Argon2Params, Wallet};
 
 /// Creates a temporary file and a temporary wallet.
 pub fn create_test_wallet(default_accounts: Option<PreHashMap<Address, KeyPair>>) -> Wallet {
@@ -23,5 +23,10 @@ pub fn create_test_wallet(default_accounts: Option<PreHashMap<Address, KeyPair>>
             .unwrap(),
         )
         .unwrap();
-    Wallet::new(wallet_file.path().to_path_buf(), "test".to_string()).unwrap()
+    Wallet::new(
+        wallet_file.path().to_path_buf(),
+        "test".to_string(),
+        Argon2Params::default(),
+    )
+    .unwrap()
 }